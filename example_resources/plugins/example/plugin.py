@@ -0,0 +1,33 @@
+# ATAC plugin protocol v1
+# stdin: {"api_version": 1, "hook": "<hook>", "input": {...}}
+# stdout: a JSON object, a non-zero exit code being an error described on stderr
+import hashlib
+import json
+import sys
+
+message = json.load(sys.stdin)
+hook = message["hook"]
+data = message["input"]
+
+if hook == "auth":
+    # Signs the method, the URL and the body with the config as key
+    to_sign = data["config"] + data["method"] + data["url"] + (data["body"] or "")
+    output = {"headers": [["X-Example-Signature", hashlib.sha256(to_sign.encode()).hexdigest()]]}
+elif hook == "body_encoder":
+    output = {"body": data["body"].upper()}
+elif hook == "importer":
+    # One GET request per non-empty line of the file
+    urls = [line.strip() for line in data["content"].splitlines() if line.strip()]
+    # The missing request fields take their default value
+    requests = [{"name": url, "url": url, "method": "GET"} for url in urls]
+    output = {"collection": {"name": "imported", "requests": requests}}
+elif hook == "exporter":
+    requests = [data["request"]] if data["request"] else data["collection"]["requests"]
+    output = {"output": "".join(f'{request["method"]} {request["url"]}\n' for request in requests)}
+elif hook == "response_processor":
+    output = {"tests": [{"name": "status is 2xx", "passed": (data["status_code"] or "").startswith("2")}]}
+else:
+    print(f"Unknown hook {hook}", file=sys.stderr)
+    sys.exit(1)
+
+json.dump(output, sys.stdout)
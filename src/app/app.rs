@@ -1,7 +1,9 @@
+use std::path::PathBuf;
 use std::sync::Arc;
-use std::time::Duration;
+use std::time::{Duration, Instant};
 
 use ratatui::crossterm::terminal::disable_raw_mode;
+use indexmap::IndexMap;
 use parking_lot::RwLock;
 use ratatui::backend::Backend;
 use ratatui::Terminal;
@@ -10,47 +12,119 @@ use tui_textarea::TextArea;
 
 use crate::app::files::config::Config;
 use crate::models::collection::Collection;
+use crate::models::dataset::DataRow;
 use crate::models::environment::Environment;
+use crate::models::journal::UnsavedEdit;
+use crate::models::request::Request;
+use crate::models::template::RequestTemplate;
+use crate::models::user_agent::USER_AGENT_PRESETS;
 use crate::tui::app_states::AppState;
 use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
 use crate::tui::ui::result_tabs::RequestResultTabs;
 use crate::tui::ui::views::RequestView;
 use crate::tui::utils::stateful::choice_popup::ChoicePopup;
 use crate::tui::utils::stateful::cookies_popup::CookiesPopup;
+use crate::tui::utils::stateful::cheatsheet_popup::CheatsheetPopup;
 use crate::tui::utils::stateful::help_popup::HelpPopup;
 use crate::tui::utils::stateful::new_request_popup::NewRequestPopup;
 use crate::tui::utils::stateful::script_console::ScriptConsole;
+use crate::tui::utils::stateful::encoding_tools_popup::EncodingToolsPopup;
+use crate::tui::utils::stateful::go_to_line_popup::GoToLinePopup;
+use crate::tui::utils::stateful::jwt_popup::JwtPopup;
+use crate::tui::utils::stateful::logs_popup::LogsPopup;
+use crate::tui::utils::stateful::error_popup::ErrorPopup;
+use crate::tui::utils::stateful::macro_popup::MacroPopup;
+use crate::tui::utils::stateful::request_preview_popup::RequestPreviewPopup;
+use crate::tui::utils::stateful::response_cache_popup::ResponseCachePopup;
+use crate::tui::utils::stateful::response_links_popup::ResponseLinksPopup;
+use crate::tui::utils::stateful::scratchpad_popup::ScratchpadPopup;
+use crate::tui::utils::stateful::scripting_console_popup::ScriptingConsolePopup;
 use crate::tui::utils::stateful::settings_popup::SettingsPopup;
+use crate::tui::utils::stateful::variable_usages_popup::VariableUsagesPopup;
 use crate::tui::utils::stateful::stateful_custom_table::StatefulCustomTable;
 use crate::tui::utils::stateful::stateful_scrollbar::StatefulScrollbar;
 use crate::tui::utils::stateful::stateful_tree::StatefulTree;
 use crate::tui::utils::stateful::text_input::TextInput;
 use crate::tui::utils::stateful::text_input_selection::TextInputSelection;
 use crate::tui::utils::stateful::validation_popup::ValidationPopup;
+use crate::tui::utils::stateful::webhook_listener_popup::WebhookListenerPopup;
+use crate::tui::utils::stateful::load_test_popup::LoadTestPopup;
+use crate::tui::utils::stateful::git_popup::GitPopup;
+use crate::tui::tui_logic::request::send::ReceivedResponse;
+use crate::tui::utils::response_views::ResponseViews;
 use crate::tui::utils::syntax_highlighting::SyntaxHighlighting;
 use crate::tui::utils::vim_emulation::Vim;
 
+/// Path of the body file along with its size and first bytes, or the error met reading it
+pub type BodyFilePreview = (String, Result<(u64, Vec<u8>), String>);
+
 pub struct App<'a> {
     pub tick_rate: Duration,
     pub should_quit: bool,
     pub should_display_help: bool,
+    pub should_display_cheatsheet: bool,
+    /// Set after giving the terminal to an external program, the next draw has to repaint everything
+    pub should_clear_terminal: bool,
 
     pub state: AppState,
 
     pub config: Config,
 
+    /// Set by the offline toggle, the --offline argument and the config decide until then
+    pub offline_mode: Option<bool>,
+
+    /// Workspaces of the config, preceded by the project directory when one is detected
+    pub workspaces: IndexMap<String, PathBuf>,
+    /// Name of the selected workspace, None for the app directory
+    pub selected_workspace: Option<String>,
+    /// Snippets and notes pinned in the selected workspace
+    pub scratchpad_popup: ScratchpadPopup,
+    pub scripting_console_popup: ScriptingConsolePopup,
+
     /* Help */
 
     pub help_popup: HelpPopup,
+    pub cheatsheet_popup: CheatsheetPopup,
 
     /* Environments */
     
     pub environments: Vec<Arc<RwLock<Environment>>>,
     pub selected_environment: usize,
+    pub environment_popup: ChoicePopup,
+    pub variable_usages_popup: VariableUsagesPopup,
+
+    /// Dataset row currently bound by the data-driven runner, takes precedence over the environment values
+    pub data_row: Option<DataRow>,
+
+    /* Templates */
+
+    pub request_templates: Vec<RequestTemplate>,
 
     /* Cookies */
     
     pub cookies_popup: CookiesPopup,
+
+    /* Webhook listener */
+
+    pub webhook_listener_popup: WebhookListenerPopup,
+    pub load_test_popup: LoadTestPopup,
+
+    /* Git */
+
+    pub git_popup: GitPopup,
+
+    /* Response cache */
+
+    pub response_cache_popup: ResponseCachePopup,
+
+    /* Logs */
+
+    pub logs_popup: LogsPopup,
+
+    /* Errors */
+
+    pub error_popup: ErrorPopup,
+    pub macro_popup: MacroPopup,
     
     /* Collections */
     
@@ -60,6 +134,12 @@ pub struct App<'a> {
     pub request_view: RequestView,
     pub request_param_tab: RequestParamsTabs,
     pub request_result_tab: RequestResultTabs,
+    /// Long lines of the result and of the request preview are wrapped instead of scrolled horizontally
+    pub should_wrap_lines: bool,
+    /// Width of the result area when it was last rendered, used to count the wrapped rows
+    pub result_wrap_width: usize,
+    /// Line numbers of the request body editor and of the result body
+    pub should_display_line_numbers: bool,
 
     pub creation_popup: ChoicePopup,
 
@@ -67,6 +147,11 @@ pub struct App<'a> {
     pub rename_collection_input: TextInput,
     pub new_request_popup: NewRequestPopup,
     pub rename_request_input: TextInput,
+    /// Tags of the highlighted collection or request, separated by commas
+    pub edit_tags_input: TextInput,
+    pub tag_filter_input: TextInput,
+    /// Only the requests having one of these tags, or whose collection has one, are displayed
+    pub tag_filter: Vec<String>,
 
     pub delete_collection_popup: ValidationPopup,
     pub delete_request_popup: ValidationPopup,
@@ -81,15 +166,46 @@ pub struct App<'a> {
     pub auth_basic_username_text_input: TextInput,
     pub auth_basic_password_text_input: TextInput,
     pub auth_bearer_token_text_input: TextInput,
+    pub auth_hmac_secret_text_input: TextInput,
+    pub auth_hmac_string_to_sign_text_input: TextInput,
+    pub auth_hmac_header_text_input: TextInput,
+    pub auth_ntlm_username_text_input: TextInput,
+    pub auth_ntlm_password_text_input: TextInput,
+    pub auth_ntlm_domain_text_input: TextInput,
+    pub auth_oauth1_consumer_key_text_input: TextInput,
+    pub auth_oauth1_consumer_secret_text_input: TextInput,
+    pub auth_oauth1_token_text_input: TextInput,
+    pub auth_oauth1_token_secret_text_input: TextInput,
+    pub auth_plugin_name_text_input: TextInput,
+    pub auth_plugin_config_text_input: TextInput,
+
+    /// Environment keys completing the "{{" typed in the URL, a query param, a header or the body
+    pub env_key_completion_popup: ChoicePopup,
 
     pub headers_table: StatefulCustomTable,
+    /// Names or values completing the header cell being edited
+    pub header_completion_popup: ChoicePopup,
+
+    pub request_cookies_table: StatefulCustomTable,
 
     pub body_file_text_input: TextInput,
+    pub body_file_content_type_text_input: TextInput,
+    pub body_file_text_input_selection: TextInputSelection,
+    /// Paths completing the body file path being edited
+    pub body_file_completion_popup: ChoicePopup,
+    /// Resolved path along with its size and first bytes, read again when the path changes
+    pub body_file_preview: Option<BodyFilePreview>,
     pub body_form_table: StatefulCustomTable,
     pub body_text_area: TextArea<'a>,
     pub body_text_area_vim_emulation: Vim,
 
     pub request_settings_popup: SettingsPopup,
+    pub user_agent_popup: ChoicePopup,
+    pub request_preview_popup: RequestPreviewPopup,
+    pub jwt_popup: JwtPopup,
+    pub encoding_tools_popup: EncodingToolsPopup,
+    pub go_to_line_popup: GoToLinePopup,
+    pub response_links_popup: ResponseLinksPopup,
 
     pub result_throbber_state: ThrobberState,
     pub result_vertical_scrollbar: StatefulScrollbar,
@@ -97,9 +213,39 @@ pub struct App<'a> {
 
     pub script_console: ScriptConsole<'a>,
 
+    pub request_description_scroll: u16,
+
+    /* Journal */
+
+    /// Last edit written to the journal
+    pub journaled_edit: Option<UnsavedEdit>,
+    pub last_journal_save: Instant,
+    /// Edit left unsaved by the previous run, waiting for the user to recover or discard it
+    pub recovered_edit: Option<UnsavedEdit>,
+    pub recover_edit_popup: ValidationPopup,
+
+    /* File watcher */
+
+    pub last_files_check: Instant,
+    /// Collection changed on disk while one of its requests was being edited, along with the editing state
+    pub changed_collection: Option<(usize, AppState)>,
+    pub reload_collection_popup: ValidationPopup,
+
     /* Others */
     
     pub syntax_highlighting: SyntaxHighlighting,
+    /// Responses of the requests prepared for display, swapped in when a request is selected
+    pub response_views: ResponseViews,
+
+    /// Notifications (title, body) waiting to be written to the terminal between two draws
+    pub pending_notifications: Arc<RwLock<Vec<(String, String)>>>,
+
+    /// Requests which got a 401, waiting for the login request of their collection to be sent
+    pub pending_logins: Arc<RwLock<Vec<Arc<RwLock<Request>>>>>,
+    /// Requests to send again once the login request has been received
+    pub pending_login_retries: Arc<RwLock<Vec<Arc<RwLock<Request>>>>>,
+    /// Responses received by the requests sent from the TUI, shown if their request is still selected
+    pub pending_received_responses: Arc<RwLock<Vec<ReceivedResponse>>>,
 }
 
 impl App<'_> {
@@ -108,23 +254,62 @@ impl App<'_> {
             tick_rate: Duration::from_millis(250),
             should_quit: false,
             should_display_help: false,
+            should_display_cheatsheet: false,
+            should_clear_terminal: false,
             
             state: AppState::Normal,
 
             config: Config::default(),
+            offline_mode: None,
+
+            workspaces: IndexMap::new(),
+            selected_workspace: None,
+            scratchpad_popup: ScratchpadPopup::default(),
+            scripting_console_popup: ScriptingConsolePopup::default(),
 
             /* Help */
 
             help_popup: HelpPopup::default(),
+            cheatsheet_popup: CheatsheetPopup::default(),
 
             /* Environments */
 
             environments: vec![],
             selected_environment: 0,
+            environment_popup: ChoicePopup::default(),
+            variable_usages_popup: VariableUsagesPopup::default(),
+
+            data_row: None,
+
+            /* Templates */
+
+            request_templates: vec![],
 
             /* Cookies */
 
             cookies_popup: CookiesPopup::default(),
+
+            /* Webhook listener */
+
+            webhook_listener_popup: WebhookListenerPopup::default(),
+            load_test_popup: LoadTestPopup::default(),
+
+            /* Git */
+
+            git_popup: GitPopup::default(),
+
+            /* Response cache */
+
+            response_cache_popup: ResponseCachePopup::default(),
+
+            /* Logs */
+
+            logs_popup: LogsPopup::default(),
+
+            /* Errors */
+
+            error_popup: ErrorPopup::default(),
+            macro_popup: MacroPopup::default(),
             
             /* Collections */
             
@@ -135,6 +320,9 @@ impl App<'_> {
 
             request_param_tab: RequestParamsTabs::QueryParams,
             request_result_tab: RequestResultTabs::Body,
+            should_wrap_lines: false,
+            result_wrap_width: 0,
+            should_display_line_numbers: true,
 
             creation_popup: ChoicePopup {
               choices: vec![String::from("Collection"), String::from("Request")],
@@ -145,6 +333,9 @@ impl App<'_> {
             rename_collection_input: TextInput::default(),
             new_request_popup: NewRequestPopup::default(),
             rename_request_input: TextInput::default(),
+            edit_tags_input: TextInput::default(),
+            tag_filter_input: TextInput::default(),
+            tag_filter: vec![],
 
             delete_collection_popup: ValidationPopup::default(),
             delete_request_popup: ValidationPopup::default(),
@@ -159,16 +350,50 @@ impl App<'_> {
             auth_basic_username_text_input: TextInput::default(),
             auth_basic_password_text_input: TextInput::default(),
             auth_bearer_token_text_input: TextInput::default(),
+            auth_hmac_secret_text_input: TextInput::default(),
+            auth_hmac_string_to_sign_text_input: TextInput::default(),
+            auth_hmac_header_text_input: TextInput::default(),
+            auth_ntlm_username_text_input: TextInput::default(),
+            auth_ntlm_password_text_input: TextInput::default(),
+            auth_ntlm_domain_text_input: TextInput::default(),
+            auth_oauth1_consumer_key_text_input: TextInput::default(),
+            auth_oauth1_consumer_secret_text_input: TextInput::default(),
+            auth_oauth1_token_text_input: TextInput::default(),
+            auth_oauth1_token_secret_text_input: TextInput::default(),
+            auth_plugin_name_text_input: TextInput::default(),
+            auth_plugin_config_text_input: TextInput::default(),
+
+            env_key_completion_popup: ChoicePopup::default(),
 
             headers_table: StatefulCustomTable::default(),
+            header_completion_popup: ChoicePopup::default(),
+
+            request_cookies_table: StatefulCustomTable::default(),
 
             body_file_text_input: TextInput::default(),
+            body_file_content_type_text_input: TextInput::default(),
+            body_file_text_input_selection: TextInputSelection {
+                usable: true,
+                selected: 0,
+                max_selection: 2,
+            },
+            body_file_completion_popup: ChoicePopup::default(),
+            body_file_preview: None,
             body_form_table: StatefulCustomTable::default(),
             body_text_area: TextArea::default(),
             body_text_area_vim_emulation: Vim::default(),
 
 
             request_settings_popup: SettingsPopup::default(),
+            user_agent_popup: ChoicePopup {
+                choices: USER_AGENT_PRESETS.iter().map(|(name, _)| name.to_string()).collect(),
+                selection: 0
+            },
+            request_preview_popup: RequestPreviewPopup::default(),
+            jwt_popup: JwtPopup::default(),
+            encoding_tools_popup: EncodingToolsPopup::default(),
+            go_to_line_popup: GoToLinePopup::default(),
+            response_links_popup: ResponseLinksPopup::default(),
             
             result_throbber_state: ThrobberState::default(),
             result_vertical_scrollbar: StatefulScrollbar::default(),
@@ -176,9 +401,31 @@ impl App<'_> {
 
             script_console: ScriptConsole::default(),
 
+            request_description_scroll: 0,
+
+            /* Journal */
+
+            journaled_edit: None,
+            last_journal_save: Instant::now(),
+            recovered_edit: None,
+            recover_edit_popup: ValidationPopup::default(),
+
+            /* File watcher */
+
+            last_files_check: Instant::now(),
+            changed_collection: None,
+            reload_collection_popup: ValidationPopup::default(),
+
             /* Others */
 
             syntax_highlighting: SyntaxHighlighting::default(),
+            response_views: ResponseViews::default(),
+
+            pending_notifications: Arc::new(RwLock::new(vec![])),
+
+            pending_logins: Arc::new(RwLock::new(vec![])),
+            pending_login_retries: Arc::new(RwLock::new(vec![])),
+            pending_received_responses: Arc::new(RwLock::new(vec![])),
         }
     }
 
@@ -186,9 +433,23 @@ impl App<'_> {
         terminal.clear()?;
 
         while !self.should_quit {
+            self.tui_run_due_monitors().await;
+            self.tui_run_due_watch().await;
+            self.tui_send_pending_logins().await;
+            self.tui_send_queued_requests().await;
+            self.tui_send_pending_notifications();
+            self.tui_display_pending_error();
+            self.tui_show_received_responses();
             self.update_current_available_events();
             self.draw(&mut terminal)?;
             self.handle_events().await;
+            self.tui_update_journal();
+            self.tui_reload_changed_files();
+
+            if self.should_clear_terminal {
+                terminal.clear()?;
+                self.should_clear_terminal = false;
+            }
         }
 
         Ok(())
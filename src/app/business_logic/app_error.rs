@@ -0,0 +1,39 @@
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use tracing::error;
+
+use crate::cli::args::ARGS;
+use crate::panic_error;
+
+lazy_static! {
+    /// Errors waiting to be displayed by the TUI, one popup at a time
+    pub static ref PENDING_ERRORS: RwLock<VecDeque<AppError>> = RwLock::new(VecDeque::new());
+}
+
+/// Failure the app can go on after, displayed in a popup
+pub struct AppError {
+    /// What could not be done, e.g. "Could not write collection file"
+    pub message: String,
+    /// Value which made it fail, e.g. the file path along with the OS error
+    pub value: String,
+    /// What the user can do about it
+    pub hint: String,
+}
+
+/// Log the error and display it in a popup, the TUI keeps running. The CLI exits since its command cannot complete
+pub fn report_error<T: Display>(message: &str, value: T, hint: &str) {
+    error!("{message}: {value}");
+
+    if ARGS.command.is_some() {
+        panic_error(format!("{message}\n\t{value}\n\t{hint}"));
+    }
+
+    PENDING_ERRORS.write().push_back(AppError {
+        message: message.to_string(),
+        value: value.to_string(),
+        hint: hint.to_string(),
+    });
+}
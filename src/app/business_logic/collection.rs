@@ -5,11 +5,14 @@ use parking_lot::RwLock;
 use thiserror::Error;
 use tracing::info;
 use crate::app::app::App;
-use crate::app::business_logic::collection::CollectionError::{CollectionNameAlreadyExists, CollectionNameIsEmpty};
+use crate::app::files::collection::{collection_file_path_with_format, new_collection_path};
+use crate::app::business_logic::collection::CollectionError::{CollectionNameAlreadyExists, CollectionNameIsEmpty, CollectionPathAlreadyExists, EncryptedCollectionLayout};
+use crate::app::files::encryption::{is_encrypted_file, toggle_encrypted_file_path};
 use crate::app::business_logic::collection::RequestError::RequestNameIsEmpty;
-use crate::cli::args::ARGS;
-use crate::models::collection::Collection;
+use crate::models::collection::{Collection, CollectionFileFormat, CollectionLayout};
+use crate::models::collection_hooks::{CollectionHooks, HookKind};
 use crate::models::request::Request;
+use crate::models::think_time::ThinkTime;
 
 #[derive(Error, Debug)]
 pub enum CollectionError {
@@ -17,6 +20,10 @@ pub enum CollectionError {
     CollectionNameIsEmpty,
     #[error("A collection with this name already exists")]
     CollectionNameAlreadyExists,
+    #[error("\"{0}\" already exists")]
+    CollectionPathAlreadyExists(String),
+    #[error("Only collections stored in a single file can be encrypted")]
+    EncryptedCollectionLayout,
 }
 
 #[derive(Error, Debug)]
@@ -41,12 +48,20 @@ impl App<'_> {
         info!("Collection \"{new_collection_name}\" created");
 
         let file_format = self.config.get_preferred_collection_file_format();
+        let layout = self.config.get_preferred_collection_layout();
         
         let new_collection = Collection {
             name: new_collection_name.clone(),
+            description: None,
+            read_only: false,
+            login_request: None,
+            hooks: CollectionHooks::default(),
+            think_time: None,
+            tags: vec![],
             requests: vec![],
-            path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", new_collection_name, file_format.to_string())),
+            path: new_collection_path(&self.get_workspace_directory().unwrap(), &new_collection_name, file_format, layout),
             file_format,
+            layout,
         };
 
         self.collections.push(new_collection);
@@ -78,10 +93,119 @@ impl App<'_> {
         self.delete_collection_file(collection);
     }
 
+    /// Move the collection to a single file or to a directory with one file per request
+    pub fn modify_collection_layout(&mut self, collection_index: usize, layout: CollectionLayout) -> anyhow::Result<()> {
+        let previous_collection = self.collections[collection_index].clone();
+
+        if previous_collection.layout == layout {
+            return Ok(());
+        }
+
+        if is_encrypted_file(&previous_collection.path) {
+            return Err(anyhow!(EncryptedCollectionLayout));
+        }
+
+        // Keep the name on disk, which differs from the collection name after a rename
+        let path_name = match previous_collection.layout {
+            CollectionLayout::SingleFile => previous_collection.path.file_stem(),
+            CollectionLayout::FilePerRequest => previous_collection.path.file_name()
+        };
+
+        let directory = previous_collection.path.parent().unwrap();
+        let new_path = new_collection_path(directory, &path_name.unwrap().to_string_lossy(), previous_collection.file_format, layout);
+
+        if new_path.exists() {
+            return Err(anyhow!(CollectionPathAlreadyExists(new_path.display().to_string())));
+        }
+
+        info!("Collection layout set to \"{layout}\"");
+
+        self.collections[collection_index].layout = layout;
+        self.collections[collection_index].path = new_path;
+
+        self.save_collection_to_file(collection_index);
+        self.delete_collection_file(previous_collection);
+
+        Ok(())
+    }
+
+    /// Serialize the collection in another file format, e.g. "my_collection.json" -> "my_collection.toml"
+    pub fn modify_collection_file_format(&mut self, collection_index: usize, file_format: CollectionFileFormat) -> anyhow::Result<()> {
+        let previous_collection = self.collections[collection_index].clone();
+
+        if previous_collection.file_format == file_format {
+            return Ok(());
+        }
+
+        // The directory of the file per request layout is kept, the files in the previous format are removed when saving
+        let new_path = match previous_collection.layout {
+            CollectionLayout::SingleFile => collection_file_path_with_format(&previous_collection.path, file_format),
+            CollectionLayout::FilePerRequest => previous_collection.path.clone()
+        };
+
+        if previous_collection.layout == CollectionLayout::SingleFile && new_path.exists() {
+            return Err(anyhow!(CollectionPathAlreadyExists(new_path.display().to_string())));
+        }
+
+        info!("Collection file format set to \"{file_format}\"");
+
+        self.collections[collection_index].file_format = file_format;
+        self.collections[collection_index].path = new_path;
+
+        self.save_collection_to_file(collection_index);
+
+        if previous_collection.layout == CollectionLayout::SingleFile {
+            self.delete_collection_file(previous_collection);
+        }
+
+        Ok(())
+    }
+
+    /// Move the collection to an encrypted file ("my_collection.json.age"), or back to a plain file
+    pub fn modify_collection_encryption(&mut self, collection_index: usize, encrypted: bool) -> anyhow::Result<()> {
+        let previous_collection = self.collections[collection_index].clone();
+
+        if is_encrypted_file(&previous_collection.path) == encrypted {
+            return Ok(());
+        }
+
+        if previous_collection.layout == CollectionLayout::FilePerRequest {
+            return Err(anyhow!(EncryptedCollectionLayout));
+        }
+
+        let new_path = toggle_encrypted_file_path(&previous_collection.path);
+
+        if new_path.exists() {
+            return Err(anyhow!(CollectionPathAlreadyExists(new_path.display().to_string())));
+        }
+
+        match encrypted {
+            true => info!("Collection encrypted"),
+            false => info!("Collection decrypted")
+        }
+
+        self.collections[collection_index].path = new_path;
+
+        self.save_collection_to_file(collection_index);
+        self.delete_collection_file(previous_collection);
+
+        Ok(())
+    }
+
     pub fn delete_request(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
         info!("Request deleted");
         
-        self.collections[collection_index].requests.remove(request_index);
+        let deleted_request = self.collections[collection_index].requests.remove(request_index);
+        let deleted_request_name = deleted_request.read().name.clone();
+
+        // The other requests no longer wait for it
+        for request in &self.collections[collection_index].requests {
+            request.write().depends_on.retain(|dependency| *dependency != deleted_request_name);
+        }
+
+        // The runs no longer send it as a hook
+        self.collections[collection_index].hooks.remove_request(&deleted_request_name);
+
         self.save_collection_to_file(collection_index);
         
         Ok(())
@@ -107,20 +231,108 @@ impl App<'_> {
         Ok(())
     }
 
+    /// A blank description removes it
+    pub fn modify_collection_description(&mut self, collection_index: usize, description: Option<String>) -> anyhow::Result<()> {
+        self.collections[collection_index].description = match description {
+            Some(description) if !description.trim().is_empty() => {
+                info!("Collection description set");
+                Some(description)
+            },
+            _ => {
+                info!("Collection description removed");
+                None
+            }
+        };
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    /// Shared by all the requests of the collection, no tags removes them
+    pub fn modify_collection_tags(&mut self, collection_index: usize, tags: Vec<String>) -> anyhow::Result<()> {
+        match tags.is_empty() {
+            true => info!("Collection tags removed"),
+            false => info!("Collection tags set to {}", tags.join(", "))
+        }
+
+        self.collections[collection_index].tags = tags;
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    /// Pause of the runs after each request that has none of its own
+    pub fn modify_collection_think_time(&mut self, collection_index: usize, think_time: Option<ThinkTime>) -> anyhow::Result<()> {
+        match &think_time {
+            Some(think_time) => info!("Collection think time set to {think_time}"),
+            None => info!("Collection think time removed")
+        }
+
+        self.collections[collection_index].think_time = think_time;
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    /// Request the runs send before or after the other ones
+    pub fn modify_collection_hook(&mut self, collection_index: usize, hook_kind: HookKind, hook_request: Option<String>) -> anyhow::Result<()> {
+        match &hook_request {
+            Some(hook_request) => info!("Collection {hook_kind} hook set to \"{hook_request}\""),
+            None => info!("Collection {hook_kind} hook removed")
+        }
+
+        self.collections[collection_index].hooks.set(hook_kind, hook_request);
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    /// Request re-sent to log in again when a request of the collection gets a 401
+    pub fn modify_collection_login_request(&mut self, collection_index: usize, login_request: Option<String>) -> anyhow::Result<()> {
+        match &login_request {
+            Some(login_request) => info!("Collection login request set to \"{login_request}\""),
+            None => info!("Collection login request removed")
+        }
+
+        self.collections[collection_index].login_request = login_request;
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
     pub fn rename_request(&mut self, collection_index: usize, request_index: usize, new_request_name: String) -> anyhow::Result<()> {
         if new_request_name.trim().is_empty() {
             return Err(anyhow!(RequestNameIsEmpty));
         }
 
         let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let previous_request_name = local_selected_request.read().name.clone();
 
         {
             let mut selected_request = local_selected_request.write();
 
             info!("Request renamed to \"{new_request_name}\"");
 
+            // Keeps designating the same login request
+            let collection = &mut self.collections[collection_index];
+
+            if collection.login_request.as_ref() == Some(&selected_request.name) {
+                collection.login_request = Some(new_request_name.to_string());
+            }
+
+            collection.hooks.rename_request(&selected_request.name, &new_request_name);
+
             selected_request.name = new_request_name.to_string();
         }
+
+        // The requests depending on it keep designating it
+        for request in &self.collections[collection_index].requests {
+            for dependency in request.write().depends_on.iter_mut() {
+                if *dependency == previous_request_name {
+                    *dependency = new_request_name.to_string();
+                }
+            }
+        }
         
         self.save_collection_to_file(collection_index);
         
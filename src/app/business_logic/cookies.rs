@@ -0,0 +1,290 @@
+use chrono::Utc;
+use cookie_store::{CookieDomain, RawCookie};
+use reqwest::Url;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::monitor::parse_interval;
+use crate::tui::utils::stateful::cookie_table::{CookieColumns, COOKIES_COLUMNS_NUMBER};
+
+#[derive(Error, Debug)]
+pub enum CookieError {
+    #[error("Invalid URL \"{0}\"")]
+    InvalidUrl(String),
+
+    #[error("Expected \"<URL> <name>=<value>; <attributes>\"")]
+    InvalidInput,
+
+    #[error("Could not parse cookie: {0}")]
+    InvalidCookie(String),
+
+    #[error("Invalid expiration \"{0}\", expected session or a duration like 30m, 1d")]
+    InvalidExpiration(String),
+
+    #[error("Invalid {0} \"{1}\", expected {2}")]
+    InvalidAttribute(String, String, String),
+
+    #[error("Cookie rejected: {0}")]
+    Rejected(String),
+
+    #[error("Line {0}: expected 7 tab separated fields (domain, subdomains, path, secure, expiration, name, value)")]
+    InvalidNetscapeLine(usize),
+}
+
+impl App<'_> {
+    /// Create a cookie from a URL followed by a Set-Cookie header value,
+    /// e.g. "https://example.com session=abc; Path=/; Max-Age=3600; HttpOnly"
+    pub fn create_cookie(&self, input: &str) -> Result<(), CookieError> {
+        let (url, set_cookie) = match input.trim().split_once(char::is_whitespace) {
+            Some((url, set_cookie)) if !set_cookie.trim().is_empty() => (url, set_cookie.trim()),
+            _ => return Err(CookieError::InvalidInput)
+        };
+
+        let url = match Url::parse(url) {
+            Ok(url) => url,
+            Err(_) => return Err(CookieError::InvalidUrl(url.to_string()))
+        };
+
+        let raw_cookie = match RawCookie::parse(set_cookie) {
+            Ok(raw_cookie) => raw_cookie,
+            Err(error) => return Err(CookieError::InvalidCookie(error.to_string()))
+        };
+
+        let mut cookie_store = self.cookies_popup.cookie_store.write().unwrap();
+
+        if let Err(error) = cookie_store.insert_raw(&raw_cookie, &url) {
+            return Err(CookieError::Rejected(error.to_string()));
+        }
+
+        info!("Cookie \"{}\" created", raw_cookie.name());
+
+        drop(cookie_store);
+        self.save_cookie_jar();
+
+        Ok(())
+    }
+
+    /// Import the cookies of a Netscape cookies.txt file, expired ones are skipped.
+    /// Returns the number of imported cookies
+    pub fn import_netscape_cookies(&self, content: &str) -> Result<usize, CookieError> {
+        let now = Utc::now().timestamp();
+        let mut cookies: Vec<(Url, String)> = vec![];
+
+        for (index, line) in content.lines().enumerate() {
+            // curl and most exporters prefix HttpOnly cookies this way
+            let (line, http_only) = match line.strip_prefix("#HttpOnly_") {
+                Some(line) => (line, true),
+                None => (line, false)
+            };
+
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let [domain, include_subdomains, path, secure, expires, name, value] = match line.trim_end_matches(['\r', '\n']).split('\t').collect::<Vec<&str>>()[..] {
+                [domain, include_subdomains, path, secure, expires, name, value] => [domain, include_subdomains, path, secure, expires, name, value],
+                _ => return Err(CookieError::InvalidNetscapeLine(index + 1))
+            };
+
+            let expires = match expires.parse::<i64>() {
+                Ok(expires) => expires,
+                Err(_) => return Err(CookieError::InvalidNetscapeLine(index + 1))
+            };
+
+            let secure = secure.eq_ignore_ascii_case("true");
+            let host = domain.trim_start_matches('.');
+
+            let scheme = match secure {
+                true => "https",
+                false => "http"
+            };
+
+            let url = match Url::parse(&format!("{scheme}://{host}{path}")) {
+                Ok(url) => url,
+                Err(_) => return Err(CookieError::InvalidUrl(format!("{scheme}://{host}{path}")))
+            };
+
+            let mut set_cookie = format!("{name}={value}; Path={path}");
+
+            if include_subdomains.eq_ignore_ascii_case("true") {
+                set_cookie += &format!("; Domain={host}");
+            }
+
+            // 0 stands for a session cookie
+            if expires != 0 {
+                if expires <= now {
+                    continue;
+                }
+
+                set_cookie += &format!("; Max-Age={}", expires - now);
+            }
+
+            if secure {
+                set_cookie += "; Secure";
+            }
+
+            if http_only {
+                set_cookie += "; HttpOnly";
+            }
+
+            cookies.push((url, set_cookie));
+        }
+
+        let mut cookie_store = self.cookies_popup.cookie_store.write().unwrap();
+
+        for (url, set_cookie) in &cookies {
+            let raw_cookie = match RawCookie::parse(set_cookie.as_str()) {
+                Ok(raw_cookie) => raw_cookie,
+                Err(error) => return Err(CookieError::InvalidCookie(error.to_string()))
+            };
+
+            if let Err(error) = cookie_store.insert_raw(&raw_cookie, url) {
+                return Err(CookieError::Rejected(format!("{} ({error})", raw_cookie.name())));
+            }
+        }
+
+        info!("{} cookie(s) imported", cookies.len());
+
+        drop(cookie_store);
+        self.save_cookie_jar();
+
+        return Ok(cookies.len());
+    }
+
+    /// Replace the cookie described by the previous row with the one described by the new row
+    pub fn modify_cookie(&self, previous_row: &[String; COOKIES_COLUMNS_NUMBER], new_row: &[String; COOKIES_COLUMNS_NUMBER]) -> Result<(), CookieError> {
+        let [domain, name, value, path, expires, http_only, secure, same_site] = new_row;
+
+        let http_only = parse_flag(CookieColumns::HttpOnly, http_only)?;
+        let secure = parse_flag(CookieColumns::Secure, secure)?;
+
+        let path = match path.starts_with('/') {
+            true => path.clone(),
+            false => format!("/{path}")
+        };
+
+        let scheme = match secure {
+            true => "https",
+            false => "http"
+        };
+
+        let url = match Url::parse(&format!("{scheme}://{domain}{path}")) {
+            Ok(url) => url,
+            Err(_) => return Err(CookieError::InvalidUrl(format!("{scheme}://{domain}{path}")))
+        };
+
+        let mut set_cookie = format!("{name}={value}; Path={path}");
+
+        if http_only {
+            set_cookie += "; HttpOnly";
+        }
+
+        if secure {
+            set_cookie += "; Secure";
+        }
+
+        match same_site.to_lowercase().as_str() {
+            "" => {},
+            "strict" | "lax" | "none" => set_cookie += &format!("; SameSite={same_site}"),
+            _ => return Err(CookieError::InvalidAttribute(CookieColumns::SameSite.to_string(), same_site.clone(), String::from("Strict, Lax or None")))
+        }
+
+        let is_expiration_modified = expires != &previous_row[CookieColumns::Expires as usize];
+
+        if is_expiration_modified && expires != "session" && !expires.is_empty() {
+            match parse_interval(expires) {
+                Ok(max_age) => set_cookie += &format!("; Max-Age={}", max_age.as_secs()),
+                Err(_) => return Err(CookieError::InvalidExpiration(expires.clone()))
+            }
+        }
+
+        let mut cookie_store = self.cookies_popup.cookie_store.write().unwrap();
+
+        let previous_cookie = cookie_store
+            .remove(&previous_row[CookieColumns::Domain as usize], &previous_row[CookieColumns::Path as usize], &previous_row[CookieColumns::Name as usize]);
+
+        // Cookies that were valid for the subdomains stay so
+        if let Some(CookieDomain::Suffix(_)) = previous_cookie.as_ref().map(|previous_cookie| &previous_cookie.domain) {
+            set_cookie += &format!("; Domain={domain}");
+        }
+
+        let result = match RawCookie::parse(set_cookie) {
+            Ok(mut raw_cookie) => {
+                if !is_expiration_modified {
+                    if let Some(previous_cookie) = &previous_cookie {
+                        raw_cookie.set_expires(previous_cookie.expires_datetime());
+                    }
+                }
+
+                cookie_store
+                    .insert_raw(&raw_cookie, &url)
+                    .map(|_| ())
+                    .map_err(|error| CookieError::Rejected(error.to_string()))
+            },
+            Err(error) => Err(CookieError::InvalidCookie(error.to_string()))
+        };
+
+        match result {
+            Ok(_) => {
+                info!("Cookie \"{name}\" modified");
+
+                drop(cookie_store);
+                self.save_cookie_jar();
+            },
+            Err(_) => {
+                // Puts the previous cookie back
+                if let Some(previous_cookie) = previous_cookie {
+                    let previous_url = format!("{scheme}://{}{}", &previous_row[CookieColumns::Domain as usize], &previous_row[CookieColumns::Path as usize]);
+
+                    if let Ok(previous_url) = Url::parse(&previous_url) {
+                        let _ = cookie_store.insert(previous_cookie, &previous_url);
+                    }
+                }
+            }
+        }
+
+        return result;
+    }
+
+    pub fn delete_cookie(&self, domain: &str, path: &str, name: &str) {
+        let mut cookie_store = self.cookies_popup.cookie_store.write().unwrap();
+
+        if cookie_store.remove(domain, path, name).is_some() {
+            info!("Cookie \"{name}\" deleted");
+
+            drop(cookie_store);
+            self.save_cookie_jar();
+        }
+    }
+
+    /// Delete every cookie set for the domain, returns the number of deleted cookies
+    pub fn delete_domain_cookies(&self, domain: &str) -> usize {
+        let mut cookie_store = self.cookies_popup.cookie_store.write().unwrap();
+
+        let cookies_to_delete: Vec<(String, String)> = cookie_store
+            .iter_any()
+            .filter(|cookie| String::from(&cookie.domain) == domain)
+            .map(|cookie| (String::from(&cookie.path), cookie.name().to_string()))
+            .collect();
+
+        for (path, name) in &cookies_to_delete {
+            cookie_store.remove(domain, path, name);
+        }
+
+        info!("{} cookie(s) deleted for domain \"{domain}\"", cookies_to_delete.len());
+
+        drop(cookie_store);
+        self.save_cookie_jar();
+
+        return cookies_to_delete.len();
+    }
+}
+
+fn parse_flag(column: CookieColumns, value: &str) -> Result<bool, CookieError> {
+    match value.to_lowercase().as_str() {
+        "true" => Ok(true),
+        "false" | "" => Ok(false),
+        _ => Err(CookieError::InvalidAttribute(column.to_string().replace('\n', " "), value.to_string(), String::from("true or false")))
+    }
+}
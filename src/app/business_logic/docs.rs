@@ -0,0 +1,187 @@
+use pulldown_cmark::{html, Options, Parser};
+
+use crate::models::auth::Auth;
+use crate::models::body::{ContentType, MultipartPart};
+use crate::models::collection::Collection;
+use crate::models::request::{KeyValue, Request};
+
+/// Render a whole collection as Markdown, one section per request
+pub fn collection_to_markdown(collection: &Collection) -> String {
+    let mut markdown = format!("# {}\n\n", collection.name);
+
+    if let Some(description) = &collection.description {
+        markdown += &format!("{}\n\n", description.trim_end());
+    }
+
+    if !collection.requests.is_empty() {
+        markdown += "## Requests\n\n";
+
+        for request in &collection.requests {
+            let request = request.read();
+            markdown += &format!("- `{}` {}\n", request.method, request.name);
+        }
+
+        markdown += "\n";
+    }
+
+    for request in &collection.requests {
+        markdown += &request_to_markdown(&request.read());
+    }
+
+    return markdown;
+}
+
+fn request_to_markdown(request: &Request) -> String {
+    let mut markdown = format!("## {}\n\n```\n{} {}\n```\n\n", request.name, request.method, request.url);
+
+    if let Some(description) = &request.description {
+        markdown += &format!("{}\n\n", description.trim_end());
+    }
+
+    /* QUERY PARAMS */
+
+    if !request.params.is_empty() {
+        markdown += &format!("### Query params\n\n{}\n", key_value_table(&request.params));
+    }
+
+    /* AUTH */
+
+    // Credentials are left out, the documentation is meant to be shared
+    match &request.auth {
+        Auth::NoAuth => {},
+        Auth::BasicAuth { .. } => markdown += "### Auth\n\nBasic auth\n\n",
+        Auth::BearerToken { .. } => markdown += "### Auth\n\nBearer token\n\n",
+        Auth::HmacSignature { string_to_sign, header, algorithm, encoding, .. } => markdown += &format!("### Auth\n\n{algorithm} signature ({encoding}) of `{string_to_sign}` in the `{header}` header\n\n"),
+        Auth::Ntlm { .. } => markdown += "### Auth\n\nNTLM\n\n",
+        Auth::OAuth1 { signature_method, placement, .. } => markdown += &format!("### Auth\n\nOAuth 1.0a {signature_method} signature, sent in the {}\n\n", placement.to_string().to_lowercase()),
+        Auth::Plugin { plugin, .. } => markdown += &format!("### Auth\n\nComputed by the `{plugin}` plugin\n\n"),
+    }
+
+    /* HEADERS */
+
+    if !request.headers.is_empty() {
+        markdown += &format!("### Headers\n\n{}\n", key_value_table(&request.headers));
+    }
+
+    /* COOKIES */
+
+    if !request.cookies.is_empty() {
+        markdown += &format!("### Cookies\n\n{}\n", key_value_table(&request.cookies));
+    }
+
+    /* BODY */
+
+    match &request.body {
+        ContentType::NoBody => {},
+        ContentType::File(body_file) => markdown += &format!("### Body\n\n`{}`\n\nFile `{}`\n\n", request.body.to_content_type(), body_file.path),
+        ContentType::Multipart(parts) => {
+            markdown += &format!("### Body\n\n`{}`\n\n{}\n", request.body.to_content_type(), multipart_table(parts));
+        },
+        ContentType::Form(form) => {
+            markdown += &format!("### Body\n\n`{}`\n\n{}\n", request.body.to_content_type(), key_value_table(form));
+        },
+        ContentType::Raw(body) => markdown += &format!("### Body\n\n{}\n", code_block("text", body)),
+        ContentType::Json(body) => markdown += &format!("### Body\n\n{}\n", code_block("json", body)),
+        ContentType::Xml(body) => markdown += &format!("### Body\n\n{}\n", code_block("xml", body)),
+        ContentType::Html(body) => markdown += &format!("### Body\n\n{}\n", code_block("html", body)),
+        ContentType::Javascript(body) => markdown += &format!("### Body\n\n{}\n", code_block("javascript", body)),
+        ContentType::MessagePack(body) | ContentType::Cbor(body) => markdown += &format!("### Body\n\n`{}`, as JSON\n\n{}\n", request.body.to_content_type(), code_block("json", body)),
+        ContentType::Protobuf(body) => markdown += &format!("### Body\n\n`{}`, as JSON\n\n{}\n", request.body.to_content_type(), code_block("json", body)),
+    }
+
+    /* EXAMPLE RESPONSE */
+
+    if let Some(body) = request.snapshot.as_ref().and_then(|snapshot| snapshot.body.as_ref()) {
+        let language = match serde_json::from_str::<serde_json::Value>(body) {
+            Ok(_) => "json",
+            Err(_) => "text"
+        };
+
+        markdown += &format!("### Example response\n\n{}\n", code_block(language, body));
+    }
+
+    return markdown;
+}
+
+fn key_value_table(key_values: &Vec<KeyValue>) -> String {
+    let mut table = String::from("| Name | Value | Enabled |\n|------|-------|---------|\n");
+
+    for key_value in key_values {
+        table += &format!(
+            "| {} | {} | {} |\n",
+            escape_table_cell(&key_value.data.0),
+            escape_table_cell(&key_value.data.1),
+            key_value.enabled
+        );
+    }
+
+    return table;
+}
+
+fn multipart_table(parts: &Vec<MultipartPart>) -> String {
+    let mut table = String::from("| Name | Value | Source | Content-Type | File name | Enabled |\n|------|-------|--------|--------------|-----------|---------|\n");
+
+    for part in parts {
+        table += &format!(
+            "| {} | {} | {} | {} | {} | {} |\n",
+            escape_table_cell(&part.name),
+            escape_table_cell(&part.value),
+            part.source,
+            escape_table_cell(part.content_type.as_deref().unwrap_or("")),
+            escape_table_cell(part.file_name.as_deref().unwrap_or("")),
+            part.enabled
+        );
+    }
+
+    return table;
+}
+
+fn escape_table_cell(cell: &str) -> String {
+    return cell.replace('|', "\\|").replace('\n', " ");
+}
+
+/// Fenced code block whose fence is longer than any backtick run in the code
+fn code_block(language: &str, code: &str) -> String {
+    let mut fence = String::from("```");
+
+    while code.contains(&fence) {
+        fence.push('`');
+    }
+
+    return format!("{fence}{language}\n{}\n{fence}\n", code.trim_end());
+}
+
+/// Render Markdown into a standalone HTML page
+pub fn markdown_to_html_page(title: &str, markdown: &str) -> String {
+    let options = Options::ENABLE_TABLES | Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+    let parser = Parser::new_ext(markdown, options);
+
+    let mut body = String::new();
+    html::push_html(&mut body, parser);
+
+    let escaped_title = title
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;");
+
+    return format!(r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta name="generator" content="ATAC v{}">
+<title>{escaped_title}</title>
+<style>
+body {{ max-width: 960px; margin: 2em auto; padding: 0 1em; font-family: sans-serif; line-height: 1.5; color: #222; }}
+h2 {{ margin-top: 2em; border-bottom: 1px solid #ddd; }}
+pre {{ background: #f5f5f5; padding: 1em; overflow-x: auto; }}
+code {{ font-family: monospace; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #ddd; padding: 0.3em 0.8em; text-align: left; }}
+</style>
+</head>
+<body>
+{body}</body>
+</html>
+"#, env!("CARGO_PKG_VERSION"));
+}
@@ -0,0 +1,108 @@
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD};
+use md5::{Digest, Md5};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, percent_decode_str, utf8_percent_encode};
+use ring::{digest, hmac};
+use strum::{Display, EnumIter, FromRepr};
+use thiserror::Error;
+
+use crate::app::business_logic::encoding::EncodingError::{InvalidBase64, InvalidUrlEncoding, NotUtf8};
+
+/// Unreserved characters of RFC 3986 are kept as is
+pub const URL_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'_').remove(b'.').remove(b'~');
+/// Characters kept as is in an application/x-www-form-urlencoded body
+const FORM_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'*').remove(b'-').remove(b'.').remove(b'_');
+
+#[derive(Error, Debug)]
+pub enum EncodingError {
+    #[error("Invalid base64\n\t{0}")]
+    InvalidBase64(String),
+    #[error("Invalid URL encoding\n\t{0}")]
+    InvalidUrlEncoding(String),
+    #[error("The decoded bytes are not UTF-8 text")]
+    NotUtf8,
+}
+
+#[derive(Default, Clone, Copy, PartialEq, Display, FromRepr, EnumIter)]
+pub enum EncodingTool {
+    #[default]
+    #[strum(to_string = "Base64 encode")]
+    Base64Encode,
+    #[strum(to_string = "Base64 decode")]
+    Base64Decode,
+    #[strum(to_string = "URL encode")]
+    UrlEncode,
+    #[strum(to_string = "URL decode")]
+    UrlDecode,
+    #[strum(to_string = "MD5")]
+    Md5,
+    #[strum(to_string = "SHA-1")]
+    Sha1,
+    #[strum(to_string = "SHA-256")]
+    Sha256,
+    #[strum(to_string = "HMAC-SHA256")]
+    HmacSha256,
+}
+
+impl EncodingTool {
+    pub fn needs_secret(&self) -> bool {
+        return *self == EncodingTool::HmacSha256;
+    }
+
+    /// Hashes and HMAC are given in lowercase hexadecimal
+    pub fn apply(&self, input: &str, secret: &str) -> anyhow::Result<String> {
+        let output = match self {
+            EncodingTool::Base64Encode => STANDARD.encode(input),
+            EncodingTool::Base64Decode => {
+                // Also accepts the URL-safe alphabet and missing padding
+                let input: String = input
+                    .trim()
+                    .trim_end_matches('=')
+                    .replace('-', "+")
+                    .replace('_', "/");
+
+                let bytes = STANDARD_NO_PAD.decode(input)
+                    .map_err(|error| anyhow!(InvalidBase64(error.to_string())))?;
+
+                String::from_utf8(bytes).map_err(|_| anyhow!(NotUtf8))?
+            },
+            EncodingTool::UrlEncode => utf8_percent_encode(input, URL_ENCODE_SET).to_string(),
+            EncodingTool::UrlDecode => percent_decode_str(&input.replace('+', " "))
+                .decode_utf8()
+                .map_err(|error| anyhow!(InvalidUrlEncoding(error.to_string())))?
+                .to_string(),
+            EncodingTool::Md5 => to_hex(&Md5::digest(input.as_bytes())),
+            EncodingTool::Sha1 => to_hex(digest::digest(&digest::SHA1_FOR_LEGACY_USE_ONLY, input.as_bytes()).as_ref()),
+            EncodingTool::Sha256 => to_hex(digest::digest(&digest::SHA256, input.as_bytes()).as_ref()),
+            EncodingTool::HmacSha256 => {
+                let key = hmac::Key::new(hmac::HMAC_SHA256, secret.as_bytes());
+
+                to_hex(hmac::sign(&key, input.as_bytes()).as_ref())
+            }
+        };
+
+        return Ok(output);
+    }
+}
+
+/// Same encoding as the sent form body, "=" and "&" in the keys and values are escaped
+pub fn form_url_encode(pairs: &[(String, String)]) -> String {
+    return pairs
+        .iter()
+        .map(|(key, value)| format!("{}={}", form_url_encode_component(key), form_url_encode_component(value)))
+        .collect::<Vec<String>>()
+        .join("&");
+}
+
+/// Spaces become "+", a literal "+" being escaped
+pub fn form_url_encode_component(text: &str) -> String {
+    return utf8_percent_encode(text, FORM_ENCODE_SET).to_string().replace("%20", "+");
+}
+
+pub fn to_hex(bytes: &[u8]) -> String {
+    return bytes
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+}
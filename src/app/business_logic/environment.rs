@@ -2,14 +2,30 @@ use std::sync::Arc;
 use rayon::prelude::*;
 use anyhow::anyhow;
 use chrono::Utc;
+use lazy_static::lazy_static;
 use parking_lot::RwLock;
+use regex::Regex;
 use thiserror::Error;
 use tracing::{info, trace};
 use uuid::Uuid;
 
 use crate::app::app::App;
-use crate::app::business_logic::environment::EnvironmentError::{EnvironmentNotFound, KeyAlreadyExists, KeyNotFound};
+use crate::cli::args::ARGS;
+use crate::app::business_logic::environment::EnvironmentError::{EnvironmentNotFound, EnvironmentPathAlreadyExists, KeyAlreadyExists, KeyNotFound};
+use crate::app::files::encryption::{is_encrypted_file, toggle_encrypted_file_path};
+use crate::app::files::environment::delete_environment_file;
+use crate::models::auth::Auth;
+use crate::models::body::ContentType;
+use crate::models::dataset::DataRow;
 use crate::models::environment::Environment;
+use crate::models::request::{KeyValue, Request};
+
+/// Keys replaced whatever the selected environment
+pub const BUILTIN_ENV_KEYS: [&str; 4] = ["NOW", "TIMESTAMP", "UUIDv4", "UUIDv7"];
+
+lazy_static! {
+    pub static ref ENV_KEY_REGEX: Regex = Regex::new(r"\{\{(\w+)}}").unwrap();
+}
 
 #[derive(Error, Debug)]
 pub enum EnvironmentError {
@@ -21,6 +37,9 @@ pub enum EnvironmentError {
 
     #[error("Key already exists")]
     KeyAlreadyExists,
+
+    #[error("\"{0}\" already exists")]
+    EnvironmentPathAlreadyExists(String),
 }
 
 impl App<'_> {
@@ -152,16 +171,80 @@ impl App<'_> {
         Ok(())
     }
 
-    pub fn replace_env_keys_by_value(&self, input: &String) -> String {
-        if self.environments.is_empty() {
-            return input.to_string();
+    /// Move the environment to an encrypted file (".env.my_env.age"), or back to a plain file
+    pub fn modify_environment_encryption(&mut self, env_index: usize, encrypted: bool) -> anyhow::Result<()> {
+        let local_env = self.get_env_as_local_from_index(env_index).unwrap();
+        let previous_path = local_env.read().path.clone();
+
+        if is_encrypted_file(&previous_path) == encrypted {
+            return Ok(());
+        }
+
+        let new_path = toggle_encrypted_file_path(&previous_path);
+
+        if new_path.exists() {
+            return Err(anyhow!(EnvironmentPathAlreadyExists(new_path.display().to_string())));
+        }
+
+        match encrypted {
+            true => info!("Environment encrypted"),
+            false => info!("Environment decrypted")
+        }
+
+        local_env.write().path = new_path;
+
+        self.save_environment_to_file(env_index);
+        delete_environment_file(&previous_path);
+
+        Ok(())
+    }
+
+    /// Values of the selected environment, of the data row and of the --var options
+    pub fn get_variable_resolver(&self) -> VariableResolver<'_> {
+        VariableResolver {
+            variables: &ARGS.variables,
+            data_row: self.data_row.as_ref(),
+            environment: self.get_selected_env_as_local(),
         }
+    }
+
+    pub fn replace_env_keys_by_value(&self, input: &String) -> String {
+        return self.get_variable_resolver().replace_env_keys_by_value(input);
+    }
+
+    pub fn is_env_key_resolved(&self, key: &str) -> bool {
+        return self.get_variable_resolver().is_env_key_resolved(key);
+    }
+
+    pub fn get_unresolved_env_keys(&self, input: &str) -> Vec<String> {
+        return self.get_variable_resolver().get_unresolved_env_keys(input);
+    }
+}
 
+/// Replaces the "{{key}}" placeholders, the --var values prevailing over the data row ones, which prevail over the environment ones.
+/// The last --var value of a repeated key prevails.
+/// Holds no app state so that the requests can be prepared outside of the app, e.g. by the library engine
+pub struct VariableResolver<'a> {
+    pub variables: &'a [(String, String)],
+    pub data_row: Option<&'a DataRow>,
+    pub environment: Option<Arc<RwLock<Environment>>>,
+}
+
+impl VariableResolver<'_> {
+    pub fn replace_env_keys_by_value(&self, input: &String) -> String {
         let mut tmp_string = input.to_string();
 
-        let local_env = self.get_selected_env_as_local();
+        for (key, value) in self.variables.iter().rev() {
+            tmp_string = tmp_string.replace(&format!("{{{{{}}}}}", key), value);
+        }
 
-        if let Some(local_env) = local_env {
+        if let Some(data_row) = self.data_row {
+            for (key, value) in data_row {
+                tmp_string = tmp_string.replace(&format!("{{{{{}}}}}", key), value);
+            }
+        }
+
+        if let Some(local_env) = &self.environment {
             let env = local_env.read();
 
             for (key, value) in &env.values {
@@ -177,4 +260,148 @@ impl App<'_> {
         
         return tmp_string;
     }
-}
\ No newline at end of file
+
+    /// The key is set with --var, by the data row, the selected environment or is a builtin key
+    pub fn is_env_key_resolved(&self, key: &str) -> bool {
+        if BUILTIN_ENV_KEYS.contains(&key) {
+            return true;
+        }
+
+        if self.variables.iter().any(|(variable_key, _)| variable_key == key) {
+            return true;
+        }
+
+        if self.data_row.is_some_and(|data_row| data_row.contains_key(key)) {
+            return true;
+        }
+
+        match &self.environment {
+            None => false,
+            Some(local_env) => local_env.read().values.contains_key(key)
+        }
+    }
+
+    /// "{{key}}" placeholders of the URL, params, auth, headers, cookies and body that would be sent literally
+    pub fn get_request_unresolved_env_keys(&self, request: &Request) -> Vec<String> {
+        let mut unresolved_keys: Vec<String> = vec![];
+
+        for (_, input) in get_request_fields(request, false) {
+            for key in self.get_unresolved_env_keys(input) {
+                if !unresolved_keys.contains(&key) {
+                    unresolved_keys.push(key);
+                }
+            }
+        }
+
+        return unresolved_keys;
+    }
+
+    pub fn get_unresolved_env_keys(&self, input: &str) -> Vec<String> {
+        return ENV_KEY_REGEX
+            .captures_iter(input)
+            .map(|capture| capture[1].to_string())
+            .filter(|key| !self.is_env_key_resolved(key))
+            .collect();
+    }
+
+    /// Enabled key values, with their placeholders replaced
+    pub fn key_value_vec_to_tuple_vec(&self, key_value: &Vec<KeyValue>) -> Vec<(String, String)> {
+        key_value
+            .par_iter()
+            .filter_map(|param| {
+                if param.enabled {
+                    let key = self.replace_env_keys_by_value(&param.data.0);
+                    let value = self.replace_env_keys_by_value(&param.data.1);
+
+                    Some((key, value))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Fields of a request that may contain "{{key}}" placeholders, with their name
+pub fn get_request_fields(request: &Request, include_disabled: bool) -> Vec<(String, &String)> {
+    let mut fields: Vec<(String, &String)> = vec![(String::from("URL"), &request.url)];
+
+    let key_values = [
+        ("Query param", &request.params),
+        ("Header", &request.headers),
+        ("Cookie", &request.cookies),
+    ];
+
+    for (field_name, key_values) in key_values {
+        for key_value in key_values {
+            if key_value.enabled || include_disabled {
+                fields.push((format!("{field_name} \"{}\"", key_value.data.0), &key_value.data.0));
+                fields.push((format!("{field_name} \"{}\"", key_value.data.0), &key_value.data.1));
+            }
+        }
+    }
+
+    match &request.auth {
+        Auth::NoAuth => {},
+        Auth::BasicAuth { username, password } => {
+            fields.push((String::from("Basic auth username"), username));
+            fields.push((String::from("Basic auth password"), password));
+        },
+        Auth::BearerToken { token } => fields.push((String::from("Bearer token"), token)),
+        Auth::HmacSignature { secret, string_to_sign, header, .. } => {
+            fields.push((String::from("HMAC secret"), secret));
+            fields.push((String::from("HMAC string to sign"), string_to_sign));
+            fields.push((String::from("HMAC header"), header));
+        },
+        Auth::Ntlm { username, password, domain } => {
+            fields.push((String::from("NTLM username"), username));
+            fields.push((String::from("NTLM password"), password));
+            fields.push((String::from("NTLM domain"), domain));
+        },
+        Auth::OAuth1 { consumer_key, consumer_secret, token, token_secret, .. } => {
+            fields.push((String::from("OAuth consumer key"), consumer_key));
+            fields.push((String::from("OAuth consumer secret"), consumer_secret));
+            fields.push((String::from("OAuth token"), token));
+            fields.push((String::from("OAuth token secret"), token_secret));
+        },
+        Auth::Plugin { config, .. } => fields.push((String::from("Plugin auth config"), config))
+    }
+
+    match &request.body {
+        ContentType::NoBody => {},
+        ContentType::Multipart(parts) => {
+            for part in parts {
+                if part.enabled || include_disabled {
+                    fields.push((format!("Multipart part \"{}\"", part.name), &part.name));
+                    fields.push((format!("Multipart part \"{}\"", part.name), &part.value));
+
+                    if let Some(content_type) = &part.content_type {
+                        fields.push((format!("Multipart part \"{}\" content type", part.name), content_type));
+                    }
+
+                    if let Some(file_name) = &part.file_name {
+                        fields.push((format!("Multipart part \"{}\" file name", part.name), file_name));
+                    }
+                }
+            }
+        },
+        ContentType::Form(form_data) => {
+            for key_value in form_data {
+                if key_value.enabled || include_disabled {
+                    fields.push((format!("Form data \"{}\"", key_value.data.0), &key_value.data.0));
+                    fields.push((format!("Form data \"{}\"", key_value.data.0), &key_value.data.1));
+                }
+            }
+        },
+        ContentType::File(body_file) => {
+            fields.push((String::from("Body file"), &body_file.path));
+
+            if let Some(content_type) = &body_file.content_type {
+                fields.push((String::from("Body file content type"), content_type));
+            }
+        },
+        ContentType::Raw(body) | ContentType::Json(body) | ContentType::Xml(body) | ContentType::Html(body) | ContentType::Javascript(body) | ContentType::Protobuf(body) | ContentType::MessagePack(body) | ContentType::Cbor(body) => fields.push((String::from("Body"), body))
+    }
+
+    return fields;
+}
@@ -0,0 +1,159 @@
+use serde_json::Value;
+
+use crate::models::body::{ContentType, MultipartPart, PartSource};
+use crate::models::fuzz::{FuzzGenerator, FuzzTarget};
+use crate::models::request::{KeyValue, Request};
+
+/// A copy of the request where one location holds a generated payload
+pub struct FuzzCase {
+    /// e.g. "param id", "header Authorization", "body name"
+    pub location: String,
+    pub generator: FuzzGenerator,
+    pub payload: String,
+    pub request: Request,
+}
+
+/// Build one mutated request per (location, payload).
+/// Only the locations whose name is in `fields` are mutated, unless `fields` is empty.
+pub fn generate_fuzz_cases(request: &Request, targets: &[FuzzTarget], generators: &[FuzzGenerator], fields: &[String]) -> Vec<FuzzCase> {
+    let mut fuzz_cases: Vec<FuzzCase> = vec![];
+
+    let is_selected = |name: &str| fields.is_empty() || fields.iter().any(|field| field == name);
+
+    for generator in generators {
+        for payload in generator.payloads() {
+            let mut push_case = |location: String, mutated_request: Request| {
+                fuzz_cases.push(FuzzCase {
+                    location,
+                    generator: *generator,
+                    payload: payload.clone(),
+                    request: mutated_request,
+                });
+            };
+
+            for target in targets {
+                match target {
+                    FuzzTarget::Params => for (index, param) in request.params.iter().enumerate() {
+                        if param.enabled && is_selected(&param.data.0) {
+                            let mut mutated_request = request.clone();
+                            mutated_request.params[index].data.1 = payload.clone();
+                            push_case(format!("param {}", param.data.0), mutated_request);
+                        }
+                    },
+                    FuzzTarget::Headers => for (index, header) in request.headers.iter().enumerate() {
+                        if header.enabled && is_selected(&header.data.0) {
+                            let mut mutated_request = request.clone();
+                            mutated_request.headers[index].data.1 = payload.clone();
+                            push_case(format!("header {}", header.data.0), mutated_request);
+                        }
+                    },
+                    FuzzTarget::Body => for (location, body) in mutate_body(&request.body, &payload, &is_selected) {
+                        let mut mutated_request = request.clone();
+                        mutated_request.body = body;
+                        push_case(location, mutated_request);
+                    }
+                }
+            }
+        }
+    }
+
+    return fuzz_cases;
+}
+
+fn mutate_body(body: &ContentType, payload: &str, is_selected: &dyn Fn(&str) -> bool) -> Vec<(String, ContentType)> {
+    match body {
+        // Payloads would seldom fit the types of the protobuf message fields
+        ContentType::NoBody | ContentType::File(_) | ContentType::Protobuf(_) => vec![],
+        ContentType::Form(form) => mutate_key_values(form, payload, is_selected)
+            .into_iter()
+            .map(|(location, form)| (location, ContentType::Form(form)))
+            .collect(),
+        ContentType::Multipart(parts) => mutate_multipart_parts(parts, payload, is_selected)
+            .into_iter()
+            .map(|(location, parts)| (location, ContentType::Multipart(parts)))
+            .collect(),
+        ContentType::Json(json) | ContentType::MessagePack(json) | ContentType::Cbor(json) => {
+            // The mutated documents keep the body type
+            let with_json = |json: String| match body {
+                ContentType::MessagePack(_) => ContentType::MessagePack(json),
+                ContentType::Cbor(_) => ContentType::Cbor(json),
+                _ => ContentType::Json(json)
+            };
+
+            match serde_json::from_str::<Value>(json) {
+                Ok(Value::Object(object)) => {
+                    // Numbers, booleans, null and objects are inserted as JSON, everything else as a string
+                    let payload_value = match serde_json::from_str::<Value>(payload) {
+                        Ok(Value::String(_)) | Err(_) => Value::String(payload.to_string()),
+                        Ok(value) => value
+                    };
+
+                    object
+                        .keys()
+                        .filter(|key| is_selected(key))
+                        .map(|key| {
+                            let mut mutated_object = object.clone();
+                            mutated_object.insert(key.clone(), payload_value.clone());
+
+                            (format!("body {key}"), with_json(Value::Object(mutated_object).to_string()))
+                        })
+                        .collect()
+                },
+                _ => match is_selected("body") {
+                    true => vec![(String::from("body"), with_json(payload.to_string()))],
+                    false => vec![]
+                }
+            }
+        },
+        ContentType::Raw(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) => match is_selected("body") {
+            true => {
+                let mutated_body = match body {
+                    ContentType::Xml(_) => ContentType::Xml(payload.to_string()),
+                    ContentType::Html(_) => ContentType::Html(payload.to_string()),
+                    ContentType::Javascript(_) => ContentType::Javascript(payload.to_string()),
+                    _ => ContentType::Raw(payload.to_string())
+                };
+
+                vec![(String::from("body"), mutated_body)]
+            },
+            false => vec![]
+        }
+    }
+}
+
+fn mutate_key_values(key_values: &[KeyValue], payload: &str, is_selected: &dyn Fn(&str) -> bool) -> Vec<(String, Vec<KeyValue>)> {
+    let mut mutations: Vec<(String, Vec<KeyValue>)> = vec![];
+
+    for (index, key_value) in key_values.iter().enumerate() {
+        if key_value.enabled && is_selected(&key_value.data.0) {
+            let mut mutated_key_values = key_values.to_vec();
+            mutated_key_values[index].data.1 = payload.to_string();
+            mutations.push((format!("body {}", key_value.data.0), mutated_key_values));
+        }
+    }
+
+    return mutations;
+}
+
+/// Send a fuzzed request and return its status, or "No response" when the request failed
+pub async fn send_fuzz_case(prepared_request: reqwest_middleware::RequestBuilder) -> String {
+    match prepared_request.send().await {
+        Ok(response) => response.status().to_string(),
+        Err(_) => String::from("No response")
+    }
+}
+
+/// Only the text parts are mutated, a payload is not a file path
+fn mutate_multipart_parts(parts: &[MultipartPart], payload: &str, is_selected: &dyn Fn(&str) -> bool) -> Vec<(String, Vec<MultipartPart>)> {
+    let mut mutations: Vec<(String, Vec<MultipartPart>)> = vec![];
+
+    for (index, part) in parts.iter().enumerate() {
+        if part.enabled && part.source == PartSource::Text && is_selected(&part.name) {
+            let mut mutated_parts = parts.to_vec();
+            mutated_parts[index].value = payload.to_string();
+            mutations.push((format!("body {}", part.name), mutated_parts));
+        }
+    }
+
+    return mutations;
+}
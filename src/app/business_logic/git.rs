@@ -0,0 +1,86 @@
+use std::path::Path;
+use std::process::{Command, Stdio};
+
+use anyhow::anyhow;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::business_logic::git::GitError::{CouldNotRunGit, GitCommandFailed};
+
+#[derive(Error, Debug)]
+pub enum GitError {
+    #[error("Could not run git\n\t{0}")]
+    CouldNotRunGit(String),
+    #[error("git {0} failed\n\t{1}")]
+    GitCommandFailed(String, String),
+}
+
+/// Branch and changed files of the directory, e.g. the collections and environments of the workspace
+pub fn git_status(directory: &Path) -> anyhow::Result<String> {
+    return run_git_captured(directory, &["status", "--short", "--branch", "--", "."]);
+}
+
+/// Run git without any terminal interaction and return what it printed
+pub fn run_git_captured(directory: &Path, args: &[&str]) -> anyhow::Result<String> {
+    info!("Running git {}", args.join(" "));
+
+    let output = Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .env("GIT_TERMINAL_PROMPT", "0")
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|error| anyhow!(CouldNotRunGit(error.to_string())))?;
+
+    let printed = format!("{}{}", String::from_utf8_lossy(&output.stdout), String::from_utf8_lossy(&output.stderr));
+
+    match output.status.success() {
+        true => Ok(printed.trim_end().to_string()),
+        false => Err(anyhow!(GitCommandFailed(args[0].to_string(), printed.trim_end().to_string())))
+    }
+}
+
+/// Run git attached to the terminal, so that it can ask for credentials or open an editor
+pub fn run_git_interactive(directory: &Path, args: &[&str]) -> anyhow::Result<()> {
+    info!("Running git {}", args.join(" "));
+
+    let status = Command::new("git")
+        .args(args)
+        .current_dir(directory)
+        .status()
+        .map_err(|error| anyhow!(CouldNotRunGit(error.to_string())))?;
+
+    match status.success() {
+        true => Ok(()),
+        false => Err(anyhow!(GitCommandFailed(args[0].to_string(), status.to_string())))
+    }
+}
+
+/// Files of the workspace which are never committed, e.g. the cookie jars holding the session cookies in plaintext.
+/// The encrypted versions are matched as well
+const PRIVATE_FILES_PATHSPECS: [&str; 5] = [
+    ":(exclude,glob).cookies*",
+    ":(exclude,glob).journal*",
+    ":(exclude,glob).scratchpad*",
+    ":(exclude,glob).macro*",
+    ":(exclude).trusted_projects",
+];
+
+/// Stage every change of the directory but the private files and commit them only, git opens the editor when there is no message
+pub fn git_commit(directory: &Path, message: Option<&str>) -> anyhow::Result<()> {
+    let mut add_args = vec!["add", "--all", "--", "."];
+    add_args.extend(PRIVATE_FILES_PATHSPECS);
+
+    run_git_captured(directory, &add_args)?;
+
+    let mut commit_args = match message {
+        None => vec!["commit"],
+        Some(message) => vec!["commit", "--message", message]
+    };
+
+    // A private file staged or tracked beforehand is left out of the commit as well
+    commit_args.extend(["--", "."]);
+    commit_args.extend(PRIVATE_FILES_PATHSPECS);
+
+    return run_git_interactive(directory, &commit_args);
+}
@@ -0,0 +1,156 @@
+use regex::Captures;
+use serde::de::IgnoredAny;
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::app::business_logic::environment::ENV_KEY_REGEX;
+
+/// Position of the error in the text, lines and columns start at 1
+#[derive(Error, Debug)]
+#[error("Line {line}, col {column}: {message}")]
+pub struct JsonSyntaxError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+/// Check the syntax of a JSON body, an empty body is valid.
+/// The environment keys can be used as any value, e.g. {"id": {{ID}}}
+pub fn validate_json(text: &str) -> Result<(), JsonSyntaxError> {
+    if text.trim().is_empty() {
+        return Ok(());
+    }
+
+    // Same length as the keys, so the columns of the errors stay right
+    let text = ENV_KEY_REGEX.replace_all(text, |captures: &Captures| format!("{:<width$}", 0, width = captures[0].len()));
+
+    return match serde_json::from_str::<IgnoredAny>(&text) {
+        Ok(_) => Ok(()),
+        Err(error) => {
+            let error_message = error.to_string();
+
+            // The position is given by the fields
+            let message = match error_message.rsplit_once(" at line ") {
+                Some((message, _)) => message.to_string(),
+                None => error_message
+            };
+
+            Err(JsonSyntaxError {
+                line: error.line(),
+                column: error.column(),
+                message,
+            })
+        }
+    };
+}
+
+/// Indent a valid JSON body, the keys order and the numbers are kept as written
+pub fn format_json(text: &str) -> Result<String, JsonSyntaxError> {
+    validate_json(text)?;
+
+    let mut env_keys: Vec<String> = vec![];
+
+    // The environment keys outside of the strings would be taken for objects
+    let text = ENV_KEY_REGEX.replace_all(text, |captures: &Captures| {
+        env_keys.push(captures[0].to_string());
+        format!("__ATAC_ENV_KEY_{}__", env_keys.len() - 1)
+    });
+
+    let mut formatted_text = jsonxf::pretty_print(&text).unwrap_or(text.to_string());
+
+    for (index, env_key) in env_keys.iter().enumerate() {
+        formatted_text = formatted_text.replacen(&format!("__ATAC_ENV_KEY_{index}__"), env_key, 1);
+    }
+
+    return Ok(formatted_text.trim_end().to_string());
+}
+
+/// Bracket at the cursor or right before it, and the bracket matching it, as (row, column) positions.
+/// The brackets in the strings are ignored
+pub fn find_matching_bracket(lines: &[String], cursor: (usize, usize)) -> Option<((usize, usize), (usize, usize))> {
+    let (cursor_row, cursor_column) = cursor;
+    let cursor_line = lines.get(cursor_row)?;
+
+    let is_bracket = |column: usize| cursor_line.chars().nth(column).is_some_and(|char| matches!(char, '{' | '}' | '[' | ']'));
+
+    let bracket = match (is_bracket(cursor_column), cursor_column.checked_sub(1)) {
+        (true, _) => (cursor_row, cursor_column),
+        (false, Some(previous_column)) if is_bracket(previous_column) => (cursor_row, previous_column),
+        _ => return None
+    };
+
+    let mut opened_brackets: Vec<(char, (usize, usize))> = vec![];
+    let mut is_in_string = false;
+    let mut is_escaped = false;
+
+    for (row, line) in lines.iter().enumerate() {
+        for (column, char) in line.chars().enumerate() {
+            if is_in_string {
+                match char {
+                    _ if is_escaped => is_escaped = false,
+                    '\\' => is_escaped = true,
+                    '"' => is_in_string = false,
+                    _ => {}
+                }
+
+                continue;
+            }
+
+            match char {
+                '"' => is_in_string = true,
+                '{' | '[' => opened_brackets.push((char, (row, column))),
+                '}' | ']' => {
+                    let opening_char = match char {
+                        '}' => '{',
+                        _ => '['
+                    };
+
+                    // Unbalanced brackets are not matched
+                    let (opened_char, opened_position) = opened_brackets.pop()?;
+
+                    if opened_char != opening_char {
+                        return None;
+                    }
+
+                    if opened_position == bracket || (row, column) == bracket {
+                        return Some((opened_position, (row, column)));
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+
+    return None;
+}
+
+/// Value at a simple JSON path, e.g. $.data.items[0]['next-page'], the $ being optional.
+/// Filters and wildcards are not supported
+pub fn get_json_path_value<'a>(value: &'a Value, path: &str) -> Option<&'a Value> {
+    let path = path.trim();
+    let mut remaining = path.strip_prefix('$').unwrap_or(path);
+    let mut current = value;
+
+    while !remaining.is_empty() {
+        if let Some(rest) = remaining.strip_prefix('[') {
+            let (segment, rest) = rest.split_once(']')?;
+            let segment = segment.trim();
+
+            current = match segment.parse::<usize>() {
+                Ok(index) => current.get(index)?,
+                Err(_) => current.get(segment.trim_matches(|char| char == '\'' || char == '"'))?
+            };
+
+            remaining = rest;
+        }
+        else {
+            let rest = remaining.strip_prefix('.').unwrap_or(remaining);
+            let key_end = rest.find(['.', '[']).unwrap_or(rest.len());
+
+            current = current.get(&rest[..key_end])?;
+            remaining = &rest[key_end..];
+        }
+    }
+
+    return Some(current);
+}
@@ -0,0 +1,267 @@
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use lazy_static::lazy_static;
+use regex::Regex;
+use ring::{hmac, signature};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::app::business_logic::jwt::JwtError::{CouldNotDecode, CouldNotFetchJwks, InvalidSignature, JwksNeeded, NoMatchingKey, NotAJwt, SecretNeeded, UnsupportedAlgorithm};
+use crate::models::auth::Auth;
+use crate::models::response::ResponseContent;
+
+#[derive(Error, Debug)]
+pub enum JwtError {
+    #[error("Not a JWT, expected a header, a payload and a signature separated by dots")]
+    NotAJwt,
+    #[error("Could not decode the {0}\n\t{1}")]
+    CouldNotDecode(String, String),
+    #[error("Unsupported algorithm \"{0}\"")]
+    UnsupportedAlgorithm(String),
+    #[error("{0} signatures are verified with a JWKS URL")]
+    JwksNeeded(String),
+    #[error("{0} signatures are verified with a secret")]
+    SecretNeeded(String),
+    #[error("Could not fetch the JWKS\n\t{0}")]
+    CouldNotFetchJwks(String),
+    #[error("No key of the JWKS matches the token")]
+    NoMatchingKey,
+    #[error("Invalid signature")]
+    InvalidSignature,
+}
+
+lazy_static! {
+    static ref JWT_REGEX: Regex = Regex::new(r"^eyJ[\w-]*\.eyJ[\w-]*\.[\w-]*$").unwrap();
+}
+
+pub struct DecodedJwt {
+    pub header: Value,
+    pub payload: Value,
+    signing_input: String,
+    signature: Vec<u8>,
+}
+
+pub fn is_jwt(text: &str) -> bool {
+    return JWT_REGEX.is_match(text);
+}
+
+pub fn decode_jwt(token: &str) -> anyhow::Result<DecodedJwt> {
+    let parts: Vec<&str> = token.trim().split('.').collect();
+
+    if parts.len() != 3 {
+        return Err(anyhow!(NotAJwt));
+    }
+
+    let header = decode_json_part(parts[0], "header")?;
+    let payload = decode_json_part(parts[1], "payload")?;
+
+    let signature = URL_SAFE_NO_PAD.decode(parts[2])
+        .map_err(|error| anyhow!(CouldNotDecode(String::from("signature"), error.to_string())))?;
+
+    return Ok(DecodedJwt {
+        header,
+        payload,
+        signing_input: format!("{}.{}", parts[0], parts[1]),
+        signature,
+    });
+}
+
+fn decode_json_part(part: &str, part_name: &str) -> anyhow::Result<Value> {
+    let bytes = URL_SAFE_NO_PAD.decode(part)
+        .map_err(|error| anyhow!(CouldNotDecode(part_name.to_string(), error.to_string())))?;
+
+    return serde_json::from_slice(&bytes)
+        .map_err(|error| anyhow!(CouldNotDecode(part_name.to_string(), error.to_string())));
+}
+
+pub async fn fetch_jwks(url: &str) -> anyhow::Result<Value> {
+    let response = reqwest::get(url).await
+        .map_err(|error| anyhow!(CouldNotFetchJwks(error.to_string())))?;
+
+    let jwks = response.text().await
+        .map_err(|error| anyhow!(CouldNotFetchJwks(error.to_string())))?;
+
+    return serde_json::from_str(&jwks)
+        .map_err(|error| anyhow!(CouldNotFetchJwks(error.to_string())));
+}
+
+impl DecodedJwt {
+    pub fn get_algorithm(&self) -> String {
+        return self.header["alg"].as_str().unwrap_or("none").to_string();
+    }
+
+    /// HS256, HS384 and HS512 signatures
+    pub fn verify_with_secret(&self, secret: &str) -> anyhow::Result<()> {
+        let algorithm = match self.get_algorithm().as_str() {
+            "HS256" => hmac::HMAC_SHA256,
+            "HS384" => hmac::HMAC_SHA384,
+            "HS512" => hmac::HMAC_SHA512,
+            "RS256" | "RS384" | "RS512" | "PS256" | "PS384" | "PS512" | "ES256" | "ES384" | "EdDSA" => return Err(anyhow!(JwksNeeded(self.get_algorithm()))),
+            algorithm => return Err(anyhow!(UnsupportedAlgorithm(algorithm.to_string())))
+        };
+
+        let key = hmac::Key::new(algorithm, secret.as_bytes());
+
+        return hmac::verify(&key, self.signing_input.as_bytes(), &self.signature)
+            .map_err(|_| anyhow!(InvalidSignature));
+    }
+
+    /// RSA, ECDSA and EdDSA signatures, with the key of the same "kid", otherwise with every key of the JWKS
+    pub fn verify_with_jwks(&self, jwks: &Value) -> anyhow::Result<()> {
+        let algorithm = self.get_algorithm();
+
+        if algorithm.starts_with("HS") {
+            return Err(anyhow!(SecretNeeded(algorithm)));
+        }
+
+        let keys = match jwks["keys"].as_array() {
+            None => return Err(anyhow!(NoMatchingKey)),
+            Some(keys) => keys
+        };
+
+        let kid = self.header["kid"].as_str();
+
+        let keys: Vec<&Value> = keys
+            .iter()
+            .filter(|key| kid.is_none() || key["kid"].as_str() == kid)
+            .collect();
+
+        if keys.is_empty() {
+            return Err(anyhow!(NoMatchingKey));
+        }
+
+        for key in keys {
+            match self.verify_with_jwk(&algorithm, key) {
+                Ok(_) => return Ok(()),
+                Err(error) => match error.downcast_ref::<JwtError>() {
+                    Some(UnsupportedAlgorithm(_)) => return Err(error),
+                    _ => continue
+                }
+            }
+        }
+
+        return Err(anyhow!(InvalidSignature));
+    }
+
+    fn verify_with_jwk(&self, algorithm: &str, jwk: &Value) -> anyhow::Result<()> {
+        let message = self.signing_input.as_bytes();
+
+        let result = match algorithm {
+            "RS256" | "RS384" | "RS512" | "PS256" | "PS384" | "PS512" => {
+                let parameters = match algorithm {
+                    "RS256" => &signature::RSA_PKCS1_2048_8192_SHA256,
+                    "RS384" => &signature::RSA_PKCS1_2048_8192_SHA384,
+                    "RS512" => &signature::RSA_PKCS1_2048_8192_SHA512,
+                    "PS256" => &signature::RSA_PSS_2048_8192_SHA256,
+                    "PS384" => &signature::RSA_PSS_2048_8192_SHA384,
+                    _ => &signature::RSA_PSS_2048_8192_SHA512
+                };
+
+                let public_key = signature::RsaPublicKeyComponents {
+                    n: decode_jwk_parameter(jwk, "n")?,
+                    e: decode_jwk_parameter(jwk, "e")?,
+                };
+
+                public_key.verify(parameters, message, &self.signature)
+            },
+            "ES256" | "ES384" => {
+                let parameters = match algorithm {
+                    "ES256" => &signature::ECDSA_P256_SHA256_FIXED,
+                    _ => &signature::ECDSA_P384_SHA384_FIXED
+                };
+
+                // Uncompressed point
+                let mut public_key = vec![0x04];
+                public_key.extend(decode_jwk_parameter(jwk, "x")?);
+                public_key.extend(decode_jwk_parameter(jwk, "y")?);
+
+                signature::UnparsedPublicKey::new(parameters, public_key).verify(message, &self.signature)
+            },
+            "EdDSA" => {
+                let public_key = decode_jwk_parameter(jwk, "x")?;
+
+                signature::UnparsedPublicKey::new(&signature::ED25519, public_key).verify(message, &self.signature)
+            },
+            algorithm => return Err(anyhow!(UnsupportedAlgorithm(algorithm.to_string())))
+        };
+
+        return result.map_err(|_| anyhow!(InvalidSignature));
+    }
+}
+
+fn decode_jwk_parameter(jwk: &Value, parameter: &str) -> anyhow::Result<Vec<u8>> {
+    let value = jwk[parameter].as_str().ok_or(anyhow!(NoMatchingKey))?;
+
+    return URL_SAFE_NO_PAD.decode(value)
+        .map_err(|error| anyhow!(CouldNotDecode(format!("JWKS parameter \"{parameter}\""), error.to_string())));
+}
+
+impl App<'_> {
+    /// JWT-looking values of the selected request, its response and the selected environment, with where they were found
+    pub fn find_jwts(&self) -> Vec<(String, String)> {
+        let mut jwts: Vec<(String, String)> = vec![];
+
+        if let Some(local_request) = self.collections_tree.selected.map(|selection| self.get_request_as_local_from_indexes(&selection)) {
+            let request = local_request.read();
+
+            if let Auth::BearerToken { token } = &request.auth {
+                jwts.push((String::from("Bearer token"), self.replace_env_keys_by_value(token)));
+            }
+
+            for header in &request.headers {
+                jwts.push((format!("Header \"{}\"", header.data.0), self.replace_env_keys_by_value(&header.data.1)));
+            }
+
+            for (header_name, header_value) in &request.response.headers {
+                jwts.push((format!("Response header \"{header_name}\""), header_value.clone()));
+            }
+
+            if let Some(ResponseContent::Body(body)) = &request.response.content {
+                if let Ok(body) = serde_json::from_str::<Value>(body) {
+                    find_json_jwts(&body, String::from("Response body"), &mut jwts);
+                }
+            }
+        }
+
+        if let Some(local_env) = self.get_selected_env_as_local() {
+            let env = local_env.read();
+
+            for (key, value) in &env.values {
+                jwts.push((format!("Environment \"{key}\""), value.clone()));
+            }
+        }
+
+        return jwts
+            .into_iter()
+            .map(|(source, value)| {
+                // e.g. "Bearer eyJ..."
+                let token = match value.split_once(' ') {
+                    Some((scheme, token)) if scheme.eq_ignore_ascii_case("bearer") => token.to_string(),
+                    _ => value
+                };
+
+                (source, token)
+            })
+            .filter(|(_, token)| is_jwt(token))
+            .collect();
+    }
+}
+
+fn find_json_jwts(value: &Value, path: String, jwts: &mut Vec<(String, String)>) {
+    match value {
+        Value::String(string) => jwts.push((path, string.clone())),
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                find_json_jwts(value, format!("{path}[{index}]"), jwts);
+            }
+        },
+        Value::Object(object) => {
+            for (key, value) in object {
+                find_json_jwts(value, format!("{path} > {key}"), jwts);
+            }
+        },
+        _ => {}
+    }
+}
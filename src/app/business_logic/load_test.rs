@@ -0,0 +1,97 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Instant;
+
+use parking_lot::RwLock;
+use thiserror::Error;
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::models::load_test::{LoadTestLimit, LoadTestOptions, LoadTestStats};
+
+#[derive(Error, Debug)]
+pub enum LoadTestError {
+    #[error("The request body cannot be sent more than once (streamed body)")]
+    RequestNotCloneable,
+}
+
+/// Performance run engine, separate from the single-shot send: `concurrency` workers send the same prepared request until the limit is reached.
+/// Pre-request scripts run once when preparing the request, post-request scripts are not run.
+pub async fn run_load_test(prepared_request: reqwest_middleware::RequestBuilder, options: LoadTestOptions, stats: Arc<RwLock<LoadTestStats>>, cancellation_token: CancellationToken) -> Result<(), LoadTestError> {
+    if prepared_request.try_clone().is_none() {
+        return Err(LoadTestError::RequestNotCloneable);
+    }
+
+    info!("Starting load test with {} workers", options.concurrency);
+
+    let started_at = Instant::now();
+
+    *stats.write() = LoadTestStats {
+        started_at: Some(started_at),
+        ..Default::default()
+    };
+
+    let prepared_request = Arc::new(prepared_request);
+    let issued_requests = Arc::new(AtomicU64::new(0));
+
+    let mut workers = JoinSet::new();
+
+    for _ in 0..usize::max(options.concurrency, 1) {
+        let prepared_request = Arc::clone(&prepared_request);
+        let issued_requests = Arc::clone(&issued_requests);
+        let stats = Arc::clone(&stats);
+        let cancellation_token = cancellation_token.clone();
+
+        workers.spawn(async move {
+            loop {
+                let should_stop = match options.limit {
+                    LoadTestLimit::Iterations(iterations) => issued_requests.fetch_add(1, Ordering::SeqCst) >= iterations,
+                    LoadTestLimit::Duration(duration) => started_at.elapsed() >= duration
+                };
+
+                if should_stop || cancellation_token.is_cancelled() {
+                    break;
+                }
+
+                // Checked before starting the run
+                let request = prepared_request.try_clone().unwrap();
+                let request_start = Instant::now();
+
+                let result = tokio::select! {
+                    _ = cancellation_token.cancelled() => break,
+                    result = request.send() => result
+                };
+
+                let status_code = match result {
+                    Ok(response) => {
+                        let status_code = response.status().as_u16();
+                        // The latency includes the body download
+                        let _ = response.bytes().await;
+                        Some(status_code)
+                    },
+                    Err(_) => None
+                };
+
+                let latency = request_start.elapsed();
+                let mut stats = stats.write();
+
+                match status_code {
+                    None => stats.errors += 1,
+                    Some(status_code) => {
+                        stats.samples.push((request_start - started_at, latency));
+                        *stats.status_codes.entry(status_code).or_insert(0) += 1;
+                    }
+                }
+            }
+        });
+    }
+
+    while workers.join_next().await.is_some() {}
+
+    stats.write().finished_at = Some(Instant::now());
+
+    info!("Load test finished");
+
+    Ok(())
+}
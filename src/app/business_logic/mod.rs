@@ -1,4 +1,24 @@
 pub mod request;
 pub mod collection;
 pub mod environment;
-pub mod key_value;
\ No newline at end of file
+pub mod key_value;
+pub mod webhook_listener;
+pub mod monitor;
+pub mod notification;
+pub mod load_test;pub mod fuzz;
+pub mod cookies;
+pub mod template;
+pub mod docs;
+pub mod workspace;
+pub mod git;
+pub mod variable_usages;
+pub mod jwt;
+pub mod encoding;
+pub mod json;
+pub mod offline;
+
+pub mod app_error;
+pub mod watch;
+pub mod plugin;
+pub mod scripting_console;
+pub mod response_links;
\ No newline at end of file
@@ -0,0 +1,129 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use parking_lot::RwLock;
+use tracing::{info, warn};
+
+use crate::app::app::App;
+use crate::app::business_logic::request::send::send_request;
+use crate::models::environment::Environment;
+use crate::models::monitor::{MonitorRecord, RequestMonitor};
+use crate::models::request::Request;
+use crate::models::response::{RequestResult, ResponseContent};
+
+impl App<'_> {
+    pub fn modify_request_monitor(&mut self, collection_index: usize, request_index: usize, monitor: Option<RequestMonitor>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &monitor {
+                None => info!("Monitor removed"),
+                Some(monitor) => info!("Monitor set to every \"{}\"", monitor.every)
+            }
+
+            selected_request.monitor = monitor;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// Returns the indexes of the monitored requests whose next check is due, and schedules their next check
+    pub fn take_due_monitors(&mut self, collection_index: Option<usize>) -> Vec<(usize, usize)> {
+        let now = Instant::now();
+        let mut due_monitors: Vec<(usize, usize)> = vec![];
+
+        for (index, collection) in self.collections.iter().enumerate() {
+            if collection_index.is_some_and(|collection_index| collection_index != index) {
+                continue;
+            }
+
+            for (request_index, request) in collection.requests.iter().enumerate() {
+                // Locked while being sent, waiting for it would freeze the app
+                let mut request = match request.try_write() {
+                    Some(request) => request,
+                    None => continue
+                };
+
+                // The request is already being sent
+                if request.is_pending {
+                    continue;
+                }
+
+                let monitor = match &mut request.monitor {
+                    Some(monitor) if monitor.is_due(now) => monitor,
+                    _ => continue
+                };
+
+                let interval = match monitor.get_interval() {
+                    Ok(interval) => interval,
+                    Err(error) => {
+                        warn!("{error}");
+                        continue;
+                    }
+                };
+
+                monitor.next_run = Some(now + interval);
+                due_monitors.push((index, request_index));
+            }
+        }
+
+        return due_monitors;
+    }
+}
+
+/// Sends a monitored request and records the check in its monitor history
+pub async fn send_monitored_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, env: &Option<Arc<RwLock<Environment>>>, offline: bool) -> MonitorRecord {
+    let checked_at = Utc::now().format("%Y-%m-%d %H:%M:%S").to_string();
+    let check_start = Instant::now();
+
+    let (status_code, is_failure) = match send_request(prepared_request, local_request.clone(), env, offline).await {
+        Ok((response, _, _)) => {
+            let is_failure = response.is_failure();
+
+            // Without response, the response content holds the error
+            let status_code = match (&response.result, response.content) {
+                (Some(RequestResult::TransportError(_)), Some(ResponseContent::Body(error))) => Some(error.to_string()),
+                (result, _) => result.as_ref().map(|result| result.to_string())
+            };
+
+            (status_code, is_failure)
+        },
+        Err(error) => (Some(error.to_string()), true)
+    };
+
+    let record = MonitorRecord {
+        checked_at,
+        status_code,
+        duration: check_start.elapsed(),
+        is_failure,
+    };
+
+    record_monitor_check(&local_request, record.clone());
+
+    return record;
+}
+
+/// Check that failed before the request could be sent (e.g. invalid URL)
+pub fn failed_monitor_check(error: String) -> MonitorRecord {
+    return MonitorRecord {
+        checked_at: Utc::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        status_code: Some(error),
+        duration: Duration::ZERO,
+        is_failure: true,
+    };
+}
+
+pub fn record_monitor_check(local_request: &Arc<RwLock<Request>>, record: MonitorRecord) {
+    if record.is_failure {
+        warn!("Monitor check failed: {:?}", record.status_code);
+    }
+
+    if let Some(monitor) = &mut local_request.write().monitor {
+        monitor.record(record);
+    }
+}
@@ -0,0 +1,24 @@
+use std::io::{stdout, Write};
+
+use tracing::trace;
+
+use crate::app::files::config::NotificationMethod;
+
+/// Writes a notification to the terminal, either as a bell or as a desktop notification escape sequence
+pub fn send_notification(method: NotificationMethod, title: &str, body: &str) {
+    trace!("Sending notification \"{title}: {body}\"");
+
+    // Escape sequences are terminated by BEL, avoid breaking them
+    let title = title.replace(['\x07', '\x1b', ';'], " ");
+    let body = body.replace(['\x07', '\x1b'], " ");
+
+    let sequence = match method {
+        NotificationMethod::Bell => String::from("\x07"),
+        NotificationMethod::Osc9 => format!("\x1b]9;{title}: {body}\x07"),
+        NotificationMethod::Osc777 => format!("\x1b]777;notify;{title};{body}\x07"),
+    };
+
+    let mut stdout = stdout();
+    let _ = stdout.write_all(sequence.as_bytes());
+    let _ = stdout.flush();
+}
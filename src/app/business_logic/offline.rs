@@ -0,0 +1,22 @@
+use tracing::info;
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+
+impl App<'_> {
+    /// Sends answered with the request snapshots instead of the network
+    pub fn is_offline(&self) -> bool {
+        return self.offline_mode.unwrap_or(ARGS.offline || self.config.is_offline_mode_enabled());
+    }
+
+    pub fn toggle_offline_mode(&mut self) {
+        let offline_mode = !self.is_offline();
+
+        match offline_mode {
+            true => info!("Offline mode enabled, the requests are answered with their snapshot"),
+            false => info!("Offline mode disabled")
+        }
+
+        self.offline_mode = Some(offline_mode);
+    }
+}
@@ -0,0 +1,78 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::{info, trace};
+
+use crate::app::files::plugins::PLUGINS;
+use crate::models::plugin::{Plugin, PluginError, PluginHook, PluginMessage, PLUGIN_API_VERSION};
+
+/// Plugin named so, providing the hook
+pub fn find_plugin(name: &str, hook: PluginHook) -> Result<Plugin, PluginError> {
+    let plugins = PLUGINS.read();
+
+    let plugin = match plugins.iter().find(|plugin| plugin.manifest.name == name) {
+        Some(plugin) => plugin,
+        None => return Err(PluginError::NotFound(name.to_string()))
+    };
+
+    match plugin.has_hook(hook) {
+        true => Ok(plugin.clone()),
+        false => Err(PluginError::MissingHook(name.to_string(), hook))
+    }
+}
+
+/// Every plugin providing the hook, sorted by name
+pub fn get_plugins_with_hook(hook: PluginHook) -> Vec<Plugin> {
+    return PLUGINS.read()
+        .iter()
+        .filter(|plugin| plugin.has_hook(hook))
+        .cloned()
+        .collect();
+}
+
+/// Run the plugin command from its directory, the message is written to its standard input as JSON
+/// and the output is read from its standard output. A non-zero exit status fails with what it printed on its standard error.
+pub fn run_plugin<I: Serialize, O: DeserializeOwned>(plugin: &Plugin, hook: PluginHook, input: &I) -> Result<O, PluginError> {
+    let name = &plugin.manifest.name;
+
+    info!("Running plugin \"{name}\" {hook} hook");
+
+    let message = PluginMessage {
+        api_version: PLUGIN_API_VERSION,
+        hook,
+        input,
+    };
+
+    let message = serde_json::to_vec(&message).unwrap();
+
+    let mut child = Command::new(&plugin.manifest.command[0])
+        .args(&plugin.manifest.command[1..])
+        .current_dir(&plugin.directory)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| PluginError::Spawn(name.clone(), error.to_string()))?;
+
+    // Written from another thread so that a plugin answering before reading everything cannot block
+    let mut stdin = child.stdin.take().unwrap();
+    let writer = thread::spawn(move || stdin.write_all(&message));
+
+    let output = child
+        .wait_with_output()
+        .map_err(|error| PluginError::Spawn(name.clone(), error.to_string()))?;
+
+    let _ = writer.join();
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim_end().to_string();
+        return Err(PluginError::Failed(name.clone(), output.status.to_string(), stderr));
+    }
+
+    trace!("Plugin \"{name}\" answered");
+
+    return serde_json::from_slice(&output.stdout).map_err(|error| PluginError::InvalidOutput(name.clone(), error.to_string()));
+}
\ No newline at end of file
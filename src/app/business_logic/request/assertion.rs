@@ -0,0 +1,22 @@
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::assertion::ResponseAssertion;
+
+impl App<'_> {
+    pub fn modify_request_assertions(&mut self, collection_index: usize, request_index: usize, assertions: Vec<ResponseAssertion>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            info!("Response assertions set to [{}]", assertions.iter().map(ResponseAssertion::to_test_name).collect::<Vec<String>>().join(", "));
+
+            selected_request.assertions = assertions;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
@@ -1,7 +1,35 @@
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::Utc;
+use reqwest::Url;
+use ring::{digest, hmac};
 use tracing::{info};
 use crate::app::app::App;
-use crate::models::auth::Auth;
-use crate::models::auth::Auth::{BasicAuth, BearerToken};
+use crate::app::business_logic::encoding::to_hex;
+use crate::models::auth::{Auth, HmacAlgorithm, OAuth1Placement, OAuth1SignatureMethod, SignatureEncoding};
+use crate::models::auth::Auth::{BasicAuth, BearerToken, HmacSignature, Ntlm, OAuth1, Plugin};
+use crate::models::method::Method;
+
+/// Fields of an HMAC signature auth to modify, the others are kept
+#[derive(Default)]
+pub struct HmacAuthUpdate {
+    pub secret: Option<String>,
+    pub string_to_sign: Option<String>,
+    pub header: Option<String>,
+    pub algorithm: Option<HmacAlgorithm>,
+    pub encoding: Option<SignatureEncoding>,
+}
+
+/// Fields of an OAuth 1.0a auth to modify, the others are kept
+#[derive(Default)]
+pub struct OAuth1AuthUpdate {
+    pub consumer_key: Option<String>,
+    pub consumer_secret: Option<String>,
+    pub token: Option<String>,
+    pub token_secret: Option<String>,
+    pub signature_method: Option<OAuth1SignatureMethod>,
+    pub placement: Option<OAuth1Placement>,
+}
 
 impl App<'_> {
     pub fn modify_request_auth(&mut self, collection_index: usize, request_index: usize, auth: Auth) -> anyhow::Result<()> {
@@ -82,4 +110,179 @@ impl App<'_> {
 
         self.save_collection_to_file(collection_index);
     }
+
+    pub fn modify_request_auth_hmac(&mut self, collection_index: usize, request_index: usize, update: HmacAuthUpdate) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &mut selected_request.auth {
+                HmacSignature { secret, string_to_sign, header, algorithm, encoding } => {
+                    if let Some(new_secret) = update.secret {
+                        info!("Auth HMAC secret set to \"{}\"", new_secret);
+                        *secret = new_secret;
+                    }
+
+                    if let Some(new_string_to_sign) = update.string_to_sign {
+                        info!("Auth HMAC string to sign set to \"{}\"", new_string_to_sign);
+                        *string_to_sign = new_string_to_sign;
+                    }
+
+                    if let Some(new_header) = update.header {
+                        info!("Auth HMAC header set to \"{}\"", new_header);
+                        *header = new_header;
+                    }
+
+                    if let Some(new_algorithm) = update.algorithm {
+                        info!("Auth HMAC algorithm set to \"{}\"", new_algorithm);
+                        *algorithm = new_algorithm;
+                    }
+
+                    if let Some(new_encoding) = update.encoding {
+                        info!("Auth HMAC encoding set to \"{}\"", new_encoding);
+                        *encoding = new_encoding;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_ntlm(&mut self, collection_index: usize, request_index: usize, new_username: Option<String>, new_password: Option<String>, new_domain: Option<String>) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &mut selected_request.auth {
+                Ntlm { username, password, domain } => {
+                    if let Some(new_username) = new_username {
+                        info!("Auth NTLM username set to \"{}\"", new_username);
+                        *username = new_username;
+                    }
+
+                    if let Some(new_password) = new_password {
+                        info!("Auth NTLM password set to \"{}\"", new_password);
+                        *password = new_password;
+                    }
+
+                    if let Some(new_domain) = new_domain {
+                        info!("Auth NTLM domain set to \"{}\"", new_domain);
+                        *domain = new_domain;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_oauth1(&mut self, collection_index: usize, request_index: usize, update: OAuth1AuthUpdate) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &mut selected_request.auth {
+                OAuth1 { consumer_key, consumer_secret, token, token_secret, signature_method, placement } => {
+                    if let Some(new_consumer_key) = update.consumer_key {
+                        info!("Auth OAuth consumer key set to \"{}\"", new_consumer_key);
+                        *consumer_key = new_consumer_key;
+                    }
+
+                    if let Some(new_consumer_secret) = update.consumer_secret {
+                        info!("Auth OAuth consumer secret set to \"{}\"", new_consumer_secret);
+                        *consumer_secret = new_consumer_secret;
+                    }
+
+                    if let Some(new_token) = update.token {
+                        info!("Auth OAuth token set to \"{}\"", new_token);
+                        *token = new_token;
+                    }
+
+                    if let Some(new_token_secret) = update.token_secret {
+                        info!("Auth OAuth token secret set to \"{}\"", new_token_secret);
+                        *token_secret = new_token_secret;
+                    }
+
+                    if let Some(new_signature_method) = update.signature_method {
+                        info!("Auth OAuth signature method set to \"{}\"", new_signature_method);
+                        *signature_method = new_signature_method;
+                    }
+
+                    if let Some(new_placement) = update.placement {
+                        info!("Auth OAuth placement set to \"{}\"", new_placement);
+                        *placement = new_placement;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+
+    pub fn modify_request_auth_plugin(&mut self, collection_index: usize, request_index: usize, new_plugin: Option<String>, new_config: Option<String>) {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &mut selected_request.auth {
+                Plugin { plugin, config } => {
+                    if let Some(new_plugin) = new_plugin {
+                        info!("Auth plugin set to \"{}\"", new_plugin);
+                        *plugin = new_plugin;
+                    }
+
+                    if let Some(new_config) = new_config {
+                        info!("Auth plugin config set to \"{}\"", new_config);
+                        *config = new_config;
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        self.save_collection_to_file(collection_index);
+    }
+}
+
+/// Fill the placeholders of an HMAC string-to-sign template, a literal "\n" being a new line
+pub fn get_string_to_sign(template: &str, method: &Method, url: &Url, body: &[u8]) -> String {
+    let host = match url.port() {
+        None => url.host_str().unwrap_or("").to_string(),
+        Some(port) => format!("{}:{port}", url.host_str().unwrap_or(""))
+    };
+
+    return template
+        .replace("\\n", "\n")
+        .replace("{method}", &method.to_string())
+        .replace("{url}", url.as_str())
+        .replace("{path}", url.path())
+        .replace("{query}", url.query().unwrap_or(""))
+        .replace("{host}", &host)
+        .replace("{timestamp}", &Utc::now().timestamp().to_string())
+        .replace("{body_sha256}", &to_hex(digest::digest(&digest::SHA256, body).as_ref()))
+        .replace("{body}", &String::from_utf8_lossy(body));
+}
+
+pub fn sign_hmac(algorithm: &HmacAlgorithm, encoding: &SignatureEncoding, secret: &str, string_to_sign: &str) -> String {
+    let algorithm = match algorithm {
+        HmacAlgorithm::HmacSha1 => hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY,
+        HmacAlgorithm::HmacSha256 => hmac::HMAC_SHA256,
+        HmacAlgorithm::HmacSha512 => hmac::HMAC_SHA512
+    };
+
+    let key = hmac::Key::new(algorithm, secret.as_bytes());
+    let signature = hmac::sign(&key, string_to_sign.as_bytes());
+
+    return match encoding {
+        SignatureEncoding::Hex => to_hex(signature.as_ref()),
+        SignatureEncoding::Base64 => STANDARD.encode(signature.as_ref())
+    };
 }
\ No newline at end of file
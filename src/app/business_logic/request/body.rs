@@ -1,16 +1,22 @@
+use anyhow::anyhow;
 use reqwest::header::CONTENT_TYPE;
 use thiserror::Error;
 use tracing::{info};
 
 use crate::app::app::App;
 use crate::app::business_logic::key_value::find_key;
-use crate::models::body::ContentType;
+use crate::app::business_logic::request::body::FormError::{NotAForm, NotMultipart, PartNotFound};
+use crate::models::body::{ContentType, MultipartPart};
 use crate::models::request::KeyValue;
 
 #[derive(Error, Debug)]
 pub enum FormError {
     #[error("The request body is not a form")]
     NotAForm,
+    #[error("The request body is not multipart")]
+    NotMultipart,
+    #[error("Part not found")]
+    PartNotFound,
 }
 
 impl App<'_> {    
@@ -32,7 +38,7 @@ impl App<'_> {
                 // TODO: Impossible to set the header for multipart yet, because of boundary and content-length that are computed on reqwest's side
                 ContentType::Multipart(_) => {},
                 // Create or replace Content-Type header with new body content type
-                ContentType::File(_) | ContentType::Form(_) | ContentType::Raw(_) | ContentType::Json(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) => {
+                ContentType::File(_) | ContentType::Form(_) | ContentType::Raw(_) | ContentType::Json(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) | ContentType::Protobuf(_) | ContentType::MessagePack(_) | ContentType::Cbor(_) => {
                     let content_type = &selected_request.body.to_content_type();
                     selected_request.modify_or_create_header(CONTENT_TYPE.as_str(), content_type)
                 }
@@ -49,7 +55,7 @@ impl App<'_> {
         let selected_request = local_selected_request.read();
         
         let form = selected_request.body.get_form()?;
-        find_key(form, key)
+        find_key(&form, key)
     }
     
     pub fn modify_request_form_data(&mut self, collection_index: usize, request_index: usize, value: String, column: usize, row: usize) -> anyhow::Result<()> {
@@ -57,20 +63,32 @@ impl App<'_> {
 
         {
             let mut selected_request = local_selected_request.write();
-            let form = selected_request.body.get_form_mut()?;
 
             let form_data_type = match column {
                 0 => "key",
                 1 => "value",
+                3 => "content type",
+                4 => "file name",
                 _ => ""
             };
             
             info!("Form data {form_data_type} set to \"{value}\"");
 
-            match column {
-                0 => form[row].data.0 = value.clone(),
-                1 => form[row].data.1 = value.clone(),
-                _ => {}
+            match &mut selected_request.body {
+                ContentType::Form(form) => match column {
+                    0 => form[row].data.0 = value.clone(),
+                    1 => form[row].data.1 = value.clone(),
+                    _ => {}
+                },
+                ContentType::Multipart(parts) => match column {
+                    0 => parts[row].name = value.clone(),
+                    1 => parts[row].value = value.clone(),
+                    // Empty to go back to the default one
+                    3 => parts[row].content_type = non_empty(value),
+                    4 => parts[row].file_name = non_empty(value),
+                    _ => {}
+                },
+                _ => return Err(anyhow!(NotAForm))
             }
         }
 
@@ -83,14 +101,17 @@ impl App<'_> {
 
         {
             let mut selected_request = local_selected_request.write();
-            let form = selected_request.body.get_form_mut()?;
 
             info!("Key \"{key}\" with value \"{value}\" added to the body form");
 
-            form.push(KeyValue {
-                enabled: true,
-                data: (key, value)
-            });
+            match &mut selected_request.body {
+                ContentType::Form(form) => form.push(KeyValue {
+                    enabled: true,
+                    data: (key, value)
+                }),
+                ContentType::Multipart(parts) => parts.push(MultipartPart::text(key, value)),
+                _ => return Err(anyhow!(NotAForm))
+            }
         }
 
         self.save_collection_to_file(collection_index);
@@ -102,11 +123,14 @@ impl App<'_> {
 
         {
             let mut selected_request = local_selected_request.write();
-            let form = selected_request.body.get_form_mut()?;
 
             info!("Form key deleted");
 
-            form.remove(row);
+            match &mut selected_request.body {
+                ContentType::Form(form) => { form.remove(row); },
+                ContentType::Multipart(parts) => { parts.remove(row); },
+                _ => return Err(anyhow!(NotAForm))
+            }
         }
 
         self.save_collection_to_file(collection_index);
@@ -118,11 +142,16 @@ impl App<'_> {
 
         {
             let mut selected_request = local_selected_request.write();
-            let form = selected_request.body.get_form_mut()?;
+
+            let enabled = match &mut selected_request.body {
+                ContentType::Form(form) => &mut form[row].enabled,
+                ContentType::Multipart(parts) => &mut parts[row].enabled,
+                _ => return Err(anyhow!(NotAForm))
+            };
 
             let new_state = match state {
                 None => {
-                    let state = !form[row].enabled;
+                    let state = !*enabled;
                     // Better user feedback
                     println!("{state}");
                     state
@@ -132,10 +161,82 @@ impl App<'_> {
 
             info!("Body form key state set to \"{new_state}\"");
 
-            form[row].enabled = new_state;
+            *enabled = new_state;
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn create_new_multipart_part(&mut self, collection_index: usize, request_index: usize, part: MultipartPart) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let parts = match &mut selected_request.body {
+                ContentType::Multipart(parts) => parts,
+                _ => return Err(anyhow!(NotMultipart))
+            };
+
+            info!("{} part \"{}\" added to the multipart body", part.source, part.name);
+
+            parts.push(part);
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn delete_multipart_part(&mut self, collection_index: usize, request_index: usize, index: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let parts = match &mut selected_request.body {
+                ContentType::Multipart(parts) => parts,
+                _ => return Err(anyhow!(NotMultipart))
+            };
+
+            if index >= parts.len() {
+                return Err(anyhow!(PartNotFound));
+            }
+
+            let part = parts.remove(index);
+
+            info!("Part \"{}\" deleted from the multipart body", part.name);
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    /// Switches the part between text and file
+    pub fn next_multipart_part_source(&mut self, collection_index: usize, request_index: usize, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let part = match &mut selected_request.body {
+                ContentType::Multipart(parts) => &mut parts[row],
+                _ => return Err(anyhow!(NotMultipart))
+            };
+
+            part.source = part.source.next();
+
+            info!("Multipart part source set to \"{}\"", part.source);
         }
 
         self.save_collection_to_file(collection_index);
         Ok(())
     }
+}
+
+fn non_empty(value: String) -> Option<String> {
+    return match value.trim().is_empty() {
+        true => None,
+        false => Some(value)
+    };
 }
\ No newline at end of file
@@ -0,0 +1,251 @@
+use std::collections::HashMap;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use reqwest::header::HeaderMap;
+use reqwest::redirect::Policy;
+use reqwest::{Client, ClientBuilder, Proxy};
+use reqwest_cookie_store::CookieStoreRwLock;
+use tracing::{info, trace};
+
+use crate::app::app::App;
+use crate::app::business_logic::request::dns::{clear_dns_resolutions, RequestResolver};
+use crate::app::business_logic::request::send::PrepareRequestError;
+use crate::models::connection_pool::ConnectionPoolOptions;
+use crate::models::dns::DnsServer;
+use crate::models::http2::Http2Options;
+use crate::models::ip_version::IpVersion;
+use crate::models::tls_version::{TlsVersion, MIN_SUPPORTED_TLS_VERSION};
+
+lazy_static! {
+    /// Clients kept between the sends, so that their connections are reused
+    static ref CLIENTS: RwLock<HashMap<ClientKey, Client>> = RwLock::new(HashMap::new());
+}
+
+/// Everything a client is built from, the requests with the same key share a client and its connections
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClientKey {
+    pub store_received_cookies: bool,
+    pub http_proxy: Option<String>,
+    pub https_proxy: Option<String>,
+    pub accept_invalid_certs: bool,
+    pub accept_invalid_hostnames: bool,
+    pub connection_pool: ConnectionPoolOptions,
+    pub http2: Http2Options,
+    pub ip_version: IpVersion,
+    pub local_address: Option<String>,
+    /// As written in the config, see DnsServer
+    pub dns_resolver: Option<String>,
+    pub min_tls_version: Option<TlsVersion>,
+    pub max_tls_version: Option<TlsVersion>,
+    /// Address of the cookie store, each workspace has its own
+    pub cookie_store: usize,
+}
+
+/// The client of the key, built on its first use
+pub fn get_client(client_key: &ClientKey, cookie_store: &Arc<CookieStoreRwLock>) -> Result<Client, PrepareRequestError> {
+    if let Some(client) = CLIENTS.read().get(client_key) {
+        trace!("Reusing the HTTP client");
+        return Ok(client.clone());
+    }
+
+    let client = build_client(client_key, cookie_store)?;
+
+    CLIENTS.write().insert(client_key.clone(), client.clone());
+
+    return Ok(client);
+}
+
+/// Close the idle connections, the next sends build new clients and resolve the hosts again
+pub fn clear_clients() {
+    CLIENTS.write().clear();
+    clear_dns_resolutions();
+}
+
+impl App<'_> {
+    pub fn modify_request_connection_pool(&mut self, collection_index: usize, request_index: usize, connection_pool: Option<ConnectionPoolOptions>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &connection_pool {
+                None => info!("Connection pool options removed"),
+                Some(connection_pool) => info!("Connection pool options set to {}", connection_pool.to_summary())
+            }
+
+            selected_request.settings.connection_pool = connection_pool;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    pub fn modify_request_http2(&mut self, collection_index: usize, request_index: usize, http2: Option<Http2Options>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &http2 {
+                None => info!("HTTP/2 options removed"),
+                Some(http2) => info!("HTTP/2 options set to {}", http2.to_summary())
+            }
+
+            selected_request.settings.http2 = http2;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// "none" removes the local address
+    pub fn modify_request_local_address(&mut self, collection_index: usize, request_index: usize, local_address: &str) -> anyhow::Result<()> {
+        let local_address = match local_address.trim() {
+            "" | "none" => None,
+            local_address if local_address.parse::<IpAddr>().is_ok() => Some(local_address.to_string()),
+            interface if cfg!(target_os = "linux") && !interface.contains(char::is_whitespace) => Some(interface.to_string()),
+            local_address => return Err(anyhow!("\"{local_address}\" is neither an IP address nor a network interface name, which are only supported on Linux"))
+        };
+
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &local_address {
+                None => info!("Local address removed"),
+                Some(local_address) => info!("Local address set to \"{local_address}\"")
+            }
+
+            selected_request.settings.local_address = local_address;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
+
+fn build_client(client_key: &ClientKey, cookie_store: &Arc<CookieStoreRwLock>) -> Result<Client, PrepareRequestError> {
+    trace!("Building an HTTP client");
+
+    let mut client_builder = ClientBuilder::new()
+        .default_headers(HeaderMap::new())
+        .referer(false);
+
+    /* REDIRECTS */
+
+    // Followed by send_request, reqwest would always strip the Authorization header on cross-origin redirects
+    client_builder = client_builder.redirect(Policy::none());
+
+    /* STORE COOKIES */
+
+    client_builder = client_builder.cookie_store(client_key.store_received_cookies);
+
+    /* PROXY */
+
+    if let Some(http_proxy_str) = &client_key.http_proxy {
+        let proxy = match Proxy::http(http_proxy_str) {
+            Ok(proxy) => proxy,
+            Err(e) => return Err(PrepareRequestError::InvalidProxy(format!("\"{http_proxy_str}\" {e}")))
+        };
+        client_builder = client_builder.proxy(proxy);
+    }
+
+    if let Some(https_proxy_str) = &client_key.https_proxy {
+        let proxy = match Proxy::https(https_proxy_str) {
+            Ok(proxy) => proxy,
+            Err(e) => return Err(PrepareRequestError::InvalidProxy(format!("\"{https_proxy_str}\" {e}")))
+        };
+        client_builder = client_builder.proxy(proxy);
+    }
+
+    /* COOKIES */
+
+    client_builder = client_builder.cookie_provider(Arc::clone(cookie_store));
+
+    /* INVALID CERTS */
+
+    if client_key.accept_invalid_certs {
+        client_builder = client_builder.danger_accept_invalid_certs(true);
+    }
+
+    /* INVALID HOSTNAMES */
+
+    if client_key.accept_invalid_hostnames {
+        client_builder = client_builder.danger_accept_invalid_hostnames(true);
+    }
+
+    /* DNS RESOLVER AND IP VERSION */
+
+    let dns_server = match &client_key.dns_resolver {
+        None => DnsServer::System,
+        Some(dns_resolver) => match DnsServer::parse(dns_resolver) {
+            Ok(dns_server) => dns_server,
+            Err(error) => return Err(PrepareRequestError::InvalidDnsResolver(error))
+        }
+    };
+
+    if dns_server != DnsServer::System || client_key.ip_version != IpVersion::Any {
+        client_builder = client_builder.dns_resolver(Arc::new(RequestResolver {
+            dns_server,
+            ip_version: client_key.ip_version
+        }));
+    }
+
+    /* LOCAL ADDRESS */
+
+    if let Some(local_address) = &client_key.local_address {
+        client_builder = match local_address.parse::<IpAddr>() {
+            Ok(ip) => client_builder.local_address(ip),
+            #[cfg(target_os = "linux")]
+            Err(_) => client_builder.interface(local_address),
+            #[cfg(not(target_os = "linux"))]
+            Err(_) => return Err(PrepareRequestError::InvalidLocalAddress(local_address.clone()))
+        };
+    }
+
+    /* TLS VERSIONS */
+
+    if let (Some(min_tls_version), Some(max_tls_version)) = (client_key.min_tls_version, client_key.max_tls_version) {
+        if min_tls_version > max_tls_version {
+            return Err(PrepareRequestError::UnsupportedTlsVersion(format!("the min version {min_tls_version} is newer than the max version {max_tls_version}")));
+        }
+    }
+
+    if let Some(min_tls_version) = client_key.min_tls_version {
+        client_builder = client_builder.min_tls_version(min_tls_version.to_reqwest());
+    }
+
+    if let Some(max_tls_version) = client_key.max_tls_version {
+        // A server rejecting the older versions cannot be checked, the handshake would not even be attempted
+        if max_tls_version < MIN_SUPPORTED_TLS_VERSION {
+            return Err(PrepareRequestError::UnsupportedTlsVersion(format!("{max_tls_version} is not supported, rustls only negotiates TLS 1.2 and TLS 1.3")));
+        }
+
+        client_builder = client_builder.max_tls_version(max_tls_version.to_reqwest());
+    }
+
+    /* CONNECTION POOL */
+
+    client_builder = client_key.connection_pool.apply(client_builder);
+
+    /* HTTP/2 */
+
+    if let Some(max_frame_size) = client_key.http2.get_invalid_frame_size() {
+        return Err(PrepareRequestError::InvalidHttp2Options(format!("the max frame size {max_frame_size} is not between 16384 and 16777215 bytes")));
+    }
+
+    client_builder = client_key.http2.apply(client_builder);
+
+    return match client_builder.build() {
+        Ok(client) => Ok(client),
+        Err(e) => Err(PrepareRequestError::ClientBuild(e.to_string()))
+    };
+}
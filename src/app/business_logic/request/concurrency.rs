@@ -0,0 +1,37 @@
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, trace};
+
+lazy_static! {
+    /// Slots of the requests sent at the same time, see max_concurrent_requests in the config
+    static ref REQUEST_SLOTS: RwLock<Option<Arc<Semaphore>>> = RwLock::new(None);
+}
+
+/// The requests already sent keep their slot of the previous limit
+pub fn set_max_concurrent_requests(max_concurrent_requests: Option<usize>) {
+    match max_concurrent_requests {
+        None => trace!("Concurrent requests not limited"),
+        Some(max_concurrent_requests) => info!("Concurrent requests limited to {max_concurrent_requests}")
+    }
+
+    *REQUEST_SLOTS.write() = max_concurrent_requests.map(|max_concurrent_requests| Arc::new(Semaphore::new(max_concurrent_requests)));
+}
+
+/// Wait until fewer requests than the limit are being sent, the slot is released once the permit is dropped.
+/// None without limit, or when the request is canceled while waiting
+pub async fn wait_for_request_slot(cancellation_token: &CancellationToken) -> Option<OwnedSemaphorePermit> {
+    let request_slots = REQUEST_SLOTS.read().clone()?;
+
+    if request_slots.available_permits() == 0 {
+        info!("Waiting for a request slot");
+    }
+
+    return tokio::select! {
+        permit = request_slots.acquire_owned() => permit.ok(),
+        _ = cancellation_token.cancelled() => None
+    };
+}
@@ -0,0 +1,97 @@
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::key_value::find_key;
+use crate::models::request::KeyValue;
+
+impl App<'_> {
+    pub fn find_request_cookie(&mut self, collection_index: usize, request_index: usize, key: &str) -> anyhow::Result<usize> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let selected_request = local_selected_request.read();
+
+        find_key(&selected_request.cookies, key)
+    }
+    
+    pub fn modify_request_cookie(&mut self, collection_index: usize, request_index: usize, value: String, column: usize, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            let cookie_type = match column {
+                0 => "key",
+                1 => "value",
+                _ => ""
+            };
+            
+            info!("Cookie {cookie_type} set to \"{value}\"");
+
+            match column {
+                0 => selected_request.cookies[row].data.0 = value.clone(),
+                1 => selected_request.cookies[row].data.1 = value.clone(),
+                _ => {}
+            };
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn create_new_request_cookie(&mut self, collection_index: usize, request_index: usize, key: String, value: String) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            info!("Key \"{key}\" with value \"{value}\" added to the request cookies");
+
+            selected_request.cookies.push(KeyValue {
+                enabled: true,
+                data: (key, value)
+            });
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn delete_request_cookie(&mut self, collection_index: usize, request_index: usize, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            info!("Cookie deleted");
+            
+            selected_request.cookies.remove(row);
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
+    pub fn toggle_request_cookie(&mut self, collection_index: usize, request_index: usize, state: Option<bool>, row: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        
+        {
+            let mut selected_request = local_selected_request.write();
+            
+            let new_state = match state {
+                None => {
+                    let state = !selected_request.cookies[row].enabled;
+                    // Better user feedback
+                    println!("{state}");
+                    state
+                },
+                Some(state) => state
+            };
+
+            info!("Cookie state set to \"{new_state}\"");
+
+            selected_request.cookies[row].enabled = new_state;
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+}
\ No newline at end of file
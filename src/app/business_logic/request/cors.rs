@@ -0,0 +1,255 @@
+use reqwest::header::{HeaderName, HeaderValue, COOKIE, ORIGIN};
+use reqwest::Method;
+use reqwest_middleware::RequestBuilder;
+use tracing::info;
+
+use crate::models::response::{RequestResponse, TestResult};
+
+/// Origin of the simulated browser when neither the request nor the config gives one
+pub const DEFAULT_CORS_ORIGIN: &str = "http://localhost";
+
+/// Headers a browser sends without any preflight, the content-type only with the values below
+const SAFELISTED_HEADERS: [&str; 4] = ["accept", "accept-language", "content-language", "content-type"];
+
+const SAFELISTED_CONTENT_TYPES: [&str; 3] = ["application/x-www-form-urlencoded", "multipart/form-data", "text/plain"];
+
+/// Headers set by the browser itself, a script cannot send them
+const BROWSER_HEADERS: [&str; 19] = [
+    "accept-charset",
+    "accept-encoding",
+    "access-control-request-headers",
+    "access-control-request-method",
+    "connection",
+    "content-length",
+    "cookie",
+    "date",
+    "dnt",
+    "expect",
+    "host",
+    "keep-alive",
+    "origin",
+    "referer",
+    "te",
+    "trailer",
+    "transfer-encoding",
+    "upgrade",
+    "user-agent",
+];
+
+/// Request sent the way a browser would from another origin
+pub struct CorsSimulation {
+    pub origin: String,
+    pub method: String,
+    /// Non-safelisted headers, as listed in Access-Control-Request-Headers
+    pub request_headers: Vec<String>,
+    /// A Cookie header is sent, the wildcards are then refused
+    pub with_credentials: bool,
+    pub preflight: CorsPreflight,
+}
+
+pub enum CorsPreflight {
+    /// Simple request, sent without any preflight
+    NotNeeded,
+    Received { status: u16, headers: Vec<(String, String)> },
+    Failed(String),
+}
+
+/// Sends the OPTIONS preflight a browser would send first, if any, and returns the request with its Origin header.
+/// The origin of the request is kept when it already has one
+pub async fn send_cors_preflight(prepared_request: RequestBuilder, default_origin: &str) -> Result<(RequestBuilder, CorsSimulation), reqwest::Error> {
+    let (client, request) = prepared_request.build_split();
+    let mut request = request?;
+
+    let origin = match request.headers().get(ORIGIN).and_then(|origin| origin.to_str().ok()) {
+        Some(origin) => origin.to_string(),
+        None => default_origin.to_string()
+    };
+
+    if let Ok(origin) = HeaderValue::from_str(&origin) {
+        request.headers_mut().insert(ORIGIN, origin);
+    }
+
+    let method = request.method().to_string();
+    let with_credentials = request.headers().contains_key(COOKIE);
+
+    let mut request_headers: Vec<String> = request.headers()
+        .iter()
+        .filter(|(header_name, header_value)| !is_safelisted_header(header_name, header_value))
+        .map(|(header_name, _)| header_name.to_string())
+        .collect();
+
+    request_headers.sort();
+    request_headers.dedup();
+
+    let is_simple_method = matches!(request.method(), &Method::GET | &Method::HEAD | &Method::POST);
+
+    let preflight = match is_simple_method && request_headers.is_empty() {
+        true => CorsPreflight::NotNeeded,
+        false => {
+            info!("Sending CORS preflight from \"{origin}\"");
+
+            let mut preflight_request = client
+                .request(Method::OPTIONS, request.url().clone())
+                .header(ORIGIN, &origin)
+                .header("access-control-request-method", &method);
+
+            if !request_headers.is_empty() {
+                preflight_request = preflight_request.header("access-control-request-headers", request_headers.join(","));
+            }
+
+            match preflight_request.send().await {
+                Ok(preflight_response) => CorsPreflight::Received {
+                    status: preflight_response.status().as_u16(),
+                    headers: preflight_response.headers()
+                        .iter()
+                        .map(|(header_name, header_value)| (header_name.to_string(), header_value.to_str().unwrap_or("").to_string()))
+                        .collect(),
+                },
+                Err(error) => CorsPreflight::Failed(error.to_string())
+            }
+        }
+    };
+
+    let cors_simulation = CorsSimulation {
+        origin,
+        method,
+        request_headers,
+        with_credentials,
+        preflight,
+    };
+
+    return Ok((RequestBuilder::from_parts(client, request), cors_simulation));
+}
+
+fn is_safelisted_header(header_name: &HeaderName, header_value: &HeaderValue) -> bool {
+    let header_name = header_name.as_str();
+
+    if BROWSER_HEADERS.contains(&header_name) || header_name.starts_with("sec-") || header_name.starts_with("proxy-") {
+        return true;
+    }
+
+    if header_name == "content-type" {
+        let content_type = header_value.to_str().unwrap_or("");
+        let mime_type = content_type.split(';').next().unwrap_or("").trim().to_ascii_lowercase();
+
+        return SAFELISTED_CONTENT_TYPES.contains(&mime_type.as_str());
+    }
+
+    return SAFELISTED_HEADERS.contains(&header_name);
+}
+
+impl CorsSimulation {
+    /// Whether a browser would let a script send the request and read its response, as test results
+    pub fn get_test_results(&self, response: &RequestResponse) -> Vec<TestResult> {
+        let mut test_results: Vec<TestResult> = vec![];
+
+        let preflight_test_name = format!("CORS preflight from {}", self.origin);
+
+        match &self.preflight {
+            CorsPreflight::NotNeeded => test_results.push(TestResult::passed(format!("{preflight_test_name} (not needed)"))),
+            CorsPreflight::Failed(error) => test_results.push(TestResult::failed(preflight_test_name, vec![error.clone()])),
+            CorsPreflight::Received { status, headers } => {
+                let mut messages: Vec<String> = vec![];
+
+                if !(200..300).contains(status) {
+                    messages.push(format!("Preflight answered {status}, a 2xx status is expected"));
+                }
+
+                self.check_allowed_origin(headers, &mut messages);
+                self.check_allowed_method(headers, &mut messages);
+                self.check_allowed_headers(headers, &mut messages);
+
+                match messages.is_empty() {
+                    true => test_results.push(TestResult::passed(preflight_test_name)),
+                    false => test_results.push(TestResult::failed(preflight_test_name, messages))
+                }
+            }
+        }
+
+        let response_test_name = format!("CORS response readable from {}", self.origin);
+
+        // Canceled, timed out or not sent at all
+        let has_received_response = response.get_status_code().is_some();
+
+        if !has_received_response {
+            test_results.push(TestResult::failed(response_test_name, vec![String::from("No response received")]));
+            return test_results;
+        }
+
+        let mut messages: Vec<String> = vec![];
+
+        self.check_allowed_origin(&response.headers, &mut messages);
+
+        match messages.is_empty() {
+            true => test_results.push(TestResult::passed(response_test_name)),
+            false => test_results.push(TestResult::failed(response_test_name, messages))
+        }
+
+        return test_results;
+    }
+
+    fn check_allowed_origin(&self, headers: &[(String, String)], messages: &mut Vec<String>) {
+        match get_header(headers, "access-control-allow-origin") {
+            None => messages.push(String::from("No Access-Control-Allow-Origin header")),
+            Some("*") if self.with_credentials => messages.push(String::from("Access-Control-Allow-Origin is \"*\", refused for a request with cookies")),
+            Some("*") => {},
+            Some(allowed_origin) if allowed_origin == self.origin => {},
+            Some(allowed_origin) => messages.push(format!("Access-Control-Allow-Origin is \"{allowed_origin}\", \"{}\" is expected", self.origin))
+        }
+
+        if self.with_credentials && get_header(headers, "access-control-allow-credentials") != Some("true") {
+            messages.push(String::from("Access-Control-Allow-Credentials is not \"true\", required for a request with cookies"));
+        }
+    }
+
+    fn check_allowed_method(&self, headers: &[(String, String)], messages: &mut Vec<String>) {
+        if ["GET", "HEAD", "POST"].contains(&self.method.as_str()) {
+            return;
+        }
+
+        let allowed_methods = get_header_list(headers, "access-control-allow-methods");
+
+        let is_allowed = allowed_methods.contains(&self.method)
+            || (!self.with_credentials && allowed_methods.contains(&String::from("*")));
+
+        if !is_allowed {
+            messages.push(format!("Method {} missing from Access-Control-Allow-Methods", self.method));
+        }
+    }
+
+    fn check_allowed_headers(&self, headers: &[(String, String)], messages: &mut Vec<String>) {
+        let allowed_headers: Vec<String> = get_header_list(headers, "access-control-allow-headers")
+            .iter()
+            .map(|allowed_header| allowed_header.to_lowercase())
+            .collect();
+
+        let allows_any_header = !self.with_credentials && allowed_headers.contains(&String::from("*"));
+
+        for request_header in &self.request_headers {
+            // The wildcard never covers the Authorization header
+            let is_allowed = allowed_headers.contains(request_header) || (allows_any_header && request_header != "authorization");
+
+            if !is_allowed {
+                messages.push(format!("Header {request_header} missing from Access-Control-Allow-Headers"));
+            }
+        }
+    }
+}
+
+fn get_header<'a>(headers: &'a [(String, String)], header_name: &str) -> Option<&'a str> {
+    return headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(header_name))
+        .map(|(_, value)| value.trim());
+}
+
+/// Comma separated values of a header, possibly sent several times
+fn get_header_list(headers: &[(String, String)], header_name: &str) -> Vec<String> {
+    return headers
+        .iter()
+        .filter(|(name, _)| name.eq_ignore_ascii_case(header_name))
+        .flat_map(|(_, value)| value.split(','))
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+        .collect();
+}
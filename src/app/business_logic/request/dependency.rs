@@ -0,0 +1,29 @@
+use anyhow::anyhow;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::dependency::ExecutionPlan;
+
+impl App<'_> {
+    /// The dependencies must be requests of the same collection, without creating a cycle
+    pub fn modify_request_dependencies(&mut self, collection_index: usize, request_index: usize, depends_on: Vec<String>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        let previous_depends_on = std::mem::replace(&mut local_selected_request.write().depends_on, depends_on.clone());
+
+        // The lock of the request is released, the plan reads every request of the collection
+        if let Err(error) = ExecutionPlan::new(&self.collections[collection_index].requests) {
+            local_selected_request.write().depends_on = previous_depends_on;
+            return Err(anyhow!(error));
+        }
+
+        match depends_on.is_empty() {
+            true => info!("Request dependencies removed"),
+            false => info!("Request dependencies set to {}", depends_on.join(", "))
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
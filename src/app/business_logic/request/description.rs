@@ -0,0 +1,28 @@
+use tracing::info;
+
+use crate::app::app::App;
+
+impl App<'_> {
+    /// A blank description removes it
+    pub fn modify_request_description(&mut self, collection_index: usize, request_index: usize, description: Option<String>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            selected_request.description = match description {
+                Some(description) if !description.trim().is_empty() => {
+                    info!("Request description set");
+                    Some(description)
+                },
+                _ => {
+                    info!("Request description removed");
+                    None
+                }
+            };
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use reqwest::dns::{Addrs, Name, Resolve, Resolving};
+use reqwest::header::{ACCEPT, CONTENT_TYPE};
+use reqwest::Url;
+use tokio::net::UdpSocket;
+use tracing::trace;
+
+use crate::models::dns::{DnsResolution, DnsServer};
+use crate::models::ip_version::IpVersion;
+
+/// Content type of the DNS-over-HTTPS queries and answers
+const DNS_MESSAGE_CONTENT_TYPE: &str = "application/dns-message";
+
+const DNS_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Record types of the queries
+const A_RECORD: u16 = 1;
+const AAAA_RECORD: u16 = 28;
+
+lazy_static! {
+    /// Last resolution of each host name, displayed with the responses
+    static ref DNS_RESOLUTIONS: RwLock<HashMap<String, DnsResolution>> = RwLock::new(HashMap::new());
+
+    /// Sends the DNS-over-HTTPS queries, the endpoint itself is resolved by the system
+    static ref DOH_CLIENT: reqwest::Client = reqwest::Client::new();
+}
+
+/// None when the host was not resolved by a RequestResolver, e.g. with the system resolver and no IP version
+pub fn get_last_dns_resolution(host: &str) -> Option<DnsResolution> {
+    return DNS_RESOLUTIONS.read().get(host).cloned();
+}
+
+pub fn clear_dns_resolutions() {
+    DNS_RESOLUTIONS.write().clear();
+}
+
+/// Resolves the host names with the DNS server of the config, keeping the addresses of the IP version only
+pub struct RequestResolver {
+    pub dns_server: DnsServer,
+    pub ip_version: IpVersion,
+}
+
+impl Resolve for RequestResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let dns_server = self.dns_server.clone();
+        let ip_version = self.ip_version;
+        let host = name.as_str().to_string();
+
+        Box::pin(async move {
+            let resolution_start = Instant::now();
+
+            let addresses: Vec<IpAddr> = match &dns_server {
+                DnsServer::System => tokio::net::lookup_host((host.as_str(), 0))
+                    .await?
+                    .map(|address| address.ip())
+                    .collect(),
+                DnsServer::Nameserver(_) | DnsServer::DnsOverHttps(_) => {
+                    let mut addresses: Vec<IpAddr> = vec![];
+
+                    for record_type in [A_RECORD, AAAA_RECORD] {
+                        let is_wanted = match record_type {
+                            A_RECORD => ip_version != IpVersion::Ipv6,
+                            _ => ip_version != IpVersion::Ipv4
+                        };
+
+                        if is_wanted {
+                            addresses.extend(query_dns_server(&dns_server, &host, record_type).await?);
+                        }
+                    }
+
+                    addresses
+                }
+            };
+
+            let addresses: Vec<IpAddr> = addresses
+                .into_iter()
+                .filter(|address| ip_version.accepts(address))
+                .collect();
+
+            if addresses.is_empty() {
+                return Err(format!("No {} address found for \"{host}\" with the {dns_server}", ip_version_name(ip_version)).into());
+            }
+
+            trace!("\"{host}\" resolved to {addresses:?}");
+
+            let dns_resolution = DnsResolution {
+                addresses: addresses.clone(),
+                server: dns_server.to_string(),
+                duration: resolution_start.elapsed(),
+            };
+
+            DNS_RESOLUTIONS.write().insert(host, dns_resolution);
+
+            // The port is replaced by the one of the URL
+            let socket_addresses: Vec<SocketAddr> = addresses
+                .into_iter()
+                .map(|address| SocketAddr::new(address, 0))
+                .collect();
+
+            Ok(Box::new(socket_addresses.into_iter()) as Addrs)
+        })
+    }
+}
+
+fn ip_version_name(ip_version: IpVersion) -> String {
+    return match ip_version {
+        IpVersion::Any => String::from("IP"),
+        ip_version => ip_version.to_string()
+    };
+}
+
+async fn query_dns_server(dns_server: &DnsServer, host: &str, record_type: u16) -> Result<Vec<IpAddr>, String> {
+    // DNS-over-HTTPS queries use the ID 0, so that they can be cached (RFC 8484)
+    let id = match dns_server {
+        DnsServer::DnsOverHttps(_) => 0,
+        _ => SystemTime::now().duration_since(UNIX_EPOCH).map(|time| time.subsec_nanos() as u16).unwrap_or(1)
+    };
+
+    let query = build_dns_query(id, host, record_type)?;
+
+    let answer = match dns_server {
+        DnsServer::System => return Ok(vec![]),
+        DnsServer::Nameserver(address) => send_udp_query(*address, &query).await?,
+        DnsServer::DnsOverHttps(url) => send_doh_query(url, query).await?
+    };
+
+    return parse_dns_answer(&answer, id, record_type);
+}
+
+async fn send_udp_query(address: SocketAddr, query: &[u8]) -> Result<Vec<u8>, String> {
+    let local_address: SocketAddr = match address {
+        SocketAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+        SocketAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into()
+    };
+
+    let socket = UdpSocket::bind(local_address).await.map_err(|error| error.to_string())?;
+    socket.send_to(query, address).await.map_err(|error| format!("Could not query {address}: {error}"))?;
+
+    let mut buffer = vec![0u8; 4096];
+
+    let length = match tokio::time::timeout(DNS_TIMEOUT, socket.recv(&mut buffer)).await {
+        Ok(Ok(length)) => length,
+        Ok(Err(error)) => return Err(format!("Could not query {address}: {error}")),
+        Err(_) => return Err(format!("{address} did not answer"))
+    };
+
+    buffer.truncate(length);
+
+    return Ok(buffer);
+}
+
+async fn send_doh_query(url: &Url, query: Vec<u8>) -> Result<Vec<u8>, String> {
+    let response = DOH_CLIENT
+        .post(url.clone())
+        .header(CONTENT_TYPE, DNS_MESSAGE_CONTENT_TYPE)
+        .header(ACCEPT, DNS_MESSAGE_CONTENT_TYPE)
+        .timeout(DNS_TIMEOUT)
+        .body(query)
+        .send()
+        .await
+        .map_err(|error| format!("Could not query {url}: {error}"))?;
+
+    if !response.status().is_success() {
+        return Err(format!("{url} answered {}", response.status()));
+    }
+
+    let answer = response.bytes().await.map_err(|error| error.to_string())?;
+
+    return Ok(answer.to_vec());
+}
+
+/// Recursive query of a single question, in the DNS wire format (RFC 1035)
+fn build_dns_query(id: u16, host: &str, record_type: u16) -> Result<Vec<u8>, String> {
+    let mut query: Vec<u8> = vec![];
+
+    query.extend(id.to_be_bytes());
+    // Recursion desired
+    query.extend(0x0100u16.to_be_bytes());
+    // 1 question, no answer, authority or additional records
+    query.extend([0, 1, 0, 0, 0, 0, 0, 0]);
+
+    for label in host.trim_end_matches('.').split('.') {
+        if label.is_empty() || label.len() > 63 {
+            return Err(format!("Invalid host name \"{host}\""));
+        }
+
+        query.push(label.len() as u8);
+        query.extend(label.as_bytes());
+    }
+
+    query.push(0);
+    query.extend(record_type.to_be_bytes());
+    // Internet class
+    query.extend(1u16.to_be_bytes());
+
+    return Ok(query);
+}
+
+/// Addresses of the records of the answer, the CNAME records are skipped
+fn parse_dns_answer(answer: &[u8], id: u16, record_type: u16) -> Result<Vec<IpAddr>, String> {
+    let invalid_answer = || String::from("Invalid DNS answer");
+
+    if answer.len() < 12 || u16::from_be_bytes([answer[0], answer[1]]) != id {
+        return Err(invalid_answer());
+    }
+
+    let flags = u16::from_be_bytes([answer[2], answer[3]]);
+
+    match flags & 0x000F {
+        0 => {},
+        3 => return Ok(vec![]),
+        response_code => return Err(format!("DNS error code {response_code}"))
+    }
+
+    if flags & 0x0200 != 0 {
+        return Err(String::from("Truncated DNS answer"));
+    }
+
+    let question_count = u16::from_be_bytes([answer[4], answer[5]]);
+    let answer_count = u16::from_be_bytes([answer[6], answer[7]]);
+
+    let mut position = 12;
+
+    for _ in 0..question_count {
+        position = skip_dns_name(answer, position).ok_or_else(invalid_answer)? + 4;
+    }
+
+    let mut addresses: Vec<IpAddr> = vec![];
+
+    for _ in 0..answer_count {
+        position = skip_dns_name(answer, position).ok_or_else(invalid_answer)?;
+
+        let header = answer.get(position..position + 10).ok_or_else(invalid_answer)?;
+        let answer_type = u16::from_be_bytes([header[0], header[1]]);
+        let data_length = u16::from_be_bytes([header[8], header[9]]) as usize;
+
+        position += 10;
+
+        let data = answer.get(position..position + data_length).ok_or_else(invalid_answer)?;
+
+        match (answer_type, data_length) {
+            (A_RECORD, 4) if record_type == A_RECORD => addresses.push(IpAddr::from([data[0], data[1], data[2], data[3]])),
+            (AAAA_RECORD, 16) if record_type == AAAA_RECORD => {
+                let mut octets = [0u8; 16];
+                octets.copy_from_slice(data);
+                addresses.push(IpAddr::from(octets));
+            },
+            _ => {}
+        }
+
+        position += data_length;
+    }
+
+    return Ok(addresses);
+}
+
+/// Position after the name, which ends with an empty label or a compression pointer
+fn skip_dns_name(answer: &[u8], mut position: usize) -> Option<usize> {
+    loop {
+        let length = *answer.get(position)?;
+
+        match length {
+            0 => return Some(position + 1),
+            length if length & 0xC0 == 0xC0 => return Some(position + 2),
+            length => position += length as usize + 1
+        }
+    }
+}
@@ -55,6 +55,35 @@ impl App<'_> {
         Ok(())
     }
 
+    /// Modify and enable the User-Agent header, or create it if the request does not have one
+    pub fn set_request_user_agent(&mut self, collection_index: usize, request_index: usize, user_agent: String) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            info!("User-Agent set to \"{user_agent}\"");
+
+            let user_agent_header = selected_request.headers
+                .iter_mut()
+                .find(|header| header.data.0.eq_ignore_ascii_case("user-agent"));
+
+            match user_agent_header {
+                Some(header) => {
+                    header.enabled = true;
+                    header.data.1 = user_agent;
+                },
+                None => selected_request.headers.push(KeyValue {
+                    enabled: true,
+                    data: (String::from("user-agent"), user_agent)
+                })
+            }
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+
     pub fn delete_header(&mut self, collection_index: usize, request_index: usize, row: usize) -> anyhow::Result<()> {
         let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
 
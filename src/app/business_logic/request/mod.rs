@@ -1,9 +1,34 @@
 pub(super) mod utils;
 pub mod send;
 pub mod url;
+pub mod description;
 pub mod method;
 pub mod query_params;
 pub mod auth;
 pub mod headers;
+pub mod cookies;
 pub mod body;
 pub mod scripts;
+pub mod schema;
+pub mod snapshot;
+pub mod preview;
+pub mod ntlm;
+pub mod oauth1;
+pub mod upload;
+pub mod protobuf;
+pub mod cors;
+pub mod throttle;
+pub mod network_conditions;
+pub mod response_processor;
+pub mod soap;
+pub mod pagination;
+pub mod concurrency;
+pub mod client_pool;
+pub mod dns;
+pub mod response_body;
+pub mod raw_socket;
+pub mod assertion;
+pub mod tags;
+pub mod skip;
+pub mod dependency;
+pub mod think_time;
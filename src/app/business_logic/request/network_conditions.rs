@@ -0,0 +1,115 @@
+use std::future::Future;
+use std::io;
+use std::pin::Pin;
+use std::task::{ready, Context, Poll};
+use std::time::{Duration, Instant};
+
+use reqwest::header::{HeaderValue, CONTENT_LENGTH};
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::time::Sleep;
+use tokio_util::io::ReaderStream;
+
+/// Keeps a transfer under a bandwidth, the bytes are counted from the first one
+pub struct BandwidthLimiter {
+    bytes_per_second: u64,
+    started_at: Option<Instant>,
+    transferred: u64,
+}
+
+impl BandwidthLimiter {
+    /// None when the limit is 0, in KiB/s
+    pub fn new(kib_per_second: usize) -> Option<BandwidthLimiter> {
+        return match kib_per_second {
+            0 => None,
+            kib_per_second => Some(BandwidthLimiter {
+                bytes_per_second: kib_per_second as u64 * 1024,
+                started_at: None,
+                transferred: 0,
+            })
+        };
+    }
+
+    /// Bytes read at once, the waits stay short and the rate even
+    pub fn get_chunk_size(&self) -> usize {
+        return u64::max(self.bytes_per_second / 10, 1) as usize;
+    }
+
+    /// Count the transferred bytes, returns the time the transfer can go on at when it is ahead of the bandwidth
+    pub fn record(&mut self, length: usize) -> Option<Instant> {
+        let started_at = *self.started_at.get_or_insert_with(Instant::now);
+        self.transferred += length as u64;
+
+        let due_at = started_at + Duration::from_secs_f64(self.transferred as f64 / self.bytes_per_second as f64);
+
+        return match due_at > Instant::now() {
+            true => Some(due_at),
+            false => None
+        };
+    }
+
+    pub async fn wait(&mut self, length: usize) {
+        if let Some(due_at) = self.record(length) {
+            tokio::time::sleep_until(due_at.into()).await;
+        }
+    }
+}
+
+/// Reads the body under the upload bandwidth, the client sends it as it reads it
+pub struct ThrottledReader<R> {
+    inner: R,
+    bandwidth_limiter: BandwidthLimiter,
+    delay: Option<Pin<Box<Sleep>>>,
+}
+
+impl<R> ThrottledReader<R> {
+    pub fn new(inner: R, bandwidth_limiter: BandwidthLimiter) -> ThrottledReader<R> {
+        ThrottledReader {
+            inner,
+            bandwidth_limiter,
+            delay: None,
+        }
+    }
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ThrottledReader<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        if let Some(delay) = self.delay.as_mut() {
+            ready!(delay.as_mut().poll(cx));
+            self.delay = None;
+        }
+
+        let chunk_size = usize::min(buf.remaining(), self.bandwidth_limiter.get_chunk_size());
+        let mut chunk = vec![0u8; chunk_size];
+        let mut chunk_buf = ReadBuf::new(&mut chunk);
+
+        ready!(Pin::new(&mut self.inner).poll_read(cx, &mut chunk_buf))?;
+
+        let read_length = chunk_buf.filled().len();
+        buf.put_slice(chunk_buf.filled());
+
+        if let Some(due_at) = self.bandwidth_limiter.record(read_length) {
+            self.delay = Some(Box::pin(tokio::time::sleep_until(due_at.into())));
+        }
+
+        return Poll::Ready(Ok(()));
+    }
+}
+
+/// Replace an in-memory body by a stream sent under the upload bandwidth, the streamed bodies are throttled when opened
+pub fn throttle_request_body(request: &mut reqwest::Request, upload_limit: usize) {
+    let bandwidth_limiter = match BandwidthLimiter::new(upload_limit) {
+        None => return,
+        Some(bandwidth_limiter) => bandwidth_limiter
+    };
+
+    let body = match request.body().and_then(|body| body.as_bytes()) {
+        None => return,
+        Some(body) => body.to_vec()
+    };
+
+    // The length is known, no need for a chunked upload
+    request.headers_mut().insert(CONTENT_LENGTH, HeaderValue::from(body.len()));
+
+    let throttled_reader = ThrottledReader::new(io::Cursor::new(body), bandwidth_limiter);
+    *request.body_mut() = Some(reqwest::Body::wrap_stream(ReaderStream::new(throttled_reader)));
+}
@@ -0,0 +1,378 @@
+use anyhow::anyhow;
+use async_trait::async_trait;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use http::Extensions;
+use md4::{Digest, Md4};
+use md5::Md5;
+use reqwest::{Request, Response, StatusCode};
+use reqwest::header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, HeaderValue, WWW_AUTHENTICATE};
+use reqwest_middleware::{Middleware, Next};
+use ring::rand::{SecureRandom, SystemRandom};
+use thiserror::Error;
+use tracing::{trace, warn};
+
+use crate::app::business_logic::request::ntlm::NtlmError::{InvalidChallenge, InvalidHeader};
+
+const SIGNATURE: &[u8; 8] = b"NTLMSSP\0";
+
+const NEGOTIATE_UNICODE: u32 = 0x00000001;
+const REQUEST_TARGET: u32 = 0x00000004;
+const NEGOTIATE_NTLM: u32 = 0x00000200;
+const NEGOTIATE_ALWAYS_SIGN: u32 = 0x00008000;
+const NEGOTIATE_EXTENDED_SESSION_SECURITY: u32 = 0x00080000;
+const NEGOTIATE_128: u32 = 0x20000000;
+const NEGOTIATE_56: u32 = 0x80000000;
+
+const NEGOTIATE_FLAGS: u32 = NEGOTIATE_UNICODE | REQUEST_TARGET | NEGOTIATE_NTLM | NEGOTIATE_ALWAYS_SIGN | NEGOTIATE_EXTENDED_SESSION_SECURITY | NEGOTIATE_128 | NEGOTIATE_56;
+
+/// Pair id of the server time in the target info of the challenge
+const AV_TIMESTAMP: u16 = 7;
+/// Seconds between 1601-01-01, start of the Windows file times, and 1970-01-01
+const FILETIME_UNIX_OFFSET: i64 = 11_644_473_600;
+
+#[derive(Error, Debug)]
+pub enum NtlmError {
+    #[error("Invalid NTLM challenge message")]
+    InvalidChallenge,
+    #[error("Invalid Authorization header")]
+    InvalidHeader,
+}
+
+/// Authorization scheme of the handshake, a "Negotiate" challenge would expect a SPNEGO token which is not supported
+const SCHEME: &str = "NTLM";
+
+/// Answers the challenge of the server, the handshake requests go through the same client so the connection is kept
+pub struct NtlmMiddleware {
+    pub username: String,
+    pub password: String,
+    pub domain: String,
+}
+
+#[async_trait]
+impl Middleware for NtlmMiddleware {
+    async fn handle(&self, request: Request, extensions: &mut Extensions, next: Next<'_>) -> reqwest_middleware::Result<Response> {
+        // Bodiless requests start with the handshake, the negotiate response is kept when the server does not challenge
+        if request.body().is_none() {
+            let negotiate_response = next.clone().run(self.get_negotiate_request(&request)?, extensions).await?;
+
+            return match self.get_challenge(&negotiate_response) {
+                Some(challenge) => self.send_authenticate_message(request, negotiate_response, &challenge, extensions, next).await,
+                None => Ok(negotiate_response)
+            };
+        }
+
+        // Sent as is first, a server not asking for NTLM receives the body once
+        let retry_request = request.try_clone();
+        let response = next.clone().run(request, extensions).await?;
+
+        if !is_ntlm_requested(&response) {
+            return Ok(response);
+        }
+
+        let request = match retry_request {
+            Some(request) => request,
+            None => {
+                warn!("Could not answer the {SCHEME} challenge, the streamed body of the request cannot be sent again");
+                return Ok(response);
+            }
+        };
+
+        // Read until the end so the connection can be reused for the handshake
+        response.bytes().await.ok();
+
+        let negotiate_response = next.clone().run(self.get_negotiate_request(&request)?, extensions).await?;
+
+        return match self.get_challenge(&negotiate_response) {
+            Some(challenge) => self.send_authenticate_message(request, negotiate_response, &challenge, extensions, next).await,
+            None => Ok(negotiate_response)
+        };
+    }
+}
+
+impl NtlmMiddleware {
+    /// Same request holding the negotiate message, the body is only sent once authenticated as curl does
+    fn get_negotiate_request(&self, request: &Request) -> reqwest_middleware::Result<Request> {
+        let mut negotiate_request = Request::new(request.method().clone(), request.url().clone());
+        *negotiate_request.headers_mut() = request.headers().clone();
+        negotiate_request.headers_mut().remove(CONTENT_TYPE);
+        negotiate_request.headers_mut().remove(CONTENT_LENGTH);
+        negotiate_request.headers_mut().insert(AUTHORIZATION, self.get_header_value(&get_negotiate_message())?);
+
+        trace!("Sending {SCHEME} negotiate message");
+
+        return Ok(negotiate_request);
+    }
+
+    async fn send_authenticate_message(&self, mut request: Request, negotiate_response: Response, challenge: &[u8], extensions: &mut Extensions, next: Next<'_>) -> reqwest_middleware::Result<Response> {
+        // Read until the end so the connection can be reused for the authenticate message
+        negotiate_response.bytes().await.ok();
+
+        let authenticate_message = get_authenticate_message(challenge, &self.username, &self.password, &self.domain)
+            .map_err(reqwest_middleware::Error::Middleware)?;
+
+        request.headers_mut().insert(AUTHORIZATION, self.get_header_value(&authenticate_message)?);
+
+        trace!("Sending {SCHEME} authenticate message");
+
+        return next.run(request, extensions).await;
+    }
+
+    fn get_header_value(&self, message: &[u8]) -> reqwest_middleware::Result<HeaderValue> {
+        return HeaderValue::from_str(&format!("{SCHEME} {}", STANDARD.encode(message)))
+            .map_err(|_| reqwest_middleware::Error::Middleware(anyhow!(InvalidHeader)));
+    }
+
+    /// Challenge message of the NTLM WWW-Authenticate header of a 401 response
+    fn get_challenge(&self, response: &Response) -> Option<Vec<u8>> {
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return None;
+        }
+
+        let prefix = format!("{} ", SCHEME.to_lowercase());
+
+        return response.headers()
+            .get_all(WWW_AUTHENTICATE)
+            .iter()
+            .filter_map(|header_value| header_value.to_str().ok())
+            .find(|header_value| header_value.to_lowercase().starts_with(&prefix))
+            .and_then(|header_value| STANDARD.decode(header_value[prefix.len()..].trim()).ok());
+    }
+}
+
+/// 401 response offering the NTLM scheme, among others
+fn is_ntlm_requested(response: &Response) -> bool {
+    if response.status() != StatusCode::UNAUTHORIZED {
+        return false;
+    }
+
+    return response.headers()
+        .get_all(WWW_AUTHENTICATE)
+        .iter()
+        .filter_map(|header_value| header_value.to_str().ok())
+        .any(|header_value| header_value.split_whitespace().next().is_some_and(|scheme| scheme.eq_ignore_ascii_case(SCHEME)));
+}
+
+pub fn get_negotiate_message() -> Vec<u8> {
+    let mut message = SIGNATURE.to_vec();
+
+    message.extend_from_slice(&1u32.to_le_bytes());
+    message.extend_from_slice(&NEGOTIATE_FLAGS.to_le_bytes());
+    // Empty domain and workstation
+    message.extend_from_slice(&[0; 16]);
+
+    return message;
+}
+
+/// NTLMv2 response to the challenge message, looked up from its signature
+pub fn get_authenticate_message(challenge: &[u8], username: &str, password: &str, domain: &str) -> anyhow::Result<Vec<u8>> {
+    let start = challenge
+        .windows(SIGNATURE.len())
+        .position(|window| window == SIGNATURE)
+        .ok_or(anyhow!(InvalidChallenge))?;
+
+    let challenge = &challenge[start..];
+
+    if challenge.len() < 32 || read_u32(challenge, 8) != Some(2) {
+        return Err(anyhow!(InvalidChallenge));
+    }
+
+    let server_challenge = &challenge[24..32];
+
+    let target_info = match challenge.len() >= 48 {
+        true => read_security_buffer(challenge, 40).ok_or(anyhow!(InvalidChallenge))?,
+        false => &[]
+    };
+
+    let server_timestamp = get_target_info_timestamp(target_info);
+
+    let timestamp = match server_timestamp {
+        Some(timestamp) => timestamp,
+        None => ((Utc::now().timestamp() + FILETIME_UNIX_OFFSET) * 10_000_000).to_le_bytes()
+    };
+
+    let mut client_challenge = [0u8; 8];
+    SystemRandom::new().fill(&mut client_challenge).expect("Could not generate the NTLM client challenge");
+
+    let nt_owf_v2 = get_nt_owf_v2(username, password, domain);
+    let nt_response = get_nt_response(&nt_owf_v2, server_challenge, &timestamp, &client_challenge, target_info);
+
+    // The LMv2 response must be left empty when the server gave its time
+    let lm_response = match server_timestamp {
+        Some(_) => vec![0; 24],
+        None => [hmac_md5(&nt_owf_v2, &[server_challenge, &client_challenge].concat()), client_challenge.to_vec()].concat()
+    };
+
+    let payloads = [
+        lm_response,
+        nt_response,
+        to_utf16(domain),
+        to_utf16(username),
+        // Workstation
+        vec![],
+        // Encrypted random session key
+        vec![],
+    ];
+
+    let mut message = SIGNATURE.to_vec();
+    message.extend_from_slice(&3u32.to_le_bytes());
+
+    // Payloads come after the 6 security buffers and the flags
+    let mut offset = 12 + 8 * payloads.len() as u32 + 4;
+
+    for payload in &payloads {
+        message.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+        message.extend_from_slice(&(payload.len() as u16).to_le_bytes());
+        message.extend_from_slice(&offset.to_le_bytes());
+
+        offset += payload.len() as u32;
+    }
+
+    message.extend_from_slice(&NEGOTIATE_FLAGS.to_le_bytes());
+
+    for payload in &payloads {
+        message.extend_from_slice(payload);
+    }
+
+    return Ok(message);
+}
+
+fn get_nt_owf_v2(username: &str, password: &str, domain: &str) -> Vec<u8> {
+    let nt_hash = Md4::digest(to_utf16(password));
+
+    return hmac_md5(&nt_hash, &to_utf16(&format!("{}{domain}", username.to_uppercase())));
+}
+
+/// NT proof followed by the blob it signs
+fn get_nt_response(nt_owf_v2: &[u8], server_challenge: &[u8], timestamp: &[u8; 8], client_challenge: &[u8; 8], target_info: &[u8]) -> Vec<u8> {
+    let mut blob = vec![1, 1, 0, 0, 0, 0, 0, 0];
+    blob.extend_from_slice(timestamp);
+    blob.extend_from_slice(client_challenge);
+    blob.extend_from_slice(&[0; 4]);
+    blob.extend_from_slice(target_info);
+    blob.extend_from_slice(&[0; 4]);
+
+    let nt_proof = hmac_md5(nt_owf_v2, &[server_challenge, &blob].concat());
+
+    return [nt_proof, blob].concat();
+}
+
+fn get_target_info_timestamp(target_info: &[u8]) -> Option<[u8; 8]> {
+    let mut index = 0;
+
+    while index + 4 <= target_info.len() {
+        let av_id = u16::from_le_bytes([target_info[index], target_info[index + 1]]);
+        let av_length = u16::from_le_bytes([target_info[index + 2], target_info[index + 3]]) as usize;
+
+        let value = target_info.get(index + 4..index + 4 + av_length)?;
+
+        match av_id {
+            // End of the list
+            0 => return None,
+            AV_TIMESTAMP => return value.try_into().ok(),
+            _ => index += 4 + av_length
+        }
+    }
+
+    return None;
+}
+
+fn read_u32(bytes: &[u8], index: usize) -> Option<u32> {
+    return bytes
+        .get(index..index + 4)
+        .map(|bytes| u32::from_le_bytes(bytes.try_into().unwrap()));
+}
+
+fn read_security_buffer(message: &[u8], index: usize) -> Option<&[u8]> {
+    let length = u16::from_le_bytes(message.get(index..index + 2)?.try_into().ok()?) as usize;
+    let offset = read_u32(message, index + 4)? as usize;
+
+    return message.get(offset..offset + length);
+}
+
+fn to_utf16(text: &str) -> Vec<u8> {
+    return text
+        .encode_utf16()
+        .flat_map(|unit| unit.to_le_bytes())
+        .collect();
+}
+
+fn hmac_md5(key: &[u8], data: &[u8]) -> Vec<u8> {
+    let mut mac = Hmac::<Md5>::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(data);
+
+    return mac.finalize().into_bytes().to_vec();
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::app::business_logic::encoding::to_hex;
+
+    use super::*;
+
+    const SERVER_CHALLENGE: [u8; 8] = [0x01, 0x23, 0x45, 0x67, 0x89, 0xab, 0xcd, 0xef];
+
+    fn to_av_pair(av_id: u16, value: &[u8]) -> Vec<u8> {
+        return [&av_id.to_le_bytes()[..], &(value.len() as u16).to_le_bytes(), value].concat();
+    }
+
+    /// Type 2 message with the target info as its only payload
+    fn get_challenge_message(target_info: &[u8]) -> Vec<u8> {
+        let mut message = SIGNATURE.to_vec();
+        message.extend_from_slice(&2u32.to_le_bytes());
+        // Empty target name
+        message.extend_from_slice(&[0, 0, 0, 0, 48, 0, 0, 0]);
+        message.extend_from_slice(&NEGOTIATE_FLAGS.to_le_bytes());
+        message.extend_from_slice(&SERVER_CHALLENGE);
+        // Reserved
+        message.extend_from_slice(&[0; 8]);
+        message.extend_from_slice(&(target_info.len() as u16).to_le_bytes());
+        message.extend_from_slice(&(target_info.len() as u16).to_le_bytes());
+        message.extend_from_slice(&48u32.to_le_bytes());
+        message.extend_from_slice(target_info);
+
+        return message;
+    }
+
+    /// MS-NLMP 4.2.4 NTLMv2 authentication test vectors
+    #[test]
+    fn nt_owf_v2_and_nt_proof() {
+        let nt_owf_v2 = get_nt_owf_v2("User", "Password", "Domain");
+
+        assert_eq!(to_hex(&nt_owf_v2), "0c868a403bfd7a93a3001ef22ef02e3f");
+
+        let target_info = [to_av_pair(2, &to_utf16("Domain")), to_av_pair(1, &to_utf16("Server")), to_av_pair(0, &[])].concat();
+        let nt_response = get_nt_response(&nt_owf_v2, &SERVER_CHALLENGE, &[0; 8], &[0xaa; 8], &target_info);
+
+        assert_eq!(to_hex(&nt_response[..16]), "68cd0ab851e51c96aabc927bebef6a1c");
+    }
+
+    #[test]
+    fn authenticate_message_answers_the_challenge() {
+        let server_timestamp = 133_000_000_000_000_000u64.to_le_bytes();
+        let target_info = [to_av_pair(AV_TIMESTAMP, &server_timestamp), to_av_pair(0, &[])].concat();
+
+        let message = get_authenticate_message(&get_challenge_message(&target_info), "User", "Password", "Domain").unwrap();
+
+        assert_eq!(&message[..8], SIGNATURE);
+        assert_eq!(read_u32(&message, 8), Some(3));
+
+        // The server gave its time
+        assert_eq!(read_security_buffer(&message, 12), Some(&[0u8; 24][..]));
+        assert_eq!(read_security_buffer(&message, 36), Some(&to_utf16("User")[..]));
+
+        let nt_response = read_security_buffer(&message, 20).unwrap();
+        let (nt_proof, blob) = nt_response.split_at(16);
+
+        assert_eq!(&blob[8..16], &server_timestamp);
+        assert_eq!(nt_proof, hmac_md5(&get_nt_owf_v2("User", "Password", "Domain"), &[&SERVER_CHALLENGE[..], blob].concat()));
+    }
+
+    #[test]
+    fn invalid_challenge() {
+        assert!(get_authenticate_message(b"not a challenge", "User", "Password", "Domain").is_err());
+        assert!(get_authenticate_message(&get_negotiate_message(), "User", "Password", "Domain").is_err());
+    }
+}
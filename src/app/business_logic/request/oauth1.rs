@@ -0,0 +1,195 @@
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use chrono::Utc;
+use percent_encoding::utf8_percent_encode;
+use reqwest::Url;
+use ring::hmac;
+use ring::rand::{SecureRandom, SystemRandom};
+use rsa::RsaPrivateKey;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::signature::{SignatureEncoding, Signer};
+use sha1::Sha1;
+use thiserror::Error;
+
+use crate::app::business_logic::encoding::{to_hex, URL_ENCODE_SET};
+use crate::app::business_logic::request::oauth1::OAuth1Error::{CouldNotReadPrivateKey, InvalidPrivateKey};
+use crate::models::auth::OAuth1SignatureMethod;
+use crate::models::method::Method;
+
+#[derive(Error, Debug)]
+pub enum OAuth1Error {
+    #[error("Could not read the OAuth private key file\n\t{0}")]
+    CouldNotReadPrivateKey(String),
+    #[error("Invalid OAuth RSA private key, PKCS#1 or PKCS#8 PEM expected\n\t{0}")]
+    InvalidPrivateKey(String),
+}
+
+/// OAuth credentials of a request, its environment values already substituted
+pub struct OAuth1Config {
+    pub consumer_key: String,
+    pub consumer_secret: String,
+    pub token: String,
+    pub token_secret: String,
+    pub signature_method: OAuth1SignatureMethod,
+}
+
+/// OAuth params, signature included, of a request.
+/// The signature covers the method, the URL, its query params and the form body params.
+pub fn get_oauth1_params(config: &OAuth1Config, method: &Method, url: &Url, form_params: &[(String, String)]) -> anyhow::Result<Vec<(String, String)>> {
+    let mut nonce = [0u8; 16];
+    SystemRandom::new().fill(&mut nonce).expect("Could not generate the OAuth nonce");
+
+    return sign_oauth1_params(config, method, url, form_params, &to_hex(&nonce), Utc::now().timestamp());
+}
+
+fn sign_oauth1_params(config: &OAuth1Config, method: &Method, url: &Url, form_params: &[(String, String)], nonce: &str, timestamp: i64) -> anyhow::Result<Vec<(String, String)>> {
+    let mut oauth_params = vec![
+        (String::from("oauth_consumer_key"), config.consumer_key.clone()),
+        (String::from("oauth_nonce"), nonce.to_string()),
+        (String::from("oauth_signature_method"), config.signature_method.to_string()),
+        (String::from("oauth_timestamp"), timestamp.to_string()),
+    ];
+
+    // Two-legged requests have no token
+    if !config.token.is_empty() {
+        oauth_params.push((String::from("oauth_token"), config.token.clone()));
+    }
+
+    oauth_params.push((String::from("oauth_version"), String::from("1.0")));
+
+    let mut encoded_params: Vec<(String, String)> = url.query_pairs()
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .chain(form_params.iter().cloned())
+        .chain(oauth_params.iter().cloned())
+        .map(|(key, value)| (encode(&key), encode(&value)))
+        .collect();
+
+    encoded_params.sort();
+
+    let normalized_params = encoded_params
+        .iter()
+        .map(|(key, value)| format!("{key}={value}"))
+        .collect::<Vec<String>>()
+        .join("&");
+
+    // The default port is left out by the URL
+    let base_uri = match url.port() {
+        None => format!("{}://{}{}", url.scheme(), url.host_str().unwrap_or("").to_lowercase(), url.path()),
+        Some(port) => format!("{}://{}:{port}{}", url.scheme(), url.host_str().unwrap_or("").to_lowercase(), url.path())
+    };
+
+    let base_string = format!("{}&{}&{}", method.to_string().to_uppercase(), encode(&base_uri), encode(&normalized_params));
+
+    let signature = match config.signature_method {
+        OAuth1SignatureMethod::HmacSha1 => {
+            let signing_key = format!("{}&{}", encode(&config.consumer_secret), encode(&config.token_secret));
+            let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, signing_key.as_bytes());
+
+            STANDARD.encode(hmac::sign(&key, base_string.as_bytes()).as_ref())
+        },
+        OAuth1SignatureMethod::RsaSha1 => {
+            let private_key = get_private_key(&config.consumer_secret)?;
+            let signing_key = SigningKey::<Sha1>::new(private_key);
+
+            STANDARD.encode(signing_key.sign(base_string.as_bytes()).to_bytes())
+        }
+    };
+
+    oauth_params.push((String::from("oauth_signature"), signature));
+
+    return Ok(oauth_params);
+}
+
+/// Authorization header value holding the OAuth params
+pub fn get_oauth1_header(oauth_params: &[(String, String)]) -> String {
+    let params = oauth_params
+        .iter()
+        .map(|(key, value)| format!("{}=\"{}\"", encode(key), encode(value)))
+        .collect::<Vec<String>>()
+        .join(", ");
+
+    return format!("OAuth {params}");
+}
+
+/// PEM private key, or "!!" followed by the path of a PEM file
+fn get_private_key(consumer_secret: &str) -> anyhow::Result<RsaPrivateKey> {
+    let pem = match consumer_secret.strip_prefix("!!") {
+        None => consumer_secret.to_string(),
+        Some(path) => std::fs::read_to_string(path).map_err(|error| anyhow!(CouldNotReadPrivateKey(error.to_string())))?
+    };
+
+    return match RsaPrivateKey::from_pkcs8_pem(&pem) {
+        Ok(private_key) => Ok(private_key),
+        Err(_) => RsaPrivateKey::from_pkcs1_pem(&pem).map_err(|error| anyhow!(InvalidPrivateKey(error.to_string())))
+    };
+}
+
+fn encode(text: &str) -> String {
+    return utf8_percent_encode(text, URL_ENCODE_SET).to_string();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_config(signature_method: OAuth1SignatureMethod) -> OAuth1Config {
+        OAuth1Config {
+            consumer_key: String::from("xvz1evFS4wEEPTGEFPHBog"),
+            consumer_secret: String::from("kAcSOqF21Fu85e7zjz7ZN2U4ZRhfV3WpwPAoE3Z7kBw"),
+            token: String::from("370773112-GmHxMAgYyLbNEtIKZeRNFsMKPR9EyMZeS9weJAEb"),
+            token_secret: String::from("LswwdoUaIvS8ltyTt5jkRh4J50vUPVVHtR2YPi5kE"),
+            signature_method,
+        }
+    }
+
+    fn find_param<'a>(oauth_params: &'a [(String, String)], key: &str) -> Option<&'a str> {
+        return oauth_params.iter().find(|(param_key, _)| param_key == key).map(|(_, value)| value.as_str());
+    }
+
+    /// Example of the Twitter documentation, "Creating a signature"
+    #[test]
+    fn hmac_sha1_signature() {
+        let url = Url::parse("https://api.twitter.com/1.1/statuses/update.json?include_entities=true").unwrap();
+        let form_params = vec![(String::from("status"), String::from("Hello Ladies + Gentlemen, a signed OAuth request!"))];
+
+        let oauth_params = sign_oauth1_params(&get_config(OAuth1SignatureMethod::HmacSha1), &Method::POST, &url, &form_params, "kYjzVBB8Y0ZFabxSWbWovY3uYSQ2pTgmZeNu2VS4cg", 1318622958).unwrap();
+
+        assert_eq!(find_param(&oauth_params, "oauth_signature"), Some("hCtSmYh+iHYCEqBWrE7C7hYmtUk="));
+        assert_eq!(find_param(&oauth_params, "oauth_signature_method"), Some("HMAC-SHA1"));
+        assert_eq!(find_param(&oauth_params, "oauth_version"), Some("1.0"));
+    }
+
+    #[test]
+    fn two_legged_request_has_no_token() {
+        let config = OAuth1Config {
+            token: String::new(),
+            ..get_config(OAuth1SignatureMethod::HmacSha1)
+        };
+        let url = Url::parse("https://example.com/").unwrap();
+
+        let oauth_params = get_oauth1_params(&config, &Method::GET, &url, &[]).unwrap();
+
+        assert_eq!(find_param(&oauth_params, "oauth_token"), None);
+        assert!(find_param(&oauth_params, "oauth_signature").is_some());
+    }
+
+    #[test]
+    fn invalid_rsa_private_key() {
+        let url = Url::parse("https://example.com/").unwrap();
+
+        assert!(get_oauth1_params(&get_config(OAuth1SignatureMethod::RsaSha1), &Method::GET, &url, &[]).is_err());
+    }
+
+    #[test]
+    fn header_encodes_the_values() {
+        let oauth_params = vec![
+            (String::from("oauth_consumer_key"), String::from("key")),
+            (String::from("oauth_signature"), String::from("a+b/c=")),
+        ];
+
+        assert_eq!(get_oauth1_header(&oauth_params), "OAuth oauth_consumer_key=\"key\", oauth_signature=\"a%2Bb%2Fc%3D\"");
+    }
+}
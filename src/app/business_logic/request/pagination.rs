@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use reqwest::Url;
+use serde_json::Value;
+use thiserror::Error;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::json::get_json_path_value;
+use crate::app::business_logic::request::pagination::PaginationError::{CouldNotPrepare, CouldNotSend, NoPageSize, NoPagination, NoParam};
+use crate::app::business_logic::request::send::send_request;
+use crate::models::pagination::{PaginationStrategy, RequestPagination};
+use crate::models::request::{KeyValue, Request};
+use crate::models::response::ResponseContent;
+
+#[derive(Error, Debug)]
+pub enum PaginationError {
+    #[error("The request has no pagination, set one with \"request pagination <COLLECTION>/<REQUEST> set\"")]
+    NoPagination,
+    #[error("The {0} strategy needs a param, set it with --param")]
+    NoParam(PaginationStrategy),
+    #[error("The offset strategy needs a page size, set it with --page-size")]
+    NoPageSize,
+    #[error("Could not prepare page {0}: {1}")]
+    CouldNotPrepare(usize, String),
+    #[error("Could not send page {0}: {1}")]
+    CouldNotSend(usize, String),
+}
+
+pub struct PaginationPage {
+    pub number: usize,
+    /// e.g. "page=3" or the followed link
+    pub target: String,
+    pub status_code: Option<String>,
+    /// None when no items array was found
+    pub items_count: Option<usize>,
+}
+
+pub struct PaginationResult {
+    pub pages: Vec<PaginationPage>,
+    pub items: Vec<Value>,
+    /// Why no more page was requested
+    pub stop_reason: String,
+}
+
+impl App<'_> {
+    pub fn modify_request_pagination(&mut self, collection_index: usize, request_index: usize, pagination: Option<RequestPagination>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &pagination {
+                None => info!("Pagination removed"),
+                Some(pagination) => info!("Pagination set to {}", pagination.to_summary())
+            }
+
+            selected_request.pagination = pagination;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+
+    /// Send the pages one after the other until the last one, and aggregate their items.
+    /// Each page is given to on_page once received
+    pub async fn follow_pagination(&self, collection_index: usize, request_index: usize, on_page: impl Fn(&PaginationPage)) -> Result<PaginationResult, PaginationError> {
+        let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let mut page_request = local_request.read().clone();
+
+        let pagination = match page_request.pagination.take() {
+            None => return Err(NoPagination),
+            Some(pagination) => pagination
+        };
+
+        let page_param = match (pagination.strategy, &pagination.param, pagination.page_size) {
+            (PaginationStrategy::NextLink, _, _) => None,
+            (strategy, None, _) => return Err(NoParam(strategy)),
+            (PaginationStrategy::Offset, Some(_), None) => return Err(NoPageSize),
+            (_, Some(param), _) => Some(param.clone())
+        };
+
+        let env = self.get_selected_env_as_local();
+        let offline = self.is_offline();
+
+        let mut pages: Vec<PaginationPage> = vec![];
+        let mut items: Vec<Value> = vec![];
+        let mut page_value = pagination.get_start();
+        let mut stop_reason = format!("Reached the maximum of {} pages", pagination.get_max_pages());
+
+        for number in 1..=pagination.get_max_pages() {
+            let target = match &page_param {
+                None => page_request.url.clone(),
+                Some(param) => {
+                    set_page_param(&mut page_request, param, page_value);
+                    format!("{param}={page_value}")
+                }
+            };
+
+            let prepared_request = match self.prepare_request(&page_request).await {
+                Ok((prepared_request, _)) => prepared_request,
+                Err(prepare_request_error) => return Err(CouldNotPrepare(number, prepare_request_error.to_string()))
+            };
+
+            info!("Sending page {number}");
+
+            let local_page_request = Arc::new(RwLock::new(page_request.clone()));
+
+            let response = match send_request(prepared_request, local_page_request, &env, offline).await {
+                Ok((response, _, _)) => response,
+                Err(error) => return Err(CouldNotSend(number, error.to_string()))
+            };
+
+            let body = match &response.content {
+                Some(ResponseContent::Body(body)) => serde_json::from_str::<Value>(body).ok(),
+                _ => None
+            };
+
+            let page_items = body.as_ref().and_then(|body| match &pagination.items_path {
+                None => body.as_array(),
+                Some(items_path) => get_json_path_value(body, items_path).and_then(Value::as_array)
+            });
+
+            let page = PaginationPage {
+                number,
+                target,
+                status_code: response.get_status_text(),
+                items_count: page_items.map(Vec::len),
+            };
+
+            on_page(&page);
+            pages.push(page);
+
+            if !response.is_success() {
+                stop_reason = format!("Page {number} did not succeed");
+                break;
+            }
+
+            if let Some(page_items) = page_items {
+                items.extend(page_items.iter().cloned());
+            }
+
+            let items_count = page_items.map(Vec::len).unwrap_or(0);
+
+            match pagination.strategy {
+                PaginationStrategy::NextLink => {
+                    let next_link = match &pagination.next_link_path {
+                        Some(next_link_path) => body
+                            .as_ref()
+                            .and_then(|body| get_json_path_value(body, next_link_path))
+                            .and_then(Value::as_str)
+                            .map(String::from),
+                        None => find_link_header_next(&response.headers)
+                    };
+
+                    let next_link = match next_link {
+                        Some(next_link) if !next_link.trim().is_empty() => next_link,
+                        _ => {
+                            stop_reason = String::from("No next link");
+                            break;
+                        }
+                    };
+
+                    let current_url = self.replace_env_keys_by_value(&page_request.url);
+                    let current_url = match Url::parse_with_params(&current_url, self.key_value_vec_to_tuple_vec(&page_request.params)) {
+                        Ok(current_url) => current_url,
+                        Err(_) => {
+                            stop_reason = format!("Invalid URL \"{current_url}\"");
+                            break;
+                        }
+                    };
+
+                    // The next link holds the query params of the next page
+                    let next_url = match current_url.join(&next_link) {
+                        Ok(next_url) => next_url,
+                        Err(_) => {
+                            stop_reason = format!("Invalid next link \"{next_link}\"");
+                            break;
+                        }
+                    };
+
+                    if next_url == current_url {
+                        stop_reason = String::from("The next link is the current page");
+                        break;
+                    }
+
+                    page_request.url = next_url.to_string();
+                    page_request.params = vec![];
+                },
+                PaginationStrategy::Page | PaginationStrategy::Offset => {
+                    if items_count == 0 {
+                        stop_reason = format!("Page {number} has no items");
+                        break;
+                    }
+
+                    if pagination.page_size.is_some_and(|page_size| items_count < page_size) {
+                        stop_reason = format!("Page {number} has fewer items than the page size");
+                        break;
+                    }
+
+                    page_value += match pagination.strategy {
+                        PaginationStrategy::Offset => pagination.page_size.unwrap_or(1),
+                        _ => 1
+                    };
+                }
+            }
+        }
+
+        Ok(PaginationResult {
+            pages,
+            items,
+            stop_reason,
+        })
+    }
+}
+
+/// Replace the value of the param, or add it
+fn set_page_param(request: &mut Request, param: &str, value: usize) {
+    match request.params.iter_mut().find(|key_value| key_value.data.0 == param) {
+        Some(key_value) => {
+            key_value.enabled = true;
+            key_value.data.1 = value.to_string();
+        },
+        None => request.params.push(KeyValue {
+            enabled: true,
+            data: (param.to_string(), value.to_string()),
+        })
+    }
+}
+
+/// e.g. Link: <https://api.example.com/items?page=2>; rel="next", <https://api.example.com/items?page=5>; rel="last"
+fn find_link_header_next(headers: &[(String, String)]) -> Option<String> {
+    for (header_name, header_value) in headers {
+        if !header_name.eq_ignore_ascii_case("link") {
+            continue;
+        }
+
+        for link in header_value.split(',') {
+            let mut parts = link.split(';');
+
+            let url = match parts.next() {
+                None => continue,
+                Some(url) => url.trim().trim_start_matches('<').trim_end_matches('>')
+            };
+
+            let is_next = parts.any(|part| {
+                let part = part.trim().replace('"', "");
+
+                match part.split_once('=') {
+                    Some((key, relations)) => key.trim().eq_ignore_ascii_case("rel") && relations.split_whitespace().any(|relation| relation.eq_ignore_ascii_case("next")),
+                    None => false
+                }
+            });
+
+            if is_next {
+                return Some(url.to_string());
+            }
+        }
+    }
+
+    return None;
+}
@@ -0,0 +1,173 @@
+use regex::Captures;
+
+use crate::app::app::App;
+use crate::app::business_logic::encoding::{form_url_encode, form_url_encode_component};
+use crate::app::business_logic::environment::ENV_KEY_REGEX;
+use crate::models::auth::Auth::{BasicAuth, BearerToken, HmacSignature, NoAuth, Ntlm, OAuth1, Plugin};
+use crate::models::auth::OAuth1Placement;
+use crate::models::body::ContentType::{Cbor, File, Form, Html, Javascript, Json, MessagePack, Multipart, NoBody, Protobuf, Raw, Xml};
+use crate::models::body::PartSource;
+use crate::models::method::Method;
+use crate::models::request::{KeyValue, Request};
+
+const MASK: &str = "••••••";
+
+/// Environment keys and headers whose name contains one of these are masked in the preview
+const SECRET_NAME_PARTS: [&str; 6] = ["secret", "password", "token", "key", "auth", "cookie"];
+
+/// Request as it would be sent with the selected environment, without running its pre-request script
+#[derive(Default)]
+pub struct RequestPreview {
+    pub method: Method,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    pub body: Option<String>,
+    pub has_pre_request_script: bool,
+}
+
+impl App<'_> {
+    pub fn get_request_preview(&self, request: &Request) -> RequestPreview {
+        /* URL */
+
+        let mut url = self.replace_env_keys_by_masked_value(&request.url);
+
+        // Not URL encoded, so that the masked values stay readable
+        let params: Vec<String> = request.params
+            .iter()
+            .filter(|param| param.enabled)
+            .map(|param| format!("{}={}", self.replace_env_keys_by_masked_value(&param.data.0), self.replace_env_keys_by_masked_value(&param.data.1)))
+            .collect();
+
+        if !params.is_empty() {
+            url.push(match url.contains('?') {
+                true => '&',
+                false => '?'
+            });
+            url.push_str(&params.join("&"));
+        }
+
+        /* HEADERS */
+
+        let mut headers: Vec<(String, String)> = vec![];
+
+        for (header_name, header_value) in self.config.get_default_headers() {
+            let is_overridden = request.headers
+                .iter()
+                .any(|header| header.enabled && header.data.0.eq_ignore_ascii_case(&header_name));
+
+            if !is_overridden {
+                headers.push(self.get_masked_header(&header_name, &header_value));
+            }
+        }
+
+        for header in request.headers.iter().filter(|header| header.enabled) {
+            headers.push(self.get_masked_header(&header.data.0, &header.data.1));
+        }
+
+        match &request.auth {
+            NoAuth => {},
+            BasicAuth { username, .. } => headers.push((String::from("Authorization"), format!("Basic {}:{MASK}", self.replace_env_keys_by_masked_value(username)))),
+            BearerToken { .. } => headers.push((String::from("Authorization"), format!("Bearer {MASK}"))),
+            HmacSignature { header, algorithm, .. } => headers.push((self.replace_env_keys_by_masked_value(header), format!("{algorithm} signature, computed when sending"))),
+            Ntlm { .. } => headers.push((String::from("Authorization"), format!("{} handshake, made when sending", request.auth))),
+            OAuth1 { signature_method, placement, .. } => match placement {
+                OAuth1Placement::Header => headers.push((String::from("Authorization"), format!("OAuth 1.0a {signature_method} signature, computed when sending"))),
+                // Added to the URL when sending
+                OAuth1Placement::Query => {}
+            },
+            Plugin { plugin, .. } => headers.push((String::from("Authorization"), format!("Computed by the \"{plugin}\" plugin when sending")))
+        }
+
+        let cookies: Vec<String> = request.cookies
+            .iter()
+            .filter(|cookie| cookie.enabled)
+            .map(|cookie| format!("{}={MASK}", self.replace_env_keys_by_masked_value(&cookie.data.0)))
+            .collect();
+
+        if !cookies.is_empty() {
+            headers.push((String::from("Cookie"), cookies.join("; ")));
+        }
+
+        /* BODY */
+
+        let body = match &request.body {
+            NoBody => None,
+            Multipart(parts) => Some(
+                parts
+                    .iter()
+                    .filter(|part| part.enabled)
+                    .map(|part| {
+                        // Same notation as curl's -F option
+                        let mut line = match part.source {
+                            PartSource::Text => format!("{}={}", self.replace_env_keys_by_masked_value(&part.name), self.replace_env_keys_by_masked_value(&part.value)),
+                            PartSource::File => format!("{}=@{}", self.replace_env_keys_by_masked_value(&part.name), self.replace_env_keys_by_masked_value(&part.value))
+                        };
+
+                        if let Some(content_type) = &part.content_type {
+                            line += &format!(";type={content_type}");
+                        }
+
+                        if let Some(file_name) = &part.file_name {
+                            line += &format!(";filename={file_name}");
+                        }
+
+                        line
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            ),
+            Form(form_data) => Some(self.get_masked_form_body(form_data)),
+            File(body_file) => Some(format!("File {}", self.replace_env_keys_by_masked_value(&body_file.path))),
+            Raw(body) | Json(body) | Xml(body) | Html(body) | Javascript(body) | Protobuf(body) | MessagePack(body) | Cbor(body) => Some(self.replace_env_keys_by_masked_value(body))
+        };
+
+        return RequestPreview {
+            method: request.method,
+            url,
+            headers,
+            body,
+            has_pre_request_script: request.scripts.pre_request_script.is_some(),
+        };
+    }
+
+    fn get_masked_header(&self, header_name: &str, header_value: &str) -> (String, String) {
+        let header_name = self.replace_env_keys_by_masked_value(header_name);
+
+        let header_value = match is_secret_name(&header_name) {
+            true => String::from(MASK),
+            false => self.replace_env_keys_by_masked_value(header_value)
+        };
+
+        return (header_name, header_value);
+    }
+
+    /// URL-encoded form body as sent, the values of the secret keys being masked
+    pub fn get_masked_form_body(&self, form_data: &[KeyValue]) -> String {
+        let masked_pairs: Vec<(String, String)> = form_data
+            .iter()
+            .filter(|form_data| form_data.enabled)
+            .map(|form_data| (self.replace_env_keys_by_masked_value(&form_data.data.0), self.replace_env_keys_by_masked_value(&form_data.data.1)))
+            .collect();
+
+        // The mask is kept readable
+        return form_url_encode(&masked_pairs).replace(&form_url_encode_component(MASK), MASK);
+    }
+
+    /// Same as replace_env_keys_by_value, but the values of the secret keys are masked
+    fn replace_env_keys_by_masked_value(&self, input: &str) -> String {
+        let masked_input = ENV_KEY_REGEX.replace_all(input, |captures: &Captures| {
+            match is_secret_name(&captures[1]) && self.is_env_key_resolved(&captures[1]) {
+                true => String::from(MASK),
+                false => captures[0].to_string()
+            }
+        });
+
+        return self.replace_env_keys_by_value(&masked_input.to_string());
+    }
+}
+
+fn is_secret_name(name: &str) -> bool {
+    let name = name.to_lowercase();
+
+    return SECRET_NAME_PARTS.iter().any(|secret_name_part| name.contains(secret_name_part));
+}
@@ -0,0 +1,33 @@
+use anyhow::anyhow;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::protobuf::ProtobufSchema;
+
+impl App<'_> {
+    pub fn modify_request_protobuf_schema(&mut self, collection_index: usize, request_index: usize, protobuf_schema: Option<ProtobufSchema>) -> anyhow::Result<()> {
+        // Refuses proto files that could not encode or decode anything
+        if let Some(protobuf_schema) = &protobuf_schema {
+            if let Err(error) = protobuf_schema.validate() {
+                return Err(anyhow!(error));
+            }
+        }
+
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &protobuf_schema {
+                None => info!("Protobuf schema removed"),
+                Some(protobuf_schema) => info!("Protobuf schema set to {}", protobuf_schema.to_summary())
+            }
+
+            selected_request.protobuf = protobuf_schema;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
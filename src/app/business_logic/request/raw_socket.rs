@@ -0,0 +1,286 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{Method, ResponseBuilderExt, StatusCode, Version};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio_rustls::rustls::client::danger::{HandshakeSignatureValid, ServerCertVerified, ServerCertVerifier};
+use tokio_rustls::rustls::crypto::{ring, verify_tls12_signature, verify_tls13_signature, CryptoProvider};
+use tokio_rustls::rustls::pki_types::{CertificateDer, ServerName, UnixTime};
+use tokio_rustls::rustls::{ClientConfig, DigitallySignedStruct, RootCertStore, SignatureScheme};
+use tokio_rustls::TlsConnector;
+use tracing::{trace, warn};
+
+use crate::models::settings::RequestSettings;
+
+/// Silence after which the response is considered complete, the servers often keep the connection open
+const RAW_RESPONSE_IDLE_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Headers parsed from the raw request and response, the next ones are only displayed in the body
+const MAX_PARSED_HEADERS: usize = 100;
+
+/// Write the text body of the request as is to a TCP or TLS connection to the host of the URL, and read the raw response.
+/// The response body is the whole response, as received
+pub async fn send_raw_socket_request(prepared_request: reqwest_middleware::RequestBuilder, settings: &RequestSettings) -> reqwest_middleware::Result<(reqwest::Response, Method, HeaderMap)> {
+    let (_, request) = prepared_request.build_split();
+    let request = request?;
+    let url = request.url().clone();
+
+    let raw_request_text = match request.body().and_then(|body| body.as_bytes()) {
+        Some(body) => String::from_utf8_lossy(body).to_string(),
+        None => return Err(anyhow!("The raw socket requests need a text body, holding the start line, the headers and the body").into())
+    };
+
+    let raw_request = decode_raw_request(&raw_request_text).map_err(|error| anyhow!(error))?;
+
+    let host = url.host_str().unwrap_or_default().trim_start_matches('[').trim_end_matches(']').to_string();
+
+    let port = match url.port_or_known_default() {
+        Some(port) => port,
+        None => return Err(anyhow!("No port known for the \"{}\" scheme", url.scheme()).into())
+    };
+
+    trace!("Writing {} raw bytes to {host}:{port}", raw_request.len());
+
+    let tcp_stream = TcpStream::connect((host.as_str(), port))
+        .await
+        .map_err(|error| anyhow!("Could not connect to {host}:{port}: {error}"))?;
+
+    let max_bytes = settings.get_max_response_bytes();
+
+    let raw_response = match url.scheme() {
+        "http" => exchange_raw_bytes(tcp_stream, &raw_request, max_bytes).await,
+        "https" => {
+            let server_name = ServerName::try_from(host.clone()).map_err(|error| anyhow!("Invalid host \"{host}\": {error}"))?;
+            let tls_connector = TlsConnector::from(Arc::new(get_tls_config(settings.accept_invalid_certs)));
+
+            let tls_stream = tls_connector
+                .connect(server_name, tcp_stream)
+                .await
+                .map_err(|error| anyhow!("TLS handshake with {host}:{port} failed: {error}"))?;
+
+            exchange_raw_bytes(tls_stream, &raw_request, max_bytes).await
+        },
+        scheme => return Err(anyhow!("The raw socket requests need an http or https URL, not \"{scheme}\"").into())
+    };
+
+    let raw_response = raw_response.map_err(|error| anyhow!("Raw socket error: {error}"))?;
+
+    let (method, request_headers) = parse_raw_request(&raw_request);
+
+    return Ok((build_response(url, &raw_response)?, method, request_headers));
+}
+
+/// The newlines are sent as CRLF, and \r, \n, \t, \0, \xHH and \\ are decoded.
+/// A backslash ending a line joins it to the next one, e.g. to end it with a bare LF
+pub fn decode_raw_request(text: &str) -> Result<Vec<u8>, String> {
+    let mut bytes: Vec<u8> = vec![];
+    let mut chars = text.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '\r' if chars.peek() == Some(&'\n') => {},
+            '\n' => bytes.extend(b"\r\n"),
+            '\\' => match chars.next() {
+                Some('r') => bytes.push(b'\r'),
+                Some('n') => bytes.push(b'\n'),
+                Some('t') => bytes.push(b'\t'),
+                Some('0') => bytes.push(0),
+                Some('\\') => bytes.push(b'\\'),
+                Some('x') => {
+                    let hex: String = [chars.next(), chars.next()].into_iter().flatten().collect();
+
+                    match u8::from_str_radix(&hex, 16) {
+                        Ok(byte) if hex.len() == 2 => bytes.push(byte),
+                        _ => return Err(format!("Invalid escape sequence \"\\x{hex}\", expected two hexadecimal digits"))
+                    }
+                },
+                // Line continuation
+                Some('\n') => {},
+                Some('\r') if chars.peek() == Some(&'\n') => {
+                    chars.next();
+                },
+                Some(other) => return Err(format!("Unknown escape sequence \"\\{other}\", use \"\\\\\" for a backslash")),
+                None => bytes.push(b'\\')
+            },
+            char => {
+                let mut buffer = [0u8; 4];
+                bytes.extend(char.encode_utf8(&mut buffer).as_bytes());
+            }
+        }
+    }
+
+    return Ok(bytes);
+}
+
+/// The received bytes as text, the bare CR and the other control characters escaped so that they can be told apart
+pub fn encode_raw_response(bytes: &[u8]) -> String {
+    let mut text = String::new();
+
+    for chunk in bytes.utf8_chunks() {
+        let mut chars = chunk.valid().chars().peekable();
+
+        while let Some(char) = chars.next() {
+            match char {
+                '\r' if chars.peek() == Some(&'\n') => {},
+                '\r' => text.push_str("\\r"),
+                '\n' | '\t' => text.push(char),
+                char if char.is_control() => text.push_str(&format!("\\x{:02X}", char as u32)),
+                char => text.push(char)
+            }
+        }
+
+        for byte in chunk.invalid() {
+            text.push_str(&format!("\\x{byte:02X}"));
+        }
+    }
+
+    return text;
+}
+
+async fn exchange_raw_bytes<S: AsyncRead + AsyncWrite + Unpin>(mut stream: S, raw_request: &[u8], max_bytes: Option<usize>) -> std::io::Result<Vec<u8>> {
+    stream.write_all(raw_request).await?;
+    stream.flush().await?;
+
+    let mut raw_response: Vec<u8> = vec![];
+    let mut buffer = vec![0u8; 16 * 1024];
+
+    loop {
+        let read_bytes = match tokio::time::timeout(RAW_RESPONSE_IDLE_TIMEOUT, stream.read(&mut buffer)).await {
+            Ok(Ok(0)) | Err(_) => break,
+            Ok(Ok(read_bytes)) => read_bytes,
+            // e.g. a TLS connection closed without close_notify, what was received is kept
+            Ok(Err(error)) if !raw_response.is_empty() => {
+                warn!("Raw response interrupted: {error}");
+                break;
+            },
+            Ok(Err(error)) => return Err(error)
+        };
+
+        raw_response.extend_from_slice(&buffer[..read_bytes]);
+
+        if max_bytes.is_some_and(|max_bytes| raw_response.len() >= max_bytes) {
+            raw_response.truncate(max_bytes.unwrap_or_default());
+            break;
+        }
+    }
+
+    return Ok(raw_response);
+}
+
+/// The method and the headers as a standard parser reads them, displayed in the wire tab
+fn parse_raw_request(raw_request: &[u8]) -> (Method, HeaderMap) {
+    let mut headers = [httparse::EMPTY_HEADER; MAX_PARSED_HEADERS];
+    let mut request = httparse::Request::new(&mut headers);
+
+    let _ = request.parse(raw_request);
+
+    let method = request.method
+        .and_then(|method| Method::from_bytes(method.as_bytes()).ok())
+        .unwrap_or_default();
+
+    return (method, to_header_map(request.headers));
+}
+
+/// The status and the headers of the first response, the body is the whole raw response
+fn build_response(url: reqwest::Url, raw_response: &[u8]) -> reqwest_middleware::Result<reqwest::Response> {
+    let mut headers = [httparse::EMPTY_HEADER; MAX_PARSED_HEADERS];
+    let mut response = httparse::Response::new(&mut headers);
+
+    if response.parse(raw_response).is_err() || response.code.is_none() {
+        let message = match raw_response.is_empty() {
+            true => String::from("The server closed the connection without answering"),
+            false => format!("No HTTP status line received:\n{}", encode_raw_response(raw_response))
+        };
+
+        return Err(anyhow!(message).into());
+    }
+
+    let status = StatusCode::from_u16(response.code.unwrap_or_default()).map_err(|error| anyhow!(error))?;
+
+    let version = match response.version {
+        Some(0) => Version::HTTP_10,
+        _ => Version::HTTP_11
+    };
+
+    let mut http_response = http::Response::builder()
+        .status(status)
+        .version(version)
+        .url(url)
+        .body(encode_raw_response(raw_response))
+        .map_err(|error| anyhow!(error))?;
+
+    *http_response.headers_mut() = to_header_map(response.headers);
+
+    return Ok(reqwest::Response::from(http_response));
+}
+
+/// The empty headers were not parsed, e.g. after an incomplete message
+fn to_header_map(headers: &[httparse::Header]) -> HeaderMap {
+    let mut header_map = HeaderMap::new();
+
+    for header in headers.iter().filter(|header| !header.name.is_empty()) {
+        if let (Ok(name), Ok(value)) = (HeaderName::from_bytes(header.name.as_bytes()), HeaderValue::from_bytes(header.value)) {
+            header_map.append(name, value);
+        }
+    }
+
+    return header_map;
+}
+
+fn get_tls_config(accept_invalid_certs: bool) -> ClientConfig {
+    let crypto_provider = Arc::new(ring::default_provider());
+
+    let config_builder = ClientConfig::builder_with_provider(crypto_provider.clone())
+        .with_safe_default_protocol_versions()
+        .expect("The ring provider supports the default protocol versions");
+
+    let mut tls_config = match accept_invalid_certs {
+        true => config_builder
+            .dangerous()
+            .with_custom_certificate_verifier(Arc::new(AcceptInvalidCerts(crypto_provider)))
+            .with_no_client_auth(),
+        false => {
+            let mut root_store = RootCertStore {
+                roots: webpki_roots::TLS_SERVER_ROOTS.to_vec()
+            };
+
+            if let Ok(native_certs) = rustls_native_certs::load_native_certs() {
+                root_store.add_parsable_certificates(native_certs);
+            }
+
+            config_builder
+                .with_root_certificates(root_store)
+                .with_no_client_auth()
+        }
+    };
+
+    // The raw requests are HTTP/1.x
+    tls_config.alpn_protocols = vec![b"http/1.1".to_vec()];
+
+    return tls_config;
+}
+
+/// Same as the "Accept invalid certs" setting of the HTTP client, the signatures are still checked
+#[derive(Debug)]
+struct AcceptInvalidCerts(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for AcceptInvalidCerts {
+    fn verify_server_cert(&self, _end_entity: &CertificateDer<'_>, _intermediates: &[CertificateDer<'_>], _server_name: &ServerName<'_>, _ocsp_response: &[u8], _now: UnixTime) -> Result<ServerCertVerified, tokio_rustls::rustls::Error> {
+        return Ok(ServerCertVerified::assertion());
+    }
+
+    fn verify_tls12_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        return verify_tls12_signature(message, cert, dss, &self.0.signature_verification_algorithms);
+    }
+
+    fn verify_tls13_signature(&self, message: &[u8], cert: &CertificateDer<'_>, dss: &DigitallySignedStruct) -> Result<HandshakeSignatureValid, tokio_rustls::rustls::Error> {
+        return verify_tls13_signature(message, cert, dss, &self.0.signature_verification_algorithms);
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        return self.0.signature_verification_algorithms.supported_schemes();
+    }
+}
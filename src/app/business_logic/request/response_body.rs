@@ -0,0 +1,48 @@
+use bytes::Bytes;
+use http_body_util::BodyExt;
+use reqwest::header::HeaderMap;
+
+/// Body of a response read frame by frame, reqwest drops the trailer fields when reading the chunks
+pub struct ResponseBody {
+    body: reqwest::Body,
+    /// Received after the last chunk, with a chunked HTTP/1.1 body or over HTTP/2
+    pub trailers: Option<HeaderMap>,
+}
+
+impl ResponseBody {
+    pub fn new(response: reqwest::Response) -> ResponseBody {
+        let response: http::Response<reqwest::Body> = response.into();
+
+        ResponseBody {
+            body: response.into_body(),
+            trailers: None,
+        }
+    }
+
+    /// None once the whole body was received
+    pub async fn chunk(&mut self) -> reqwest::Result<Option<Bytes>> {
+        loop {
+            let frame = match self.body.frame().await {
+                Some(frame) => frame?,
+                None => return Ok(None)
+            };
+
+            match frame.into_data() {
+                Ok(chunk) => return Ok(Some(chunk)),
+                Err(frame) => if let Ok(trailers) = frame.into_trailers() {
+                    self.trailers = Some(trailers);
+                }
+            }
+        }
+    }
+
+    pub fn get_trailers(&self) -> Vec<(String, String)> {
+        return match &self.trailers {
+            None => vec![],
+            Some(trailers) => trailers
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.to_str().unwrap_or("").to_string()))
+                .collect()
+        };
+    }
+}
@@ -0,0 +1,52 @@
+use std::io::Write;
+use std::process::{Command, Stdio};
+use std::thread;
+
+use tracing::{info, trace};
+
+/// Run the command with the system shell, the body is written to its standard input and the processed body read from its standard output.
+/// A non-zero exit status fails with what it printed on its standard error.
+pub fn run_response_processor(command: &str, body: &str) -> Result<String, String> {
+    info!("Running response processor \"{command}\"");
+
+    let mut shell = match cfg!(windows) {
+        true => {
+            let mut shell = Command::new("cmd");
+            shell.arg("/C");
+            shell
+        },
+        false => {
+            let mut shell = Command::new("sh");
+            shell.arg("-c");
+            shell
+        }
+    };
+
+    let mut child = shell
+        .arg(command)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|error| format!("Could not run the response processor: {error}"))?;
+
+    // Written from another thread so that a command answering before reading everything cannot block
+    let mut stdin = child.stdin.take().unwrap();
+    let body = body.as_bytes().to_vec();
+    let writer = thread::spawn(move || stdin.write_all(&body));
+
+    let output = child
+        .wait_with_output()
+        .map_err(|error| format!("Could not run the response processor: {error}"))?;
+
+    let _ = writer.join();
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim_end().to_string();
+        return Err(format!("Response processor failed with {}: {stderr}", output.status));
+    }
+
+    trace!("Response processed");
+
+    return Ok(String::from_utf8_lossy(&output.stdout).to_string());
+}
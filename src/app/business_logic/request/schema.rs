@@ -0,0 +1,33 @@
+use anyhow::anyhow;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::schema::ResponseSchema;
+
+impl App<'_> {
+    pub fn modify_request_response_schema(&mut self, collection_index: usize, request_index: usize, response_schema: Option<ResponseSchema>) -> anyhow::Result<()> {
+        // Refuses schemas that would fail on every send
+        if let Some(response_schema) = &response_schema {
+            if let Err(error) = response_schema.compile() {
+                return Err(anyhow!(error));
+            }
+        }
+
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &response_schema {
+                None => info!("Response schema removed"),
+                Some(response_schema) => info!("Response schema set to {}", response_schema.to_summary())
+            }
+
+            selected_request.response_schema = response_schema;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
@@ -4,7 +4,7 @@ use tracing::{info, trace};
 
 use crate::app::app::App;
 use crate::models::request::Request;
-use crate::models::response::RequestResponse;
+use crate::models::response::{RequestResponse, ResponseContent};
 use crate::models::scripts::ScriptType;
 
 impl App<'_> {
@@ -17,9 +17,13 @@ impl App<'_> {
             match script_type {
                 ScriptType::Pre => selected_request.scripts.pre_request_script = script,
                 ScriptType::Post => selected_request.scripts.post_request_script = script,
+                ScriptType::Processor => selected_request.scripts.response_processor = script,
             }
 
-            info!("{}-request script set", script_type);
+            match script_type {
+                ScriptType::Processor => info!("Response processor set"),
+                _ => info!("{}-request script set", script_type)
+            }
         }
 
         self.save_collection_to_file(collection_index);
@@ -28,7 +32,7 @@ impl App<'_> {
     }
 }
 
-const JS_CONSOLE: &str = r#"
+pub const JS_CONSOLE: &str = r#"
 let console_log_output = "";
 
 globalThis.console = {
@@ -39,7 +43,7 @@ globalThis.console = {
 }
 "#;
 
-const JS_UTILS: &str = r#"
+pub const JS_UTILS: &str = r#"
 function pretty_print(data) {
     console.log(JSON.stringify(data, null, 2));
 }
@@ -131,7 +135,17 @@ pub fn execute_post_request_script(user_script: &String, response: &RequestRespo
         Ok((mut response_result, result_env_values, console_output)) => {
             // Avoid loosing those fields since they are not serialized
             response_result.duration = response.duration.clone();
-            response_result.status_code = response.status_code.clone();
+            response_result.result = response.result.clone();
+            response_result.charset = response.charset.clone();
+            response_result.is_offline = response.is_offline;
+
+            // The received bytes are only kept while the script leaves the body as is
+            if let (Some(ResponseContent::Body(result_body)), Some(ResponseContent::Body(body))) = (&response_result.content, &response.content) {
+                if result_body.as_str() == body.as_str() {
+                    response_result.content = response.content.clone();
+                    response_result.raw_body = response.raw_body.clone();
+                }
+            }
 
             (Some(response_result), result_env_values, console_output)
         },
@@ -1,33 +1,59 @@
-use std::io::Read;
-use std::path::PathBuf;
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use parking_lot::RwLock;
 
-use ratatui::prelude::Line;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
 use rayon::prelude::*;
-use reqwest::{ClientBuilder, Proxy, Url};
-use reqwest::header::{CONTENT_TYPE, HeaderMap};
+use reqwest::{StatusCode, Url};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, EXPECT, CONTENT_LENGTH, CONTENT_TYPE, COOKIE, LOCATION, PROXY_AUTHORIZATION};
 use reqwest::multipart::Part;
-use reqwest::redirect::Policy;
 use reqwest_tracing::{TracingMiddleware, OtelName, DisableOtelPropagation};
 use reqwest_middleware::Extension;
+use reqwest_cookie_store::CookieStoreRwLock;
 use thiserror::Error;
+use tokio_util::io::ReaderStream;
 use tokio_util::sync::CancellationToken;
-use tracing::{info, trace};
+use tracing::{info, trace, warn};
 
 use crate::app::app::App;
+use crate::app::business_logic::environment::VariableResolver;
+use crate::app::files::config::Config;
 use crate::app::business_logic::request::scripts::{execute_post_request_script, execute_pre_request_script};
 use crate::app::business_logic::request::send::RequestResponseError::PostRequestScript;
 use crate::app::files::environment::save_environment_to_file;
-use crate::models::auth::Auth::{BasicAuth, BearerToken, NoAuth};
-use crate::models::body::ContentType::{File, Form, Html, Javascript, Json, Multipart, NoBody, Raw, Xml};
-use crate::models::body::find_file_format_in_content_type;
+use crate::app::business_logic::request::auth::{get_string_to_sign, sign_hmac};
+use crate::app::business_logic::request::client_pool::{get_client, ClientKey};
+use crate::app::business_logic::request::concurrency::wait_for_request_slot;
+use crate::app::business_logic::request::dns::get_last_dns_resolution;
+use crate::app::business_logic::request::ntlm::NtlmMiddleware;
+use crate::app::business_logic::request::oauth1::{get_oauth1_header, get_oauth1_params, OAuth1Config};
+use crate::app::business_logic::request::network_conditions::{throttle_request_body, BandwidthLimiter, ThrottledReader};
+use crate::app::business_logic::request::upload::ProgressReader;
+use crate::app::business_logic::request::response_processor::run_response_processor;
+use crate::app::business_logic::request::response_body::ResponseBody;
+use crate::app::business_logic::request::raw_socket::send_raw_socket_request;
+use crate::app::business_logic::plugin::{find_plugin, get_plugins_with_hook, run_plugin};
+use crate::models::auth::Auth::{BasicAuth, BearerToken, HmacSignature, NoAuth, Ntlm, OAuth1, Plugin};
+use crate::models::auth::OAuth1Placement;
+use crate::models::binary_body::BinaryFormat;
+use crate::models::body::ContentType::{Cbor, File, Form, Html, Javascript, Json, MessagePack, Multipart, NoBody, Protobuf, Raw, Xml};
+use crate::models::body::{find_response_file_format, is_ndjson_content_type, PartSource};
 use crate::models::environment::Environment;
+use crate::models::plugin::{AuthInput, AuthOutput, BodyEncoderInput, BodyEncoderOutput, PluginHook, ResponseProcessorInput, ResponseProcessorOutput};
+use crate::models::protobuf::{is_protobuf_content_type, ProtobufError};
 use crate::models::request::Request;
-use crate::models::response::{ImageResponse, RequestResponse, ResponseContent};
-use crate::panic_error;
-use crate::tui::utils::syntax_highlighting::highlight;
+use crate::models::settings::RequestSettings;
+use crate::models::soap::{find_soap_fault, pretty_print_xml, RequestSoap};
+use crate::models::request_error::RequestError;
+use crate::models::response::{BodyText, ImageResponse, ProcessedBody, RawBody, RequestResponse, RequestResult, ResponseContent, ResponseStream, TestResult};
+use crate::models::wire::WireExchange;
+
+/// Smallest body sent with "Expect: 100-continue" when the request setting is enabled
+const EXPECT_CONTINUE_MIN_BODY_SIZE: usize = 1024 * 1024;
 
 #[derive(Error, Debug)]
 pub enum PrepareRequestError {
@@ -35,67 +61,112 @@ pub enum PrepareRequestError {
     PreRequestScript,
     #[error("INVALID URL")]
     InvalidUrl,
-    #[error("COULD NOT OPEN FILE")]
-    CouldNotOpenFile
+    #[error("COULD NOT OPEN FILE \"{0}\" {1}")]
+    CouldNotOpenFile(String, String),
+    #[error("INVALID PART CONTENT TYPE \"{0}\"")]
+    InvalidPartContentType(String),
+    #[error("UNRESOLVED VARIABLES {0}")]
+    UnresolvedVariables(String),
+    #[error("OAUTH 1.0A SIGNATURE ERROR {0}")]
+    OAuth1Signature(String),
+    #[error("PROTOBUF ENCODING ERROR {0}")]
+    ProtobufEncoding(String),
+    #[error("BODY ENCODING ERROR {0}")]
+    BinaryBodyEncoding(String),
+    #[error("INVALID PROXY {0}")]
+    InvalidProxy(String),
+    #[error("INVALID HTTP/2 OPTIONS {0}")]
+    InvalidHttp2Options(String),
+    #[error("UNSUPPORTED TLS VERSION {0}")]
+    UnsupportedTlsVersion(String),
+    #[error("INVALID DNS RESOLVER {0}")]
+    InvalidDnsResolver(String),
+    #[error("INVALID LOCAL ADDRESS \"{0}\"")]
+    #[cfg_attr(target_os = "linux", allow(dead_code))]
+    InvalidLocalAddress(String),
+    #[error("COULD NOT BUILD HTTP CLIENT {0}")]
+    ClientBuild(String),
+    #[error("PLUGIN ERROR {0}")]
+    Plugin(String)
 }
 
-impl App<'_> {
-    pub async fn prepare_request(&self, request: &Request) -> Result<(reqwest_middleware::RequestBuilder, String), PrepareRequestError> {
-        trace!("Preparing request");
-        
-        let env = self.get_selected_env_as_local();
-
-        let mut client_builder = ClientBuilder::new()
-            .default_headers(HeaderMap::new())
-            .referer(false);
-
-        /* REDIRECTS */
+impl PrepareRequestError {
+    /// What the user can do about the errors coming from the config rather than from the request, displayed in a popup
+    pub fn get_remediation_hint(&self) -> Option<&'static str> {
+        return match self {
+            PrepareRequestError::InvalidProxy(_) => Some("Fix the http_proxy and https_proxy of the [proxy] section of atac.toml, e.g. \"http://127.0.0.1:8080\""),
+            PrepareRequestError::InvalidDnsResolver(_) => Some("Fix the dns_resolver of atac.toml, e.g. \"system\", \"10.0.0.53\" or \"https://cloudflare-dns.com/dns-query\""),
+            PrepareRequestError::ClientBuild(_) => Some("Check the [proxy] section of atac.toml and the certificate settings of the request"),
+            _ => None
+        };
+    }
+}
 
-        if !request.settings.allow_redirects {
-            client_builder = client_builder.redirect(Policy::none());
+impl App<'_> {
+    /// Config, cookie jar and variables of the app
+    pub fn get_send_context(&self) -> SendContext<'_> {
+        SendContext {
+            config: &self.config,
+            cookie_store: &self.cookies_popup.cookie_store,
+            variables: self.get_variable_resolver(),
         }
+    }
 
-        /* STORE COOKIES */
+    pub async fn prepare_request(&self, request: &Request) -> Result<(reqwest_middleware::RequestBuilder, String), PrepareRequestError> {
+        return self.get_send_context().prepare_request(request).await;
+    }
+}
+
+/// What preparing a request needs besides the request, without depending on the app so that other tools can send the requests
+pub struct SendContext<'a> {
+    pub config: &'a Config,
+    pub cookie_store: &'a Arc<CookieStoreRwLock>,
+    pub variables: VariableResolver<'a>,
+}
 
-        let should_store_cookies = request.settings.store_received_cookies;
+impl SendContext<'_> {
+    pub async fn prepare_request(&self, request: &Request) -> Result<(reqwest_middleware::RequestBuilder, String), PrepareRequestError> {
+        trace!("Preparing request");
+        
+        let env = self.variables.environment.clone();
 
-        client_builder = client_builder.cookie_store(should_store_cookies);
+        /* CLIENT */
 
-        /* PROXY */
+        // Built once per key, the next sends with the same settings reuse its connections
+        let (http_proxy, https_proxy) = match (request.settings.use_config_proxy, &self.config.proxy) {
+            (true, Some(proxy)) => (proxy.http_proxy.clone(), proxy.https_proxy.clone()),
+            _ => (None, None)
+        };
 
-        if request.settings.use_config_proxy {
-            match &self.config.proxy {
-                None => {}
-                Some(proxy) => {
-                    match &proxy.http_proxy {
-                        None => {}
-                        Some(http_proxy_str) => {
-                            let proxy = match Proxy::http(http_proxy_str) {
-                                Ok(proxy) => proxy,
-                                Err(e) => panic_error(format!("Could not parse HTTP proxy\n\t{e}"))
-                            };
-                            client_builder = client_builder.proxy(proxy);
-                        }
-                    }
+        let connection_pool = match &request.settings.connection_pool {
+            None => self.config.get_connection_pool_options(),
+            Some(connection_pool) => connection_pool.merged_over(&self.config.get_connection_pool_options())
+        };
 
-                    match &proxy.https_proxy {
-                        None => {}
-                        Some(https_proxy_str) => {
-                            let proxy = match Proxy::https(https_proxy_str) {
-                                Ok(proxy) => proxy,
-                                Err(e) => panic_error(format!("Could not parse HTTPS proxy\n\t{e}"))
-                            };
-                            client_builder = client_builder.proxy(proxy);
-                        }
-                    }
-                }
-            }
-        }
+        let http2 = match &request.settings.http2 {
+            None => self.config.get_http2_options(),
+            Some(http2) => http2.merged_over(&self.config.get_http2_options())
+        };
 
-        /* COOKIES */
+        let (config_min_tls_version, config_max_tls_version) = self.config.get_tls_versions();
+
+        let client_key = ClientKey {
+            store_received_cookies: request.settings.store_received_cookies,
+            http_proxy,
+            https_proxy,
+            accept_invalid_certs: request.settings.accept_invalid_certs,
+            accept_invalid_hostnames: request.settings.accept_invalid_hostnames,
+            connection_pool,
+            http2,
+            ip_version: request.settings.ip_version,
+            local_address: request.settings.local_address.clone(),
+            dns_resolver: self.config.dns_resolver.clone(),
+            min_tls_version: request.settings.min_tls_version.or(config_min_tls_version),
+            max_tls_version: request.settings.max_tls_version.or(config_max_tls_version),
+            cookie_store: Arc::as_ptr(self.cookie_store) as usize,
+        };
 
-        let local_cookie_store = Arc::clone(&self.cookies_popup.cookie_store);
-        client_builder = client_builder.cookie_provider(local_cookie_store);
+        let untraced_client = get_client(&client_key, self.cookie_store)?;
 
         let (modified_request, console_output): (Request, String) = match &request.scripts.pre_request_script {
             None => {
@@ -134,34 +205,53 @@ impl App<'_> {
             }
         };
 
-        /* INVALID CERTS */
-        
-        if request.settings.accept_invalid_certs {
-            client_builder = client_builder.danger_accept_invalid_certs(true);
+        /* UNRESOLVED VARIABLES */
+
+        // Checked after the pre-request script, which may set the missing values
+        let unresolved_keys = self.variables.get_request_unresolved_env_keys(&modified_request);
+
+        if !unresolved_keys.is_empty() {
+            let unresolved_keys = unresolved_keys
+                .iter()
+                .map(|key| format!("{{{{{key}}}}}"))
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            match self.config.should_block_unresolved_variables() {
+                true => return Err(PrepareRequestError::UnresolvedVariables(unresolved_keys)),
+                false => warn!("Unresolved variables sent as is: {unresolved_keys}")
+            }
         }
 
-        /* INVALID HOSTNAMES */
+        /* MIDDLEWARES */
+
+        let mut middleware_client_builder = reqwest_middleware::ClientBuilder::new(untraced_client)
+            .with(TracingMiddleware::default());
 
-        if request.settings.accept_invalid_hostnames {
-            client_builder = client_builder.danger_accept_invalid_hostnames(true);
+        // The handshake is made for each sent request, redirects included
+        match &modified_request.auth {
+            Ntlm { username, password, domain } => {
+                middleware_client_builder = middleware_client_builder.with(NtlmMiddleware {
+                    username: self.variables.replace_env_keys_by_value(username),
+                    password: self.variables.replace_env_keys_by_value(password),
+                    domain: self.variables.replace_env_keys_by_value(domain),
+                });
+            }
+            _ => {}
         }
-        
-        /* CLIENT */
 
-        let untraced_client = client_builder.build().expect("Could not build HTTP client");
-        let client = reqwest_middleware::ClientBuilder::new(untraced_client)
-            .with(TracingMiddleware::default())
+        let client = middleware_client_builder
             .with_init(Extension(OtelName(modified_request.name.into())))
             .with_init(Extension(DisableOtelPropagation))
             .build();
 
         /* PARAMS */
 
-        let params = self.key_value_vec_to_tuple_vec(&modified_request.params);
+        let params = self.variables.key_value_vec_to_tuple_vec(&modified_request.params);
 
         /* URL */
 
-        let url = self.replace_env_keys_by_value(&modified_request.url);
+        let url = self.variables.replace_env_keys_by_value(&modified_request.url);
 
         let url = match Url::parse_with_params(&url, params) {
             Ok(url) => url,
@@ -174,7 +264,7 @@ impl App<'_> {
 
         let mut request_builder = client.request(
             modified_request.method.to_reqwest(),
-            url
+            url.clone()
         );
 
         /* CORS */
@@ -188,74 +278,173 @@ impl App<'_> {
         match &modified_request.auth {
             NoAuth => {}
             BasicAuth { username, password} => {
-                let username = self.replace_env_keys_by_value(&username);
-                let password = self.replace_env_keys_by_value(&password);
+                let username = self.variables.replace_env_keys_by_value(username);
+                let password = self.variables.replace_env_keys_by_value(password);
 
                 request_builder = request_builder.basic_auth(username, Some(password));
             }
             BearerToken { token: bearer_token } => {
-                let bearer_token = self.replace_env_keys_by_value(&bearer_token);
+                let bearer_token = self.variables.replace_env_keys_by_value(bearer_token);
 
                 request_builder = request_builder.bearer_auth(bearer_token);
             }
+            // Signed once the body and the headers are set, see below
+            HmacSignature { .. } => {}
+            // Answered by the NTLM middleware of the client
+            Ntlm { .. } => {}
+            // Signed once the body is set, see below
+            OAuth1 { .. } => {}
+            // Computed once the body and the headers are set, see below
+            Plugin { .. } => {}
         }
 
         /* BODY */
 
         match &modified_request.body {
             NoBody => {},
-            Multipart(form_data) => {
+            Multipart(parts) => {
                 let mut multipart = reqwest::multipart::Form::new();
 
-                for form_data in form_data {
-                    let key = self.replace_env_keys_by_value(&form_data.data.0);
-                    let value = self.replace_env_keys_by_value(&form_data.data.1);
-
-                    // If the value starts with !!, then it is supposed to be a file
-                    if value.starts_with("!!") {
-                        let path = PathBuf::from(&value[2..]);
-
-                        match get_file_content_with_name(path) {
-                            Ok((file_content, file_name)) => {
-                                let part = Part::bytes(file_content).file_name(file_name);
-                                multipart = multipart.part(key, part);
-                            }
-                            Err(_) => {
-                                return Err(PrepareRequestError::CouldNotOpenFile);
+                // Several parts may share the same name, e.g. to send many files
+                for part in parts.iter().filter(|part| part.enabled) {
+                    let name = self.variables.replace_env_keys_by_value(&part.name);
+                    let value = self.variables.replace_env_keys_by_value(&part.value);
+
+                    let mut multipart_part = match part.source {
+                        PartSource::Text => Part::text(value),
+                        PartSource::File => match get_file_content_with_name(PathBuf::from(&value)) {
+                            Ok((file_content, file_name)) => Part::bytes(file_content).file_name(file_name),
+                            Err(error) => {
+                                return Err(PrepareRequestError::CouldNotOpenFile(value, error.to_string()));
                             }
                         }
+                    };
+
+                    if let Some(file_name) = &part.file_name {
+                        multipart_part = multipart_part.file_name(self.variables.replace_env_keys_by_value(file_name));
                     }
-                    else {
-                        multipart = multipart.text(key, value);
+
+                    if let Some(content_type) = &part.content_type {
+                        let content_type = self.variables.replace_env_keys_by_value(content_type);
+
+                        multipart_part = multipart_part
+                            .mime_str(&content_type)
+                            .map_err(|_| PrepareRequestError::InvalidPartContentType(content_type))?;
                     }
+
+                    multipart = multipart.part(name, multipart_part);
                 }
 
                 request_builder = request_builder.multipart(multipart);
             },
             Form(form_data) => {
-                let form = self.key_value_vec_to_tuple_vec(&form_data);
+                let form = self.variables.key_value_vec_to_tuple_vec(form_data);
 
                 request_builder = request_builder.form(&form);
             },
-            File(file_path) => {
-                let file_path_with_env_values = self.replace_env_keys_by_value(&file_path);
-                let path = PathBuf::from(file_path_with_env_values);
+            File(body_file) => {
+                let file_path_with_env_values = self.variables.replace_env_keys_by_value(&body_file.path);
+                let path = PathBuf::from(&file_path_with_env_values);
 
                 match tokio::fs::File::open(path).await {
                     Ok(file) => {
-                        request_builder = request_builder.body(file);
+                        let file_length = file.metadata().await.map(|metadata| metadata.len()).unwrap_or(0);
+                        request.upload_progress.reset(file_length);
+
+                        let progress_reader = ProgressReader {
+                            inner: file,
+                            sent: Arc::clone(&request.upload_progress.sent),
+                        };
+
+                        let body = match BandwidthLimiter::new(request.settings.upload_limit) {
+                            None => reqwest::Body::wrap_stream(ReaderStream::new(progress_reader)),
+                            Some(bandwidth_limiter) => reqwest::Body::wrap_stream(ReaderStream::new(ThrottledReader::new(progress_reader, bandwidth_limiter)))
+                        };
+
+                        // The length is known, no need for a chunked upload
+                        request_builder = request_builder
+                            .header(CONTENT_LENGTH, file_length)
+                            .body(body);
                     }
-                    Err(_) => {
-                        return Err(PrepareRequestError::CouldNotOpenFile);
+                    Err(error) => {
+                        return Err(PrepareRequestError::CouldNotOpenFile(file_path_with_env_values, error.to_string()));
                     }
                 }
             },
             Raw(body) | Json(body) | Xml(body) | Html(body) | Javascript(body) => {
-                let body_with_env_values = self.replace_env_keys_by_value(body);
-                request_builder = request_builder.body(body_with_env_values);
-            }
+                // A SOAP body only holds the payload, the envelope is added around it
+                let body_with_env_values = match &modified_request.soap {
+                    None => self.variables.replace_env_keys_by_value(body),
+                    Some(soap) => self.variables.replace_env_keys_by_value(&soap.wrap_in_envelope(body))
+                };
+
+                let content_type = modified_request.headers
+                    .iter()
+                    .find(|header| header.enabled && header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()))
+                    .map(|header| self.variables.replace_env_keys_by_value(&header.data.1));
+
+                // A body encoder plugin may handle the content type, e.g. to serialize the text body into a binary format
+                let body_encoder = content_type.as_ref().and_then(|content_type| get_plugins_with_hook(PluginHook::BodyEncoder)
+                    .into_iter()
+                    .find(|plugin| plugin.encodes_content_type(content_type))
+                );
+
+                match body_encoder {
+                    None => request_builder = request_builder.body(body_with_env_values),
+                    Some(body_encoder) => {
+                        let input = BodyEncoderInput {
+                            content_type: content_type.unwrap(),
+                            body: body_with_env_values,
+                        };
+
+                        let output: BodyEncoderOutput = run_plugin(&body_encoder, PluginHook::BodyEncoder, &input)
+                            .map_err(|error| PrepareRequestError::Plugin(error.to_string()))?;
+
+                        request_builder = match (output.body_base64, output.body) {
+                            (Some(body_base64), _) => match STANDARD.decode(body_base64) {
+                                Ok(body) => request_builder.body(body),
+                                Err(error) => return Err(PrepareRequestError::Plugin(format!("\"{}\" answered an invalid base64 body {error}", body_encoder.manifest.name)))
+                            },
+                            (None, Some(body)) => request_builder.body(body),
+                            (None, None) => request_builder
+                        };
+                    }
+                }
+            },
+            Protobuf(json) => {
+                let json_with_env_values = self.variables.replace_env_keys_by_value(json);
+
+                let encoded_message = match &request.protobuf {
+                    None => Err(ProtobufError::NoSchema),
+                    Some(protobuf) => protobuf.encode_request(&json_with_env_values)
+                };
+
+                match encoded_message {
+                    Ok(encoded_message) => request_builder = request_builder.body(encoded_message),
+                    Err(error) => return Err(PrepareRequestError::ProtobufEncoding(error.to_string()))
+                }
+            },
+            MessagePack(json) => request_builder = request_builder.body(encode_binary_body(&self.variables.replace_env_keys_by_value(json), BinaryFormat::MessagePack)?),
+            Cbor(json) => request_builder = request_builder.body(encode_binary_body(&self.variables.replace_env_keys_by_value(json), BinaryFormat::Cbor)?)
         };
 
+        /* DEFAULT HEADERS */
+
+        for (header_name, header_value) in self.config.get_default_headers() {
+            let is_overridden = modified_request.headers
+                .iter()
+                .any(|header| header.enabled && header.data.0.eq_ignore_ascii_case(&header_name));
+
+            if is_overridden {
+                continue;
+            }
+
+            let header_name = self.variables.replace_env_keys_by_value(&header_name);
+            let header_value = self.variables.replace_env_keys_by_value(&header_value);
+
+            request_builder = request_builder.header(header_name, header_value);
+        }
+
         /* HEADERS */
 
         for header in &modified_request.headers {
@@ -263,69 +452,241 @@ impl App<'_> {
                 continue;
             }
 
-            let header_name = self.replace_env_keys_by_value(&header.data.0);
-            let header_value = self.replace_env_keys_by_value(&header.data.1);
+            let header_name = self.variables.replace_env_keys_by_value(&header.data.0);
+            let header_value = self.variables.replace_env_keys_by_value(&header.data.1);
 
             request_builder = request_builder.header(header_name, header_value);
         }
 
+        /* SOAP ACTION */
+
+        if let Some(soap_action) = modified_request.soap.as_ref().and_then(RequestSoap::get_soap_action_header) {
+            let is_overridden = modified_request.headers
+                .iter()
+                .any(|header| header.enabled && header.data.0.eq_ignore_ascii_case("soapaction"));
+
+            if !is_overridden {
+                request_builder = request_builder.header("SOAPAction", self.variables.replace_env_keys_by_value(&soap_action));
+            }
+        }
+
+        /* REQUEST COOKIES */
+
+        let request_cookies = self.variables.key_value_vec_to_tuple_vec(&modified_request.cookies);
+
+        if !request_cookies.is_empty() {
+            // A Cookie header prevents reqwest from adding the jar cookies, so they are merged here
+            let mut cookies: Vec<(String, String)> = self.cookie_store.read().unwrap()
+                .get_request_values(&url)
+                .filter(|(name, _)| !request_cookies.iter().any(|(request_cookie_name, _)| request_cookie_name == name))
+                .map(|(name, value)| (name.to_string(), value.to_string()))
+                .collect();
+
+            cookies.extend(request_cookies);
+
+            let cookie_header = cookies
+                .iter()
+                .map(|(name, value)| format!("{name}={value}"))
+                .collect::<Vec<String>>()
+                .join("; ");
+
+            request_builder = request_builder.header(COOKIE, cookie_header);
+        }
+
+        /* HMAC SIGNATURE */
+
+        if let HmacSignature { secret, string_to_sign, header, algorithm, encoding } = &modified_request.auth {
+            // Streamed bodies (file, multipart) cannot be read beforehand, they are signed as empty
+            let body = request_builder
+                .try_clone()
+                .and_then(|request_builder| request_builder.build().ok())
+                .and_then(|request| request.body().and_then(|body| body.as_bytes()).map(|body| body.to_vec()))
+                .unwrap_or_default();
+
+            let secret = self.variables.replace_env_keys_by_value(secret);
+            let string_to_sign = self.variables.replace_env_keys_by_value(string_to_sign);
+            let header = self.variables.replace_env_keys_by_value(header);
+
+            let string_to_sign = get_string_to_sign(&string_to_sign, &modified_request.method, &url, &body);
+            let signature = sign_hmac(algorithm, encoding, &secret, &string_to_sign);
+
+            request_builder = request_builder.header(header, signature);
+        }
+
+        /* OAUTH 1.0A SIGNATURE */
+
+        if let OAuth1 { consumer_key, consumer_secret, token, token_secret, signature_method, placement } = &modified_request.auth {
+            let form_params = match &modified_request.body {
+                Form(form_data) => self.variables.key_value_vec_to_tuple_vec(form_data),
+                _ => vec![]
+            };
+
+            let oauth1_config = OAuth1Config {
+                consumer_key: self.variables.replace_env_keys_by_value(consumer_key),
+                consumer_secret: self.variables.replace_env_keys_by_value(consumer_secret),
+                token: self.variables.replace_env_keys_by_value(token),
+                token_secret: self.variables.replace_env_keys_by_value(token_secret),
+                signature_method: *signature_method,
+            };
+
+            let oauth_params = get_oauth1_params(&oauth1_config, &modified_request.method, &url, &form_params);
+
+            let oauth_params = match oauth_params {
+                Ok(oauth_params) => oauth_params,
+                Err(error) => return Err(PrepareRequestError::OAuth1Signature(error.to_string()))
+            };
+
+            request_builder = match placement {
+                OAuth1Placement::Header => request_builder.header(AUTHORIZATION, get_oauth1_header(&oauth_params)),
+                OAuth1Placement::Query => request_builder.query(&oauth_params)
+            };
+        }
+
+        /* PLUGIN AUTH */
+
+        if let Plugin { plugin, config } = &modified_request.auth {
+            let plugin_name = self.variables.replace_env_keys_by_value(plugin);
+            let auth_plugin = find_plugin(&plugin_name, PluginHook::Auth).map_err(|error| PrepareRequestError::Plugin(error.to_string()))?;
+
+            // Streamed bodies (file, multipart) cannot be read beforehand, they are given as none
+            let built_request = request_builder
+                .try_clone()
+                .and_then(|request_builder| request_builder.build().ok());
+
+            let (headers, body) = match &built_request {
+                None => (vec![], None),
+                Some(built_request) => (
+                    built_request.headers()
+                        .iter()
+                        .map(|(name, value)| (name.to_string(), String::from_utf8_lossy(value.as_bytes()).to_string()))
+                        .collect(),
+                    built_request.body()
+                        .and_then(|body| body.as_bytes())
+                        .and_then(|body| String::from_utf8(body.to_vec()).ok())
+                )
+            };
+
+            let input = AuthInput {
+                config: self.variables.replace_env_keys_by_value(config),
+                method: modified_request.method.to_string(),
+                url: built_request.as_ref().map(|built_request| built_request.url().to_string()).unwrap_or(url.to_string()),
+                headers,
+                body,
+            };
+
+            let output: AuthOutput = run_plugin(&auth_plugin, PluginHook::Auth, &input)
+                .map_err(|error| PrepareRequestError::Plugin(error.to_string()))?;
+
+            for (header_name, header_value) in output.headers {
+                request_builder = request_builder.header(header_name, header_value);
+            }
+
+            if !output.query.is_empty() {
+                request_builder = request_builder.query(&output.query);
+            }
+        }
+
         trace!("Request prepared");
 
         Ok((request_builder, console_output))
     }
 }
 
+/// JSON body, its environment values already substituted, encoded in the format of the body
+fn encode_binary_body(json: &str, binary_format: BinaryFormat) -> Result<Vec<u8>, PrepareRequestError> {
+    return binary_format
+        .encode_json(json)
+        .map_err(|error| PrepareRequestError::BinaryBodyEncoding(error.to_string()));
+}
+
 #[derive(Error, Debug)]
 pub enum RequestResponseError {
     #[error("(CONSOLE) POST-SCRIPT ERROR")]
     PostRequestScript,
 }
 
-pub async fn send_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, env: &Option<Arc<RwLock<Environment>>>) -> Result<(RequestResponse, String, Option<Vec<Line<'static>>>), RequestResponseError> {
+/// Send the request, or answer it from its snapshot when offline
+pub async fn send_request(prepared_request: reqwest_middleware::RequestBuilder, local_request: Arc<RwLock<Request>>, env: &Option<Arc<RwLock<Environment>>>, offline: bool) -> Result<(RequestResponse, String, Option<String>), RequestResponseError> {
     info!("Sending request");
 
     local_request.write().is_pending = true;
 
+    let cancellation_token = local_request.read().cancellation_token.clone();
+
+    /* CONCURRENT REQUESTS LIMIT */
+
+    // Waited for before locking the request, the request stays pending and can be canceled meanwhile
+    let _request_slot = match offline {
+        true => None,
+        false => wait_for_request_slot(&cancellation_token).await
+    };
+
     let request = local_request.read();
 
-    let cancellation_token = request.cancellation_token.clone();
     let timeout = tokio::time::sleep(Duration::from_secs(30));
 
     let request_start = Instant::now();
     let elapsed_time: Duration;
 
-    let mut highlighted_result_body: Option<Vec<Line>> = None;
+    let mut response_file_format: Option<String> = None;
 
+    // Offline, every branch is disabled and the snapshot is answered instead
     let mut response = tokio::select! {
-        _ = cancellation_token.cancelled() => {
+        _ = cancellation_token.cancelled(), if !offline => {
             elapsed_time = request_start.elapsed();
             
             RequestResponse {
                 duration: None,
-                status_code: Some(String::from("CANCELED")),
+                result: Some(RequestResult::local_error("CANCELED")),
                 content: None,
+                raw_body: None,
                 cookies: None,
                 headers: vec![],
+                charset: None,
+                tests: vec![],
+                cache_status: None,
+                is_offline: false,
+                processed_body: None,
+                soap_fault: None,
+                dns_resolution: None,
+                wire: None,
+                trailers: vec![],
             }
         },
-        _ = timeout => {
+        _ = timeout, if !offline => {
             elapsed_time = request_start.elapsed();
 
             RequestResponse {
                 duration: None,
-                status_code: Some(String::from("TIMEOUT")),
+                result: Some(RequestResult::TransportError(RequestError::timed_out())),
                 content: None,
+                raw_body: None,
                 cookies: None,
                 headers: vec![],
+                charset: None,
+                tests: vec![],
+                cache_status: None,
+                is_offline: false,
+                processed_body: None,
+                soap_fault: None,
+                dns_resolution: None,
+                wire: None,
+                trailers: vec![],
             }
         },
-        response = prepared_request.send() => match response {
-            Ok(response) => {
+        response = send_prepared_request(prepared_request, &request.settings), if !offline => match response {
+            Ok((response, sent_method, sent_headers)) => {
                 elapsed_time = request_start.elapsed();
 
-                let status_code = response.status().to_string();
+                let status_code = response.status().as_u16();
+
+                let wire = WireExchange::new(&sent_method, response.url(), &sent_headers, response.version(), response.status(), response.headers());
 
                 let mut is_image = false;
+                let mut is_protobuf = false;
+                let mut binary_format: Option<BinaryFormat> = None;
+                let mut is_ndjson = false;
+                let mut charset: Option<String> = None;
 
                 let headers: Vec<(String, String)> = response.headers().clone()
                     .iter()
@@ -336,10 +697,27 @@ pub async fn send_request(prepared_request: reqwest_middleware::RequestBuilder,
                             is_image = true;
                         }
 
+                        if header_name == CONTENT_TYPE && is_protobuf_content_type(&value) {
+                            is_protobuf = true;
+                        }
+
+                        if header_name == CONTENT_TYPE {
+                            binary_format = BinaryFormat::from_content_type(&value);
+                            is_ndjson = is_ndjson_content_type(&value);
+                        }
+
                         (header_name.to_string(), value)
                     })
                     .collect();
 
+                // The body is the whole raw response, displayed as received
+                if request.settings.raw_socket {
+                    is_image = false;
+                    is_protobuf = false;
+                    binary_format = None;
+                    is_ndjson = false;
+                }
+
                 let cookies = response.cookies()
                     .par_bridge()
                     .map(|cookie| {
@@ -348,68 +726,188 @@ pub async fn send_request(prepared_request: reqwest_middleware::RequestBuilder,
                     .collect::<Vec<String>>()
                     .join("\n");
 
+                // The pooled connections are not resolved again, the last resolution of the host is the one they use
+                let dns_resolution = response.url().host_str().and_then(get_last_dns_resolution);
+
+                let mut response_body = ResponseBody::new(response);
+
+                let max_response_bytes = request.settings.get_max_response_bytes();
+                let response_output = request.response_output.as_deref();
+
+                // The received bytes, when the displayed text is not a view over them
+                let mut raw_body: Option<RawBody> = None;
+
                 let response_content = match is_image {
-                    true => {
-                        let content = response.bytes().await.unwrap();
-                        let image = image::load_from_memory(content.as_ref());
-
-                        ResponseContent::Image(ImageResponse {
-                            data: content.to_vec(),
-                            image: image.ok(),
-                        })
+                    true => match read_response_body(&mut response_body, &cancellation_token, max_response_bytes, request.settings.download_limit, response_output).await {
+                        // A truncated image cannot be decoded
+                        (content, true) => {
+                            raw_body = Some(RawBody::new(content, &headers, None, Some(get_truncation_notice(max_response_bytes))));
+                            ResponseContent::Body(BodyText::Owned(get_truncation_notice(max_response_bytes)))
+                        },
+                        (content, false) => {
+                            let image = image::load_from_memory(&content);
+
+                            ResponseContent::Image(ImageResponse {
+                                data: content,
+                                image: image.ok(),
+                            })
+                        }
                     },
                     false => {
-                        let mut result_body = response.text().await.unwrap();
-
-                        // If a file format has been found in the content-type header
-                        if let Some(file_format) = find_file_format_in_content_type(&headers) {
-                            // If the request response content can be pretty printed
-                            if request.settings.pretty_print_response_content {
-                                // Match the file format
-                                match file_format.as_str() {
-                                    "json" => {
-                                        result_body = jsonxf::pretty_print(&result_body).unwrap_or(result_body);
-                                    },
-                                    _ => {}
+                        // Decoded as JSON when the protobuf schema has a response message
+                        let protobuf_schema = request.protobuf
+                            .as_ref()
+                            .filter(|protobuf_schema| is_protobuf && protobuf_schema.response_message.is_some());
+
+                        let (mut result_body, file_format): (BodyText, Option<String>) = match (protobuf_schema, binary_format) {
+                            (Some(protobuf_schema), _) => match read_response_body(&mut response_body, &cancellation_token, max_response_bytes, request.settings.download_limit, response_output).await {
+                                (content, true) => {
+                                    raw_body = Some(RawBody::new(content, &headers, None, Some(get_truncation_notice(max_response_bytes))));
+                                    (BodyText::Owned(get_truncation_notice(max_response_bytes)), None)
+                                },
+                                (content, false) => {
+                                    let decoded_body = match protobuf_schema.decode_response(&content) {
+                                        Ok(json) => (BodyText::Owned(json), Some(String::from("json"))),
+                                        Err(error) => (BodyText::Owned(error.to_string()), None)
+                                    };
+
+                                    raw_body = Some(RawBody::new(content, &headers, None, None));
+                                    decoded_body
+                                }
+                            },
+                            // MessagePack and CBOR responses are displayed as JSON
+                            (None, Some(binary_format)) => match read_response_body(&mut response_body, &cancellation_token, max_response_bytes, request.settings.download_limit, response_output).await {
+                                (content, true) => {
+                                    raw_body = Some(RawBody::new(content, &headers, None, Some(get_truncation_notice(max_response_bytes))));
+                                    (BodyText::Owned(get_truncation_notice(max_response_bytes)), None)
+                                },
+                                (content, false) => {
+                                    let decoded_body = match binary_format.decode_to_json(&content) {
+                                        Ok(json) => (BodyText::Owned(json), Some(String::from("json"))),
+                                        Err(error) => (BodyText::Owned(error.to_string()), None)
+                                    };
+
+                                    raw_body = Some(RawBody::new(content, &headers, None, None));
+                                    decoded_body
+                                }
+                            },
+                            // Received line by line, long-polling responses are displayed while they arrive
+                            (None, None) if is_ndjson => {
+                                let result_body = read_response_stream(&mut response_body, &request.response_stream, &cancellation_token, max_response_bytes, request.settings.download_limit, response_output).await;
+
+                                (BodyText::Owned(result_body), Some(String::from("ndjson")))
+                            },
+                            (None, None) => {
+                                let (content, is_truncated) = read_response_body(&mut response_body, &cancellation_token, max_response_bytes, request.settings.download_limit, response_output).await;
+                                // The raw responses are already decoded, their invalid bytes escaped
+                                let charset_override = match request.settings.raw_socket {
+                                    true => Some("utf-8"),
+                                    false => request.settings.response_charset.as_deref()
+                                };
+
+                                let truncation_notice = match is_truncated {
+                                    true => Some(get_truncation_notice(max_response_bytes)),
+                                    false => None
+                                };
+
+                                // Decoded on first use, the text is then shared by every use of the body
+                                let text_body = RawBody::new(content, &headers, charset_override, truncation_notice);
+                                charset = Some(text_body.encoding.name().to_string());
+
+                                let file_format = match request.settings.raw_socket {
+                                    true => None,
+                                    false => find_response_file_format(&headers, &text_body.get_received_text())
+                                };
+
+                                (BodyText::Raw(text_body), file_format)
+                            }
+                        };
+
+                        // If a file format has been found in the content-type header, or guessed from the content
+                        if let Some(file_format) = file_format {
+                            // If the request response content can be pretty printed, a truncated body being displayed as received
+                            if request.settings.pretty_print_response_content && !result_body.is_truncated() {
+                                // Match the file format, the received text is borrowed from the bytes when they are valid in their charset
+                                let pretty_body = {
+                                    let received_text = result_body.get_received_text();
+
+                                    match file_format.as_str() {
+                                        "json" => jsonxf::pretty_print(&received_text).ok(),
+                                        "xml" => pretty_print_xml(&received_text),
+                                        _ => None
+                                    }
+                                };
+
+                                // The received bytes are kept apart once the text no longer matches them
+                                if let Some(pretty_body) = pretty_body {
+                                    if let BodyText::Raw(text_body) = std::mem::replace(&mut result_body, BodyText::Owned(pretty_body)) {
+                                        raw_body = Some(text_body);
+                                    }
                                 }
                             }
 
-                            highlighted_result_body = highlight(&result_body, &file_format);
+                            response_file_format = Some(file_format);
                         }
 
                         ResponseContent::Body(result_body)
                     }
                 };
 
+                // Canceled while its body was being received, the received part is kept. A stream is stopped by canceling it
+                let result = match cancellation_token.is_cancelled() && !is_ndjson {
+                    true => RequestResult::local_error("CANCELED"),
+                    false => RequestResult::Success { status: status_code }
+                };
+
                 RequestResponse {
                     duration: None,
-                    status_code: Some(status_code),
+                    result: Some(result),
                     content: Some(response_content),
+                    raw_body,
                     cookies: Some(cookies),
                     headers,
+                    charset,
+                    tests: vec![],
+                    cache_status: None,
+                    is_offline: false,
+                    processed_body: None,
+                    soap_fault: None,
+                    dns_resolution,
+                    wire: Some(wire),
+                    trailers: response_body.get_trailers(),
                 }
             },
             Err(error) => {
                 elapsed_time = request_start.elapsed();
 
-                let response_status_code;
-
-                if let Some(status_code) = error.status() {
-                    response_status_code = Some(status_code.to_string());
-                } else {
-                    response_status_code = None;
-                }
-
-                let result_body = ResponseContent::Body(error.to_string());
+                let result_body = ResponseContent::Body(BodyText::Owned(error.to_string()));
 
                 RequestResponse {
                     duration: None,
-                    status_code: response_status_code,
+                    result: Some(RequestResult::TransportError(RequestError::from_send_error(&error))),
                     content: Some(result_body),
+                    raw_body: None,
                     cookies: None,
                     headers: vec![],
+                    charset: None,
+                    tests: vec![],
+                    cache_status: None,
+                    is_offline: false,
+                    processed_body: None,
+                    soap_fault: None,
+                    dns_resolution: None,
+                    wire: None,
+                    trailers: vec![],
                 }
             }
+        },
+        else => {
+            elapsed_time = request_start.elapsed();
+
+            let (offline_response, file_format) = get_offline_response(&request);
+            response_file_format = file_format;
+
+            offline_response
         }
     };
 
@@ -417,9 +915,70 @@ pub async fn send_request(prepared_request: reqwest_middleware::RequestBuilder,
 
     trace!("Request sent");
 
+    info!("\"{}\" answered {} in {elapsed_time:?}", request.name, response.get_status_text().unwrap_or(String::from("without any status")));
+
+    /* RESPONSE PROCESSOR */
+
+    // The raw body stays available, the other steps work on the processed one
+    if let (Some(response_processor), None, Some(ResponseContent::Body(body))) = (&request.scripts.response_processor, response.get_transport_error(), &response.content) {
+        match run_response_processor(response_processor, body) {
+            Ok(processed_body) => {
+                let raw_body = body.clone();
+
+                // The received bytes no longer match the displayed body
+                response.raw_body = None;
+                response.content = Some(ResponseContent::Body(BodyText::Owned(processed_body)));
+                response.processed_body = Some(ProcessedBody {
+                    other_body: raw_body,
+                    is_raw: false,
+                });
+            },
+            Err(error) => response.tests.push(TestResult::failed(String::from("Response processor"), vec![error]))
+        }
+    }
+
+    /* RESPONSE PROCESSOR PLUGINS */
+
+    // Only the text bodies of the received responses, in the plugin name order
+    if response.get_transport_error().is_none() {
+        for response_processor in get_plugins_with_hook(PluginHook::ResponseProcessor) {
+            let body = match &response.content {
+                Some(ResponseContent::Body(body)) => body.to_string(),
+                _ => break
+            };
+
+            let input = ResponseProcessorInput {
+                request_name: request.name.clone(),
+                status_code: response.get_status_text(),
+                headers: response.headers.clone(),
+                body,
+            };
+
+            let test_name = format!("Plugin \"{}\"", response_processor.manifest.name);
+
+            match run_plugin::<ResponseProcessorInput, ResponseProcessorOutput>(&response_processor, PluginHook::ResponseProcessor, &input) {
+                Ok(output) => {
+                    if let Some(body) = output.body {
+                        response.raw_body = None;
+                        response.content = Some(ResponseContent::Body(BodyText::Owned(body)));
+                    }
+
+                    for test in output.tests {
+                        response.tests.push(TestResult {
+                            name: format!("{test_name} {}", test.name),
+                            passed: test.passed,
+                            messages: test.messages,
+                        });
+                    }
+                },
+                Err(error) => response.tests.push(TestResult::failed(test_name, vec![error.to_string()]))
+            }
+        }
+    }
+
     /* POST-REQUEST SCRIPT */
 
-    let (modified_response, console_output): (RequestResponse, String) = match &request.scripts.post_request_script {
+    let (mut modified_response, console_output): (RequestResponse, String) = match &request.scripts.post_request_script {
         None => {
             (response, String::new())
         },
@@ -455,6 +1014,44 @@ pub async fn send_request(prepared_request: reqwest_middleware::RequestBuilder,
         }
     };
 
+    /* RESPONSE SCHEMA */
+
+    if let Some(response_schema) = &request.response_schema {
+        let test_result = response_schema.validate_response(&modified_response);
+        modified_response.tests.push(test_result);
+    }
+
+    /* SNAPSHOT */
+
+    // An offline response is the snapshot itself
+    if let (Some(snapshot), false) = (&request.snapshot, offline) {
+        if let Some(test_result) = snapshot.compare(&modified_response) {
+            modified_response.tests.push(test_result);
+        }
+    }
+
+    /* ASSERTIONS */
+
+    // An offline response was not timed
+    let assertion_elapsed_time = match offline {
+        true => None,
+        false => Some(elapsed_time)
+    };
+
+    for assertion in &request.assertions {
+        if let Some(test_result) = assertion.check(&modified_response, assertion_elapsed_time) {
+            modified_response.tests.push(test_result);
+        }
+    }
+
+    /* SOAP FAULT */
+
+    if let (Some(_), Some(ResponseContent::Body(body))) = (&request.soap, &modified_response.content) {
+        if let Some(soap_fault) = find_soap_fault(body) {
+            modified_response.tests.push(TestResult::failed(String::from("SOAP fault"), vec![soap_fault.to_message()]));
+            modified_response.soap_fault = Some(soap_fault);
+        }
+    }
 
     drop(request);
 
@@ -465,7 +1062,314 @@ pub async fn send_request(prepared_request: reqwest_middleware::RequestBuilder,
         request.cancellation_token = CancellationToken::new();
     }
         
-    return Ok((modified_response, console_output, highlighted_result_body));
+    return Ok((modified_response, console_output, response_file_format));
+}
+
+/// Response recorded in the request snapshot, and its file format
+fn get_offline_response(request: &Request) -> (RequestResponse, Option<String>) {
+    let offline_response = request.snapshot
+        .as_ref()
+        .and_then(|snapshot| snapshot.to_offline_response());
+
+    return match offline_response {
+        Some(offline_response) => {
+            let file_format = match &offline_response.content {
+                Some(ResponseContent::Body(body)) => find_response_file_format(&offline_response.headers, body),
+                _ => None
+            };
+
+            (offline_response, file_format)
+        },
+        // The body holds the error
+        None => (
+            RequestResponse {
+                duration: None,
+                result: Some(RequestResult::local_error("NO SNAPSHOT")),
+                content: Some(ResponseContent::Body(BodyText::Owned(String::from("Offline, no snapshot recorded for this request: atac request snapshot <COLLECTION>/<REQUEST> update")))),
+                raw_body: None,
+                cookies: None,
+                headers: vec![],
+                charset: None,
+                tests: vec![],
+                cache_status: None,
+                is_offline: true,
+                processed_body: None,
+                soap_fault: None,
+                dns_resolution: None,
+                wire: None,
+                trailers: vec![],
+            },
+            None
+        )
+    };
+}
+
+/// Read the response body, the bytes past the max size are dropped and the body is marked as truncated.
+/// The full body is still written to the output file, if any. A canceled request keeps the bytes received so far.
+async fn read_response_body(response: &mut ResponseBody, cancellation_token: &CancellationToken, max_bytes: Option<usize>, download_limit: usize, output: Option<&Path>) -> (Vec<u8>, bool) {
+    let mut bandwidth_limiter = BandwidthLimiter::new(download_limit);
+    let mut output_file = create_output_file(output);
+    let mut content: Vec<u8> = vec![];
+    let mut is_truncated = false;
+
+    loop {
+        let chunk = tokio::select! {
+            _ = cancellation_token.cancelled() => break,
+            chunk = response.chunk() => chunk
+        };
+
+        let chunk = match chunk {
+            Ok(Some(chunk)) => chunk,
+            Ok(None) => break,
+            Err(error) => {
+                warn!("Response body interrupted: {error}");
+                break;
+            }
+        };
+
+        if let Some(bandwidth_limiter) = &mut bandwidth_limiter {
+            tokio::select! {
+                _ = cancellation_token.cancelled() => break,
+                _ = bandwidth_limiter.wait(chunk.len()) => {}
+            }
+        }
+
+        write_output_file(&mut output_file, &chunk);
+
+        if is_truncated {
+            continue;
+        }
+
+        match max_bytes {
+            Some(max_bytes) if content.len() + chunk.len() > max_bytes => {
+                content.extend_from_slice(&chunk[..max_bytes - content.len()]);
+                is_truncated = true;
+
+                // Nothing else needs the rest of the body
+                if output_file.is_none() {
+                    break;
+                }
+            },
+            _ => content.extend_from_slice(&chunk)
+        }
+    }
+
+    return (content, is_truncated);
+}
+
+fn create_output_file(output: Option<&Path>) -> Option<fs::File> {
+    let output = output?;
+
+    return match fs::File::create(output) {
+        Ok(output_file) => Some(output_file),
+        Err(error) => {
+            warn!("Could not create the output file \"{}\": {error}", output.display());
+            None
+        }
+    };
+}
+
+/// Writing stops at the first error
+fn write_output_file(output_file: &mut Option<fs::File>, bytes: &[u8]) {
+    if let Some(file) = output_file {
+        if let Err(error) = file.write_all(bytes) {
+            warn!("Could not write the response body to the output file: {error}");
+            *output_file = None;
+        }
+    }
+}
+
+fn get_truncation_notice(max_bytes: Option<usize>) -> String {
+    return format!(
+        "[Response truncated at {} bytes (max response size setting), save it to a file for the full body: atac request send <COLLECTION>/<REQUEST> --output <FILE>]",
+        max_bytes.unwrap_or_default()
+    );
+}
+
+/// Read a newline-delimited response line by line, the received lines being shared with the UI.
+/// A canceled request keeps the lines received so far.
+async fn read_response_stream(response: &mut ResponseBody, response_stream: &ResponseStream, cancellation_token: &CancellationToken, max_bytes: Option<usize>, download_limit: usize, output: Option<&Path>) -> String {
+    // Drops the lines of the previous response
+    response_stream.take();
+
+    let mut bandwidth_limiter = BandwidthLimiter::new(download_limit);
+    let mut output_file = create_output_file(output);
+    let mut pending_bytes: Vec<u8> = vec![];
+    let mut received_bytes: usize = 0;
+    let mut is_truncated = false;
+
+    loop {
+        let chunk = tokio::select! {
+            _ = cancellation_token.cancelled() => break,
+            chunk = response.chunk() => chunk
+        };
+
+        match chunk {
+            Ok(Some(chunk)) => {
+                if let Some(bandwidth_limiter) = &mut bandwidth_limiter {
+                    tokio::select! {
+                        _ = cancellation_token.cancelled() => break,
+                        _ = bandwidth_limiter.wait(chunk.len()) => {}
+                    }
+                }
+
+                write_output_file(&mut output_file, &chunk);
+
+                if is_truncated {
+                    continue;
+                }
+
+                received_bytes += chunk.len();
+                pending_bytes.extend_from_slice(&chunk);
+            },
+            Ok(None) => break,
+            Err(error) => {
+                warn!("Response stream interrupted: {error}");
+                break;
+            }
+        }
+
+        while let Some(newline_index) = pending_bytes.iter().position(|byte| *byte == b'\n') {
+            let line: Vec<u8> = pending_bytes.drain(..=newline_index).collect();
+            push_stream_line(response_stream, &line);
+        }
+
+        if max_bytes.is_some_and(|max_bytes| received_bytes > max_bytes) {
+            is_truncated = true;
+
+            // Nothing else needs the rest of the body
+            if output_file.is_none() {
+                break;
+            }
+        }
+    }
+
+    // The last line may not end with a newline
+    push_stream_line(response_stream, &pending_bytes);
+
+    if is_truncated {
+        response_stream.push(get_truncation_notice(max_bytes));
+    }
+
+    return response_stream.take().join("\n");
+}
+
+fn push_stream_line(response_stream: &ResponseStream, bytes: &[u8]) {
+    let line = String::from_utf8_lossy(bytes).trim_end().to_string();
+
+    if !line.is_empty() {
+        response_stream.push(line);
+    }
+}
+
+/// Send the request over a raw socket or with the HTTP client, depending on the request settings
+async fn send_prepared_request(prepared_request: reqwest_middleware::RequestBuilder, settings: &RequestSettings) -> reqwest_middleware::Result<(reqwest::Response, reqwest::Method, HeaderMap)> {
+    return match settings.raw_socket {
+        true => send_raw_socket_request(prepared_request, settings).await,
+        false => send_following_redirects(prepared_request, settings).await
+    };
+}
+
+/// Send the request and follow its redirects according to the request settings
+/// The last response, with the method and the headers of the request it answers
+async fn send_following_redirects(prepared_request: reqwest_middleware::RequestBuilder, settings: &RequestSettings) -> reqwest_middleware::Result<(reqwest::Response, reqwest::Method, HeaderMap)> {
+    let (client, request) = prepared_request.build_split();
+    let mut request = request?;
+    let mut redirect_count = 0;
+
+    loop {
+        // Header only, the client cannot hold the body until the 100 Continue so it is sent right away
+        if settings.expect_continue_header && is_large_body(&request) && !request.headers().contains_key(EXPECT) {
+            request.headers_mut().insert(EXPECT, HeaderValue::from_static("100-continue"));
+        }
+
+        let method = request.method().clone();
+        let headers = request.headers().clone();
+        let previous_url = request.url().clone();
+        // Streamed bodies (files) cannot be cloned
+        let retry_request = request.try_clone();
+
+        throttle_request_body(&mut request, settings.upload_limit);
+
+        if settings.simulated_latency > 0 {
+            tokio::time::sleep(Duration::from_millis(settings.simulated_latency as u64)).await;
+        }
+
+        let response = client.execute(request).await?;
+
+        if !settings.allow_redirects || !response.status().is_redirection() {
+            return Ok((response, method, headers));
+        }
+
+        let next_url = match response.headers().get(LOCATION).and_then(|location| location.to_str().ok()) {
+            Some(location) => match previous_url.join(location) {
+                Ok(next_url) => next_url,
+                Err(_) => return Ok((response, method, headers))
+            },
+            None => return Ok((response, method, headers))
+        };
+
+        if redirect_count >= settings.max_redirects {
+            warn!("Max redirects reached, \"{next_url}\" was not followed and the last redirect response is kept");
+            return Ok((response, method, headers));
+        }
+
+        let mut next_request = match response.status() {
+            // The body is dropped and the method becomes GET
+            StatusCode::MOVED_PERMANENTLY | StatusCode::FOUND | StatusCode::SEE_OTHER => {
+                let next_method = match method {
+                    reqwest::Method::HEAD => reqwest::Method::HEAD,
+                    _ => reqwest::Method::GET
+                };
+
+                let mut next_request = reqwest::Request::new(next_method, next_url.clone());
+                *next_request.headers_mut() = headers.clone();
+                next_request.headers_mut().remove(CONTENT_TYPE);
+                next_request.headers_mut().remove(CONTENT_LENGTH);
+
+                next_request
+            },
+            // The method and the body are kept
+            StatusCode::TEMPORARY_REDIRECT | StatusCode::PERMANENT_REDIRECT => match retry_request {
+                Some(retry_request) if retry_request.body().is_none() || settings.resend_body_on_redirect => retry_request,
+                _ => return Ok((response, method, headers))
+            },
+            _ => return Ok((response, method, headers))
+        };
+
+        *next_request.url_mut() = next_url.clone();
+
+        // Scheme, host and port
+        let is_cross_origin = next_url.origin() != previous_url.origin();
+        // The credentials would be sent in cleartext, whatever the request settings
+        let is_downgrade = previous_url.scheme() == "https" && next_url.scheme() == "http";
+
+        if is_cross_origin {
+            // The cookie jar will add the cookies of the new host
+            next_request.headers_mut().remove(COOKIE);
+
+            if settings.strip_authorization_on_redirect || is_downgrade {
+                next_request.headers_mut().remove(AUTHORIZATION);
+                next_request.headers_mut().remove(PROXY_AUTHORIZATION);
+            }
+        }
+
+        trace!("Following redirect to \"{next_url}\"");
+
+        redirect_count += 1;
+        request = next_request;
+    }
+}
+
+/// Streamed bodies are files, their size is not known
+fn is_large_body(request: &reqwest::Request) -> bool {
+    return match request.body() {
+        None => false,
+        Some(body) => match body.as_bytes() {
+            Some(bytes) => bytes.len() >= EXPECT_CONTINUE_MIN_BODY_SIZE,
+            None => true
+        }
+    };
 }
 
 pub fn get_file_content_with_name(path: PathBuf) -> std::io::Result<(Vec<u8>, String)> {
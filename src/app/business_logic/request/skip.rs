@@ -0,0 +1,24 @@
+use tracing::info;
+
+use crate::app::app::App;
+
+impl App<'_> {
+    /// A skipped request is left out of the collection runs
+    pub fn modify_request_skipped(&mut self, collection_index: usize, request_index: usize, skipped: bool) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match skipped {
+                true => info!("Request skipped in the collection runs"),
+                false => info!("Request included in the collection runs")
+            }
+
+            selected_request.skipped = skipped;
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+}
@@ -0,0 +1,25 @@
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::snapshot::ResponseSnapshot;
+
+impl App<'_> {
+    pub fn modify_request_snapshot(&mut self, collection_index: usize, request_index: usize, snapshot: Option<ResponseSnapshot>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &snapshot {
+                None => info!("Snapshot removed"),
+                Some(_) => info!("Snapshot updated")
+            }
+
+            selected_request.snapshot = snapshot;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,42 @@
+use reqwest::header::CONTENT_TYPE;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::body::ContentType;
+use crate::models::soap::RequestSoap;
+
+impl App<'_> {
+    pub fn modify_request_soap(&mut self, collection_index: usize, request_index: usize, soap: Option<RequestSoap>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            // The content type tells the SOAP version, and holds the action with SOAP 1.2
+            match &soap {
+                None => {
+                    info!("SOAP mode removed");
+
+                    match &selected_request.body {
+                        ContentType::NoBody | ContentType::Multipart(_) => {},
+                        body => {
+                            let content_type = body.to_content_type();
+                            selected_request.modify_or_create_header(CONTENT_TYPE.as_str(), &content_type);
+                        }
+                    }
+                },
+                Some(soap) => {
+                    info!("SOAP mode set to {}", soap.to_summary());
+
+                    selected_request.modify_or_create_header(CONTENT_TYPE.as_str(), &soap.get_content_type());
+                }
+            }
+
+            selected_request.soap = soap;
+        }
+
+        self.save_collection_to_file(collection_index);
+
+        Ok(())
+    }
+}
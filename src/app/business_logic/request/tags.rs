@@ -0,0 +1,24 @@
+use tracing::info;
+
+use crate::app::app::App;
+
+impl App<'_> {
+    /// No tags removes them
+    pub fn modify_request_tags(&mut self, collection_index: usize, request_index: usize, tags: Vec<String>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match tags.is_empty() {
+                true => info!("Request tags removed"),
+                false => info!("Request tags set to {}", tags.join(", "))
+            }
+
+            selected_request.tags = tags;
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+}
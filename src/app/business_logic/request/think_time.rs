@@ -0,0 +1,25 @@
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::think_time::ThinkTime;
+
+impl App<'_> {
+    /// Pause of the runs after the request, None uses the one of the collection
+    pub fn modify_request_think_time(&mut self, collection_index: usize, request_index: usize, think_time: Option<ThinkTime>) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let mut selected_request = local_selected_request.write();
+
+            match &think_time {
+                Some(think_time) => info!("Request think time set to {think_time}"),
+                None => info!("Request think time removed")
+            }
+
+            selected_request.think_time = think_time;
+        }
+
+        self.save_collection_to_file(collection_index);
+        Ok(())
+    }
+}
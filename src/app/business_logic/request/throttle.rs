@@ -0,0 +1,51 @@
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::RwLock;
+
+use crate::models::rate_limit::RateLimitOptions;
+
+/// Longest wait before sending again a request answered with a 429, a longer Retry-After gives up
+pub const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+/// Spaces the requests of a run out, shared by the requests waiting for their response at the same time
+#[derive(Clone)]
+pub struct Throttle {
+    interval: Duration,
+    /// Time the next request can be sent at
+    next_start: Arc<RwLock<Instant>>,
+}
+
+impl Throttle {
+    pub fn new(options: &RateLimitOptions) -> Throttle {
+        Throttle {
+            interval: options.get_interval(),
+            next_start: Arc::new(RwLock::new(Instant::now())),
+        }
+    }
+
+    /// Waits for the turn of the next request
+    pub async fn wait(&self) {
+        let start = {
+            let mut next_start = self.next_start.write();
+            let start = Instant::max(*next_start, Instant::now());
+
+            *next_start = start + self.interval;
+
+            start
+        };
+
+        tokio::time::sleep_until(start.into()).await;
+    }
+
+    /// Delays every following request, the whole run slows down when the server rate limits it
+    pub fn back_off(&self, duration: Duration) {
+        let mut next_start = self.next_start.write();
+        *next_start = Instant::max(*next_start, Instant::now() + duration);
+    }
+}
+
+/// Wait before the next attempt when the server gives no Retry-After, 1s then 2s, 4s, ...
+pub fn get_exponential_backoff(attempt: u32) -> Duration {
+    return Duration::from_secs(2u64.saturating_pow(attempt)).min(MAX_BACKOFF);
+}
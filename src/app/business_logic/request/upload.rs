@@ -0,0 +1,27 @@
+use std::io;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+
+use tokio::io::{AsyncRead, ReadBuf};
+
+/// Counts the bytes of the body file read by the client, which reads it as it sends it
+pub struct ProgressReader<R> {
+    pub inner: R,
+    pub sent: Arc<AtomicU64>,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
+    fn poll_read(mut self: Pin<&mut Self>, cx: &mut Context<'_>, buf: &mut ReadBuf<'_>) -> Poll<io::Result<()>> {
+        let filled_length = buf.filled().len();
+        let poll = Pin::new(&mut self.inner).poll_read(cx, buf);
+
+        if let Poll::Ready(Ok(())) = &poll {
+            let read_length = buf.filled().len() - filled_length;
+            self.sent.fetch_add(read_length as u64, Ordering::Relaxed);
+        }
+
+        return poll;
+    }
+}
@@ -11,7 +11,29 @@ impl App<'_> {
         self.collections[selected_request_index.0].requests[selected_request_index.1].clone()
     }
 
+    /// Whether the request is the one shown, its send may have started before the selection changed
+    pub fn is_selected_request(&self, local_request: &Arc<RwLock<Request>>) -> bool {
+        return match &self.collections_tree.selected {
+            None => false,
+            Some(selected_request_index) => Arc::ptr_eq(local_request, &self.get_request_as_local_from_indexes(selected_request_index))
+        };
+    }
+
     pub fn get_request_as_local_from_indexes(&self, selected_request_index: &(usize, usize)) -> Arc<RwLock<Request>> {
         self.collections[selected_request_index.0].requests[selected_request_index.1].clone()
     }
-}
\ No newline at end of file
+
+    /// Login request of the collection holding the given request, unless it is the login request itself
+    pub fn get_login_request_as_local(&self, local_request: &Arc<RwLock<Request>>) -> Option<Arc<RwLock<Request>>> {
+        let collection = self.collections
+            .iter()
+            .find(|collection| collection.requests.iter().any(|request| Arc::ptr_eq(request, local_request)))?;
+
+        let login_request_name = collection.login_request.as_ref()?;
+
+        return collection.requests
+            .iter()
+            .find(|request| !Arc::ptr_eq(request, local_request) && &request.read().name == login_request_name)
+            .cloned();
+    }
+}
@@ -0,0 +1,197 @@
+use reqwest::header::{CONTENT_LENGTH, CONTENT_TYPE};
+use reqwest::Url;
+use serde_json::Value;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::body::ContentType;
+use crate::models::method::Method;
+use crate::models::request::Request;
+use crate::models::response::ResponseContent;
+
+/// Links kept from a single response
+const MAX_RESPONSE_LINKS: usize = 500;
+
+/// Hypermedia link found in a JSON response body
+#[derive(Debug, Clone)]
+pub struct ResponseLink {
+    /// Relation of the link, e.g. "next" or "self"
+    pub rel: String,
+    pub href: String,
+    /// Where the link was found in the body, e.g. "Body > _embedded > orders[0] > _links"
+    pub path: String,
+    pub title: Option<String>,
+    /// HAL URI template to complete, e.g. "/orders{?page}"
+    pub templated: bool,
+}
+
+/// HAL (_links), JSON:API (links) and OData (@odata.nextLink, __next, __deferred) links of the body
+pub fn find_response_links(body: &Value) -> Vec<ResponseLink> {
+    let mut links: Vec<ResponseLink> = vec![];
+
+    find_json_links(body, String::from("Body"), &mut links);
+
+    links.dedup_by(|link, other_link| link.rel == other_link.rel && link.href == other_link.href);
+    links.truncate(MAX_RESPONSE_LINKS);
+
+    return links;
+}
+
+fn find_json_links(value: &Value, path: String, links: &mut Vec<ResponseLink>) {
+    match value {
+        Value::Array(array) => {
+            for (index, value) in array.iter().enumerate() {
+                find_json_links(value, format!("{path}[{index}]"), links);
+            }
+        },
+        Value::Object(object) => {
+            for (key, value) in object {
+                let link_path = format!("{path} > {key}");
+
+                match (key.as_str(), value) {
+                    // HAL and JSON:API, each key is a relation
+                    ("_links" | "links", Value::Object(relations)) => {
+                        for (rel, link) in relations {
+                            match link {
+                                Value::Array(link_array) => {
+                                    for link in link_array {
+                                        push_link_object(rel, link, &link_path, links);
+                                    }
+                                },
+                                link => push_link_object(rel, link, &link_path, links)
+                            }
+                        }
+                    },
+                    // OData v4 annotations, e.g. "@odata.nextLink" or "Orders@odata.navigationLink"
+                    (key, Value::String(href)) if key.contains("odata.") && (key.ends_with("Link") || key.ends_with("odata.id")) => {
+                        let rel = key.replace("@odata.", " ").replace("odata.", " ").trim().to_string();
+                        push_link(rel, href, None, false, &path, links);
+                    },
+                    // OData v2 next page
+                    ("__next", Value::String(href)) => push_link(String::from("next"), href, None, false, &path, links),
+                    // OData v2 entity and navigation properties
+                    ("__metadata" | "__deferred", Value::Object(metadata)) => {
+                        if let Some(Value::String(uri)) = metadata.get("uri") {
+                            let rel = match key.as_str() {
+                                "__metadata" => String::from("self"),
+                                _ => path.rsplit(" > ").next().unwrap_or("related").to_string()
+                            };
+
+                            push_link(rel, uri, None, false, &path, links);
+                        }
+                    },
+                    _ => find_json_links(value, link_path, links)
+                }
+            }
+        },
+        _ => {}
+    }
+}
+
+/// A link is either its URL or an object with a href
+fn push_link_object(rel: &str, link: &Value, path: &str, links: &mut Vec<ResponseLink>) {
+    match link {
+        Value::String(href) => push_link(rel.to_string(), href, None, false, path, links),
+        Value::Object(link_object) => {
+            if let Some(Value::String(href)) = link_object.get("href") {
+                let title = link_object.get("title").and_then(Value::as_str).map(String::from);
+                let templated = link_object.get("templated").and_then(Value::as_bool).unwrap_or(false);
+
+                push_link(rel.to_string(), href, title, templated, path, links);
+            }
+        },
+        _ => {}
+    }
+}
+
+fn push_link(rel: String, href: &str, title: Option<String>, templated: bool, path: &str, links: &mut Vec<ResponseLink>) {
+    if href.trim().is_empty() || links.len() >= MAX_RESPONSE_LINKS {
+        return;
+    }
+
+    links.push(ResponseLink {
+        rel,
+        href: href.to_string(),
+        path: path.to_string(),
+        title,
+        templated,
+    });
+}
+
+impl App<'_> {
+    pub fn find_selected_response_links(&self) -> Vec<ResponseLink> {
+        let local_request = match self.collections_tree.selected {
+            None => return vec![],
+            Some(selection) => self.get_request_as_local_from_indexes(&selection)
+        };
+
+        let request = local_request.read();
+
+        return match &request.response.content {
+            Some(ResponseContent::Body(body)) => match serde_json::from_str::<Value>(body) {
+                Ok(body) => find_response_links(&body),
+                Err(_) => vec![]
+            },
+            _ => vec![]
+        };
+    }
+
+    /// Create a GET request to the link in the same collection, with the auth, headers and settings of the request, and return its index.
+    /// A relative link is resolved against the URL of the request.
+    pub fn follow_response_link(&mut self, collection_index: usize, request_index: usize, link: &ResponseLink) -> anyhow::Result<usize> {
+        let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        let (mut new_request, base_url) = {
+            let request = local_request.read();
+
+            let new_request = Request {
+                name: request.name.clone(),
+                url: String::new(),
+                method: Method::GET,
+                headers: request.headers
+                    .iter()
+                    .filter(|header| !header.data.0.eq_ignore_ascii_case(CONTENT_TYPE.as_str()) && !header.data.0.eq_ignore_ascii_case(CONTENT_LENGTH.as_str()))
+                    .cloned()
+                    .collect(),
+                body: ContentType::NoBody,
+                auth: request.auth.clone(),
+                settings: request.settings.clone(),
+                ..Default::default()
+            };
+
+            (new_request, self.replace_env_keys_by_value(&request.url))
+        };
+
+        // Only the characters allowed by the CLI, so that the request can be sent from it
+        let rel: String = link.rel
+            .chars()
+            .map(|char| match char.is_ascii_alphanumeric() || char == '-' || char == '_' || char == ' ' {
+                true => char,
+                false => '_'
+            })
+            .collect();
+
+        let request_name = format!("{} {rel}", new_request.name);
+        new_request.name = request_name.clone();
+        let mut suffix = 2;
+
+        while self.collections[collection_index].requests.iter().any(|request| request.read().name == new_request.name) {
+            new_request.name = format!("{request_name} {suffix}");
+            suffix += 1;
+        }
+
+        let url = match Url::parse(&base_url).and_then(|base_url| base_url.join(&link.href)) {
+            Ok(url) => url.to_string(),
+            Err(_) => link.href.clone()
+        };
+
+        info!("Following link \"{}\" to \"{url}\"", link.rel);
+
+        self.new_request(collection_index, new_request)?;
+
+        let new_request_index = self.collections[collection_index].requests.len() - 1;
+        self.modify_request_url(collection_index, new_request_index, url)?;
+
+        Ok(new_request_index)
+    }
+}
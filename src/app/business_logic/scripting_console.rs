@@ -0,0 +1,118 @@
+use boa_engine::{Context, Source};
+use indexmap::IndexMap;
+use serde::Serialize;
+use serde_json::Value;
+use tracing::trace;
+
+use crate::app::business_logic::request::scripts::{JS_CONSOLE, JS_UTILS};
+use crate::models::collection::Collection;
+use crate::models::response::{RequestResponse, ResponseContent};
+
+/// Loop iterations a console script can make, so that an endless loop cannot freeze the app
+const MAX_LOOP_ITERATIONS: u64 = 1_000_000;
+
+const JS_SEND: &str = r#"
+let __sends = [];
+
+function send(collection, request) {
+    let path = (request === undefined) ? collection : collection + "/" + request;
+    __sends.push(path);
+    return "Sending " + path;
+}
+"#;
+
+/// What a console script returned
+pub struct ConsoleEvaluation {
+    /// Value of the last expression, strings are not quoted
+    pub value: Option<String>,
+    pub console_output: String,
+    pub env: Option<IndexMap<String, String>>,
+    /// JSON object kept between two evaluations
+    pub vars: String,
+    /// "collection/request" paths to send
+    pub sends: Vec<String>,
+}
+
+/// Last response of a request sent from the console, given to the next scripts
+#[derive(Serialize)]
+struct ConsoleResponse<'a> {
+    status_code: Option<String>,
+    duration: &'a Option<String>,
+    headers: &'a Vec<(String, String)>,
+    body: Option<&'a String>,
+}
+
+/// Evaluate a console script with the collections (read-only), the selected environment values, the variables of the previous scripts
+/// and the responses of the requests sent from the console
+pub fn evaluate_console_script(code: &str, collections: &[Collection], env: Option<IndexMap<String, String>>, vars: &str, responses: &IndexMap<String, RequestResponse>) -> Result<ConsoleEvaluation, String> {
+    let mut context = Context::default();
+    context.runtime_limits_mut().set_loop_iteration_limit(MAX_LOOP_ITERATIONS);
+
+    let collections_json = serde_json::to_string(collections).unwrap();
+    let env_json = match &env {
+        Some(env) => serde_json::to_string(env).unwrap(),
+        None => String::from("undefined")
+    };
+
+    let responses: IndexMap<&String, ConsoleResponse> = responses
+        .iter()
+        .map(|(path, response)| (path, ConsoleResponse {
+            status_code: response.get_status_text(),
+            duration: &response.duration,
+            headers: &response.headers,
+            body: match &response.content {
+                Some(ResponseContent::Body(body)) => Some(body),
+                _ => None
+            },
+        }))
+        .collect();
+
+    let responses_json = serde_json::to_string(&responses).unwrap();
+    let code_json = serde_json::to_string(code).unwrap();
+
+    let script = format!(r#"
+        let collections = {collections_json};
+        let env = {env_json};
+        let vars = {vars};
+        let responses = {responses_json};
+
+        {JS_CONSOLE}
+        {JS_UTILS}
+        {JS_SEND}
+
+        let __result = eval({code_json});
+        let __value = null;
+
+        if (typeof __result === "string") {{
+            __value = __result;
+        }}
+        else if (__result !== undefined) {{
+            let __json = JSON.stringify(__result, null, 2);
+            __value = (__json === undefined) ? String(__result) : __json;
+        }}
+
+        JSON.stringify([__value, env, vars, __sends, console_log_output])
+    "#);
+
+    trace!("Executing console script");
+
+    let result = context.eval(Source::from_bytes(&script)).map_err(|error| error.to_string())?;
+    let stringed_result = result.as_string().unwrap().to_std_string_escaped();
+
+    let (value, env, vars, sends, console_output) = serde_json::from_str::<(Option<String>, Option<IndexMap<String, String>>, Value, Vec<String>, String)>(&stringed_result)
+        .map_err(|error| error.to_string())?;
+
+    // Anything else than an object is reset
+    let vars = match vars.is_object() {
+        true => vars.to_string(),
+        false => String::from("{}")
+    };
+
+    return Ok(ConsoleEvaluation {
+        value,
+        console_output,
+        env,
+        vars,
+        sends,
+    });
+}
\ No newline at end of file
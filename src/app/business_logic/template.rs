@@ -0,0 +1,64 @@
+use std::path::PathBuf;
+
+use anyhow::anyhow;
+use thiserror::Error;
+use tracing::{info, trace};
+
+use crate::app::app::App;
+use crate::app::business_logic::template::TemplateError::{TemplateNameIsEmpty, TemplateNotFound};
+use crate::models::template::RequestTemplate;
+
+#[derive(Error, Debug)]
+pub enum TemplateError {
+    #[error("Template not found")]
+    TemplateNotFound,
+    #[error("The template name is empty")]
+    TemplateNameIsEmpty,
+}
+
+impl App<'_> {
+    pub fn find_template(&self, template_name: &str) -> anyhow::Result<usize> {
+        trace!("Trying to find template \"{template_name}\"");
+
+        match self.request_templates.iter().position(|template| template.name == template_name) {
+            None => {
+                trace!("Not found");
+                Err(anyhow!(TemplateNotFound))
+            },
+            Some(index) => {
+                trace!("Found");
+                Ok(index)
+            }
+        }
+    }
+
+    /// Save the request as a template, an existing template with the same name is replaced
+    pub fn save_request_as_template(&mut self, collection_index: usize, request_index: usize, template_name: String) -> anyhow::Result<PathBuf> {
+        let template_name = template_name.trim().to_string();
+
+        if template_name.is_empty() {
+            return Err(anyhow!(TemplateNameIsEmpty));
+        }
+
+        let template = {
+            let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+            let request = local_request.read();
+
+            RequestTemplate::from_request(template_name, &request)
+        };
+
+        let path = self.save_template_to_file(&template)?;
+
+        info!("Request saved as template \"{}\"", template.name);
+
+        match self.find_template(&template.name) {
+            Ok(template_index) => self.request_templates[template_index] = template,
+            Err(_) => {
+                self.request_templates.push(template);
+                self.request_templates.sort_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+
+        return Ok(path);
+    }
+}
@@ -0,0 +1,83 @@
+use crate::app::app::App;
+use crate::app::business_logic::environment::{get_request_fields, BUILTIN_ENV_KEYS, ENV_KEY_REGEX};
+
+#[derive(Default)]
+pub struct VariableUsages {
+    pub key: String,
+    /// Names of the environments defining the key
+    pub defined_in: Vec<String>,
+    /// Names of the environments lacking the key
+    pub missing_in: Vec<String>,
+    pub is_builtin: bool,
+    /// e.g. "my_collection > my_request > URL"
+    pub references: Vec<String>,
+}
+
+impl VariableUsages {
+    /// Referenced but defined nowhere, sent literally whatever the environment
+    pub fn is_undefined(&self) -> bool {
+        return !self.is_builtin && self.defined_in.is_empty();
+    }
+}
+
+impl App<'_> {
+    /// Every environment key followed by the undefined placeholders, with the request fields referencing them
+    pub fn get_variable_usages(&self) -> Vec<VariableUsages> {
+        let mut usages: Vec<VariableUsages> = vec![];
+
+        for environment in &self.environments {
+            for key in environment.read().values.keys() {
+                if !usages.iter().any(|usage| usage.key == *key) {
+                    usages.push(VariableUsages {
+                        key: key.clone(),
+                        ..Default::default()
+                    });
+                }
+            }
+        }
+
+        for collection in &self.collections {
+            for request in &collection.requests {
+                let request = request.read();
+
+                for (field_name, input) in get_request_fields(&request, true) {
+                    for capture in ENV_KEY_REGEX.captures_iter(input) {
+                        let key = &capture[1];
+                        let reference = format!("{} > {} > {}", collection.name, request.name, field_name);
+
+                        let usage = match usages.iter().position(|usage| usage.key == key) {
+                            Some(index) => &mut usages[index],
+                            None => {
+                                usages.push(VariableUsages {
+                                    key: key.to_string(),
+                                    ..Default::default()
+                                });
+                                usages.last_mut().unwrap()
+                            }
+                        };
+
+                        // A key used twice in the same field is listed once
+                        if !usage.references.contains(&reference) {
+                            usage.references.push(reference);
+                        }
+                    }
+                }
+            }
+        }
+
+        for usage in &mut usages {
+            usage.is_builtin = BUILTIN_ENV_KEYS.contains(&usage.key.as_str());
+
+            for environment in &self.environments {
+                let environment = environment.read();
+
+                match environment.values.contains_key(&usage.key) {
+                    true => usage.defined_in.push(environment.name.clone()),
+                    false => usage.missing_in.push(environment.name.clone())
+                }
+            }
+        }
+
+        return usages;
+    }
+}
@@ -0,0 +1,86 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Instant, SystemTime};
+
+use parking_lot::RwLock;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::body::{ContentType, PartSource};
+use crate::models::request::Request;
+use crate::models::watch::RequestWatch;
+
+impl App<'_> {
+    pub fn toggle_request_watch(&mut self, local_request: &Arc<RwLock<Request>>) {
+        let mut request = local_request.write();
+
+        request.watch = match request.watch {
+            None => {
+                info!("Watching \"{}\"", request.name);
+                Some(RequestWatch::default())
+            },
+            Some(_) => {
+                info!("Stopped watching \"{}\"", request.name);
+                None
+            }
+        };
+    }
+
+    /// Latest modification of the file body or of the multipart files, None when the request sends no file
+    pub fn get_body_files_modified_at(&self, body: &ContentType) -> Option<SystemTime> {
+        let paths = match body {
+            ContentType::File(body_file) => vec![body_file.path.clone()],
+            ContentType::Multipart(parts) => parts
+                .iter()
+                .filter(|part| part.enabled && part.source == PartSource::File)
+                .map(|part| part.value.clone())
+                .collect(),
+            _ => return None
+        };
+
+        return paths
+            .iter()
+            .filter_map(|path| {
+                let path = PathBuf::from(self.replace_env_keys_by_value(path));
+                path.metadata().and_then(|metadata| metadata.modified()).ok()
+            })
+            .max();
+    }
+
+    /// Returns the selected request when it is watched and due, and records the run
+    pub fn take_due_watched_request(&mut self) -> Option<Arc<RwLock<Request>>> {
+        let selected_request_index = self.collections_tree.selected?;
+        let local_request = self.get_request_as_local_from_indexes(&selected_request_index);
+
+        // The files are looked up before locking the request, the environment substitution may wait for the thread pool
+        let (interval, body) = {
+            let request = local_request.try_read()?;
+            request.watch.as_ref()?;
+
+            (request.settings.watch_interval, request.body.clone())
+        };
+
+        let files_modified_at = self.get_body_files_modified_at(&body);
+        let now = Instant::now();
+
+        {
+            // Locked while being sent, waiting for it would freeze the app
+            let mut request = local_request.try_write()?;
+
+            // The request is already being sent, it runs again once done if still due
+            if request.is_pending {
+                return None;
+            }
+
+            let watch = request.watch.as_mut()?;
+
+            if !watch.is_due(now, interval, files_modified_at) {
+                return None;
+            }
+
+            watch.record_run(now, files_modified_at);
+        }
+
+        return Some(local_request);
+    }
+}
@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use httparse::{Status, EMPTY_HEADER};
+use parking_lot::RwLock;
+use reqwest::StatusCode;
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_util::sync::CancellationToken;
+use tracing::{info, trace, warn};
+
+use crate::models::webhook::{CannedResponse, ReceivedRequest};
+
+/// Maximum size of the request head (request line + headers)
+const MAX_HEAD_SIZE: usize = 64 * 1024;
+const MAX_HEADERS: usize = 100;
+
+#[derive(Error, Debug)]
+pub enum WebhookListenerError {
+    #[error("Could not bind port {0}\n\t{1}")]
+    CouldNotBind(u16, std::io::Error),
+
+    #[error("Malformed HTTP request")]
+    MalformedRequest,
+
+    #[error("Request head is too large")]
+    HeadTooLarge,
+
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+}
+
+/// Bind the port and store every incoming request until the cancellation token is triggered.
+/// Each request is answered with the canned response.
+pub async fn run_webhook_listener(port: u16, canned_response: CannedResponse, received_requests: Arc<RwLock<Vec<ReceivedRequest>>>, cancellation_token: CancellationToken, on_request: Option<fn(&ReceivedRequest)>) -> Result<(), WebhookListenerError> {
+    let listener = match TcpListener::bind(("0.0.0.0", port)).await {
+        Ok(listener) => listener,
+        Err(error) => return Err(WebhookListenerError::CouldNotBind(port, error))
+    };
+
+    info!("Webhook listener started on port {port}");
+
+    let canned_response = Arc::new(canned_response);
+
+    loop {
+        let (stream, address) = tokio::select! {
+            _ = cancellation_token.cancelled() => break,
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(error) => {
+                    warn!("Could not accept connection: {error}");
+                    continue;
+                }
+            }
+        };
+
+        trace!("Incoming connection from {address}");
+
+        let canned_response = Arc::clone(&canned_response);
+        let received_requests = Arc::clone(&received_requests);
+
+        tokio::spawn(async move {
+            match handle_connection(stream, &canned_response).await {
+                Ok(received_request) => {
+                    if let Some(on_request) = on_request {
+                        on_request(&received_request);
+                    }
+
+                    received_requests.write().push(received_request);
+                },
+                Err(error) => warn!("Webhook listener: {error}")
+            }
+        });
+    }
+
+    info!("Webhook listener stopped");
+
+    Ok(())
+}
+
+async fn handle_connection(mut stream: TcpStream, canned_response: &CannedResponse) -> Result<ReceivedRequest, WebhookListenerError> {
+    let mut buffer: Vec<u8> = vec![];
+    let mut chunk = [0u8; 4096];
+
+    // Read until the whole request head has been received
+    let (head_length, method, path, headers) = loop {
+        let read = stream.read(&mut chunk).await?;
+
+        if read == 0 {
+            return Err(WebhookListenerError::MalformedRequest);
+        }
+
+        buffer.extend_from_slice(&chunk[..read]);
+
+        let mut raw_headers = [EMPTY_HEADER; MAX_HEADERS];
+        let mut request = httparse::Request::new(&mut raw_headers);
+
+        match request.parse(&buffer) {
+            Ok(Status::Complete(head_length)) => {
+                let headers: Vec<(String, String)> = request.headers
+                    .iter()
+                    .map(|header| (header.name.to_string(), String::from_utf8_lossy(header.value).to_string()))
+                    .collect();
+
+                break (
+                    head_length,
+                    request.method.unwrap_or_default().to_string(),
+                    request.path.unwrap_or_default().to_string(),
+                    headers
+                );
+            },
+            Ok(Status::Partial) if buffer.len() < MAX_HEAD_SIZE => continue,
+            Ok(Status::Partial) => return Err(WebhookListenerError::HeadTooLarge),
+            Err(_) => return Err(WebhookListenerError::MalformedRequest)
+        }
+    };
+
+    let mut body = buffer.split_off(head_length);
+
+    let is_chunked = headers
+        .iter()
+        .any(|(name, value)| name.eq_ignore_ascii_case("transfer-encoding") && value.to_lowercase().contains("chunked"));
+
+    if is_chunked {
+        // Read until the last chunk marker
+        while !body.ends_with(b"0\r\n\r\n") {
+            let read = stream.read(&mut chunk).await?;
+
+            if read == 0 {
+                break;
+            }
+
+            body.extend_from_slice(&chunk[..read]);
+        }
+
+        body = decode_chunked_body(&body);
+    }
+    else {
+        let content_length = headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("content-length"))
+            .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+            .unwrap_or(0);
+
+        while body.len() < content_length {
+            let read = stream.read(&mut chunk).await?;
+
+            if read == 0 {
+                break;
+            }
+
+            body.extend_from_slice(&chunk[..read]);
+        }
+    }
+
+    stream.write_all(&canned_response_to_bytes(canned_response)).await?;
+    stream.flush().await?;
+
+    Ok(ReceivedRequest {
+        received_at: Utc::now().format("%H:%M:%S").to_string(),
+        method,
+        path,
+        headers,
+        body: String::from_utf8_lossy(&body).to_string(),
+    })
+}
+
+fn decode_chunked_body(raw_body: &[u8]) -> Vec<u8> {
+    let mut decoded: Vec<u8> = vec![];
+    let mut remaining = raw_body;
+
+    loop {
+        let line_end = match remaining.windows(2).position(|window| window == b"\r\n") {
+            None => break,
+            Some(line_end) => line_end
+        };
+
+        let size_line = String::from_utf8_lossy(&remaining[..line_end]);
+        // Chunk extensions are ignored
+        let size_str = size_line.split(';').next().unwrap_or("").trim();
+
+        let size = match usize::from_str_radix(size_str, 16) {
+            Ok(size) => size,
+            Err(_) => break
+        };
+
+        if size == 0 {
+            break;
+        }
+
+        let chunk_start = line_end + 2;
+        let chunk_end = usize::min(chunk_start + size, remaining.len());
+
+        decoded.extend_from_slice(&remaining[chunk_start..chunk_end]);
+
+        remaining = &remaining[usize::min(chunk_end + 2, remaining.len())..];
+    }
+
+    return decoded;
+}
+
+fn canned_response_to_bytes(canned_response: &CannedResponse) -> Vec<u8> {
+    let reason = match StatusCode::from_u16(canned_response.status_code) {
+        Ok(status_code) => status_code.canonical_reason().unwrap_or(""),
+        Err(_) => ""
+    };
+
+    let mut response = format!("HTTP/1.1 {} {}\r\n", canned_response.status_code, reason);
+
+    for (header, value) in &canned_response.headers {
+        response += &format!("{header}: {value}\r\n");
+    }
+
+    response += &format!("content-length: {}\r\n", canned_response.body.len());
+    response += "connection: close\r\n\r\n";
+    response += &canned_response.body;
+
+    return response.into_bytes();
+}
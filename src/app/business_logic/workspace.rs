@@ -0,0 +1,83 @@
+use anyhow::anyhow;
+use thiserror::Error;
+use tracing::{info, trace};
+use tui_tree_widget::TreeState;
+
+use crate::app::app::App;
+use crate::app::business_logic::workspace::WorkspaceError::{WorkspaceDirectoryNotFound, WorkspaceNotFound};
+use crate::app::files::config::Config;
+use crate::cli::args::ARGS;
+
+#[derive(Error, Debug)]
+pub enum WorkspaceError {
+    #[error("Workspace \"{0}\" not found in the atac.toml workspaces")]
+    WorkspaceNotFound(String),
+    #[error("Workspace directory \"{0}\" not found")]
+    WorkspaceDirectoryNotFound(String),
+}
+
+impl App<'_> {
+    pub fn find_workspace(&self, workspace_name: &str) -> anyhow::Result<()> {
+        trace!("Trying to find workspace \"{workspace_name}\"");
+
+        match self.workspaces.contains_key(workspace_name) {
+            true => {
+                trace!("Found");
+                Ok(())
+            },
+            false => {
+                trace!("Not found");
+                Err(anyhow!(WorkspaceNotFound(workspace_name.to_string())))
+            }
+        }
+    }
+
+    /// Unload the current workspace and load the given one, None being the app directory
+    pub fn switch_workspace(&mut self, workspace_name: Option<String>) -> anyhow::Result<()> {
+        let previous_workspace = std::mem::replace(&mut self.selected_workspace, workspace_name.clone());
+        let workspace_directory = self.get_workspace_directory();
+        self.selected_workspace = previous_workspace;
+
+        match workspace_directory {
+            Some(workspace_directory) if workspace_directory.is_dir() => {},
+            Some(workspace_directory) => return Err(anyhow!(WorkspaceDirectoryNotFound(workspace_directory.display().to_string()))),
+            None => return Err(anyhow!(WorkspaceNotFound(workspace_name.unwrap_or_default())))
+        }
+
+        // The cookie jar belongs to the workspace being left
+        self.save_cookie_jar();
+
+        self.collections.clear();
+        self.collections_tree.state = TreeState::default();
+        self.collections_tree.set_unselected();
+
+        self.environments.clear();
+        self.selected_environment = 0;
+
+        self.request_templates.clear();
+
+        // Drop the previous workspace config overrides
+        self.config = Config::default();
+
+        let config_path = ARGS.directory.as_ref().unwrap().join("atac.toml");
+
+        if config_path.is_file() {
+            self.parse_config_file(config_path);
+        }
+
+        match &workspace_name {
+            None => info!("Switched to the main workspace"),
+            Some(workspace_name) => info!("Switched to workspace \"{workspace_name}\"")
+        }
+
+        self.selected_workspace = workspace_name;
+
+        self.parse_workspace_directory();
+        self.load_cookie_jar();
+        self.load_scratchpad();
+        self.load_key_macro();
+        self.parse_templates_directory();
+
+        Ok(())
+    }
+}
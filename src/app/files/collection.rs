@@ -1,51 +1,66 @@
 use std::fs;
-use std::fs::OpenOptions;
-use std::io::{Read, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
-use tracing::{info, trace, warn};
+use anyhow::anyhow;
+use parking_lot::RwLock;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use tracing::{error, info, trace, warn};
 
 use crate::app::app::App;
+use crate::app::business_logic::app_error::report_error;
+use crate::app::files::encryption::{is_encrypted_file, read_file_content, toggle_encrypted_file_path, write_file_content};
+use crate::app::files::file_watcher::record_modified_time;
 use crate::cli::args::ARGS;
-use crate::panic_error;
-use crate::models::collection::{Collection, CollectionFileFormat};
-use crate::models::collection::CollectionFileFormat::{Json, Yaml};
+use crate::models::collection::{Collection, CollectionFileFormat, CollectionIndexFile, CollectionLayout};
+use crate::models::request::Request;
+use crate::models::collection::CollectionFileFormat::{Json, Toml, Yaml};
+
+/// Collection file of a collection directory, e.g. "collection.json"
+const COLLECTION_INDEX_FILE_NAME: &str = "collection";
+
+const WRITE_HINT: &str = "Check the permissions of the directory and the free disk space, the changes are kept until ATAC quits";
+const SERIALIZE_HINT: &str = "TOML cannot hold every value, switch the collection to the JSON or YAML file format";
+const DELETE_HINT: &str = "Check the permissions of the file, or delete it by hand";
 
 impl App<'_> {
-    /// Set the app request to the requests found in the collection file
+    /// Set the app request to the requests found in the collection file, an unreadable collection is skipped
     pub fn set_collections_from_file(&mut self, path_buf: PathBuf, file_format: CollectionFileFormat) {
-        let mut file_content = String::new();
-
         trace!("Trying to open \"{}\" collection", path_buf.display());
 
-        let mut collection_file = OpenOptions::new()
-            .read(true)
-            .write(true)
-            .create(true)
-            .open(path_buf.clone())
-            .expect("\tCould not open collection file");
-
-        collection_file.read_to_string(&mut file_content).expect("\tCould not read collection file");
-
-        let mut collection: Collection = match file_format {
-            Json => match serde_json::from_str(&file_content) {
-                Ok(collection) => collection,
-                Err(e) => panic_error(format!("Could not parse JSON collection \"{}\"\n\t{}", path_buf.display(), e.to_string()))
-            },
-            Yaml => match serde_yaml::from_str(&file_content) {
-                Ok(collection) => collection,
-                Err(e) => panic_error(format!("Could not parse YAML collection \"{}\"\n\t{}", path_buf.display(), e))
+        let collection = match read_collection_file(&path_buf, file_format) {
+            Ok(collection) => collection,
+            Err(e) => {
+                error!("{e}");
+                return;
             }
         };
 
-        collection.path = path_buf;
-        collection.file_format = file_format;
-
+        record_modified_time(&collection.path);
         self.collections.push(collection);
 
         trace!("Collection file parsed!");
     }
 
+    /// Set the app request to the requests found in a collection directory (file per request layout), an unreadable collection is skipped
+    pub fn set_collection_from_directory(&mut self, directory: PathBuf, file_format: CollectionFileFormat) {
+        trace!("Trying to open \"{}\" collection directory", directory.display());
+
+        let collection = match read_collection_directory(&directory, file_format) {
+            Ok(collection) => collection,
+            Err(e) => {
+                error!("Collection directory \"{}\" skipped: {e}", directory.display());
+                return;
+            }
+        };
+
+        record_modified_time(&collection.path);
+        self.collections.push(collection);
+
+        trace!("Collection directory parsed!");
+    }
+
     /// Save app collection in the collection file through a temporary file
     pub fn save_collection_to_file(&mut self, collection_index: usize) {
         if !ARGS.should_save {
@@ -57,36 +72,285 @@ impl App<'_> {
 
         info!("Saving collection \"{}\"", collection.name);
 
-        let temp_file_name = format!("{}_", collection.path.file_name().unwrap().to_str().unwrap());
+        if collection.layout == CollectionLayout::FilePerRequest {
+            save_collection_to_directory(collection);
 
-        let temp_file_path = collection.path.with_file_name(temp_file_name);
-        
-        let mut temp_file = OpenOptions::new()
-            .write(true)
-            .create(true)
-            .truncate(true)
-            .open(&temp_file_path)
-            .expect("Could not open temp file");
+            let collection_path = collection.path.clone();
+            record_modified_time(&collection_path);
 
-        let collection_stringed = match collection.file_format {
-            Json => serde_json::to_string_pretty(collection).expect("Could not serialize collection to JSON"),
-            Yaml => serde_yaml::to_string(collection).expect("Could not serialize collection to YAML")
+            trace!("Collection saved");
+            return;
+        }
+
+        let collection_stringed: anyhow::Result<String> = match collection.file_format {
+            Json => serde_json::to_string_pretty(collection).map_err(anyhow::Error::from),
+            Yaml => serde_yaml::to_string(collection).map_err(anyhow::Error::from),
+            Toml => toml::to_string_pretty(collection).map_err(anyhow::Error::from)
+        };
+
+        let collection_stringed = match collection_stringed {
+            Ok(collection_stringed) => collection_stringed,
+            Err(e) => {
+                report_error("Could not serialize collection", format!("\"{}\" {e}", collection.name), SERIALIZE_HINT);
+                return;
+            }
         };
 
-        temp_file.write_all(collection_stringed.as_bytes()).expect("Could not write to temp file");
-        temp_file.flush().unwrap();
+        // Through a temporary file, encrypted if the collection file is
+        if let Err(e) = write_file_content(&collection.path, &collection_stringed) {
+            report_error("Could not write collection file", format!("\"{}\" {e}", collection.path.display()), WRITE_HINT);
+            return;
+        }
+
+        let collection_path = collection.path.clone();
+        record_modified_time(&collection_path);
 
-        fs::rename(temp_file_path, &collection.path).expect("Could not move temp file to collection file");
-        
         trace!("Collection saved");
     }
 
-    /// Delete collection file
+    /// Delete collection file, or directory with the file per request layout
     pub fn delete_collection_file(&mut self, collection: Collection) {
         if !ARGS.should_save {
             return;
         }
 
-        fs::remove_file(&collection.path).expect("Could not delete collection file");
+        let result = match collection.layout {
+            CollectionLayout::SingleFile => fs::remove_file(&collection.path),
+            CollectionLayout::FilePerRequest => fs::remove_dir_all(&collection.path)
+        };
+
+        if let Err(e) = result {
+            report_error("Could not delete collection", format!("\"{}\" {e}", collection.path.display()), DELETE_HINT);
+        }
+    }
+}
+
+/// Read and parse a collection file, without panicking since the file may be reloaded while the TUI runs
+pub fn read_collection_file(path: &Path, file_format: CollectionFileFormat) -> anyhow::Result<Collection> {
+    let file_content = match read_file_content(path) {
+        Ok(file_content) => file_content,
+        Err(e) => return Err(anyhow!("Could not read collection file \"{}\"\n\t{e}", path.display()))
+    };
+
+    let mut collection: Collection = match file_format {
+        Json => serde_json::from_str(&file_content).map_err(|e| anyhow!("Could not parse JSON collection \"{}\"\n\t{e}", path.display()))?,
+        Yaml => serde_yaml::from_str(&file_content).map_err(|e| anyhow!("Could not parse YAML collection \"{}\"\n\t{e}", path.display()))?,
+        Toml => toml::from_str(&file_content).map_err(|e| anyhow!("Could not parse TOML collection \"{}\"\n\t{e}", path.display()))?
+    };
+
+    collection.path = path.to_path_buf();
+    collection.file_format = file_format;
+
+    return Ok(collection);
+}
+
+/// Read and parse a collection directory (file per request layout)
+pub fn read_collection_directory(directory: &Path, file_format: CollectionFileFormat) -> anyhow::Result<Collection> {
+    let index_file_path = directory.join(format!("{COLLECTION_INDEX_FILE_NAME}.{file_format}"));
+
+    let index_file: CollectionIndexFile = read_file(&index_file_path, file_format)?;
+
+    let mut requests = vec![];
+
+    for request_file_name in &index_file.requests {
+        let request: Request = read_file(&directory.join(request_file_name), file_format)?;
+        requests.push(Arc::new(RwLock::new(request)));
+    }
+
+    return Ok(Collection {
+        name: index_file.name,
+        description: index_file.description,
+        read_only: index_file.read_only,
+        login_request: index_file.login_request,
+        hooks: index_file.hooks,
+        think_time: index_file.think_time,
+        tags: index_file.tags,
+        requests,
+        path: directory.to_path_buf(),
+        file_format,
+        layout: CollectionLayout::FilePerRequest,
+    });
+}
+
+/// Path of a new collection in the given directory, a directory itself with the file per request layout
+pub fn new_collection_path(directory: &Path, collection_name: &str, file_format: CollectionFileFormat, layout: CollectionLayout) -> PathBuf {
+    match layout {
+        CollectionLayout::SingleFile => directory.join(format!("{collection_name}.{file_format}")),
+        CollectionLayout::FilePerRequest => directory.join(collection_name)
+    }
+}
+
+/// Same collection file in another format, e.g. "my_collection.json.age" -> "my_collection.toml.age"
+pub fn collection_file_path_with_format(path: &Path, file_format: CollectionFileFormat) -> PathBuf {
+    match is_encrypted_file(path) {
+        true => toggle_encrypted_file_path(&toggle_encrypted_file_path(path).with_extension(file_format.to_string())),
+        false => path.with_extension(file_format.to_string())
+    }
+}
+
+/// Format of a collection file, e.g. "my_collection.yaml" or "my_collection.toml.age"
+pub fn get_collection_file_format(path: &Path) -> Option<CollectionFileFormat> {
+    let plain_path = match is_encrypted_file(path) {
+        true => toggle_encrypted_file_path(path),
+        false => path.to_path_buf()
+    };
+
+    return plain_path
+        .extension()
+        .and_then(|extension| extension.to_str())
+        .and_then(CollectionFileFormat::from_extension);
+}
+
+/// Directory containing a collection file, i.e. using the file per request layout
+pub fn get_collection_directory_format(directory: &Path) -> Option<CollectionFileFormat> {
+    return [Json, Yaml, Toml]
+        .into_iter()
+        .find(|file_format| directory.join(format!("{COLLECTION_INDEX_FILE_NAME}.{file_format}")).is_file());
+}
+
+fn read_file<T: DeserializeOwned>(path: &Path, file_format: CollectionFileFormat) -> anyhow::Result<T> {
+    let file_content = match fs::read_to_string(path) {
+        Ok(file_content) => file_content,
+        Err(e) => return Err(anyhow!("Could not read \"{}\"\n\t{e}", path.display()))
+    };
+
+    let value = match file_format {
+        Json => serde_json::from_str(&file_content).map_err(|e| anyhow!("Could not parse JSON file \"{}\"\n\t{e}", path.display()))?,
+        Yaml => serde_yaml::from_str(&file_content).map_err(|e| anyhow!("Could not parse YAML file \"{}\"\n\t{e}", path.display()))?,
+        Toml => toml::from_str(&file_content).map_err(|e| anyhow!("Could not parse TOML file \"{}\"\n\t{e}", path.display()))?
+    };
+
+    return Ok(value);
+}
+
+fn serialize<T: Serialize>(value: &T, file_format: CollectionFileFormat) -> anyhow::Result<String> {
+    let content = match file_format {
+        // Ending with a new line avoids diff noise
+        Json => serde_json::to_string_pretty(value)? + "\n",
+        Yaml => serde_yaml::to_string(value)?,
+        Toml => toml::to_string_pretty(value)?
+    };
+
+    return Ok(content);
+}
+
+/// Write through a temporary file, unchanged files are left untouched
+fn write_file_if_changed(path: &Path, content: &str) -> std::io::Result<()> {
+    if fs::read_to_string(path).is_ok_and(|existing_content| existing_content == content) {
+        return Ok(());
+    }
+
+    let temp_file_path = path.with_file_name(format!("{}_", path.file_name().unwrap().to_str().unwrap()));
+
+    fs::write(&temp_file_path, content)?;
+    fs::rename(temp_file_path, path)?;
+
+    return Ok(());
+}
+
+/// Serialize and write a file of a collection directory, the error is reported
+fn save_collection_directory_file<T: Serialize>(path: &Path, value: &T, file_format: CollectionFileFormat) -> bool {
+    let content = match serialize(value, file_format) {
+        Ok(content) => content,
+        Err(e) => {
+            report_error("Could not serialize collection", format!("\"{}\" {e}", path.display()), SERIALIZE_HINT);
+            return false;
+        }
+    };
+
+    if let Err(e) = write_file_if_changed(path, &content) {
+        report_error("Could not write collection file", format!("\"{}\" {e}", path.display()), WRITE_HINT);
+        return false;
+    }
+
+    return true;
+}
+
+/// Write the collection file and one file per request, then remove the files of the deleted or renamed requests,
+/// and the ones left in another file format after a conversion
+fn save_collection_to_directory(collection: &Collection) {
+    let file_format = collection.file_format;
+
+    if let Err(e) = fs::create_dir_all(&collection.path) {
+        report_error("Could not create collection directory", format!("\"{}\" {e}", collection.path.display()), WRITE_HINT);
+        return;
+    }
+
+    let mut request_file_names: Vec<String> = vec![];
+
+    for request in &collection.requests {
+        let request = request.read();
+        let request_file_name = unique_request_file_name(&request.name, file_format, &request_file_names);
+
+        if !save_collection_directory_file(&collection.path.join(&request_file_name), &*request, file_format) {
+            return;
+        }
+
+        request_file_names.push(request_file_name);
+    }
+
+    let index_file_name = format!("{COLLECTION_INDEX_FILE_NAME}.{file_format}");
+
+    let index_file = CollectionIndexFile {
+        name: collection.name.clone(),
+        description: collection.description.clone(),
+        read_only: collection.read_only,
+        login_request: collection.login_request.clone(),
+        hooks: collection.hooks.clone(),
+        think_time: collection.think_time,
+        tags: collection.tags.clone(),
+        requests: request_file_names.clone(),
+    };
+
+    if !save_collection_directory_file(&collection.path.join(&index_file_name), &index_file, file_format) {
+        return;
+    }
+
+    let paths = match collection.path.read_dir() {
+        Ok(paths) => paths,
+        Err(_) => return
+    };
+
+    for path in paths {
+        let path = path.unwrap().path();
+        let file_name = path.file_name().unwrap().to_str().unwrap().to_string();
+
+        let is_stale_request_file = path.is_file()
+            && path.extension().and_then(|extension| extension.to_str()).and_then(CollectionFileFormat::from_extension).is_some()
+            && file_name != index_file_name
+            && !request_file_names.contains(&file_name);
+
+        if is_stale_request_file {
+            trace!("Removing stale request file \"{}\"", path.display());
+            if let Err(e) = fs::remove_file(&path) {
+                report_error("Could not delete stale request file", format!("\"{}\" {e}", path.display()), DELETE_HINT);
+            }
+        }
+    }
+}
+
+/// Request name turned into a file name, suffixed when two requests share the same name
+fn unique_request_file_name(request_name: &str, file_format: CollectionFileFormat, taken_file_names: &[String]) -> String {
+    let mut file_stem: String = request_name
+        .trim()
+        .chars()
+        .map(|char| match char.is_alphanumeric() || matches!(char, '-' | '_' | '.' | ' ') {
+            true => char,
+            false => '-'
+        })
+        .collect();
+
+    if file_stem.is_empty() || file_stem.starts_with('.') || file_stem.eq_ignore_ascii_case(COLLECTION_INDEX_FILE_NAME) {
+        file_stem = format!("request {file_stem}").trim().to_string();
+    }
+
+    let mut file_name = format!("{file_stem}.{file_format}");
+    let mut suffix = 2;
+
+    while taken_file_names.iter().any(|taken_file_name| taken_file_name.eq_ignore_ascii_case(&file_name)) {
+        file_name = format!("{file_stem} {suffix}.{file_format}");
+        suffix += 1;
     }
+
+    return file_name;
 }
@@ -1,13 +1,25 @@
 use std::fs::OpenOptions;
 use std::io::Read;
 use std::path::PathBuf;
+use std::time::Duration;
 
-use tracing::trace;
+use indexmap::IndexMap;
+use tracing::{error, trace, warn};
 use serde::{Deserialize, Serialize};
 
 use crate::app::app::App;
+use crate::app::business_logic::request::cors::DEFAULT_CORS_ORIGIN;
+use crate::app::files::locale::Locale;
 use crate::panic_error;
-use crate::models::collection::CollectionFileFormat;
+use crate::models::collection::{CollectionFileFormat, CollectionLayout};
+use crate::models::connection_pool::ConnectionPoolOptions;
+use crate::models::http2::Http2Options;
+use crate::models::load_test::{LoadTestLimit, LoadTestOptions};
+use crate::models::rate_limit::RateLimitOptions;
+use crate::models::result_tab::{ResultTab, ResultTabFocus};
+use crate::models::tls_version::TlsVersion;
+use crate::models::monitor::parse_interval;
+use crate::models::webhook::CannedResponse;
 
 #[derive(Default, Serialize, Deserialize)]
 pub struct Config {
@@ -16,14 +28,99 @@ pub struct Config {
     
     #[serde(default)]
     pub disable_cors: Option<bool>,
+
+    /// Origin of the simulated browser requests, when the request has no Origin header
+    #[serde(default)]
+    pub cors_origin: Option<String>,
     
+    /// Serve the GET and HEAD responses from an in-memory cache while their Cache-Control or Expires headers keep them fresh
+    #[serde(default)]
+    pub enable_response_cache: Option<bool>,
+
+    /// Answer the sends with the request snapshots instead of the network, can be toggled from the main menu
+    #[serde(default)]
+    pub offline_mode: Option<bool>,
+
     #[serde(default)]
     pub disable_images_preview: Option<bool>,
     
+    /// Do not save the cookies to the app directory between sessions
+    #[serde(default)]
+    pub disable_cookie_persistence: Option<bool>,
+
     #[serde(default)]
     pub preferred_collection_file_format: Option<CollectionFileFormat>,
+
+    /// Layout of the new collections, "file_per_request" keeps the diffs of a versioned workspace small
+    #[serde(default)]
+    pub preferred_collection_layout: Option<CollectionLayout>,
+
+    /// Headers sent with every request, a request header with the same name takes precedence
+    #[serde(default)]
+    pub default_headers: Option<IndexMap<String, String>>,
     
-    pub proxy: Option<Proxy>
+    pub proxy: Option<Proxy>,
+
+    #[serde(default)]
+    pub webhook_listener: Option<WebhookListenerConfig>,
+
+    #[serde(default)]
+    pub notifications: Option<NotificationsConfig>,
+
+    #[serde(default)]
+    pub load_test: Option<LoadTestConfig>,
+
+    /// Pace of the collection runs and of the data-driven runs
+    #[serde(default)]
+    pub runner: Option<RunnerConfig>,
+
+    /// Requests waiting for their response at the same time, the other sends wait for one of them to end. No limit by default
+    #[serde(default)]
+    pub max_concurrent_requests: Option<usize>,
+
+    /// Idle timeout, idle connections per host, TCP keepalive and nodelay of the connections, a request can override them
+    #[serde(default)]
+    pub connection_pool: Option<ConnectionPoolOptions>,
+
+    /// Prior knowledge, adaptive window, max frame size and window sizes of the HTTP/2 connections, a request can override them
+    #[serde(default)]
+    pub http2: Option<Http2Options>,
+
+    /// Oldest and newest TLS versions negotiated, a request can override them
+    #[serde(default)]
+    pub tls: Option<TlsConfig>,
+
+    /// Resolver of the request host names, "system", a nameserver (e.g. "10.0.0.53") or a DNS-over-HTTPS URL
+    #[serde(default)]
+    pub dns_resolver: Option<String>,
+
+    /// Names of the environments targeting production, highlighted in the TUI
+    #[serde(default)]
+    pub production_environments: Option<Vec<String>>,
+
+    /// Refuse to send a request containing "{{key}}" placeholders that the selected environment does not resolve
+    #[serde(default)]
+    pub block_unresolved_variables: Option<bool>,
+
+    /// Other directories of collections and environments, by name. Each one can have its own atac.toml overriding this config
+    #[serde(default)]
+    pub workspaces: Option<IndexMap<String, PathBuf>>,
+
+    /// Key binding hints and indicators of the footer
+    #[serde(default)]
+    pub status_bar: Option<StatusBarConfig>,
+
+    /// Result tabs displayed and the one focused after a response
+    #[serde(default)]
+    pub result_tabs: Option<ResultTabsConfig>,
+
+    /// Language of the TUI, "en" or "fr"
+    #[serde(default)]
+    pub locale: Option<Locale>,
+
+    /// Do not look for a ".atac" project directory in the current directory and its parents
+    #[serde(default)]
+    pub disable_project_directory_detection: Option<bool>
 }
 
 #[derive(Default, Serialize, Deserialize)]
@@ -32,6 +129,85 @@ pub struct Proxy {
     pub https_proxy: Option<String>,
 }
 
+#[derive(Default, Serialize, Deserialize)]
+pub struct TlsConfig {
+    /// e.g. "1.2"
+    pub min_version: Option<TlsVersion>,
+    /// e.g. "1.3"
+    pub max_version: Option<TlsVersion>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct NotificationsConfig {
+    pub method: Option<NotificationMethod>,
+    /// Only notify when the request took at least this many seconds
+    pub minimum_duration: Option<u64>,
+}
+
+#[derive(Default, Copy, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NotificationMethod {
+    /// Terminal bell
+    #[default]
+    Bell,
+    /// Desktop notification through the OSC 9 escape sequence (iTerm2, Windows Terminal, WezTerm, ...)
+    Osc9,
+    /// Desktop notification through the OSC 777 escape sequence (foot, Ghostty, urxvt, ...)
+    Osc777,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct StatusBarConfig {
+    pub hints: Option<StatusBarHints>,
+    /// Number of requests waiting for their response, displayed next to the environment
+    pub show_pending_requests: Option<bool>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ResultTabsConfig {
+    /// Tabs displayed, in this order. The console is only displayed once a script wrote to it, the wire tab only when listed
+    pub shown: Option<Vec<ResultTab>>,
+    /// Tab focused once a response is received, a request setting can override it
+    pub focus_after_response: Option<ResultTabFocus>,
+}
+
+#[derive(Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusBarHints {
+    /// Main key bindings of the current state and of the selected tabs
+    #[default]
+    Short,
+    /// Every key binding of the current state, with its description
+    Full,
+    Hidden,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct LoadTestConfig {
+    pub concurrency: Option<usize>,
+    pub iterations: Option<u64>,
+    /// e.g. "30s", takes precedence over iterations
+    pub duration: Option<String>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct RunnerConfig {
+    /// e.g. 2.5, no limit by default
+    pub requests_per_second: Option<f64>,
+    /// Requests waiting for their response at the same time, 1 by default
+    pub concurrency: Option<usize>,
+    /// Minimum time between the start of two requests, in milliseconds
+    pub delay_ms: Option<u64>,
+    /// Times a request answered with a 429 is sent again, after its Retry-After or an exponential backoff. 3 by default
+    pub max_retries: Option<u32>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct WebhookListenerConfig {
+    pub port: Option<u16>,
+    pub response: Option<CannedResponse>,
+}
+
 impl Config {
     pub fn is_syntax_highlighting_disabled(&self) -> bool {
         return self.disable_syntax_highlighting.unwrap_or(false)
@@ -40,40 +216,377 @@ impl Config {
     pub fn is_cors_disabled(&self) -> bool {
         return self.disable_cors.unwrap_or(false)
     }
+
+    pub fn get_cors_origin(&self) -> &str {
+        match &self.cors_origin {
+            None => DEFAULT_CORS_ORIGIN,
+            Some(cors_origin) => cors_origin
+        }
+    }
     
+    pub fn is_response_cache_enabled(&self) -> bool {
+        return self.enable_response_cache.unwrap_or(false)
+    }
+
+    pub fn is_offline_mode_enabled(&self) -> bool {
+        return self.offline_mode.unwrap_or(false)
+    }
+
     pub fn is_image_preview_disabled(&self) -> bool {
         return self.disable_images_preview.unwrap_or(false)
     }
     
+    pub fn is_cookie_persistence_disabled(&self) -> bool {
+        return self.disable_cookie_persistence.unwrap_or(false)
+    }
+
+    pub fn is_project_directory_detection_disabled(&self) -> bool {
+        return self.disable_project_directory_detection.unwrap_or(false)
+    }
+
+    pub fn should_block_unresolved_variables(&self) -> bool {
+        return self.block_unresolved_variables.unwrap_or(false)
+    }
+
     pub fn get_preferred_collection_file_format(&self) -> CollectionFileFormat {
         match &self.preferred_collection_file_format {
             None => CollectionFileFormat::default(),
             Some(file_format) => file_format.clone()
         }
     }
-}
 
-impl App<'_> {
-    pub fn parse_config_file(&mut self, path_buf: PathBuf) {
-        let mut file_content = String::new();
+    pub fn get_preferred_collection_layout(&self) -> CollectionLayout {
+        match &self.preferred_collection_layout {
+            None => CollectionLayout::default(),
+            Some(layout) => *layout
+        }
+    }
+
+    pub fn get_default_headers(&self) -> IndexMap<String, String> {
+        match &self.default_headers {
+            None => IndexMap::new(),
+            Some(default_headers) => default_headers.clone()
+        }
+    }
 
-        trace!("Trying to open or create \"atac.toml\" config file");
+    pub fn get_notification_method(&self) -> NotificationMethod {
+        match &self.notifications {
+            Some(NotificationsConfig { method: Some(method), .. }) => *method,
+            _ => NotificationMethod::default()
+        }
+    }
 
-        let mut config_file = OpenOptions::new()
-            .read(true)
-            .write(true)
-            .open(path_buf.clone())
-            .expect("\tCould not open config file");
+    pub fn get_notification_minimum_duration(&self) -> Duration {
+        match &self.notifications {
+            Some(NotificationsConfig { minimum_duration: Some(minimum_duration), .. }) => Duration::from_secs(*minimum_duration),
+            _ => Duration::ZERO
+        }
+    }
+
+    pub fn get_load_test_options(&self) -> LoadTestOptions {
+        let mut options = LoadTestOptions::default();
 
-        config_file.read_to_string(&mut file_content).expect("\tCould not read config file");
+        let load_test_config = match &self.load_test {
+            None => return options,
+            Some(load_test_config) => load_test_config
+        };
 
-        let config: Config = match toml::from_str(&file_content) {
+        if let Some(concurrency) = load_test_config.concurrency {
+            options.concurrency = concurrency;
+        }
+
+        if let Some(iterations) = load_test_config.iterations {
+            options.limit = LoadTestLimit::Iterations(iterations);
+        }
+
+        if let Some(duration) = &load_test_config.duration {
+            match parse_interval(duration) {
+                Ok(duration) => options.limit = LoadTestLimit::Duration(duration),
+                Err(error) => warn!("{error}")
+            }
+        }
+
+        return options;
+    }
+
+    pub fn get_rate_limit_options(&self) -> RateLimitOptions {
+        let mut options = RateLimitOptions::default();
+
+        let runner_config = match &self.runner {
+            None => return options,
+            Some(runner_config) => runner_config
+        };
+
+        options.requests_per_second = runner_config.requests_per_second;
+
+        if let Some(concurrency) = runner_config.concurrency {
+            options.concurrency = concurrency;
+        }
+
+        if let Some(delay_ms) = runner_config.delay_ms {
+            options.delay = Duration::from_millis(delay_ms);
+        }
+
+        if let Some(max_retries) = runner_config.max_retries {
+            options.max_retries = max_retries;
+        }
+
+        return options;
+    }
+
+    /// None when the requests are not limited
+    pub fn get_max_concurrent_requests(&self) -> Option<usize> {
+        return self.max_concurrent_requests.filter(|max_concurrent_requests| *max_concurrent_requests > 0);
+    }
+
+    pub fn get_connection_pool_options(&self) -> ConnectionPoolOptions {
+        return self.connection_pool.clone().unwrap_or_default();
+    }
+
+    pub fn get_http2_options(&self) -> Http2Options {
+        return self.http2.clone().unwrap_or_default();
+    }
+
+    /// Oldest and newest TLS versions of the config, None leaves them to the TLS backend
+    pub fn get_tls_versions(&self) -> (Option<TlsVersion>, Option<TlsVersion>) {
+        return match &self.tls {
+            None => (None, None),
+            Some(tls) => (tls.min_version, tls.max_version)
+        };
+    }
+
+    pub fn get_status_bar_hints(&self) -> StatusBarHints {
+        match &self.status_bar {
+            Some(StatusBarConfig { hints: Some(hints), .. }) => *hints,
+            _ => StatusBarHints::default()
+        }
+    }
+
+    pub fn should_show_pending_requests(&self) -> bool {
+        match &self.status_bar {
+            Some(StatusBarConfig { show_pending_requests: Some(show_pending_requests), .. }) => *show_pending_requests,
+            _ => true
+        }
+    }
+
+    /// Every tab when none is given
+    pub fn get_shown_result_tabs(&self) -> Vec<ResultTab> {
+        match &self.result_tabs {
+            Some(ResultTabsConfig { shown: Some(shown), .. }) if !shown.is_empty() => shown.clone(),
+            _ => vec![ResultTab::Body, ResultTab::Cookies, ResultTab::Headers, ResultTab::Tests, ResultTab::Console]
+        }
+    }
+
+    pub fn get_result_tab_focus(&self) -> ResultTabFocus {
+        match &self.result_tabs {
+            Some(ResultTabsConfig { focus_after_response: Some(focus_after_response), .. }) => *focus_after_response,
+            _ => ResultTabFocus::default()
+        }
+    }
+
+    pub fn get_locale(&self) -> Locale {
+        match &self.locale {
+            None => Locale::default(),
+            Some(locale) => *locale
+        }
+    }
+
+    pub fn is_production_environment(&self, environment_name: &str) -> bool {
+        match &self.production_environments {
+            None => false,
+            Some(production_environments) => production_environments.iter().any(|name| name == environment_name)
+        }
+    }
+
+    pub fn get_workspaces(&self) -> IndexMap<String, PathBuf> {
+        match &self.workspaces {
+            None => IndexMap::new(),
+            Some(workspaces) => workspaces.clone()
+        }
+    }
+
+    /// Replace the values set in a workspace config, the workspaces, the project detection and the locale can only be set in the main config
+    pub fn merge_workspace_config(&mut self, workspace_config: Config) {
+        let Config {
+            disable_syntax_highlighting,
+            disable_cors,
+            cors_origin,
+            enable_response_cache,
+            offline_mode,
+            disable_images_preview,
+            disable_cookie_persistence,
+            preferred_collection_file_format,
+            preferred_collection_layout,
+            default_headers,
+            proxy,
+            webhook_listener,
+            notifications,
+            load_test,
+            runner,
+            max_concurrent_requests,
+            connection_pool,
+            http2,
+            tls,
+            dns_resolver,
+            production_environments,
+            block_unresolved_variables,
+            status_bar,
+            result_tabs,
+            locale: _,
+            workspaces: _,
+            disable_project_directory_detection: _
+        } = workspace_config;
+
+        if disable_syntax_highlighting.is_some() {
+            self.disable_syntax_highlighting = disable_syntax_highlighting;
+        }
+
+        if disable_cors.is_some() {
+            self.disable_cors = disable_cors;
+        }
+
+        if cors_origin.is_some() {
+            self.cors_origin = cors_origin;
+        }
+
+        if enable_response_cache.is_some() {
+            self.enable_response_cache = enable_response_cache;
+        }
+
+        if offline_mode.is_some() {
+            self.offline_mode = offline_mode;
+        }
+
+        if disable_images_preview.is_some() {
+            self.disable_images_preview = disable_images_preview;
+        }
+
+        if disable_cookie_persistence.is_some() {
+            self.disable_cookie_persistence = disable_cookie_persistence;
+        }
+
+        if preferred_collection_file_format.is_some() {
+            self.preferred_collection_file_format = preferred_collection_file_format;
+        }
+
+        if preferred_collection_layout.is_some() {
+            self.preferred_collection_layout = preferred_collection_layout;
+        }
+
+        if default_headers.is_some() {
+            self.default_headers = default_headers;
+        }
+
+        if proxy.is_some() {
+            self.proxy = proxy;
+        }
+
+        if webhook_listener.is_some() {
+            self.webhook_listener = webhook_listener;
+        }
+
+        if notifications.is_some() {
+            self.notifications = notifications;
+        }
+
+        if load_test.is_some() {
+            self.load_test = load_test;
+        }
+
+        if runner.is_some() {
+            self.runner = runner;
+        }
+
+        if max_concurrent_requests.is_some() {
+            self.max_concurrent_requests = max_concurrent_requests;
+        }
+
+        if connection_pool.is_some() {
+            self.connection_pool = connection_pool;
+        }
+
+        if http2.is_some() {
+            self.http2 = http2;
+        }
+
+        if tls.is_some() {
+            self.tls = tls;
+        }
+
+        if dns_resolver.is_some() {
+            self.dns_resolver = dns_resolver;
+        }
+
+        if production_environments.is_some() {
+            self.production_environments = production_environments;
+        }
+
+        if block_unresolved_variables.is_some() {
+            self.block_unresolved_variables = block_unresolved_variables;
+        }
+
+        if status_bar.is_some() {
+            self.status_bar = status_bar;
+        }
+
+        if result_tabs.is_some() {
+            self.result_tabs = result_tabs;
+        }
+    }
+
+    pub fn get_webhook_listener_port(&self) -> u16 {
+        match &self.webhook_listener {
+            Some(WebhookListenerConfig { port: Some(port), .. }) => *port,
+            _ => 8080
+        }
+    }
+
+    pub fn get_webhook_listener_response(&self) -> CannedResponse {
+        match &self.webhook_listener {
+            Some(WebhookListenerConfig { response: Some(response), .. }) => response.clone(),
+            _ => CannedResponse::default()
+        }
+    }
+}
+
+impl App<'_> {
+    pub fn parse_config_file(&mut self, path_buf: PathBuf) {
+        self.config = match read_config_file(path_buf) {
             Ok(config) => config,
             Err(e) => panic_error(format!("Could not parse config file\n\t{e}"))
         };
 
-        self.config = config;
-
         trace!("Config file parsed!");
     }
+
+    /// Apply the atac.toml of the selected workspace on top of the main config, the main config is kept alone when it cannot be parsed
+    pub fn parse_workspace_config_file(&mut self, path_buf: PathBuf) {
+        let workspace_config = match read_config_file(path_buf.clone()) {
+            Ok(workspace_config) => workspace_config,
+            Err(e) => {
+                error!("Could not parse workspace config file \"{}\": {e}", path_buf.display());
+                return;
+            }
+        };
+
+        self.config.merge_workspace_config(workspace_config);
+
+        trace!("Workspace config file parsed!");
+    }
+}
+
+fn read_config_file(path_buf: PathBuf) -> Result<Config, toml::de::Error> {
+    let mut file_content = String::new();
+
+    trace!("Trying to open or create \"atac.toml\" config file");
+
+    let mut config_file = OpenOptions::new()
+        .read(true)
+        .write(true)
+        .open(path_buf.clone())
+        .expect("\tCould not open config file");
+
+    config_file.read_to_string(&mut file_content).expect("\tCould not read config file");
+
+    return toml::from_str(&file_content);
 }
@@ -0,0 +1,88 @@
+use std::fs::{File, OpenOptions};
+use std::io::BufReader;
+use std::path::{Path, PathBuf};
+
+use cookie_store::CookieStore;
+use reqwest_cookie_store::CookieStoreRwLock;
+use tracing::{trace, warn};
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+
+impl App<'_> {
+    /// Cookie jar file of the selected environment, ".cookies.<env>" or ".cookies" without any environment.
+    /// Returns None when the persistence is disabled or when there is no workspace directory
+    pub fn get_cookie_jar_path(&self) -> Option<PathBuf> {
+        if self.config.is_cookie_persistence_disabled() {
+            return None;
+        }
+
+        let directory = self.get_workspace_directory()?;
+
+        let file_name = match self.environments.get(self.selected_environment) {
+            Some(environment) => format!(".cookies.{}", environment.read().name),
+            None => String::from(".cookies")
+        };
+
+        return Some(directory.join(file_name));
+    }
+
+    /// Replace the cookie store content with the cookie jar of the selected environment
+    pub fn load_cookie_jar(&mut self) {
+        let path = match self.get_cookie_jar_path() {
+            None => return,
+            Some(path) => path
+        };
+
+        let cookie_store = match path.exists() {
+            true => {
+                trace!("Loading cookie jar \"{}\"", path.display());
+
+                match File::open(&path) {
+                    Ok(file) => match CookieStore::load_json(BufReader::new(file)) {
+                        Ok(cookie_store) => cookie_store,
+                        Err(error) => {
+                            warn!("Could not parse cookie jar \"{}\"\n\t{error}", path.display());
+                            CookieStore::default()
+                        }
+                    },
+                    Err(error) => {
+                        warn!("Could not open cookie jar \"{}\"\n\t{error}", path.display());
+                        CookieStore::default()
+                    }
+                }
+            },
+            false => CookieStore::default()
+        };
+
+        *self.cookies_popup.cookie_store.write().unwrap() = cookie_store;
+    }
+
+    pub fn save_cookie_jar(&self) {
+        if let Some(path) = self.get_cookie_jar_path() {
+            save_cookie_jar_to_file(&self.cookies_popup.cookie_store, &path);
+        }
+    }
+}
+
+/// Session cookies are saved too, since keeping them is the point of the jar
+pub fn save_cookie_jar_to_file(cookie_store: &CookieStoreRwLock, path: &Path) {
+    if !ARGS.should_save {
+        trace!("Should not save cookie jar");
+        return;
+    }
+
+    trace!("Writing cookie jar \"{}\"", path.display());
+
+    let mut file = match OpenOptions::new().write(true).create(true).truncate(true).open(path) {
+        Ok(file) => file,
+        Err(error) => {
+            warn!("Could not open cookie jar \"{}\"\n\t{error}", path.display());
+            return;
+        }
+    };
+
+    if let Err(error) = cookie_store.read().unwrap().save_incl_expired_and_nonpersistent_json(&mut file) {
+        warn!("Could not write cookie jar \"{}\"\n\t{error}", path.display());
+    }
+}
@@ -0,0 +1,229 @@
+use std::env;
+use std::fs;
+use std::io::{stdout, Read, Write};
+use std::iter;
+use std::path::{Path, PathBuf};
+
+use age::{Decryptor, Encryptor, Identity, IdentityFile, Recipient, scrypt};
+use age::secrecy::{ExposeSecret, SecretString};
+use anyhow::anyhow;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use ratatui::crossterm::ExecutableCommand;
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode, is_raw_mode_enabled, EnterAlternateScreen, LeaveAlternateScreen};
+use thiserror::Error;
+use tracing::{info, trace};
+
+use crate::app::files::encryption::EncryptionError::{CouldNotDecrypt, CouldNotEncrypt, CouldNotReadIdentityFile, CouldNotReadPassphrase, PassphraseIsEmpty, PassphrasesDoNotMatch};
+use crate::app::files::utils::expand_tilde;
+
+/// Encrypted files are suffixed, e.g. "my_collection.json.age" or ".env.my_env.age"
+pub const ENCRYPTED_FILE_EXTENSION: &str = "age";
+
+/// Lower than the age default (about 1 second), since the files are encrypted again on every save
+const SCRYPT_WORK_FACTOR: u8 = 16;
+
+#[derive(Error, Debug)]
+pub enum EncryptionError {
+    #[error("Could not read the identity file \"{0}\"\n\t{1}")]
+    CouldNotReadIdentityFile(String, String),
+    #[error("Could not read the passphrase\n\t{0}")]
+    CouldNotReadPassphrase(String),
+    #[error("The passphrase is empty")]
+    PassphraseIsEmpty,
+    #[error("The passphrases do not match")]
+    PassphrasesDoNotMatch,
+    #[error("Could not encrypt \"{0}\"\n\t{1}")]
+    CouldNotEncrypt(String, String),
+    #[error("Could not decrypt \"{0}\", is the key right?\n\t{1}")]
+    CouldNotDecrypt(String, String),
+}
+
+#[derive(Clone)]
+enum EncryptionKey {
+    Passphrase(SecretString),
+    /// Content of an age identity file, e.g. generated with age-keygen
+    IdentityFile(SecretString),
+}
+
+lazy_static! {
+    /// Kept once known to be right, then used for every encrypted file
+    static ref ENCRYPTION_KEY: RwLock<Option<EncryptionKey>> = RwLock::new(None);
+}
+
+pub fn is_encrypted_file(path: &Path) -> bool {
+    return path.extension().is_some_and(|extension| extension == ENCRYPTED_FILE_EXTENSION);
+}
+
+/// "my_collection.json" <-> "my_collection.json.age"
+pub fn toggle_encrypted_file_path(path: &Path) -> PathBuf {
+    match is_encrypted_file(path) {
+        true => path.with_extension(""),
+        false => {
+            let mut file_name = path.file_name().unwrap().to_os_string();
+            file_name.push(format!(".{ENCRYPTED_FILE_EXTENSION}"));
+            path.with_file_name(file_name)
+        }
+    }
+}
+
+/// Read a file, decrypting it first if it is an encrypted file
+pub fn read_file_content(path: &Path) -> anyhow::Result<String> {
+    if !is_encrypted_file(path) {
+        return Ok(fs::read_to_string(path)?);
+    }
+
+    trace!("Decrypting \"{}\"", path.display());
+
+    let ciphertext = fs::read(path)?;
+    let key = get_encryption_key(false)?;
+
+    let plaintext = match decrypt(&ciphertext, &key) {
+        // The key is known to be right, it is kept for the next files
+        Ok(plaintext) => {
+            *ENCRYPTION_KEY.write() = Some(key);
+            plaintext
+        },
+        // A mistyped passphrase must not be used again, it is asked on the next access
+        Err(error) => {
+            *ENCRYPTION_KEY.write() = None;
+            return Err(anyhow!(CouldNotDecrypt(path.display().to_string(), error.to_string())));
+        }
+    };
+
+    return Ok(String::from_utf8(plaintext)?);
+}
+
+/// Write a file through a temporary file, encrypting it first if it is an encrypted file
+pub fn write_file_content(path: &Path, content: &str) -> anyhow::Result<()> {
+    let bytes = match is_encrypted_file(path) {
+        false => content.as_bytes().to_vec(),
+        true => {
+            trace!("Encrypting \"{}\"", path.display());
+
+            // No encrypted file was read yet, the passphrase is new
+            let key = get_encryption_key(true)?;
+
+            let ciphertext = encrypt(content.as_bytes(), &key)
+                .map_err(|error| anyhow!(CouldNotEncrypt(path.display().to_string(), error.to_string())))?;
+
+            // A new passphrase was confirmed when prompted, the files it encrypts are then read with it
+            if ENCRYPTION_KEY.read().is_none() {
+                *ENCRYPTION_KEY.write() = Some(key);
+            }
+
+            ciphertext
+        }
+    };
+
+    let temp_file_path = path.with_file_name(format!("{}_", path.file_name().unwrap().to_str().unwrap()));
+
+    fs::write(&temp_file_path, bytes)?;
+    fs::rename(temp_file_path, path)?;
+
+    Ok(())
+}
+
+/// Key from $ATAC_IDENTITY_FILE or $ATAC_PASSPHRASE, otherwise the passphrase is prompted.
+/// The key is only kept once it decrypted a file, or encrypted one with a confirmed passphrase
+fn get_encryption_key(is_new_passphrase: bool) -> anyhow::Result<EncryptionKey> {
+    if let Some(key) = ENCRYPTION_KEY.read().as_ref() {
+        return Ok(key.clone());
+    }
+
+    let key = if let Ok(identity_file_path) = env::var("ATAC_IDENTITY_FILE") {
+        info!("Using identity file \"{identity_file_path}\"");
+
+        let identity_file_path = expand_tilde(PathBuf::from(identity_file_path));
+
+        match fs::read_to_string(&identity_file_path) {
+            Ok(identity_file) => EncryptionKey::IdentityFile(SecretString::from(identity_file)),
+            Err(error) => return Err(anyhow!(CouldNotReadIdentityFile(identity_file_path.display().to_string(), error.to_string())))
+        }
+    }
+    else if let Ok(passphrase) = env::var("ATAC_PASSPHRASE") {
+        EncryptionKey::Passphrase(SecretString::from(passphrase))
+    }
+    else {
+        EncryptionKey::Passphrase(prompt_passphrase(is_new_passphrase)?)
+    };
+
+    return Ok(key);
+}
+
+fn prompt_passphrase(is_new_passphrase: bool) -> anyhow::Result<SecretString> {
+    // The TUI may be displayed, e.g. when switching to a workspace containing encrypted files
+    let is_tui_displayed = is_raw_mode_enabled().unwrap_or(false);
+
+    if is_tui_displayed {
+        disable_raw_mode()?;
+        stdout().execute(LeaveAlternateScreen)?;
+    }
+
+    let passphrase = read_passphrase(is_new_passphrase);
+
+    if is_tui_displayed {
+        stdout().execute(EnterAlternateScreen)?;
+        enable_raw_mode()?;
+    }
+
+    return passphrase;
+}
+
+fn read_passphrase(is_new_passphrase: bool) -> anyhow::Result<SecretString> {
+    let passphrase = rpassword::prompt_password("Passphrase of the encrypted files: ")
+        .map_err(|error| anyhow!(CouldNotReadPassphrase(error.to_string())))?;
+
+    if passphrase.is_empty() {
+        return Err(anyhow!(PassphraseIsEmpty));
+    }
+
+    if is_new_passphrase {
+        let confirmation = rpassword::prompt_password("Confirm the passphrase: ")
+            .map_err(|error| anyhow!(CouldNotReadPassphrase(error.to_string())))?;
+
+        if confirmation != passphrase {
+            return Err(anyhow!(PassphrasesDoNotMatch));
+        }
+    }
+
+    return Ok(SecretString::from(passphrase));
+}
+
+fn encrypt(plaintext: &[u8], key: &EncryptionKey) -> anyhow::Result<Vec<u8>> {
+    let encryptor = match key {
+        EncryptionKey::Passphrase(passphrase) => {
+            let mut recipient = scrypt::Recipient::new(passphrase.clone());
+            recipient.set_work_factor(SCRYPT_WORK_FACTOR);
+
+            Encryptor::with_recipients(iter::once(&recipient as &dyn Recipient))?
+        },
+        EncryptionKey::IdentityFile(identity_file) => {
+            let recipients = IdentityFile::from_buffer(identity_file.expose_secret().as_bytes())?.to_recipients()?;
+
+            Encryptor::with_recipients(recipients.iter().map(|recipient| recipient.as_ref() as &dyn Recipient))?
+        }
+    };
+
+    let mut ciphertext = vec![];
+    let mut writer = encryptor.wrap_output(&mut ciphertext)?;
+    writer.write_all(plaintext)?;
+    writer.finish()?;
+
+    return Ok(ciphertext);
+}
+
+fn decrypt(ciphertext: &[u8], key: &EncryptionKey) -> anyhow::Result<Vec<u8>> {
+    let identities: Vec<Box<dyn Identity>> = match key {
+        EncryptionKey::Passphrase(passphrase) => vec![Box::new(scrypt::Identity::new(passphrase.clone()))],
+        EncryptionKey::IdentityFile(identity_file) => IdentityFile::from_buffer(identity_file.expose_secret().as_bytes())?.into_identities()?
+    };
+
+    let decryptor = Decryptor::new(ciphertext)?;
+
+    let mut plaintext = vec![];
+    let mut reader = decryptor.decrypt(identities.iter().map(|identity| identity.as_ref()))?;
+    reader.read_to_end(&mut plaintext)?;
+
+    return Ok(plaintext);
+}
@@ -1,36 +1,42 @@
 use std::fs;
-use std::fs::{File, OpenOptions};
-use std::io::{BufRead, BufReader, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::from_utf8;
 use std::sync::Arc;
 
+use anyhow::anyhow;
 use indexmap::IndexMap;
 use parking_lot::RwLock;
 use snailquote::unescape;
-use tracing::{info, trace, warn};
+use tracing::{error, info, trace, warn};
 use rayon::prelude::*;
 
 use crate::app::app::App;
+use crate::app::business_logic::app_error::report_error;
+use crate::app::files::encryption::{is_encrypted_file, read_file_content, write_file_content, ENCRYPTED_FILE_EXTENSION};
+use crate::app::files::file_watcher::record_modified_time;
 use crate::cli::args::ARGS;
-use crate::panic_error;
 use crate::models::environment::Environment;
 
 impl App<'_> {
-    /// Add the environment file to the app environments
+    /// Add the environment file to the app environments, an unreadable environment is skipped
     pub fn add_environment_from_file(&mut self, path_buf: PathBuf) {
-        let file_name = path_buf.file_name().unwrap().to_str().unwrap().to_string().replace(".env.", "");
+        let file_name = get_environment_name(&path_buf);
 
         trace!("Trying to open \"{}\" env file", path_buf.display());
 
-        let env_file: File = match File::open(path_buf.clone()) {
-            Ok(env_file) => env_file,
-            Err(e) => panic_error(format!("Could not open environment file\n\t{e}"))
+        let values = match read_environment_file(&path_buf) {
+            Ok(values) => values,
+            Err(e) => {
+                error!("Environment file \"{}\" skipped: {e}", path_buf.display());
+                return;
+            }
         };
 
+        record_modified_time(&path_buf);
+
         let environment = Environment {
             name: file_name,
-            values: read_environment_from_file(env_file),
+            values,
             path: path_buf,
         };
         
@@ -41,20 +47,36 @@ impl App<'_> {
 
     pub fn save_environment_to_file(&mut self, env_index: usize) {
         let environment = self.environments[env_index].read();
-
         save_environment_to_file(&environment);
     }
 }
 
-fn read_environment_from_file(file: File) -> IndexMap<String, String> {
-    let reader = BufReader::new(file);
+/// e.g. "my_env" for ".env.my_env" and ".env.my_env.age"
+pub fn get_environment_name(path: &Path) -> String {
+    let file_name = path.file_name().unwrap().to_str().unwrap().to_string().replace(".env.", "");
+
+    return match is_encrypted_file(path) {
+        true => file_name.trim_end_matches(&format!(".{ENCRYPTED_FILE_EXTENSION}")).to_string(),
+        false => file_name
+    };
+}
+
+/// Read the values of an environment file, without panicking since the file may be reloaded while the TUI runs
+pub fn read_environment_file(path: &Path) -> anyhow::Result<IndexMap<String, String>> {
+    let env_file_content = match read_file_content(path) {
+        Ok(env_file_content) => env_file_content,
+        Err(e) => return Err(anyhow!("Could not open environment file\n\t{e}"))
+    };
+
+    return Ok(read_environment_from_file(&env_file_content));
+}
+
+fn read_environment_from_file(file_content: &str) -> IndexMap<String, String> {
     let mut environment_values = IndexMap::new();
 
-    for line in reader.lines() {
-        if let Ok(line) = line {
-            if let Some((key, value)) = parse_line(line.trim().as_bytes()) {
-                environment_values.insert(key, value);
-            }
+    for line in file_content.lines() {
+        if let Some((key, value)) = parse_line(line.trim().as_bytes()) {
+            environment_values.insert(key, value);
         }
     }
 
@@ -84,8 +106,13 @@ fn parse_line(entry: &[u8]) -> Option<(String, String)> {
     })
 }
 
-/// Save app environment in a file through a temporary file
+/// Save app environment in a file through a temporary file, encrypted if the environment file is
 pub fn save_environment_to_file(environment: &Environment) {
+    // Kept in memory, e.g. the environments given to the library engine
+    if environment.path.as_os_str().is_empty() {
+        return;
+    }
+
     if !ARGS.should_save {
         warn!("Dry-run, not saving the environment");
         return;
@@ -93,17 +120,6 @@ pub fn save_environment_to_file(environment: &Environment) {
 
     info!("Saving environment \"{}\"", environment.name);
 
-    let temp_file_name = format!("{}_", environment.path.file_name().unwrap().to_str().unwrap());
-
-    let temp_file_path = environment.path.with_file_name(temp_file_name);
-
-    let mut temp_file = OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(&temp_file_path)
-        .expect("Could not open temp file");
-
     let mut data: String = environment.values
         .iter()
         .par_bridge()
@@ -113,10 +129,27 @@ pub fn save_environment_to_file(environment: &Environment) {
     // Remove trailing \n
     data.pop();
 
-    temp_file.write_all(data.as_bytes()).expect("Could not write to temp file");
-    temp_file.flush().unwrap();
+    if let Err(e) = write_file_content(&environment.path, &data) {
+        report_error(
+            "Could not write environment file",
+            format!("\"{}\" {e}", environment.path.display()),
+            "Check the permissions of the file and the free disk space, the changes are kept until ATAC quits"
+        );
+        return;
+    }
 
-    fs::rename(temp_file_path, &environment.path).expect("Could not move temp file to environment file");
+    // Not reloaded as an external change, e.g. when saved by the scripts of a request sent in the background
+    record_modified_time(&environment.path);
 
     trace!("Environment saved")
 }
+
+pub fn delete_environment_file(path: &Path) {
+    if !ARGS.should_save {
+        return;
+    }
+
+    if let Err(e) = fs::remove_file(path) {
+        report_error("Could not delete environment file", format!("\"{}\" {e}", path.display()), "Check the permissions of the file, or delete it by hand");
+    }
+}
@@ -0,0 +1,44 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+
+lazy_static! {
+    /// Modification time of the collection and environment files when ATAC last read or wrote them,
+    /// shared with the requests sent in the background since their scripts may save the environment
+    static ref FILES_MODIFIED_TIMES: RwLock<HashMap<PathBuf, SystemTime>> = RwLock::new(HashMap::new());
+}
+
+/// Remember when a collection or environment file was last read or written by ATAC,
+/// so that only the changes made outside of ATAC are reloaded
+pub fn record_modified_time(path: &Path) {
+    if let Some(modified_time) = get_modified_time(path) {
+        FILES_MODIFIED_TIMES.write().insert(path.to_path_buf(), modified_time);
+    }
+}
+
+/// The file changed since ATAC last read or wrote it, deleted files are not considered changed
+pub fn has_file_changed(path: &Path) -> bool {
+    match get_modified_time(path) {
+        None => false,
+        Some(modified_time) => FILES_MODIFIED_TIMES.read().get(path) != Some(&modified_time)
+    }
+}
+
+/// Last modification of a file, or of a directory and the files it directly contains (file per request layout)
+fn get_modified_time(path: &Path) -> Option<SystemTime> {
+    let mut modified_time = fs::metadata(path).ok()?.modified().ok()?;
+
+    if path.is_dir() {
+        for entry in fs::read_dir(path).ok()?.flatten() {
+            if let Ok(entry_modified_time) = entry.metadata().and_then(|metadata| metadata.modified()) {
+                modified_time = modified_time.max(entry_modified_time);
+            }
+        }
+    }
+
+    return Some(modified_time);
+}
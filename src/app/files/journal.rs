@@ -0,0 +1,69 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use tracing::{trace, warn};
+
+use crate::app::files::encryption::{read_file_content, toggle_encrypted_file_path, write_file_content};
+use crate::cli::args::ARGS;
+use crate::models::journal::UnsavedEdit;
+
+/// Journal of the workspace directory, ".journal.age" when the edited collection is encrypted
+const JOURNAL_FILE_NAME: &str = ".journal";
+
+/// Write the edit in progress, so that it can be recovered after a crash or a closed terminal
+pub fn save_journal(directory: &Path, unsaved_edit: &UnsavedEdit, is_encrypted: bool) {
+    if !ARGS.should_save {
+        return;
+    }
+
+    let journal_path = match is_encrypted {
+        true => toggle_encrypted_file_path(&directory.join(JOURNAL_FILE_NAME)),
+        false => directory.join(JOURNAL_FILE_NAME)
+    };
+
+    trace!("Saving journal \"{}\"", journal_path.display());
+
+    let journal = serde_json::to_string_pretty(unsaved_edit).expect("Could not serialize journal");
+
+    if let Err(error) = write_file_content(&journal_path, &journal) {
+        warn!("Could not save journal\n\t{error}");
+    }
+}
+
+/// Edit left unsaved by the previous run, if any
+pub fn read_journal(directory: &Path) -> Option<UnsavedEdit> {
+    let journal_path = get_journal_paths(directory).into_iter().find(|path| path.is_file())?;
+
+    trace!("Reading journal \"{}\"", journal_path.display());
+
+    let journal = match read_file_content(&journal_path) {
+        Ok(journal) => journal,
+        Err(error) => {
+            warn!("Could not read journal\n\t{error}");
+            return None;
+        }
+    };
+
+    match serde_json::from_str(&journal) {
+        Ok(unsaved_edit) => Some(unsaved_edit),
+        Err(error) => {
+            warn!("Could not parse journal\n\t{error}");
+            None
+        }
+    }
+}
+
+pub fn delete_journal(directory: &Path) {
+    for journal_path in get_journal_paths(directory) {
+        if journal_path.is_file() {
+            trace!("Deleting journal \"{}\"", journal_path.display());
+            fs::remove_file(journal_path).ok();
+        }
+    }
+}
+
+fn get_journal_paths(directory: &Path) -> [PathBuf; 2] {
+    let journal_path = directory.join(JOURNAL_FILE_NAME);
+
+    return [toggle_encrypted_file_path(&journal_path), journal_path];
+}
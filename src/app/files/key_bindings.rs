@@ -34,11 +34,71 @@ nest! {
             pub move_request_down: KeyCombination,
 
             pub next_environment: KeyCombination,
-
-            pub display_cookies: KeyCombination,},
+            pub previous_environment: KeyCombination,
+            /// Popup listing every environment
+            pub choose_environment: KeyCombination,
+            /// Every environment variable with the requests referencing it
+            pub display_variable_usages: KeyCombination,
+            /// Cycle between the main directory and the config workspaces
+            pub next_workspace: KeyCombination,
+
+            pub display_cookies: KeyCombination,
+            /// Only used in the cookies popup
+            pub delete_domain_cookies: KeyCombination,
+
+            pub display_webhook_listener: KeyCombination,
+
+            /// Git status of the workspace directory
+            pub display_git_status: KeyCombination,
+            /// Only used in the git popup
+            pub git_pull: KeyCombination,
+            /// Only used in the git popup
+            pub git_commit: KeyCombination,
+            /// Only used in the git popup
+            pub git_push: KeyCombination,
+
+            /// Snippets and notes pinned in the workspace
+            pub display_scratchpad: KeyCombination,
+            /// Only used in the scratchpad popup
+            pub pin_clipboard: KeyCombination,
+
+            /// Stored responses of the response cache and the cache status of the last sends
+            pub display_response_cache: KeyCombination,
+
+            /// Answer the sends with the request snapshots instead of the network
+            pub toggle_offline_mode: KeyCombination,
+
+            /// Application log, with the request lifecycle, the file errors and the warnings
+            pub display_logs: KeyCombination,
+
+            /// JavaScript console with the collections, the environment and a send function
+            pub display_scripting_console: KeyCombination,
+
+            /// Edit the Markdown description of the highlighted collection or request in $EDITOR
+            pub edit_description: KeyCombination,
+
+            /// Tags of the highlighted collection or request, separated by commas
+            pub edit_tags: KeyCombination,
+            /// Only display the requests having one of the given tags, or whose collection has one
+            pub filter_by_tag: KeyCombination,
+            /// Leave the highlighted request out of the collection runs, or include it again
+            pub toggle_request_skip: KeyCombination,
+
+            /// Only used in the new request popup
+            pub next_request_template: KeyCombination,},
 
         pub generic: #[derive(Copy, Clone, Deserialize)] pub struct Generic {
             pub display_help: KeyCombination,
+            /// Every action grouped by context, searchable. Not available while typing text
+            pub display_cheatsheet: KeyCombination,
+
+            /// Cancels every pending request, stops the webhook listener and returns to the main menu
+            pub abort_all: KeyCombination,
+
+            /// Start or stop recording the pressed keys, available everywhere
+            pub record_macro: KeyCombination,
+            /// Replay the recorded keys a given number of times, available everywhere
+            pub replay_macro: KeyCombination,
 
             pub text_inputs: #[derive(Copy, Clone, Deserialize)] pub struct TexInputs {
                 /// Collection name, request name, URL, Header, Query param, Basic Auth, Bearer Token
@@ -51,6 +111,9 @@ nest! {
 
                     pub move_cursor_left: KeyCombination,
                     pub move_cursor_right: KeyCombination,
+
+                    /// Insert the environment key highlighted in the completions displayed after "{{", also used in the request body
+                    pub complete_env_key: KeyCombination,
                 },
 
                 /// Request body
@@ -84,7 +147,7 @@ nest! {
                 pub edit_element: KeyCombination,
                 /// Only used in the collections list (main menu)
                 pub rename_element: KeyCombination,
-                /// Only used in tables (Query params, headers, cookies)
+                /// Only used in tables (Query params, headers, cookies, body forms)
                 pub toggle_element: KeyCombination,
             }
         },
@@ -99,10 +162,42 @@ nest! {
 
             pub send_request: KeyCombination,
             pub alt_send_request: KeyCombination,
+
+            pub load_test: KeyCombination,
+
+            /// Send the CORS preflight a browser would send, then the request, and check the CORS headers
+            pub simulate_cors: KeyCombination,
+
+            /// Send the request without looking the response cache up, the new response replaces the cached one
+            pub refresh_request: KeyCombination,
+
+            /// Re-send the request with the ETag and Last-Modified of its last response as If-None-Match and If-Modified-Since
+            pub revalidate_request: KeyCombination,
+
+            /// Re-send the request every interval of its settings or when its body files change, while it is selected
+            pub toggle_watch: KeyCombination,
+
+            /// Request with the environment values substituted and the secrets masked
+            pub preview_request: KeyCombination,
+
+            /// Decode the tokens found in the request, its response and the environment
+            pub decode_jwt: KeyCombination,
+
+            /// Base64, URL encoding, hashes and HMAC of the selected field
+            pub encoding_tools: KeyCombination,
+            /// Only used in the encoding tools popup
+            pub insert_tool_result: KeyCombination,
             
             pub param_tabs: #[derive(Copy, Clone, Deserialize)] pub struct ParamTabs {
                 pub change_auth_method: KeyCombination,
                 pub change_body_content_type: KeyCombination,
+                pub change_user_agent: KeyCombination,
+                /// Only used while editing a header
+                pub complete_header: KeyCombination,
+                /// Only used while editing the body file path
+                pub complete_file_path: KeyCombination,
+                /// Only used while editing a JSON body
+                pub format_json_body: KeyCombination,
             },
 
             pub result_tabs: #[derive(Copy, Clone, Deserialize)] pub struct ResultTabs {
@@ -112,6 +207,32 @@ nest! {
                 pub scroll_right: KeyCombination,
 
                 pub yank_response_part: KeyCombination,
+                /// Pin the current result tab to the scratchpad
+                pub pin_response_part: KeyCombination,
+
+                /// Cycle between auto-detected, JSON, XML, HTML and plain text body highlighting
+                pub cycle_body_highlighting: KeyCombination,
+
+                /// Fold or unfold the newline-delimited JSON record at the top of the body
+                pub toggle_record_fold: KeyCombination,
+
+                /// Switch between the CSV or TSV table and the raw body
+                pub toggle_table_view: KeyCombination,
+
+                /// Switch between the body rewritten by the response processor and the body as it was received
+                pub toggle_processed_body: KeyCombination,
+
+                /// Wrap the long lines instead of scrolling horizontally, also used in the request preview
+                pub toggle_line_wrapping: KeyCombination,
+
+                /// Display the line numbers of the result body, also used in the request body editor
+                pub toggle_line_numbers: KeyCombination,
+
+                /// Scroll the result body to a given line, also used in the request body editor
+                pub go_to_line: KeyCombination,
+
+                /// List the hypermedia links of a JSON response (HAL, JSON:API, OData) to follow one as a new request
+                pub display_response_links: KeyCombination,
 
                 /// Will use param_next_tab depending on the selected view
                 pub result_next_tab: KeyCombination,
@@ -164,12 +285,49 @@ impl Default for KeyBindings {
                 move_request_down: key!(ctrl-down),
 
                 next_environment: key!(e),
+                previous_environment: key!(shift-e),
+                choose_environment: key!(ctrl-e),
+                display_variable_usages: key!(ctrl-f),
+                next_workspace: key!(ctrl-w),
 
                 display_cookies: key!(c),
+                delete_domain_cookies: key!(ctrl-d),
+
+                display_webhook_listener: key!(w),
+
+                display_git_status: key!(g),
+                git_pull: key!(p),
+                git_commit: key!(c),
+                git_push: key!(u),
+
+                display_scratchpad: key!(s),
+                pin_clipboard: key!(p),
+
+                display_response_cache: key!(k),
+
+                toggle_offline_mode: key!(o),
+
+                display_logs: key!(l),
+
+                display_scripting_console: key!(x),
+
+                edit_description: key!(i),
+
+                edit_tags: key!(t),
+                filter_by_tag: key!(f),
+                toggle_request_skip: key!(shift-s),
+
+                next_request_template: key!(tab),
             },
 
             generic: Generic {
                 display_help: key!(Ctrl-h),
+                display_cheatsheet: key!('?'),
+
+                abort_all: key!(ctrl-x),
+
+                record_macro: key!(alt-q),
+                replay_macro: key!(alt-p),
 
                 text_inputs: TexInputs {
                     text_input: TextInput {
@@ -181,6 +339,8 @@ impl Default for KeyBindings {
 
                         move_cursor_left: key!(left),
                         move_cursor_right: key!(right),
+
+                        complete_env_key: key!(tab),
                     },
                     text_area_mode: TextAreaMode::Custom(CustomTextArea::default()),
                 },
@@ -223,9 +383,30 @@ impl Default for KeyBindings {
                 send_request: key!(space),
                 alt_send_request: key!(ctrl-enter),
 
+                load_test: key!(p),
+
+                simulate_cors: key!(alt-c),
+
+                refresh_request: key!(ctrl-r),
+
+                revalidate_request: key!(alt-r),
+
+                toggle_watch: key!(alt-w),
+
+                preview_request: key!(ctrl-p),
+
+                decode_jwt: key!(j),
+
+                encoding_tools: key!(o),
+                insert_tool_result: key!(i),
+
                 param_tabs: ParamTabs {
                     change_auth_method: key!(ctrl-a),
                     change_body_content_type: key!(ctrl-b),
+                    change_user_agent: key!(ctrl-u),
+                    complete_header: key!(tab),
+                    complete_file_path: key!(tab),
+                    format_json_body: key!(alt-f),
                 },
                 result_tabs: ResultTabs {
                     scroll_up: key!(ctrl-up),
@@ -234,6 +415,23 @@ impl Default for KeyBindings {
                     scroll_right: key!(ctrl-right),
 
                     yank_response_part: key!(y),
+                    pin_response_part: key!(shift-y),
+
+                    cycle_body_highlighting: key!(ctrl-l),
+
+                    toggle_record_fold: key!(ctrl-o),
+
+                    toggle_table_view: key!(ctrl-g),
+
+                    toggle_processed_body: key!(ctrl-t),
+
+                    toggle_line_wrapping: key!(ctrl-w),
+
+                    toggle_line_numbers: key!(ctrl-n),
+
+                    go_to_line: key!(ctrl-k),
+
+                    display_response_links: key!(shift-f),
 
                     result_next_tab: key!(shift-backtab),
                 },
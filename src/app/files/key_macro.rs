@@ -0,0 +1,60 @@
+use std::fs;
+use std::path::PathBuf;
+
+use tracing::{trace, warn};
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+
+/// Last recorded macro of the workspace directory
+const MACRO_FILE_NAME: &str = ".macro";
+
+impl App<'_> {
+    fn get_key_macro_path(&self) -> Option<PathBuf> {
+        return Some(self.get_workspace_directory()?.join(MACRO_FILE_NAME));
+    }
+
+    /// Replace the recorded macro with the one of the selected workspace
+    pub fn load_key_macro(&mut self) {
+        self.macro_popup.keys.clear();
+
+        let path = match self.get_key_macro_path() {
+            Some(path) if path.is_file() => path,
+            _ => return
+        };
+
+        trace!("Loading macro \"{}\"", path.display());
+
+        let key_macro = match fs::read_to_string(&path) {
+            Ok(key_macro) => key_macro,
+            Err(error) => {
+                warn!("Could not read macro \"{}\"\n\t{error}", path.display());
+                return;
+            }
+        };
+
+        match serde_json::from_str(&key_macro) {
+            Ok(keys) => self.macro_popup.keys = keys,
+            Err(error) => warn!("Could not parse macro \"{}\"\n\t{error}", path.display())
+        }
+    }
+
+    pub fn save_key_macro(&self) {
+        if !ARGS.should_save {
+            return;
+        }
+
+        let path = match self.get_key_macro_path() {
+            None => return,
+            Some(path) => path
+        };
+
+        trace!("Saving macro \"{}\"", path.display());
+
+        let key_macro = serde_json::to_string_pretty(&self.macro_popup.keys).expect("Could not serialize macro");
+
+        if let Err(error) = fs::write(&path, key_macro) {
+            warn!("Could not save macro \"{}\"\n\t{error}", path.display());
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use tracing::{trace, warn};
+
+use crate::app::app::App;
+
+/// Language of the TUI, the strings missing from a catalog stay in English
+#[derive(Default, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Locale {
+    #[default]
+    #[serde(rename = "en")]
+    English,
+    #[serde(rename = "fr")]
+    French,
+}
+
+/// English UI strings and their translation
+const FRENCH_CATALOG: &str = include_str!("locales/fr.toml");
+
+lazy_static! {
+    /// Catalog of the selected locale, empty in English
+    static ref MESSAGES: RwLock<HashMap<String, String>> = RwLock::new(HashMap::new());
+}
+
+impl App<'_> {
+    pub fn apply_locale(&self) {
+        let catalog = match self.config.get_locale() {
+            Locale::English => return,
+            Locale::French => FRENCH_CATALOG
+        };
+
+        trace!("Parsing locale catalog");
+
+        match toml::from_str::<HashMap<String, String>>(catalog) {
+            Ok(messages) => *MESSAGES.write() = messages,
+            Err(error) => warn!("Could not parse locale catalog, using English\n\t{error}")
+        }
+    }
+}
+
+/// UI string in the selected locale, the English string is the catalog key
+pub fn tr(text: &str) -> String {
+    return match MESSAGES.read().get(text) {
+        Some(translation) => translation.clone(),
+        None => text.to_string()
+    };
+}
@@ -1,6 +1,17 @@
 pub mod collection;
 pub mod environment;
+pub mod cookies;
+pub mod templates;
+pub mod workspace;
+pub mod encryption;
+pub mod journal;
+pub mod scratchpad;
+pub mod file_watcher;
 pub mod config;
 pub mod key_bindings;
 pub mod theme;
+pub mod locale;
 pub mod utils;
+
+pub mod key_macro;
+pub mod plugins;
\ No newline at end of file
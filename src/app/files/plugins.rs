@@ -0,0 +1,90 @@
+use std::fs;
+use std::path::PathBuf;
+
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use tracing::{info, trace, warn};
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+use crate::models::plugin::{Plugin, PluginManifest, PLUGIN_API_VERSION};
+
+lazy_static! {
+    /// Read by the request sending, which runs outside of the app
+    pub static ref PLUGINS: RwLock<Vec<Plugin>> = RwLock::new(Vec::new());
+}
+
+impl App<'_> {
+    /// "plugins" folder of the app directory, shared by every workspace
+    pub fn get_plugins_directory(&self) -> Option<PathBuf> {
+        return Some(ARGS.directory.as_ref()?.join("plugins"));
+    }
+
+    /// Load every "<plugin>/plugin.toml" manifest, sorted by name
+    pub fn parse_plugins_directory(&mut self) {
+        let plugins_directory = match self.get_plugins_directory() {
+            Some(plugins_directory) if plugins_directory.is_dir() => plugins_directory,
+            _ => return
+        };
+
+        let paths = match plugins_directory.read_dir() {
+            Ok(paths) => paths,
+            Err(error) => {
+                warn!("Could not read plugins directory \"{}\"\n\t{error}", plugins_directory.display());
+                return;
+            }
+        };
+
+        let mut plugins = vec![];
+
+        for path in paths {
+            let directory = path.unwrap().path();
+            let manifest_path = directory.join("plugin.toml");
+
+            if !manifest_path.is_file() {
+                continue;
+            }
+
+            trace!("Trying to parse \"{}\" plugin manifest", manifest_path.display());
+
+            let file_content = match fs::read_to_string(&manifest_path) {
+                Ok(file_content) => file_content,
+                Err(error) => {
+                    warn!("Could not read plugin manifest \"{}\"\n\t{error}", manifest_path.display());
+                    continue;
+                }
+            };
+
+            let manifest: PluginManifest = match toml::from_str(&file_content) {
+                Ok(manifest) => manifest,
+                Err(error) => {
+                    warn!("Could not parse plugin manifest \"{}\"\n\t{error}", manifest_path.display());
+                    continue;
+                }
+            };
+
+            if manifest.api_version > PLUGIN_API_VERSION {
+                warn!("Plugin \"{}\" requires the plugin API v{}, only v{PLUGIN_API_VERSION} is supported", manifest.name, manifest.api_version);
+                continue;
+            }
+
+            if manifest.command.is_empty() {
+                warn!("Plugin \"{}\" has no command", manifest.name);
+                continue;
+            }
+
+            info!("Plugin \"{}\" loaded", manifest.name);
+
+            plugins.push(Plugin {
+                manifest,
+                directory,
+            });
+        }
+
+        plugins.sort_by(|a, b| a.manifest.name.cmp(&b.manifest.name));
+
+        *PLUGINS.write() = plugins;
+
+        trace!("Plugins parsed!");
+    }
+}
\ No newline at end of file
@@ -0,0 +1,61 @@
+use std::fs;
+use std::path::PathBuf;
+
+use tracing::{trace, warn};
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+
+/// Scratchpad of the workspace directory
+const SCRATCHPAD_FILE_NAME: &str = ".scratchpad";
+
+impl App<'_> {
+    fn get_scratchpad_path(&self) -> Option<PathBuf> {
+        return Some(self.get_workspace_directory()?.join(SCRATCHPAD_FILE_NAME));
+    }
+
+    /// Replace the pinned entries with the scratchpad of the selected workspace
+    pub fn load_scratchpad(&mut self) {
+        self.scratchpad_popup.entries.clear();
+        self.scratchpad_popup.selection = 0;
+
+        let path = match self.get_scratchpad_path() {
+            Some(path) if path.is_file() => path,
+            _ => return
+        };
+
+        trace!("Loading scratchpad \"{}\"", path.display());
+
+        let scratchpad = match fs::read_to_string(&path) {
+            Ok(scratchpad) => scratchpad,
+            Err(error) => {
+                warn!("Could not read scratchpad \"{}\"\n\t{error}", path.display());
+                return;
+            }
+        };
+
+        match serde_json::from_str(&scratchpad) {
+            Ok(entries) => self.scratchpad_popup.entries = entries,
+            Err(error) => warn!("Could not parse scratchpad \"{}\"\n\t{error}", path.display())
+        }
+    }
+
+    pub fn save_scratchpad(&self) {
+        if !ARGS.should_save {
+            return;
+        }
+
+        let path = match self.get_scratchpad_path() {
+            None => return,
+            Some(path) => path
+        };
+
+        trace!("Saving scratchpad \"{}\"", path.display());
+
+        let scratchpad = serde_json::to_string_pretty(&self.scratchpad_popup.entries).expect("Could not serialize scratchpad");
+
+        if let Err(error) = fs::write(&path, scratchpad) {
+            warn!("Could not save scratchpad \"{}\"\n\t{error}", path.display());
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::PathBuf;
+
+use tracing::{info, trace, warn};
+
+use crate::app::app::App;
+use crate::cli::args::ARGS;
+use crate::models::collection::CollectionFileFormat;
+use crate::models::template::RequestTemplate;
+
+impl App<'_> {
+    /// "templates" folder of the workspace directory
+    pub fn get_templates_directory(&self) -> Option<PathBuf> {
+        let directory = self.get_workspace_directory()?;
+
+        return Some(directory.join("templates"));
+    }
+
+    /// Load every JSON or YAML request template, sorted by name
+    pub fn parse_templates_directory(&mut self) {
+        let templates_directory = match self.get_templates_directory() {
+            Some(templates_directory) if templates_directory.is_dir() => templates_directory,
+            _ => return
+        };
+
+        let paths = match templates_directory.read_dir() {
+            Ok(paths) => paths,
+            Err(error) => {
+                warn!("Could not read templates directory \"{}\"\n\t{error}", templates_directory.display());
+                return;
+            }
+        };
+
+        for path in paths {
+            let path = path.unwrap().path();
+
+            let file_format = match path.extension().and_then(|extension| extension.to_str()).and_then(CollectionFileFormat::from_extension) {
+                Some(file_format) => file_format,
+                None => continue
+            };
+
+            trace!("Trying to parse \"{}\" template", path.display());
+
+            let file_content = match fs::read_to_string(&path) {
+                Ok(file_content) => file_content,
+                Err(error) => {
+                    warn!("Could not read template \"{}\"\n\t{error}", path.display());
+                    continue;
+                }
+            };
+
+            let template: Result<RequestTemplate, String> = match file_format {
+                CollectionFileFormat::Json => serde_json::from_str(&file_content).map_err(|error| error.to_string()),
+                CollectionFileFormat::Yaml => serde_yaml::from_str(&file_content).map_err(|error| error.to_string()),
+                CollectionFileFormat::Toml => toml::from_str(&file_content).map_err(|error| error.to_string())
+            };
+
+            match template {
+                Ok(mut template) => {
+                    template.name = path.file_stem().unwrap().to_str().unwrap().to_string();
+                    self.request_templates.push(template);
+                },
+                Err(error) => warn!("Could not parse template \"{}\"\n\t{error}", path.display())
+            }
+        }
+
+        self.request_templates.sort_by(|a, b| a.name.cmp(&b.name));
+
+        trace!("Templates parsed!");
+    }
+
+    /// Write the template in the preferred collection file format, returns the template file path
+    pub fn save_template_to_file(&self, template: &RequestTemplate) -> anyhow::Result<PathBuf> {
+        let templates_directory = match self.get_templates_directory() {
+            None => return Err(anyhow::anyhow!("No app directory")),
+            Some(templates_directory) => templates_directory
+        };
+
+        let file_format = self.config.get_preferred_collection_file_format();
+        let path = templates_directory.join(format!("{}.{}", template.name, file_format));
+
+        if !ARGS.should_save {
+            warn!("Dry-run, not saving the template");
+            return Ok(path);
+        }
+
+        let template_stringed = match file_format {
+            CollectionFileFormat::Json => serde_json::to_string_pretty(template)?,
+            CollectionFileFormat::Yaml => serde_yaml::to_string(template)?,
+            CollectionFileFormat::Toml => toml::to_string_pretty(template)?
+        };
+
+        fs::create_dir_all(&templates_directory)?;
+        fs::write(&path, template_stringed)?;
+
+        info!("Template \"{}\" saved", template.name);
+
+        return Ok(path);
+    }
+}
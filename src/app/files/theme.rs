@@ -54,6 +54,20 @@ nest! {
                 #[serde(alias = "OPTIONS")]
                 pub options: Color
             },
+
+        #[serde(alias = "Accessibility", default)]
+        pub accessibility: #[derive(Default, Serialize, Deserialize)]
+            pub struct ThemeAccessibility {
+                /// Text markers next to the statuses otherwise only signaled by a color or an icon, e.g. "[off]" or "[pending]"
+                #[serde(default)]
+                pub text_markers: bool,
+                /// Bold text, the dimmed texts use the font color
+                #[serde(default)]
+                pub high_contrast: bool,
+                /// Borders and separators drawn with blank spaces, the titles stay
+                #[serde(default)]
+                pub reduced_borders: bool,
+            },
     }
 }
 
@@ -83,6 +97,7 @@ impl Default for Theme {
                 head: Color::Green,
                 options: Color::Magenta,
             },
+            accessibility: ThemeAccessibility::default(),
         }
     }
 }
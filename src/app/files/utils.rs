@@ -1,5 +1,11 @@
-use std::path::PathBuf;
+use std::{env, fs};
+use std::io::Read;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use anyhow::anyhow;
 use directories::UserDirs;
+use uuid::Uuid;
 
 pub fn expand_tilde(path_buf: PathBuf) -> PathBuf {
     if !path_buf.starts_with("~/") {
@@ -14,4 +20,103 @@ pub fn expand_tilde(path_buf: PathBuf) -> PathBuf {
         },
         None => panic!("No home directory found when trying to expand \"~\"")
     }
-}
\ No newline at end of file
+}
+
+/// Write the content to a temporary file, wait for $VISUAL or $EDITOR (vi by default) to close and return the edited content
+pub fn edit_in_external_editor(content: &str, file_extension: &str) -> anyhow::Result<String> {
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or(String::from("vi"));
+
+    // e.g. "code --wait"
+    let mut editor_parts = editor.split_whitespace();
+    let editor_program = editor_parts.next().unwrap_or("vi");
+
+    let temp_file_path = env::temp_dir().join(format!("atac-{}.{file_extension}", Uuid::new_v4()));
+    fs::write(&temp_file_path, content)?;
+
+    let status = Command::new(editor_program)
+        .args(editor_parts)
+        .arg(&temp_file_path)
+        .status();
+
+    let edited_content = fs::read_to_string(&temp_file_path);
+    let _ = fs::remove_file(&temp_file_path);
+
+    let status = status?;
+
+    if !status.success() {
+        return Err(anyhow!("\"{editor}\" exited with {status}"));
+    }
+
+    return Ok(edited_content?);
+}
+
+/// Files and directories whose path starts with the given one, directories end with a slash
+pub fn get_path_completions(path: &str) -> Vec<String> {
+    let (directory_path, file_name_start) = match path.rfind('/') {
+        None => ("", path),
+        Some(index) => (&path[..=index], &path[index + 1..])
+    };
+
+    let directory = match directory_path.is_empty() {
+        true => PathBuf::from("."),
+        false => expand_tilde(PathBuf::from(directory_path))
+    };
+
+    let entries = match fs::read_dir(directory) {
+        Ok(entries) => entries,
+        Err(_) => return vec![]
+    };
+
+    let mut completions: Vec<String> = entries
+        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| {
+            let file_name = entry.file_name().to_string_lossy().to_string();
+
+            // Hidden files are only completed once their dot has been typed
+            if !file_name.starts_with(file_name_start) || (file_name.starts_with('.') && !file_name_start.starts_with('.')) {
+                return None;
+            }
+
+            return match entry.path().is_dir() {
+                true => Some(format!("{directory_path}{file_name}/")),
+                false => Some(format!("{directory_path}{file_name}"))
+            };
+        })
+        .collect();
+
+    completions.sort();
+
+    return completions;
+}
+
+/// Size of the file and its first bytes
+pub fn read_file_start(path: &Path, length: usize) -> std::io::Result<(u64, Vec<u8>)> {
+    let file = fs::File::open(path)?;
+    let file_length = file.metadata()?.len();
+
+    let mut buffer = vec![];
+    file.take(length as u64).read_to_end(&mut buffer)?;
+
+    return Ok((file_length, buffer));
+}
+
+/// e.g. "512 B", "1.5 KB", "2.0 MB"
+pub fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit_index = 0;
+
+    while size >= 1024.0 && unit_index < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit_index += 1;
+    }
+
+    return format!("{size:.1} {}", UNITS[unit_index]);
+}
@@ -0,0 +1,187 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io::{stderr, stdin, IsTerminal, Write};
+use std::path::{Path, PathBuf};
+
+use tracing::{error, info, trace, warn};
+
+use crate::app::app::App;
+use crate::app::business_logic::request::client_pool::clear_clients;
+use crate::app::business_logic::request::concurrency::set_max_concurrent_requests;
+use crate::app::files::collection::{get_collection_directory_format, get_collection_file_format};
+use crate::app::files::utils::expand_tilde;
+use crate::cli::args::ARGS;
+
+/// Project directories the user trusted, one canonical path per line
+const TRUSTED_PROJECTS_FILE_NAME: &str = ".trusted_projects";
+
+impl App<'_> {
+    /// Directory of the selected workspace, the app directory when no workspace is selected.
+    /// Relative workspace paths are relative to the app directory
+    pub fn get_workspace_directory(&self) -> Option<PathBuf> {
+        let app_directory = ARGS.directory.as_ref()?;
+
+        let workspace_name = match &self.selected_workspace {
+            None => return Some(app_directory.clone()),
+            Some(workspace_name) => workspace_name
+        };
+
+        let workspace_path = expand_tilde(self.workspaces.get(workspace_name)?.clone());
+
+        return Some(app_directory.join(workspace_path));
+    }
+
+    /// Select the closest ".atac" directory as a workspace named after its project, like direnv does with ".envrc" files.
+    /// The project has to be trusted first. The main directory and the config workspaces remain reachable from the TUI
+    pub fn detect_project_workspace(&mut self) {
+        let project_directory = match find_project_directory() {
+            None => return,
+            Some(project_directory) => project_directory
+        };
+
+        // The app directory itself may be called ".atac"
+        if let Some(app_directory) = ARGS.directory.as_ref() {
+            if app_directory.canonicalize().ok() == project_directory.canonicalize().ok() {
+                return;
+            }
+        }
+
+        // A cloned repository may ship collections running commands, e.g. response processors or plugins
+        if !is_project_directory_trusted(&project_directory) {
+            warn!("Project directory \"{}\" is not trusted, it is not loaded", project_directory.display());
+            return;
+        }
+
+        let project_name = project_directory
+            .parent()
+            .and_then(|parent| parent.file_name())
+            .map(|file_name| file_name.to_string_lossy().to_string())
+            .unwrap_or(String::from("project"));
+
+        info!("Project directory \"{}\" detected", project_directory.display());
+
+        self.workspaces.shift_insert(0, project_name.clone(), project_directory);
+        self.selected_workspace = Some(project_name);
+    }
+
+    /// Load the collections and environments of the selected workspace, along with its config overrides
+    pub fn parse_workspace_directory(&mut self) {
+        let directory = self.get_workspace_directory().unwrap();
+
+        let paths = match directory.read_dir() {
+            Ok(paths) => paths,
+            Err(e) => {
+                error!("Directory \"{}\" not found: {e}", directory.display());
+                return;
+            }
+        };
+
+        for path in paths {
+            let path = path.unwrap().path();
+
+            if path.is_dir() {
+                // Collection using the file per request layout
+                if let Some(file_format) = get_collection_directory_format(&path) {
+                    self.set_collection_from_directory(path, file_format);
+                }
+
+                continue;
+            }
+
+            let file_name = path.file_name().unwrap().to_str().unwrap();
+
+            trace!("Checking file \"{}\"", path.display());
+
+            // Checked first, since TOML collection files share its extension
+            if file_name == "atac.toml" {
+                match self.selected_workspace.is_some() {
+                    true => self.parse_workspace_config_file(path),
+                    false => trace!("Main config file is already parsed")
+                }
+            }
+            else if let Some(file_format) = get_collection_file_format(&path) {
+                self.set_collections_from_file(path, file_format);
+            }
+            else if file_name.starts_with(".env.") {
+                self.add_environment_from_file(path)
+            }
+            else if file_name.starts_with(".cookies") {
+                trace!("Cookie jar is loaded with its environment")
+            }
+            else if file_name.starts_with(".journal") {
+                trace!("Journal is recovered when the TUI starts")
+            }
+            else if file_name == TRUSTED_PROJECTS_FILE_NAME {
+                trace!("Trusted projects are read when detecting the project directory")
+            }
+            else if file_name == "atac.log" {
+                trace!("Log file is not parsable")
+            }
+        }
+
+        // Once the workspace config overrides are known
+        set_max_concurrent_requests(self.config.get_max_concurrent_requests());
+        clear_clients();
+    }
+}
+
+/// Closest ".atac" directory, from the current directory up to the filesystem root
+fn find_project_directory() -> Option<PathBuf> {
+    let current_directory = env::current_dir().ok()?;
+
+    return current_directory
+        .ancestors()
+        .map(|directory| directory.join(".atac"))
+        .find(|project_directory| project_directory.is_dir());
+}
+
+/// Whether the project directory was trusted, the user is asked otherwise when a terminal is attached.
+/// The answer is remembered in the app directory
+fn is_project_directory_trusted(project_directory: &Path) -> bool {
+    let project_directory = match project_directory.canonicalize() {
+        Ok(project_directory) => project_directory,
+        Err(_) => return false
+    };
+
+    let trusted_projects_path = ARGS.directory.as_ref().map(|app_directory| app_directory.join(TRUSTED_PROJECTS_FILE_NAME));
+
+    if let Some(trusted_projects_path) = &trusted_projects_path {
+        let trusted_projects = fs::read_to_string(trusted_projects_path).unwrap_or_default();
+
+        if trusted_projects.lines().any(|line| Path::new(line) == project_directory) {
+            return true;
+        }
+    }
+
+    if !stdin().is_terminal() || !stderr().is_terminal() {
+        eprintln!("Project directory \"{}\" is not trusted, run atac from a terminal to trust it or use --no-project", project_directory.display());
+        return false;
+    }
+
+    eprint!("Trust the project directory \"{}\"? Its collections may run commands [y/N] ", project_directory.display());
+    let _ = stderr().flush();
+
+    let mut answer = String::new();
+
+    if stdin().read_line(&mut answer).is_err() || !matches!(answer.trim(), "y" | "Y" | "yes") {
+        return false;
+    }
+
+    info!("Project directory \"{}\" trusted", project_directory.display());
+
+    if let Some(trusted_projects_path) = &trusted_projects_path {
+        let trusted_project = format!("{}\n", project_directory.display());
+
+        let result = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(trusted_projects_path)
+            .and_then(|mut file| file.write_all(trusted_project.as_bytes()));
+
+        if let Err(error) = result {
+            warn!("Could not remember the trusted project directory: {error}");
+        }
+    }
+
+    return true;
+}
@@ -0,0 +1,61 @@
+use std::fmt::Debug;
+
+use chrono::Local;
+use lazy_static::lazy_static;
+use parking_lot::RwLock;
+use tracing::{Event, Subscriber};
+use tracing::field::{Field, Visit};
+use tracing_subscriber::layer::Context;
+use tracing_subscriber::Layer;
+
+use crate::models::app_log::{AppLog, LogRecord};
+
+lazy_static! {
+    pub static ref APP_LOG: RwLock<AppLog> = RwLock::new(AppLog::default());
+}
+
+/// Copies the events to the log panel, the log file is written by the fmt layer
+pub struct LogPanelLayer;
+
+impl<S: Subscriber> Layer<S> for LogPanelLayer {
+    fn on_event(&self, event: &Event<'_>, _: Context<'_, S>) {
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+
+        let mut message = visitor.message;
+
+        // Structured fields follow the message, e.g. "Request sent status=200"
+        for field in visitor.fields {
+            message.push(' ');
+            message.push_str(&field);
+        }
+
+        APP_LOG.write().push(LogRecord {
+            time: Local::now().format("%H:%M:%S").to_string(),
+            level: *event.metadata().level(),
+            message,
+        });
+    }
+}
+
+#[derive(Default)]
+struct MessageVisitor {
+    message: String,
+    fields: Vec<String>,
+}
+
+impl Visit for MessageVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        match field.name() {
+            "message" => self.message = value.to_string(),
+            name => self.fields.push(format!("{name}={value}"))
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        match field.name() {
+            "message" => self.message = format!("{value:?}"),
+            name => self.fields.push(format!("{name}={value:?}"))
+        }
+    }
+}
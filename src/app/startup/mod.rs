@@ -1,2 +1,3 @@
 pub mod startup;
 mod prepare_terminal;
+pub mod log_layer;
@@ -1,13 +1,16 @@
 use std::fs::{File, OpenOptions};
+use std::path::Path;
 use clap_verbosity_flag::LevelFilter;
-use tracing::trace;
+use tracing::Level;
 use tracing_log::AsTrace;
+use tracing_subscriber::filter::Targets;
+use tracing_subscriber::prelude::*;
 
 use crate::app::app::App;
+use crate::app::startup::log_layer::LogPanelLayer;
 use crate::cli::args::{ARGS, Command};
 use crate::panic_error;
 use crate::app::startup::startup::AppMode::{CLI, TUI};
-use crate::models::collection::CollectionFileFormat;
 
 pub enum AppMode<'a> {
     TUI(&'a mut App<'a>),
@@ -20,34 +23,59 @@ impl<'a> App<'a> {
         // Logging is initialized before anything else
         match ARGS.command.is_some() {
             // CLI
-            true => tracing_subscriber::fmt()
-                .pretty()
-                .with_max_level(ARGS.verbosity.log_level_filter().as_trace())
-                .with_file(false)
-                .with_line_number(false)
-                .with_ansi(ARGS.ansi_log)
-                .init(),
+            true => {
+                let subscriber = tracing_subscriber::fmt()
+                    .pretty()
+                    .with_max_level(ARGS.verbosity.log_level_filter().as_trace())
+                    .with_file(false)
+                    .with_line_number(false)
+                    .with_ansi(ARGS.ansi_log);
+
+                match &ARGS.log_file {
+                    None => subscriber.init(),
+                    // Keeps the logs out of the command output
+                    Some(log_file_path) => subscriber.with_writer(self.create_log_file(log_file_path)).init()
+                }
+            },
             // TUI
             false => {
                 let verbosity = match ARGS.verbosity.log_level_filter() {
                     LevelFilter::Error => LevelFilter::Debug, // Ensure that at least the debug level is always active
                     level => level
                 };
-                
+
+                let log_file_path = match &ARGS.log_file {
+                    None => ARGS.directory.as_ref().unwrap().join("atac.log"),
+                    Some(log_file_path) => log_file_path.clone()
+                };
+
                 // Using a separate file allows to redirect the output and avoid printing to screen
-                let log_file = self.create_log_file();
-                tracing_subscriber::fmt()
-                    .with_max_level(verbosity.as_trace())
+                let log_file = self.create_log_file(&log_file_path);
+
+                let file_layer = tracing_subscriber::fmt::layer()
                     .with_writer(log_file)
                     .with_file(false)
                     .with_line_number(false)
                     .with_ansi(ARGS.ansi_log)
+                    .with_filter(verbosity.as_trace());
+
+                // The other crates, e.g. the HTTP client, only bring their warnings to the log panel
+                let log_panel_filter = Targets::new()
+                    .with_target("atac", Level::DEBUG)
+                    .with_default(Level::WARN);
+
+                tracing_subscriber::registry()
+                    .with(file_layer)
+                    .with(LogPanelLayer.with_filter(log_panel_filter))
                     .init()
             }
         };
 
         if ARGS.should_parse_directory {
             self.parse_app_directory();
+            self.load_cookie_jar();
+            self.parse_templates_directory();
+            self.parse_plugins_directory();
         }
 
         if let Some(command) = &ARGS.command {
@@ -56,52 +84,54 @@ impl<'a> App<'a> {
         else {
             self.parse_key_bindings_file();
             self.parse_theme_file();
+            self.apply_locale();
+
+            if ARGS.should_parse_directory {
+                self.load_scratchpad();
+                self.load_key_macro();
+                self.tui_check_journal();
+            }
             
             TUI(self)
         }
     }
 
     fn parse_app_directory(&mut self) {
-        let paths = match ARGS.directory.as_ref().unwrap().read_dir() {
-            Ok(paths) => paths,
-            Err(e) => panic_error(format!("Directory \"{}\" not found\n\t{e}", ARGS.directory.as_ref().unwrap().display()))
-        };
+        let app_directory = ARGS.directory.as_ref().unwrap();
 
-        for path in paths {
-            let path = path.unwrap().path();
+        if !app_directory.is_dir() {
+            panic_error(format!("Directory \"{}\" not found", app_directory.display()));
+        }
 
-            if path.is_dir() {
-                continue;
-            }
+        // The main config declares the workspaces, it has to be parsed first
+        let config_path = app_directory.join("atac.toml");
 
-            let file_name = path.file_name().unwrap().to_str().unwrap();
+        if config_path.is_file() {
+            self.parse_config_file(config_path);
+        }
 
-            trace!("Checking file \"{}\"", path.display());
+        self.workspaces = self.config.get_workspaces();
 
-            if file_name.ends_with(".json") {
-                self.set_collections_from_file(path, CollectionFileFormat::Json);
-            }
-            else if file_name.ends_with(".yaml") {
-                self.set_collections_from_file(path, CollectionFileFormat::Yaml);
-            }
-            else if file_name.starts_with(".env.") {
-                self.add_environment_from_file(path)
-            }
-            else if file_name == "atac.toml" {
-                self.parse_config_file(path);
-            }
-            else if file_name == "atac.log" {
-                trace!("Log file is not parsable")
+        // An explicitly chosen workspace takes precedence over the project directory
+        if ARGS.workspace.is_none() && ARGS.detect_project_directory && !self.config.is_project_directory_detection_disabled() {
+            self.detect_project_workspace();
+        }
+
+        if let Some(workspace_name) = &ARGS.workspace {
+            if let Err(error) = self.find_workspace(workspace_name) {
+                panic_error(error.to_string());
             }
+
+            self.selected_workspace = Some(workspace_name.clone());
         }
-    }
 
-    fn create_log_file(&mut self) -> File {
-        let path = ARGS.directory.as_ref().unwrap().join("atac.log");
+        self.parse_workspace_directory();
+    }
 
+    fn create_log_file(&mut self, path: &Path) -> File {
         let log_file = match OpenOptions::new().write(true).create(true).truncate(true).open(path) {
             Ok(log_file) => log_file,
-            Err(e) => panic_error(format!("Could not open log file\n\t{e}"))
+            Err(e) => panic_error(format!("Could not open log file \"{}\"\n\t{e}", path.display()))
         };
 
         return log_file;
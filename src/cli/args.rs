@@ -8,13 +8,21 @@ use directories::ProjectDirs;
 use lazy_static::lazy_static;
 
 use crate::cli::commands::collection_commands::collection_commands::CollectionCommand;
-use crate::cli::commands::completions::CompletionsCommand;
+use crate::cli::commands::completions::{CompleteNamesCommand, CompletionsCommand};
+use crate::cli::commands::docs::DocsCommand;
 use crate::cli::commands::import::ImportCommand;
 use crate::cli::commands::request_commands::request_commands::RequestCommand;
+use crate::cli::commands::send::SendRequestCommand;
 use crate::app::files::utils::expand_tilde;
 use crate::cli::commands::env::EnvCommand;
+use crate::cli::commands::git::GitCommand;
+use crate::cli::commands::plugins::PluginsCommand;
 use crate::cli::commands::man::ManCommand;
+use crate::cli::commands::list::ListCommand;
+use crate::cli::commands::listen::ListenCommand;
+use crate::cli::commands::monitor::MonitorCommand;
 use crate::cli::commands::try_command::TryCommand;
+use crate::cli::utils::arguments_validators::variable_override_validator;
 use crate::panic_error;
 
 #[derive(Parser, Debug)]
@@ -31,6 +39,18 @@ pub struct Args {
     #[arg(short, long, value_hint = clap::ValueHint::DirPath)]
     pub directory: Option<PathBuf>,
 
+    /// Workspace declared in the atac.toml config file to use instead of the main directory collections and environments
+    #[arg(long, global = true, value_name = "WORKSPACE_NAME")]
+    pub workspace: Option<String>,
+
+    /// Do not load the ".atac" project directory found in the current directory or its parents
+    #[arg(long, global = true, default_value_t = false)]
+    pub no_project: bool,
+
+    /// Disable every action modifying the collections in the TUI, e.g. when browsing a team repository
+    #[arg(long, default_value_t = false)]
+    pub read_only: bool,
+
     #[command(subcommand)]
     pub command: Option<Command>,
 
@@ -38,9 +58,22 @@ pub struct Args {
     #[arg(long, global = true, default_value_t = false, display_order = 99)]
     pub dry_run: bool,
 
+    /// Value of a {{KEY}} variable without editing any file, e.g. --var base_url=https://staging.example.com.
+    /// Prevails over the dataset rows, which prevail over the --env environment file, the last one prevails when a key is repeated
+    #[arg(long = "var", global = true, value_name = "KEY=VALUE", value_parser = variable_override_validator)]
+    pub variables: Vec<(String, String)>,
+
+    /// Answer the sends with the request snapshots instead of the network
+    #[arg(long, global = true, default_value_t = false)]
+    pub offline: bool,
+
     /// Avoid using ANSI format for log file/output
     #[arg(long, global = true, default_value_t = false)]
     pub no_ansi_log: bool,
+
+    /// Write the logs to this file, instead of "atac.log" in the main directory for the TUI and of the standard error for the CLI
+    #[arg(long, global = true, value_hint = clap::ValueHint::FilePath)]
+    pub log_file: Option<PathBuf>,
     
     #[command(flatten)]
     pub verbose: Verbosity
@@ -54,24 +87,41 @@ atac
       - new
       - delete
       - rename
+      - layout
+      - format
+      - encrypt
+      - decrypt
+      - description
       - send (all requests from the collection)
+  - list (collections and requests, --json)
   - request
       - info
       - new
+      - save-as-template
       - delete
       - rename
+      - description
       - url
       - method
       - params
       - auth
       - headers
+      - cookie
       - body
       - scripts
       - send
       - settings
+      - monitor
+      - schema
+      - snapshot
+      - load-test
+      - fuzz
+  - send (a given or picked request)
   - try
   - env
       - info
+      - encrypt
+      - decrypt
       - key
           - get
           - add
@@ -81,6 +131,15 @@ atac
   - import
       - postman
       - curl
+      - cookies
+  - listen
+  - monitor
+  - docs
+  - git
+      - status
+      - pull
+      - commit
+      - push
  - completions
       - bash, powershell, fish, zsh
  - man
@@ -94,18 +153,43 @@ pub enum Command {
     /// Request commands
     Request(RequestCommand),
 
+    /// List the collections and their requests, as JSON with --json
+    List(ListCommand),
+
+    /// Send a request, picked among all the requests when none is given
+    Send(SendRequestCommand),
+
     /// One-shot request sender
     Try(TryCommand),
     
     /// Environment commands
     Env(EnvCommand),
 
-    /// Import a collection or a request from other file formats (Postman v2.1.0, cURL)
+    /// Import a collection or a request from other file formats (Postman v2.1.0, cURL, plugins)
     Import(ImportCommand),
 
+    /// Bind a local port and print every incoming HTTP request (webhook listener / request bin)
+    Listen(ListenCommand),
+
+    /// Run the monitored requests on their schedule and print each check
+    Monitor(MonitorCommand),
+
+    /// Generate the Markdown or HTML documentation of a collection
+    Docs(DocsCommand),
+
+    /// Git helpers run in the workspace directory, to share collections through a repository
+    Git(GitCommand),
+
+    /// List the plugins or export a collection with a plugin
+    Plugins(PluginsCommand),
+
     /// Create a completion file
     Completions(CompletionsCommand),
 
+    /// Print the names completing the arguments, used by the completion files
+    #[command(hide = true)]
+    CompleteNames(CompleteNamesCommand),
+
     /// Generate ATAC man page
     Man(ManCommand),
 }
@@ -130,11 +214,17 @@ lazy_static! {
 
         GlobalArgs {
             directory,
+            workspace: args.workspace,
+            detect_project_directory: !args.no_project,
             command: args.command,
             should_save: !args.dry_run,
+            read_only: args.read_only,
+            offline: args.offline,
+            variables: args.variables,
             should_parse_directory,
             verbosity: args.verbose,
-            ansi_log: !args.no_ansi_log
+            ansi_log: !args.no_ansi_log,
+            log_file: args.log_file.map(expand_tilde)
         }
     };
 }
@@ -155,7 +245,7 @@ fn choose_app_directory(path_buf: Option<PathBuf>) -> PathBuf {
                     let config_dir = project_dir.config_dir();
                     
                     if !config_dir.exists() {
-                        fs::create_dir_all(config_dir).expect(&format!("Could not recursively create folder \"{}\"", config_dir.display()));
+                        fs::create_dir_all(config_dir).unwrap_or_else(|_| panic!("Could not recursively create folder \"{}\"", config_dir.display()));
                     }
                     
                     config_dir.to_path_buf()
@@ -169,9 +259,16 @@ fn choose_app_directory(path_buf: Option<PathBuf>) -> PathBuf {
 #[derive(Debug)]
 pub struct GlobalArgs {
     pub directory: Option<PathBuf>,
+    pub workspace: Option<String>,
+    pub detect_project_directory: bool,
     pub command: Option<Command>,
     pub should_save: bool,
+    pub read_only: bool,
+    pub offline: bool,
+    /// Given with --var, in the command line order
+    pub variables: Vec<(String, String)>,
     pub should_parse_directory: bool,
     pub verbosity: Verbosity,
-    pub ansi_log: bool
+    pub ansi_log: bool,
+    pub log_file: Option<PathBuf>
 }
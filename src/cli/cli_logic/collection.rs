@@ -1,5 +1,15 @@
+use clap::ValueEnum;
 use crate::app::app::App;
-use crate::models::collection::Collection;
+use crate::app::files::utils::edit_in_external_editor;
+use crate::cli::commands::collection_commands::collection_commands::{HookCommand, LoginRequestCommand};
+use crate::cli::commands::description::DescriptionCommand;
+use crate::cli::commands::tag::TagCommand;
+use crate::cli::commands::think_time::ThinkTimeCommand;
+use crate::models::collection::{Collection, CollectionFileFormat, CollectionLayout};
+use crate::models::collection_hooks::HookKind;
+use crate::models::dependency::ExecutionPlan;
+use crate::models::tag::{add_tags, remove_tags};
+use crate::models::think_time::ThinkTime;
 
 impl App<'_> {
     pub fn list_collections(&mut self, with_request_names: bool) -> anyhow::Result<()> {
@@ -38,6 +48,165 @@ impl App<'_> {
 
         Ok(())
     }
+
+    pub fn cli_collection_layout(&mut self, collection_name: &str, layout: Option<CollectionLayout>) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        match layout {
+            None => {
+                let collection = &self.collections[collection_index];
+                println!("{} ({})", collection.layout, collection.path.display());
+
+                Ok(())
+            },
+            Some(layout) => self.modify_collection_layout(collection_index, layout)
+        }
+    }
+
+    pub fn cli_collection_file_format(&mut self, collection_name: &str, file_format: Option<CollectionFileFormat>) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        match file_format {
+            None => {
+                let collection = &self.collections[collection_index];
+                println!("{} ({})", collection.file_format, collection.path.display());
+
+                Ok(())
+            },
+            Some(file_format) => self.modify_collection_file_format(collection_index, file_format)
+        }
+    }
+
+    pub fn cli_collection_encryption(&mut self, collection_name: &str, encrypted: bool) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        self.modify_collection_encryption(collection_index, encrypted)
+    }
+
+    pub fn cli_collection_description(&mut self, collection_name: &str, description_command: &DescriptionCommand) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        match description_command {
+            DescriptionCommand::Get => {
+                if let Some(description) = &self.collections[collection_index].description {
+                    println!("{description}");
+                }
+
+                Ok(())
+            },
+            DescriptionCommand::Set { description } => self.modify_collection_description(collection_index, Some(description.clone())),
+            DescriptionCommand::Edit => {
+                let description = self.collections[collection_index].description.clone().unwrap_or_default();
+                let edited_description = edit_in_external_editor(&description, "md")?;
+
+                self.modify_collection_description(collection_index, Some(edited_description))
+            },
+            DescriptionCommand::Remove => self.modify_collection_description(collection_index, None)
+        }
+    }
+
+    pub fn cli_collection_tags(&mut self, collection_name: &str, tag_command: &TagCommand) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+        let tags = &self.collections[collection_index].tags;
+
+        let new_tags = match tag_command {
+            TagCommand::List => {
+                for tag in tags {
+                    println!("{tag}");
+                }
+
+                return Ok(());
+            },
+            TagCommand::Add { tags: new_tags } => add_tags(tags, new_tags),
+            TagCommand::Remove { tags: removed_tags } => remove_tags(tags, removed_tags),
+            TagCommand::Clear => vec![]
+        };
+
+        self.modify_collection_tags(collection_index, new_tags)
+    }
+
+    pub fn cli_collection_think_time(&mut self, collection_name: &str, think_time_command: &ThinkTimeCommand) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        match think_time_command {
+            ThinkTimeCommand::Get => {
+                if let Some(think_time) = &self.collections[collection_index].think_time {
+                    println!("{think_time}");
+                }
+
+                Ok(())
+            },
+            ThinkTimeCommand::Set { min_ms, max_ms } => {
+                let think_time = ThinkTime::new(*min_ms, *max_ms)?;
+                self.modify_collection_think_time(collection_index, Some(think_time))
+            },
+            ThinkTimeCommand::Remove => self.modify_collection_think_time(collection_index, None)
+        }
+    }
+
+    pub fn cli_print_execution_graph(&mut self, collection_name: &str) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+        let requests = &self.collections[collection_index].requests;
+
+        let execution_plan = ExecutionPlan::new(requests)?;
+
+        for (stage_index, stage) in execution_plan.stages.iter().enumerate() {
+            println!("stage {}:", stage_index + 1);
+
+            for request_index in stage {
+                let request = requests[*request_index].read();
+
+                let mut line = format!("\t{}", request.name);
+
+                if !request.depends_on.is_empty() {
+                    line += &format!(" <- {}", request.depends_on.join(", "));
+                }
+
+                if request.skipped {
+                    line += " (skipped)";
+                }
+
+                println!("{line}");
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_collection_login_request(&mut self, collection_name: &str, login_request_command: &LoginRequestCommand) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        match login_request_command {
+            LoginRequestCommand::Get => {
+                if let Some(login_request) = &self.collections[collection_index].login_request {
+                    println!("{login_request}");
+                }
+
+                Ok(())
+            },
+            LoginRequestCommand::Set { request_name } => {
+                self.find_collection_slash_request(collection_name, request_name)?;
+                self.modify_collection_login_request(collection_index, Some(request_name.clone()))
+            },
+            LoginRequestCommand::Remove => self.modify_collection_login_request(collection_index, None)
+        }
+    }
+
+    pub fn cli_collection_hooks(&mut self, collection_name: &str, hook_command: &HookCommand) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(collection_name)?;
+
+        match hook_command {
+            HookCommand::Get => {
+                print_collection_hooks(&self.collections[collection_index]);
+                Ok(())
+            },
+            HookCommand::Set { hook_kind, request_name } => {
+                self.find_collection_slash_request(collection_name, request_name)?;
+                self.modify_collection_hook(collection_index, *hook_kind, Some(request_name.clone()))
+            },
+            HookCommand::Remove { hook_kind } => self.modify_collection_hook(collection_index, *hook_kind, None)
+        }
+    }
 }
 
 fn print_collection(collection: &Collection, shortened: bool, with_request_names: bool) {
@@ -46,6 +215,24 @@ fn print_collection(collection: &Collection, shortened: bool, with_request_names
     }
     else {
         println!("collection: {}", collection.name);
+
+        if let Some(description) = &collection.description {
+            println!("description:\n\t{}", description.replace('\n', "\n\t"));
+        }
+
+        if let Some(login_request) = &collection.login_request {
+            println!("login request: {login_request}");
+        }
+
+        print_collection_hooks(collection);
+
+        if let Some(think_time) = &collection.think_time {
+            println!("think time: {think_time}");
+        }
+
+        if !collection.tags.is_empty() {
+            println!("tags: {}", collection.tags.join(", "));
+        }
     }
 
     if with_request_names {
@@ -55,4 +242,12 @@ fn print_collection(collection: &Collection, shortened: bool, with_request_names
             println!("\t{}", local_request.name);
         }
     }
+}
+
+fn print_collection_hooks(collection: &Collection) {
+    for hook_kind in HookKind::value_variants() {
+        if let Some(hook_request) = collection.hooks.get(*hook_kind) {
+            println!("{hook_kind} hook: {hook_request}");
+        }
+    }
 }
\ No newline at end of file
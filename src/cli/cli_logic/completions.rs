@@ -1,13 +1,78 @@
-use std::env;
+use std::{env, fs};
 use std::str::FromStr;
 
 use anyhow::anyhow;
 use clap::CommandFactory;
-use clap_complete::{generate_to, Shell};
+use clap_complete::{generate, Generator, Shell};
 
+use crate::app::app::App;
 use crate::cli::args::{Args, ARGS};
-use crate::cli::commands::completions::CompletionsCommand;
+use crate::cli::commands::completions::{CompletedNames, CompletionsCommand};
 
+/// Completes the collection, request and environment names with "atac complete-names", the generated "_atac" function is renamed "_atac_clap"
+const BASH_NAME_COMPLETIONS: &str = r#"
+# Names of the collections, requests and environments of the app directory
+_atac() {
+    local cur="${COMP_WORDS[COMP_CWORD]}"
+    local prev="${COMP_WORDS[COMP_CWORD-1]}"
+    local names=""
+
+    if [[ "${prev}" == "--env" ]]; then
+        names="env"
+    elif [[ "${cur}" != -* ]]; then
+        case "${COMP_CWORD}:${COMP_WORDS[1]}" in
+            2:send) names="request" ;;
+            3:request|3:collection|3:env) names="${COMP_WORDS[1]}" ;;
+        esac
+    fi
+
+    if [[ -n "${names}" ]]; then
+        local IFS=$'\n'
+        COMPREPLY=($(compgen -W "$(atac complete-names "${names}" 2>/dev/null)" -- "${cur}"))
+        return 0
+    fi
+
+    _atac_clap "$@"
+}
+
+"#;
+
+const ZSH_NAME_COMPLETIONS: &str = r#"
+# Names of the collections, requests and environments of the app directory
+_atac() {
+    local names=""
+
+    if [[ "${words[CURRENT-1]}" == "--env" ]]; then
+        names="env"
+    elif [[ "${words[CURRENT]}" != -* ]]; then
+        case "$((CURRENT - 1)):${words[2]}" in
+            2:send) names="request" ;;
+            3:request|3:collection|3:env) names="${words[2]}" ;;
+        esac
+    fi
+
+    if [[ -n "${names}" ]]; then
+        local -a completed_names
+        completed_names=("${(@f)$(atac complete-names "${names}" 2>/dev/null)}")
+        compadd -a completed_names
+        return
+    fi
+
+    _atac_clap "$@"
+}
+
+"#;
+
+const FISH_NAME_COMPLETIONS: &str = r#"
+# Names of the collections, requests and environments of the app directory
+complete -c atac -n "__fish_seen_subcommand_from send; and test (count (commandline -opc)) -eq 2" -f -a "(atac complete-names request 2>/dev/null)"
+complete -c atac -n "__fish_seen_subcommand_from request; and test (count (commandline -opc)) -eq 3" -f -a "(atac complete-names request 2>/dev/null)"
+complete -c atac -n "__fish_seen_subcommand_from collection; and test (count (commandline -opc)) -eq 3" -f -a "(atac complete-names collection 2>/dev/null)"
+complete -c atac -n "__fish_seen_subcommand_from env; and test (count (commandline -opc)) -eq 3" -f -a "(atac complete-names env 2>/dev/null)"
+complete -c atac -l env -f -r -a "(atac complete-names env 2>/dev/null)"
+"#;
+
+/// Bash, Zsh and Fish also complete the collection, request and environment names, read from the app directory
 pub fn generate_completions(completions_command: &CompletionsCommand) -> anyhow::Result<()> {
     let shell: Shell = match Shell::from_str(&completions_command.shell) {
         Ok(shell) => shell,
@@ -20,11 +85,60 @@ pub fn generate_completions(completions_command: &CompletionsCommand) -> anyhow:
         None => &env::current_dir()?,
         Some(path) => path
     };
-    
+
     let mut command = Args::command();
-    generate_to(shell, &mut command, "atac", path)?;
+    let mut script: Vec<u8> = vec![];
+
+    generate(shell, &mut command, "atac", &mut script);
+
+    let script = add_name_completions(shell, String::from_utf8(script)?);
+    let file_path = path.join(shell.file_name("atac"));
+
+    fs::write(&file_path, script)?;
 
     println!("Completions file generated into \"{}\"", path.display());
 
     Ok(())
-}
\ No newline at end of file
+}
+
+fn add_name_completions(shell: Shell, script: String) -> String {
+    let (name_completions, registration) = match shell {
+        Shell::Bash => (BASH_NAME_COMPLETIONS, "\nif [[ \"${BASH_VERSINFO[0]}\""),
+        Shell::Zsh => (ZSH_NAME_COMPLETIONS, "\nif [ \"$funcstack[1]\" = \"_atac\" ]"),
+        Shell::Fish => return script + FISH_NAME_COMPLETIONS,
+        _ => return script
+    };
+
+    // The wrapper takes the name of the generated function, which the shell registration calls
+    let script = match script.strip_prefix("_atac() {") {
+        Some(function_body) => format!("_atac_clap() {{{function_body}"),
+        None => script.replacen("\n_atac() {", "\n_atac_clap() {", 1)
+    };
+
+    // The registration block ends the script, the generated functions hold similar tests
+    return match script.rfind(registration).map(|registration_start| registration_start + 1) {
+        None => script,
+        Some(registration_start) => format!("{}{}{}", &script[..registration_start], name_completions.trim_start(), &script[registration_start..])
+    };
+}
+
+impl App<'_> {
+    /// One name per line, the requests as collection/request
+    pub fn cli_print_completed_names(&self, completed_names: CompletedNames) -> anyhow::Result<()> {
+        match completed_names {
+            CompletedNames::Collection => for collection in &self.collections {
+                println!("{}", collection.name);
+            },
+            CompletedNames::Request => for collection in &self.collections {
+                for request in &collection.requests {
+                    println!("{}/{}", collection.name, request.read().name);
+                }
+            },
+            CompletedNames::Env => for environment in &self.environments {
+                println!("{}", environment.read().name);
+            }
+        }
+
+        Ok(())
+    }
+}
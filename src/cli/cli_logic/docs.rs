@@ -0,0 +1,29 @@
+use std::fs;
+
+use crate::app::app::App;
+use crate::app::business_logic::docs::{collection_to_markdown, markdown_to_html_page};
+use crate::cli::commands::docs::{DocsCommand, DocsFormat};
+
+impl App<'_> {
+    pub fn cli_docs(&mut self, docs_command: &DocsCommand) -> anyhow::Result<()> {
+        let collection_index = self.find_collection(&docs_command.collection_name)?;
+        let collection = &self.collections[collection_index];
+
+        let markdown = collection_to_markdown(collection);
+
+        let docs = match docs_command.format {
+            DocsFormat::Markdown => markdown,
+            DocsFormat::Html => markdown_to_html_page(&collection.name, &markdown)
+        };
+
+        match &docs_command.output {
+            None => print!("{docs}"),
+            Some(output) => {
+                fs::write(output, docs)?;
+                println!("Documentation written to \"{}\"", output.display());
+            }
+        }
+
+        Ok(())
+    }
+}
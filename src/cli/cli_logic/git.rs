@@ -0,0 +1,19 @@
+use crate::app::app::App;
+use crate::app::business_logic::git::{git_commit, git_status, run_git_interactive};
+use crate::cli::commands::git::{GitCommand, GitSubcommand};
+
+impl App<'_> {
+    pub fn cli_git(&mut self, git_command: &GitCommand) -> anyhow::Result<()> {
+        let workspace_directory = self.get_workspace_directory().unwrap();
+
+        match &git_command.git_subcommand {
+            GitSubcommand::Status => {
+                println!("{}", git_status(&workspace_directory)?);
+                Ok(())
+            },
+            GitSubcommand::Pull => run_git_interactive(&workspace_directory, &["pull"]),
+            GitSubcommand::Commit { message } => git_commit(&workspace_directory, message.as_deref()),
+            GitSubcommand::Push => run_git_interactive(&workspace_directory, &["push"])
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use std::fs;
+
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::cli::commands::import::CookiesImport;
+
+impl App<'_> {
+    pub fn import_cookies_file(&mut self, cookies_import: &CookiesImport) -> anyhow::Result<()> {
+        if let Some(env_name) = &cookies_import.env {
+            self.selected_environment = self.find_environment(env_name)?;
+            self.load_cookie_jar();
+        }
+
+        if self.get_cookie_jar_path().is_none() {
+            return Err(anyhow!("Cookie persistence is disabled, the imported cookies would be lost"));
+        }
+
+        let content = match fs::read_to_string(&cookies_import.import_path) {
+            Ok(content) => content,
+            Err(error) => return Err(anyhow!("Could not read cookies file\n\t{error}"))
+        };
+
+        let cookie_count = self.import_netscape_cookies(&content)?;
+
+        println!("{cookie_count} cookie(s) imported");
+
+        Ok(())
+    }
+}
@@ -13,13 +13,14 @@ use walkdir::WalkDir;
 use rayon::prelude::*;
 
 use crate::app::app::App;
-use crate::cli::args::ARGS;
+use crate::app::files::collection::new_collection_path;
 use crate::cli::cli_logic::import::curl::ImportCurlError::{CouldNotParseCurl, CouldNotParseUrl, CouldNotReadFile, UnknownMethod};
 use crate::cli::commands::import::CurlImport;
 use crate::models::auth::Auth;
 use crate::models::body::ContentType;
 use crate::models::body::ContentType::NoBody;
 use crate::models::collection::Collection;
+use crate::models::collection_hooks::CollectionHooks;
 use crate::models::method::Method;
 use crate::models::request::{KeyValue, Request};
 
@@ -56,12 +57,20 @@ impl App<'_> {
                 println!("Collection does not exist. Creating it...");
 
                 let file_format = self.config.get_preferred_collection_file_format();
+                let layout = self.config.get_preferred_collection_layout();
 
                 let collection = Collection {
                     name: collection_name.clone(),
+                    description: None,
+                    read_only: false,
+                    login_request: None,
+                    hooks: CollectionHooks::default(),
+                    think_time: None,
+                    tags: vec![],
                     requests: vec![],
-                    path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name.clone(), file_format.to_string())),
+                    path: new_collection_path(&self.get_workspace_directory().unwrap(), collection_name, file_format, layout),
                     file_format,
+                    layout,
                 };
 
                 self.collections.push(collection);
@@ -1,2 +1,5 @@
 pub mod postman;
-pub mod curl;
\ No newline at end of file
+pub mod curl;
+pub mod cookies;
+pub mod plugin;
+pub mod wsdl;
\ No newline at end of file
@@ -0,0 +1,51 @@
+use std::fs;
+
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::app::business_logic::plugin::{find_plugin, run_plugin};
+use crate::app::files::collection::new_collection_path;
+use crate::cli::commands::import::PluginImport;
+use crate::models::plugin::{ImporterInput, ImporterOutput, PluginHook};
+
+impl App<'_> {
+    pub fn import_plugin_file(&mut self, plugin_import: &PluginImport) -> anyhow::Result<()> {
+        let importer = find_plugin(&plugin_import.plugin, PluginHook::Importer)?;
+        let path = &plugin_import.import_path;
+
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => return Err(anyhow!("Could not read file \"{}\"\n\t{error}", path.display()))
+        };
+
+        println!("Importing with the \"{}\" plugin", importer.manifest.name);
+
+        let input = ImporterInput {
+            path: path.display().to_string(),
+            content,
+        };
+
+        let importer_output: ImporterOutput = run_plugin(&importer, PluginHook::Importer, &input)?;
+        let mut collection = match importer_output.into_collection() {
+            Ok(collection) => collection,
+            Err(error) => return Err(anyhow!("Plugin \"{}\" answered an invalid collection\n\t{error}", importer.manifest.name))
+        };
+
+        println!("Collection name: {}", collection.name);
+
+        if self.collections.iter().any(|existing_collection| existing_collection.name == collection.name) {
+            return Err(anyhow!("Collection \"{}\" already exists", collection.name));
+        }
+
+        collection.file_format = self.config.get_preferred_collection_file_format();
+        collection.layout = self.config.get_preferred_collection_layout();
+        collection.path = new_collection_path(&self.get_workspace_directory().unwrap(), &collection.name, collection.file_format, collection.layout);
+
+        println!("{} request(s) imported", collection.requests.len());
+
+        self.collections.push(collection);
+        self.save_collection_to_file(self.collections.len() - 1);
+
+        Ok(())
+    }
+}
\ No newline at end of file
@@ -1,3 +1,4 @@
+use std::path::Path;
 use std::str::FromStr;
 use std::sync::Arc;
 
@@ -5,16 +6,17 @@ use anyhow::anyhow;
 use parking_lot::RwLock;
 use rayon::prelude::*;
 
-use parse_postman_collection::v2_1_0::{AuthType, Body, FormParameterSrcUnion, HeaderUnion, Host, Items, Language, Mode, RequestClass, RequestUnion, Url};
+use parse_postman_collection::v2_1_0::{AuthType, Body, DescriptionUnion, FormParameterSrcUnion, HeaderUnion, Host, Items, Language, Mode, RequestClass, RequestUnion, Url};
 use thiserror::Error;
 
 use crate::app::app::App;
-use crate::cli::args::ARGS;
+use crate::app::files::collection::new_collection_path;
 use crate::cli::cli_logic::import::postman::ImportPostmanError::{CollectionAlreadyExists, CouldNotParseCollection, UnknownMethod};
 use crate::cli::commands::import::PostmanImport;
-use crate::models::auth::Auth;
-use crate::models::body::ContentType;
-use crate::models::collection::{Collection, CollectionFileFormat};
+use crate::models::auth::{Auth, OAuth1Placement, OAuth1SignatureMethod};
+use crate::models::body::{BodyFile, ContentType, MultipartPart};
+use crate::models::collection::{Collection, CollectionFileFormat, CollectionLayout};
+use crate::models::collection_hooks::CollectionHooks;
 use crate::models::method::Method;
 use crate::models::request::{DEFAULT_HEADERS, KeyValue, Request};
 use crate::models::settings::RequestSettings;
@@ -54,18 +56,33 @@ impl App<'_> {
         }
 
         let file_format = self.config.get_preferred_collection_file_format();
+        let layout = self.config.get_preferred_collection_layout();
+        let workspace_directory = self.get_workspace_directory().unwrap();
 
         let mut collections: Vec<Collection> = vec![
             Collection {
                 name: collection_name.clone(),
+                description: retrieve_description(&postman_collection.info.description),
+                read_only: false,
+                login_request: None,
+                hooks: CollectionHooks::default(),
+                think_time: None,
+                tags: vec![],
                 requests: vec![],
-                path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format.to_string())),
+                path: new_collection_path(&workspace_directory, &collection_name, file_format, layout),
                 file_format,
+                layout,
             }
         ];
         
         let mut depth_level: u16 = 0;
 
+        let collection_files = CollectionFiles {
+            file_format,
+            layout,
+            workspace_directory: &workspace_directory,
+        };
+
         if max_depth == 0 {
             for item in postman_collection.item.iter_mut() {
                 collections[0].requests.extend(recursive_get_requests(item)?);
@@ -82,9 +99,7 @@ impl App<'_> {
                     let mut temp_nesting_prefix = String::new();
                     let new_collections: Vec<Collection> = vec![];
 
-                    let file_format = self.config.get_preferred_collection_file_format();
-
-                    recursive_has_requests(&mut item, &mut collections, &mut temp_nesting_prefix, &mut depth_level, max_depth, file_format)?;
+                    recursive_has_requests(&mut item, &mut collections, &mut temp_nesting_prefix, &mut depth_level, max_depth, &collection_files)?;
 
                     collections.extend(new_collections);
                 } else {
@@ -110,7 +125,14 @@ impl App<'_> {
     }
 }
 
-fn recursive_has_requests(item: &mut Items, collections: &mut Vec<Collection>, mut nesting_prefix: &mut String, mut depth_level: &mut u16, max_depth: u16, file_format: CollectionFileFormat) -> anyhow::Result<Option<Arc<RwLock<Request>>>> {
+/// Format, layout and directory of the collection files created by the import
+struct CollectionFiles<'a> {
+    file_format: CollectionFileFormat,
+    layout: CollectionLayout,
+    workspace_directory: &'a Path,
+}
+
+fn recursive_has_requests(item: &mut Items, collections: &mut Vec<Collection>, nesting_prefix: &mut String, depth_level: &mut u16, max_depth: u16, collection_files: &CollectionFiles) -> anyhow::Result<Option<Arc<RwLock<Request>>>> {
     return if is_folder(&item) {
         let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
 
@@ -133,7 +155,7 @@ fn recursive_has_requests(item: &mut Items, collections: &mut Vec<Collection>, m
             let mut has_sub_folders = false;
 
             for mut sub_item in item.item.clone().unwrap() {
-                if let Some(request) = recursive_has_requests(&mut sub_item, collections, &mut nesting_prefix, &mut depth_level, max_depth, file_format)? {
+                if let Some(request) = recursive_has_requests(&mut sub_item, collections, nesting_prefix, depth_level, max_depth, collection_files)? {
                     requests.push(request);
                 } else {
                     has_sub_folders = true;
@@ -150,9 +172,16 @@ fn recursive_has_requests(item: &mut Items, collections: &mut Vec<Collection>, m
 
             let collection = Collection {
                 name: collection_name.clone(),
+                description: retrieve_description(&item.description),
+                read_only: false,
+                login_request: None,
+                hooks: CollectionHooks::default(),
+                think_time: None,
+                tags: vec![],
                 requests,
-                path: ARGS.directory.as_ref().unwrap().join(format!("{}.{}", collection_name, file_format.to_string())),
-                file_format,
+                path: new_collection_path(collection_files.workspace_directory, &collection_name, collection_files.file_format, collection_files.layout),
+                file_format: collection_files.file_format,
+                layout: collection_files.layout,
             };
 
             collections.push(collection);
@@ -188,9 +217,10 @@ fn parse_request(item: Items) -> anyhow::Result<Request> {
 
     println!("\t\tFound request \"{}\"", item_name);
 
-    let mut request = Request::default();
-
-    request.name = item_name;
+    let mut request = Request {
+        name: item_name,
+        ..Default::default()
+    };
 
     request.scripts.pre_request_script = retrieve_request_scripts(&item);
 
@@ -208,6 +238,10 @@ fn parse_request(item: Items) -> anyhow::Result<Request> {
 
     match &item_request {
         RequestUnion::RequestClass(request_class) => {
+            /* DESCRIPTION */
+
+            request.description = retrieve_description(&request_class.description);
+
             /* URL */
 
             if let Some(url) = &request_class.url {
@@ -272,6 +306,19 @@ fn parse_request(item: Items) -> anyhow::Result<Request> {
     return Ok(request);
 }
 
+fn retrieve_description(description: &Option<DescriptionUnion>) -> Option<String> {
+    let description = match description {
+        None => return None,
+        Some(DescriptionUnion::String(description)) => description.clone(),
+        Some(DescriptionUnion::Description(description)) => description.content.clone()?
+    };
+
+    match description.trim().is_empty() {
+        true => None,
+        false => Some(description)
+    }
+}
+
 fn retrieve_query_params(request_class: &RequestClass) -> Option<Vec<KeyValue>> {
     let url = request_class.url.clone()?;
 
@@ -325,31 +372,36 @@ fn retrieve_body(request_class: &RequestClass) -> Option<ContentType> {
                     let file = body.file?;
                     let file_path = file.src?;
                     
-                    Some(ContentType::File(file_path))
+                    Some(ContentType::File(BodyFile { path: file_path, content_type: None }))
                 },
                 Mode::Formdata => {
                     let form_data = body.formdata?;
 
-                    let mut multipart: Vec<KeyValue> = vec![];
+                    let mut multipart: Vec<MultipartPart> = vec![];
 
                     for param in form_data {
                         let param_type = param.form_parameter_type?;
-
-                        let key_value = match param_type.as_str() {
-                            "text" => KeyValue {
-                                enabled: true,
-                                data: (param.key, param.value.unwrap_or(String::new())),
-                            },
+                        let enabled = !param.disabled.unwrap_or(false);
+
+                        match param_type.as_str() {
+                            "text" => multipart.push(MultipartPart {
+                                enabled,
+                                content_type: param.content_type,
+                                ..MultipartPart::text(param.key, param.value.unwrap_or(String::new()))
+                            }),
                             "file" => {
-                                let file = match param.src? {
-                                    FormParameterSrcUnion::File(file) => file,
-                                    // If there are many files, tries to get the first one
-                                    FormParameterSrcUnion::Files(files) => files.get(0)?.to_string()
+                                let files = match param.src? {
+                                    FormParameterSrcUnion::File(file) => vec![file],
+                                    // Each file gets its own part under the same name
+                                    FormParameterSrcUnion::Files(files) => files
                                 };
 
-                                KeyValue {
-                                    enabled: true,
-                                    data: (param.key, format!("!!{file}")),
+                                for file in files {
+                                    multipart.push(MultipartPart {
+                                        enabled,
+                                        content_type: param.content_type.clone(),
+                                        ..MultipartPart::file(param.key.clone(), file)
+                                    });
                                 }
                             },
                             param_type => {
@@ -357,8 +409,6 @@ fn retrieve_body(request_class: &RequestClass) -> Option<ContentType> {
                                 return None;
                             }
                         };
-
-                        multipart.push(key_value);
                     }
 
                     Some(ContentType::Multipart(multipart))
@@ -421,12 +471,67 @@ fn retrieve_auth(request_class: &RequestClass) -> Option<Auth> {
 
             Some(Auth::BearerToken { token: bearer_token })
         },
+        AuthType::Ntlm => {
+            let ntlm_attributes = auth.ntlm?;
+
+            let mut username = String::new();
+            let mut password = String::new();
+            let mut domain = String::new();
+
+            for ntlm_attribute in ntlm_attributes {
+                match ntlm_attribute.key.as_str() {
+                    "username" => username = ntlm_attribute.value.unwrap().as_str()?.to_string(),
+                    "password" => password = ntlm_attribute.value.unwrap().as_str()?.to_string(),
+                    "domain" => domain = ntlm_attribute.value.unwrap().as_str()?.to_string(),
+                    _ => {}
+                }
+            }
+
+            Some(Auth::Ntlm { username, password, domain })
+        },
+        AuthType::Oauth1 => {
+            let oauth1_attributes = auth.oauth1?;
+
+            let mut consumer_key = String::new();
+            let mut consumer_secret = String::new();
+            let mut private_key = String::new();
+            let mut token = String::new();
+            let mut token_secret = String::new();
+            let mut signature_method = OAuth1SignatureMethod::HmacSha1;
+            let mut placement = OAuth1Placement::Header;
+
+            for oauth1_attribute in oauth1_attributes {
+                match oauth1_attribute.key.as_str() {
+                    "consumerKey" => consumer_key = oauth1_attribute.value.unwrap().as_str()?.to_string(),
+                    "consumerSecret" => consumer_secret = oauth1_attribute.value.unwrap().as_str()?.to_string(),
+                    "privateKey" => private_key = oauth1_attribute.value.unwrap().as_str()?.to_string(),
+                    "token" => token = oauth1_attribute.value.unwrap().as_str()?.to_string(),
+                    "tokenSecret" => token_secret = oauth1_attribute.value.unwrap().as_str()?.to_string(),
+                    "signatureMethod" => signature_method = match oauth1_attribute.value.unwrap().as_str()? {
+                        "HMAC-SHA1" => OAuth1SignatureMethod::HmacSha1,
+                        "RSA-SHA1" => OAuth1SignatureMethod::RsaSha1,
+                        // PLAINTEXT and the SHA-256/512 variants are not supported
+                        _ => return None
+                    },
+                    "addParamsToHeader" => placement = match oauth1_attribute.value.unwrap().as_bool()? {
+                        true => OAuth1Placement::Header,
+                        false => OAuth1Placement::Query
+                    },
+                    _ => {}
+                }
+            }
+
+            // The private key takes the place of the consumer secret with RSA-SHA1
+            if signature_method == OAuth1SignatureMethod::RsaSha1 {
+                consumer_secret = private_key;
+            }
+
+            Some(Auth::OAuth1 { consumer_key, consumer_secret, token, token_secret, signature_method, placement })
+        },
         AuthType::Awsv4 => None,
         AuthType::Digest => None,
         AuthType::Hawk => None,
         AuthType::Noauth => None,
-        AuthType::Ntlm => None,
-        AuthType::Oauth1 => None,
         AuthType::Oauth2 => None,
     }
 }
@@ -489,4 +594,116 @@ fn retrieve_settings(item: &Items) -> Option<RequestSettings> {
     }
     
     Some(settings)
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const POSTMAN_COLLECTION: &str = r#"{
+        "info": {
+            "name": "Users",
+            "schema": "https://schema.getpostman.com/json/collection/v2.1.0/collection.json"
+        },
+        "item": [
+            {
+                "name": "Admin",
+                "item": [
+                    {
+                        "name": "Create user",
+                        "request": {
+                            "method": "POST",
+                            "url": {
+                                "raw": "{{base_url}}/users?notify=true&dry=1",
+                                "query": [
+                                    { "key": "notify", "value": "true" },
+                                    { "key": "dry", "value": "1", "disabled": true }
+                                ]
+                            },
+                            "auth": {
+                                "type": "basic",
+                                "basic": [
+                                    { "key": "username", "value": "admin", "type": "string" },
+                                    { "key": "password", "value": "secret", "type": "string" }
+                                ]
+                            },
+                            "header": [
+                                { "key": "X-Trace", "value": "1", "disabled": true }
+                            ],
+                            "body": {
+                                "mode": "raw",
+                                "raw": "{\"name\": \"Ada\"}",
+                                "options": { "raw": { "language": "json" } }
+                            }
+                        }
+                    },
+                    {
+                        "name": "Login",
+                        "request": {
+                            "method": "POST",
+                            "url": "{{base_url}}/login",
+                            "body": {
+                                "mode": "urlencoded",
+                                "urlencoded": [
+                                    { "key": "user", "value": "ada" },
+                                    { "key": "remember", "value": "yes", "disabled": true }
+                                ]
+                            }
+                        }
+                    }
+                ]
+            }
+        ]
+    }"#;
+
+    fn get_requests() -> Vec<Request> {
+        let mut postman_collection = parse_postman_collection::from_reader(POSTMAN_COLLECTION.as_bytes()).unwrap();
+
+        assert!(is_folder(&postman_collection.item[0]));
+
+        return recursive_get_requests(&mut postman_collection.item[0])
+            .unwrap()
+            .iter()
+            .map(|request| request.read().clone())
+            .collect();
+    }
+
+    #[test]
+    fn folder_requests() {
+        let requests = get_requests();
+
+        assert_eq!(requests.iter().map(|request| request.name.as_str()).collect::<Vec<&str>>(), ["Create user", "Login"]);
+    }
+
+    #[test]
+    fn raw_json_request() {
+        let request = &get_requests()[0];
+
+        assert!(matches!(request.method, Method::POST));
+        assert_eq!(request.url, "{{base_url}}/users?notify=true&dry=1");
+
+        let params: Vec<(bool, &str)> = request.params.iter().map(|param| (param.enabled, param.data.0.as_str())).collect();
+        assert_eq!(params, [(true, "notify"), (false, "dry")]);
+
+        assert!(matches!(&request.auth, Auth::BasicAuth { username, password } if username == "admin" && password == "secret"));
+        assert!(matches!(&request.body, ContentType::Json(body) if body == "{\"name\": \"Ada\"}"));
+
+        assert!(request.headers.iter().any(|header| !header.enabled && header.data == (String::from("X-Trace"), String::from("1"))));
+        assert!(request.headers.iter().any(|header| header.data.0 == "content-type" && header.data.1 == "application/json"));
+    }
+
+    #[test]
+    fn url_encoded_request() {
+        let request = &get_requests()[1];
+
+        assert_eq!(request.url, "{{base_url}}/login");
+
+        let form = match &request.body {
+            ContentType::Form(form) => form,
+            body => panic!("Form body expected, got {body}")
+        };
+
+        let form: Vec<(bool, &str, &str)> = form.iter().map(|field| (field.enabled, field.data.0.as_str(), field.data.1.as_str())).collect();
+        assert_eq!(form, [(true, "user", "ada"), (false, "remember", "yes")]);
+    }
+}
@@ -0,0 +1,513 @@
+use std::collections::HashMap;
+use std::fs;
+use std::sync::Arc;
+
+use anyhow::anyhow;
+use parking_lot::RwLock;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use reqwest::header::CONTENT_TYPE;
+use thiserror::Error;
+
+use crate::app::app::App;
+use crate::app::files::collection::new_collection_path;
+use crate::cli::cli_logic::import::wsdl::ImportWsdlError::{CollectionAlreadyExists, CouldNotParseWsdl, CouldNotReadFile, NoSoapPort, UnsupportedWsdlVersion};
+use crate::cli::commands::import::WsdlImport;
+use crate::models::body::ContentType;
+use crate::models::collection::Collection;
+use crate::models::collection_hooks::CollectionHooks;
+use crate::models::method::Method;
+use crate::models::request::{KeyValue, Request, DEFAULT_HEADERS};
+use crate::models::soap::{RequestSoap, SoapVersion};
+
+const WSDL_NAMESPACE: &str = "http://schemas.xmlsoap.org/wsdl/";
+const WSDL_SOAP_11_NAMESPACE: &str = "http://schemas.xmlsoap.org/wsdl/soap/";
+const WSDL_SOAP_12_NAMESPACE: &str = "http://schemas.xmlsoap.org/wsdl/soap12/";
+const XML_SCHEMA_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema";
+
+/// Recursive types are not expanded deeper
+const MAX_SKELETON_DEPTH: usize = 12;
+
+#[derive(Error, Debug)]
+pub enum ImportWsdlError {
+    #[error("Could not read WSDL file \"{0}\"\n\t{1}")]
+    CouldNotReadFile(String, String),
+    #[error("Could not parse WSDL file \"{0}\"\n\t{1}")]
+    CouldNotParseWsdl(String, String),
+    #[error("Only WSDL 1.1 files are supported")]
+    UnsupportedWsdlVersion,
+    #[error("No SOAP port found in the services of the WSDL file")]
+    NoSoapPort,
+    #[error("Collection \"{0}\" already exists")]
+    CollectionAlreadyExists(String),
+}
+
+impl App<'_> {
+    pub fn import_wsdl_file(&mut self, wsdl_import: &WsdlImport) -> anyhow::Result<()> {
+        let path = &wsdl_import.import_path;
+
+        println!("Parsing WSDL file");
+
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(error) => return Err(anyhow!(CouldNotReadFile(path.display().to_string(), error.to_string())))
+        };
+
+        let definitions = match parse_xml_tree(&content) {
+            Ok(definitions) => definitions,
+            Err(error) => return Err(anyhow!(CouldNotParseWsdl(path.display().to_string(), error)))
+        };
+
+        if definitions.namespace != WSDL_NAMESPACE || definitions.name != "definitions" {
+            return Err(anyhow!(UnsupportedWsdlVersion));
+        }
+
+        let collection_name = match &wsdl_import.collection_name {
+            Some(collection_name) => collection_name.clone(),
+            None => definitions.attribute("name")
+                .or_else(|| definitions.wsdl_children("service").next().and_then(|service| service.attribute("name")))
+                .map(String::from)
+                .unwrap_or_else(|| path.file_stem().unwrap().to_string_lossy().to_string())
+        };
+
+        println!("Collection name: {}", collection_name);
+
+        if self.collections.iter().any(|existing_collection| existing_collection.name == collection_name) {
+            return Err(anyhow!(CollectionAlreadyExists(collection_name)));
+        }
+
+        let requests = get_wsdl_requests(&definitions);
+
+        if requests.is_empty() {
+            return Err(anyhow!(NoSoapPort));
+        }
+
+        println!("{} operation(s) imported", requests.len());
+
+        let file_format = self.config.get_preferred_collection_file_format();
+        let layout = self.config.get_preferred_collection_layout();
+
+        let collection = Collection {
+            name: collection_name.clone(),
+            description: definitions.wsdl_children("documentation").next().map(XmlElement::get_text),
+            read_only: false,
+            login_request: None,
+            hooks: CollectionHooks::default(),
+            think_time: None,
+            tags: vec![],
+            requests: requests
+                .into_iter()
+                .map(|request| Arc::new(RwLock::new(request)))
+                .collect(),
+            path: new_collection_path(&self.get_workspace_directory().unwrap(), &collection_name, file_format, layout),
+            file_format,
+            layout,
+        };
+
+        self.collections.push(collection);
+        self.save_collection_to_file(self.collections.len() - 1);
+
+        Ok(())
+    }
+}
+
+/// One request per operation of each SOAP port of the services
+fn get_wsdl_requests(definitions: &XmlElement) -> Vec<Request> {
+    let schemas = Schemas::new(definitions);
+    let mut requests: Vec<Request> = vec![];
+
+    for port in definitions.wsdl_children("service").flat_map(|service| service.wsdl_children("port")) {
+        let address = port.children.iter().find(|child| child.name == "address" && get_soap_version(&child.namespace).is_some());
+
+        let (address, version) = match address {
+            Some(address) => (address, get_soap_version(&address.namespace).unwrap()),
+            // e.g. an HTTP binding
+            None => continue
+        };
+
+        let binding = port.attribute("binding").and_then(|binding_name| definitions.find_wsdl_child("binding", binding_name));
+        let binding = match binding {
+            Some(binding) => binding,
+            None => continue
+        };
+
+        let port_type = binding.attribute("type").and_then(|port_type_name| definitions.find_wsdl_child("portType", port_type_name));
+
+        let binding_style = binding.children
+            .iter()
+            .find(|child| child.name == "binding" && child.namespace == address.namespace)
+            .and_then(|soap_binding| soap_binding.attribute("style"))
+            .unwrap_or("document");
+
+        for binding_operation in binding.wsdl_children("operation") {
+            let operation_name = binding_operation.attribute("name").unwrap_or_default();
+
+            let soap_operation = binding_operation.children
+                .iter()
+                .find(|child| child.name == "operation" && child.namespace == address.namespace);
+
+            let action = soap_operation
+                .and_then(|soap_operation| soap_operation.attribute("soapAction"))
+                .filter(|action| !action.is_empty())
+                .map(String::from);
+
+            let style = soap_operation
+                .and_then(|soap_operation| soap_operation.attribute("style"))
+                .unwrap_or(binding_style);
+
+            let operation = port_type.and_then(|port_type| port_type.find_wsdl_child("operation", operation_name));
+
+            // The namespace of an RPC operation is given by its soap:body
+            let rpc_namespace = binding_operation.wsdl_children("input")
+                .flat_map(|input| input.children.iter())
+                .find(|child| child.name == "body")
+                .and_then(|soap_body| soap_body.attribute("namespace"))
+                .or_else(|| definitions.attribute("targetNamespace"))
+                .unwrap_or_default();
+
+            let body = match operation {
+                None => String::new(),
+                Some(operation) => {
+                    let parts: Vec<&XmlElement> = operation.wsdl_children("input")
+                        .next()
+                        .and_then(|input| input.attribute("message"))
+                        .and_then(|message_name| definitions.find_wsdl_child("message", message_name))
+                        .map(|message| message.wsdl_children("part").collect())
+                        .unwrap_or_default();
+
+                    match style {
+                        "rpc" => schemas.get_rpc_skeleton(operation_name, rpc_namespace, &parts),
+                        _ => schemas.get_document_skeleton(&parts)
+                    }
+                }
+            };
+
+            let soap = RequestSoap {
+                version,
+                action,
+                envelope_header: None,
+            };
+
+            // The same operation can be reached from several ports, e.g. one per SOAP version
+            let request_name = match requests.iter().any(|request| request.name == operation_name) {
+                false => operation_name.to_string(),
+                true => format!("{operation_name} - {}", port.attribute("name").unwrap_or(&version.to_string()))
+            };
+
+            println!("\tRequest name: {}", request_name);
+
+            let mut headers = DEFAULT_HEADERS.clone();
+            headers.push(KeyValue {
+                enabled: true,
+                data: (CONTENT_TYPE.to_string(), soap.get_content_type()),
+            });
+
+            requests.push(Request {
+                name: request_name,
+                description: operation.and_then(|operation| operation.wsdl_children("documentation").next()).map(XmlElement::get_text),
+                url: address.attribute("location").unwrap_or_default().to_string(),
+                method: Method::POST,
+                headers,
+                body: ContentType::Xml(body),
+                soap: Some(soap),
+                ..Default::default()
+            });
+        }
+    }
+
+    return requests;
+}
+
+fn get_soap_version(namespace: &str) -> Option<SoapVersion> {
+    match namespace {
+        WSDL_SOAP_11_NAMESPACE => Some(SoapVersion::Soap11),
+        WSDL_SOAP_12_NAMESPACE => Some(SoapVersion::Soap12),
+        _ => None
+    }
+}
+
+/// Local part of a qualified name, e.g. "GetWeather" for "tns:GetWeather"
+fn get_local_name(qualified_name: &str) -> &str {
+    return qualified_name.rsplit(':').next().unwrap_or(qualified_name);
+}
+
+/* SCHEMAS */
+
+/// Global elements and complex types of the schemas embedded in the WSDL file
+struct Schemas<'a> {
+    elements: HashMap<&'a str, (&'a XmlElement, &'a XmlElement)>,
+    complex_types: HashMap<&'a str, (&'a XmlElement, &'a XmlElement)>,
+}
+
+impl<'a> Schemas<'a> {
+    fn new(definitions: &'a XmlElement) -> Schemas<'a> {
+        let mut elements = HashMap::new();
+        let mut complex_types = HashMap::new();
+
+        let schemas = definitions.wsdl_children("types")
+            .flat_map(|types| types.children.iter())
+            .filter(|child| child.name == "schema" && child.namespace == XML_SCHEMA_NAMESPACE);
+
+        for schema in schemas {
+            for child in &schema.children {
+                let name = match child.attribute("name") {
+                    Some(name) => name,
+                    None => continue
+                };
+
+                match child.name.as_str() {
+                    "element" => { elements.insert(name, (child, schema)); },
+                    "complexType" => { complex_types.insert(name, (child, schema)); },
+                    _ => {}
+                }
+            }
+        }
+
+        Schemas {
+            elements,
+            complex_types,
+        }
+    }
+
+    /// The message parts are global elements of the schemas
+    fn get_document_skeleton(&self, parts: &[&XmlElement]) -> String {
+        let mut lines: Vec<String> = vec![];
+
+        for part in parts {
+            let element = part.attribute("element").and_then(|element_name| self.elements.get(get_local_name(element_name)));
+
+            match element {
+                Some((element, schema)) => self.write_element(&mut lines, element, schema, 0, true),
+                None => lines.push(format!("<{}>?</{0}>", part.attribute("name").unwrap_or("part")))
+            }
+        }
+
+        return lines.join("\n");
+    }
+
+    /// The operation wraps an element per message part
+    fn get_rpc_skeleton(&self, operation_name: &str, namespace: &str, parts: &[&XmlElement]) -> String {
+        let mut lines = vec![format!("<ns:{operation_name} xmlns:ns=\"{namespace}\">")];
+
+        for part in parts {
+            let part_name = part.attribute("name").unwrap_or("part");
+            let complex_type = part.attribute("type").and_then(|type_name| self.complex_types.get(get_local_name(type_name)));
+
+            match complex_type {
+                None => lines.push(format!("    <{part_name}>?</{part_name}>")),
+                Some((complex_type, schema)) => {
+                    lines.push(format!("    <{part_name}>"));
+                    self.write_type_content(&mut lines, complex_type, schema, 2);
+                    lines.push(format!("    </{part_name}>"));
+                }
+            }
+        }
+
+        lines.push(format!("</ns:{operation_name}>"));
+
+        return lines.join("\n");
+    }
+
+    fn write_element(&self, lines: &mut Vec<String>, element: &XmlElement, schema: &XmlElement, depth: usize, is_global: bool) {
+        // Reference to a global element
+        if let Some(reference) = element.attribute("ref") {
+            if let Some((global_element, global_schema)) = self.elements.get(get_local_name(reference)) {
+                write_occurrence_comment(lines, element, depth);
+                self.write_element(lines, global_element, global_schema, depth, false);
+            }
+
+            return;
+        }
+
+        let indentation = "    ".repeat(depth);
+        let name = element.attribute("name").unwrap_or("element");
+
+        // Global elements are qualified, the local ones only when the schema says so
+        let is_qualified = is_global || schema.attribute("elementFormDefault") == Some("qualified");
+
+        let tag_name = match is_qualified {
+            true => format!("ns:{name}"),
+            false => name.to_string()
+        };
+
+        let namespace_declaration = match is_global {
+            true => format!(" xmlns:ns=\"{}\"", schema.attribute("targetNamespace").unwrap_or_default()),
+            false => String::new()
+        };
+
+        if !is_global {
+            write_occurrence_comment(lines, element, depth);
+        }
+
+        let complex_type = element.children
+            .iter()
+            .find(|child| child.name == "complexType")
+            .map(|complex_type| (complex_type, schema))
+            .or_else(|| element.attribute("type").and_then(|type_name| self.complex_types.get(get_local_name(type_name)).copied()));
+
+        match complex_type {
+            Some((complex_type, complex_type_schema)) if depth < MAX_SKELETON_DEPTH => {
+                lines.push(format!("{indentation}<{tag_name}{namespace_declaration}>"));
+                self.write_type_content(lines, complex_type, complex_type_schema, depth + 1);
+                lines.push(format!("{indentation}</{tag_name}>"));
+            },
+            _ => lines.push(format!("{indentation}<{tag_name}{namespace_declaration}>?</{tag_name}>"))
+        }
+    }
+
+    fn write_type_content(&self, lines: &mut Vec<String>, complex_type: &XmlElement, schema: &XmlElement, depth: usize) {
+        for child in &complex_type.children {
+            match child.name.as_str() {
+                "sequence" | "all" | "choice" => self.write_particle(lines, child, schema, depth),
+                // The base type elements come first
+                "complexContent" => {
+                    for derivation in child.children.iter().filter(|derivation| derivation.name == "extension" || derivation.name == "restriction") {
+                        let base_type = derivation.attribute("base").and_then(|base| self.complex_types.get(get_local_name(base)));
+
+                        if let (Some((base_type, base_schema)), "extension") = (base_type, derivation.name.as_str()) {
+                            self.write_type_content(lines, base_type, base_schema, depth);
+                        }
+
+                        self.write_type_content(lines, derivation, schema, depth);
+                    }
+                },
+                _ => {}
+            }
+        }
+    }
+
+    fn write_particle(&self, lines: &mut Vec<String>, particle: &XmlElement, schema: &XmlElement, depth: usize) {
+        if particle.name == "choice" {
+            lines.push(format!("{}<!--Choice of one of the next elements-->", "    ".repeat(depth)));
+        }
+
+        for child in &particle.children {
+            match child.name.as_str() {
+                "element" => self.write_element(lines, child, schema, depth, false),
+                "sequence" | "all" | "choice" => self.write_particle(lines, child, schema, depth),
+                _ => {}
+            }
+        }
+    }
+}
+
+fn write_occurrence_comment(lines: &mut Vec<String>, element: &XmlElement, depth: usize) {
+    let indentation = "    ".repeat(depth);
+
+    match (element.attribute("minOccurs"), element.attribute("maxOccurs")) {
+        (_, Some("unbounded")) => lines.push(format!("{indentation}<!--Repeatable-->")),
+        (_, Some(max_occurs)) if max_occurs != "1" => lines.push(format!("{indentation}<!--Repeatable, up to {max_occurs} times-->")),
+        (Some("0"), _) => lines.push(format!("{indentation}<!--Optional-->")),
+        _ => {}
+    }
+}
+
+/* XML TREE */
+
+/// Element of a parsed XML document, with its namespace resolved
+struct XmlElement {
+    name: String,
+    namespace: String,
+    attributes: Vec<(String, String)>,
+    children: Vec<XmlElement>,
+    text: String,
+}
+
+impl XmlElement {
+    fn attribute(&self, name: &str) -> Option<&str> {
+        return self.attributes
+            .iter()
+            .find(|(attribute_name, _)| attribute_name == name)
+            .map(|(_, value)| value.as_str());
+    }
+
+    fn wsdl_children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a XmlElement> {
+        return self.children
+            .iter()
+            .filter(move |child| child.name == name && child.namespace == WSDL_NAMESPACE);
+    }
+
+    /// The qualified name refers to the name attribute of the child
+    fn find_wsdl_child<'a>(&'a self, name: &'a str, qualified_name: &str) -> Option<&'a XmlElement> {
+        let local_name = get_local_name(qualified_name);
+
+        return self.wsdl_children(name).find(|child| child.attribute("name") == Some(local_name));
+    }
+
+    fn get_text(&self) -> String {
+        return self.text.trim().to_string();
+    }
+}
+
+fn parse_xml_tree(content: &str) -> Result<XmlElement, String> {
+    let mut reader = Reader::from_str(content);
+    // Open elements, with the namespace prefixes declared in their scope
+    let mut stack: Vec<(XmlElement, HashMap<String, String>)> = vec![];
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(start)) => {
+                let scope = stack.last().map(|(_, scope)| scope.clone()).unwrap_or_default();
+                stack.push(new_xml_element(&start, scope)?);
+            },
+            Ok(Event::Empty(start)) => {
+                let scope = stack.last().map(|(_, scope)| scope.clone()).unwrap_or_default();
+                let (element, _) = new_xml_element(&start, scope)?;
+
+                match stack.last_mut() {
+                    Some((parent, _)) => parent.children.push(element),
+                    None => return Ok(element)
+                }
+            },
+            Ok(Event::Text(text)) => {
+                if let Some((element, _)) = stack.last_mut() {
+                    element.text += &text.unescape().map_err(|error| error.to_string())?;
+                }
+            },
+            Ok(Event::End(_)) => {
+                let (element, _) = stack.pop().unwrap();
+
+                match stack.last_mut() {
+                    Some((parent, _)) => parent.children.push(element),
+                    None => return Ok(element)
+                }
+            },
+            Ok(Event::Eof) => return Err(String::from("Unexpected end of file")),
+            Err(error) => return Err(error.to_string()),
+            _ => {}
+        }
+    }
+}
+
+fn new_xml_element(start: &BytesStart, mut scope: HashMap<String, String>) -> Result<(XmlElement, HashMap<String, String>), String> {
+    let mut attributes: Vec<(String, String)> = vec![];
+
+    for attribute in start.attributes() {
+        let attribute = attribute.map_err(|error| error.to_string())?;
+        let key = String::from_utf8_lossy(attribute.key.as_ref()).to_string();
+        let value = attribute.unescape_value().map_err(|error| error.to_string())?.to_string();
+
+        match key.strip_prefix("xmlns") {
+            Some("") => { scope.insert(String::new(), value); },
+            Some(prefix) if prefix.starts_with(':') => { scope.insert(prefix[1..].to_string(), value); },
+            _ => attributes.push((key, value))
+        }
+    }
+
+    let qualified_name = String::from_utf8_lossy(start.name().as_ref()).to_string();
+
+    let (prefix, name) = match qualified_name.split_once(':') {
+        Some((prefix, name)) => (prefix.to_string(), name.to_string()),
+        None => (String::new(), qualified_name)
+    };
+
+    let element = XmlElement {
+        name,
+        namespace: scope.get(&prefix).cloned().unwrap_or_default(),
+        attributes,
+        children: vec![],
+        text: String::new(),
+    };
+
+    return Ok((element, scope));
+}
@@ -0,0 +1,118 @@
+use std::path::PathBuf;
+
+use serde::Serialize;
+
+use crate::app::app::App;
+use crate::cli::commands::list::ListCommand;
+use crate::models::collection::{Collection, CollectionFileFormat, CollectionLayout};
+use crate::models::collection_hooks::CollectionHooks;
+use crate::models::method::Method;
+use crate::models::request::Request;
+use crate::models::think_time::ThinkTime;
+
+/// Structure of the workspace printed by "atac list --json", without the request contents nor the secrets they may hold
+#[derive(Serialize)]
+struct WorkspaceListing {
+    collections: Vec<CollectionListing>,
+    environments: Vec<String>,
+}
+
+#[derive(Serialize)]
+struct CollectionListing {
+    name: String,
+    description: Option<String>,
+    /// File of a single file collection, directory of a file per request one
+    path: PathBuf,
+    file_format: CollectionFileFormat,
+    layout: CollectionLayout,
+    read_only: bool,
+    login_request: Option<String>,
+    hooks: CollectionHooks,
+    think_time: Option<ThinkTime>,
+    tags: Vec<String>,
+    requests: Vec<RequestListing>,
+}
+
+#[derive(Serialize)]
+struct RequestListing {
+    name: String,
+    description: Option<String>,
+    method: Method,
+    url: String,
+    /// e.g. "JSON", "Multipart"
+    body: String,
+    /// e.g. "Bearer", "No Auth"
+    auth: String,
+    tags: Vec<String>,
+    skipped: bool,
+    depends_on: Vec<String>,
+    think_time: Option<ThinkTime>,
+    assertions: usize,
+    monitored: bool,
+    has_snapshot: bool,
+}
+
+impl App<'_> {
+    /// Every collection with its requests, as a tree or as JSON
+    pub fn cli_list(&mut self, list_command: &ListCommand) -> anyhow::Result<()> {
+        if list_command.json {
+            let workspace_listing = WorkspaceListing {
+                collections: self.collections.iter().map(CollectionListing::from).collect(),
+                environments: self.environments.iter().map(|environment| environment.read().name.clone()).collect(),
+            };
+
+            println!("{}", serde_json::to_string_pretty(&workspace_listing)?);
+
+            return Ok(());
+        }
+
+        for collection in &self.collections {
+            println!("{}", collection.name);
+
+            for request in &collection.requests {
+                let request = request.read();
+                println!("\t{} {} {}", request.method, request.name, request.url);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl From<&Collection> for CollectionListing {
+    fn from(collection: &Collection) -> Self {
+        CollectionListing {
+            name: collection.name.clone(),
+            description: collection.description.clone(),
+            path: collection.path.clone(),
+            file_format: collection.file_format,
+            layout: collection.layout,
+            read_only: collection.read_only,
+            login_request: collection.login_request.clone(),
+            hooks: collection.hooks.clone(),
+            think_time: collection.think_time,
+            tags: collection.tags.clone(),
+            requests: collection.requests.iter().map(|request| RequestListing::from(&*request.read())).collect(),
+        }
+    }
+}
+
+impl From<&Request> for RequestListing {
+    fn from(request: &Request) -> Self {
+        RequestListing {
+            name: request.name.clone(),
+            description: request.description.clone(),
+            method: request.method,
+            url: request.url.clone(),
+            body: request.body.to_string(),
+            auth: request.auth.to_string(),
+            tags: request.tags.clone(),
+            skipped: request.skipped,
+            depends_on: request.depends_on.clone(),
+            think_time: request.think_time,
+            assertions: request.assertions.len(),
+            monitored: request.monitor.is_some(),
+            has_snapshot: request.snapshot.is_some(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::app::App;
+use crate::app::business_logic::webhook_listener::run_webhook_listener;
+use crate::cli::commands::listen::ListenCommand;
+use crate::models::webhook::ReceivedRequest;
+
+impl App<'_> {
+    pub async fn cli_listen(&mut self, listen_command: &ListenCommand) -> anyhow::Result<()> {
+        let port = listen_command.port.unwrap_or(self.config.get_webhook_listener_port());
+        let mut canned_response = self.config.get_webhook_listener_response();
+
+        if let Some(status_code) = listen_command.status_code {
+            canned_response.status_code = status_code;
+        }
+
+        if let Some(body) = &listen_command.body {
+            canned_response.body = body.clone();
+        }
+
+        for header in listen_command.add_header.chunks(2) {
+            canned_response.headers.push((header[0].clone(), header[1].clone()));
+        }
+
+        let on_request = match listen_command.hide_content {
+            true => print_received_request_line,
+            false => print_received_request
+        };
+
+        println!("Listening on port {port}, press Ctrl+C to stop");
+
+        let received_requests = Arc::new(RwLock::new(Vec::new()));
+
+        run_webhook_listener(port, canned_response, received_requests, CancellationToken::new(), Some(on_request)).await?;
+
+        Ok(())
+    }
+}
+
+fn print_received_request_line(received_request: &ReceivedRequest) {
+    println!("{}", received_request.to_summary());
+}
+
+fn print_received_request(received_request: &ReceivedRequest) {
+    print_received_request_line(received_request);
+
+    for (header, value) in &received_request.headers {
+        println!("\t{header}: {value}");
+    }
+
+    if !received_request.body.is_empty() {
+        println!("\n{}", received_request.body);
+    }
+
+    println!();
+}
@@ -4,4 +4,10 @@ mod environment;
 mod import;
 pub(super) mod try_request;
 pub(super) mod completions;
-pub(super) mod man;
\ No newline at end of file
+pub(super) mod man;
+pub(super) mod listen;
+pub(super) mod monitor;
+pub(super) mod docs;
+pub(super) mod git;
+pub(super) mod plugins;
+pub(super) mod list;
\ No newline at end of file
@@ -0,0 +1,69 @@
+use std::time::Duration;
+
+use crate::app::app::App;
+use crate::app::business_logic::notification::send_notification;
+use crate::app::business_logic::monitor::{failed_monitor_check, record_monitor_check, send_monitored_request};
+use crate::cli::commands::monitor::MonitorCommand;
+use crate::models::monitor::MonitorRecord;
+
+impl App<'_> {
+    /// Runs the monitored requests on their schedule until the process is stopped
+    pub async fn cli_monitor(&mut self, monitor_command: &MonitorCommand) -> anyhow::Result<()> {
+        let collection_index = match &monitor_command.collection {
+            None => None,
+            Some(collection_name) => Some(self.find_collection(collection_name)?)
+        };
+
+        if let Some(env_name) = &monitor_command.env {
+            self.selected_environment = self.find_environment(env_name)?;
+        }
+
+        println!("Running monitors, press Ctrl+C to stop");
+
+        loop {
+            for (collection_index, request_index) in self.take_due_monitors(collection_index) {
+                let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+                let request = local_request.read().clone();
+                let prepared_request = self.prepare_request(&request).await;
+
+                let record = match prepared_request {
+                    Ok((prepared_request, _)) => {
+                        let local_env = self.get_selected_env_as_local();
+                        send_monitored_request(prepared_request, local_request.clone(), &local_env, self.is_offline()).await
+                    },
+                    Err(prepare_request_error) => {
+                        let record = failed_monitor_check(prepare_request_error.to_string());
+                        record_monitor_check(&local_request, record.clone());
+                        record
+                    }
+                };
+
+                if record.is_failure && request.settings.notify_on_completion {
+                    send_notification(self.config.get_notification_method(), &format!("Monitor failed: {}", request.name), &record.status_code.clone().unwrap_or_default());
+                }
+
+                if monitor_command.failures_only && !record.is_failure {
+                    continue;
+                }
+
+                let collection_name = &self.collections[collection_index].name;
+
+                print_monitor_record(&format!("{collection_name}/{}", request.name), &record);
+            }
+
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    }
+}
+
+fn print_monitor_record(request_path: &str, record: &MonitorRecord) {
+    let status = match record.is_failure {
+        true => "FAIL",
+        false => "OK"
+    };
+
+    let status_code = record.status_code.clone().unwrap_or_default();
+
+    println!("{} {status} {request_path} {status_code} ({:?})", record.checked_at, record.duration);
+}
@@ -0,0 +1,71 @@
+use std::fs;
+
+use crate::app::app::App;
+use crate::app::business_logic::plugin::{find_plugin, run_plugin};
+use crate::app::files::plugins::PLUGINS;
+use crate::cli::commands::plugins::{PluginsCommand, PluginsSubcommand};
+use crate::models::plugin::{ExporterInput, ExporterOutput, PluginHook};
+
+impl App<'_> {
+    pub fn cli_plugins(&mut self, plugins_command: &PluginsCommand) -> anyhow::Result<()> {
+        match &plugins_command.plugins_subcommand {
+            PluginsSubcommand::List => self.cli_list_plugins(),
+            PluginsSubcommand::Export { plugin, collection_name, request, output } => {
+                let collection_index = self.find_collection(collection_name)?;
+
+                let request_index = match request {
+                    None => None,
+                    Some(request_name) => Some(self.find_collection_slash_request(collection_name, request_name)?.1)
+                };
+
+                let export = {
+                    let collection = &self.collections[collection_index];
+                    let request = request_index.map(|request_index| collection.requests[request_index].read().clone());
+
+                    let exporter = find_plugin(plugin, PluginHook::Exporter)?;
+
+                    let input = ExporterInput {
+                        collection,
+                        request: request.as_ref(),
+                    };
+
+                    let exporter_output: ExporterOutput = run_plugin(&exporter, PluginHook::Exporter, &input)?;
+
+                    exporter_output.output
+                };
+
+                match output {
+                    None => print!("{export}"),
+                    Some(output) => {
+                        fs::write(output, export)?;
+                        println!("Export written to \"{}\"", output.display());
+                    }
+                }
+
+                Ok(())
+            }
+        }
+    }
+
+    fn cli_list_plugins(&self) -> anyhow::Result<()> {
+        for plugin in PLUGINS.read().iter() {
+            let hooks = plugin.manifest.hooks
+                .iter()
+                .map(|hook| hook.to_string())
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            println!("{} ({hooks})", plugin.manifest.name);
+
+            if !plugin.manifest.description.is_empty() {
+                println!("\t{}", plugin.manifest.description);
+            }
+
+            if !plugin.manifest.content_types.is_empty() {
+                println!("\tcontent types: {}", plugin.manifest.content_types.join(", "));
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::cli::commands::request_commands::assertion::RequestAssertionCommand;
+use crate::models::assertion::ResponseAssertion;
+
+impl App<'_> {
+    pub fn cli_request_assertion(&mut self, collection_index: usize, request_index: usize, assertion_command: &RequestAssertionCommand) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let mut assertions = local_selected_request.read().assertions.clone();
+
+        match assertion_command {
+            RequestAssertionCommand::List => {
+                if assertions.is_empty() {
+                    println!("No assertions");
+                }
+
+                for (index, assertion) in assertions.iter().enumerate() {
+                    println!("{}. {}", index + 1, assertion.to_test_name());
+                }
+
+                return Ok(());
+            },
+            RequestAssertionCommand::AddHeader { name, equals, contains, absent } => {
+                let name = name.trim().to_string();
+
+                if name.is_empty() {
+                    return Err(anyhow!("The header name is empty"));
+                }
+
+                let assertion = match (equals, contains, absent) {
+                    (Some(value), _, _) => ResponseAssertion::HeaderEquals { name, value: value.clone() },
+                    (_, Some(value), _) => ResponseAssertion::HeaderContains { name, value: value.clone() },
+                    (_, _, true) => ResponseAssertion::HeaderAbsent(name),
+                    (None, None, false) => ResponseAssertion::HeaderPresent(name)
+                };
+
+                assertions.push(assertion);
+            },
+            RequestAssertionCommand::AddMaxDuration { max_duration } => assertions.push(ResponseAssertion::MaxDuration(*max_duration)),
+            RequestAssertionCommand::Delete { number } => match (1..=assertions.len()).contains(number) {
+                true => {
+                    assertions.remove(number - 1);
+                },
+                false => return Err(anyhow!("No assertion number {number}, the request has {}", assertions.len()))
+            },
+            RequestAssertionCommand::Clear => assertions.clear()
+        }
+
+        self.modify_request_assertions(collection_index, request_index, assertions)
+    }
+}
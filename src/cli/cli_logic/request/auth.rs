@@ -13,7 +13,11 @@ impl App<'_> {
             match &selected_request.auth {
                 Auth::NoAuth => {},
                 Auth::BasicAuth { username, password } => print!(" {username} {password}"),
-                Auth::BearerToken { token: bearer_token } => print!(" {bearer_token}")
+                Auth::BearerToken { token: bearer_token } => print!(" {bearer_token}"),
+                Auth::HmacSignature { secret, string_to_sign, header, algorithm, encoding } => print!(" {secret} {string_to_sign} {header} {algorithm} {encoding}"),
+                Auth::Ntlm { username, password, domain } => print!(" {username} {password} {domain}"),
+                Auth::OAuth1 { consumer_key, consumer_secret, token, token_secret, signature_method, placement } => print!(" {consumer_key} {consumer_secret} {token} {token_secret} {signature_method} {placement}"),
+                Auth::Plugin { plugin, config } => print!(" {plugin} {config}")
             }
             
             println!()
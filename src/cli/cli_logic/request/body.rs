@@ -1,6 +1,10 @@
+use ratatui::prelude::Stylize;
+
 use crate::app::app::App;
 use crate::app::business_logic::key_value::print_key_value_vector;
-use crate::models::body::ContentType;
+use crate::app::files::theme::THEME;
+use crate::cli::commands::request_commands::body::AddPartCommand;
+use crate::models::body::{ContentType, MultipartPart, PartSource};
 
 impl App<'_> {
     pub fn cli_print_request_body(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
@@ -13,12 +17,19 @@ impl App<'_> {
 
             match &selected_request.body {
                 ContentType::NoBody => {},
-                ContentType::Multipart(form) | ContentType::Form(form) => {
-                    for key_value in form {
-                        println!("\t{}: {}", key_value.data.0, key_value.data.1);
+                ContentType::Multipart(parts) => print_multipart_parts(parts, Some("\t")),
+                ContentType::Form(form) => {
+                    print_key_value_vector(form, Some("\t"));
+                    println!("encoded: {}", self.get_masked_form_body(form));
+                },
+                ContentType::File(body_file) => {
+                    println!("{}", body_file.path);
+
+                    if let Some(content_type) = &body_file.content_type {
+                        println!("content-type: {content_type}")
                     }
                 },
-                ContentType::File(content) | ContentType::Raw(content) | ContentType::Json(content) | ContentType::Xml(content) | ContentType::Html(content) | ContentType::Javascript(content) => {
+                ContentType::Raw(content) | ContentType::Json(content) | ContentType::Xml(content) | ContentType::Html(content) | ContentType::Javascript(content) | ContentType::Protobuf(content) | ContentType::MessagePack(content) | ContentType::Cbor(content) => {
                     println!("{content}")
                 }
             }
@@ -32,21 +43,38 @@ impl App<'_> {
 
         {
             let selected_request = local_selected_request.read();
-            let form = selected_request.body.get_form()?;
-            print_key_value_vector(form, None);
+            match &selected_request.body {
+                ContentType::Multipart(parts) => print_multipart_parts(parts, None),
+                _ => print_key_value_vector(&selected_request.body.get_form()?, None)
+            }
         }
 
         self.save_collection_to_file(collection_index);
         Ok(())
     }
 
+    pub fn cli_add_multipart_part(&mut self, collection_index: usize, request_index: usize, add_part_command: &AddPartCommand) -> anyhow::Result<()> {
+        let part = match add_part_command.file {
+            true => MultipartPart::file(add_part_command.name.clone(), add_part_command.value.clone()),
+            false => MultipartPart::text(add_part_command.name.clone(), add_part_command.value.clone())
+        };
+
+        let part = MultipartPart {
+            content_type: add_part_command.content_type.clone(),
+            file_name: add_part_command.file_name.clone(),
+            ..part
+        };
+
+        self.create_new_multipart_part(collection_index, request_index, part)
+    }
+
     pub fn cli_print_form_data(&mut self, collection_index: usize, request_index: usize, row: usize) -> anyhow::Result<()> {
         let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
 
         {
             let selected_request = local_selected_request.read();
 
-            let value = &selected_request.headers[row].data.1;
+            let value = &selected_request.body.get_form()?[row].data.1;
 
             println!("{value}")
         }
@@ -54,4 +82,31 @@ impl App<'_> {
         self.save_collection_to_file(collection_index);
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+/// Parts are numbered since several of them can share the same name
+pub fn print_multipart_parts(parts: &[MultipartPart], prefix: Option<&str>) {
+    let prefix = prefix.unwrap_or("");
+
+    for (index, part) in parts.iter().enumerate() {
+        let mut text = match part.source {
+            PartSource::Text => format!("{prefix}{index}. {}: {}", part.name, part.value),
+            PartSource::File => format!("{prefix}{index}. {}: @{}", part.name, part.value)
+        };
+
+        if let Some(content_type) = &part.content_type {
+            text += &format!(" (type: {content_type})");
+        }
+
+        if let Some(file_name) = &part.file_name {
+            text += &format!(" (file name: {file_name})");
+        }
+
+        if part.enabled {
+            println!("{}", text);
+        }
+        else {
+            println!("{}", text.fg(THEME.read().ui.secondary_foreground_color));
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use crate::app::app::App;
+use crate::cli::commands::request_commands::connection_pool::RequestConnectionPoolCommand;
+use crate::models::connection_pool::ConnectionPoolOptions;
+
+impl App<'_> {
+    pub fn cli_request_connection_pool(&mut self, collection_index: usize, request_index: usize, connection_pool_command: &RequestConnectionPoolCommand) -> anyhow::Result<()> {
+        let connection_pool = match connection_pool_command {
+            RequestConnectionPoolCommand::Get => {
+                let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+                let selected_request = local_selected_request.read();
+
+                match &selected_request.settings.connection_pool {
+                    None => println!("Config connection pool: {}", self.config.get_connection_pool_options().to_summary()),
+                    Some(connection_pool) => println!("{}", connection_pool.to_summary())
+                }
+
+                return Ok(());
+            },
+            RequestConnectionPoolCommand::Set { idle_timeout, max_idle_per_host, tcp_keepalive, tcp_nodelay } => Some(ConnectionPoolOptions {
+                idle_timeout: *idle_timeout,
+                max_idle_per_host: *max_idle_per_host,
+                tcp_keepalive: *tcp_keepalive,
+                tcp_nodelay: *tcp_nodelay,
+            }),
+            RequestConnectionPoolCommand::Remove => None
+        };
+
+        self.modify_request_connection_pool(collection_index, request_index, connection_pool)
+    }
+}
@@ -0,0 +1,42 @@
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::cli::commands::request_commands::dependency::RequestDependencyCommand;
+
+impl App<'_> {
+    pub fn cli_request_dependencies(&mut self, collection_index: usize, request_index: usize, dependency_command: &RequestDependencyCommand) -> anyhow::Result<()> {
+        let mut depends_on = {
+            let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+            let selected_request = local_selected_request.read();
+
+            selected_request.depends_on.clone()
+        };
+
+        match dependency_command {
+            RequestDependencyCommand::List => {
+                for dependency in &depends_on {
+                    println!("{dependency}");
+                }
+
+                return Ok(());
+            },
+            RequestDependencyCommand::Add { request_name } => {
+                if depends_on.contains(request_name) {
+                    return Ok(());
+                }
+
+                depends_on.push(request_name.clone());
+            },
+            RequestDependencyCommand::Remove { request_name } => {
+                if !depends_on.contains(request_name) {
+                    return Err(anyhow!("The request does not depend on \"{request_name}\""));
+                }
+
+                depends_on.retain(|dependency| dependency != request_name);
+            },
+            RequestDependencyCommand::Clear => depends_on.clear()
+        }
+
+        self.modify_request_dependencies(collection_index, request_index, depends_on)
+    }
+}
@@ -1,5 +1,6 @@
 use crate::app::app::App;
 use crate::app::business_logic::key_value::print_key_value_vector;
+use crate::cli::cli_logic::request::body::print_multipart_parts;
 use crate::models::auth::Auth;
 use crate::models::body::ContentType;
 
@@ -9,6 +10,27 @@ impl App<'_> {
         let request = local_request.read();
 
         println!("name: {}", request.name);
+
+        if let Some(description) = &request.description {
+            println!("description:\n\t{}", description.replace('\n', "\n\t"));
+        }
+
+        if !request.tags.is_empty() {
+            println!("tags: {}", request.tags.join(", "));
+        }
+
+        if request.skipped {
+            println!("skipped in the collection runs");
+        }
+
+        if !request.depends_on.is_empty() {
+            println!("depends on: {}", request.depends_on.join(", "));
+        }
+
+        if let Some(think_time) = &request.think_time {
+            println!("think time: {think_time}");
+        }
+
         println!("method: {}", request.method);
         println!("url: {}", request.url_with_params_to_string());
 
@@ -17,20 +39,33 @@ impl App<'_> {
             print_key_value_vector(&request.headers, Some("\t"));
         }
 
+        if !request.cookies.is_empty() {
+            println!("cookies:");
+            print_key_value_vector(&request.cookies, Some("\t"));
+        }
+
         match &request.auth {
             Auth::NoAuth => {}
             Auth::BasicAuth { username, password } => println!("auth: Basic\n\t{username}\n\t{password}"),
             Auth::BearerToken { token: bearer_token } => println!("auth: Bearer token\n\t{bearer_token}"),
+            Auth::HmacSignature { secret, string_to_sign, header, algorithm, encoding } => println!("auth: {algorithm} signature ({encoding})\n\t{secret}\n\t{string_to_sign}\n\t{header}"),
+            Auth::Ntlm { username, password, domain } => println!("auth: {}\n\t{username}\n\t{password}\n\t{domain}", request.auth),
+            Auth::OAuth1 { consumer_key, consumer_secret, token, token_secret, signature_method, placement } => println!("auth: OAuth 1.0a {signature_method} ({placement})\n\t{consumer_key}\n\t{consumer_secret}\n\t{token}\n\t{token_secret}"),
+            Auth::Plugin { plugin, config } => println!("auth: Plugin \"{plugin}\"\n\t{config}"),
         }
 
         match &request.body {
             ContentType::NoBody => {}
-            ContentType::File(file) => println!("body: {}\n{file}", &request.body.to_string()),
-            ContentType::Multipart(form) | ContentType::Form(form) => {
+            ContentType::File(body_file) => println!("body: {} ({})\n{}", &request.body.to_string(), request.body.to_content_type(), body_file.path),
+            ContentType::Multipart(parts) => {
+                println!("body: {}", &request.body.to_string());
+                print_multipart_parts(parts, Some("\t"));
+            },
+            ContentType::Form(form) => {
                 println!("body: {}", &request.body.to_string());
                 print_key_value_vector(form, Some("\t"));
             },
-            ContentType::Raw(body) | ContentType::Json(body) | ContentType::Xml(body) | ContentType::Html(body) | ContentType::Javascript(body) => {
+            ContentType::Raw(body) | ContentType::Json(body) | ContentType::Xml(body) | ContentType::Html(body) | ContentType::Javascript(body) | ContentType::Protobuf(body) | ContentType::MessagePack(body) | ContentType::Cbor(body) => {
                 println!("body: {}{body}", &request.body.to_string());
             }
         }
@@ -42,6 +77,53 @@ impl App<'_> {
             (true, true) => println!("scripts:\n\tpre and post-request"),
         }
 
+        if let Some(response_processor) = &request.scripts.response_processor {
+            println!("response processor: {response_processor}");
+        }
+
+        if let Some(monitor) = &request.monitor {
+            println!("monitor: every {}", monitor.every);
+        }
+
+        if let Some(response_schema) = &request.response_schema {
+            println!("response schema: {}", response_schema.to_summary());
+        }
+
+        if !request.assertions.is_empty() {
+            println!("assertions:");
+
+            for assertion in &request.assertions {
+                println!("\t{}", assertion.to_test_name());
+            }
+        }
+
+        if let Some(protobuf_schema) = &request.protobuf {
+            println!("protobuf: {}", protobuf_schema.to_summary());
+        }
+
+        if let Some(soap) = &request.soap {
+            println!("soap: {}", soap.to_summary());
+        }
+
+        if let Some(pagination) = &request.pagination {
+            println!("pagination: {}", pagination.to_summary());
+        }
+
+        if let Some(connection_pool) = &request.settings.connection_pool {
+            println!("connection pool: {}", connection_pool.to_summary());
+        }
+
+        if let Some(http2) = &request.settings.http2 {
+            println!("http/2: {}", http2.to_summary());
+        }
+
+        if let Some(snapshot) = &request.snapshot {
+            match snapshot.ignore.is_empty() {
+                true => println!("snapshot: recorded"),
+                false => println!("snapshot: recorded, ignoring {}", snapshot.ignore.join(", "))
+            }
+        }
+
         Ok(())
     }
 }
\ No newline at end of file
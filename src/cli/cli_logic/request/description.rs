@@ -0,0 +1,31 @@
+use crate::app::app::App;
+use crate::app::files::utils::edit_in_external_editor;
+
+impl App<'_> {
+    pub fn cli_print_request_description(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let selected_request = local_selected_request.read();
+
+            if let Some(description) = &selected_request.description {
+                println!("{description}");
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_edit_request_description(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let description = {
+            let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+            let selected_request = local_selected_request.read();
+
+            selected_request.description.clone().unwrap_or_default()
+        };
+
+        let edited_description = edit_in_external_editor(&description, "md")?;
+
+        self.modify_request_description(collection_index, request_index, Some(edited_description))
+    }
+}
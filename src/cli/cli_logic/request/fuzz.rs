@@ -0,0 +1,70 @@
+use std::collections::BTreeMap;
+
+use crate::app::app::App;
+use crate::app::business_logic::fuzz::{generate_fuzz_cases, send_fuzz_case};
+use crate::cli::commands::request_commands::fuzz::FuzzCommand;
+use crate::models::fuzz::{payload_to_summary, FuzzGenerator, FuzzTarget};
+
+impl App<'_> {
+    pub async fn cli_fuzz_request(&mut self, collection_index: usize, request_index: usize, fuzz_command: &FuzzCommand) -> anyhow::Result<()> {
+        if let Some(env_name) = &fuzz_command.env {
+            self.selected_environment = self.find_environment(env_name)?;
+        }
+
+        let targets = match fuzz_command.targets.is_empty() {
+            true => FuzzTarget::all(),
+            false => fuzz_command.targets.clone()
+        };
+
+        let generators = match fuzz_command.generators.is_empty() {
+            true => FuzzGenerator::all(),
+            false => fuzz_command.generators.clone()
+        };
+
+        let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let request = local_request.read().clone();
+
+        let fuzz_cases = generate_fuzz_cases(&request, &targets, &generators, &fuzz_command.fields);
+        let total_cases = fuzz_cases.len();
+
+        if total_cases == 0 {
+            println!("Nothing to fuzz, the request has no matching params, headers or body fields");
+            return Ok(());
+        }
+
+        // Status code => cases that got this status
+        let mut results: BTreeMap<String, Vec<String>> = BTreeMap::new();
+
+        for (index, fuzz_case) in fuzz_cases.into_iter().enumerate() {
+            eprint!("\rSending {}/{total_cases}", index + 1);
+
+            let status_code = match self.prepare_request(&fuzz_case.request).await {
+                Ok((prepared_request, _)) => send_fuzz_case(prepared_request).await,
+                Err(prepare_request_error) => prepare_request_error.to_string()
+            };
+
+            let case_summary = format!("{} [{}] {}", fuzz_case.location, fuzz_case.generator, payload_to_summary(&fuzz_case.payload));
+
+            results
+                .entry(status_code)
+                .or_default()
+                .push(case_summary);
+        }
+
+        eprintln!();
+
+        for (status_code, cases) in results {
+            println!("{status_code} ({})", cases.len());
+
+            if status_code.starts_with('2') && !fuzz_command.show_successes {
+                continue;
+            }
+
+            for case in cases {
+                println!("\t{case}");
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,31 @@
+use crate::app::app::App;
+use crate::cli::commands::request_commands::http2::RequestHttp2Command;
+use crate::models::http2::Http2Options;
+
+impl App<'_> {
+    pub fn cli_request_http2(&mut self, collection_index: usize, request_index: usize, http2_command: &RequestHttp2Command) -> anyhow::Result<()> {
+        let http2 = match http2_command {
+            RequestHttp2Command::Get => {
+                let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+                let selected_request = local_selected_request.read();
+
+                match &selected_request.settings.http2 {
+                    None => println!("Config HTTP/2: {}", self.config.get_http2_options().to_summary()),
+                    Some(http2) => println!("{}", http2.to_summary())
+                }
+
+                return Ok(());
+            },
+            RequestHttp2Command::Set { prior_knowledge, adaptive_window, max_frame_size, initial_stream_window_size, initial_connection_window_size } => Some(Http2Options {
+                prior_knowledge: *prior_knowledge,
+                adaptive_window: *adaptive_window,
+                max_frame_size: *max_frame_size,
+                initial_stream_window_size: *initial_stream_window_size,
+                initial_connection_window_size: *initial_connection_window_size,
+            }),
+            RequestHttp2Command::Remove => None
+        };
+
+        self.modify_request_http2(collection_index, request_index, http2)
+    }
+}
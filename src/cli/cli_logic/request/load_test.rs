@@ -0,0 +1,61 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use anyhow::anyhow;
+use parking_lot::RwLock;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::app::App;
+use crate::app::business_logic::load_test::run_load_test;
+use crate::cli::commands::request_commands::load_test::LoadTestCommand;
+use crate::models::load_test::{LoadTestLimit, LoadTestStats};
+use crate::models::monitor::parse_interval;
+
+impl App<'_> {
+    pub async fn cli_load_test_request(&mut self, collection_index: usize, request_index: usize, load_test_command: &LoadTestCommand) -> anyhow::Result<()> {
+        if let Some(env_name) = &load_test_command.env {
+            self.selected_environment = self.find_environment(env_name)?;
+        }
+
+        let mut options = self.config.get_load_test_options();
+
+        if let Some(concurrency) = load_test_command.concurrency {
+            options.concurrency = concurrency;
+        }
+
+        if let Some(iterations) = load_test_command.iterations {
+            options.limit = LoadTestLimit::Iterations(iterations);
+        }
+
+        if let Some(duration) = &load_test_command.duration {
+            options.limit = LoadTestLimit::Duration(parse_interval(duration)?);
+        }
+
+        let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let request = local_request.read().clone();
+
+        let (prepared_request, _) = self.prepare_request(&request).await?;
+
+        let stats = Arc::new(RwLock::new(LoadTestStats::default()));
+        let local_stats = Arc::clone(&stats);
+
+        let load_test = tokio::spawn(run_load_test(prepared_request, options, local_stats, CancellationToken::new()));
+
+        while !load_test.is_finished() {
+            tokio::time::sleep(Duration::from_secs(1)).await;
+
+            let stats = stats.read();
+            eprintln!("{} requests, {:.2} rps", stats.total_requests(), stats.requests_per_second());
+        }
+
+        if let Err(error) = load_test.await? {
+            return Err(anyhow!(error));
+        }
+
+        for line in stats.read().to_report_lines() {
+            println!("{line}");
+        }
+
+        Ok(())
+    }
+}
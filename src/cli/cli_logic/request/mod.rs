@@ -1,11 +1,28 @@
 pub(super) mod new;
+mod template;
 mod describe;
 mod url;
+mod description;
 mod method;
 mod auth;
 mod scripts;
-mod send;
+pub(super) mod send;
 mod settings;
 mod body;
 mod query_params;
 mod header;
+mod cookie;
+mod monitor;
+mod load_test;
+mod fuzz;
+mod schema;
+mod snapshot;
+mod protobuf;
+mod soap;
+mod pagination;
+pub mod connection_pool;
+mod http2;
+mod assertion;
+mod tags;
+mod dependency;
+mod think_time;
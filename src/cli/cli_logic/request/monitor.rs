@@ -0,0 +1,30 @@
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::models::monitor::RequestMonitor;
+
+impl App<'_> {
+    pub fn cli_print_request_monitor(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
+        let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+        {
+            let selected_request = local_selected_request.read();
+
+            match &selected_request.monitor {
+                None => println!("No monitor"),
+                Some(monitor) => println!("every {}", monitor.every)
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn cli_set_request_monitor(&mut self, collection_index: usize, request_index: usize, every: String) -> anyhow::Result<()> {
+        let monitor = match RequestMonitor::new(every) {
+            Ok(monitor) => monitor,
+            Err(error) => return Err(anyhow!(error))
+        };
+
+        self.modify_request_monitor(collection_index, request_index, Some(monitor))
+    }
+}
@@ -1,17 +1,30 @@
+use std::sync::atomic::AtomicUsize;
+use std::sync::Arc;
+
 use tokio_util::sync::CancellationToken;
 use crate::app::app::App;
 use crate::cli::commands::request_commands::new::{AuthArgs, BodyArgs, NewRequestCommand};
-use crate::models::auth::Auth;
-use crate::models::body::ContentType;
+use crate::models::auth::{Auth, HmacAlgorithm, OAuth1Placement, OAuth1SignatureMethod, SignatureEncoding};
+use crate::models::body::{BodyFile, ContentType, MultipartPart, UploadProgress};
+use crate::models::cache_validators::CacheValidators;
 use crate::models::request::{KeyValue, Request, DEFAULT_HEADERS};
-use crate::models::response::RequestResponse;
+use crate::models::response::{RequestResponse, ResponseStream};
 use crate::models::scripts::RequestScripts;
 use crate::models::settings::RequestSettings;
+use crate::models::template::RequestTemplate;
 
 impl App<'_> {
-    pub fn cli_new_request(&mut self, collection_slash_request: (String, String), new_request_command: NewRequestCommand) -> anyhow::Result<()> {
+    pub fn cli_new_request(&mut self, collection_slash_request: (String, String), template_name: Option<&str>, new_request_command: NewRequestCommand) -> anyhow::Result<()> {
         let collection_index = self.find_collection(&collection_slash_request.0)?;
-        let new_request = create_request_from_new_request_command(collection_slash_request.1.trim().to_string(), new_request_command);
+        let request_name = collection_slash_request.1.trim().to_string();
+
+        let new_request = match template_name {
+            None => create_request_from_new_request_command(request_name, new_request_command),
+            Some(template_name) => {
+                let template_index = self.find_template(template_name)?;
+                create_request_from_template(request_name, &self.request_templates[template_index], new_request_command)
+            }
+        };
         
         self.new_request(collection_index, new_request)?;
         
@@ -20,6 +33,7 @@ impl App<'_> {
 }
 
 pub fn create_request_from_new_request_command(request_name: String, new_request_command: NewRequestCommand) -> Request {
+    let settings = apply_settings_options(RequestSettings::default(), &new_request_command);
     let params = string_array_to_key_value_array(new_request_command.add_param);
     let auth = get_auth_from_auth_args(new_request_command.auth);
     let headers = string_array_to_key_value_array(new_request_command.add_header);
@@ -32,28 +46,114 @@ pub fn create_request_from_new_request_command(request_name: String, new_request
 
     Request {
         name: request_name,
+        description: None,
+        tags: vec![],
+        skipped: false,
+        depends_on: vec![],
+        think_time: None,
         url: new_request_command.url,
         method: new_request_command.method,
         params,
         auth,
         headers: vec![base_headers, headers].concat(),
+        cookies: vec![],
         body,
         scripts: RequestScripts {
             pre_request_script: new_request_command.pre_request_script,
             post_request_script: new_request_command.post_request_script,
+            response_processor: new_request_command.response_processor,
         },
-        settings: RequestSettings {
-            use_config_proxy: !new_request_command.no_proxy,
-            allow_redirects: !new_request_command.no_redirects,
-            store_received_cookies: !new_request_command.no_cookies,
-            pretty_print_response_content: !new_request_command.no_pretty,
-            accept_invalid_certs: new_request_command.accept_invalid_certs,
-            accept_invalid_hostnames: new_request_command.accept_invalid_hostnames,
-        },
+        settings,
+        monitor: None,
+        response_schema: None,
+        snapshot: None,
+        assertions: vec![],
+        protobuf: None,
+        soap: None,
+        pagination: None,
         response: RequestResponse::default(),
         is_pending: false,
+        queued_sends: Arc::new(AtomicUsize::new(0)),
         cancellation_token: CancellationToken::new(),
+        upload_progress: UploadProgress::default(),
+        response_stream: ResponseStream::default(),
+        response_output: None,
+        cache_validators: CacheValidators::default(),
+        watch: None,
+    }
+}
+
+/// Template fields are kept unless the command overrides them, params and headers are appended
+fn create_request_from_template(request_name: String, template: &RequestTemplate, new_request_command: NewRequestCommand) -> Request {
+    let mut request = template.to_request(request_name);
+
+    if !new_request_command.url.is_empty() {
+        request.url = new_request_command.url.clone();
+    }
+
+    request.params.extend(string_array_to_key_value_array(new_request_command.add_param.clone()));
+    request.headers.extend(string_array_to_key_value_array(new_request_command.add_header.clone()));
+
+    if new_request_command.pre_request_script.is_some() {
+        request.scripts.pre_request_script = new_request_command.pre_request_script.clone();
+    }
+
+    if new_request_command.post_request_script.is_some() {
+        request.scripts.post_request_script = new_request_command.post_request_script.clone();
+    }
+
+    if new_request_command.response_processor.is_some() {
+        request.scripts.response_processor = new_request_command.response_processor.clone();
+    }
+
+    request.settings = apply_settings_options(request.settings, &new_request_command);
+
+    return request;
+}
+
+/// Only the options differing from their default value are applied
+fn apply_settings_options(mut settings: RequestSettings, new_request_command: &NewRequestCommand) -> RequestSettings {
+    if new_request_command.no_proxy {
+        settings.use_config_proxy = false;
+    }
+
+    if new_request_command.no_redirects {
+        settings.allow_redirects = false;
+    }
+
+    if new_request_command.no_cookies {
+        settings.store_received_cookies = false;
     }
+
+    if new_request_command.no_pretty {
+        settings.pretty_print_response_content = false;
+    }
+
+    if new_request_command.accept_invalid_certs {
+        settings.accept_invalid_certs = true;
+    }
+
+    if new_request_command.accept_invalid_hostnames {
+        settings.accept_invalid_hostnames = true;
+    }
+
+    if new_request_command.notify {
+        settings.notify_on_completion = true;
+    }
+
+    if new_request_command.max_redirects != RequestSettings::default().max_redirects {
+        settings.max_redirects = new_request_command.max_redirects;
+    }
+
+    if new_request_command.no_redirect_body {
+        settings.resend_body_on_redirect = false;
+    }
+
+    if new_request_command.keep_redirect_auth {
+        settings.strip_authorization_on_redirect = false;
+    }
+
+    return settings;
 }
 
 fn string_array_to_key_value_array(string_array: Vec<String>) -> Vec<KeyValue> {
@@ -81,6 +181,38 @@ fn get_auth_from_auth_args(auth_args: AuthArgs) -> Auth {
             token: auth_args.auth_bearer_token[0].clone()
         };
     }
+    else if !auth_args.auth_hmac.is_empty() {
+        return Auth::HmacSignature {
+            secret: auth_args.auth_hmac[0].clone(),
+            string_to_sign: auth_args.auth_hmac[1].clone(),
+            header: auth_args.auth_hmac[2].clone(),
+            algorithm: HmacAlgorithm::default(),
+            encoding: SignatureEncoding::default(),
+        };
+    }
+    else if !auth_args.auth_ntlm.is_empty() {
+        return Auth::Ntlm {
+            username: auth_args.auth_ntlm[0].clone(),
+            password: auth_args.auth_ntlm[1].clone(),
+            domain: auth_args.auth_ntlm.get(2).cloned().unwrap_or_default()
+        };
+    }
+    else if !auth_args.auth_oauth1.is_empty() {
+        return Auth::OAuth1 {
+            consumer_key: auth_args.auth_oauth1[0].clone(),
+            consumer_secret: auth_args.auth_oauth1[1].clone(),
+            token: auth_args.auth_oauth1.get(2).cloned().unwrap_or_default(),
+            token_secret: auth_args.auth_oauth1.get(3).cloned().unwrap_or_default(),
+            signature_method: OAuth1SignatureMethod::default(),
+            placement: OAuth1Placement::default(),
+        };
+    }
+    else if !auth_args.auth_plugin.is_empty() {
+        return Auth::Plugin {
+            plugin: auth_args.auth_plugin[0].clone(),
+            config: auth_args.auth_plugin.get(1).cloned().unwrap_or_default()
+        };
+    }
     else {
         return Auth::NoAuth;
     }
@@ -88,14 +220,18 @@ fn get_auth_from_auth_args(auth_args: AuthArgs) -> Auth {
 
 fn get_content_type_from_body_args(body_args: BodyArgs) -> ContentType {
     if let Some(file_path) = &body_args.body_file {
-        return ContentType::File(file_path.clone());
+        return ContentType::File(BodyFile { path: file_path.clone(), content_type: None });
     }
     else if !body_args.add_body_multipart.is_empty() {
-        let multipart_key_values = string_array_to_key_value_array(body_args.add_body_multipart);
-        return ContentType::Multipart(multipart_key_values);
+        let multipart_parts = string_array_to_key_value_array(body_args.add_body_multipart)
+            .into_iter()
+            .map(MultipartPart::from_key_value)
+            .collect();
+
+        return ContentType::Multipart(multipart_parts);
     }
     else if !body_args.add_body_form.is_empty() {
-        let form_key_values = string_array_to_key_value_array(body_args.add_body_multipart);
+        let form_key_values = string_array_to_key_value_array(body_args.add_body_form);
         return ContentType::Form(form_key_values);
     }
     else if let Some(raw) = &body_args.body_raw {
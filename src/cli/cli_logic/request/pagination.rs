@@ -0,0 +1,77 @@
+use std::fs;
+
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::pagination::PaginationPage;
+use crate::cli::commands::request_commands::pagination::{PaginateCommand, RequestPaginationCommand};
+use crate::models::pagination::RequestPagination;
+
+impl App<'_> {
+    pub fn cli_request_pagination(&mut self, collection_index: usize, request_index: usize, pagination_command: &RequestPaginationCommand) -> anyhow::Result<()> {
+        let pagination = match pagination_command {
+            RequestPaginationCommand::Get => {
+                let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+                let selected_request = local_selected_request.read();
+
+                match &selected_request.pagination {
+                    None => println!("No pagination"),
+                    Some(pagination) => println!("{}", pagination.to_summary())
+                }
+
+                return Ok(());
+            },
+            RequestPaginationCommand::Set { strategy, next_link_path, param, start, page_size, items_path, max_pages } => Some(RequestPagination {
+                strategy: *strategy,
+                next_link_path: next_link_path.clone(),
+                param: param.clone(),
+                start: *start,
+                page_size: *page_size,
+                items_path: items_path.clone(),
+                max_pages: *max_pages,
+            }),
+            RequestPaginationCommand::Remove => None
+        };
+
+        self.modify_request_pagination(collection_index, request_index, pagination)
+    }
+
+    /// The pages are printed on the standard error, so that the standard output only holds the items
+    pub async fn cli_paginate_request(&mut self, collection_index: usize, request_index: usize, paginate_command: &PaginateCommand) -> anyhow::Result<()> {
+        if let Some(env_name) = &paginate_command.env {
+            self.selected_environment = self.find_environment(env_name)?;
+        }
+
+        if let Some(max_pages) = paginate_command.max_pages {
+            let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+            let mut request = local_request.write();
+
+            if let Some(pagination) = &mut request.pagination {
+                pagination.max_pages = Some(max_pages);
+            }
+        }
+
+        let print_page = |page: &PaginationPage| {
+            let items_count = match page.items_count {
+                None => String::from("no items array"),
+                Some(items_count) => format!("{items_count} items")
+            };
+
+            eprintln!("Page {} ({}): {}, {items_count}", page.number, page.target, page.status_code.as_deref().unwrap_or("no response"));
+        };
+
+        let pagination_result = self.follow_pagination(collection_index, request_index, print_page).await?;
+
+        eprintln!("{} items in {} pages. {}", pagination_result.items.len(), pagination_result.pages.len(), pagination_result.stop_reason);
+
+        let items = serde_json::to_string_pretty(&pagination_result.items)?;
+
+        match &paginate_command.output {
+            None => println!("{items}"),
+            Some(output) => fs::write(output, items).map_err(|error| anyhow!("Could not write \"{}\": {error}", output.display()))?
+        }
+
+        Ok(())
+    }
+}
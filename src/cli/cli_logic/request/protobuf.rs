@@ -0,0 +1,29 @@
+use crate::app::app::App;
+use crate::cli::commands::request_commands::protobuf::RequestProtobufCommand;
+use crate::models::protobuf::ProtobufSchema;
+
+impl App<'_> {
+    pub fn cli_request_protobuf(&mut self, collection_index: usize, request_index: usize, protobuf_command: &RequestProtobufCommand) -> anyhow::Result<()> {
+        let protobuf_schema = match protobuf_command {
+            RequestProtobufCommand::Get => {
+                let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+                let selected_request = local_selected_request.read();
+
+                match &selected_request.protobuf {
+                    None => println!("No protobuf schema"),
+                    Some(protobuf_schema) => println!("{}", protobuf_schema.to_summary())
+                }
+
+                return Ok(());
+            },
+            RequestProtobufCommand::Set { files, request_message, response_message } => Some(ProtobufSchema {
+                files: files.clone(),
+                request_message: request_message.clone(),
+                response_message: response_message.clone(),
+            }),
+            RequestProtobufCommand::Remove => None
+        };
+
+        self.modify_request_protobuf_schema(collection_index, request_index, protobuf_schema)
+    }
+}
@@ -0,0 +1,30 @@
+use crate::app::app::App;
+use crate::cli::commands::request_commands::schema::RequestSchemaCommand;
+use crate::models::schema::ResponseSchema;
+
+impl App<'_> {
+    pub fn cli_request_schema(&mut self, collection_index: usize, request_index: usize, schema_command: &RequestSchemaCommand) -> anyhow::Result<()> {
+        let response_schema = match schema_command {
+            RequestSchemaCommand::Get => {
+                let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+                let selected_request = local_selected_request.read();
+
+                match &selected_request.response_schema {
+                    None => println!("No response schema"),
+                    Some(response_schema) => println!("{}", response_schema.to_summary())
+                }
+
+                return Ok(());
+            },
+            RequestSchemaCommand::Set { schema } => Some(ResponseSchema::Inline(schema.clone())),
+            RequestSchemaCommand::SetFile { path } => Some(ResponseSchema::File(path.clone())),
+            RequestSchemaCommand::SetOpenapi { file, pointer } => Some(ResponseSchema::OpenApi {
+                file: file.clone(),
+                pointer: pointer.clone()
+            }),
+            RequestSchemaCommand::Remove => None
+        };
+
+        self.modify_request_response_schema(collection_index, request_index, response_schema)
+    }
+}
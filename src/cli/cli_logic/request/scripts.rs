@@ -11,6 +11,7 @@ impl App<'_> {
             let script = match script_type {
                 ScriptType::Pre => &selected_request.scripts.pre_request_script,
                 ScriptType::Post => &selected_request.scripts.post_request_script,
+                ScriptType::Processor => &selected_request.scripts.response_processor,
             };
 
             let data = match script {
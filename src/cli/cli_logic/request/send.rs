@@ -1,68 +1,869 @@
+use std::collections::VecDeque;
+use std::fs;
+use std::io::{stdout, Write};
 use std::sync::Arc;
+use std::time::{Duration, Instant};
+use anyhow::anyhow;
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use clap::ValueEnum;
 use parking_lot::RwLock;
+use reqwest::header::CONTENT_TYPE;
+use reqwest_middleware::RequestBuilder;
+use serde_json::{json, Value};
+use tokio::task::JoinHandle;
 
 use crate::app::app::App;
+use crate::app::business_logic::notification::send_notification;
+use crate::app::business_logic::request::cors::{send_cors_preflight, CorsSimulation};
 use crate::app::business_logic::request::send::send_request;
-use crate::cli::commands::request_commands::send::SendCommand;
+use crate::app::business_logic::request::throttle::{get_exponential_backoff, Throttle, MAX_BACKOFF};
+use crate::cli::commands::request_commands::send::{SendCommand, SendOutputFormat};
+use crate::cli::commands::send::SendRequestCommand;
+use crate::cli::exit_code::{SendFailure, SendFailureKind};
+use crate::cli::utils::picker::pick;
+use crate::models::assertion::ResponseAssertion;
+use crate::models::cache_validators::CacheValidators;
+use crate::models::collection_hooks::HookKind;
+use crate::models::dataset::{load_dataset, DataRow};
+use crate::models::dependency::ExecutionPlan;
+use crate::models::environment::Environment;
+use crate::models::rate_limit::RateLimitOptions;
 use crate::models::request::Request;
-use crate::models::response::ResponseContent;
+use crate::models::response::{RequestResponse, RequestResult, ResponseContent, TestResult};
+use crate::models::run_report::{get_response_excerpt, RunReport, RunReportEntry};
+use crate::models::think_time::ThinkTime;
+
+/// The response, the console output, the CORS simulation and the time spent sending
+type SendingOutput = (RequestResponse, String, Option<CorsSimulation>, Duration);
+
+/// Request of a run sent in the background, its output is printed once the previous ones have been
+struct PendingSend {
+    local_request: Arc<RwLock<Request>>,
+    request_name: String,
+    /// Row the request was prepared with, bound again for the login retry
+    data_row: Option<DataRow>,
+    console_output: String,
+    cache_validators: CacheValidators,
+    should_notify: bool,
+    should_store_cookies: bool,
+    request_start: Instant,
+    sending: JoinHandle<anyhow::Result<SendingOutput>>,
+}
+
+/// Request of a run waiting for its output to be printed
+struct QueuedSend {
+    row_number: Option<usize>,
+    request_name: String,
+    /// Printed before the output of the first request of each dataset row
+    row_header: Option<String>,
+    pending_send: anyhow::Result<PendingSend>,
+}
+
+/// Outcome of a request of a run
+struct SendResult {
+    row_number: Option<usize>,
+    request_name: String,
+    result: anyhow::Result<SendOutcome>,
+}
+
+impl SendResult {
+    /// A response with a 2xx status was received
+    fn is_success(&self) -> bool {
+        return match &self.result {
+            Ok(send_outcome) => send_outcome.is_success(),
+            Err(_) => false
+        };
+    }
+}
+
+/// Failure of a dataset row, a None kind is any other error
+type RowFailure = (Option<SendFailureKind>, String);
+
+/// What a response of a run tells about the exit code
+struct SendOutcome {
+    /// None when the request was not sent
+    result: Option<RequestResult>,
+    /// Message of the error, when the request could not reach the server
+    network_error: Option<String>,
+    tests: Vec<TestResult>,
+    /// Results of the request assertions, among the tests
+    assertions: Vec<TestResult>,
+    duration: Duration,
+    /// Start of the received body, written to the reports
+    response_excerpt: Option<String>,
+}
+
+impl SendOutcome {
+    fn is_success(&self) -> bool {
+        return self.result.as_ref().is_some_and(RequestResult::is_success);
+    }
+
+    fn is_failure(&self) -> bool {
+        return self.result.as_ref().map_or(true, RequestResult::is_failure);
+    }
+
+    /// e.g. "200 OK" or "CANCELED"
+    fn get_status_text(&self) -> Option<String> {
+        return self.result.as_ref().map(|result| result.to_string());
+    }
+}
+
+/// What the collection adds to its runs, nothing when a request is sent on its own
+#[derive(Default)]
+pub struct CollectionRun {
+    hooks: RunHooks,
+    /// Pause after the requests without any think time of their own
+    think_time: Option<ThinkTime>,
+}
+
+/// Hook requests of the collection, sent around the requests of a run
+#[derive(Default)]
+struct RunHooks(Vec<(HookKind, Arc<RwLock<Request>>)>);
+
+impl RunHooks {
+    fn get(&self, hook_kind: HookKind) -> Option<&Arc<RwLock<Request>>> {
+        return self.0
+            .iter()
+            .find(|(kind, _)| *kind == hook_kind)
+            .map(|(_, hook_request)| hook_request);
+    }
+}
+
+/// Results of an assertion during a run, the requests sharing it are counted together
+struct AssertionTally {
+    test_name: String,
+    checks: usize,
+    /// Once per failed check
+    failed_requests: Vec<String>,
+}
 
 impl App<'_> {
-    pub async fn cli_send_request(&mut self, collection_index: usize, request_index: usize, send_command: &SendCommand) -> anyhow::Result<()> {
+    /// Send the given request, or the one picked among the requests of all the collections
+    pub async fn cli_send_picked_request(&mut self, send_request_command: &SendRequestCommand) -> anyhow::Result<()> {
+        let (collection_index, request_index) = match &send_request_command.collection_slash_request {
+            Some((collection_name, request_name)) => self.find_collection_slash_request(collection_name, request_name)?,
+            None => {
+                let mut request_indexes: Vec<(usize, usize)> = vec![];
+                let mut choices: Vec<String> = vec![];
+
+                for (collection_index, collection) in self.collections.iter().enumerate() {
+                    for (request_index, request) in collection.requests.iter().enumerate() {
+                        request_indexes.push((collection_index, request_index));
+                        choices.push(format!("{}/{}", collection.name, request.read().name));
+                    }
+                }
+
+                if choices.is_empty() {
+                    return Err(anyhow!("No request to send"));
+                }
+
+                match pick("Send: ", &choices)? {
+                    None => return Err(anyhow!("No request picked")),
+                    Some(choice_index) => {
+                        eprintln!("Sending \"{}\"", choices[choice_index]);
+                        request_indexes[choice_index]
+                    }
+                }
+            }
+        };
+
+        self.cli_send_request(collection_index, request_index, send_request_command.body.as_deref(), &send_request_command.send_command).await
+    }
+
+    /// The body is sent instead of the request one when given, "-" reading it from the standard input
+    pub async fn cli_send_request(&mut self, collection_index: usize, request_index: usize, body: Option<&str>, send_command: &SendCommand) -> anyhow::Result<()> {
         let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let run_name = self.collections[collection_index].name.clone();
 
-        self.local_send_request(&send_command, local_request).await?;
+        // Swapped in place rather than sending a copy, which the login retry would not find in the collection
+        let request_body_and_headers = match body {
+            None => None,
+            Some(body) => {
+                let body_text = match body {
+                    "-" => std::io::read_to_string(std::io::stdin()).map_err(|error| anyhow!("Could not read the body from the standard input: {error}"))?,
+                    body => body.to_string()
+                };
 
-        Ok(())
+                let mut request = local_request.write();
+                let request_body_and_headers = (request.body.clone(), request.headers.clone());
+
+                request.body = request.body.with_text(body_text);
+
+                // e.g. a JSON body sent by a request without any
+                if request.body.to_content_type() != request_body_and_headers.0.to_content_type() {
+                    let content_type = request.body.to_content_type();
+                    request.modify_or_create_header(CONTENT_TYPE.as_str(), &content_type);
+                }
+
+                Some(request_body_and_headers)
+            }
+        };
+
+        let result = self.local_send_requests(&run_name, send_command, vec![local_request.clone()], CollectionRun::default()).await;
+
+        if let Some((request_body, request_headers)) = request_body_and_headers {
+            let mut request = local_request.write();
+            request.body = request_body;
+            request.headers = request_headers;
+        }
+
+        result
     }
 
-    pub async fn cli_send_collection(&mut self, collection_name: &str, send_command: &SendCommand) -> anyhow::Result<()> {
+    /// Only the requests having one of the tags are sent when some are given, along with the requests they depend on.
+    /// The skipped requests and the ones depending on them never are. The requests are sent after their dependencies,
+    /// and the hook requests of the collection around them
+    pub async fn cli_send_collection(&mut self, collection_name: &str, tags: &[String], from: Option<&str>, send_command: &SendCommand) -> anyhow::Result<()> {
         let collection_index = self.find_collection(collection_name)?;
+
+        let first_request_index = match from {
+            None => 0,
+            Some(request_name) => self.find_collection_slash_request(collection_name, request_name)?.1
+        };
+
         let collection = &self.collections[collection_index];
+        let execution_plan = ExecutionPlan::new(&collection.requests)?;
+
+        let mut is_selected: Vec<bool> = collection.requests
+            .iter()
+            .enumerate()
+            .map(|(request_index, request)| request_index >= first_request_index && (tags.is_empty() || collection.is_request_tagged(&request.read(), tags)))
+            .collect();
+
+        if !is_selected.contains(&true) && !tags.is_empty() {
+            return Err(anyhow!("No request of the collection to send is tagged {}", tags.join(" or ")));
+        }
+
+        let names: Vec<String> = collection.requests
+            .iter()
+            .map(|request| request.read().name.clone())
+            .collect();
+
+        // The dependents come after their dependencies in the execution order, whose reverse selects the dependencies of the dependencies too
+        for request_index in execution_plan.order.iter().rev() {
+            if !is_selected[*request_index] {
+                continue;
+            }
+
+            for dependency in &collection.requests[*request_index].read().depends_on {
+                let dependency_index = names.iter().position(|name| name == dependency).unwrap_or_default();
+
+                if !is_selected[dependency_index] {
+                    eprintln!("Also sending \"{dependency}\", which \"{}\" depends on", names[*request_index]);
+                    is_selected[dependency_index] = true;
+                }
+            }
+        }
+
+        let mut run_hooks = RunHooks::default();
 
+        for hook_kind in HookKind::value_variants() {
+            let hook_request_name = match collection.hooks.get(*hook_kind) {
+                None => continue,
+                Some(hook_request_name) => hook_request_name
+            };
+
+            let hook_index = match names.iter().position(|name| name == hook_request_name) {
+                None => return Err(anyhow!("The {hook_kind} hook \"{hook_request_name}\" is not a request of the collection")),
+                Some(hook_index) => hook_index
+            };
+
+            // Only sent as a hook
+            is_selected[hook_index] = false;
+
+            let hook_request = &collection.requests[hook_index];
+
+            match hook_request.read().skipped {
+                true => eprintln!("Skipping the {hook_kind} hook \"{hook_request_name}\""),
+                false => run_hooks.0.push((*hook_kind, hook_request.clone()))
+            }
+        }
+
+        // Sent concurrently, the requests of a stage do not wait for the dependencies of the next stages
+        let execution_order = match self.get_send_rate_limit_options(send_command).concurrency > 1 {
+            true => execution_plan.stages.concat(),
+            false => execution_plan.order
+        };
+
+        let mut is_skipped: Vec<bool> = vec![false; collection.requests.len()];
         let mut requests: Vec<Arc<RwLock<Request>>> = vec![];
 
-        for request in &collection.requests {
-            let local_request = request.clone();
+        for request_index in execution_order {
+            if !is_selected[request_index] {
+                continue;
+            }
+
+            let request = collection.requests[request_index].read();
+
+            let skipped_dependency = request.depends_on
+                .iter()
+                .find(|dependency| names.iter().position(|name| name == *dependency).is_some_and(|dependency_index| is_skipped[dependency_index]));
+
+            if request.skipped {
+                eprintln!("Skipping \"{}\"", request.name);
+                is_skipped[request_index] = true;
+                continue;
+            }
+
+            if let Some(skipped_dependency) = skipped_dependency {
+                eprintln!("Skipping \"{}\", the request \"{skipped_dependency}\" it depends on is skipped", request.name);
+                is_skipped[request_index] = true;
+                continue;
+            }
+
+            let local_request = collection.requests[request_index].clone();
             requests.push(local_request);
         }
 
-        for request in requests {
-            self.local_send_request(&send_command, request).await?
-        }
+        let collection_run = CollectionRun {
+            hooks: run_hooks,
+            think_time: collection.think_time,
+        };
+
+        self.local_send_requests(collection_name, send_command, requests, collection_run).await?;
 
         Ok(())
     }
 
-    pub async fn local_send_request(&mut self, send_command: &SendCommand, local_request: Arc<RwLock<Request>>) -> anyhow::Result<()> {
+    /// Send the requests once, or once per dataset row when a data file is given.
+    /// The run name titles the reports, e.g. the collection name
+    pub async fn local_send_requests(&mut self, run_name: &str, send_command: &SendCommand, requests: Vec<Arc<RwLock<Request>>>, collection_run: CollectionRun) -> anyhow::Result<()> {
+        let run_report = RunReport::new(run_name.to_string());
+
+        let data_path = match &send_command.data {
+            None => {
+                let mut failures: Vec<(SendFailureKind, String)> = vec![];
+                let mut assertion_tallies: Vec<AssertionTally> = vec![];
+
+                let send_results = self.send_run(send_command, &requests, &collection_run, None).await;
+
+                // Written before the run stops at its first error, which is then reported too
+                write_run_reports(send_command, run_report, &send_results)?;
+
+                for send_result in send_results {
+                    let send_outcome = send_result.result?;
+                    failures.extend(get_send_failures(send_command, &send_result.request_name, &send_outcome));
+                    tally_assertions(&mut assertion_tallies, &send_result.request_name, &send_outcome.assertions);
+                }
+
+                print_assertion_summary(send_command, &assertion_tallies);
+
+                // Fails the run so that regression suites can rely on the exit code
+                return check_run_failures(failures);
+            },
+            Some(data_path) => data_path
+        };
+
+        let rows = load_dataset(data_path)?;
+        let mut row_results: Vec<(usize, Vec<RowFailure>)> = (1..=rows.len()).map(|row_number| (row_number, vec![])).collect();
+        let mut assertion_tallies: Vec<AssertionTally> = vec![];
+
+        let send_results = self.send_run(send_command, &requests, &collection_run, Some(rows)).await;
+
+        write_run_reports(send_command, run_report, &send_results)?;
+
+        for send_result in send_results {
+            let request_name = send_result.request_name;
+
+            // Always given with a dataset
+            let failures = &mut row_results[send_result.row_number.unwrap_or(1) - 1].1;
+
+            if let Ok(send_outcome) = &send_result.result {
+                tally_assertions(&mut assertion_tallies, &request_name, &send_outcome.assertions);
+            }
+
+            match send_result.result {
+                Ok(send_outcome) if send_outcome.is_failure() && send_outcome.network_error.is_none() => {
+                    failures.push((Some(SendFailureKind::Status), format!("{request_name}: {}", send_outcome.get_status_text().unwrap_or_default())));
+                },
+                Ok(send_outcome) => failures.extend(
+                    get_send_failures(send_command, &request_name, &send_outcome)
+                        .into_iter()
+                        .map(|(kind, failure)| (Some(kind), failure))
+                ),
+                Err(error) => failures.push((None, format!("{request_name}: {error}")))
+            }
+        }
+
+        let total_rows = row_results.len();
+        let passed_rows = row_results.iter().filter(|(_, failures)| failures.is_empty()).count();
+
+        // The lowest exit code is kept, the other errors (None) have the lowest
+        let failure_kind = row_results
+            .iter()
+            .flat_map(|(_, failures)| failures.iter().map(|(kind, _)| *kind))
+            .min();
+
+        print_summary(send_command, String::new());
+
+        for (row_number, failures) in row_results {
+            match failures.is_empty() {
+                true => print_summary(send_command, format!("Row {row_number}: PASS")),
+                false => {
+                    print_summary(send_command, format!("Row {row_number}: FAIL"));
+
+                    for (_, failure) in failures {
+                        print_summary(send_command, format!("\t{failure}"));
+                    }
+                }
+            }
+        }
+
+        print_summary(send_command, format!("{passed_rows}/{total_rows} rows passed"));
+
+        print_assertion_summary(send_command, &assertion_tallies);
+
+        let message = format!("{} row(s) failed", total_rows - passed_rows);
+
+        match failure_kind {
+            None => Ok(()),
+            Some(None) => Err(anyhow!(message)),
+            Some(Some(kind)) => Err(SendFailure { kind, message }.into())
+        }
+    }
+
+    fn get_send_rate_limit_options(&self, send_command: &SendCommand) -> RateLimitOptions {
+        let mut options = self.config.get_rate_limit_options();
+
+        if send_command.requests_per_second.is_some() {
+            options.requests_per_second = send_command.requests_per_second;
+        }
+
+        if let Some(concurrency) = send_command.concurrency {
+            options.concurrency = concurrency;
+        }
+
+        if let Some(delay_ms) = send_command.delay_ms {
+            options.delay = std::time::Duration::from_millis(delay_ms);
+        }
+
+        if let Some(max_retries) = send_command.max_retries {
+            options.max_retries = max_retries;
+        }
+
+        return options;
+    }
+
+    /// Send the requests, once per row when rows are given, at the pace of the rate limit options.
+    /// The outputs are printed in the sending order. Without any dataset, the run stops at the first error.
+    /// A request waits for the requests of the run it depends on, and is not sent when one of them did not succeed.
+    /// No request is sent when the before all hook does not succeed, the failures of the after hooks are only printed.
+    /// The run pauses for the think time of a request before sending the next one
+    async fn send_run(&mut self, send_command: &SendCommand, requests: &[Arc<RwLock<Request>>], collection_run: &CollectionRun, rows: Option<Vec<DataRow>>) -> Vec<SendResult> {
+        let hooks = &collection_run.hooks;
+        let options = self.get_send_rate_limit_options(send_command);
+        let throttle = Throttle::new(&options);
+        let should_stop_on_error = rows.is_none();
+
+        // A run without any dataset is a single row without any variable
+        let mut rows: Vec<Option<DataRow>> = match rows {
+            None => vec![None],
+            Some(rows) => rows.into_iter().map(Some).collect()
+        };
+
+        let concurrency = usize::max(options.concurrency, 1);
+
+        let run_request_names: Vec<String> = requests
+            .iter()
+            .map(|request| request.read().name.clone())
+            .collect();
+
+        let mut queued_sends: VecDeque<QueuedSend> = VecDeque::new();
+        let mut send_results: Vec<SendResult> = vec![];
+        let mut pending_think_time: Option<ThinkTime> = None;
+
+        if let Some(before_all) = hooks.get(HookKind::BeforeAll) {
+            if let Err(error) = self.send_hook(send_command, HookKind::BeforeAll, before_all, None).await {
+                send_results.push(SendResult {
+                    row_number: None,
+                    request_name: before_all.read().name.clone(),
+                    result: Err(error),
+                });
+
+                rows.clear();
+            }
+        }
+
+        'run: for (index, row) in rows.into_iter().enumerate() {
+            let row_number = row.as_ref().map(|_| index + 1);
+
+            let mut row_header = row.as_ref().map(|row| format!(
+                "Row {}: {}",
+                index + 1,
+                row.iter().map(|(key, value)| format!("{key}={value}")).collect::<Vec<String>>().join(", ")
+            ));
+
+            self.data_row = row;
+
+            for request in requests {
+                let (request_name, dependencies, think_time) = {
+                    let request = request.read();
+
+                    // The dependencies outside of the run are not waited for, e.g. when sending a single request
+                    let dependencies: Vec<String> = request.depends_on
+                        .iter()
+                        .filter(|dependency| run_request_names.contains(dependency))
+                        .cloned()
+                        .collect();
+
+                    (request.name.clone(), dependencies, request.think_time.or(collection_run.think_time))
+                };
+
+                // The dependencies are finished before the request is prepared, it may use what they stored in the environment
+                while queued_sends.len() >= concurrency || queued_sends.iter().any(|queued_send| queued_send.row_number == row_number && dependencies.contains(&queued_send.request_name)) {
+                    let send_result = self.finish_send(send_command, queued_sends.pop_front().unwrap(), hooks).await;
+                    let is_error = send_result.result.is_err();
+
+                    send_results.push(send_result);
+
+                    if is_error && should_stop_on_error {
+                        break 'run;
+                    }
+                }
+
+                // Sent one after the other, the pause starts once the response of the previous request is received
+                if let Some(think_time) = pending_think_time.take() {
+                    tokio::time::sleep(think_time.get_duration()).await;
+                }
+
+                let failed_dependency = dependencies.iter().find(|dependency| !send_results
+                    .iter()
+                    .any(|send_result| send_result.row_number == row_number && send_result.request_name == **dependency && send_result.is_success())
+                );
+
+                let pending_send = match (failed_dependency, hooks.get(HookKind::BeforeEach)) {
+                    (Some(failed_dependency), _) => Err(anyhow!("\"{request_name}\" not sent, the request \"{failed_dependency}\" it depends on did not succeed")),
+                    (None, None) => self.start_send(send_command, request.clone(), &throttle, options.max_retries).await,
+                    (None, Some(before_each)) => match self.send_hook(send_command, HookKind::BeforeEach, before_each, self.data_row.clone()).await {
+                        Ok(()) => self.start_send(send_command, request.clone(), &throttle, options.max_retries).await,
+                        Err(error) => Err(anyhow!("\"{request_name}\" not sent: {error}"))
+                    }
+                };
+
+                if pending_send.is_ok() {
+                    pending_think_time = think_time;
+                }
+
+                queued_sends.push_back(QueuedSend {
+                    row_number,
+                    request_name,
+                    row_header: row_header.take(),
+                    pending_send,
+                });
+            }
+        }
+
+        while let Some(queued_send) = queued_sends.pop_front() {
+            let send_result = self.finish_send(send_command, queued_send, hooks).await;
+            send_results.push(send_result);
+        }
+
+        // Also sent when the run stopped early, to clean what the requests created
+        if let Some(after_all) = hooks.get(HookKind::AfterAll) {
+            if let Err(error) = self.send_hook(send_command, HookKind::AfterAll, after_all, None).await {
+                eprintln!("{error}");
+            }
+        }
+
+        self.data_row = None;
+
+        return send_results;
+    }
+
+    /// Prepare the request with the current dataset row, then send it in the background once the throttle allows it
+    async fn start_send(&mut self, send_command: &SendCommand, local_request: Arc<RwLock<Request>>, throttle: &Throttle, max_retries: u32) -> anyhow::Result<PendingSend> {
         let request = local_request.read();
 
-         if let Some(env_name )= &send_command.env {
+        self.select_send_environment(send_command)?;
+
+        let (prepared_request, console_output) = self.prepare_request(&request).await?;
+
+        let should_notify = request.settings.notify_on_completion;
+        let should_store_cookies = request.settings.store_received_cookies;
+        let request_name = request.name.clone();
+
+        drop(request);
+
+        local_request.write().response_output = send_command.output.clone();
+
+        let cache_validators = CacheValidators {
+            etag: send_command.if_none_match.clone(),
+            last_modified: send_command.if_modified_since.clone(),
+        };
+
+        let prepared_request = cache_validators.add_conditional_headers(prepared_request);
+
+        let local_env = self.get_selected_env_as_local();
+        let offline = self.is_offline();
+
+        let cors_origin = match offline {
+            // The preflight would reach the network
+            true => None,
+            false => send_command.cors_origin.clone()
+        };
+
+        throttle.wait().await;
+
+        let request_start = Instant::now();
+
+        let sending = tokio::spawn({
+            let local_request = local_request.clone();
+            let throttle = throttle.clone();
+
+            async move {
+                let send_start = Instant::now();
+
+                let (prepared_request, cors_simulation) = match &cors_origin {
+                    None => (prepared_request, None),
+                    Some(cors_origin) => {
+                        let (prepared_request, cors_simulation) = send_cors_preflight(prepared_request, cors_origin).await?;
+                        (prepared_request, Some(cors_simulation))
+                    }
+                };
+
+                let (response, result_console_output) = send_request_with_retries(prepared_request, local_request, &local_env, offline, &throttle, max_retries).await?;
+
+                Ok((response, result_console_output, cors_simulation, send_start.elapsed()))
+            }
+        });
+
+        Ok(PendingSend {
+            local_request,
+            request_name,
+            data_row: self.data_row.clone(),
+            console_output,
+            cache_validators,
+            should_notify,
+            should_store_cookies,
+            request_start,
+            sending,
+        })
+    }
+
+    fn select_send_environment(&mut self, send_command: &SendCommand) -> anyhow::Result<()> {
+        if let Some(env_name) = &send_command.env {
             let env_index = self.find_environment(env_name)?;
-             self.selected_environment = env_index;
+
+            if env_index != self.selected_environment {
+                self.selected_environment = env_index;
+                self.load_cookie_jar();
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Print the output of the request, then send the after each hook when the request was sent
+    async fn finish_send(&mut self, send_command: &SendCommand, queued_send: QueuedSend, hooks: &RunHooks) -> SendResult {
+        if let Some(row_header) = &queued_send.row_header {
+            eprintln!("{row_header}");
+        }
+
+        // The after each hook uses the dataset row of the request
+        let sent_data_row = queued_send.pending_send
+            .as_ref()
+            .ok()
+            .map(|pending_send| pending_send.data_row.clone());
+
+        let send_result = SendResult {
+            row_number: queued_send.row_number,
+            request_name: queued_send.request_name,
+            result: self.print_send_output(send_command, queued_send.row_number, queued_send.pending_send).await,
         };
-        
-        if send_command.request_name {
-            println!("{}", request.name);
+
+        if let (Some(data_row), Some(after_each)) = (sent_data_row, hooks.get(HookKind::AfterEach)) {
+            if let Err(error) = self.send_hook(send_command, HookKind::AfterEach, after_each, data_row).await {
+                eprintln!("{error}");
+            }
         }
-        
-        let (prepared_request, mut console_output) = self.prepare_request(&request).await?;
 
-        drop(request);
+        send_result
+    }
+
+    /// Send a hook request of the run with the given dataset row, an error when it does not get a 2xx response
+    async fn send_hook(&mut self, send_command: &SendCommand, hook_kind: HookKind, local_hook_request: &Arc<RwLock<Request>>, data_row: Option<DataRow>) -> anyhow::Result<()> {
+        self.select_send_environment(send_command)?;
+
+        let hook_request = local_hook_request.read().clone();
+
+        let current_data_row = std::mem::replace(&mut self.data_row, data_row);
+        let prepared_hook_request = self.prepare_request(&hook_request).await;
+        self.data_row = current_data_row;
+
+        let (prepared_hook_request, _) = prepared_hook_request?;
 
         let local_env = self.get_selected_env_as_local();
-        let (response, result_console_output, _) = send_request(prepared_request, local_request, &local_env).await?;
+        let (response, _, _) = send_request(prepared_hook_request, local_hook_request.clone(), &local_env, self.is_offline()).await?;
+
+        // The request did not reach the server and the body holds the error
+        let status_code = match (&response.result, &response.content) {
+            (Some(RequestResult::TransportError(_)), Some(ResponseContent::Body(error))) => error.to_string(),
+            (Some(result), _) => result.to_string(),
+            (None, _) => String::from("no response")
+        };
+
+        if !response.is_success() {
+            return Err(anyhow!("The {hook_kind} hook \"{}\" did not succeed: {status_code}", hook_request.name));
+        }
+
+        eprintln!("Sent the {hook_kind} hook \"{}\": {status_code}", hook_request.name);
+
+        Ok(())
+    }
+
+    /// Wait for the response of the request, print the parts asked by the command and return its status code and test results
+    async fn print_send_output(&mut self, send_command: &SendCommand, row_number: Option<usize>, pending_send: anyhow::Result<PendingSend>) -> anyhow::Result<SendOutcome> {
+        let PendingSend {
+            local_request,
+            request_name,
+            data_row,
+            mut console_output,
+            cache_validators,
+            should_notify,
+            should_store_cookies,
+            request_start,
+            sending
+        } = pending_send?;
+
+        let assertion_test_names: Vec<String> = local_request.read().assertions
+            .iter()
+            .map(ResponseAssertion::to_test_name)
+            .collect();
+
+        if send_command.request_name && send_command.format.is_none() {
+            println!("{}", request_name);
+        }
+
+        let (mut response, mut result_console_output, cors_simulation, mut duration) = sending.await??;
+
+        if response.is_offline {
+            eprintln!("Offline, \"{request_name}\" answered with its snapshot");
+        }
+
+        let local_env = self.get_selected_env_as_local();
+
+        /* LOGIN RETRY */
+
+        if response.is_unauthorized() {
+            if let Some(local_login_request) = self.get_login_request_as_local(&local_request) {
+                let login_request = local_login_request.read().clone();
+
+                eprintln!("401 received, sending the login request \"{}\" and retrying", login_request.name);
+
+                // The login request and the retry use the dataset row of the request
+                let current_data_row = std::mem::replace(&mut self.data_row, data_row);
+
+                // The post-request script of the login request stores the new token in the environment
+                let (prepared_login_request, _) = self.prepare_request(&login_request).await?;
+                send_request(prepared_login_request, local_login_request, &local_env, self.is_offline()).await?;
+
+                let request = local_request.read().clone();
+                let (prepared_request, retry_console_output) = self.prepare_request(&request).await?;
+
+                self.data_row = current_data_row;
+
+                console_output = retry_console_output;
+
+                let retry_start = Instant::now();
+                (response, result_console_output, _) = send_request(prepared_request, local_request, &local_env, self.is_offline()).await?;
+                duration += retry_start.elapsed();
+            }
+        }
+
+        if should_notify && request_start.elapsed() >= self.config.get_notification_minimum_duration() {
+            send_notification(self.config.get_notification_method(), &request_name, &response.get_status_text().unwrap_or_default());
+        }
+
+        if should_store_cookies {
+            self.save_cookie_jar();
+        }
 
         console_output = format!("{console_output}{result_console_output}");
 
+        // The request did not reach the server and the body holds the error
+        let status_code = match (&response.result, &response.content) {
+            (Some(RequestResult::TransportError(_)), Some(ResponseContent::Body(error))) => Some(error.to_string()),
+            (result, _) => result.as_ref().map(|result| result.to_string())
+        };
+
+        if let Some(cors_simulation) = &cors_simulation {
+            let cors_test_results = cors_simulation.get_test_results(&response);
+            response.tests.extend(cors_test_results);
+        }
+
+        let tests = response.tests.clone();
+        let is_not_modified = response.is_not_modified();
+
+        if let Some(output) = &send_command.output {
+            if !output.exists() {
+                return Err(anyhow!("Could not save the response body to \"{}\"", output.display()));
+            }
+        }
+
+        let send_outcome = SendOutcome {
+            result: response.result.clone(),
+            network_error: response.get_transport_error().and(status_code.clone()),
+            assertions: tests
+                .iter()
+                .filter(|test| assertion_test_names.contains(&test.name))
+                .cloned()
+                .collect(),
+            tests,
+            duration,
+            response_excerpt: match &response.content {
+                Some(ResponseContent::Body(body)) if response.get_transport_error().is_none() => Some(get_response_excerpt(body)),
+                Some(ResponseContent::Image(image)) => Some(format!("Image, {} bytes", image.data.len())),
+                _ => None
+            },
+        };
+
+        if let Some(format) = send_command.format {
+            print_formatted_response(format, &request_name, row_number, &response);
+            return Ok(send_outcome);
+        }
+
         if send_command.status_code {
-            println!("{}", response.status_code.unwrap());
+            println!("{}", status_code.clone().unwrap_or_default());
+        }
+
+        if send_command.tests {
+            for test in &send_outcome.tests {
+                match test.passed {
+                    true => println!("PASS {}", test.name),
+                    false => {
+                        println!("FAIL {}", test.name);
+
+                        for message in &test.messages {
+                            println!("\t{message}");
+                        }
+                    }
+                }
+            }
         }
 
         if send_command.duration {
             println!("{}", response.duration.unwrap());
         }
 
+        if send_command.charset {
+            println!("{}", response.charset.clone().unwrap_or_default());
+        }
+
+        if send_command.dns {
+            match &response.dns_resolution {
+                None => println!("No DNS resolution"),
+                Some(dns_resolution) => println!("{dns_resolution}")
+            }
+        }
+
+        if send_command.wire {
+            match &response.wire {
+                None => println!("No exchange received"),
+                Some(wire) => println!("{}", wire.to_lines().join("\n"))
+            }
+        }
+
         if send_command.cookies {
             println!("{}", response.cookies.unwrap());
         }
@@ -71,17 +872,281 @@ impl App<'_> {
             println!("{:?}", response.headers);
         }
 
+        if send_command.trailers {
+            println!("{:?}", response.trailers);
+        }
+
         if send_command.console {
             println!("{}", console_output);
         }
 
         if !send_command.hide_content {
             match response.content.unwrap() {
+                // The explanation is kept out of the output, which stays empty like the body
+                ResponseContent::Body(body) if body.is_empty() && is_not_modified => eprintln!("{}", cache_validators.explain_not_modified().join("\n")),
                 ResponseContent::Body(body) => println!("{}", body),
                 ResponseContent::Image(image) => println!("{:?}", image.data)
             };
         }
 
-        Ok(())
+        Ok(send_outcome)
+    }
+}
+
+/// Failures of a response of a run, without any response the tests and the status are not checked
+fn get_send_failures(send_command: &SendCommand, request_name: &str, send_outcome: &SendOutcome) -> Vec<(SendFailureKind, String)> {
+    if let Some(network_error) = &send_outcome.network_error {
+        return vec![(SendFailureKind::Network, format!("{request_name}: {network_error}"))];
+    }
+
+    let mut failures: Vec<(SendFailureKind, String)> = vec![];
+
+    for test in send_outcome.tests.iter().filter(|test| !test.passed) {
+        failures.push((SendFailureKind::Tests, format!("{request_name}: {}", test.name)));
+    }
+
+    if send_command.fail && !send_outcome.is_success() {
+        failures.push((SendFailureKind::Status, format!("{request_name}: {}", send_outcome.get_status_text().unwrap_or_default())));
+    }
+
+    return failures;
+}
+
+/// Write the JUnit and HTML reports asked by the command
+fn write_run_reports(send_command: &SendCommand, mut run_report: RunReport, send_results: &[SendResult]) -> anyhow::Result<()> {
+    if send_command.junit.is_none() && send_command.html_report.is_none() {
+        return Ok(());
+    }
+
+    run_report.entries = send_results
+        .iter()
+        .map(|send_result| to_run_report_entry(send_command, send_result))
+        .collect();
+
+    for (path, report) in [(&send_command.junit, run_report.to_junit_xml()), (&send_command.html_report, run_report.to_html())] {
+        if let Some(path) = path {
+            if let Err(error) = fs::write(path, report) {
+                return Err(anyhow!("Could not write the report to \"{}\"\n\t{error}", path.display()));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The statuses fail the sends as they fail the run, with --fail or a dataset
+fn to_run_report_entry(send_command: &SendCommand, send_result: &SendResult) -> RunReportEntry {
+    let send_outcome = match &send_result.result {
+        Ok(send_outcome) => send_outcome,
+        Err(error) => return RunReportEntry {
+            request_name: send_result.request_name.clone(),
+            row_number: send_result.row_number,
+            status_code: None,
+            duration: Duration::ZERO,
+            error: Some(error.to_string()),
+            failure: None,
+            tests: vec![],
+            response_excerpt: None,
+        }
+    };
+
+    let is_failed_status = match send_result.row_number {
+        None => send_command.fail && !send_outcome.is_success(),
+        Some(_) => send_outcome.is_failure()
+    };
+
+    let failure = match is_failed_status && send_outcome.network_error.is_none() {
+        true => Some(format!("Status {}", send_outcome.get_status_text().unwrap_or_default())),
+        false => None
+    };
+
+    RunReportEntry {
+        request_name: send_result.request_name.clone(),
+        row_number: send_result.row_number,
+        status_code: send_outcome.get_status_text(),
+        duration: send_outcome.duration,
+        error: send_outcome.network_error.clone(),
+        failure,
+        tests: send_outcome.tests.clone(),
+        response_excerpt: send_outcome.response_excerpt.clone(),
+    }
+}
+
+/// The formatted responses stay alone on the standard output
+fn print_summary(send_command: &SendCommand, line: String) {
+    match send_command.format {
+        None => println!("{line}"),
+        Some(_) => eprintln!("{line}")
+    }
+}
+
+fn tally_assertions(assertion_tallies: &mut Vec<AssertionTally>, request_name: &str, assertions: &[TestResult]) {
+    for assertion in assertions {
+        let assertion_tally = match assertion_tallies.iter().position(|assertion_tally| assertion_tally.test_name == assertion.name) {
+            Some(index) => &mut assertion_tallies[index],
+            None => {
+                assertion_tallies.push(AssertionTally {
+                    test_name: assertion.name.clone(),
+                    checks: 0,
+                    failed_requests: vec![],
+                });
+
+                assertion_tallies.last_mut().unwrap()
+            }
+        };
+
+        assertion_tally.checks += 1;
+
+        if !assertion.passed {
+            assertion_tally.failed_requests.push(request_name.to_string());
+        }
+    }
+}
+
+/// Failure count of each assertion over the whole run, e.g. to check a latency threshold on every request
+fn print_assertion_summary(send_command: &SendCommand, assertion_tallies: &[AssertionTally]) {
+    if assertion_tallies.is_empty() {
+        return;
+    }
+
+    print_summary(send_command, String::new());
+
+    for assertion_tally in assertion_tallies {
+        let failed_checks = assertion_tally.failed_requests.len();
+
+        match failed_checks {
+            0 => print_summary(send_command, format!("PASS {} (0/{} failed)", assertion_tally.test_name, assertion_tally.checks)),
+            _ => {
+                print_summary(send_command, format!("FAIL {} ({failed_checks}/{} failed)", assertion_tally.test_name, assertion_tally.checks));
+
+                // In the order of their first failure, the dataset rows repeat them
+                let mut failed_requests: Vec<&String> = vec![];
+
+                for request_name in &assertion_tally.failed_requests {
+                    if !failed_requests.contains(&request_name) {
+                        failed_requests.push(request_name);
+                    }
+                }
+
+                for request_name in failed_requests {
+                    let request_failures = assertion_tally.failed_requests.iter().filter(|failed_request| *failed_request == request_name).count();
+                    print_summary(send_command, format!("\t{request_name}: {request_failures} failure(s)"));
+                }
+            }
+        }
+    }
+
+    let passed_assertions = assertion_tallies.iter().filter(|assertion_tally| assertion_tally.failed_requests.is_empty()).count();
+
+    print_summary(send_command, format!("{passed_assertions}/{} assertions passed", assertion_tallies.len()));
+}
+
+/// Fail the run with the lowest exit code of its failures, listed by kind
+fn check_run_failures(failures: Vec<(SendFailureKind, String)>) -> anyhow::Result<()> {
+    let kind = match failures.iter().map(|(kind, _)| *kind).min() {
+        None => return Ok(()),
+        Some(kind) => kind
+    };
+
+    let mut sections: Vec<String> = vec![];
+
+    for (section_kind, summary) in [(SendFailureKind::Network, "request(s) got no response"), (SendFailureKind::Tests, "test(s) failed"), (SendFailureKind::Status, "response(s) not 2xx")] {
+        let section_failures: Vec<&str> = failures
+            .iter()
+            .filter(|(kind, _)| *kind == section_kind)
+            .map(|(_, failure)| failure.as_str())
+            .collect();
+
+        if !section_failures.is_empty() {
+            sections.push(format!("{} {summary}\n\t{}", section_failures.len(), section_failures.join("\n\t")));
+        }
+    }
+
+    return Err(SendFailure { kind, message: sections.join("\n") }.into());
+}
+
+/// Stable output for scripts, the parts asked by the other flags are not printed
+fn print_formatted_response(format: SendOutputFormat, request_name: &str, row_number: Option<usize>, response: &RequestResponse) {
+    match format {
+        SendOutputFormat::Json => {
+            // The bodies which are not valid UTF-8 are also given as received
+            let (body, body_base64) = match (&response.content, response.get_body_bytes()) {
+                (Some(ResponseContent::Body(body)), Some(bytes)) if std::str::from_utf8(bytes).is_err() => (Some(body.to_string()), Some(STANDARD.encode(bytes))),
+                (Some(ResponseContent::Body(body)), _) if response.get_transport_error().is_none() => (Some(body.to_string()), None),
+                (Some(ResponseContent::Image(image)), _) => (None, Some(STANDARD.encode(&image.data))),
+                _ => (None, None)
+            };
+
+            let output = json!({
+                "request": request_name,
+                "row": row_number,
+                "result": response.result.as_ref().map(RequestResult::get_code),
+                "status": response.get_status_code(),
+                "status_text": response.get_status_text(),
+                "duration": response.duration,
+                "headers": response.headers.iter().map(|(name, value)| json!({ "name": name, "value": value })).collect::<Vec<Value>>(),
+                "trailers": response.trailers.iter().map(|(name, value)| json!({ "name": name, "value": value })).collect::<Vec<Value>>(),
+                "body": body,
+                "body_base64": body_base64,
+                "tests": response.tests.iter().map(|test| json!({ "name": test.name, "passed": test.passed, "messages": test.messages })).collect::<Vec<Value>>(),
+                "error": response.get_transport_error().map(|error| json!({ "kind": error.kind.get_code(), "url": error.url, "causes": error.chain })),
+            });
+
+            println!("{output}");
+        },
+        SendOutputFormat::Raw => {
+            // The received bytes, a binary body is written as is
+            let content = response.get_body_bytes().unwrap_or_default();
+
+            if response.is_truncated() {
+                eprintln!("\"{request_name}\" was truncated at the max response size setting, use --output for the full body");
+            }
+
+            let _ = stdout().write_all(content);
+        },
+        SendOutputFormat::Headers => {
+            if let Some(result) = &response.result {
+                println!("{result}");
+            }
+
+            for (header_name, header_value) in &response.headers {
+                println!("{header_name}: {header_value}");
+            }
+        }
+    }
+}
+
+/// Send the request, and again while the server answers 429 and the retries allow it.
+/// The throttle is delayed as well, the other requests of the run wait too
+async fn send_request_with_retries(mut prepared_request: RequestBuilder, local_request: Arc<RwLock<Request>>, local_env: &Option<Arc<RwLock<Environment>>>, offline: bool, throttle: &Throttle, max_retries: u32) -> anyhow::Result<(RequestResponse, String)> {
+    let mut attempt = 0;
+
+    loop {
+        // A streamed body cannot be sent twice
+        let retry_request = prepared_request.try_clone();
+
+        let (response, result_console_output, _) = send_request(prepared_request, local_request.clone(), local_env, offline).await?;
+
+        let retry_request = match retry_request {
+            Some(retry_request) if response.is_too_many_requests() && attempt < max_retries => retry_request,
+            _ => return Ok((response, result_console_output))
+        };
+
+        let request_name = local_request.read().name.clone();
+        let backoff = response.get_retry_after().unwrap_or(get_exponential_backoff(attempt));
+
+        if backoff > MAX_BACKOFF {
+            eprintln!("429 received for \"{request_name}\", Retry-After of {}s exceeds {}s, not retrying", backoff.as_secs(), MAX_BACKOFF.as_secs());
+            return Ok((response, result_console_output));
+        }
+
+        attempt += 1;
+
+        eprintln!("429 received for \"{request_name}\", retry {attempt}/{max_retries} in {:.1}s", backoff.as_secs_f64());
+
+        throttle.back_off(backoff);
+        throttle.wait().await;
+
+        prepared_request = retry_request;
     }
-}
\ No newline at end of file
+}
@@ -1,7 +1,13 @@
+use anyhow::anyhow;
 use tracing::{info};
 
 use crate::app::app::App;
 use crate::cli::commands::request_commands::setting::RequestSettingName;
+use crate::models::charset::{find_charset, RESPONSE_CHARSETS};
+use crate::models::ip_version::{IpVersion, IP_VERSION_CHOICES};
+use crate::models::result_tab::{ResultTabFocus, RESULT_TAB_FOCUS_CHOICES};
+use crate::models::tls_version::{TlsVersion, TLS_VERSION_CHOICES};
+use crate::models::settings::{SettingValue, BANDWIDTH_STEPS, LATENCY_STEPS, WATCH_INTERVAL_STEPS};
 
 impl App<'_> {
     pub fn cli_print_request_settings(&mut self, collection_index: usize, request_index: usize) -> anyhow::Result<()> {
@@ -13,12 +19,62 @@ impl App<'_> {
             for (setting, state) in selected_request.settings.to_vec() {
                 println!("{setting}: {state}");
             }
+
+            if let Some(local_address) = &selected_request.settings.local_address {
+                println!("Local address: {local_address}");
+            }
         }
 
         Ok(())
     }
     
-    pub fn cli_modify_request_setting(&mut self, collection_index: usize, request_index: usize, setting_name: &RequestSettingName, new_state: &bool) -> anyhow::Result<()> {
+    pub fn cli_modify_request_setting(&mut self, collection_index: usize, request_index: usize, setting_name: &RequestSettingName, new_state: &str) -> anyhow::Result<()> {
+        let new_state = match setting_name {
+            RequestSettingName::MaxRedirects | RequestSettingName::MaxSize => match new_state.parse::<usize>() {
+                Ok(value) => SettingValue::Number(value),
+                Err(_) => return Err(anyhow!("Expected a number, got \"{new_state}\""))
+            },
+            RequestSettingName::Latency | RequestSettingName::UploadLimit | RequestSettingName::DownloadLimit | RequestSettingName::WatchInterval => match new_state.parse::<usize>() {
+                Ok(value) => match setting_name {
+                    RequestSettingName::Latency => SettingValue::Steps(value, &LATENCY_STEPS),
+                    RequestSettingName::WatchInterval => SettingValue::Steps(value, &WATCH_INTERVAL_STEPS),
+                    _ => SettingValue::Steps(value, &BANDWIDTH_STEPS)
+                },
+                Err(_) => return Err(anyhow!("Expected a number, got \"{new_state}\""))
+            },
+            RequestSettingName::Charset => match new_state.eq_ignore_ascii_case("auto") {
+                true => SettingValue::Choice(RESPONSE_CHARSETS[0], &RESPONSE_CHARSETS),
+                false => match find_charset(new_state) {
+                    Some(charset) => SettingValue::Choice(charset, &RESPONSE_CHARSETS),
+                    None => return Err(anyhow!("Unknown charset \"{new_state}\""))
+                }
+            },
+            RequestSettingName::IpVersion => match IpVersion::from_choice(new_state) {
+                Some(ip_version) => SettingValue::Choice(ip_version.get_choice_name(), &IP_VERSION_CHOICES),
+                None => return Err(anyhow!("Unknown IP version \"{new_state}\", expected any, ipv4 or ipv6"))
+            },
+            RequestSettingName::MinTls | RequestSettingName::MaxTls => match new_state.eq_ignore_ascii_case("config") {
+                true => SettingValue::Choice(TLS_VERSION_CHOICES[0], &TLS_VERSION_CHOICES),
+                false => match TlsVersion::from_choice(new_state) {
+                    Some(tls_version) => SettingValue::Choice(TlsVersion::get_choice_name(Some(tls_version)), &TLS_VERSION_CHOICES),
+                    None => return Err(anyhow!("Unknown TLS version \"{new_state}\", expected 1.0, 1.1, 1.2, 1.3 or config"))
+                }
+            },
+            // Free text, not a setting of the TUI popup
+            RequestSettingName::LocalAddress => return self.modify_request_local_address(collection_index, request_index, new_state),
+            RequestSettingName::ResultTab => match new_state.eq_ignore_ascii_case("config") {
+                true => SettingValue::Choice(RESULT_TAB_FOCUS_CHOICES[0], &RESULT_TAB_FOCUS_CHOICES),
+                false => match ResultTabFocus::from_choice(new_state) {
+                    Some(focus) => SettingValue::Choice(ResultTabFocus::get_choice_name(Some(focus)), &RESULT_TAB_FOCUS_CHOICES),
+                    None => return Err(anyhow!("Unknown result tab \"{new_state}\""))
+                }
+            },
+            _ => match new_state.parse::<bool>() {
+                Ok(value) => SettingValue::Bool(value),
+                Err(_) => return Err(anyhow!("Expected true or false, got \"{new_state}\""))
+            }
+        };
+
         let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
 
         {
@@ -26,11 +82,33 @@ impl App<'_> {
 
             info!("Setting \"{}\" set to \"{}\"", setting_name, new_state);
 
-            match setting_name {
-                RequestSettingName::Proxy => selected_request.settings.use_config_proxy = *new_state,
-                RequestSettingName::Redirects => selected_request.settings.allow_redirects= *new_state,
-                RequestSettingName::Cookies => selected_request.settings.store_received_cookies= *new_state,
-                RequestSettingName::Pretty => selected_request.settings.pretty_print_response_content= *new_state,
+            match (setting_name, new_state) {
+                (RequestSettingName::Proxy, SettingValue::Bool(state)) => selected_request.settings.use_config_proxy = state,
+                (RequestSettingName::Redirects, SettingValue::Bool(state)) => selected_request.settings.allow_redirects = state,
+                (RequestSettingName::MaxRedirects, SettingValue::Number(value)) => selected_request.settings.max_redirects = value,
+                (RequestSettingName::ResendBody, SettingValue::Bool(state)) => selected_request.settings.resend_body_on_redirect = state,
+                (RequestSettingName::StripAuth, SettingValue::Bool(state)) => selected_request.settings.strip_authorization_on_redirect = state,
+                (RequestSettingName::Cookies, SettingValue::Bool(state)) => selected_request.settings.store_received_cookies = state,
+                (RequestSettingName::Pretty, SettingValue::Bool(state)) => selected_request.settings.pretty_print_response_content = state,
+                (RequestSettingName::Notify, SettingValue::Bool(state)) => selected_request.settings.notify_on_completion = state,
+                (RequestSettingName::MaxSize, SettingValue::Number(value)) => selected_request.settings.max_response_size = value,
+                (RequestSettingName::Charset, SettingValue::Choice(charset, _)) => selected_request.settings.response_charset = match charset == RESPONSE_CHARSETS[0] {
+                    true => None,
+                    false => Some(charset.to_string())
+                },
+                (RequestSettingName::ResultTab, SettingValue::Choice(focus, _)) => selected_request.settings.result_tab_after_response = ResultTabFocus::from_choice(focus),
+                (RequestSettingName::Revalidate, SettingValue::Bool(state)) => selected_request.settings.auto_revalidate = state,
+                (RequestSettingName::Latency, SettingValue::Steps(value, _)) => selected_request.settings.simulated_latency = value,
+                (RequestSettingName::UploadLimit, SettingValue::Steps(value, _)) => selected_request.settings.upload_limit = value,
+                (RequestSettingName::DownloadLimit, SettingValue::Steps(value, _)) => selected_request.settings.download_limit = value,
+                (RequestSettingName::WatchInterval, SettingValue::Steps(value, _)) => selected_request.settings.watch_interval = value,
+                (RequestSettingName::QueueSends, SettingValue::Bool(state)) => selected_request.settings.queue_sends = state,
+                (RequestSettingName::ExpectContinueHeader, SettingValue::Bool(state)) => selected_request.settings.expect_continue_header = state,
+                (RequestSettingName::RawSocket, SettingValue::Bool(state)) => selected_request.settings.raw_socket = state,
+                (RequestSettingName::IpVersion, SettingValue::Choice(ip_version, _)) => selected_request.settings.ip_version = IpVersion::from_choice(ip_version).unwrap_or_default(),
+                (RequestSettingName::MinTls, SettingValue::Choice(tls_version, _)) => selected_request.settings.min_tls_version = TlsVersion::from_choice(tls_version),
+                (RequestSettingName::MaxTls, SettingValue::Choice(tls_version, _)) => selected_request.settings.max_tls_version = TlsVersion::from_choice(tls_version),
+                _ => {}
             };
         }
 
@@ -46,10 +124,32 @@ impl App<'_> {
             let selected_request = local_selected_request.write();
             
             let setting = match setting_name {
-                RequestSettingName::Proxy => selected_request.settings.use_config_proxy,
-                RequestSettingName::Redirects => selected_request.settings.allow_redirects,
-                RequestSettingName::Cookies => selected_request.settings.store_received_cookies,
-                RequestSettingName::Pretty => selected_request.settings.pretty_print_response_content,
+                RequestSettingName::Proxy => SettingValue::Bool(selected_request.settings.use_config_proxy),
+                RequestSettingName::Redirects => SettingValue::Bool(selected_request.settings.allow_redirects),
+                RequestSettingName::MaxRedirects => SettingValue::Number(selected_request.settings.max_redirects),
+                RequestSettingName::ResendBody => SettingValue::Bool(selected_request.settings.resend_body_on_redirect),
+                RequestSettingName::StripAuth => SettingValue::Bool(selected_request.settings.strip_authorization_on_redirect),
+                RequestSettingName::Cookies => SettingValue::Bool(selected_request.settings.store_received_cookies),
+                RequestSettingName::Pretty => SettingValue::Bool(selected_request.settings.pretty_print_response_content),
+                RequestSettingName::Notify => SettingValue::Bool(selected_request.settings.notify_on_completion),
+                RequestSettingName::MaxSize => SettingValue::Number(selected_request.settings.max_response_size),
+                RequestSettingName::Charset => SettingValue::Choice(selected_request.settings.get_response_charset_name(), &RESPONSE_CHARSETS),
+                RequestSettingName::ResultTab => SettingValue::Choice(ResultTabFocus::get_choice_name(selected_request.settings.result_tab_after_response), &RESULT_TAB_FOCUS_CHOICES),
+                RequestSettingName::Revalidate => SettingValue::Bool(selected_request.settings.auto_revalidate),
+                RequestSettingName::Latency => SettingValue::Steps(selected_request.settings.simulated_latency, &LATENCY_STEPS),
+                RequestSettingName::UploadLimit => SettingValue::Steps(selected_request.settings.upload_limit, &BANDWIDTH_STEPS),
+                RequestSettingName::DownloadLimit => SettingValue::Steps(selected_request.settings.download_limit, &BANDWIDTH_STEPS),
+                RequestSettingName::WatchInterval => SettingValue::Steps(selected_request.settings.watch_interval, &WATCH_INTERVAL_STEPS),
+                RequestSettingName::QueueSends => SettingValue::Bool(selected_request.settings.queue_sends),
+                RequestSettingName::ExpectContinueHeader => SettingValue::Bool(selected_request.settings.expect_continue_header),
+                RequestSettingName::RawSocket => SettingValue::Bool(selected_request.settings.raw_socket),
+                RequestSettingName::IpVersion => SettingValue::Choice(selected_request.settings.ip_version.get_choice_name(), &IP_VERSION_CHOICES),
+                RequestSettingName::MinTls => SettingValue::Choice(TlsVersion::get_choice_name(selected_request.settings.min_tls_version), &TLS_VERSION_CHOICES),
+                RequestSettingName::MaxTls => SettingValue::Choice(TlsVersion::get_choice_name(selected_request.settings.max_tls_version), &TLS_VERSION_CHOICES),
+                RequestSettingName::LocalAddress => {
+                    println!("{}", selected_request.settings.local_address.as_deref().unwrap_or("none"));
+                    return Ok(());
+                }
             };
             
             println!("{setting}")
@@ -57,4 +157,4 @@ impl App<'_> {
         
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,58 @@
+use anyhow::anyhow;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::send::send_request;
+use crate::cli::commands::request_commands::snapshot::RequestSnapshotCommand;
+use crate::models::response::{RequestResult, ResponseContent};
+
+impl App<'_> {
+    pub async fn cli_request_snapshot(&mut self, collection_index: usize, request_index: usize, snapshot_command: &RequestSnapshotCommand) -> anyhow::Result<()> {
+        let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+        let mut snapshot = local_request.read().snapshot.clone().unwrap_or_default();
+
+        match snapshot_command {
+            RequestSnapshotCommand::Get => {
+                match &snapshot.body {
+                    None => println!("No snapshot"),
+                    Some(body) => println!("{body}")
+                }
+
+                if !snapshot.ignore.is_empty() {
+                    println!("ignored: {}", snapshot.ignore.join(", "));
+                }
+
+                return Ok(());
+            },
+            RequestSnapshotCommand::Update { env } => {
+                if let Some(env_name) = env {
+                    self.selected_environment = self.find_environment(env_name)?;
+                }
+
+                let request = local_request.read().clone();
+                let (prepared_request, _) = self.prepare_request(&request).await?;
+
+                let local_env = self.get_selected_env_as_local();
+                let (response, _, _) = send_request(prepared_request, local_request, &local_env, self.is_offline()).await?;
+
+                match (&response.result, &response.content) {
+                    (Some(result @ RequestResult::Success { .. }), Some(ResponseContent::Body(body))) => snapshot.record(&result.to_string(), &response.headers, body),
+                    _ => return Err(anyhow!("No response body to record"))
+                }
+            },
+            RequestSnapshotCommand::Ignore { rule } => {
+                if !snapshot.ignore.contains(rule) {
+                    snapshot.ignore.push(rule.clone());
+                }
+            },
+            RequestSnapshotCommand::Unignore { rule } => match snapshot.ignore.iter().position(|ignore_rule| ignore_rule == rule) {
+                None => return Err(anyhow!("Rule \"{rule}\" not found")),
+                Some(index) => {
+                    snapshot.ignore.remove(index);
+                }
+            },
+            RequestSnapshotCommand::Remove => return self.modify_request_snapshot(collection_index, request_index, None)
+        }
+
+        self.modify_request_snapshot(collection_index, request_index, Some(snapshot))
+    }
+}
@@ -0,0 +1,29 @@
+use crate::app::app::App;
+use crate::cli::commands::request_commands::soap::RequestSoapCommand;
+use crate::models::soap::RequestSoap;
+
+impl App<'_> {
+    pub fn cli_request_soap(&mut self, collection_index: usize, request_index: usize, soap_command: &RequestSoapCommand) -> anyhow::Result<()> {
+        let soap = match soap_command {
+            RequestSoapCommand::Get => {
+                let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+                let selected_request = local_selected_request.read();
+
+                match &selected_request.soap {
+                    None => println!("No SOAP mode"),
+                    Some(soap) => println!("{}", soap.to_summary())
+                }
+
+                return Ok(());
+            },
+            RequestSoapCommand::Set { version, action, envelope_header } => Some(RequestSoap {
+                version: *version,
+                action: action.clone(),
+                envelope_header: envelope_header.clone(),
+            }),
+            RequestSoapCommand::Remove => None
+        };
+
+        self.modify_request_soap(collection_index, request_index, soap)
+    }
+}
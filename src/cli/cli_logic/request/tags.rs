@@ -0,0 +1,29 @@
+use crate::app::app::App;
+use crate::cli::commands::tag::TagCommand;
+use crate::models::tag::{add_tags, remove_tags};
+
+impl App<'_> {
+    pub fn cli_request_tags(&mut self, collection_index: usize, request_index: usize, tag_command: &TagCommand) -> anyhow::Result<()> {
+        let tags = {
+            let local_selected_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+            let selected_request = local_selected_request.read();
+
+            selected_request.tags.clone()
+        };
+
+        let new_tags = match tag_command {
+            TagCommand::List => {
+                for tag in &tags {
+                    println!("{tag}");
+                }
+
+                return Ok(());
+            },
+            TagCommand::Add { tags: new_tags } => add_tags(&tags, new_tags),
+            TagCommand::Remove { tags: removed_tags } => remove_tags(&tags, removed_tags),
+            TagCommand::Clear => vec![]
+        };
+
+        self.modify_request_tags(collection_index, request_index, new_tags)
+    }
+}
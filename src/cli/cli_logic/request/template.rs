@@ -0,0 +1,11 @@
+use crate::app::app::App;
+
+impl App<'_> {
+    pub fn cli_save_request_as_template(&mut self, collection_index: usize, request_index: usize, template_name: String) -> anyhow::Result<()> {
+        let path = self.save_request_as_template(collection_index, request_index, template_name)?;
+
+        println!("Template saved to \"{}\"", path.display());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+use crate::app::app::App;
+use crate::cli::commands::think_time::ThinkTimeCommand;
+use crate::models::think_time::ThinkTime;
+
+impl App<'_> {
+    pub fn cli_request_think_time(&mut self, collection_index: usize, request_index: usize, think_time_command: &ThinkTimeCommand) -> anyhow::Result<()> {
+        match think_time_command {
+            ThinkTimeCommand::Get => {
+                let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+                if let Some(think_time) = &local_request.read().think_time {
+                    println!("{think_time}");
+                }
+
+                Ok(())
+            },
+            ThinkTimeCommand::Set { min_ms, max_ms } => {
+                let think_time = ThinkTime::new(*min_ms, *max_ms)?;
+                self.modify_request_think_time(collection_index, request_index, Some(think_time))
+            },
+            ThinkTimeCommand::Remove => self.modify_request_think_time(collection_index, request_index, None)
+        }
+    }
+}
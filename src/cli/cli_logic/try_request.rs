@@ -2,6 +2,7 @@ use std::sync::Arc;
 use parking_lot::RwLock;
 use crate::app::app::App;
 use crate::cli::cli_logic::request::new::create_request_from_new_request_command;
+use crate::cli::cli_logic::request::send::CollectionRun;
 use crate::cli::commands::request_commands::new::NewRequestCommand;
 use crate::cli::commands::request_commands::send::SendCommand;
 
@@ -10,7 +11,7 @@ impl App<'_> {
         let new_request = create_request_from_new_request_command(String::new(), new_request_command.clone());
         let local_request = Arc::new(RwLock::new(new_request));
         
-        self.local_send_request(&send_command, local_request).await?;
+        self.local_send_requests("try", send_command, vec![local_request], CollectionRun::default()).await?;
 
         Ok(())
     }
@@ -1,5 +1,10 @@
 use clap::Subcommand;
+use crate::cli::commands::description::DescriptionCommand;
 use crate::cli::commands::request_commands::send::SendCommand;
+use crate::cli::commands::tag::TagCommand;
+use crate::cli::commands::think_time::ThinkTimeCommand;
+use crate::models::collection::{CollectionFileFormat, CollectionLayout};
+use crate::models::collection_hooks::HookKind;
 
 #[derive(clap::Args, Debug, Clone)]
 pub struct CollectionCommand {
@@ -51,13 +56,133 @@ pub enum CollectionSubcommand {
         /// New collection name
         new_collection_name: String
     },
+
+    /// Print or change how the collection is stored on disk
+    Layout {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        /// Single file, or a directory with one file per request which is easier to review in git
+        layout: Option<CollectionLayout>
+    },
+
+    /// Print or change the format of the collection files, the collection is converted without loss
+    Format {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        /// JSON, YAML or TOML, the last two being easier to review in git
+        file_format: Option<CollectionFileFormat>
+    },
+
+    /// Encrypt the collection file, the key is prompted or read from $ATAC_PASSPHRASE or $ATAC_IDENTITY_FILE
+    Encrypt {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+    },
+
+    /// Decrypt the collection file
+    Decrypt {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+    },
+
+    /// Get, set, edit or remove a collection Markdown description
+    Description {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: DescriptionCommand
+    },
+
+    /// List, add or remove collection tags, shared by all its requests
+    Tag {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: TagCommand
+    },
+
+    /// Get, set or remove the login request, re-sent before retrying once any request of the collection getting a 401
+    Login {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: LoginRequestCommand
+    },
+
+    /// Get, set or remove the requests the runs send before or after the other ones, e.g. to create and delete test fixtures
+    Hook {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: HookCommand
+    },
     
+    /// Get, set or remove the pause of the runs after each request, to mimic a user or to stay under a rate limit
+    ThinkTime {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+
+        #[command(subcommand)]
+        subcommand: ThinkTimeCommand
+    },
+
+    /// Print the order the runs send the requests in, by stages of requests that can be sent in parallel
+    Graph {
+        /// e.g. my_collection, "my collection"
+        collection_name: String,
+    },
+
     /// Send all the collection's requests
     Send {
         /// e.g. my_collection, "my collection"
         collection_name: String,
 
+        /// Only send the requests having one of these tags, or whose collection has one, e.g. --tag smoke
+        #[arg(long = "tag", value_name = "TAG")]
+        tags: Vec<String>,
+
+        /// Start the run at this request, the previous ones are not sent, e.g. --from my_request
+        #[arg(long, value_name = "REQUEST")]
+        from: Option<String>,
+
         #[clap(flatten)]
         subcommand: SendCommand
     },
-}
\ No newline at end of file
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum LoginRequestCommand {
+    /// Print the login request name
+    Get,
+    /// Set the login request, its post-request script should store the new token in the environment
+    Set {
+        /// Name of a request of the collection, e.g. login
+        request_name: String
+    },
+    /// Remove the login request
+    Remove,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum HookCommand {
+    /// Print the hook requests, one per line
+    Get,
+    /// Set a hook request, which is then no longer sent as part of the runs
+    Set {
+        /// When the runs send the request
+        hook_kind: HookKind,
+
+        /// Name of a request of the collection, e.g. create_fixtures
+        request_name: String
+    },
+    /// Remove a hook request
+    Remove {
+        hook_kind: HookKind
+    },
+}
@@ -8,4 +8,19 @@ pub struct CompletionsCommand {
     /// The path of the directory where to generate the file. If empty, will generate the file in the current folder
     #[clap(value_hint = clap::ValueHint::FilePath)]
     pub output_directory: Option<PathBuf>,
-}
\ No newline at end of file
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct CompleteNamesCommand {
+    pub names: CompletedNames,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, clap::ValueEnum)]
+pub enum CompletedNames {
+    /// Collection names
+    Collection,
+    /// Request names, as collection/request
+    Request,
+    /// Environment names
+    Env,
+}
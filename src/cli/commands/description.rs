@@ -0,0 +1,15 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum DescriptionCommand {
+    /// Print the Markdown description
+    Get,
+    /// Set the Markdown description
+    Set {
+        description: String
+    },
+    /// Edit the Markdown description with $VISUAL or $EDITOR
+    Edit,
+    /// Remove the description
+    Remove,
+}
@@ -0,0 +1,27 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use strum::Display;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct DocsCommand {
+    /// e.g. my_collection
+    pub collection_name: String,
+
+    /// Output format of the documentation
+    #[arg(short, long, value_enum, default_value_t = DocsFormat::Markdown)]
+    pub format: DocsFormat,
+
+    /// File where to write the documentation. If empty, will print it to the standard output
+    #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
+    pub output: Option<PathBuf>,
+}
+
+#[derive(Debug, Copy, Clone, ValueEnum, Display)]
+pub enum DocsFormat {
+    #[strum(to_string = "markdown")]
+    Markdown,
+    /// Standalone HTML page
+    #[strum(to_string = "html")]
+    Html,
+}
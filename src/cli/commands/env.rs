@@ -15,6 +15,18 @@ pub enum EnvSubcommand {
         env_name: String,
     },
 
+    /// Encrypt the environment file, the key is prompted or read from $ATAC_PASSPHRASE or $ATAC_IDENTITY_FILE
+    Encrypt {
+        /// e.g. my_env (from the file .env.my_env)
+        env_name: String,
+    },
+
+    /// Decrypt the environment file
+    Decrypt {
+        /// e.g. my_env (from the file .env.my_env.age)
+        env_name: String,
+    },
+
     /// Add, get or set a key/value pair
     Key {
         /// e.g. my_env (from the file .env.my_env)
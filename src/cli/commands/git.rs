@@ -0,0 +1,26 @@
+use clap::Subcommand;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct GitCommand {
+    #[command(subcommand)]
+    pub git_subcommand: GitSubcommand,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum GitSubcommand {
+    /// Print the branch and the changed files of the workspace
+    Status,
+
+    /// Pull the remote changes
+    Pull,
+
+    /// Stage and commit every change of the workspace
+    Commit {
+        /// Commit message, opens the git editor if empty
+        #[arg(short, long)]
+        message: Option<String>,
+    },
+
+    /// Push the commits to the remote
+    Push,
+}
@@ -12,7 +12,16 @@ nest! {
             Postman(PostmanImport),
 
             /// Import a curl file
-            Curl(CurlImport)
+            Curl(CurlImport),
+
+            /// Import a Netscape cookies.txt file into the cookie jar
+            Cookies(CookiesImport),
+
+            /// Import a WSDL 1.1 file, with a SOAP request per operation
+            Wsdl(WsdlImport),
+
+            /// Import a file with an importer plugin
+            Plugin(PluginImport)
         },
     }
 }
@@ -47,4 +56,35 @@ pub struct CurlImport {
     /// Max depth at which import should stop creating nested collections and only get the deeper requests
     #[arg(long, requires = "recursive", conflicts_with = "request_name")]
     pub max_depth: Option<u16>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct CookiesImport {
+    /// Path to the cookies.txt file, browsers can export one with an extension (e.g. "Get cookies.txt")
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub import_path: PathBuf,
+
+    /// Environment whose cookie jar will receive the cookies
+    #[arg(short, long, value_name = "ENV")]
+    pub env: Option<String>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct WsdlImport {
+    /// Path to the WSDL file to import
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub import_path: PathBuf,
+
+    /// Collection name to save the requests to, defaults to the name of the WSDL definitions
+    pub collection_name: Option<String>,
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct PluginImport {
+    /// Name of the importer plugin
+    pub plugin: String,
+
+    /// Path to the file to import
+    #[clap(value_hint = clap::ValueHint::FilePath)]
+    pub import_path: PathBuf,
 }
\ No newline at end of file
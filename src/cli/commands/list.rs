@@ -0,0 +1,6 @@
+#[derive(clap::Args, Debug, Clone)]
+pub struct ListCommand {
+    /// Print the collections, their requests and their metadata as JSON, for scripts and external tools
+    #[arg(long)]
+    pub json: bool,
+}
@@ -0,0 +1,23 @@
+#[derive(clap::Args, Debug, Clone)]
+pub struct ListenCommand {
+    /// Port to listen on (defaults to the atac.toml value, or 8080)
+    #[arg(short, long)]
+    pub port: Option<u16>,
+
+    /// Status code of the canned response
+    #[arg(long, value_name = "STATUS_CODE")]
+    pub status_code: Option<u16>,
+
+    /// Body of the canned response
+    #[arg(long)]
+    pub body: Option<String>,
+
+    /// Add a header to the canned response
+    /// (can be used multiple times)
+    #[arg(long, action = clap::ArgAction::Append, num_args = 2, value_names = ["KEY", "VALUE"])]
+    pub add_header: Vec<String>,
+
+    /// Only print the request line, without the headers and the body
+    #[arg(long, default_value_t = false)]
+    pub hide_content: bool,
+}
@@ -7,3 +7,13 @@ pub mod completions;
 pub mod man;
 pub mod key;
 pub mod key_value;
+pub mod listen;
+pub mod monitor;
+pub mod description;
+pub mod docs;
+pub mod git;
+pub mod plugins;
+pub mod tag;
+pub mod think_time;
+pub mod send;
+pub mod list;
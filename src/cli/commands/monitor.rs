@@ -0,0 +1,14 @@
+#[derive(clap::Args, Debug, Clone)]
+pub struct MonitorCommand {
+    /// Only run the monitors of this collection
+    #[arg(long, value_name = "COLLECTION_NAME")]
+    pub collection: Option<String>,
+
+    /// Name of the environment to use, e.g. my_env (from file .env.my_env)
+    #[arg(long, value_name = "ENV_NAME")]
+    pub env: Option<String>,
+
+    /// Only print the failing checks
+    #[arg(long, default_value_t = false)]
+    pub failures_only: bool,
+}
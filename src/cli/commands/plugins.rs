@@ -0,0 +1,32 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct PluginsCommand {
+    #[command(subcommand)]
+    pub plugins_subcommand: PluginsSubcommand,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum PluginsSubcommand {
+    /// List the plugins found in the plugins directory and their hooks
+    List,
+
+    /// Export a collection or one of its requests with an exporter plugin
+    Export {
+        /// Name of the exporter plugin
+        plugin: String,
+
+        /// e.g. my_collection
+        collection_name: String,
+
+        /// Only export this request of the collection
+        #[arg(short, long)]
+        request: Option<String>,
+
+        /// File where to write the export. If empty, will print it to the standard output
+        #[arg(short, long, value_hint = clap::ValueHint::FilePath)]
+        output: Option<PathBuf>,
+    },
+}
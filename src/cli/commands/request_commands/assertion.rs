@@ -0,0 +1,36 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestAssertionCommand {
+    /// Print the assertions checked on each response, numbered
+    List,
+    /// Check that a response header is received, or with a value equal to or containing the given one
+    AddHeader {
+        /// e.g. content-type
+        name: String,
+
+        /// e.g. "application/json"
+        #[arg(long, conflicts_with_all = ["contains", "absent"])]
+        equals: Option<String>,
+
+        /// e.g. json
+        #[arg(long, conflicts_with = "absent")]
+        contains: Option<String>,
+
+        /// Check that the header is not received instead, e.g. x-debug
+        #[arg(long, default_value_t = false)]
+        absent: bool
+    },
+    /// Check that the response is received within a duration, redirects included
+    AddMaxDuration {
+        /// In milliseconds, e.g. 500
+        max_duration: u64
+    },
+    /// Delete an assertion
+    Delete {
+        /// Number of the assertion, as listed
+        number: usize
+    },
+    /// Delete all the assertions
+    Clear
+}
@@ -1,6 +1,6 @@
 use clap::Subcommand;
 use crate::cli::commands::key_value::KeyValueCommand;
-use crate::models::body::ContentType;
+use crate::models::body::{BodyFile, ContentType};
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum BodySubcommand {
@@ -17,15 +17,60 @@ pub enum BodySubcommand {
     Key {
         #[command(subcommand)]
         subcommand: KeyValueCommand
+    },
+
+    /// Add or delete a part, several parts can share the same name (multipart body only)
+    Part {
+        #[command(subcommand)]
+        subcommand: PartCommand
     }
 }
 
+#[derive(clap::Args, Debug, Clone)]
+pub struct AddPartCommand {
+    pub name: String,
+
+    /// Text, or path of the file with --file
+    pub value: String,
+
+    /// Send the file at the value path
+    #[arg(long)]
+    pub file: bool,
+
+    /// Content-Type of the part, e.g. image/png
+    #[arg(long)]
+    pub content_type: Option<String>,
+
+    /// Defaults to the name of the file
+    #[arg(long)]
+    pub file_name: Option<String>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum PartCommand {
+    /// Add a text part, or a file part with --file
+    Add(AddPartCommand),
+
+    /// Delete the part at the given index
+    Delete {
+        /// Index of the part, as printed by "part all"
+        index: usize
+    },
+
+    /// Print all the parts with their index
+    All
+}
+
 #[derive(Subcommand, Clone, Debug)]
 pub enum ContentTypeAsArg {
     NoBody,
 
     File {
-        file_path: String
+        file_path: String,
+
+        /// Sent instead of application/octet-stream, e.g. image/png
+        #[arg(long)]
+        content_type: Option<String>
     },
 
     Multipart,
@@ -51,20 +96,39 @@ pub enum ContentTypeAsArg {
     Javascript {
         javascript: String
     },
+
+    /// Encoded with the protobuf schema of the request, see "request protobuf"
+    Protobuf {
+        /// Message as JSON, e.g. '{"id": 1}'
+        json: String
+    },
+
+    /// JSON document sent as MessagePack
+    MessagePack {
+        json: String
+    },
+
+    /// JSON document sent as CBOR
+    Cbor {
+        json: String
+    },
 }
 
 impl ContentTypeAsArg {
     pub fn to_content_type(&self) -> ContentType {
         match self {
             ContentTypeAsArg::NoBody => ContentType::NoBody,
-            ContentTypeAsArg::File { file_path } => ContentType::File(file_path.clone()),
+            ContentTypeAsArg::File { file_path, content_type } => ContentType::File(BodyFile { path: file_path.clone(), content_type: content_type.clone() }),
             ContentTypeAsArg::Multipart => ContentType::Multipart(vec![]),
             ContentTypeAsArg::Form => ContentType::Form(vec![]),
             ContentTypeAsArg::Raw { text } => ContentType::Raw(text.clone()),
             ContentTypeAsArg::Json { json } => ContentType::Json(json.clone()),
             ContentTypeAsArg::Xml { xml } => ContentType::Xml(xml.clone()),
             ContentTypeAsArg::Html { html } => ContentType::Html(html.clone()),
-            ContentTypeAsArg::Javascript { javascript } => ContentType::Javascript(javascript.clone())
+            ContentTypeAsArg::Javascript { javascript } => ContentType::Javascript(javascript.clone()),
+            ContentTypeAsArg::Protobuf { json } => ContentType::Protobuf(json.clone()),
+            ContentTypeAsArg::MessagePack { json } => ContentType::MessagePack(json.clone()),
+            ContentTypeAsArg::Cbor { json } => ContentType::Cbor(json.clone())
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,27 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestConnectionPoolCommand {
+    /// Print the connection pool options of the request, the ones of the config are used otherwise
+    Get,
+    /// Set the connection pool options of the request, the options left empty are taken from the config
+    Set {
+        /// Seconds an idle connection is kept open, 90 by default
+        #[arg(long)]
+        idle_timeout: Option<u64>,
+
+        /// Idle connections kept open per host, 0 opens a new connection for each send
+        #[arg(long)]
+        max_idle_per_host: Option<usize>,
+
+        /// Seconds between the TCP keepalive probes, none are sent by default
+        #[arg(long)]
+        tcp_keepalive: Option<u64>,
+
+        /// Send the small packets without waiting (TCP_NODELAY), true by default
+        #[arg(long)]
+        tcp_nodelay: Option<bool>,
+    },
+    /// Remove the connection pool options of the request
+    Remove
+}
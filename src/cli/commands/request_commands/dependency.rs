@@ -0,0 +1,19 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestDependencyCommand {
+    /// Print the requests this one depends on
+    List,
+    /// Only send the request in the collection runs after this one succeeded, e.g. a create request
+    Add {
+        /// Name of a request of the same collection
+        request_name: String
+    },
+    /// Remove a dependency
+    Remove {
+        /// Name of a request of the same collection
+        request_name: String
+    },
+    /// Remove all the dependencies
+    Clear,
+}
@@ -0,0 +1,24 @@
+use crate::models::fuzz::{FuzzGenerator, FuzzTarget};
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct FuzzCommand {
+    /// Parts of the request to mutate, all of them by default
+    #[arg(short, long = "target", value_name = "TARGET")]
+    pub targets: Vec<FuzzTarget>,
+
+    /// Payload generators to use, all of them by default
+    #[arg(short, long = "generator", value_name = "GENERATOR")]
+    pub generators: Vec<FuzzGenerator>,
+
+    /// Only mutate the params, headers or body fields with this name (use "body" for a whole non-JSON body)
+    #[arg(short, long = "field", value_name = "NAME")]
+    pub fields: Vec<String>,
+
+    /// Also print the cases of the 2xx responses
+    #[arg(long, default_value_t = false)]
+    pub show_successes: bool,
+
+    /// Name of the environment to use, e.g. my_env (from file .env.my_env)
+    #[arg(long, value_name = "ENV_NAME", display_order = 98)]
+    pub env: Option<String>
+}
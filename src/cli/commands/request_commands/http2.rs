@@ -0,0 +1,31 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestHttp2Command {
+    /// Print the HTTP/2 options of the request, the ones of the config are used otherwise
+    Get,
+    /// Set the HTTP/2 options of the request, the options left empty are taken from the config
+    Set {
+        /// Speak HTTP/2 without negotiating it, needed for the cleartext HTTP/2 (h2c) servers
+        #[arg(long)]
+        prior_knowledge: Option<bool>,
+
+        /// Grow the flow control windows with the measured bandwidth, overrides the window sizes
+        #[arg(long)]
+        adaptive_window: Option<bool>,
+
+        /// Largest frame payload accepted, between 16384 and 16777215 bytes
+        #[arg(long)]
+        max_frame_size: Option<u32>,
+
+        /// Flow control window of each stream, in bytes
+        #[arg(long)]
+        initial_stream_window_size: Option<u32>,
+
+        /// Flow control window of the whole connection, in bytes
+        #[arg(long)]
+        initial_connection_window_size: Option<u32>,
+    },
+    /// Remove the HTTP/2 options of the request
+    Remove
+}
@@ -0,0 +1,18 @@
+#[derive(clap::Args, Debug, Clone)]
+pub struct LoadTestCommand {
+    /// Number of requests sent at the same time
+    #[arg(short, long)]
+    pub concurrency: Option<usize>,
+
+    /// Total number of requests to send
+    #[arg(short, long, conflicts_with = "duration")]
+    pub iterations: Option<u64>,
+
+    /// Send requests during this amount of time, e.g. 30s, 5m
+    #[arg(short, long)]
+    pub duration: Option<String>,
+
+    /// Name of the environment to use, e.g. my_env (from file .env.my_env)
+    #[arg(long, value_name = "ENV_NAME", display_order = 98)]
+    pub env: Option<String>
+}
@@ -7,3 +7,15 @@ pub mod body;
 pub mod scripts;
 pub mod send;
 pub mod setting;
+pub mod monitor;
+pub mod load_test;
+pub mod fuzz;
+pub mod schema;
+pub mod snapshot;
+pub mod protobuf;
+pub mod soap;
+pub mod pagination;
+pub mod connection_pool;
+pub mod http2;
+pub mod assertion;
+pub mod dependency;
@@ -0,0 +1,14 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestMonitorCommand {
+    /// Print the request monitor interval
+    Get,
+    /// Check the request on a schedule (in the TUI or with `atac monitor`)
+    Set {
+        /// Interval between two checks, e.g. 30s, 5m, 1h, 1d
+        every: String
+    },
+    /// Stop monitoring the request
+    Remove
+}
@@ -39,6 +39,10 @@ pub struct NewRequestCommand {
     #[arg(long, display_order = 16)]
     pub post_request_script: Option<String>,
 
+    /// Set a shell command the response body is piped through before being displayed
+    #[arg(long, display_order = 16)]
+    pub response_processor: Option<String>,
+
     /// Do not use config proxy
     #[arg(long, default_value_t = false, display_order = 17)]
     pub no_proxy: bool,
@@ -61,7 +65,23 @@ pub struct NewRequestCommand {
 
     /// Accept invalid hostnames
     #[arg(long, default_value_t = false, display_order = 22)]
-    pub accept_invalid_hostnames: bool
+    pub accept_invalid_hostnames: bool,
+
+    /// Send a notification when the request completes
+    #[arg(long, default_value_t = false, display_order = 23)]
+    pub notify: bool,
+
+    /// Max number of redirects to follow
+    #[arg(long, default_value_t = 10, display_order = 24)]
+    pub max_redirects: usize,
+
+    /// Do not re-send the body when following a 307 or a 308
+    #[arg(long, default_value_t = false, display_order = 25)]
+    pub no_redirect_body: bool,
+
+    /// Keep the Authorization header on cross-origin redirects
+    #[arg(long, default_value_t = false, display_order = 26)]
+    pub keep_redirect_auth: bool
 }
 
 #[derive(clap::Args, Debug, Clone)]
@@ -74,6 +94,23 @@ pub struct AuthArgs {
     /// Set a bearer token auth method
     #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 1, value_name = "TOKEN", display_order = 4)]
     pub auth_bearer_token: Vec<String>,
+
+    /// Set an HMAC-SHA256 signature auth method, hex encoded
+    /// (e.g. "{method}\n{path}\n{timestamp}\n{body_sha256}" X-Signature)
+    #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 3, value_names = ["SECRET", "STRING_TO_SIGN", "HEADER"], display_order = 5)]
+    pub auth_hmac: Vec<String>,
+
+    /// Set an NTLM auth method, the domain being optional
+    #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 2..=3, value_names = ["USERNAME", "PASSWORD", "DOMAIN"], display_order = 5)]
+    pub auth_ntlm: Vec<String>,
+
+    /// Set an OAuth 1.0a auth method signed with HMAC-SHA1 in the Authorization header, the token being optional
+    #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 2..=4, value_names = ["CONSUMER_KEY", "CONSUMER_SECRET", "TOKEN", "TOKEN_SECRET"], display_order = 5)]
+    pub auth_oauth1: Vec<String>,
+
+    /// Set an auth method computed by a plugin, the config being optional
+    #[arg(long, group = "auth", action = clap::ArgAction::Set, num_args = 1..=2, value_names = ["PLUGIN", "CONFIG"], display_order = 5)]
+    pub auth_plugin: Vec<String>,
 }
 
 #[derive(clap::Args, Debug, Clone)]
@@ -84,7 +121,7 @@ pub struct BodyArgs {
     pub body_file: Option<String>,
 
     /// Set a multipart form body
-    /// (adds a value each time used)
+    /// (adds a part each time used, a value starting with !! being a file path)
     #[arg(long, action = clap::ArgAction::Append, num_args = 2, value_names = ["KEY", "VALUE"], display_order = 8)]
     pub add_body_multipart: Vec<String>,
 
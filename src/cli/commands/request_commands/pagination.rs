@@ -0,0 +1,58 @@
+use std::path::PathBuf;
+
+use clap::Subcommand;
+
+use crate::models::pagination::PaginationStrategy;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestPaginationCommand {
+    /// Print how the next pages of the request are found
+    Get,
+    /// Set how the next pages of the request are found, followed with "request paginate"
+    Set {
+        /// Follow a next link, or increment a page or offset query param
+        #[arg(long, value_enum, default_value = "next-link")]
+        strategy: PaginationStrategy,
+
+        /// JSON path of the next page URL, e.g. $.links.next. The "next" relation of the Link header is used otherwise
+        #[arg(long)]
+        next_link_path: Option<String>,
+
+        /// Query param holding the page or the offset, e.g. page
+        #[arg(long)]
+        param: Option<String>,
+
+        /// Value of the param for the first page, 1 for a page and 0 for an offset by default
+        #[arg(long)]
+        start: Option<usize>,
+
+        /// Items per page, a shorter page is the last one. Needed by the offset strategy
+        #[arg(long)]
+        page_size: Option<usize>,
+
+        /// JSON path of the items array, e.g. $.data. The body itself when it is an array
+        #[arg(long)]
+        items_path: Option<String>,
+
+        /// Stop after this many pages, 100 by default
+        #[arg(long)]
+        max_pages: Option<usize>,
+    },
+    /// Remove the pagination of the request
+    Remove
+}
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct PaginateCommand {
+    /// Stop after this many pages, overrides the max pages of the pagination
+    #[arg(long)]
+    pub max_pages: Option<usize>,
+
+    /// Write the aggregated items to a file instead of the standard output
+    #[arg(long, value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Name of the environment to use, e.g. my_env (from file .env.my_env)
+    #[arg(long, value_name = "ENV_NAME", display_order = 98)]
+    pub env: Option<String>
+}
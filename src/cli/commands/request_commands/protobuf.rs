@@ -0,0 +1,23 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestProtobufCommand {
+    /// Print the proto files and the messages of the request
+    Get,
+    /// Encode the protobuf body and decode the responses with the messages of some proto files
+    Set {
+        /// Proto file, can be given several times, e.g. protos/shop.proto
+        #[arg(long = "file", required = true)]
+        files: Vec<String>,
+
+        /// Fully qualified name of the message sent, e.g. shop.v1.CreateOrder
+        #[arg(long)]
+        request_message: Option<String>,
+
+        /// Fully qualified name of the message received, e.g. shop.v1.Order
+        #[arg(long)]
+        response_message: Option<String>,
+    },
+    /// Stop encoding the body and decoding the responses
+    Remove
+}
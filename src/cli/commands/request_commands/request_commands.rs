@@ -1,14 +1,29 @@
 use clap::Subcommand;
 
+use crate::cli::commands::description::DescriptionCommand;
 use crate::cli::commands::key_value::KeyValueCommand;
+use crate::cli::commands::request_commands::assertion::RequestAssertionCommand;
 use crate::cli::commands::request_commands::auth::AuthCommand;
 use crate::cli::commands::request_commands::body::BodySubcommand;
+use crate::cli::commands::request_commands::dependency::RequestDependencyCommand;
+use crate::cli::commands::request_commands::load_test::LoadTestCommand;
+use crate::cli::commands::request_commands::fuzz::FuzzCommand;
+use crate::cli::commands::request_commands::protobuf::RequestProtobufCommand;
+use crate::cli::commands::request_commands::soap::RequestSoapCommand;
+use crate::cli::commands::request_commands::pagination::{PaginateCommand, RequestPaginationCommand};
+use crate::cli::commands::request_commands::connection_pool::RequestConnectionPoolCommand;
+use crate::cli::commands::request_commands::http2::RequestHttp2Command;
+use crate::cli::commands::request_commands::schema::RequestSchemaCommand;
+use crate::cli::commands::request_commands::snapshot::RequestSnapshotCommand;
 use crate::cli::commands::request_commands::method::MethodCommand;
+use crate::cli::commands::request_commands::monitor::RequestMonitorCommand;
 use crate::cli::commands::request_commands::new::NewRequestCommand;
 use crate::cli::commands::request_commands::scripts::ScriptsCommand;
 use crate::cli::commands::request_commands::send::SendCommand;
 use crate::cli::commands::request_commands::setting::SettingsCommand;
 use crate::cli::commands::request_commands::url::UrlCommand;
+use crate::cli::commands::tag::TagCommand;
+use crate::cli::commands::think_time::ThinkTimeCommand;
 use crate::cli::utils::arguments_validators::collection_slash_request_validator;
 
 #[derive(clap::Args, Debug, Clone)]
@@ -32,10 +47,25 @@ pub enum RequestSubcommand {
         #[arg(value_parser = collection_slash_request_validator)]
         collection_slash_request: (String, String),
 
+        /// Create the request from a template of the "templates" folder.
+        /// The URL, params, headers, scripts and settings options are applied on top of it
+        #[arg(long, value_name = "TEMPLATE_NAME", conflicts_with_all = ["method", "AuthArgs", "BodyArgs", "no_base_headers"])]
+        template: Option<String>,
+
         #[clap(flatten)]
         subcommand: NewRequestCommand
     },
 
+    /// Save a request as a template new requests can be created from
+    SaveAsTemplate {
+        /// Request to save, e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        /// Template name, an existing template with the same name is replaced
+        template_name: String
+    },
+
     /// Delete a request
     Delete {
         /// Request to delete, e.g. my_collection/my_request
@@ -53,6 +83,60 @@ pub enum RequestSubcommand {
         new_request_name: String
     },
     
+    /// Leave a request out of the collection runs, it can still be sent on its own
+    Skip {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+    },
+
+    /// Include a skipped request in the collection runs again
+    Unskip {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+    },
+
+    /// List, add or remove the requests of the collection that must succeed before the runs send this one
+    DependsOn {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestDependencyCommand
+    },
+
+    /// Get, set or remove the pause of the runs after the request, instead of the one of the collection
+    ThinkTime {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: ThinkTimeCommand
+    },
+
+    /// Get, set, edit or remove a request Markdown description
+    Description {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: DescriptionCommand
+    },
+
+    /// List, add or remove request tags, e.g. to send a subset of a collection with "collection send --tag"
+    Tag {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: TagCommand
+    },
+
     /// Get or set a request URL
     Url {
         /// e.g. my_collection/my_request
@@ -103,6 +187,16 @@ pub enum RequestSubcommand {
         subcommand: KeyValueCommand
     },
 
+    /// Get, set, add, delete, rename or toggle a cookie sent with this request only
+    Cookie {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: KeyValueCommand
+    },
+
     /// Get or set a request body
     Body {
         /// e.g. my_collection/my_request
@@ -113,7 +207,7 @@ pub enum RequestSubcommand {
         subcommand: BodySubcommand
     },
 
-    /// Get or set pre- and post-request scripts
+    /// Get or set the pre- and post-request scripts and the response processor
     Scripts {
         /// e.g. my_collection/my_request
         #[arg(value_parser = collection_slash_request_validator)]
@@ -129,6 +223,11 @@ pub enum RequestSubcommand {
         #[arg(value_parser = collection_slash_request_validator)]
         collection_slash_request: (String, String),
 
+        /// Body sent instead of the request one, which is left unchanged. "-" reads it from the standard input,
+        /// e.g. cat payload.json | atac request send my_collection/create --body -
+        #[arg(long, value_name = "BODY")]
+        body: Option<String>,
+
         #[clap(flatten)]
         subcommand: SendCommand
     },
@@ -142,4 +241,124 @@ pub enum RequestSubcommand {
         #[command(subcommand)]
         subcommand: SettingsCommand
     },
+
+    /// Get, set or remove a request monitor (scheduled check)
+    Monitor {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestMonitorCommand
+    },
+
+    /// Get or set the JSON Schema the response body is validated against after each send
+    Schema {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestSchemaCommand
+    },
+
+    /// List, add or delete the checks of the response headers and duration made after each send
+    Assertion {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestAssertionCommand
+    },
+
+    /// Get or set the proto files used to encode the protobuf body and decode the protobuf responses
+    Protobuf {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestProtobufCommand
+    },
+
+    /// Get or set the SOAP mode, sending the body in a SOAP envelope with the SOAP action
+    Soap {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestSoapCommand
+    },
+
+    /// Get or set how the next pages of a list endpoint are found
+    Pagination {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestPaginationCommand
+    },
+
+    /// Get or set the connection pool options of the request, overriding the ones of the config
+    ConnectionPool {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestConnectionPoolCommand
+    },
+
+    /// Get or set the HTTP/2 options of the request, overriding the ones of the config
+    Http2 {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestHttp2Command
+    },
+
+    /// Record a response body and compare the next responses to it
+    Snapshot {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[command(subcommand)]
+        subcommand: RequestSnapshotCommand
+    },
+
+    /// Send a request many times concurrently and print RPS, latency percentiles and errors
+    LoadTest {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[clap(flatten)]
+        subcommand: LoadTestCommand
+    },
+
+    /// Send the request with mutated params, headers and body fields, then group the responses by status code
+    Fuzz {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[clap(flatten)]
+        subcommand: FuzzCommand
+    },
+
+    /// Send the pages of the request until the last one, then print their aggregated items
+    Paginate {
+        /// e.g. my_collection/my_request
+        #[arg(value_parser = collection_slash_request_validator)]
+        collection_slash_request: (String, String),
+
+        #[clap(flatten)]
+        subcommand: PaginateCommand
+    },
 }
\ No newline at end of file
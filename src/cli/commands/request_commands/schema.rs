@@ -0,0 +1,27 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestSchemaCommand {
+    /// Print the JSON Schema the response body is validated against
+    Get,
+    /// Validate the response body against an inline JSON Schema
+    Set {
+        /// e.g. '{"type": "object", "required": ["id"]}'
+        schema: String
+    },
+    /// Validate the response body against a JSON or YAML schema file
+    SetFile {
+        /// e.g. schemas/user.json
+        path: String
+    },
+    /// Validate the response body against a schema from an OpenAPI document
+    SetOpenapi {
+        /// JSON or YAML OpenAPI document, e.g. openapi.yaml
+        file: String,
+
+        /// e.g. "#/components/schemas/User"
+        pointer: String
+    },
+    /// Stop validating the response body
+    Remove
+}
@@ -3,14 +3,14 @@ use crate::models::scripts::ScriptType;
 
 #[derive(Subcommand, Debug, Clone)]
 pub enum ScriptsCommand {
-    /// Print the current pre- or post-request script
+    /// Print the current pre-request script, post-request script or response processor command
     Get {
-        /// Pre or post
+        /// Pre, post or processor
         script_type: ScriptType
     },
-    /// Set a pre- or post-request script
+    /// Set a pre-request script, a post-request script or a response processor command
     Set {
-        /// Pre or post
+        /// Pre, post or processor
         script_type: ScriptType,
 
         /// Script to set, leave empty to set it to none
@@ -1,3 +1,8 @@
+use std::path::PathBuf;
+
+use clap::ValueEnum;
+use strum::Display;
+
 #[derive(clap::Args, Debug, Clone)]
 pub struct SendCommand {
     /// Hide response content
@@ -16,10 +21,30 @@ pub struct SendCommand {
     #[arg(long, default_value_t = false)]
     pub headers: bool,
 
+    /// Show the trailer fields received after the response body
+    #[arg(long, default_value_t = false)]
+    pub trailers: bool,
+
+    /// Show the charset the response body was decoded with
+    #[arg(long, default_value_t = false)]
+    pub charset: bool,
+
+    /// Show the addresses the host was resolved to, when the config sets a DNS resolver or the request an IP version
+    #[arg(long, default_value_t = false)]
+    pub dns: bool,
+
+    /// Show the request and response fields as exchanged, with the HTTP/2 pseudo-headers and HPACK representations
+    #[arg(long, default_value_t = false)]
+    pub wire: bool,
+
     /// Show the response cookies
     #[arg(long, default_value_t = false)]
     pub cookies: bool,
 
+    /// Show the response tests results (e.g. schema validation)
+    #[arg(long, default_value_t = false)]
+    pub tests: bool,
+
     /// Show the pre and post-request script console output
     #[arg(long, default_value_t = false)]
     pub console: bool,
@@ -28,7 +53,73 @@ pub struct SendCommand {
     #[arg(long, default_value_t = false)]
     pub request_name: bool,
 
+    /// Print each response in a stable format for scripts, instead of the parts asked above
+    #[arg(long, value_enum, value_name = "FORMAT")]
+    pub format: Option<SendOutputFormat>,
+
+    /// Exit with code 5 when a response status is not 2xx
+    #[arg(long, default_value_t = false)]
+    pub fail: bool,
+
+    /// Save the full response body to a file as received, regardless of the max response size
+    #[arg(long, visible_alias = "output-file", value_name = "FILE")]
+    pub output: Option<PathBuf>,
+
+    /// Send the CORS preflight a browser from this origin would send first, the verdicts are added to the tests
+    #[arg(long, value_name = "ORIGIN")]
+    pub cors_origin: Option<String>,
+
+    /// ETag of a previous response, sent as If-None-Match. The server answers 304 when the resource did not change
+    #[arg(long, value_name = "ETAG")]
+    pub if_none_match: Option<String>,
+
+    /// Last-Modified of a previous response, sent as If-Modified-Since. The server answers 304 when the resource did not change
+    #[arg(long, value_name = "DATE")]
+    pub if_modified_since: Option<String>,
+
+    /// Sends at most this many requests per second during the run, e.g. 2.5
+    #[arg(long, value_name = "RPS")]
+    pub requests_per_second: Option<f64>,
+
+    /// Requests of the run waiting for their response at the same time, 1 sends them one after the other.
+    /// A request may then be sent before the script of the previous one updated the environment
+    #[arg(long, value_name = "COUNT")]
+    pub concurrency: Option<usize>,
+
+    /// Minimum time between the start of two requests of the run, in milliseconds
+    #[arg(long, value_name = "MS")]
+    pub delay_ms: Option<u64>,
+
+    /// Times a request answered with a 429 is sent again, after its Retry-After or an exponential backoff. 0 disables it
+    #[arg(long, value_name = "COUNT")]
+    pub max_retries: Option<u32>,
+
+    /// Write a JUnit XML report of the run, one test suite per send, e.g. for the CI test reporting
+    #[arg(long, value_name = "FILE")]
+    pub junit: Option<PathBuf>,
+
+    /// Write a standalone HTML report of the run, with the statuses, durations, test results and response excerpts
+    #[arg(long, value_name = "FILE")]
+    pub html_report: Option<PathBuf>,
+
+    /// CSV or JSON dataset, the requests are sent once per row with the columns bound as {{variables}}
+    #[arg(long, value_name = "FILE")]
+    pub data: Option<PathBuf>,
+
     /// Name of the environment to use, e.g. my_env (from file .env.my_env)
     #[arg(long, value_name = "ENV_NAME", display_order = 98)]
     pub env: Option<String>
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, ValueEnum, Display)]
+pub enum SendOutputFormat {
+    /// One JSON object per response and per line, with the status, headers, body, tests and error
+    #[strum(to_string = "json")]
+    Json,
+    /// Response body only, the text bodies as displayed and the images as received
+    #[strum(to_string = "raw")]
+    Raw,
+    /// Status then one "name: value" line per response header
+    #[strum(to_string = "headers")]
+    Headers,
 }
\ No newline at end of file
@@ -16,9 +16,8 @@ pub enum SettingsCommand {
         /// Setting name to set status
         setting_name: RequestSettingName,
 
-        /// New state to apply to the setting
-        #[clap(action = clap::ArgAction::Set)]
-        new_state: bool
+        /// New state to apply to the setting, true/false, a number for max-redirects, max-size, latency, upload-limit, download-limit and watch-interval, a charset (e.g. Shift_JIS, auto) for charset, a tab (e.g. headers, auto, config) for result-tab, any/ipv4/ipv6 for ip-version, an address (e.g. 192.168.1.10, eth0, none) for local-address or a version (e.g. 1.2, config) for min-tls and max-tls
+        new_state: String
     }
 }
 
@@ -28,8 +27,47 @@ pub enum RequestSettingName {
     Proxy,
     /// Allow redirects
     Redirects,
+    /// Max number of redirects to follow
+    MaxRedirects,
+    /// Re-send the body when following a 307 or a 308
+    ResendBody,
+    /// Strip the Authorization header on cross-origin redirects
+    StripAuth,
     /// Store received cookies
     Cookies,
     /// Pretty print response content
-    Pretty
+    Pretty,
+    /// Notify on completion
+    Notify,
+    /// Response body kept in memory, in MiB (0 keeps the whole body)
+    MaxSize,
+    /// Charset used to decode the response body, detected when set to auto
+    Charset,
+    /// Result tab focused once the response is received, the config one when set to config
+    ResultTab,
+    /// Send the validators of the last response, the server answers 304 when nothing changed
+    Revalidate,
+    /// Delay added before sending the request and each of its redirects, in ms
+    Latency,
+    /// Upload bandwidth, in KiB/s (0 does not limit it)
+    UploadLimit,
+    /// Download bandwidth, in KiB/s (0 does not limit it)
+    DownloadLimit,
+    /// Interval the watched request is re-sent at in the TUI, in seconds (0 only re-sends it when its body files change)
+    WatchInterval,
+    /// Queue the sends of the request while it is pending in the TUI, instead of canceling it
+    QueueSends,
+    /// Add the "Expect: 100-continue" header to the large bodies and the files, the body is not held until the 100 Continue
+    #[value(alias = "expect-continue")]
+    ExpectContinueHeader,
+    /// Write the text body as the whole raw HTTP/1.1 request to a TCP or TLS connection, e.g. to test malformed requests
+    RawSocket,
+    /// Address family the host names resolve to, any, ipv4 or ipv6
+    IpVersion,
+    /// Local IP address the connections are bound to, or a network interface name on Linux (none removes it)
+    LocalAddress,
+    /// Oldest TLS version negotiated, e.g. 1.2, the config one when set to config
+    MinTls,
+    /// Newest TLS version negotiated, e.g. 1.3, the config one when set to config
+    MaxTls
 }
\ No newline at end of file
@@ -0,0 +1,25 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestSnapshotCommand {
+    /// Print the recorded response body and the ignored fields
+    Get,
+    /// Send the request and record its response as the new snapshot, also answered in offline mode
+    Update {
+        /// Name of the environment to use, e.g. my_env (from file .env.my_env)
+        #[arg(long, value_name = "ENV_NAME")]
+        env: Option<String>
+    },
+    /// Leave a volatile JSON field out of the comparison
+    Ignore {
+        /// Key name matched at any depth (e.g. updated_at) or path where * matches anything (e.g. /items/*/id)
+        rule: String
+    },
+    /// Compare a previously ignored field again
+    Unignore {
+        /// Previously ignored rule
+        rule: String
+    },
+    /// Delete the snapshot and its ignore rules
+    Remove
+}
@@ -0,0 +1,25 @@
+use clap::Subcommand;
+
+use crate::models::soap::SoapVersion;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum RequestSoapCommand {
+    /// Print the SOAP version and action of the request
+    Get,
+    /// Send the body in a SOAP envelope, with the SOAP action and content type
+    Set {
+        /// SOAP version of the envelope and content type
+        #[arg(long, value_enum, default_value = "1.1")]
+        version: SoapVersion,
+
+        /// SOAP action of the operation, e.g. http://example.com/GetWeather
+        #[arg(long)]
+        action: Option<String>,
+
+        /// XML put in the header of the envelope, can contain environment variables, e.g. a WS-Security element
+        #[arg(long)]
+        envelope_header: Option<String>,
+    },
+    /// Send the body as it is
+    Remove
+}
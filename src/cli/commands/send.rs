@@ -0,0 +1,16 @@
+use crate::cli::commands::request_commands::send::SendCommand;
+use crate::cli::utils::arguments_validators::collection_slash_request_validator;
+
+#[derive(clap::Args, Debug, Clone)]
+pub struct SendRequestCommand {
+    /// Request to send e.g. my_collection/my_request, picked among all the requests when omitted
+    #[arg(value_parser = collection_slash_request_validator)]
+    pub collection_slash_request: Option<(String, String)>,
+
+    /// Body sent instead of the request one, which is left unchanged. "-" reads it from the standard input
+    #[arg(long, value_name = "BODY")]
+    pub body: Option<String>,
+
+    #[clap(flatten)]
+    pub send_command: SendCommand
+}
@@ -0,0 +1,19 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum TagCommand {
+    /// Print the tags, one per line
+    List,
+    /// Add tags, e.g. smoke auth
+    Add {
+        #[arg(required = true)]
+        tags: Vec<String>
+    },
+    /// Remove tags
+    Remove {
+        #[arg(required = true)]
+        tags: Vec<String>
+    },
+    /// Remove all the tags
+    Clear,
+}
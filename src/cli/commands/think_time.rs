@@ -0,0 +1,17 @@
+use clap::Subcommand;
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ThinkTimeCommand {
+    /// Print the think time
+    Get,
+    /// Set the pause after the request, a random one between the two durations when a maximum is given
+    Set {
+        /// In milliseconds, e.g. 500
+        min_ms: u64,
+
+        /// In milliseconds, e.g. 1500
+        max_ms: Option<u64>
+    },
+    /// Remove the think time
+    Remove,
+}
@@ -0,0 +1,41 @@
+use thiserror::Error;
+
+/// Any other error, e.g. an unknown request or an unreadable file. Clap exits with 2 on invalid arguments
+pub const GENERIC_ERROR_EXIT_CODE: i32 = 1;
+
+/// Run of requests which failed in a way scripts can tell apart with the exit code
+#[derive(Error, Debug)]
+#[error("{message}")]
+pub struct SendFailure {
+    pub kind: SendFailureKind,
+    pub message: String,
+}
+
+/// When a run fails in several ways, the lowest exit code is kept
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SendFailureKind {
+    /// A request got no response, e.g. DNS, connection or TLS error
+    Network,
+    /// A test of a response failed
+    Tests,
+    /// A response status is not 2xx, with --fail, or is 4xx/5xx in a dataset run
+    Status,
+}
+
+impl SendFailureKind {
+    pub fn get_exit_code(&self) -> i32 {
+        match self {
+            SendFailureKind::Network => 3,
+            SendFailureKind::Tests => 4,
+            SendFailureKind::Status => 5
+        }
+    }
+}
+
+/// Exit code of a command error, the send failures have their own
+pub fn get_exit_code(error: &anyhow::Error) -> i32 {
+    match error.downcast_ref::<SendFailure>() {
+        None => GENERIC_ERROR_EXIT_CODE,
+        Some(send_failure) => send_failure.kind.get_exit_code()
+    }
+}
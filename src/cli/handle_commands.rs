@@ -4,18 +4,21 @@ use crate::cli::args::Command::*;
 use crate::cli::cli_logic::completions::generate_completions;
 use crate::cli::cli_logic::man::generate_man_page;
 use crate::cli::commands::collection_commands::collection_commands::{CollectionCommand, CollectionSubcommand};
+use crate::cli::commands::description::DescriptionCommand;
 use crate::cli::commands::env::{EnvCommand, EnvSubcommand};
 use crate::cli::commands::key::KeyCommand;
 use crate::cli::commands::import::ImportType;
 use crate::cli::commands::key_value::KeyValueCommand;
 use crate::cli::commands::request_commands::auth::{AuthCommand};
-use crate::cli::commands::request_commands::body::BodySubcommand;
+use crate::cli::commands::request_commands::body::{BodySubcommand, PartCommand};
 use crate::cli::commands::request_commands::method::MethodCommand;
+use crate::cli::commands::request_commands::monitor::RequestMonitorCommand;
 use crate::cli::commands::request_commands::request_commands::{RequestCommand, RequestSubcommand};
 use crate::cli::commands::request_commands::scripts::ScriptsCommand;
 use crate::cli::commands::request_commands::setting::SettingsCommand;
 use crate::cli::commands::request_commands::url::UrlCommand;
-use crate::panic_error;
+use crate::cli::exit_code::get_exit_code;
+use crate::exit_with_error;
 
 impl App<'_> {
     pub async fn handle_command(&mut self, command: Command) {
@@ -23,23 +26,42 @@ impl App<'_> {
             Collection(collection_command) => self.handle_collection_command(collection_command).await,
             
             Request(request_command) => self.handle_request_command(request_command).await,
+
+            List(list_command) => self.cli_list(list_command),
+
+            Send(send_request_command) => self.cli_send_picked_request(send_request_command).await,
             
             Import(import_command) => match &import_command.import_type {
                 ImportType::Postman(postman_import) => self.import_postman_collection(postman_import),
-                ImportType::Curl(curl_import) => self.import_curl_file(curl_import)
+                ImportType::Curl(curl_import) => self.import_curl_file(curl_import),
+                ImportType::Cookies(cookies_import) => self.import_cookies_file(cookies_import),
+                ImportType::Wsdl(wsdl_import) => self.import_wsdl_file(wsdl_import),
+                ImportType::Plugin(plugin_import) => self.import_plugin_file(plugin_import)
             },
 
             Try(try_command) => self.try_request(&try_command.new_request_command, &try_command.send_command).await,
 
             Env(env_command) => self.handle_env_commands(env_command),
 
+            Listen(listen_command) => self.cli_listen(listen_command).await,
+
+            Monitor(monitor_command) => self.cli_monitor(monitor_command).await,
+
+            Docs(docs_command) => self.cli_docs(docs_command),
+
+            Git(git_command) => self.cli_git(git_command),
+
+            Plugins(plugins_command) => self.cli_plugins(plugins_command),
+
             Completions(completions_command) => generate_completions(completions_command),
+
+            CompleteNames(complete_names_command) => self.cli_print_completed_names(complete_names_command.names),
             
             Man(_) => generate_man_page()
         };
 
         if let Err(error) = result {
-            panic_error(error.to_string());
+            exit_with_error(error.to_string(), get_exit_code(&error));
         }
     }
 
@@ -47,6 +69,7 @@ impl App<'_> {
         if let Some(environment_name) = &collection_command.env {
             let environment_index = self.find_environment(&environment_name)?;
             self.selected_environment = environment_index;
+            self.load_cookie_jar();
         }
 
         match &collection_command.collection_subcommand {
@@ -55,23 +78,45 @@ impl App<'_> {
             CollectionSubcommand::New { collection_name } => self.new_collection(collection_name.clone()),
             CollectionSubcommand::Delete { collection_name } => self.cli_delete_collection(collection_name),
             CollectionSubcommand::Rename { collection_name, new_collection_name } => self.cli_rename_collection(collection_name, new_collection_name.clone()),
-            CollectionSubcommand::Send { collection_name, subcommand } => self.cli_send_collection(collection_name, subcommand).await,
+            CollectionSubcommand::Layout { collection_name, layout } => self.cli_collection_layout(collection_name, *layout),
+            CollectionSubcommand::Format { collection_name, file_format } => self.cli_collection_file_format(collection_name, *file_format),
+            CollectionSubcommand::Encrypt { collection_name } => self.cli_collection_encryption(collection_name, true),
+            CollectionSubcommand::Decrypt { collection_name } => self.cli_collection_encryption(collection_name, false),
+            CollectionSubcommand::Description { collection_name, subcommand } => self.cli_collection_description(collection_name, subcommand),
+            CollectionSubcommand::Login { collection_name, subcommand } => self.cli_collection_login_request(collection_name, subcommand),
+            CollectionSubcommand::Hook { collection_name, subcommand } => self.cli_collection_hooks(collection_name, subcommand),
+            CollectionSubcommand::ThinkTime { collection_name, subcommand } => self.cli_collection_think_time(collection_name, subcommand),
+            CollectionSubcommand::Tag { collection_name, subcommand } => self.cli_collection_tags(collection_name, subcommand),
+            CollectionSubcommand::Graph { collection_name } => self.cli_print_execution_graph(collection_name),
+            CollectionSubcommand::Send { collection_name, tags, from, subcommand } => self.cli_send_collection(collection_name, tags, from.as_deref(), subcommand).await,
         }
     }
 
     async fn handle_request_command(&mut self, request_command: &RequestCommand) -> anyhow::Result<()> {
         // Since all the request commands need the collection_slash_request argument, it's preferable to parse it from here
         let (collection_index, request_index) = match &request_command.request_subcommand {
-            RequestSubcommand::Info { collection_slash_request } | RequestSubcommand::Delete { collection_slash_request }| RequestSubcommand::Rename { collection_slash_request, .. } | RequestSubcommand::Url { collection_slash_request, .. } | RequestSubcommand::Method { collection_slash_request, .. } | RequestSubcommand::Params { collection_slash_request, .. } | RequestSubcommand::Auth { collection_slash_request, .. } | RequestSubcommand::Header { collection_slash_request, .. } | RequestSubcommand::Body { collection_slash_request, .. } | RequestSubcommand::Scripts { collection_slash_request, .. } | RequestSubcommand::Send { collection_slash_request, .. } | RequestSubcommand::Settings { collection_slash_request, .. } => self.find_collection_slash_request(&collection_slash_request.0, &collection_slash_request.1)?,
+            RequestSubcommand::Info { collection_slash_request } | RequestSubcommand::Delete { collection_slash_request }| RequestSubcommand::Rename { collection_slash_request, .. } | RequestSubcommand::Skip { collection_slash_request } | RequestSubcommand::Unskip { collection_slash_request } | RequestSubcommand::DependsOn { collection_slash_request, .. } | RequestSubcommand::ThinkTime { collection_slash_request, .. } | RequestSubcommand::Description { collection_slash_request, .. } | RequestSubcommand::Tag { collection_slash_request, .. } | RequestSubcommand::Url { collection_slash_request, .. } | RequestSubcommand::Method { collection_slash_request, .. } | RequestSubcommand::Params { collection_slash_request, .. } | RequestSubcommand::Auth { collection_slash_request, .. } | RequestSubcommand::Header { collection_slash_request, .. } | RequestSubcommand::Cookie { collection_slash_request, .. } | RequestSubcommand::Body { collection_slash_request, .. } | RequestSubcommand::Scripts { collection_slash_request, .. } | RequestSubcommand::Send { collection_slash_request, .. } | RequestSubcommand::Settings { collection_slash_request, .. } | RequestSubcommand::Monitor { collection_slash_request, .. } | RequestSubcommand::Schema { collection_slash_request, .. } | RequestSubcommand::Assertion { collection_slash_request, .. } | RequestSubcommand::Protobuf { collection_slash_request, .. } | RequestSubcommand::Soap { collection_slash_request, .. } | RequestSubcommand::Pagination { collection_slash_request, .. } | RequestSubcommand::ConnectionPool { collection_slash_request, .. } | RequestSubcommand::Http2 { collection_slash_request, .. } | RequestSubcommand::Snapshot { collection_slash_request, .. } | RequestSubcommand::LoadTest { collection_slash_request, .. } | RequestSubcommand::Fuzz { collection_slash_request, .. } | RequestSubcommand::Paginate { collection_slash_request, .. } | RequestSubcommand::SaveAsTemplate { collection_slash_request, .. } => self.find_collection_slash_request(&collection_slash_request.0, &collection_slash_request.1)?,
             // Specific case
-            RequestSubcommand::New { collection_slash_request, subcommand } => return self.cli_new_request(collection_slash_request.clone(), subcommand.clone()),
+            RequestSubcommand::New { collection_slash_request, template, subcommand } => return self.cli_new_request(collection_slash_request.clone(), template.as_deref(), subcommand.clone()),
         };
 
         match &request_command.request_subcommand {
             RequestSubcommand::Info { .. } => self.cli_describe_request(collection_index, request_index),
             RequestSubcommand::Delete { .. } => self.delete_request(collection_index, request_index),
             RequestSubcommand::Rename { new_request_name, .. } => self.rename_request(collection_index, request_index, new_request_name.clone()),
+            RequestSubcommand::Skip { .. } => self.modify_request_skipped(collection_index, request_index, true),
+            RequestSubcommand::Unskip { .. } => self.modify_request_skipped(collection_index, request_index, false),
+            RequestSubcommand::DependsOn { subcommand, .. } => self.cli_request_dependencies(collection_index, request_index, subcommand),
             RequestSubcommand::New { .. } => panic!("Should not happen"),
+            RequestSubcommand::SaveAsTemplate { template_name, .. } => self.cli_save_request_as_template(collection_index, request_index, template_name.clone()),
+            RequestSubcommand::Description { subcommand, .. } => match subcommand {
+                DescriptionCommand::Get => self.cli_print_request_description(collection_index, request_index),
+                DescriptionCommand::Set { description } => self.modify_request_description(collection_index, request_index, Some(description.clone())),
+                DescriptionCommand::Edit => self.cli_edit_request_description(collection_index, request_index),
+                DescriptionCommand::Remove => self.modify_request_description(collection_index, request_index, None)
+            },
+            RequestSubcommand::Tag { subcommand, .. } => self.cli_request_tags(collection_index, request_index, subcommand),
+            RequestSubcommand::ThinkTime { subcommand, .. } => self.cli_request_think_time(collection_index, request_index, subcommand),
             RequestSubcommand::Url { subcommand, .. } => match subcommand {
                 UrlCommand::Get => self.cli_print_request_url(collection_index, request_index),
                 UrlCommand::Set { new_url } => self.modify_request_url(collection_index, request_index, new_url.clone())
@@ -136,9 +181,40 @@ impl App<'_> {
                     _ => panic!("Should not happen")
                 }
             },
+            RequestSubcommand::Cookie { subcommand, .. } => {
+                let key = match subcommand {
+                    KeyValueCommand::Key(key_command) => match key_command {
+                        // Specific case
+                        KeyCommand::Add { key, value } => return self.create_new_request_cookie(collection_index, request_index, key.clone(), value.clone()),
+                        // Otherwise, get the key
+                        KeyCommand::Get { key } | KeyCommand::Set { key, .. } | KeyCommand::Delete { key } | KeyCommand::Rename { key, .. } => key
+                    },
+                    KeyValueCommand::Toggle { key, .. } => key,
+                    KeyValueCommand::All => return self.cli_print_request_cookies(collection_index, request_index)
+                };
+
+                let cookie_index = self.find_request_cookie(collection_index, request_index, key)?;
+
+                match subcommand {
+                    KeyValueCommand::Key(key_command) => match key_command {
+                        KeyCommand::Get { .. } => self.cli_print_request_cookie(collection_index, request_index, cookie_index),
+                        KeyCommand::Set { value, .. } => self.modify_request_cookie(collection_index, request_index, value.clone(), 1, cookie_index),
+                        KeyCommand::Delete { .. } => self.delete_request_cookie(collection_index, request_index, cookie_index),
+                        KeyCommand::Rename { new_key, .. } => self.modify_request_cookie(collection_index, request_index, new_key.clone(), 0, cookie_index),
+                        _ => panic!("Should not happen")
+                    },
+                    KeyValueCommand::Toggle { state, .. } => self.toggle_request_cookie(collection_index, request_index, *state, cookie_index),
+                    _ => panic!("Should not happen")
+                }
+            },
             RequestSubcommand::Body { subcommand, .. } => match subcommand {
                 BodySubcommand::Get => self.cli_print_request_body(collection_index, request_index),
                 BodySubcommand::Set { content_type } => self.modify_request_content_type(collection_index, request_index, content_type.to_content_type()),
+                BodySubcommand::Part { subcommand } => match subcommand {
+                    PartCommand::Add(add_part_command) => self.cli_add_multipart_part(collection_index, request_index, add_part_command),
+                    PartCommand::Delete { index } => self.delete_multipart_part(collection_index, request_index, *index),
+                    PartCommand::All => self.cli_print_all_form_data(collection_index, request_index)
+                },
                 BodySubcommand::Key { subcommand } => {
                     let key = match subcommand {
                         KeyValueCommand::Key(key_command) => match key_command {
@@ -170,22 +246,43 @@ impl App<'_> {
                 ScriptsCommand::Get { script_type } => self.cli_print_request_script(collection_index, request_index, script_type),
                 ScriptsCommand::Set { script_type, script } => self.modify_request_script(collection_index, request_index, script_type, script.clone())
             },
-            RequestSubcommand::Send { subcommand, .. } => self.cli_send_request(collection_index, request_index, subcommand).await,
+            RequestSubcommand::Send { body, subcommand, .. } => self.cli_send_request(collection_index, request_index, body.as_deref(), subcommand).await,
             RequestSubcommand::Settings { subcommand, .. } => match subcommand {
                 SettingsCommand::All => self.cli_print_request_settings(collection_index, request_index),
                 SettingsCommand::Get { setting_name } => self.cli_print_request_setting(collection_index, request_index, setting_name),
                 SettingsCommand::Set { setting_name, new_state: new_status } => self.cli_modify_request_setting(collection_index, request_index, setting_name, new_status),
-            }
+            },
+            RequestSubcommand::Monitor { subcommand, .. } => match subcommand {
+                RequestMonitorCommand::Get => self.cli_print_request_monitor(collection_index, request_index),
+                RequestMonitorCommand::Set { every } => self.cli_set_request_monitor(collection_index, request_index, every.clone()),
+                RequestMonitorCommand::Remove => self.modify_request_monitor(collection_index, request_index, None),
+            },
+            RequestSubcommand::Schema { subcommand, .. } => self.cli_request_schema(collection_index, request_index, subcommand),
+            RequestSubcommand::Assertion { subcommand, .. } => self.cli_request_assertion(collection_index, request_index, subcommand),
+            RequestSubcommand::Protobuf { subcommand, .. } => self.cli_request_protobuf(collection_index, request_index, subcommand),
+            RequestSubcommand::Soap { subcommand, .. } => self.cli_request_soap(collection_index, request_index, subcommand),
+            RequestSubcommand::Pagination { subcommand, .. } => self.cli_request_pagination(collection_index, request_index, subcommand),
+            RequestSubcommand::ConnectionPool { subcommand, .. } => self.cli_request_connection_pool(collection_index, request_index, subcommand),
+            RequestSubcommand::Http2 { subcommand, .. } => self.cli_request_http2(collection_index, request_index, subcommand),
+            RequestSubcommand::Snapshot { subcommand, .. } => self.cli_request_snapshot(collection_index, request_index, subcommand).await,
+            RequestSubcommand::LoadTest { subcommand, .. } => self.cli_load_test_request(collection_index, request_index, subcommand).await,
+            RequestSubcommand::Fuzz { subcommand, .. } => self.cli_fuzz_request(collection_index, request_index, subcommand).await,
+            RequestSubcommand::Paginate { subcommand, .. } => self.cli_paginate_request(collection_index, request_index, subcommand).await,
         }
     }
 
     fn handle_env_commands(&mut self, env_command: &EnvCommand) -> anyhow::Result<()> {
         let env_index = match &env_command.env_subcommand {
-            EnvSubcommand::Info { env_name } | EnvSubcommand::Key { env_name, .. }=> self.find_environment(env_name)?
+            EnvSubcommand::Info { env_name } |
+            EnvSubcommand::Encrypt { env_name } |
+            EnvSubcommand::Decrypt { env_name } |
+            EnvSubcommand::Key { env_name, .. }=> self.find_environment(env_name)?
         };
 
         match &env_command.env_subcommand {
             EnvSubcommand::Info { .. } => self.cli_describe_env(env_index),
+            EnvSubcommand::Encrypt { .. } => self.modify_environment_encryption(env_index, true),
+            EnvSubcommand::Decrypt { .. } => self.modify_environment_encryption(env_index, false),
             EnvSubcommand::Key { subcommand, .. } => match subcommand {
                 KeyCommand::Get { key } => self.get_env_value(env_index, key),
                 KeyCommand::Set { key, value } => self.set_env_value(env_index, key, value.clone()),
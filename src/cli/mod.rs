@@ -1,5 +1,6 @@
 pub mod args;
-mod utils;
+pub(crate) mod utils;
 mod cli_logic;
 mod handle_commands;
-mod commands;
\ No newline at end of file
+mod commands;
+mod exit_code;
\ No newline at end of file
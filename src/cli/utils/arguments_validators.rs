@@ -33,4 +33,12 @@ pub fn key_value_array_validator(arg: &str) -> Result<KeyValue, Error> {
         enabled: true,
         data: (pair.0.to_string(), pair.1.to_string()),
     })
-}
\ No newline at end of file
+}
+
+/// e.g. "base_url=https://staging.example.com", the value may hold other "=" characters
+pub fn variable_override_validator(arg: &str) -> Result<(String, String), String> {
+    match arg.split_once('=') {
+        Some((key, value)) if !key.trim().is_empty() => Ok((key.trim().to_string(), value.to_string())),
+        _ => Err(String::from("expected KEY=VALUE, e.g. base_url=https://staging.example.com"))
+    }
+}
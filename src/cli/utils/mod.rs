@@ -1,3 +1,4 @@
 pub(super) mod macros;
-pub(super) mod arguments_validators;
-mod collection;
\ No newline at end of file
+pub(crate) mod arguments_validators;
+mod collection;
+pub(super) mod picker;
\ No newline at end of file
@@ -0,0 +1,161 @@
+use std::io::{stderr, IsTerminal, Write};
+
+use anyhow::anyhow;
+use ratatui::crossterm::cursor::{MoveToColumn, MoveUp};
+use ratatui::crossterm::event::{read, Event, KeyCode, KeyEventKind, KeyModifiers};
+use ratatui::crossterm::style::Stylize;
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode, size, Clear, ClearType};
+use ratatui::crossterm::QueueableCommand;
+
+/// Choices listed under the query at once
+const PICKER_HEIGHT: usize = 10;
+
+/// Narrow the choices down by typing some of their characters in order, e.g. "crus" for "crud/users", then pick one with enter.
+/// Drawn on the standard error so that the output of the command stays clean. None when cancelled with escape
+pub fn pick(prompt: &str, choices: &[String]) -> anyhow::Result<Option<usize>> {
+    if !stderr().is_terminal() {
+        return Err(anyhow!("No terminal to pick in"));
+    }
+
+    enable_raw_mode()?;
+    let picked = run_picker(prompt, choices);
+    disable_raw_mode()?;
+
+    return picked;
+}
+
+fn run_picker(prompt: &str, choices: &[String]) -> anyhow::Result<Option<usize>> {
+    let mut query = String::new();
+    let mut selection: usize = 0;
+
+    loop {
+        let matches = get_fuzzy_matches(&query, choices);
+        selection = selection.min(matches.len().saturating_sub(1));
+
+        draw_picker(prompt, &query, choices, &matches, selection)?;
+
+        let key = match read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue
+        };
+
+        let is_control = key.modifiers.contains(KeyModifiers::CONTROL);
+
+        match key.code {
+            KeyCode::Enter if !matches.is_empty() => {
+                clear_picker()?;
+                return Ok(Some(matches[selection]));
+            },
+            KeyCode::Esc => {
+                clear_picker()?;
+                return Ok(None);
+            },
+            KeyCode::Char('c') if is_control => {
+                clear_picker()?;
+                return Ok(None);
+            },
+            KeyCode::Up => selection = selection.saturating_sub(1),
+            KeyCode::Char('p') if is_control => selection = selection.saturating_sub(1),
+            KeyCode::Down if selection + 1 < matches.len() => selection += 1,
+            KeyCode::Char('n') if is_control && selection + 1 < matches.len() => selection += 1,
+            KeyCode::Backspace => {
+                query.pop();
+                selection = 0;
+            },
+            KeyCode::Char(char) if !is_control => {
+                query.push(char);
+                selection = 0;
+            },
+            _ => {}
+        }
+    }
+}
+
+/// The query line then the matches, the cursor is put back at the end of the query
+fn draw_picker(prompt: &str, query: &str, choices: &[String], matches: &[usize], selection: usize) -> anyhow::Result<()> {
+    let mut stderr = stderr();
+
+    // The lines are cut to the terminal width, a wrapped line would shift the cursor moves
+    let max_width = usize::from(size()?.0.saturating_sub(3));
+
+    stderr.queue(MoveToColumn(0))?.queue(Clear(ClearType::FromCursorDown))?;
+    write!(stderr, "{prompt}{query}")?;
+
+    let first_visible_match = selection.saturating_sub(PICKER_HEIGHT - 1);
+    let visible_matches = matches.iter().skip(first_visible_match).take(PICKER_HEIGHT);
+
+    let mut drawn_lines: u16 = 0;
+
+    for (index, choice_index) in visible_matches.enumerate() {
+        let choice: String = choices[*choice_index].chars().take(max_width).collect();
+
+        match first_visible_match + index == selection {
+            true => write!(stderr, "\r\n{}", format!("> {choice}").reverse())?,
+            false => write!(stderr, "\r\n  {choice}")?
+        }
+
+        drawn_lines += 1;
+    }
+
+    write!(stderr, "\r\n{}", format!("{}/{}", matches.len(), choices.len()).dark_grey())?;
+    drawn_lines += 1;
+
+    let query_end = (prompt.chars().count() + query.chars().count()) as u16;
+
+    stderr.queue(MoveUp(drawn_lines))?.queue(MoveToColumn(query_end))?;
+    stderr.flush()?;
+
+    Ok(())
+}
+
+fn clear_picker() -> anyhow::Result<()> {
+    let mut stderr = stderr();
+
+    stderr.queue(MoveToColumn(0))?.queue(Clear(ClearType::FromCursorDown))?;
+    stderr.flush()?;
+
+    Ok(())
+}
+
+/// Indexes of the choices holding the characters of the query in order, the best matches first.
+/// The consecutive characters and the ones starting a word score higher, the ties keep the choices order
+pub fn get_fuzzy_matches(query: &str, choices: &[String]) -> Vec<usize> {
+    let mut scored_matches: Vec<(usize, usize)> = choices
+        .iter()
+        .enumerate()
+        .filter_map(|(index, choice)| get_fuzzy_score(query, choice).map(|score| (index, score)))
+        .collect();
+
+    scored_matches.sort_by(|(_, score), (_, other_score)| other_score.cmp(score));
+
+    return scored_matches
+        .into_iter()
+        .map(|(index, _)| index)
+        .collect();
+}
+
+/// None when the choice does not hold the characters of the query in order, case-insensitively
+fn get_fuzzy_score(query: &str, choice: &str) -> Option<usize> {
+    let choice_chars: Vec<char> = choice.to_lowercase().chars().collect();
+
+    let mut score = 0;
+    let mut next_position = 0;
+
+    for query_char in query.to_lowercase().chars() {
+        let position = (next_position..choice_chars.len()).find(|position| choice_chars[*position] == query_char)?;
+
+        score += 1;
+
+        if position > 0 && position == next_position {
+            score += 5;
+        }
+
+        if position == 0 || matches!(choice_chars[position - 1], '/' | '_' | '-' | ' ' | '.') {
+            score += 3;
+        }
+
+        next_position = position + 1;
+    }
+
+    return Some(score);
+}
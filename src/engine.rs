@@ -0,0 +1,205 @@
+//! Send the requests of ATAC collections from other Rust tools, without the TUI nor the CLI.
+//! Enabled with the "library" feature.
+//!
+//! ```no_run
+//! use atac::engine::{Engine, EngineError};
+//!
+//! # async fn run() -> Result<(), EngineError> {
+//! let collection = Engine::read_collection("my_collection.json")?;
+//! let environment = Engine::read_environment(".env.staging")?;
+//!
+//! let engine = Engine::new()
+//!     .with_environment(environment)
+//!     .with_variable("token", "my_token")
+//!     .with_variable_override("base_url=https://staging.example.com")?;
+//!
+//! for (request_name, response) in engine.send_collection(&collection).await? {
+//!     println!("{request_name}: {:?}", response?.result);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use reqwest_cookie_store::CookieStoreRwLock;
+use thiserror::Error;
+
+use crate::app::business_logic::environment::VariableResolver;
+use crate::app::business_logic::request::send::{send_request, PrepareRequestError, RequestResponseError, SendContext};
+use crate::app::files::collection::{get_collection_directory_format, get_collection_file_format, read_collection_directory, read_collection_file};
+use crate::app::files::environment::{get_environment_name, read_environment_file};
+use crate::cli::utils::arguments_validators::variable_override_validator;
+use crate::models::dependency::{DependencyError, ExecutionPlan};
+
+pub use crate::app::files::config::Config;
+pub use crate::models::collection::Collection;
+pub use crate::models::environment::Environment;
+pub use crate::models::request::Request;
+pub use crate::models::response::{RequestResponse, RequestResult, ResponseContent};
+
+#[derive(Error, Debug)]
+pub enum EngineError {
+    #[error("\"{0}\" is neither a collection file nor a collection directory")]
+    NotACollection(String),
+    #[error("{0}")]
+    CouldNotRead(String),
+    #[error("Invalid variable \"{0}\", {1}")]
+    InvalidVariable(String, String),
+    #[error(transparent)]
+    Dependency(#[from] DependencyError),
+    #[error(transparent)]
+    PrepareRequest(#[from] PrepareRequestError),
+    #[error(transparent)]
+    RequestResponse(#[from] RequestResponseError),
+}
+
+/// Prepares and sends the requests the same way as the app, with its own config, environment and cookie jar.
+/// The plugins are not loaded, and the changes the scripts make to the environment are kept in memory
+pub struct Engine {
+    config: Config,
+    environment: Option<Arc<RwLock<Environment>>>,
+    variables: Vec<(String, String)>,
+    cookie_store: Arc<CookieStoreRwLock>,
+    offline: bool,
+}
+
+impl Default for Engine {
+    fn default() -> Self {
+        Engine::new()
+    }
+}
+
+impl Engine {
+    /// Default config, no environment
+    pub fn new() -> Engine {
+        Engine {
+            config: Config::default(),
+            environment: None,
+            variables: vec![],
+            cookie_store: Arc::new(CookieStoreRwLock::default()),
+            offline: false,
+        }
+    }
+
+    /// e.g. parsed from an atac.toml file
+    pub fn with_config(mut self, config: Config) -> Engine {
+        self.config = config;
+        self
+    }
+
+    /// The environment file is never written
+    pub fn with_environment(mut self, mut environment: Environment) -> Engine {
+        environment.path = PathBuf::new();
+        self.environment = Some(Arc::new(RwLock::new(environment)));
+        self
+    }
+
+    /// Value of a {{KEY}} variable, prevailing over the environment one like the --var option, the last one prevails when a key is repeated
+    pub fn with_variable(mut self, key: impl Into<String>, value: impl Into<String>) -> Engine {
+        self.variables.push((key.into(), value.into()));
+        self
+    }
+
+    /// "KEY=VALUE" variable parsed as the --var option does, e.g. forwarded from the arguments of the calling tool
+    pub fn with_variable_override(mut self, variable_override: &str) -> Result<Engine, EngineError> {
+        let variable = variable_override_validator(variable_override)
+            .map_err(|error| EngineError::InvalidVariable(variable_override.to_string(), error))?;
+
+        self.variables.push(variable);
+        Ok(self)
+    }
+
+    /// Answer the requests with their snapshots instead of the network
+    pub fn with_offline(mut self, offline: bool) -> Engine {
+        self.offline = offline;
+        self
+    }
+
+    /// Environment as modified by the scripts of the sent requests
+    pub fn get_environment(&self) -> Option<Environment> {
+        return self.environment.as_ref().map(|environment| environment.read().clone());
+    }
+
+    /// Collection file, or collection directory of the file per request layout
+    pub fn read_collection(path: impl AsRef<Path>) -> Result<Collection, EngineError> {
+        let path = path.as_ref();
+
+        let collection = match path.is_dir() {
+            true => match get_collection_directory_format(path) {
+                None => return Err(EngineError::NotACollection(path.display().to_string())),
+                Some(file_format) => read_collection_directory(path, file_format)
+            },
+            false => match get_collection_file_format(path) {
+                None => return Err(EngineError::NotACollection(path.display().to_string())),
+                Some(file_format) => read_collection_file(path, file_format)
+            }
+        };
+
+        return collection.map_err(|error| EngineError::CouldNotRead(error.to_string()));
+    }
+
+    /// ".env.<name>" file
+    pub fn read_environment(path: impl AsRef<Path>) -> Result<Environment, EngineError> {
+        let path = path.as_ref();
+
+        let values = read_environment_file(path).map_err(|error| EngineError::CouldNotRead(error.to_string()))?;
+
+        Ok(Environment {
+            name: get_environment_name(path),
+            values,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// The response is also stored in the request
+    pub async fn send(&self, local_request: &Arc<RwLock<Request>>) -> Result<RequestResponse, EngineError> {
+        let request = local_request.read().clone();
+
+        let send_context = SendContext {
+            config: &self.config,
+            cookie_store: &self.cookie_store,
+            variables: VariableResolver {
+                variables: &self.variables,
+                data_row: None,
+                environment: self.environment.clone(),
+            },
+        };
+
+        let (prepared_request, _) = send_context.prepare_request(&request).await?;
+
+        let offline = self.offline || self.config.is_offline_mode_enabled();
+
+        let (response, _, _) = send_request(prepared_request, local_request.clone(), &self.environment, offline).await?;
+
+        local_request.write().response = response.clone();
+
+        return Ok(response);
+    }
+
+    /// Every request that is not skipped, each one after the requests it depends on. The failed requests do not stop the others
+    pub async fn send_collection(&self, collection: &Collection) -> Result<Vec<(String, Result<RequestResponse, EngineError>)>, EngineError> {
+        let execution_plan = ExecutionPlan::new(&collection.requests)?;
+
+        let mut responses = vec![];
+
+        for request_index in execution_plan.order {
+            let local_request = &collection.requests[request_index];
+
+            let (request_name, skipped) = {
+                let request = local_request.read();
+                (request.name.clone(), request.skipped)
+            };
+
+            if skipped {
+                continue;
+            }
+
+            responses.push((request_name, self.send(local_request).await));
+        }
+
+        return Ok(responses);
+    }
+}
@@ -0,0 +1,66 @@
+extern crate core;
+
+use std::fmt::Display;
+use std::io::{Result, stdout};
+use std::process::exit;
+
+use ratatui::crossterm::ExecutableCommand;
+use ratatui::crossterm::style::Stylize;
+use ratatui::crossterm::terminal::{disable_raw_mode, LeaveAlternateScreen};
+use ratatui::backend::CrosstermBackend;
+use ratatui::Terminal;
+pub use ratatui::backend::Backend;
+
+use crate::app::app::App;
+use crate::app::startup::startup::AppMode;
+
+mod app;
+#[cfg(not(feature = "library"))]
+mod models;
+#[cfg(feature = "library")]
+pub mod models;
+mod cli;
+mod tui;
+
+/// Request engine, to send the requests of the collections from other Rust tools
+#[cfg(feature = "library")]
+pub mod engine;
+
+/// Entry point of the atac binary, running the CLI command or the TUI
+#[doc(hidden)]
+pub async fn run() -> Result<()> {
+    let terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
+    
+    let mut app = App::new();
+    let app_mode = app.startup();
+
+    match app_mode {
+        AppMode::CLI(app, command) => {
+            app
+                .handle_command(command)
+                .await;
+        },
+        AppMode::TUI(app) => {
+            app
+                .prepare_terminal()
+                .chain_hook()
+                .run(terminal).await?;
+
+            stdout().execute(LeaveAlternateScreen)?;
+            disable_raw_mode()?;
+        }
+    }
+
+    Ok(())
+}
+
+pub fn panic_error<T>(message: T) -> ! where T: Display {
+    println!("{error}:\n\t{message}", error = "Error".red().bold());
+    exit(1);
+}
+
+/// Error of a CLI command, printed apart from the command output so that it stays parseable
+pub fn exit_with_error<T>(message: T, exit_code: i32) -> ! where T: Display {
+    eprintln!("{error}:\n\t{message}", error = "Error".red().bold());
+    exit(exit_code);
+}
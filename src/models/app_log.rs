@@ -0,0 +1,41 @@
+use std::collections::VecDeque;
+
+use tracing::Level;
+
+/// Records kept for the log panel, the oldest are dropped
+const MAX_LOG_RECORDS: usize = 1000;
+
+/// Levels the log panel can be filtered with, from the least verbose
+pub const LOG_PANEL_LEVELS: [Level; 4] = [Level::ERROR, Level::WARN, Level::INFO, Level::DEBUG];
+
+/// Application log displayed in the TUI log panel
+#[derive(Default)]
+pub struct AppLog {
+    /// Newest first
+    pub records: VecDeque<LogRecord>,
+    /// Errors logged since the log panel was last displayed
+    pub unseen_errors: usize,
+}
+
+pub struct LogRecord {
+    /// Local time, e.g. "14:02:51"
+    pub time: String,
+    pub level: Level,
+    pub message: String,
+}
+
+impl AppLog {
+    pub fn push(&mut self, record: LogRecord) {
+        if record.level == Level::ERROR {
+            self.unseen_errors += 1;
+        }
+
+        self.records.push_front(record);
+        self.records.truncate(MAX_LOG_RECORDS);
+    }
+
+    pub fn clear(&mut self) {
+        self.records.clear();
+        self.unseen_errors = 0;
+    }
+}
@@ -0,0 +1,106 @@
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::response::{RequestResponse, TestResult};
+
+/// Check of the response headers or duration, added to the tests of each response
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseAssertion {
+    /// The header is received, whatever its value
+    HeaderPresent(String),
+
+    /// The header is not received, e.g. x-debug
+    HeaderAbsent(String),
+
+    /// One of the received values of the header is the expected one
+    HeaderEquals {
+        name: String,
+        value: String
+    },
+
+    /// One of the received values of the header contains the expected one
+    HeaderContains {
+        name: String,
+        value: String
+    },
+
+    /// The response is received within this many milliseconds, redirects included
+    MaxDuration(u64)
+}
+
+impl ResponseAssertion {
+    /// Also the name of its test, under which the runs count its failures
+    pub fn to_test_name(&self) -> String {
+        match self {
+            ResponseAssertion::HeaderPresent(name) => format!("Header {} present", name.to_lowercase()),
+            ResponseAssertion::HeaderAbsent(name) => format!("Header {} absent", name.to_lowercase()),
+            ResponseAssertion::HeaderEquals { name, value } => format!("Header {} equals \"{value}\"", name.to_lowercase()),
+            ResponseAssertion::HeaderContains { name, value } => format!("Header {} contains \"{value}\"", name.to_lowercase()),
+            ResponseAssertion::MaxDuration(max_duration) => format!("Duration under {max_duration} ms")
+        }
+    }
+
+    /// None when the assertion cannot be checked, e.g. the duration of an offline response
+    pub fn check(&self, response: &RequestResponse, elapsed_time: Option<Duration>) -> Option<TestResult> {
+        let test_name = self.to_test_name();
+
+        if response.get_status_code().is_none() {
+            return Some(TestResult::failed(test_name, vec![String::from("No response received")]));
+        }
+
+        let messages = match self {
+            ResponseAssertion::MaxDuration(max_duration) => {
+                let elapsed_time = elapsed_time?;
+
+                match elapsed_time > Duration::from_millis(*max_duration) {
+                    true => vec![format!("Received in {} ms", elapsed_time.as_millis())],
+                    false => vec![]
+                }
+            },
+            ResponseAssertion::HeaderPresent(name) => match get_header_values(response, name).is_empty() {
+                true => vec![String::from("Header not received")],
+                false => vec![]
+            },
+            ResponseAssertion::HeaderAbsent(name) => get_header_values(response, name)
+                .iter()
+                .map(|value| format!("Received \"{value}\""))
+                .collect(),
+            ResponseAssertion::HeaderEquals { name, value: expected } => check_header_values(response, name, |value| value == expected),
+            ResponseAssertion::HeaderContains { name, value: expected } => check_header_values(response, name, |value| value.contains(expected.as_str()))
+        };
+
+        return match messages.is_empty() {
+            true => Some(TestResult::passed(test_name)),
+            false => Some(TestResult::failed(test_name, messages))
+        };
+    }
+}
+
+/// The header names are compared case-insensitively
+fn get_header_values<'a>(response: &'a RequestResponse, name: &str) -> Vec<&'a str> {
+    return response.headers
+        .iter()
+        .filter(|(header_name, _)| header_name.eq_ignore_ascii_case(name))
+        .map(|(_, value)| value.as_str())
+        .collect();
+}
+
+/// The failure messages, none when one of the values is accepted
+fn check_header_values(response: &RequestResponse, name: &str, is_accepted: impl Fn(&str) -> bool) -> Vec<String> {
+    let values = get_header_values(response, name);
+
+    if values.is_empty() {
+        return vec![String::from("Header not received")];
+    }
+
+    if values.iter().any(|value| is_accepted(value)) {
+        return vec![];
+    }
+
+    return values
+        .iter()
+        .map(|value| format!("Received \"{value}\""))
+        .collect();
+}
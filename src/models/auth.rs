@@ -1,7 +1,7 @@
-use clap::Subcommand;
+use clap::{Subcommand, ValueEnum};
 use serde::{Deserialize, Serialize};
 use strum::Display;
-use crate::models::auth::Auth::{BasicAuth, BearerToken, NoAuth};
+use crate::models::auth::Auth::{BasicAuth, BearerToken, HmacSignature, NoAuth, Ntlm, OAuth1, Plugin};
 
 #[derive(Subcommand, Clone, Default, Debug, Display, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
@@ -24,9 +24,109 @@ pub enum Auth {
     /// Bearer token auth method
     BearerToken {
         token: String
+    },
+    
+    #[strum(to_string = "HMAC")]
+    #[clap(visible_alias = "hmac")]
+    /// HMAC signature of a string-to-sign template, set in a header when sending the request
+    HmacSignature {
+        secret: String,
+        /// Placeholders: {method}, {url}, {path}, {query}, {host}, {timestamp}, {body}, {body_sha256}, "\n" for a new line
+        string_to_sign: String,
+        /// Header receiving the signature
+        header: String,
+        #[arg(long, value_enum, default_value_t)]
+        #[serde(default)]
+        algorithm: HmacAlgorithm,
+        #[arg(long, value_enum, default_value_t)]
+        #[serde(default)]
+        encoding: SignatureEncoding
+    },
+    
+    #[strum(to_string = "NTLM")]
+    /// NTLMv2 challenge-response handshake, neither SPNEGO nor Kerberos being supported
+    // The former Negotiate auth method was an NTLM handshake, its requests are read as NTLM ones
+    #[serde(alias = "negotiate")]
+    Ntlm {
+        username: String,
+        password: String,
+        #[arg(default_value = "")]
+        domain: String
+    },
+    
+    #[strum(to_string = "OAuth 1.0a")]
+    #[clap(visible_alias = "oauth1")]
+    /// OAuth 1.0a signature of the method, the URL, the query params and the form body
+    OAuth1 {
+        consumer_key: String,
+        /// With RSA-SHA1, the PEM private key or "!!" followed by its file path
+        consumer_secret: String,
+        #[arg(default_value = "")]
+        token: String,
+        #[arg(default_value = "")]
+        token_secret: String,
+        #[arg(long, value_enum, default_value_t)]
+        #[serde(default)]
+        signature_method: OAuth1SignatureMethod,
+        #[arg(long, value_enum, default_value_t)]
+        #[serde(default)]
+        placement: OAuth1Placement
+    },
+    
+    #[strum(to_string = "Plugin")]
+    /// Headers and query params computed by a plugin providing the auth hook
+    Plugin {
+        /// Name of the plugin
+        plugin: String,
+        /// Free configuration given to the plugin, e.g. a key or a profile name
+        #[arg(default_value = "")]
+        config: String
     }
 }
 
+#[derive(Debug, Default, Copy, Clone, PartialEq, Display, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum HmacAlgorithm {
+    #[strum(to_string = "HMAC-SHA1")]
+    HmacSha1,
+    #[default]
+    #[strum(to_string = "HMAC-SHA256")]
+    HmacSha256,
+    #[strum(to_string = "HMAC-SHA512")]
+    HmacSha512
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Display, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureEncoding {
+    #[default]
+    #[strum(to_string = "Hex")]
+    Hex,
+    #[strum(to_string = "Base64")]
+    Base64
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Display, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth1SignatureMethod {
+    #[default]
+    #[strum(to_string = "HMAC-SHA1")]
+    HmacSha1,
+    #[strum(to_string = "RSA-SHA1")]
+    RsaSha1
+}
+
+/// Where the OAuth params and the signature are sent
+#[derive(Debug, Default, Copy, Clone, PartialEq, Display, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum OAuth1Placement {
+    #[default]
+    #[strum(to_string = "Header")]
+    Header,
+    #[strum(to_string = "Query")]
+    Query
+}
+
 pub fn next_auth(auth: &Auth) -> Auth {
     match auth {
         NoAuth => BasicAuth {
@@ -36,6 +136,59 @@ pub fn next_auth(auth: &Auth) -> Auth {
         BasicAuth { .. } => BearerToken {
             token: String::new(),
         },
-        BearerToken { .. } => NoAuth
+        BearerToken { .. } => HmacSignature {
+            secret: String::new(),
+            string_to_sign: String::from(r"{method}\n{path}\n{timestamp}\n{body_sha256}"),
+            header: String::from("X-Signature"),
+            algorithm: HmacAlgorithm::default(),
+            encoding: SignatureEncoding::default(),
+        },
+        HmacSignature { .. } => Ntlm {
+            username: String::new(),
+            password: String::new(),
+            domain: String::new(),
+        },
+        Ntlm { .. } => OAuth1 {
+            consumer_key: String::new(),
+            consumer_secret: String::new(),
+            token: String::new(),
+            token_secret: String::new(),
+            signature_method: OAuth1SignatureMethod::default(),
+            placement: OAuth1Placement::default(),
+        },
+        OAuth1 { .. } => Plugin {
+            plugin: String::new(),
+            config: String::new(),
+        },
+        Plugin { .. } => NoAuth
+    }
+}
+
+pub fn next_hmac_algorithm(algorithm: &HmacAlgorithm) -> HmacAlgorithm {
+    match algorithm {
+        HmacAlgorithm::HmacSha1 => HmacAlgorithm::HmacSha256,
+        HmacAlgorithm::HmacSha256 => HmacAlgorithm::HmacSha512,
+        HmacAlgorithm::HmacSha512 => HmacAlgorithm::HmacSha1
+    }
+}
+
+pub fn next_signature_encoding(encoding: &SignatureEncoding) -> SignatureEncoding {
+    match encoding {
+        SignatureEncoding::Hex => SignatureEncoding::Base64,
+        SignatureEncoding::Base64 => SignatureEncoding::Hex
+    }
+}
+
+pub fn next_oauth1_signature_method(signature_method: &OAuth1SignatureMethod) -> OAuth1SignatureMethod {
+    match signature_method {
+        OAuth1SignatureMethod::HmacSha1 => OAuth1SignatureMethod::RsaSha1,
+        OAuth1SignatureMethod::RsaSha1 => OAuth1SignatureMethod::HmacSha1
+    }
+}
+
+pub fn next_oauth1_placement(placement: &OAuth1Placement) -> OAuth1Placement {
+    match placement {
+        OAuth1Placement::Header => OAuth1Placement::Query,
+        OAuth1Placement::Query => OAuth1Placement::Header
     }
 }
\ No newline at end of file
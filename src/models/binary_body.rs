@@ -0,0 +1,107 @@
+use serde_json::Value;
+use strum::Display;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BinaryBodyError {
+    #[error("Invalid JSON\n\t{0}")]
+    InvalidJson(String),
+
+    #[error("Could not encode the body as {0}\n\t{1}")]
+    CouldNotEncode(BinaryFormat, String),
+
+    #[error("Could not decode the {0} response\n\t{1}")]
+    CouldNotDecode(BinaryFormat, String),
+}
+
+/// Binary serializations of a JSON document
+#[derive(Debug, Clone, Copy, PartialEq, Display)]
+pub enum BinaryFormat {
+    #[strum(to_string = "MessagePack")]
+    MessagePack,
+    #[strum(to_string = "CBOR")]
+    Cbor,
+}
+
+impl BinaryFormat {
+    /// application/msgpack, application/x-msgpack, application/vnd.msgpack, application/cbor...
+    pub fn from_content_type(content_type: &str) -> Option<BinaryFormat> {
+        if content_type.contains("msgpack") {
+            return Some(BinaryFormat::MessagePack);
+        }
+
+        if content_type.contains("cbor") {
+            return Some(BinaryFormat::Cbor);
+        }
+
+        return None;
+    }
+
+    pub fn encode_json(&self, json: &str) -> Result<Vec<u8>, BinaryBodyError> {
+        let value: Value = serde_json::from_str(json).map_err(|error| BinaryBodyError::InvalidJson(error.to_string()))?;
+
+        let bytes = match self {
+            BinaryFormat::MessagePack => rmp_serde::to_vec_named(&value).map_err(|error| error.to_string()),
+            BinaryFormat::Cbor => {
+                let mut bytes = vec![];
+
+                ciborium::into_writer(&value, &mut bytes)
+                    .map(|_| bytes)
+                    .map_err(|error| error.to_string())
+            }
+        };
+
+        return bytes.map_err(|error| BinaryBodyError::CouldNotEncode(*self, error));
+    }
+
+    /// Compact JSON, binary strings and non-string map keys cannot be represented
+    pub fn decode_to_json(&self, bytes: &[u8]) -> Result<String, BinaryBodyError> {
+        let value = match self {
+            BinaryFormat::MessagePack => rmp_serde::from_slice::<Value>(bytes).map_err(|error| error.to_string()),
+            BinaryFormat::Cbor => ciborium::from_reader::<Value, _>(bytes).map_err(|error| error.to_string())
+        };
+
+        return value
+            .map(|value| value.to_string())
+            .map_err(|error| BinaryBodyError::CouldNotDecode(*self, error));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Sorted keys, the decoded maps are not kept in order
+    const JSON: &str = r#"{"active":true,"id":1,"manager":null,"name":"Ada","scores":[1.5,-2],"tags":{"a":"b"}}"#;
+
+    #[test]
+    fn message_pack_round_trip() {
+        let bytes = BinaryFormat::MessagePack.encode_json(JSON).unwrap();
+
+        // Map of 6 entries
+        assert_eq!(bytes[0], 0x86);
+        assert_eq!(BinaryFormat::MessagePack.decode_to_json(&bytes).unwrap(), JSON);
+    }
+
+    #[test]
+    fn cbor_round_trip() {
+        let bytes = BinaryFormat::Cbor.encode_json(JSON).unwrap();
+
+        // Map of 6 entries
+        assert_eq!(bytes[0], 0xa6);
+        assert_eq!(BinaryFormat::Cbor.decode_to_json(&bytes).unwrap(), JSON);
+    }
+
+    #[test]
+    fn invalid_input() {
+        assert!(matches!(BinaryFormat::Cbor.encode_json("{"), Err(BinaryBodyError::InvalidJson(_))));
+        assert!(matches!(BinaryFormat::MessagePack.decode_to_json(&[0xc1]), Err(BinaryBodyError::CouldNotDecode(BinaryFormat::MessagePack, _))));
+    }
+
+    #[test]
+    fn format_from_content_type() {
+        assert_eq!(BinaryFormat::from_content_type("application/vnd.msgpack"), Some(BinaryFormat::MessagePack));
+        assert_eq!(BinaryFormat::from_content_type("application/cbor; charset=binary"), Some(BinaryFormat::Cbor));
+        assert_eq!(BinaryFormat::from_content_type("application/json"), None);
+    }
+}
@@ -1,3 +1,6 @@
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
 use anyhow::anyhow;
 use regex::Regex;
 use serde::{Deserialize, Serialize};
@@ -5,7 +8,7 @@ use strum::Display;
 use rayon::prelude::*;
 
 use crate::app::business_logic::request::body::FormError::NotAForm;
-use crate::models::body::ContentType::{File, Form, Html, Javascript, Json, Multipart, NoBody, Raw, Xml};
+use crate::models::body::ContentType::{Cbor, File, Form, Html, Javascript, Json, MessagePack, Multipart, NoBody, Protobuf, Raw, Xml};
 use crate::models::request::KeyValue;
 
 #[derive(Default, Debug, Clone, Display, Serialize, Deserialize)]
@@ -16,10 +19,10 @@ pub enum ContentType {
     NoBody,
 
     #[strum(to_string = "File")]
-    File(String),
+    File(BodyFile),
 
     #[strum(to_string = "Multipart")]
-    Multipart(Vec<KeyValue>),
+    Multipart(Vec<MultipartPart>),
 
     #[strum(to_string = "Form")]
     Form(Vec<KeyValue>),
@@ -37,7 +40,19 @@ pub enum ContentType {
     Html(String),
 
     #[strum(to_string = "Javascript")]
-    Javascript(String)
+    Javascript(String),
+
+    /// JSON message encoded with the protobuf schema of the request
+    #[strum(to_string = "Protobuf")]
+    Protobuf(String),
+
+    /// JSON document encoded as MessagePack
+    #[strum(to_string = "MessagePack")]
+    MessagePack(String),
+
+    /// JSON document encoded as CBOR
+    #[strum(to_string = "CBOR")]
+    Cbor(String)
 }
 
 impl ContentType {
@@ -47,7 +62,10 @@ impl ContentType {
             Multipart(_) => String::from("multipart/form-data"),
             Form(_) => String::from("application/x-www-form-urlencoded"),
             Raw(_) => String::from("text/plain"),
-            File(_) => String::from("application/octet-stream"),
+            Protobuf(_) => String::from("application/x-protobuf"),
+            MessagePack(_) => String::from("application/msgpack"),
+            Cbor(_) => String::from("application/cbor"),
+            File(body_file) => body_file.content_type.clone().unwrap_or(String::from("application/octet-stream")),
             Json(_) | Xml(_) | Html(_) | Javascript(_) => format!("application/{}", self.to_string().to_lowercase())
         }
     }
@@ -56,50 +74,238 @@ impl ContentType {
         match content_type {
             //"multipart/form-data" => Multipart(body),
             //"application/x-www-form-urlencoded" => Form(body),
-            "application/octet-stream" => File(body),
+            "application/octet-stream" => File(BodyFile { path: body, content_type: None }),
             "text/plain" => Raw(body),
             "application/json" => Json(body),
             "application/xml" => Json(body),
             "application/html" => Json(body),
             "application/javascript" => Json(body),
+            "application/x-protobuf" => Protobuf(body),
+            "application/msgpack" | "application/x-msgpack" => MessagePack(body),
+            "application/cbor" => Cbor(body),
             _ => NoBody
         }
     }
 
-    pub fn get_form(&self) -> anyhow::Result<&Vec<KeyValue>> {
+    /// Key value pairs of a form, or names and values of the multipart parts
+    pub fn get_form(&self) -> anyhow::Result<Vec<KeyValue>> {
         match self {
-            Multipart(form) | Form(form) => Ok(form),
+            Multipart(parts) => Ok(parts.iter().map(MultipartPart::to_key_value).collect()),
+            Form(form) => Ok(form.clone()),
             _ => Err(anyhow!(NotAForm))
         }
     }
 
-    pub fn get_form_mut(&mut self) -> anyhow::Result<&mut Vec<KeyValue>> {
+    /// Same kind of body holding the text instead, a JSON or a text body when this one holds no text
+    pub fn with_text(&self, text: String) -> ContentType {
         match self {
-            Multipart(form) | Form(form) => Ok(form),
-            _ => Err(anyhow!(NotAForm))
+            Raw(_) => Raw(text),
+            Json(_) => Json(text),
+            Xml(_) => Xml(text),
+            Html(_) => Html(text),
+            Javascript(_) => Javascript(text),
+            Protobuf(_) => Protobuf(text),
+            MessagePack(_) => MessagePack(text),
+            Cbor(_) => Cbor(text),
+            NoBody | File(_) | Multipart(_) | Form(_) => match serde_json::from_str::<serde_json::Value>(&text).is_ok() {
+                true => Json(text),
+                false => Raw(text)
+            }
+        }
+    }
+}
+
+/// Part of a multipart body, its value being either some text or the path of the file to send
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "MultipartPartFormat")]
+pub struct MultipartPart {
+    pub enabled: bool,
+    pub name: String,
+    pub source: PartSource,
+    pub value: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// File parts default to the name of their file
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub file_name: Option<String>,
+}
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PartSource {
+    #[default]
+    #[strum(to_string = "text")]
+    Text,
+    #[strum(to_string = "file")]
+    File,
+}
+
+impl PartSource {
+    pub fn next(&self) -> PartSource {
+        match self {
+            PartSource::Text => PartSource::File,
+            PartSource::File => PartSource::Text
+        }
+    }
+}
+
+impl MultipartPart {
+    pub fn text(name: String, value: String) -> MultipartPart {
+        MultipartPart {
+            enabled: true,
+            name,
+            source: PartSource::Text,
+            value,
+            content_type: None,
+            file_name: None,
+        }
+    }
+
+    pub fn file(name: String, path: String) -> MultipartPart {
+        MultipartPart {
+            enabled: true,
+            name,
+            source: PartSource::File,
+            value: path,
+            content_type: None,
+            file_name: None,
+        }
+    }
+
+    /// A value starting with "!!" is a file path
+    pub fn from_key_value(key_value: KeyValue) -> MultipartPart {
+        let (name, value) = key_value.data;
+
+        let part = match value.strip_prefix("!!") {
+            Some(path) => MultipartPart::file(name, path.to_string()),
+            None => MultipartPart::text(name, value)
+        };
+
+        MultipartPart {
+            enabled: key_value.enabled,
+            ..part
+        }
+    }
+
+    pub fn to_key_value(&self) -> KeyValue {
+        KeyValue {
+            enabled: self.enabled,
+            data: (self.name.clone(), self.value.clone()),
+        }
+    }
+}
+
+/// Parts used to be stored as key value pairs
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum MultipartPartFormat {
+    Part {
+        enabled: bool,
+        name: String,
+        #[serde(default)]
+        source: PartSource,
+        value: String,
+        #[serde(default)]
+        content_type: Option<String>,
+        #[serde(default)]
+        file_name: Option<String>,
+    },
+    KeyValue(KeyValue),
+}
+
+impl From<MultipartPartFormat> for MultipartPart {
+    fn from(multipart_part_format: MultipartPartFormat) -> Self {
+        match multipart_part_format {
+            MultipartPartFormat::Part { enabled, name, source, value, content_type, file_name } => MultipartPart { enabled, name, source, value, content_type, file_name },
+            MultipartPartFormat::KeyValue(key_value) => MultipartPart::from_key_value(key_value)
+        }
+    }
+}
+
+/// File sent as the body, stored as a plain path unless its content type is overridden
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "BodyFileFormat", into = "BodyFileFormat")]
+pub struct BodyFile {
+    pub path: String,
+    /// Sent instead of application/octet-stream
+    pub content_type: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum BodyFileFormat {
+    Path(String),
+    WithContentType {
+        path: String,
+        content_type: String,
+    }
+}
+
+impl From<BodyFileFormat> for BodyFile {
+    fn from(body_file_format: BodyFileFormat) -> Self {
+        match body_file_format {
+            BodyFileFormat::Path(path) => BodyFile { path, content_type: None },
+            BodyFileFormat::WithContentType { path, content_type } => BodyFile { path, content_type: Some(content_type) }
+        }
+    }
+}
+
+impl From<BodyFile> for BodyFileFormat {
+    fn from(body_file: BodyFile) -> Self {
+        match body_file.content_type {
+            None => BodyFileFormat::Path(body_file.path),
+            Some(content_type) => BodyFileFormat::WithContentType { path: body_file.path, content_type }
         }
     }
 }
 
+/// Bytes of the body file read by the client while it is being sent, displayed while the request is pending
+#[derive(Default, Debug, Clone)]
+pub struct UploadProgress {
+    pub sent: Arc<AtomicU64>,
+    pub total: Arc<AtomicU64>,
+}
+
+impl UploadProgress {
+    /// Sent and total bytes, while the body file is being uploaded
+    pub fn get(&self) -> Option<(u64, u64)> {
+        let sent = self.sent.load(Ordering::Relaxed);
+        let total = self.total.load(Ordering::Relaxed);
+
+        return match total > 0 && sent < total {
+            true => Some((sent, total)),
+            false => None
+        };
+    }
+
+    pub fn reset(&self, total: u64) {
+        self.sent.store(0, Ordering::Relaxed);
+        self.total.store(total, Ordering::Relaxed);
+    }
+}
+
 pub fn next_content_type(content_type: &ContentType) -> ContentType {
     match content_type {
         NoBody => Multipart(Vec::new()),
         Multipart(_) => Form(Vec::new()),
-        Form(_) => File(String::new()),
+        Form(_) => File(BodyFile::default()),
         File(_) => Raw(String::new()),
         Raw(body) => Json(body.to_string()),
         Json(body) => Xml(body.to_string()),
         Xml(body) => Html(body.to_string()),
         Html(body) => Javascript(body.to_string()),
-        Javascript(_) => NoBody
+        Javascript(body) => Protobuf(body.to_string()),
+        Protobuf(body) => MessagePack(body.to_string()),
+        MessagePack(body) => Cbor(body.to_string()),
+        Cbor(_) => NoBody
     }
 }
 
 /// Iter through the headers and tries to catch a file format like `application/<file_format>`
 pub fn find_file_format_in_content_type(headers: &Vec<(String, String)>) -> Option<String> {
     if let Some((_, content_type)) = headers.par_iter().find_any(|(header, _)| *header == "content-type") {
-        // Regex that likely catches the file format
-        let regex = Regex::new(r"\w+/(?<file_format>\w+)").unwrap();
+        // Regex that likely catches the file format, the suffix of a structured syntax is kept (e.g. "xml" for application/soap+xml)
+        let regex = Regex::new(r"\w+/(?:[\w.-]+\+)?(?<file_format>\w+)").unwrap();
 
         return match regex.captures(content_type) {
             // No file format found
@@ -111,4 +317,110 @@ pub fn find_file_format_in_content_type(headers: &Vec<(String, String)>) -> Opti
     else {
         return None;
     }
-}
\ No newline at end of file
+}
+
+/// Content types that do not tell anything about the content (`text/plain`, `application/octet-stream`)
+const GENERIC_FILE_FORMATS: [&str; 2] = ["plain", "octet"];
+
+/// Tries to find the file format of a response, first from its content-type header, then from its content if the header is missing or generic
+pub fn find_response_file_format(headers: &Vec<(String, String)>, content: &str) -> Option<String> {
+    if headers.iter().any(|(header, value)| header == "content-type" && is_ndjson_content_type(value)) {
+        return Some(String::from("ndjson"));
+    }
+
+    return match find_file_format_in_content_type(headers) {
+        Some(file_format) if !GENERIC_FILE_FORMATS.contains(&file_format.as_str()) => Some(file_format),
+        _ => detect_file_format_from_content(content)
+    };
+}
+
+/// Looks at the content to guess if it is JSON, XML or HTML
+pub fn detect_file_format_from_content(content: &str) -> Option<String> {
+    let trimmed_content = content.trim_start();
+
+    if trimmed_content.starts_with('{') || trimmed_content.starts_with('[') {
+        return match serde_json::from_str::<serde_json::Value>(content) {
+            Ok(_) => Some(String::from("json")),
+            Err(_) if is_ndjson_content(content) => Some(String::from("ndjson")),
+            Err(_) => None
+        };
+    }
+
+    if !trimmed_content.starts_with('<') {
+        return None;
+    }
+
+    let lowercase_start = trimmed_content.chars().take(100).collect::<String>().to_lowercase();
+
+    if lowercase_start.starts_with("<!doctype html") || lowercase_start.starts_with("<html") {
+        return Some(String::from("html"));
+    }
+
+    if trimmed_content.trim_end().ends_with('>') {
+        return Some(String::from("xml"));
+    }
+
+    return None;
+}
+
+/// Newline-delimited JSON, e.g. application/x-ndjson or application/jsonl
+pub fn is_ndjson_content_type(content_type: &str) -> bool {
+    return ["ndjson", "jsonl", "jsonlines"].iter().any(|format| content_type.contains(format));
+}
+
+/// Delimiter of a CSV or TSV response, e.g. text/csv or text/tab-separated-values
+pub fn find_response_delimiter(headers: &[(String, String)]) -> Option<u8> {
+    let (_, content_type) = headers.iter().find(|(header, _)| header == "content-type")?;
+
+    if content_type.contains("csv") {
+        return Some(b',');
+    }
+
+    if content_type.contains("tab-separated-values") || content_type.contains("tsv") {
+        return Some(b'\t');
+    }
+
+    return None;
+}
+
+/// At least two lines, each one being a JSON document
+fn is_ndjson_content(content: &str) -> bool {
+    let lines: Vec<&str> = content
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .collect();
+
+    return lines.len() >= 2 && lines.iter().all(|line| serde_json::from_str::<serde_json::Value>(line).is_ok());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_headers(content_type: &str) -> Vec<(String, String)> {
+        return vec![(String::from("content-type"), content_type.to_string())];
+    }
+
+    #[test]
+    fn detect_from_content() {
+        assert_eq!(detect_file_format_from_content("  {\"id\": 1}"), Some(String::from("json")));
+        assert_eq!(detect_file_format_from_content("{\"id\": 1}\n{\"id\": 2}\n"), Some(String::from("ndjson")));
+        assert_eq!(detect_file_format_from_content("{not json"), None);
+        assert_eq!(detect_file_format_from_content("<!DOCTYPE html><html></html>"), Some(String::from("html")));
+        assert_eq!(detect_file_format_from_content("<?xml version=\"1.0\"?><users/>"), Some(String::from("xml")));
+        assert_eq!(detect_file_format_from_content("plain text"), None);
+    }
+
+    #[test]
+    fn generic_content_type_falls_back_to_the_content() {
+        assert_eq!(find_response_file_format(&get_headers("text/plain"), "[1, 2]"), Some(String::from("json")));
+        assert_eq!(find_response_file_format(&get_headers("application/octet-stream"), "<users/>"), Some(String::from("xml")));
+        assert_eq!(find_response_file_format(&vec![], "<users/>"), Some(String::from("xml")));
+    }
+
+    #[test]
+    fn specific_content_type_prevails() {
+        assert_eq!(find_response_file_format(&get_headers("text/html; charset=utf-8"), "{}"), Some(String::from("html")));
+        assert_eq!(find_response_file_format(&get_headers("application/x-ndjson"), "{}"), Some(String::from("ndjson")));
+    }
+}
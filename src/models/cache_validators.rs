@@ -0,0 +1,85 @@
+use reqwest::header::{IF_MODIFIED_SINCE, IF_NONE_MATCH};
+use reqwest_middleware::RequestBuilder;
+
+use crate::models::response::RequestResponse;
+
+/// ETag and Last-Modified of the last response, sent back to ask the server whether the resource changed
+#[derive(Debug, Clone, Default)]
+pub struct CacheValidators {
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+impl CacheValidators {
+    pub fn is_empty(&self) -> bool {
+        return self.etag.is_none() && self.last_modified.is_none();
+    }
+
+    /// Store the validators of the response, a 304 keeps the previous ones it did not replace
+    pub fn update(&mut self, response: &RequestResponse) {
+        let etag = find_header(response, "etag");
+        let last_modified = find_header(response, "last-modified");
+
+        match response.is_not_modified() {
+            true => {
+                if etag.is_some() {
+                    self.etag = etag;
+                }
+
+                if last_modified.is_some() {
+                    self.last_modified = last_modified;
+                }
+            },
+            false => {
+                self.etag = etag;
+                self.last_modified = last_modified;
+            }
+        }
+    }
+
+    /// Adds If-None-Match and If-Modified-Since, a server supporting them answers 304 Not Modified when nothing changed
+    pub fn add_conditional_headers(&self, mut prepared_request: RequestBuilder) -> RequestBuilder {
+        if let Some(etag) = &self.etag {
+            prepared_request = prepared_request.header(IF_NONE_MATCH, etag);
+        }
+
+        if let Some(last_modified) = &self.last_modified {
+            prepared_request = prepared_request.header(IF_MODIFIED_SINCE, last_modified);
+        }
+
+        return prepared_request;
+    }
+
+    /// Explanation displayed instead of the empty body of a 304 response
+    pub fn explain_not_modified(&self) -> Vec<String> {
+        let mut lines = vec![
+            String::from("304 Not Modified"),
+            String::new(),
+            String::from("The resource did not change since the previous response, the server sent no body."),
+            String::from("The previously received body is still up to date."),
+        ];
+
+        if !self.is_empty() {
+            lines.push(String::new());
+            lines.push(String::from("Validators sent:"));
+        }
+
+        if let Some(etag) = &self.etag {
+            lines.push(format!("    If-None-Match: {etag}"));
+        }
+
+        if let Some(last_modified) = &self.last_modified {
+            lines.push(format!("    If-Modified-Since: {last_modified}"));
+        }
+
+        return lines;
+    }
+}
+
+fn find_header(response: &RequestResponse, header_name: &str) -> Option<String> {
+    return response.headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(header_name))
+        .map(|(_, value)| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+}
@@ -0,0 +1,58 @@
+use encoding_rs::{Encoding, UTF_8};
+
+/// Charsets offered as a manual override, "Auto" reads the BOM and the content-type header
+pub const RESPONSE_CHARSETS: [&str; 14] = [
+    "Auto",
+    "UTF-8",
+    "UTF-16LE",
+    "UTF-16BE",
+    "windows-1252",
+    "ISO-8859-15",
+    "windows-1251",
+    "KOI8-R",
+    "Shift_JIS",
+    "EUC-JP",
+    "GBK",
+    "gb18030",
+    "Big5",
+    "EUC-KR",
+];
+
+/// Canonical name of a charset label, e.g. "latin1" gives "windows-1252"
+pub fn find_charset(label: &str) -> Option<&'static str> {
+    return Encoding::for_label(label.trim().as_bytes()).map(|encoding| encoding.name());
+}
+
+/// Charset a text body is decoded with, and the length of the BOM to skip
+///
+/// Without override, the charset is read from the BOM, then from the content-type header, UTF-8 being the default
+pub fn find_response_encoding(bytes: &[u8], headers: &[(String, String)], charset_override: Option<&str>) -> (&'static Encoding, usize) {
+    let bom = Encoding::for_bom(bytes);
+
+    if let Some(encoding) = charset_override.and_then(|label| Encoding::for_label(label.as_bytes())) {
+        // Only the BOM of the forced charset is removed
+        return match bom {
+            Some((bom_encoding, bom_length)) if bom_encoding == encoding => (encoding, bom_length),
+            _ => (encoding, 0)
+        };
+    }
+
+    // A BOM takes precedence over the content-type header, it is then removed
+    if let Some(bom) = bom {
+        return bom;
+    }
+
+    return (find_content_type_charset(headers).unwrap_or(UTF_8), 0);
+}
+
+/// e.g. "text/html; charset=Shift_JIS"
+fn find_content_type_charset(headers: &[(String, String)]) -> Option<&'static Encoding> {
+    let (_, content_type) = headers.iter().find(|(header, _)| header == "content-type")?;
+
+    return content_type
+        .split(';')
+        .skip(1)
+        .filter_map(|parameter| parameter.split_once('='))
+        .find(|(name, _)| name.trim().eq_ignore_ascii_case("charset"))
+        .and_then(|(_, value)| Encoding::for_label(value.trim().trim_matches('"').as_bytes()));
+}
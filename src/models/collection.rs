@@ -1,5 +1,6 @@
 use std::path::PathBuf;
 use std::sync::Arc;
+use clap::ValueEnum;
 use parking_lot::RwLock;
 use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
@@ -8,21 +9,52 @@ use strum::Display;
 use tui_tree_widget::TreeItem;
 use rayon::prelude::*;
 use crate::app::files::theme::THEME;
+use crate::models::collection_hooks::{CollectionHooks, HookKind};
 use crate::models::request::Request;
+use crate::models::tag::has_any_tag;
+use crate::models::think_time::ThinkTime;
 
 #[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct Collection {
     pub name: String,
+
+    /// Markdown documentation, rendered in the homepage when the collection is highlighted
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// Shared collection whose requests can be sent but not modified from the TUI
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub read_only: bool,
+
+    /// Name of the request re-sent to log in again when a request of the collection gets a 401
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login_request: Option<String>,
+
+    /// Requests sent around the other ones by the runs, e.g. to create and delete test fixtures
+    #[serde(default, skip_serializing_if = "CollectionHooks::is_empty")]
+    pub hooks: CollectionHooks,
+
+    /// Pause of the runs after each request, unless the request has its own
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub think_time: Option<ThinkTime>,
+
+    /// Shared by all the requests of the collection, e.g. to run its requests with "collection send --tag"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
     pub requests: Vec<Arc<RwLock<Request>>>,
 
     #[serde(skip)]
     pub path: PathBuf,
 
     #[serde(skip)]
-    pub file_format: CollectionFileFormat
+    pub file_format: CollectionFileFormat,
+
+    #[serde(skip)]
+    pub layout: CollectionLayout
 }
 
-#[derive(Debug, Default, Copy, Clone, Display, Serialize, Deserialize)]
+#[derive(Debug, Default, Copy, Clone, PartialEq, Display, Serialize, Deserialize, ValueEnum)]
 pub enum CollectionFileFormat {
     #[default]
     #[serde(alias="json", alias="JSON")]
@@ -30,26 +62,127 @@ pub enum CollectionFileFormat {
     Json,
     #[serde(alias="yaml", alias="YAML")]
     #[strum(to_string = "yaml")]
-    Yaml
+    Yaml,
+    #[serde(alias="toml", alias="TOML")]
+    #[strum(to_string = "toml")]
+    Toml
+}
+
+impl CollectionFileFormat {
+    /// File format matching a file extension, e.g. "yaml"
+    pub fn from_extension(extension: &str) -> Option<CollectionFileFormat> {
+        CollectionFileFormat::value_variants()
+            .iter()
+            .find(|file_format| file_format.to_string() == extension)
+            .copied()
+    }
+}
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Display, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "snake_case")]
+pub enum CollectionLayout {
+    /// The whole collection in a single file
+    #[default]
+    #[strum(to_string = "single file")]
+    SingleFile,
+    /// A directory holding the collection file and one file per request, easier to diff and merge
+    #[strum(to_string = "file per request")]
+    FilePerRequest
+}
+
+/// Collection file of the file per request layout, the requests are replaced by their file names
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CollectionIndexFile {
+    pub name: String,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub read_only: bool,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub login_request: Option<String>,
+
+    #[serde(default, skip_serializing_if = "CollectionHooks::is_empty")]
+    pub hooks: CollectionHooks,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub think_time: Option<ThinkTime>,
+
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Request file names, in the collection order
+    pub requests: Vec<String>,
 }
 
 impl Collection {
-    pub fn to_tree_item<'a>(&self, identifier: usize) -> TreeItem<'a, usize> {
+    /// Only the requests having one of the filter tags are listed, none when the filter is empty.
+    /// None when no request of the collection matches the filter
+    pub fn to_tree_item<'a>(&self, identifier: usize, tag_filter: &[String]) -> Option<TreeItem<'a, usize>> {
         let name = self.name.clone();
 
-        let line = Line::from(vec![
-            Span::raw(name).fg(THEME.read().ui.font_color),
-            Span::from(format!(" ({})", self.requests.len()))
-        ]);
-
         let items: Vec<TreeItem<usize>> = self.requests
             .par_iter()
             .enumerate()
-            .map(|(request_index, request)| {
-                request.read().to_tree_item(request_index)
+            .filter_map(|(request_index, request)| {
+                let request = request.read();
+
+                if !tag_filter.is_empty() && !self.is_request_tagged(&request, tag_filter) {
+                    return None;
+                }
+
+                Some(request.to_tree_item(request_index, &self.get_request_roles(&request.name)))
             })
             .collect();
 
-        TreeItem::new(identifier, line, items).unwrap()
+        if items.is_empty() && !tag_filter.is_empty() {
+            return None;
+        }
+
+        let requests_count = match tag_filter.is_empty() {
+            true => self.requests.len().to_string(),
+            false => format!("{}/{}", items.len(), self.requests.len())
+        };
+
+        let mut line = Line::from(vec![
+            Span::raw(name).fg(THEME.read().ui.font_color),
+            Span::from(format!(" ({requests_count})"))
+        ]);
+
+        if self.read_only {
+            line.push_span(Span::raw(" read-only").fg(THEME.read().ui.secondary_foreground_color));
+        }
+
+        for tag in &self.tags {
+            line.push_span(Span::raw(format!(" #{tag}")).fg(THEME.read().ui.secondary_foreground_color));
+        }
+
+        Some(TreeItem::new(identifier, line, items).unwrap())
+    }
+
+    /// The collection tags apply to all of its requests
+    pub fn is_request_tagged(&self, request: &Request, tags: &[String]) -> bool {
+        return has_any_tag(self.tags.iter().chain(&request.tags), tags);
+    }
+
+    pub fn has_tagged_requests(&self, tags: &[String]) -> bool {
+        return self.requests
+            .iter()
+            .any(|request| self.is_request_tagged(&request.read(), tags));
+    }
+
+    /// What the collection uses the request as, e.g. "login" or "before all"
+    pub fn get_request_roles(&self, request_name: &str) -> Vec<String> {
+        let mut roles: Vec<String> = vec![];
+
+        if self.login_request.as_deref() == Some(request_name) {
+            roles.push(String::from("login"));
+        }
+
+        roles.extend(self.hooks.get_hook_kinds(request_name).iter().map(HookKind::to_string));
+
+        return roles;
     }
 }
\ No newline at end of file
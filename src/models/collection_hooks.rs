@@ -0,0 +1,90 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Requests of the collection the runs send around the other ones, e.g. to create and delete test fixtures.
+/// They are not sent as part of the run themselves
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CollectionHooks {
+    /// Sent once before the first request of the run, which is not sent when it does not succeed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before_all: Option<String>,
+
+    /// Sent once after the last request of the run, even when some failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_all: Option<String>,
+
+    /// Sent before each request of the run, which is not sent when it does not succeed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub before_each: Option<String>,
+
+    /// Sent after each request of the run that was sent
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub after_each: Option<String>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Display, ValueEnum)]
+pub enum HookKind {
+    #[strum(to_string = "before all")]
+    BeforeAll,
+    #[strum(to_string = "after all")]
+    AfterAll,
+    #[strum(to_string = "before each")]
+    BeforeEach,
+    #[strum(to_string = "after each")]
+    AfterEach,
+}
+
+impl CollectionHooks {
+    pub fn is_empty(&self) -> bool {
+        return HookKind::value_variants().iter().all(|hook_kind| self.get(*hook_kind).is_none());
+    }
+
+    /// Name of the hook request
+    pub fn get(&self, hook_kind: HookKind) -> Option<&String> {
+        return self.get_hook(hook_kind).as_ref();
+    }
+
+    pub fn set(&mut self, hook_kind: HookKind, request_name: Option<String>) {
+        *self.get_hook_mut(hook_kind) = request_name;
+    }
+
+    /// The hooks the request is used as
+    pub fn get_hook_kinds(&self, request_name: &str) -> Vec<HookKind> {
+        return HookKind::value_variants()
+            .iter()
+            .copied()
+            .filter(|hook_kind| self.get(*hook_kind).is_some_and(|hook| hook == request_name))
+            .collect();
+    }
+
+    pub fn rename_request(&mut self, request_name: &str, new_request_name: &str) {
+        for hook_kind in self.get_hook_kinds(request_name) {
+            self.set(hook_kind, Some(new_request_name.to_string()));
+        }
+    }
+
+    pub fn remove_request(&mut self, request_name: &str) {
+        for hook_kind in self.get_hook_kinds(request_name) {
+            self.set(hook_kind, None);
+        }
+    }
+
+    fn get_hook(&self, hook_kind: HookKind) -> &Option<String> {
+        match hook_kind {
+            HookKind::BeforeAll => &self.before_all,
+            HookKind::AfterAll => &self.after_all,
+            HookKind::BeforeEach => &self.before_each,
+            HookKind::AfterEach => &self.after_each
+        }
+    }
+
+    fn get_hook_mut(&mut self, hook_kind: HookKind) -> &mut Option<String> {
+        match hook_kind {
+            HookKind::BeforeAll => &mut self.before_all,
+            HookKind::AfterAll => &mut self.after_all,
+            HookKind::BeforeEach => &mut self.before_each,
+            HookKind::AfterEach => &mut self.after_each
+        }
+    }
+}
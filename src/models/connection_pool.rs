@@ -0,0 +1,81 @@
+use std::time::Duration;
+
+use reqwest::ClientBuilder;
+use serde::{Deserialize, Serialize};
+
+/// Connections kept open between the sends, the values left empty keep the reqwest defaults
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct ConnectionPoolOptions {
+    /// Seconds an idle connection is kept open, 90 by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout: Option<u64>,
+
+    /// Idle connections kept open per host, 0 opens a new connection for each send
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_idle_per_host: Option<usize>,
+
+    /// Seconds between the TCP keepalive probes, none are sent by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_keepalive: Option<u64>,
+
+    /// Send the small packets without waiting (TCP_NODELAY), true by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tcp_nodelay: Option<bool>,
+}
+
+impl ConnectionPoolOptions {
+    /// The values of self, or the ones of base when self leaves them empty
+    pub fn merged_over(&self, base: &ConnectionPoolOptions) -> ConnectionPoolOptions {
+        ConnectionPoolOptions {
+            idle_timeout: self.idle_timeout.or(base.idle_timeout),
+            max_idle_per_host: self.max_idle_per_host.or(base.max_idle_per_host),
+            tcp_keepalive: self.tcp_keepalive.or(base.tcp_keepalive),
+            tcp_nodelay: self.tcp_nodelay.or(base.tcp_nodelay),
+        }
+    }
+
+    pub fn apply(&self, mut client_builder: ClientBuilder) -> ClientBuilder {
+        if let Some(idle_timeout) = self.idle_timeout {
+            client_builder = client_builder.pool_idle_timeout(Duration::from_secs(idle_timeout));
+        }
+
+        if let Some(max_idle_per_host) = self.max_idle_per_host {
+            client_builder = client_builder.pool_max_idle_per_host(max_idle_per_host);
+        }
+
+        if let Some(tcp_keepalive) = self.tcp_keepalive {
+            client_builder = client_builder.tcp_keepalive(Duration::from_secs(tcp_keepalive));
+        }
+
+        if let Some(tcp_nodelay) = self.tcp_nodelay {
+            client_builder = client_builder.tcp_nodelay(tcp_nodelay);
+        }
+
+        return client_builder;
+    }
+
+    pub fn to_summary(&self) -> String {
+        let mut options: Vec<String> = vec![];
+
+        if let Some(idle_timeout) = self.idle_timeout {
+            options.push(format!("idle timeout {idle_timeout}s"));
+        }
+
+        if let Some(max_idle_per_host) = self.max_idle_per_host {
+            options.push(format!("{max_idle_per_host} idle connections per host"));
+        }
+
+        if let Some(tcp_keepalive) = self.tcp_keepalive {
+            options.push(format!("TCP keepalive {tcp_keepalive}s"));
+        }
+
+        if let Some(tcp_nodelay) = self.tcp_nodelay {
+            options.push(format!("TCP nodelay {tcp_nodelay}"));
+        }
+
+        return match options.is_empty() {
+            true => String::from("defaults"),
+            false => options.join(", ")
+        };
+    }
+}
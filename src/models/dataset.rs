@@ -0,0 +1,120 @@
+use std::fs;
+use std::path::Path;
+
+use indexmap::IndexMap;
+use serde_json::Value;
+use thiserror::Error;
+
+/// One row of a dataset, column name => value
+pub type DataRow = IndexMap<String, String>;
+
+#[derive(Error, Debug)]
+pub enum DatasetError {
+    #[error("Could not read dataset file \"{0}\"\n\t{1}")]
+    CouldNotReadFile(String, std::io::Error),
+
+    #[error("Could not parse CSV dataset\n\t{0}")]
+    InvalidCsv(csv::Error),
+
+    #[error("Could not parse JSON dataset\n\t{0}")]
+    InvalidJson(serde_json::Error),
+
+    #[error("JSON dataset must be an array of objects")]
+    NotAnArrayOfObjects,
+
+    #[error("Unknown dataset format \"{0}\", expected a .csv or .json file")]
+    UnknownFormat(String),
+
+    #[error("Dataset is empty")]
+    Empty,
+}
+
+/// Parse a CSV (first line is the header) or JSON (array of objects) file into rows
+pub fn load_dataset(path: &Path) -> Result<Vec<DataRow>, DatasetError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) => return Err(DatasetError::CouldNotReadFile(path.display().to_string(), error))
+    };
+
+    let extension = path
+        .extension()
+        .map(|extension| extension.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+
+    let rows = match extension.as_str() {
+        "csv" => parse_csv_dataset(&content)?,
+        "json" => parse_json_dataset(&content)?,
+        _ => return Err(DatasetError::UnknownFormat(extension))
+    };
+
+    match rows.is_empty() {
+        true => Err(DatasetError::Empty),
+        false => Ok(rows)
+    }
+}
+
+fn parse_csv_dataset(content: &str) -> Result<Vec<DataRow>, DatasetError> {
+    let mut reader = csv::Reader::from_reader(content.as_bytes());
+
+    let headers = match reader.headers() {
+        Ok(headers) => headers.clone(),
+        Err(error) => return Err(DatasetError::InvalidCsv(error))
+    };
+
+    let mut rows: Vec<DataRow> = vec![];
+
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(error) => return Err(DatasetError::InvalidCsv(error))
+        };
+
+        let row: DataRow = headers
+            .iter()
+            .zip(record.iter())
+            .map(|(header, value)| (header.to_string(), value.to_string()))
+            .collect();
+
+        rows.push(row);
+    }
+
+    return Ok(rows);
+}
+
+fn parse_json_dataset(content: &str) -> Result<Vec<DataRow>, DatasetError> {
+    let json: Value = match serde_json::from_str(content) {
+        Ok(json) => json,
+        Err(error) => return Err(DatasetError::InvalidJson(error))
+    };
+
+    let array = match json {
+        Value::Array(array) => array,
+        _ => return Err(DatasetError::NotAnArrayOfObjects)
+    };
+
+    let mut rows: Vec<DataRow> = vec![];
+
+    for element in array {
+        let object = match element {
+            Value::Object(object) => object,
+            _ => return Err(DatasetError::NotAnArrayOfObjects)
+        };
+
+        let row: DataRow = object
+            .into_iter()
+            .map(|(key, value)| {
+                // Strings are bound without their quotes, other values keep their JSON representation
+                let value = match value {
+                    Value::String(string) => string,
+                    other => other.to_string()
+                };
+
+                (key, value)
+            })
+            .collect();
+
+        rows.push(row);
+    }
+
+    return Ok(rows);
+}
@@ -0,0 +1,100 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use thiserror::Error;
+
+use crate::models::request::Request;
+
+#[derive(Error, Debug)]
+pub enum DependencyError {
+    #[error("\"{0}\" depends on \"{1}\", which is not a request of the collection")]
+    UnknownDependency(String, String),
+    #[error("\"{0}\" cannot depend on itself")]
+    SelfDependency(String),
+    #[error("Dependency cycle between {0}")]
+    DependencyCycle(String),
+}
+
+/// Order running each request after the requests it depends on, the collection order is kept otherwise.
+/// Each stage holds the requests whose dependencies all ran in the previous stages, they can be sent in parallel
+#[derive(Debug, Clone)]
+pub struct ExecutionPlan {
+    /// Request indexes
+    pub order: Vec<usize>,
+    /// Request indexes, in the execution order
+    pub stages: Vec<Vec<usize>>,
+}
+
+impl ExecutionPlan {
+    pub fn new(requests: &[Arc<RwLock<Request>>]) -> Result<ExecutionPlan, DependencyError> {
+        let names: Vec<String> = requests
+            .iter()
+            .map(|request| request.read().name.clone())
+            .collect();
+
+        let mut dependencies: Vec<Vec<usize>> = vec![];
+
+        for (name, request) in names.iter().zip(requests) {
+            let mut request_dependencies: Vec<usize> = vec![];
+
+            for dependency in &request.read().depends_on {
+                if dependency == name {
+                    return Err(DependencyError::SelfDependency(name.clone()));
+                }
+
+                match names.iter().position(|other_name| other_name == dependency) {
+                    None => return Err(DependencyError::UnknownDependency(name.clone(), dependency.clone())),
+                    Some(dependency_index) => request_dependencies.push(dependency_index)
+                }
+            }
+
+            dependencies.push(request_dependencies);
+        }
+
+        let mut order: Vec<usize> = vec![];
+        let mut stage_numbers: Vec<usize> = vec![0; requests.len()];
+
+        // The first request in the collection order whose dependencies all ran is the next one
+        while order.len() < requests.len() {
+            let next_request = (0..requests.len()).find(|index| {
+                !order.contains(index) && dependencies[*index].iter().all(|dependency| order.contains(dependency))
+            });
+
+            let next_request = match next_request {
+                Some(next_request) => next_request,
+                None => {
+                    let cycle: Vec<String> = (0..requests.len())
+                        .filter(|index| !order.contains(index))
+                        .map(|index| format!("\"{}\"", names[index]))
+                        .collect();
+
+                    return Err(DependencyError::DependencyCycle(cycle.join(", ")));
+                }
+            };
+
+            stage_numbers[next_request] = dependencies[next_request]
+                .iter()
+                .map(|dependency| stage_numbers[*dependency] + 1)
+                .max()
+                .unwrap_or(0);
+
+            order.push(next_request);
+        }
+
+        let stages_count = stage_numbers.iter().max().map(|max_stage| max_stage + 1).unwrap_or(0);
+
+        let stages = (0..stages_count)
+            .map(|stage_number| order
+                .iter()
+                .copied()
+                .filter(|index| stage_numbers[*index] == stage_number)
+                .collect()
+            )
+            .collect();
+
+        Ok(ExecutionPlan {
+            order,
+            stages,
+        })
+    }
+}
@@ -0,0 +1,77 @@
+use std::fmt::{Display, Formatter};
+use std::net::{IpAddr, SocketAddr};
+use std::time::Duration;
+
+use reqwest::Url;
+
+/// Default port of the nameservers
+const DNS_PORT: u16 = 53;
+
+/// Where the host names of the requests are resolved, see dns_resolver in the config
+#[derive(Debug, Clone, PartialEq)]
+pub enum DnsServer {
+    /// Resolver of the operating system
+    System,
+    /// Nameserver queried over UDP, e.g. "10.0.0.53" or "[2001:db8::53]:5353"
+    Nameserver(SocketAddr),
+    /// DNS-over-HTTPS endpoint (RFC 8484), e.g. "https://cloudflare-dns.com/dns-query"
+    DnsOverHttps(Url),
+}
+
+impl DnsServer {
+    pub fn parse(dns_server: &str) -> Result<DnsServer, String> {
+        let dns_server = dns_server.trim();
+
+        if dns_server.is_empty() || dns_server.eq_ignore_ascii_case("system") {
+            return Ok(DnsServer::System);
+        }
+
+        if dns_server.starts_with("https://") || dns_server.starts_with("http://") {
+            return match Url::parse(dns_server) {
+                Ok(url) => Ok(DnsServer::DnsOverHttps(url)),
+                Err(error) => Err(format!("\"{dns_server}\" {error}"))
+            };
+        }
+
+        if let Ok(ip) = dns_server.parse::<IpAddr>() {
+            return Ok(DnsServer::Nameserver(SocketAddr::new(ip, DNS_PORT)));
+        }
+
+        return match dns_server.parse::<SocketAddr>() {
+            Ok(address) => Ok(DnsServer::Nameserver(address)),
+            Err(_) => Err(format!("\"{dns_server}\" is neither \"system\", a nameserver IP address nor a DNS-over-HTTPS URL"))
+        };
+    }
+}
+
+impl Display for DnsServer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DnsServer::System => write!(f, "system resolver"),
+            DnsServer::Nameserver(address) if address.port() == DNS_PORT => write!(f, "{}", address.ip()),
+            DnsServer::Nameserver(address) => write!(f, "{address}"),
+            DnsServer::DnsOverHttps(url) => write!(f, "{}", url.host_str().unwrap_or(url.as_str()))
+        }
+    }
+}
+
+/// Addresses a host name was last resolved to by the resolver of the config
+#[derive(Debug, Clone)]
+pub struct DnsResolution {
+    pub addresses: Vec<IpAddr>,
+    /// e.g. "1.1.1.1" or "cloudflare-dns.com"
+    pub server: String,
+    pub duration: Duration,
+}
+
+impl Display for DnsResolution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let addresses = self.addresses
+            .iter()
+            .map(IpAddr::to_string)
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        write!(f, "DNS {addresses} via {} ({} ms)", self.server, self.duration.as_millis())
+    }
+}
@@ -0,0 +1,93 @@
+use clap::ValueEnum;
+use strum::Display;
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Display)]
+pub enum FuzzGenerator {
+    /// Empty, zero, negative, integer overflow, special numeric values
+    #[strum(to_string = "boundary")]
+    Boundary,
+
+    /// Very long and multibyte strings
+    #[strum(to_string = "long-strings")]
+    LongStrings,
+
+    /// SQL, script, path traversal, template and command injection payloads
+    #[strum(to_string = "injection")]
+    Injection,
+}
+
+#[derive(ValueEnum, Debug, Clone, Copy, PartialEq, Display)]
+pub enum FuzzTarget {
+    /// Query params values
+    #[strum(to_string = "param")]
+    Params,
+
+    /// Headers values
+    #[strum(to_string = "header")]
+    Headers,
+
+    /// Top-level JSON fields, form fields, or the whole body for other content types
+    #[strum(to_string = "body")]
+    Body,
+}
+
+impl FuzzGenerator {
+    pub fn all() -> Vec<FuzzGenerator> {
+        return vec![FuzzGenerator::Boundary, FuzzGenerator::LongStrings, FuzzGenerator::Injection];
+    }
+
+    pub fn payloads(&self) -> Vec<String> {
+        match self {
+            FuzzGenerator::Boundary => vec![
+                String::new(),
+                String::from(" "),
+                String::from("0"),
+                String::from("-1"),
+                String::from("2147483647"),
+                String::from("2147483648"),
+                String::from("-2147483649"),
+                String::from("18446744073709551616"),
+                String::from("1e309"),
+                String::from("0.000000000000000000001"),
+                String::from("NaN"),
+                String::from("null"),
+                String::from("true"),
+            ],
+            FuzzGenerator::LongStrings => vec![
+                "A".repeat(256),
+                "A".repeat(4096),
+                "A".repeat(65536),
+                "€".repeat(1024),
+                "🦀".repeat(1024),
+            ],
+            FuzzGenerator::Injection => vec![
+                String::from("' OR '1'='1"),
+                String::from("\"; DROP TABLE users; --"),
+                String::from("<script>alert(1)</script>"),
+                String::from("../../../../etc/passwd"),
+                String::from("${7*7}"),
+                String::from("<%= 7*7 %>"),
+                String::from("$(id)"),
+                String::from("%s%s%s%n"),
+                String::from("%00"),
+                String::from("{\"$gt\": \"\"}"),
+            ]
+        }
+    }
+}
+
+impl FuzzTarget {
+    pub fn all() -> Vec<FuzzTarget> {
+        return vec![FuzzTarget::Params, FuzzTarget::Headers, FuzzTarget::Body];
+    }
+}
+
+/// Printable version of a payload, long payloads are summarized
+pub fn payload_to_summary(payload: &str) -> String {
+    let char_count = payload.chars().count();
+
+    match char_count > 40 {
+        true => format!("{:?}... ({char_count} chars)", payload.chars().take(20).collect::<String>()),
+        false => format!("{payload:?}")
+    }
+}
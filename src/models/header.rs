@@ -0,0 +1,96 @@
+/// Header names proposed while editing a header name, after the ones of the workspace
+pub const STANDARD_HEADERS: [&str; 40] = [
+    "Accept",
+    "Accept-Charset",
+    "Accept-Encoding",
+    "Accept-Language",
+    "Access-Control-Request-Headers",
+    "Access-Control-Request-Method",
+    "Authorization",
+    "Cache-Control",
+    "Connection",
+    "Content-Disposition",
+    "Content-Encoding",
+    "Content-Language",
+    "Content-Length",
+    "Content-Type",
+    "Cookie",
+    "Date",
+    "DNT",
+    "Expect",
+    "Forwarded",
+    "From",
+    "Host",
+    "If-Match",
+    "If-Modified-Since",
+    "If-None-Match",
+    "If-Range",
+    "If-Unmodified-Since",
+    "Keep-Alive",
+    "Max-Forwards",
+    "Origin",
+    "Pragma",
+    "Prefer",
+    "Proxy-Authorization",
+    "Range",
+    "Referer",
+    "TE",
+    "Upgrade",
+    "User-Agent",
+    "Via",
+    "X-Forwarded-For",
+    "X-Request-ID",
+];
+
+const CONTENT_TYPES: [&str; 12] = [
+    "application/json",
+    "application/xml",
+    "application/x-www-form-urlencoded",
+    "application/octet-stream",
+    "application/x-protobuf",
+    "application/msgpack",
+    "application/cbor",
+    "application/x-ndjson",
+    "multipart/form-data",
+    "text/plain",
+    "text/html",
+    "text/csv",
+];
+
+const ENCODINGS: [&str; 5] = ["gzip", "deflate", "br", "zstd", "identity"];
+
+/// Cache-Control request directives, several can be given separated by commas
+const CACHE_CONTROL_DIRECTIVES: [&str; 8] = [
+    "no-cache",
+    "no-store",
+    "no-transform",
+    "only-if-cached",
+    "max-age=0",
+    "max-stale",
+    "min-fresh=",
+    "stale-if-error=",
+];
+
+/// Values proposed while editing the value of a standard header, the case of the name is ignored
+pub fn get_common_header_values(header: &str) -> &'static [&'static str] {
+    return match header.to_ascii_lowercase().as_str() {
+        "accept" => &["*/*", "application/json", "application/xml", "text/html", "text/plain", "text/csv", "application/x-ndjson", "application/x-protobuf"],
+        "accept-charset" => &["utf-8", "iso-8859-1"],
+        "accept-encoding" => &ENCODINGS,
+        "accept-language" => &["en-US", "en", "fr-FR", "de-DE", "es-ES", "*"],
+        "access-control-request-method" => &["GET", "POST", "PUT", "PATCH", "DELETE", "HEAD", "OPTIONS"],
+        "authorization" | "proxy-authorization" => &["Bearer ", "Basic ", "Digest "],
+        "cache-control" => &CACHE_CONTROL_DIRECTIVES,
+        "connection" => &["keep-alive", "close", "upgrade"],
+        "content-disposition" => &["inline", "attachment", "attachment; filename=", "form-data; name="],
+        "content-encoding" => &ENCODINGS,
+        "content-type" => &CONTENT_TYPES,
+        "dnt" => &["0", "1"],
+        "expect" => &["100-continue"],
+        "pragma" => &["no-cache"],
+        "prefer" => &["return=minimal", "return=representation", "respond-async", "wait="],
+        "te" => &["trailers", "gzip", "deflate"],
+        "upgrade" => &["websocket", "h2c"],
+        _ => &[]
+    };
+}
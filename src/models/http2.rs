@@ -0,0 +1,101 @@
+use reqwest::ClientBuilder;
+use serde::{Deserialize, Serialize};
+
+/// HTTP/2 connection settings, the values left empty keep the reqwest defaults
+#[derive(Default, Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Http2Options {
+    /// Speak HTTP/2 without negotiating it, needed for the cleartext HTTP/2 (h2c) servers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prior_knowledge: Option<bool>,
+
+    /// Grow the flow control windows with the measured bandwidth, overrides the window sizes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub adaptive_window: Option<bool>,
+
+    /// Largest frame payload accepted, between 16384 and 16777215 bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_frame_size: Option<u32>,
+
+    /// Flow control window of each stream, in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_stream_window_size: Option<u32>,
+
+    /// Flow control window of the whole connection, in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub initial_connection_window_size: Option<u32>,
+}
+
+/// Frame size bounds of the SETTINGS_MAX_FRAME_SIZE parameter (RFC 9113)
+const MIN_FRAME_SIZE: u32 = 16_384;
+const MAX_FRAME_SIZE: u32 = 16_777_215;
+
+impl Http2Options {
+    /// The values of self, or the ones of base when self leaves them empty
+    pub fn merged_over(&self, base: &Http2Options) -> Http2Options {
+        Http2Options {
+            prior_knowledge: self.prior_knowledge.or(base.prior_knowledge),
+            adaptive_window: self.adaptive_window.or(base.adaptive_window),
+            max_frame_size: self.max_frame_size.or(base.max_frame_size),
+            initial_stream_window_size: self.initial_stream_window_size.or(base.initial_stream_window_size),
+            initial_connection_window_size: self.initial_connection_window_size.or(base.initial_connection_window_size),
+        }
+    }
+
+    /// The max frame size out of the bounds of the protocol
+    pub fn get_invalid_frame_size(&self) -> Option<u32> {
+        return self.max_frame_size.filter(|max_frame_size| !(MIN_FRAME_SIZE..=MAX_FRAME_SIZE).contains(max_frame_size));
+    }
+
+    pub fn apply(&self, mut client_builder: ClientBuilder) -> ClientBuilder {
+        if self.prior_knowledge == Some(true) {
+            client_builder = client_builder.http2_prior_knowledge();
+        }
+
+        if let Some(adaptive_window) = self.adaptive_window {
+            client_builder = client_builder.http2_adaptive_window(adaptive_window);
+        }
+
+        if let Some(max_frame_size) = self.max_frame_size {
+            client_builder = client_builder.http2_max_frame_size(max_frame_size);
+        }
+
+        if let Some(initial_stream_window_size) = self.initial_stream_window_size {
+            client_builder = client_builder.http2_initial_stream_window_size(initial_stream_window_size);
+        }
+
+        if let Some(initial_connection_window_size) = self.initial_connection_window_size {
+            client_builder = client_builder.http2_initial_connection_window_size(initial_connection_window_size);
+        }
+
+        return client_builder;
+    }
+
+    pub fn to_summary(&self) -> String {
+        let mut options: Vec<String> = vec![];
+
+        if let Some(prior_knowledge) = self.prior_knowledge {
+            options.push(format!("prior knowledge {prior_knowledge}"));
+        }
+
+        if let Some(adaptive_window) = self.adaptive_window {
+            options.push(format!("adaptive window {adaptive_window}"));
+        }
+
+        if let Some(max_frame_size) = self.max_frame_size {
+            options.push(format!("max frame size {max_frame_size}"));
+        }
+
+        if let Some(initial_stream_window_size) = self.initial_stream_window_size {
+            options.push(format!("stream window {initial_stream_window_size}"));
+        }
+
+        if let Some(initial_connection_window_size) = self.initial_connection_window_size {
+            options.push(format!("connection window {initial_connection_window_size}"));
+        }
+
+        return match options.is_empty() {
+            true => String::from("defaults"),
+            false => options.join(", ")
+        };
+    }
+}
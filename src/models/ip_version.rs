@@ -0,0 +1,43 @@
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+/// Address family the host names of a request resolve to
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Display, EnumIter)]
+#[serde(rename_all = "lowercase")]
+pub enum IpVersion {
+    /// Every resolved address, the system picks one
+    #[default]
+    Any,
+    #[strum(to_string = "IPv4")]
+    Ipv4,
+    #[strum(to_string = "IPv6")]
+    Ipv6,
+}
+
+/// Choices of the request setting
+pub const IP_VERSION_CHOICES: [&str; 3] = ["Any", "IPv4", "IPv6"];
+
+impl IpVersion {
+    pub fn accepts(&self, ip: &IpAddr) -> bool {
+        return match self {
+            IpVersion::Any => true,
+            IpVersion::Ipv4 => ip.is_ipv4(),
+            IpVersion::Ipv6 => ip.is_ipv6()
+        };
+    }
+
+    pub fn from_choice(choice: &str) -> Option<IpVersion> {
+        return IpVersion::iter().find(|ip_version| ip_version.to_string().eq_ignore_ascii_case(choice));
+    }
+
+    pub fn get_choice_name(&self) -> &'static str {
+        let ip_version_name = self.to_string();
+
+        return IP_VERSION_CHOICES
+            .iter()
+            .find(|choice| **choice == ip_version_name)
+            .unwrap_or(&IP_VERSION_CHOICES[0]);
+    }
+}
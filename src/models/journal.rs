@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Text of a request field being edited, kept until the edit is confirmed or cancelled
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UnsavedEdit {
+    pub collection_index: usize,
+    pub collection_name: String,
+    pub request_index: usize,
+    pub request_name: String,
+    pub field: UnsavedEditField,
+    pub text: String,
+    /// When the edit was last written to the journal, UTC
+    #[serde(default)]
+    pub saved_at: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum UnsavedEditField {
+    #[strum(to_string = "URL")]
+    Url,
+    #[strum(to_string = "query param")]
+    QueryParam { row: usize, column: usize },
+    #[strum(to_string = "header")]
+    Header { row: usize, column: usize },
+    #[strum(to_string = "body")]
+    Body,
+    #[strum(to_string = "pre-request script")]
+    PreRequestScript,
+    #[strum(to_string = "post-request script")]
+    PostRequestScript,
+}
@@ -0,0 +1,169 @@
+use std::collections::BTreeMap;
+use std::time::{Duration, Instant};
+
+#[derive(Debug, Clone, Copy)]
+pub enum LoadTestLimit {
+    Iterations(u64),
+    Duration(Duration),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct LoadTestOptions {
+    pub concurrency: usize,
+    pub limit: LoadTestLimit,
+}
+
+impl Default for LoadTestOptions {
+    fn default() -> Self {
+        LoadTestOptions {
+            concurrency: 10,
+            limit: LoadTestLimit::Iterations(100),
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct LoadTestStats {
+    pub started_at: Option<Instant>,
+    pub finished_at: Option<Instant>,
+    /// (time since the start of the run, latency) of every request that received a response
+    pub samples: Vec<(Duration, Duration)>,
+    pub status_codes: BTreeMap<u16, u64>,
+    /// Requests that did not receive any response (connection error, timeout, ...)
+    pub errors: u64,
+}
+
+impl LoadTestStats {
+    pub fn is_finished(&self) -> bool {
+        return self.finished_at.is_some();
+    }
+
+    pub fn elapsed(&self) -> Duration {
+        match (self.started_at, self.finished_at) {
+            (Some(started_at), Some(finished_at)) => finished_at - started_at,
+            (Some(started_at), None) => started_at.elapsed(),
+            _ => Duration::ZERO
+        }
+    }
+
+    pub fn total_requests(&self) -> u64 {
+        return self.samples.len() as u64 + self.errors;
+    }
+
+    /// Responses with a 4xx or 5xx status code
+    pub fn failed_responses(&self) -> u64 {
+        return self.status_codes
+            .iter()
+            .filter(|(status_code, _)| **status_code >= 400)
+            .map(|(_, count)| count)
+            .sum();
+    }
+
+    pub fn requests_per_second(&self) -> f64 {
+        let elapsed = self.elapsed().as_secs_f64();
+
+        match elapsed > 0.0 {
+            true => self.total_requests() as f64 / elapsed,
+            false => 0.0
+        }
+    }
+
+    /// Nearest-rank percentile, e.g. 0.99 for p99
+    pub fn percentile(&self, percentile: f64) -> Option<Duration> {
+        if self.samples.is_empty() {
+            return None;
+        }
+
+        let mut latencies: Vec<Duration> = self.samples.iter().map(|(_, latency)| *latency).collect();
+        latencies.sort();
+
+        let rank = (percentile * latencies.len() as f64).ceil() as usize;
+        let index = rank.clamp(1, latencies.len()) - 1;
+
+        return Some(latencies[index]);
+    }
+
+    /// Average latency in milliseconds of each second of the run, used to draw a sparkline
+    pub fn latency_per_second(&self) -> Vec<u64> {
+        let seconds = self.elapsed().as_secs() as usize + 1;
+
+        let mut sums = vec![0u128; seconds];
+        let mut counts = vec![0u128; seconds];
+
+        for (offset, latency) in &self.samples {
+            let second = usize::min(offset.as_secs() as usize, seconds - 1);
+            sums[second] += latency.as_millis();
+            counts[second] += 1;
+        }
+
+        return sums
+            .iter()
+            .zip(counts.iter())
+            .map(|(sum, count)| match count {
+                0 => 0,
+                count => (sum / count) as u64
+            })
+            .collect();
+    }
+
+    pub fn to_report_lines(&self) -> Vec<String> {
+        let format_percentile = |percentile: f64| match self.percentile(percentile) {
+            None => String::from("-"),
+            Some(latency) => format!("{:?}", latency)
+        };
+
+        let status_codes = self.status_codes
+            .iter()
+            .map(|(status_code, count)| format!("{status_code}: {count}"))
+            .collect::<Vec<String>>()
+            .join(", ");
+
+        return vec![
+            format!("requests: {} in {:.2?}", self.total_requests(), self.elapsed()),
+            format!("rps: {:.2}", self.requests_per_second()),
+            format!("latency: p50 {} | p90 {} | p99 {}", format_percentile(0.5), format_percentile(0.9), format_percentile(0.99)),
+            format!("status codes: {status_codes}"),
+            format!("errors: {} (no response) + {} (4xx/5xx)", self.errors, self.failed_responses()),
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn get_stats(latencies_ms: &[u64]) -> LoadTestStats {
+        LoadTestStats {
+            samples: latencies_ms.iter().map(|latency| (Duration::ZERO, Duration::from_millis(*latency))).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn nearest_rank_percentiles() {
+        let stats = get_stats(&[50, 10, 40, 20, 30, 60, 90, 70, 100, 80]);
+
+        assert_eq!(stats.percentile(0.5), Some(Duration::from_millis(50)));
+        assert_eq!(stats.percentile(0.9), Some(Duration::from_millis(90)));
+        assert_eq!(stats.percentile(0.99), Some(Duration::from_millis(100)));
+        assert_eq!(stats.percentile(0.0), Some(Duration::from_millis(10)));
+        assert_eq!(stats.percentile(1.0), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn no_percentile_without_samples() {
+        assert_eq!(get_stats(&[]).percentile(0.5), None);
+    }
+
+    #[test]
+    fn failed_responses_and_errors() {
+        let stats = LoadTestStats {
+            status_codes: BTreeMap::from([(200, 5), (404, 2), (503, 1)]),
+            errors: 3,
+            ..get_stats(&[10; 8])
+        };
+
+        assert_eq!(stats.total_requests(), 11);
+        assert_eq!(stats.failed_responses(), 3);
+    }
+}
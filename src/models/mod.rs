@@ -7,3 +7,41 @@ pub mod environment;
 pub mod settings;
 pub mod response;
 pub mod scripts;
+pub mod webhook;
+pub mod monitor;
+pub mod load_test;
+pub mod dataset;
+pub mod fuzz;
+pub mod schema;
+pub mod snapshot;
+pub mod user_agent;
+pub mod header;
+pub mod template;
+pub mod journal;
+pub mod protobuf;
+pub mod binary_body;
+pub mod charset;
+
+pub mod scratchpad;
+pub mod result_tab;
+pub mod cache_validators;
+pub mod response_cache;
+pub mod rate_limit;
+pub mod app_log;
+pub mod request_error;
+pub mod watch;
+pub mod plugin;
+pub mod soap;
+pub mod pagination;
+pub mod connection_pool;
+pub mod ip_version;
+pub mod dns;
+pub mod tls_version;
+pub mod http2;
+pub mod wire;
+pub mod assertion;
+pub mod run_report;
+pub mod tag;
+pub mod dependency;
+pub mod collection_hooks;
+pub mod think_time;
\ No newline at end of file
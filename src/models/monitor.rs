@@ -0,0 +1,110 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Number of checks kept in a monitor history
+pub const MAX_MONITOR_HISTORY: usize = 100;
+
+#[derive(Error, Debug)]
+pub enum MonitorError {
+    #[error("Invalid monitor interval \"{0}\", expected something like 30s, 5m, 1h or 1d")]
+    InvalidInterval(String),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestMonitor {
+    /// Interval between two checks, e.g. "30s", "5m", "1h"
+    pub every: String,
+
+    #[serde(skip)]
+    pub next_run: Option<Instant>,
+
+    #[serde(skip)]
+    pub history: Vec<MonitorRecord>,
+}
+
+#[derive(Debug, Clone)]
+pub struct MonitorRecord {
+    pub checked_at: String,
+    pub status_code: Option<String>,
+    pub duration: Duration,
+    pub is_failure: bool,
+}
+
+impl RequestMonitor {
+    pub fn new(every: String) -> Result<RequestMonitor, MonitorError> {
+        parse_interval(&every)?;
+
+        Ok(RequestMonitor {
+            every,
+            next_run: None,
+            history: vec![],
+        })
+    }
+
+    pub fn get_interval(&self) -> Result<Duration, MonitorError> {
+        return parse_interval(&self.every);
+    }
+
+    pub fn is_due(&self, now: Instant) -> bool {
+        match self.next_run {
+            None => true,
+            Some(next_run) => now >= next_run
+        }
+    }
+
+    pub fn record(&mut self, record: MonitorRecord) {
+        self.history.push(record);
+
+        if self.history.len() > MAX_MONITOR_HISTORY {
+            self.history.remove(0);
+        }
+    }
+
+    pub fn has_last_check_failed(&self) -> bool {
+        match self.history.last() {
+            None => false,
+            Some(record) => record.is_failure
+        }
+    }
+
+    /// e.g. "every 30s: 18/20 up, avg 45ms"
+    pub fn to_summary(&self) -> String {
+        if self.history.is_empty() {
+            return format!("every {}: no check yet", self.every);
+        }
+
+        let checks = self.history.len();
+        let successes = self.history.iter().filter(|record| !record.is_failure).count();
+        let average_duration = self.history.iter().map(|record| record.duration).sum::<Duration>() / checks as u32;
+
+        return format!("every {}: {successes}/{checks} up, avg {}ms", self.every, average_duration.as_millis());
+    }
+}
+
+/// Parses an interval like "30s", "5m", "1h" or "1d"
+pub fn parse_interval(interval: &str) -> Result<Duration, MonitorError> {
+    let interval = interval.trim();
+
+    if interval.len() < 2 {
+        return Err(MonitorError::InvalidInterval(interval.to_string()));
+    }
+
+    let (value, unit) = interval.split_at(interval.len() - 1);
+
+    let value = match value.parse::<u64>() {
+        Ok(value) if value > 0 => value,
+        _ => return Err(MonitorError::InvalidInterval(interval.to_string()))
+    };
+
+    let seconds = match unit {
+        "s" => value,
+        "m" => value * 60,
+        "h" => value * 60 * 60,
+        "d" => value * 60 * 60 * 24,
+        _ => return Err(MonitorError::InvalidInterval(interval.to_string()))
+    };
+
+    return Ok(Duration::from_secs(seconds));
+}
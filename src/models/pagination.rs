@@ -0,0 +1,94 @@
+use clap::ValueEnum;
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+/// Pages followed when no maximum is given, so that an API always answering a next page cannot loop forever
+pub const DEFAULT_MAX_PAGES: usize = 100;
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, ValueEnum, Display, Serialize, Deserialize)]
+pub enum PaginationStrategy {
+    /// Follow the URL found at next_link_path, or in the "next" relation of the Link header
+    #[default]
+    #[value(name = "next-link")]
+    #[serde(rename = "next-link")]
+    #[strum(to_string = "next link")]
+    NextLink,
+
+    /// Increment the param by one for each page
+    #[value(name = "page")]
+    #[serde(rename = "page")]
+    #[strum(to_string = "page param")]
+    Page,
+
+    /// Increment the param by the page size for each page
+    #[value(name = "offset")]
+    #[serde(rename = "offset")]
+    #[strum(to_string = "offset param")]
+    Offset,
+}
+
+/// How to get the next pages of a list endpoint and the items of each page
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct RequestPagination {
+    pub strategy: PaginationStrategy,
+
+    /// JSON path of the next page URL, e.g. $.links.next
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next_link_path: Option<String>,
+
+    /// Query param holding the page or the offset, e.g. page
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub param: Option<String>,
+
+    /// Value of the param for the first page, 1 for a page and 0 for an offset by default
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<usize>,
+
+    /// Items per page, a shorter page is the last one. Needed by the offset strategy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub page_size: Option<usize>,
+
+    /// JSON path of the items array, e.g. $.data. The body itself when it is an array
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub items_path: Option<String>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_pages: Option<usize>,
+}
+
+impl RequestPagination {
+    pub fn to_summary(&self) -> String {
+        let mut summary = match (self.strategy, &self.param) {
+            (PaginationStrategy::NextLink, _) => match &self.next_link_path {
+                None => String::from("next link from the Link header"),
+                Some(next_link_path) => format!("next link at {next_link_path}")
+            },
+            (strategy, Some(param)) => format!("{strategy} \"{param}\" from {}", self.get_start()),
+            (strategy, None) => format!("{strategy} without param")
+        };
+
+        if let Some(page_size) = self.page_size {
+            summary += &format!(", {page_size} items per page");
+        }
+
+        if let Some(items_path) = &self.items_path {
+            summary += &format!(", items at {items_path}");
+        }
+
+        summary += &format!(", at most {} pages", self.get_max_pages());
+
+        return summary;
+    }
+
+    pub fn get_start(&self) -> usize {
+        match (self.start, self.strategy) {
+            (Some(start), _) => start,
+            (None, PaginationStrategy::Page) => 1,
+            (None, _) => 0
+        }
+    }
+
+    pub fn get_max_pages(&self) -> usize {
+        return self.max_pages.unwrap_or(DEFAULT_MAX_PAGES);
+    }
+}
@@ -0,0 +1,213 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use strum::Display;
+use thiserror::Error;
+
+use crate::models::collection::Collection;
+use crate::models::request::Request;
+
+/// Version of the JSON messages exchanged with the plugins, bumped on every breaking change.
+/// A plugin declaring a newer version is not loaded.
+pub const PLUGIN_API_VERSION: u32 = 1;
+
+#[derive(Error, Debug)]
+pub enum PluginError {
+    #[error("Plugin \"{0}\" not found")]
+    NotFound(String),
+    #[error("Plugin \"{0}\" does not provide the \"{1}\" hook")]
+    MissingHook(String, PluginHook),
+    #[error("Could not run plugin \"{0}\"\n\t{1}")]
+    Spawn(String, String),
+    #[error("Plugin \"{0}\" failed with {1}\n\t{2}")]
+    Failed(String, String, String),
+    #[error("Plugin \"{0}\" answered an invalid output\n\t{1}")]
+    InvalidOutput(String, String),
+}
+
+/// What a plugin can be called for
+#[derive(Debug, Copy, Clone, PartialEq, Display, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PluginHook {
+    /// Compute the auth headers and query params of a request
+    #[strum(to_string = "auth")]
+    Auth,
+    /// Encode a text body for the content types the plugin declares
+    #[strum(to_string = "body_encoder")]
+    BodyEncoder,
+    /// Turn a file into a collection
+    #[strum(to_string = "importer")]
+    Importer,
+    /// Turn a collection or a request into another format
+    #[strum(to_string = "exporter")]
+    Exporter,
+    /// Rewrite a response body or add tests to it
+    #[strum(to_string = "response_processor")]
+    ResponseProcessor,
+}
+
+/// "plugin.toml" file of a plugin directory
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginManifest {
+    pub name: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    /// Version of the plugin API the plugin was written for
+    pub api_version: u32,
+
+    /// Program and arguments, run from the plugin directory, e.g. ["python3", "plugin.py"]
+    pub command: Vec<String>,
+
+    pub hooks: Vec<PluginHook>,
+
+    /// Body content types handled by a body encoder, e.g. "application/x-thrift"
+    #[serde(default)]
+    pub content_types: Vec<String>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Plugin {
+    pub manifest: PluginManifest,
+    pub directory: PathBuf,
+}
+
+impl Plugin {
+    pub fn has_hook(&self, hook: PluginHook) -> bool {
+        return self.manifest.hooks.contains(&hook);
+    }
+
+    /// Whether the body encoder handles the content type, its parameters (e.g. "; charset=utf-8") being ignored
+    pub fn encodes_content_type(&self, content_type: &str) -> bool {
+        let mime = content_type.split(';').next().unwrap_or_default().trim();
+
+        return self.has_hook(PluginHook::BodyEncoder) && self.manifest.content_types
+            .iter()
+            .any(|plugin_content_type| plugin_content_type.eq_ignore_ascii_case(mime));
+    }
+}
+
+/// Written to the standard input of the plugin
+#[derive(Debug, Serialize)]
+pub struct PluginMessage<'a, I: Serialize> {
+    pub api_version: u32,
+    pub hook: PluginHook,
+    pub input: &'a I,
+}
+
+/* AUTH */
+
+#[derive(Debug, Serialize)]
+pub struct AuthInput {
+    /// Free configuration of the request auth, e.g. a key or a profile name
+    pub config: String,
+    pub method: String,
+    pub url: String,
+    pub headers: Vec<(String, String)>,
+    /// Text body, None when it is empty, binary or streamed
+    pub body: Option<String>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AuthOutput {
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+    #[serde(default)]
+    pub query: Vec<(String, String)>,
+}
+
+/* BODY ENCODER */
+
+#[derive(Debug, Serialize)]
+pub struct BodyEncoderInput {
+    pub content_type: String,
+    pub body: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct BodyEncoderOutput {
+    /// Text body
+    #[serde(default)]
+    pub body: Option<String>,
+    /// Binary body, takes precedence over the text body
+    #[serde(default)]
+    pub body_base64: Option<String>,
+}
+
+/* IMPORTER */
+
+#[derive(Debug, Serialize)]
+pub struct ImporterInput {
+    pub path: String,
+    pub content: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImporterOutput {
+    /// Collection file content, see the collection files
+    pub collection: Value,
+}
+
+impl ImporterOutput {
+    /// Missing request fields take the value of a new request, so that the plugins only give what they know
+    pub fn into_collection(mut self) -> Result<Collection, serde_json::Error> {
+        if let Some(requests) = self.collection.get_mut("requests").and_then(Value::as_array_mut) {
+            let default_request = serde_json::to_value(Request::default())?;
+
+            for request in requests.iter_mut() {
+                let mut full_request = default_request.clone();
+
+                if let (Some(full_request), Some(request)) = (full_request.as_object_mut(), request.as_object()) {
+                    full_request.extend(request.clone());
+                }
+
+                *request = full_request;
+            }
+        }
+
+        return serde_json::from_value(self.collection);
+    }
+}
+
+/* EXPORTER */
+
+#[derive(Debug, Serialize)]
+pub struct ExporterInput<'a> {
+    pub collection: &'a Collection,
+    /// Set when a single request is exported
+    pub request: Option<&'a Request>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ExporterOutput {
+    pub output: String,
+}
+
+/* RESPONSE PROCESSOR */
+
+#[derive(Debug, Serialize)]
+pub struct ResponseProcessorInput {
+    pub request_name: String,
+    pub status_code: Option<String>,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ResponseProcessorOutput {
+    /// Replaces the response body when set
+    #[serde(default)]
+    pub body: Option<String>,
+    #[serde(default)]
+    pub tests: Vec<PluginTestResult>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PluginTestResult {
+    pub name: String,
+    pub passed: bool,
+    #[serde(default)]
+    pub messages: Vec<String>,
+}
\ No newline at end of file
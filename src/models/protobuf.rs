@@ -0,0 +1,139 @@
+use std::path::{Path, PathBuf};
+
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor};
+use prost_reflect::prost::Message;
+use protobuf::descriptor::FileDescriptorSet;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProtobufError {
+    #[error("Could not parse the proto files\n\t{0}")]
+    CouldNotParseProtoFiles(String),
+
+    #[error("Invalid proto files\n\t{0}")]
+    InvalidProtoFiles(String),
+
+    #[error("Message \"{0}\" not found in the proto files")]
+    MessageNotFound(String),
+
+    #[error("No request message set")]
+    NoRequestMessage,
+
+    #[error("No response message set")]
+    NoResponseMessage,
+
+    #[error("No protobuf schema set")]
+    NoSchema,
+
+    #[error("Could not encode the JSON message\n\t{0}")]
+    CouldNotEncode(String),
+
+    #[error("Could not decode the protobuf response as \"{0}\"\n\t{1}")]
+    CouldNotDecode(String, String),
+}
+
+/// Proto files describing the messages of a protobuf-over-HTTP request
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtobufSchema {
+    /// The directory of each file is used to resolve its imports
+    pub files: Vec<String>,
+
+    /// Fully qualified name of the message sent, e.g. "shop.v1.CreateOrder"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub request_message: Option<String>,
+
+    /// Fully qualified name of the message received, application/x-protobuf responses are decoded to JSON
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_message: Option<String>,
+}
+
+impl ProtobufSchema {
+    pub fn to_summary(&self) -> String {
+        let request_message = self.request_message.as_deref().unwrap_or("-");
+        let response_message = self.response_message.as_deref().unwrap_or("-");
+
+        format!("{} (request: {request_message}, response: {response_message})", self.files.join(", "))
+    }
+
+    /// Parse the proto files and their imports
+    pub fn load(&self) -> Result<DescriptorPool, ProtobufError> {
+        let mut include_paths: Vec<PathBuf> = vec![];
+
+        for file in &self.files {
+            let include_path = match Path::new(file).parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.to_path_buf(),
+                _ => PathBuf::from(".")
+            };
+
+            if !include_paths.contains(&include_path) {
+                include_paths.push(include_path);
+            }
+        }
+
+        let parsed = protobuf_parse::Parser::new()
+            .pure()
+            .includes(&include_paths)
+            .inputs(&self.files)
+            .parse_and_typecheck()
+            .map_err(|error| ProtobufError::CouldNotParseProtoFiles(format!("{error:#}")))?;
+
+        // Imported files are kept, the messages may reference them
+        let file_descriptor_set = FileDescriptorSet {
+            file: parsed.file_descriptors,
+            ..Default::default()
+        };
+
+        let bytes = protobuf::Message::write_to_bytes(&file_descriptor_set)
+            .map_err(|error| ProtobufError::InvalidProtoFiles(error.to_string()))?;
+
+        return DescriptorPool::decode(bytes.as_slice()).map_err(|error| ProtobufError::InvalidProtoFiles(error.to_string()));
+    }
+
+    /// Check that the proto files can be parsed and that the messages exist
+    pub fn validate(&self) -> Result<(), ProtobufError> {
+        let descriptor_pool = self.load()?;
+
+        for message in [&self.request_message, &self.response_message].into_iter().flatten() {
+            get_message(&descriptor_pool, message)?;
+        }
+
+        return Ok(());
+    }
+
+    /// Encode a JSON message (protobuf JSON mapping) as the request message
+    pub fn encode_request(&self, json: &str) -> Result<Vec<u8>, ProtobufError> {
+        let request_message = self.request_message.as_ref().ok_or(ProtobufError::NoRequestMessage)?;
+        let message_descriptor = get_message(&self.load()?, request_message)?;
+
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+
+        let message = DynamicMessage::deserialize(message_descriptor, &mut deserializer)
+            .and_then(|message| deserializer.end().map(|_| message))
+            .map_err(|error| ProtobufError::CouldNotEncode(error.to_string()))?;
+
+        return Ok(message.encode_to_vec());
+    }
+
+    /// Decode a response message as JSON (protobuf JSON mapping)
+    pub fn decode_response(&self, bytes: &[u8]) -> Result<String, ProtobufError> {
+        let response_message = self.response_message.as_ref().ok_or(ProtobufError::NoResponseMessage)?;
+        let message_descriptor = get_message(&self.load()?, response_message)?;
+
+        let message = DynamicMessage::decode(message_descriptor, bytes)
+            .map_err(|error| ProtobufError::CouldNotDecode(response_message.clone(), error.to_string()))?;
+
+        return serde_json::to_string(&message).map_err(|error| ProtobufError::CouldNotDecode(response_message.clone(), error.to_string()));
+    }
+}
+
+fn get_message(descriptor_pool: &DescriptorPool, name: &str) -> Result<MessageDescriptor, ProtobufError> {
+    return descriptor_pool
+        .get_message_by_name(name)
+        .ok_or(ProtobufError::MessageNotFound(name.to_string()));
+}
+
+/// application/x-protobuf, application/protobuf, application/vnd.google.protobuf...
+pub fn is_protobuf_content_type(content_type: &str) -> bool {
+    return content_type.contains("protobuf");
+}
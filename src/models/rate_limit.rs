@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+/// Pace of the collection runs and of the data-driven runs
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimitOptions {
+    /// No limit when None
+    pub requests_per_second: Option<f64>,
+    /// Requests waiting for their response at the same time, 1 sends them one after the other
+    pub concurrency: usize,
+    /// Minimum time between the start of two requests
+    pub delay: Duration,
+    /// Times a request answered with a 429 is sent again
+    pub max_retries: u32,
+}
+
+impl Default for RateLimitOptions {
+    fn default() -> Self {
+        RateLimitOptions {
+            requests_per_second: None,
+            concurrency: 1,
+            delay: Duration::ZERO,
+            max_retries: 3,
+        }
+    }
+}
+
+impl RateLimitOptions {
+    /// Minimum time between the start of two requests, the longest of the delay and of the rate limit
+    pub fn get_interval(&self) -> Duration {
+        let rate_interval = match self.requests_per_second {
+            Some(requests_per_second) if requests_per_second > 0.0 => Duration::from_secs_f64(1.0 / requests_per_second),
+            _ => Duration::ZERO
+        };
+
+        return rate_interval.max(self.delay);
+    }
+}
@@ -1,3 +1,7 @@
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
 use lazy_static::lazy_static;
 use ratatui::prelude::{Line, Modifier, Span};
 use ratatui::style::{Color, Stylize};
@@ -9,33 +13,112 @@ use tokio_util::sync::CancellationToken;
 
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
+use crate::models::assertion::ResponseAssertion;
 use crate::models::auth::Auth;
-use crate::models::body::ContentType;
+use crate::models::body::{ContentType, UploadProgress};
+use crate::models::cache_validators::CacheValidators;
 use crate::models::method::Method;
-use crate::models::response::RequestResponse;
+use crate::models::monitor::RequestMonitor;
+use crate::models::pagination::RequestPagination;
+use crate::models::protobuf::ProtobufSchema;
+use crate::models::response::{RequestResponse, ResponseStream};
+use crate::models::schema::ResponseSchema;
+use crate::models::snapshot::ResponseSnapshot;
 use crate::models::scripts::RequestScripts;
 use crate::models::settings::RequestSettings;
+use crate::models::soap::RequestSoap;
+use crate::models::think_time::ThinkTime;
+use crate::models::watch::RequestWatch;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct Request {
     pub name: String,
+
+    /// Markdown documentation, rendered in the docs tab
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// e.g. "smoke", to filter the collections tree or the requests sent by "collection send --tag"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+
+    /// Left out of the collection runs, e.g. while its endpoint is unfinished. Still sent on its own
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub skipped: bool,
+
+    /// Names of the requests of the collection that must have succeeded before the runs send this one, e.g. a create request
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+
+    /// Pause of the runs after this request, instead of the one of the collection
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub think_time: Option<ThinkTime>,
+
     pub url: String,
     pub method: Method,
     pub params: Vec<KeyValue>,
     pub headers: Vec<KeyValue>,
+    /// Sent with this request only, override the cookies of the jar with the same name
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cookies: Vec<KeyValue>,
     pub body: ContentType,
     pub auth: Auth,
     pub scripts: RequestScripts,
     pub settings: RequestSettings,
 
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub monitor: Option<RequestMonitor>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_schema: Option<ResponseSchema>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub snapshot: Option<ResponseSnapshot>,
+
+    /// Checks of the response headers and duration, added to its tests
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assertions: Vec<ResponseAssertion>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protobuf: Option<ProtobufSchema>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub soap: Option<RequestSoap>,
+
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pagination: Option<RequestPagination>,
+
     #[serde(skip)]
     pub response: RequestResponse,
 
     #[serde(skip)]
     pub is_pending: bool,
 
+    /// Sends waiting for the pending one to end, see the queue sends setting.
+    /// Counted without locking the request, which stays read locked while it is pending
+    #[serde(skip)]
+    pub queued_sends: Arc<AtomicUsize>,
+
     #[serde(skip)]
     pub cancellation_token: CancellationToken,
+
+    #[serde(skip)]
+    pub upload_progress: UploadProgress,
+
+    #[serde(skip)]
+    pub response_stream: ResponseStream,
+
+    /// File the full response body is written to, regardless of the max response size
+    #[serde(skip)]
+    pub response_output: Option<PathBuf>,
+
+    /// Validators of the last response, sent back to revalidate it
+    #[serde(skip)]
+    pub cache_validators: CacheValidators,
+
+    /// Re-sends the request while it is selected, toggled from the TUI
+    #[serde(skip)]
+    pub watch: Option<RequestWatch>,
 }
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
@@ -46,19 +129,7 @@ pub struct KeyValue {
 
 impl App<'_> {
     pub fn key_value_vec_to_tuple_vec(&self, key_value: &Vec<KeyValue>) -> Vec<(String, String)> {
-        key_value
-            .par_iter()
-            .filter_map(|param| {
-                if param.enabled {
-                    let key = self.replace_env_keys_by_value(&param.data.0);
-                    let value = self.replace_env_keys_by_value(&param.data.1);
-
-                    Some((key, value))
-                } else {
-                    None
-                }
-            })
-            .collect()
+        return self.get_variable_resolver().key_value_vec_to_tuple_vec(key_value);
     }
 }
 
@@ -88,7 +159,7 @@ lazy_static! {
 }
 
 impl Request {
-    pub fn to_tree_item<'a>(&self, identifier: usize) -> TreeItem<'a, usize> {
+    pub fn to_tree_item<'a>(&self, identifier: usize, roles: &[String]) -> TreeItem<'a, usize> {
         let mut line_elements: Vec<Span> = vec![];
 
         let prefix = Span::from(self.method.to_string())
@@ -98,17 +169,49 @@ impl Request {
 
         line_elements.push(prefix);
 
-        if self.is_pending {
-            line_elements.push(Span::raw(" 🕛"));
+        let text_markers = THEME.read().accessibility.text_markers;
+
+        match (self.is_pending, text_markers) {
+            (true, false) => line_elements.push(Span::raw(" 🕛")),
+            (true, true) => line_elements.push(Span::raw(" [pending] ")),
+            (false, _) => line_elements.push(Span::raw(" "))
         }
-        else {
-            line_elements.push(Span::raw(" "));
+
+        match (self.queued_sends.load(Ordering::Relaxed), text_markers) {
+            (0, _) => {},
+            (queued_sends, false) => line_elements.push(Span::raw(format!("+{queued_sends} "))),
+            (queued_sends, true) => line_elements.push(Span::raw(format!("[{queued_sends} queued] ")))
         }
 
-        let text = Span::from(self.name.clone()).fg(THEME.read().ui.font_color);
+        let text = match self.skipped {
+            true => Span::from(self.name.clone()).fg(THEME.read().ui.secondary_foreground_color).add_modifier(Modifier::CROSSED_OUT),
+            false => Span::from(self.name.clone()).fg(THEME.read().ui.font_color)
+        };
 
         line_elements.push(text);
 
+        if self.skipped {
+            line_elements.push(Span::raw(" skipped").fg(THEME.read().ui.secondary_foreground_color));
+        }
+
+        for role in roles {
+            line_elements.push(Span::raw(format!(" {role}")).fg(THEME.read().ui.secondary_foreground_color));
+        }
+
+        for tag in &self.tags {
+            line_elements.push(Span::raw(format!(" #{tag}")).fg(THEME.read().ui.secondary_foreground_color));
+        }
+
+        // Flags failing monitors
+        if let Some(monitor) = &self.monitor {
+            match (monitor.has_last_check_failed(), text_markers) {
+                (true, false) => line_elements.push(Span::raw(" ✗").fg(Color::Red)),
+                (true, true) => line_elements.push(Span::raw(" [monitor failed]").fg(Color::Red)),
+                (false, false) => line_elements.push(Span::raw(" ⏱").fg(THEME.read().ui.secondary_foreground_color)),
+                (false, true) => line_elements.push(Span::raw(" [monitored]").fg(THEME.read().ui.secondary_foreground_color))
+            }
+        }
+
         let line = Line::from(line_elements);
 
         TreeItem::new_leaf(identifier, line)
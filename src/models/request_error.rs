@@ -0,0 +1,154 @@
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// Why a request got no response, displayed in the result view instead of the body
+#[derive(Debug, Clone)]
+pub struct RequestError {
+    pub kind: RequestErrorKind,
+    pub url: Option<String>,
+    /// Error then its causes, from the outermost one
+    pub chain: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RequestErrorKind {
+    Dns,
+    Connect,
+    Tls,
+    Timeout,
+    TooManyRedirects,
+    Decode,
+    Body,
+    InvalidRequest,
+    Other,
+}
+
+impl RequestError {
+    pub fn from_send_error(error: &reqwest_middleware::Error) -> RequestError {
+        let mut chain: Vec<String> = vec![];
+        let mut source: Option<&dyn Error> = Some(error);
+
+        while let Some(error) = source {
+            let message = error.to_string();
+
+            // The wrappers often end with the message of their source, e.g. "dns error: failed to lookup address"
+            if !chain.last().is_some_and(|last| last.ends_with(&message)) {
+                chain.push(message);
+            }
+
+            source = error.source();
+        }
+
+        let (kind, url) = match error {
+            reqwest_middleware::Error::Middleware(_) => (RequestErrorKind::Other, None),
+            reqwest_middleware::Error::Reqwest(error) => (
+                classify_reqwest_error(error, &chain),
+                error.url().map(|url| url.to_string())
+            )
+        };
+
+        RequestError {
+            kind,
+            url,
+            chain,
+        }
+    }
+
+    /// No response in the time ATAC waits for one, the client may still be waiting
+    pub fn timed_out() -> RequestError {
+        RequestError {
+            kind: RequestErrorKind::Timeout,
+            url: None,
+            chain: vec![String::from("No response after 30 seconds")],
+        }
+    }
+
+    /// What the user can check, depends on the kind of error
+    pub fn get_hint(&self) -> &'static str {
+        match self.kind {
+            RequestErrorKind::Dns => "Check the host of the URL, the environment it comes from and the DNS resolution, e.g. a VPN or the hosts file",
+            RequestErrorKind::Connect => "Check that the server is running and listening on this port, and the proxy of atac.toml",
+            RequestErrorKind::Tls => "Check the certificate of the server, a self-signed one needs \"Accept invalid certs\" in the request settings",
+            RequestErrorKind::Timeout => "The server did not answer in time, check that it is reachable and not overloaded",
+            RequestErrorKind::TooManyRedirects => "Check the redirect loop, or raise \"Max redirects\" in the request settings",
+            RequestErrorKind::Decode => "The response body does not match its Content-Encoding or its Content-Type",
+            RequestErrorKind::Body => "The request body could not be sent, e.g. its file is unreadable",
+            RequestErrorKind::InvalidRequest => "Check the URL and the headers of the request",
+            RequestErrorKind::Other => "See the causes above"
+        }
+    }
+}
+
+/// The DNS, TCP and TLS failures are all connect errors, told apart by their causes
+fn classify_reqwest_error(error: &reqwest::Error, chain: &[String]) -> RequestErrorKind {
+    let causes = chain.join("\n").to_lowercase();
+
+    if error.is_timeout() {
+        return RequestErrorKind::Timeout;
+    }
+
+    if error.is_redirect() {
+        return RequestErrorKind::TooManyRedirects;
+    }
+
+    if error.is_decode() {
+        return RequestErrorKind::Decode;
+    }
+
+    if error.is_builder() {
+        return RequestErrorKind::InvalidRequest;
+    }
+
+    if causes.contains("dns error") || causes.contains("failed to lookup address") {
+        return RequestErrorKind::Dns;
+    }
+
+    if error.is_connect() {
+        let is_https = error.url().is_some_and(|url| url.scheme() == "https");
+
+        // Past the TCP connection, an HTTPS connect error comes from the handshake
+        return match is_https && !causes.contains("tcp connect error") {
+            true => RequestErrorKind::Tls,
+            false => RequestErrorKind::Connect
+        };
+    }
+
+    if error.is_body() || error.is_request() {
+        return RequestErrorKind::Body;
+    }
+
+    return RequestErrorKind::Other;
+}
+
+impl RequestErrorKind {
+    /// Stable name of the kind, e.g. in the JSON output of the CLI
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            RequestErrorKind::Dns => "dns",
+            RequestErrorKind::Connect => "connect",
+            RequestErrorKind::Tls => "tls",
+            RequestErrorKind::Timeout => "timeout",
+            RequestErrorKind::TooManyRedirects => "too_many_redirects",
+            RequestErrorKind::Decode => "decode",
+            RequestErrorKind::Body => "body",
+            RequestErrorKind::InvalidRequest => "invalid_request",
+            RequestErrorKind::Other => "other"
+        }
+    }
+}
+
+impl Display for RequestErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestErrorKind::Dns => write!(f, "DNS resolution failed"),
+            RequestErrorKind::Connect => write!(f, "TCP connection failed"),
+            RequestErrorKind::Tls => write!(f, "TLS handshake failed"),
+            RequestErrorKind::Timeout => write!(f, "Timed out"),
+            RequestErrorKind::TooManyRedirects => write!(f, "Too many redirects"),
+            RequestErrorKind::Decode => write!(f, "Could not decode the response"),
+            RequestErrorKind::Body => write!(f, "Could not send the request"),
+            RequestErrorKind::InvalidRequest => write!(f, "Invalid request"),
+            RequestErrorKind::Other => write!(f, "Request failed")
+        }
+    }
+}
@@ -1,24 +1,397 @@
+use std::borrow::Cow;
+use std::fmt::{Display, Formatter};
+use std::mem;
+use std::ops::Deref;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use encoding_rs::Encoding;
 use image::DynamicImage;
-use serde::{Deserialize, Serialize};
+use parking_lot::RwLock;
+use reqwest::StatusCode;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::models::charset::find_response_encoding;
+use crate::models::dns::DnsResolution;
+use crate::models::request_error::RequestError;
+use crate::models::response_cache::CacheStatus;
+use crate::models::soap::SoapFault;
+use crate::models::wire::WireExchange;
 
 #[derive(Default, Debug, Clone, Serialize, Deserialize)]
 pub struct RequestResponse {
     #[serde(skip)]
     pub duration: Option<String>,
 
+    /// Outcome of the send, None until the request is sent
     #[serde(skip)]
-    pub status_code: Option<String>,
+    pub result: Option<RequestResult>,
 
     pub content: Option<ResponseContent>,
 
+    /// Body as received, when the content is not a view over it, e.g. a protobuf body displayed as JSON.
+    /// Dropped when the content is rewritten
+    #[serde(skip)]
+    pub raw_body: Option<RawBody>,
+
     pub cookies: Option<String>,
-    pub headers: Vec<(String, String)>
+    pub headers: Vec<(String, String)>,
+
+    /// Charset the text body was decoded with
+    #[serde(skip)]
+    pub charset: Option<String>,
+
+    #[serde(skip)]
+    pub tests: Vec<TestResult>,
+
+    /// Whether the response came from the response cache, None when the cache is not used
+    #[serde(skip)]
+    pub cache_status: Option<CacheStatus>,
+
+    /// Answered from the request snapshot instead of the network, see the offline mode
+    #[serde(skip)]
+    pub is_offline: bool,
+
+    /// Set when the response processor of the request rewrote the body
+    #[serde(skip)]
+    pub processed_body: Option<ProcessedBody>,
+
+    /// Fault answered by a SOAP request
+    #[serde(skip)]
+    pub soap_fault: Option<SoapFault>,
+
+    /// Addresses the host was resolved to, when the config sets a DNS resolver or the request an IP version
+    #[serde(skip)]
+    pub dns_resolution: Option<DnsResolution>,
+
+    /// Fields as exchanged with the server, None when no response was received
+    #[serde(skip)]
+    pub wire: Option<WireExchange>,
+
+    /// Fields received after the body, with a chunked HTTP/1.1 body or over HTTP/2
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub trailers: Vec<(String, String)>
+}
+
+/// Bytes of a received body, decoded to text on first use only so that binary bodies stay intact.
+/// The clones share the decoded text
+#[derive(Debug, Clone)]
+pub struct RawBody {
+    pub bytes: Arc<Vec<u8>>,
+    pub encoding: &'static Encoding,
+    bom_length: usize,
+    /// Appended to the text when only the first bytes were kept, see the max response size of the request settings
+    truncation_notice: Option<String>,
+    text: Arc<OnceLock<String>>,
+}
+
+impl RawBody {
+    /// The charset is read from the BOM and the response headers, unless overridden
+    pub fn new(bytes: Vec<u8>, headers: &[(String, String)], charset_override: Option<&str>, truncation_notice: Option<String>) -> RawBody {
+        let (encoding, bom_length) = find_response_encoding(&bytes, headers, charset_override);
+
+        RawBody {
+            bytes: Arc::new(bytes),
+            encoding,
+            bom_length,
+            truncation_notice,
+            text: Arc::new(OnceLock::new()),
+        }
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        return self.truncation_notice.is_some();
+    }
+
+    /// Decoded on the first call, the notice is kept on its own line after the received part
+    pub fn get_text(&self) -> &String {
+        return self.text.get_or_init(|| {
+            let text = self.get_received_text();
+
+            match &self.truncation_notice {
+                None => text.into_owned(),
+                Some(truncation_notice) => format!("{text}\n\n{truncation_notice}")
+            }
+        });
+    }
+
+    /// Text of the received bytes without the notice, borrowed from them when they are valid in the charset, e.g. UTF-8
+    pub fn get_received_text(&self) -> Cow<'_, str> {
+        let (text, _) = self.encoding.decode_without_bom_handling(&self.bytes[self.bom_length..]);
+
+        return text;
+    }
+}
+
+/// Text of a body, either a view over the received bytes or a text of its own
+#[derive(Debug, Clone)]
+pub enum BodyText {
+    Raw(RawBody),
+    /// e.g. an error message, a pretty-printed body or a body rewritten by a response processor
+    Owned(String),
+}
+
+impl BodyText {
+    /// Text of the body, the received one not being decoded when its bytes are valid in their charset
+    pub fn get_received_text(&self) -> Cow<'_, str> {
+        match self {
+            BodyText::Raw(raw_body) => raw_body.get_received_text(),
+            BodyText::Owned(text) => Cow::Borrowed(text)
+        }
+    }
+
+    pub fn is_truncated(&self) -> bool {
+        return matches!(self, BodyText::Raw(raw_body) if raw_body.is_truncated());
+    }
+}
+
+impl Deref for BodyText {
+    type Target = String;
+
+    fn deref(&self) -> &String {
+        match self {
+            BodyText::Raw(raw_body) => raw_body.get_text(),
+            BodyText::Owned(text) => text
+        }
+    }
+}
+
+impl From<String> for BodyText {
+    fn from(text: String) -> BodyText {
+        BodyText::Owned(text)
+    }
+}
+
+impl Display for BodyText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl Serialize for BodyText {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for BodyText {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<BodyText, D::Error> {
+        String::deserialize(deserializer).map(BodyText::Owned)
+    }
+}
+
+/// Body not displayed, swapped with the content to switch between the processed and the raw body
+#[derive(Debug, Clone)]
+pub struct ProcessedBody {
+    pub other_body: BodyText,
+    /// Whether the displayed body is the raw one
+    pub is_raw: bool,
+}
+
+/// Outcome of a send, the UI, the assertions and the CLI branch on it instead of parsing the status text
+#[derive(Debug, Clone)]
+pub enum RequestResult {
+    /// The server answered, whatever the status
+    Success {
+        status: u16,
+    },
+    /// No response was received, the body holds the error message
+    TransportError(RequestError),
+    /// The request was not sent or its response was dropped, e.g. it could not be prepared or it was canceled
+    LocalError {
+        message: String,
+    },
+}
+
+impl RequestResult {
+    pub fn local_error(message: impl ToString) -> RequestResult {
+        RequestResult::LocalError {
+            message: message.to_string(),
+        }
+    }
+
+    /// None when no response was received
+    pub fn get_status_code(&self) -> Option<u16> {
+        match self {
+            RequestResult::Success { status } => Some(*status),
+            _ => None
+        }
+    }
+
+    /// A 2xx status was received
+    pub fn is_success(&self) -> bool {
+        return self.get_status_code().is_some_and(|status_code| (200..300).contains(&status_code));
+    }
+
+    /// No status was received, or a 4xx or a 5xx one
+    pub fn is_failure(&self) -> bool {
+        return self.get_status_code().map_or(true, |status_code| status_code >= 400);
+    }
+
+    /// Stable name of the variant, e.g. in the JSON output of the CLI
+    pub fn get_code(&self) -> &'static str {
+        match self {
+            RequestResult::Success { .. } => "success",
+            RequestResult::TransportError(_) => "transport_error",
+            RequestResult::LocalError { .. } => "local_error"
+        }
+    }
+}
+
+/// e.g. "200 OK", "TCP connection failed" or "CANCELED"
+impl Display for RequestResult {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RequestResult::Success { status } => match StatusCode::from_u16(*status) {
+                Ok(status_code) => write!(f, "{status_code}"),
+                Err(_) => write!(f, "{status}")
+            },
+            RequestResult::TransportError(error) => write!(f, "{}", error.kind),
+            RequestResult::LocalError { message } => write!(f, "{message}")
+        }
+    }
+}
+
+impl RequestResponse {
+    /// Status of the received response
+    pub fn get_status_code(&self) -> Option<u16> {
+        return self.result.as_ref().and_then(RequestResult::get_status_code);
+    }
+
+    /// Status of the received response, or why none was received
+    pub fn get_status_text(&self) -> Option<String> {
+        return self.result.as_ref().map(|result| result.to_string());
+    }
+
+    /// Why no response was received, when the request could not reach the server
+    pub fn get_transport_error(&self) -> Option<&RequestError> {
+        match &self.result {
+            Some(RequestResult::TransportError(error)) => Some(error),
+            _ => None
+        }
+    }
+
+    /// Bytes of the received body, or of the text it was rewritten to, e.g. by the post-request script
+    pub fn get_body_bytes(&self) -> Option<&[u8]> {
+        if let Some(raw_body) = &self.raw_body {
+            return Some(&raw_body.bytes);
+        }
+
+        match &self.content {
+            Some(ResponseContent::Body(BodyText::Raw(raw_body))) => Some(&raw_body.bytes),
+            Some(ResponseContent::Body(body)) if self.get_transport_error().is_none() => Some(body.as_bytes()),
+            Some(ResponseContent::Image(image)) => Some(&image.data),
+            _ => None
+        }
+    }
+
+    /// The received body was cut at the max response size setting
+    pub fn is_truncated(&self) -> bool {
+        return match (&self.raw_body, &self.content) {
+            (Some(raw_body), _) | (None, Some(ResponseContent::Body(BodyText::Raw(raw_body)))) => raw_body.is_truncated(),
+            _ => false
+        };
+    }
+
+    /// A 2xx status was received
+    pub fn is_success(&self) -> bool {
+        return self.result.as_ref().is_some_and(RequestResult::is_success);
+    }
+
+    /// No status was received, or a 4xx or a 5xx one
+    pub fn is_failure(&self) -> bool {
+        return self.result.as_ref().map_or(true, RequestResult::is_failure);
+    }
+
+    /// The server asks to log in again, e.g. when the session or the token expired
+    pub fn is_unauthorized(&self) -> bool {
+        return self.get_status_code() == Some(401);
+    }
+
+    /// The resource did not change since the validators sent with the request
+    pub fn is_not_modified(&self) -> bool {
+        return self.get_status_code() == Some(304);
+    }
+
+    /// The server is rate limiting the requests
+    pub fn is_too_many_requests(&self) -> bool {
+        return self.get_status_code() == Some(429);
+    }
+
+    /// Wait asked by the Retry-After header, given in seconds or as an HTTP date
+    pub fn get_retry_after(&self) -> Option<Duration> {
+        let retry_after = self.headers
+            .iter()
+            .find(|(header_name, _)| header_name.eq_ignore_ascii_case("retry-after"))
+            .map(|(_, header_value)| header_value.trim())?;
+
+        if let Ok(seconds) = retry_after.parse::<u64>() {
+            return Some(Duration::from_secs(seconds));
+        }
+
+        let retry_date = DateTime::parse_from_rfc2822(retry_after).ok()?;
+
+        // A date in the past means right away
+        return Some((retry_date.with_timezone(&Utc) - Utc::now()).to_std().unwrap_or(Duration::ZERO));
+    }
+
+    /// Display the raw body instead of the processed one, or the other way around
+    pub fn toggle_processed_body(&mut self) -> bool {
+        match (&mut self.processed_body, &mut self.content) {
+            (Some(processed_body), Some(ResponseContent::Body(body))) => {
+                mem::swap(body, &mut processed_body.other_body);
+                processed_body.is_raw = !processed_body.is_raw;
+                true
+            },
+            _ => false
+        }
+    }
+}
+
+/// Outcome of a check made on the response, displayed in the tests tab
+#[derive(Debug, Clone)]
+pub struct TestResult {
+    pub name: String,
+    pub passed: bool,
+    pub messages: Vec<String>
+}
+
+impl TestResult {
+    pub fn passed(name: String) -> TestResult {
+        TestResult {
+            name,
+            passed: true,
+            messages: vec![],
+        }
+    }
+
+    pub fn failed(name: String, messages: Vec<String>) -> TestResult {
+        TestResult {
+            name,
+            passed: false,
+            messages,
+        }
+    }
+
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut lines = match self.passed {
+            true => vec![format!("PASS {}", self.name)],
+            false => vec![format!("FAIL {}", self.name)]
+        };
+
+        for message in &self.messages {
+            lines.push(format!("    {message}"));
+        }
+
+        return lines;
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(untagged)]
 pub enum ResponseContent {
-    Body(String),
+    Body(BodyText),
     Image(ImageResponse)
 }
 
@@ -28,3 +401,20 @@ pub struct ImageResponse {
     #[serde(skip)]
     pub image: Option<DynamicImage>
 }
+
+/// Lines of a newline-delimited JSON response received so far, displayed while the request is pending
+#[derive(Default, Debug, Clone)]
+pub struct ResponseStream {
+    pub lines: Arc<RwLock<Vec<String>>>,
+}
+
+impl ResponseStream {
+    pub fn push(&self, line: String) {
+        self.lines.write().push(line);
+    }
+
+    /// Takes the received lines, the stream being emptied
+    pub fn take(&self) -> Vec<String> {
+        return std::mem::take(&mut *self.lines.write());
+    }
+}
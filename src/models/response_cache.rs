@@ -0,0 +1,401 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::{Display, Formatter};
+use std::hash::{Hash, Hasher};
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Local, Utc};
+
+use crate::models::response::RequestResponse;
+
+/// Sends kept in the log of the cache inspector
+const MAX_CACHE_LOG_LENGTH: usize = 50;
+
+/// Statuses a response can be stored with without any explicit permission other than its freshness
+const CACHEABLE_STATUS_CODES: [u16; 11] = [200, 203, 204, 300, 301, 308, 404, 405, 410, 414, 501];
+
+/// Request headers sending credentials, a response is only served to the requests sending the same ones unless it is public
+const CREDENTIALS_HEADERS: [&str; 3] = ["authorization", "proxy-authorization", "cookie"];
+
+/// Client-side cache of the GET and HEAD responses, following their Cache-Control, Expires and Vary headers.
+/// Kept in memory, the Cache-Control of the request is not taken into account so that the default "no-cache" header does not bypass it
+#[derive(Default)]
+pub struct ResponseCache {
+    /// Newest first
+    pub entries: Vec<ResponseCacheEntry>,
+    /// Cache status of the last sends, newest first
+    pub log: Vec<CacheLogRecord>,
+}
+
+pub struct ResponseCacheEntry {
+    /// Method and URL, e.g. "GET https://example.com/users"
+    pub key: String,
+    /// Values the request had for the headers named by the Vary header of the response, lowercase names
+    pub vary: Vec<(String, Option<String>)>,
+    /// Hash of the credentials the response was received with, None when the response is public
+    pub credentials_hash: Option<u64>,
+    pub response: RequestResponse,
+    /// Format the body is highlighted with
+    pub file_format: Option<String>,
+    pub stored_at: Instant,
+    pub freshness_lifetime: Duration,
+    /// Raw Cache-Control header of the stored response
+    pub cache_control: Option<String>,
+    pub hits: usize,
+}
+
+/// Request a response is looked up or stored for, besides its method and URL
+pub struct CacheRequest {
+    /// Headers of the request, the cookies of the cookie jar included
+    pub headers: Vec<(String, String)>,
+    /// Selected environment, its values may hold credentials
+    pub environment: Option<String>,
+}
+
+pub struct CacheLogRecord {
+    pub sent_at: String,
+    pub key: String,
+    pub status: CacheStatus,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CacheStatus {
+    /// Served from the cache without sending the request
+    Hit { age: u64, fresh_for: u64 },
+    /// Not in the cache or stale, the request has been sent
+    Miss(CacheStorage),
+    /// Sent on purpose without looking the cache up
+    Refreshed(CacheStorage),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CacheStorage {
+    /// Freshness lifetime in seconds
+    Stored(u64),
+    NotStored(String),
+}
+
+impl Display for CacheStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CacheStatus::Hit { age, fresh_for } => write!(f, "Cache hit, age {age}s, fresh for {fresh_for}s"),
+            CacheStatus::Miss(storage) => write!(f, "Cache miss, {storage}"),
+            CacheStatus::Refreshed(storage) => write!(f, "Cache refreshed, {storage}")
+        }
+    }
+}
+
+impl Display for CacheStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CacheStorage::Stored(lifetime) => write!(f, "stored for {lifetime}s"),
+            CacheStorage::NotStored(reason) => write!(f, "not stored ({reason})")
+        }
+    }
+}
+
+impl ResponseCacheEntry {
+    pub fn get_age(&self) -> Duration {
+        return self.stored_at.elapsed();
+    }
+
+    pub fn is_fresh(&self) -> bool {
+        return self.get_age() < self.freshness_lifetime;
+    }
+
+    /// Seconds left before the entry becomes stale
+    pub fn get_fresh_for(&self) -> u64 {
+        return self.freshness_lifetime.saturating_sub(self.get_age()).as_secs();
+    }
+
+    /// Same varying headers, and same credentials unless the response is public
+    pub fn matches(&self, request: &CacheRequest) -> bool {
+        let is_same_vary = self.vary
+            .iter()
+            .all(|(header_name, value)| request.find_header(header_name) == value.as_deref());
+
+        let is_same_credentials = match self.credentials_hash {
+            None => true,
+            Some(credentials_hash) => credentials_hash == request.get_credentials_hash()
+        };
+
+        return is_same_vary && is_same_credentials;
+    }
+}
+
+impl CacheRequest {
+    fn find_header(&self, header_name: &str) -> Option<&str> {
+        return self.headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(header_name))
+            .map(|(_, value)| value.trim());
+    }
+
+    /// The requests without credentials share the same hash
+    fn get_credentials_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+
+        self.environment.hash(&mut hasher);
+
+        for header_name in CREDENTIALS_HEADERS {
+            self.find_header(header_name).hash(&mut hasher);
+        }
+
+        return hasher.finish();
+    }
+}
+
+impl ResponseCache {
+    pub fn get_key(method: &str, url: &str) -> String {
+        return format!("{method} {url}");
+    }
+
+    /// Only the GET and HEAD responses are cached
+    pub fn is_cacheable_method(method: &str) -> bool {
+        return method == "GET" || method == "HEAD";
+    }
+
+    /// The stored response and its file format when it is still fresh and matches the request, the stale entries are dropped
+    pub fn lookup(&mut self, key: &str, request: &CacheRequest) -> Option<(RequestResponse, Option<String>)> {
+        let index = self.entries.iter().position(|entry| entry.key == key && entry.matches(request))?;
+
+        if !self.entries[index].is_fresh() {
+            self.entries.remove(index);
+            return None;
+        }
+
+        let entry = &mut self.entries[index];
+        entry.hits += 1;
+
+        let status = CacheStatus::Hit {
+            age: entry.get_age().as_secs(),
+            fresh_for: entry.get_fresh_for(),
+        };
+
+        let mut response = entry.response.clone();
+        response.cache_status = Some(status.clone());
+
+        let file_format = entry.file_format.clone();
+
+        self.push_log(key, status);
+
+        return Some((response, file_format));
+    }
+
+    /// Store the response when its headers allow it, replacing the previous one of the same request
+    pub fn store(&mut self, key: &str, request: &CacheRequest, response: &RequestResponse, file_format: &Option<String>) -> CacheStorage {
+        self.entries.retain(|entry| entry.key != key || !entry.matches(request));
+
+        let freshness_lifetime = match get_freshness_lifetime(response) {
+            Ok(freshness_lifetime) => freshness_lifetime,
+            Err(reason) => return CacheStorage::NotStored(reason)
+        };
+
+        let vary = find_header(response, "vary")
+            .unwrap_or("")
+            .split(',')
+            .map(|header_name| header_name.trim().to_ascii_lowercase())
+            .filter(|header_name| !header_name.is_empty())
+            .map(|header_name| {
+                let value = request.find_header(&header_name).map(str::to_string);
+                (header_name, value)
+            })
+            .collect();
+
+        let credentials_hash = match is_public(response) {
+            true => None,
+            false => Some(request.get_credentials_hash())
+        };
+
+        self.entries.insert(0, ResponseCacheEntry {
+            key: key.to_string(),
+            vary,
+            credentials_hash,
+            response: response.clone(),
+            file_format: file_format.clone(),
+            stored_at: Instant::now(),
+            freshness_lifetime,
+            cache_control: find_header(response, "cache-control").map(str::to_string),
+            hits: 0,
+        });
+
+        return CacheStorage::Stored(freshness_lifetime.as_secs());
+    }
+
+    pub fn remove(&mut self, key: &str) {
+        self.entries.retain(|entry| entry.key != key);
+    }
+
+    /// A request with another method may modify the resource, its GET and HEAD responses are dropped
+    pub fn invalidate_url(&mut self, url: &str) {
+        self.remove(&ResponseCache::get_key("GET", url));
+        self.remove(&ResponseCache::get_key("HEAD", url));
+    }
+
+    pub fn push_log(&mut self, key: &str, status: CacheStatus) {
+        self.log.insert(0, CacheLogRecord {
+            sent_at: Local::now().format("%H:%M:%S").to_string(),
+            key: key.to_string(),
+            status,
+        });
+
+        self.log.truncate(MAX_CACHE_LOG_LENGTH);
+    }
+}
+
+/// Freshness lifetime given by the Cache-Control max-age or by the Expires header, the reason why the response cannot be stored otherwise
+fn get_freshness_lifetime(response: &RequestResponse) -> Result<Duration, String> {
+    match response.get_status_code() {
+        None => return Err(String::from("no response")),
+        Some(status_code) if !CACHEABLE_STATUS_CODES.contains(&status_code) => return Err(format!("status {status_code}")),
+        Some(_) => {}
+    }
+
+    if find_header(response, "vary") == Some("*") {
+        return Err(String::from("Vary: *"));
+    }
+
+    let directives: Vec<String> = find_header(response, "cache-control")
+        .unwrap_or("")
+        .split(',')
+        .map(|directive| directive.trim().to_ascii_lowercase())
+        .filter(|directive| !directive.is_empty())
+        .collect();
+
+    for directive in ["no-store", "no-cache"] {
+        if directives.iter().any(|response_directive| response_directive == directive) {
+            return Err(directive.to_string());
+        }
+    }
+
+    // Time the response already spent in the shared caches
+    let age = find_header(response, "age")
+        .and_then(|age| age.parse::<u64>().ok())
+        .unwrap_or(0);
+
+    let max_age = directives
+        .iter()
+        .find_map(|directive| directive.strip_prefix("max-age="))
+        .map(|max_age| max_age.trim_matches('"').parse::<u64>().unwrap_or(0));
+
+    let lifetime = match max_age {
+        Some(max_age) => max_age,
+        None => match find_header(response, "expires") {
+            None => return Err(String::from("no max-age or Expires")),
+            Some(expires) => {
+                let date = find_header(response, "date").and_then(parse_http_date).unwrap_or(Utc::now());
+
+                // An invalid date means already expired
+                match parse_http_date(expires) {
+                    Some(expires) => (expires - date).num_seconds().max(0) as u64,
+                    None => 0
+                }
+            }
+        }
+    };
+
+    return match lifetime.saturating_sub(age) {
+        0 => Err(String::from("already stale")),
+        lifetime => Ok(Duration::from_secs(lifetime))
+    };
+}
+
+/// The response may be served to the requests sending other credentials
+fn is_public(response: &RequestResponse) -> bool {
+    return find_header(response, "cache-control")
+        .unwrap_or("")
+        .split(',')
+        .any(|directive| directive.trim().eq_ignore_ascii_case("public"));
+}
+
+fn parse_http_date(date: &str) -> Option<DateTime<Utc>> {
+    return DateTime::parse_from_rfc2822(date)
+        .ok()
+        .map(|date| date.with_timezone(&Utc));
+}
+
+fn find_header<'a>(response: &'a RequestResponse, header_name: &str) -> Option<&'a str> {
+    return response.headers
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(header_name))
+        .map(|(_, value)| value.trim());
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::models::response::RequestResult;
+
+    use super::*;
+
+    const KEY: &str = "GET https://example.com/users";
+
+    fn get_response(headers: &[(&str, &str)]) -> RequestResponse {
+        RequestResponse {
+            result: Some(RequestResult::Success { status: 200 }),
+            headers: headers.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+            ..Default::default()
+        }
+    }
+
+    fn get_request(headers: &[(&str, &str)]) -> CacheRequest {
+        CacheRequest {
+            headers: headers.iter().map(|(name, value)| (name.to_string(), value.to_string())).collect(),
+            environment: None,
+        }
+    }
+
+    #[test]
+    fn vary_headers_must_match() {
+        let mut cache = ResponseCache::default();
+        let response = get_response(&[("cache-control", "max-age=60"), ("vary", "Accept-Language, Accept")]);
+
+        let storage = cache.store(KEY, &get_request(&[("Accept-Language", "fr"), ("accept", "application/json")]), &response, &None);
+        assert_eq!(storage, CacheStorage::Stored(60));
+
+        assert!(cache.lookup(KEY, &get_request(&[("accept-language", "en"), ("accept", "application/json")])).is_none());
+        assert!(cache.lookup(KEY, &get_request(&[("accept-language", "fr")])).is_none());
+        assert!(cache.lookup(KEY, &get_request(&[("accept-language", " fr "), ("ACCEPT", "application/json")])).is_some());
+        assert_eq!(cache.entries[0].hits, 1);
+    }
+
+    #[test]
+    fn vary_star_is_not_stored() {
+        let mut cache = ResponseCache::default();
+        let response = get_response(&[("cache-control", "max-age=60"), ("vary", "*")]);
+
+        assert_eq!(cache.store(KEY, &get_request(&[]), &response, &None), CacheStorage::NotStored(String::from("Vary: *")));
+        assert!(cache.entries.is_empty());
+    }
+
+    #[test]
+    fn private_response_needs_the_same_credentials() {
+        let mut cache = ResponseCache::default();
+        let response = get_response(&[("cache-control", "max-age=60")]);
+
+        cache.store(KEY, &get_request(&[("authorization", "Bearer a")]), &response, &None);
+
+        assert!(cache.lookup(KEY, &get_request(&[("authorization", "Bearer b")])).is_none());
+        assert!(cache.lookup(KEY, &get_request(&[])).is_none());
+        assert!(cache.lookup(KEY, &get_request(&[("Authorization", "Bearer a")])).is_some());
+    }
+
+    #[test]
+    fn public_response_is_shared() {
+        let mut cache = ResponseCache::default();
+        let response = get_response(&[("cache-control", "public, max-age=60")]);
+
+        cache.store(KEY, &get_request(&[("authorization", "Bearer a")]), &response, &None);
+
+        assert!(cache.lookup(KEY, &get_request(&[("authorization", "Bearer b")])).is_some());
+    }
+
+    #[test]
+    fn freshness_lifetime() {
+        let mut cache = ResponseCache::default();
+        let request = get_request(&[]);
+
+        assert_eq!(cache.store(KEY, &request, &get_response(&[("cache-control", "max-age=60"), ("age", "20")]), &None), CacheStorage::Stored(40));
+        assert_eq!(cache.store(KEY, &request, &get_response(&[("date", "Mon, 01 Jan 2024 00:00:00 GMT"), ("expires", "Mon, 01 Jan 2024 00:02:00 GMT")]), &None), CacheStorage::Stored(120));
+        assert_eq!(cache.store(KEY, &request, &get_response(&[("cache-control", "no-store, max-age=60")]), &None), CacheStorage::NotStored(String::from("no-store")));
+        assert_eq!(cache.store(KEY, &request, &get_response(&[]), &None), CacheStorage::NotStored(String::from("no max-age or Expires")));
+    }
+}
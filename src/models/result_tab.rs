@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+/// Result tab as written in the config
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultTab {
+    Body,
+    Cookies,
+    Headers,
+    Tests,
+    Console,
+    /// Pseudo-headers and HPACK representations of the exchanged fields, only shown when listed
+    Wire,
+}
+
+/// Result tab focused once the response of a request is received
+#[derive(Debug, Default, Copy, Clone, PartialEq, Serialize, Deserialize, Display, EnumIter)]
+#[serde(rename_all = "snake_case")]
+pub enum ResultTabFocus {
+    /// The tab focused when the request was sent
+    #[default]
+    Keep,
+    /// Headers for a HEAD request, Tests when the response has test results, Body otherwise
+    Auto,
+    Body,
+    Cookies,
+    Headers,
+    Tests,
+    Console,
+    Wire,
+}
+
+/// Choices of the request setting, "Config" follows the focus of the config
+pub const RESULT_TAB_FOCUS_CHOICES: [&str; 9] = ["Config", "Keep", "Auto", "Body", "Cookies", "Headers", "Tests", "Console", "Wire"];
+
+impl ResultTabFocus {
+    pub fn get_tab(&self) -> Option<ResultTab> {
+        return match self {
+            ResultTabFocus::Keep | ResultTabFocus::Auto => None,
+            ResultTabFocus::Body => Some(ResultTab::Body),
+            ResultTabFocus::Cookies => Some(ResultTab::Cookies),
+            ResultTabFocus::Headers => Some(ResultTab::Headers),
+            ResultTabFocus::Tests => Some(ResultTab::Tests),
+            ResultTabFocus::Console => Some(ResultTab::Console),
+            ResultTabFocus::Wire => Some(ResultTab::Wire)
+        };
+    }
+
+    /// None for "Config", the case is ignored
+    pub fn from_choice(choice: &str) -> Option<ResultTabFocus> {
+        return ResultTabFocus::iter().find(|focus| focus.to_string().eq_ignore_ascii_case(choice));
+    }
+
+    pub fn get_choice_name(focus: Option<ResultTabFocus>) -> &'static str {
+        let focus_name = match focus {
+            None => return RESULT_TAB_FOCUS_CHOICES[0],
+            Some(focus) => focus.to_string()
+        };
+
+        return RESULT_TAB_FOCUS_CHOICES
+            .iter()
+            .find(|choice| **choice == focus_name)
+            .unwrap_or(&RESULT_TAB_FOCUS_CHOICES[0]);
+    }
+}
@@ -0,0 +1,256 @@
+use std::time::Duration;
+
+use chrono::Utc;
+
+use crate::models::response::TestResult;
+
+/// Characters of the response bodies kept in the HTML report and the JUnit output
+const RESPONSE_EXCERPT_MAX_CHARS: usize = 2000;
+
+/// Requests of a run and their outcome, written as JUnit XML or as an HTML page
+#[derive(Debug, Clone)]
+pub struct RunReport {
+    /// e.g. the collection name
+    pub name: String,
+    /// When the run started, e.g. 2024-08-01T10:00:00
+    pub started_at: String,
+    pub entries: Vec<RunReportEntry>,
+}
+
+/// A send of a request of the run
+#[derive(Debug, Clone)]
+pub struct RunReportEntry {
+    pub request_name: String,
+    /// Dataset row the request was sent with
+    pub row_number: Option<usize>,
+    /// None when no response was received
+    pub status_code: Option<String>,
+    pub duration: Duration,
+    /// Why no response was received, or the request could not be sent
+    pub error: Option<String>,
+    /// e.g. a status not 2xx with --fail
+    pub failure: Option<String>,
+    pub tests: Vec<TestResult>,
+    pub response_excerpt: Option<String>,
+}
+
+impl RunReportEntry {
+    /// The request name, with the dataset row when there is one
+    pub fn get_name(&self) -> String {
+        match self.row_number {
+            None => self.request_name.clone(),
+            Some(row_number) => format!("{} (row {row_number})", self.request_name)
+        }
+    }
+
+    pub fn is_passed(&self) -> bool {
+        return self.error.is_none() && self.failure.is_none() && self.tests.iter().all(|test| test.passed);
+    }
+}
+
+impl RunReport {
+    pub fn new(name: String) -> RunReport {
+        RunReport {
+            name,
+            started_at: Utc::now().format("%Y-%m-%dT%H:%M:%S").to_string(),
+            entries: vec![],
+        }
+    }
+
+    /// One test suite per send, holding a "Response" test case then one test case per test of the response
+    pub fn to_junit_xml(&self) -> String {
+        let mut tests = 0;
+        let mut failures = 0;
+        let mut errors = 0;
+        let mut test_suites: Vec<String> = vec![];
+
+        for entry in &self.entries {
+            let mut test_cases: Vec<String> = vec![];
+            let class_name = escape_xml(&format!("{}.{}", self.name, entry.request_name));
+            let suite_failures = entry.failure.iter().count() + entry.tests.iter().filter(|test| !test.passed).count();
+            let suite_errors = entry.error.iter().count();
+
+            let response_case_outcome = match (&entry.error, &entry.failure) {
+                (Some(error), _) => format!("\n      <error message=\"{}\" type=\"error\"/>", escape_xml(error)),
+                (None, Some(failure)) => format!("\n      <failure message=\"{}\" type=\"status\"/>", escape_xml(failure)),
+                (None, None) => String::new()
+            };
+
+            let system_out = match &entry.response_excerpt {
+                None => String::new(),
+                Some(response_excerpt) => format!("\n      <system-out>{}</system-out>", escape_xml(response_excerpt))
+            };
+
+            test_cases.push(format!(
+                "    <testcase name=\"Response {}\" classname=\"{class_name}\" time=\"{:.3}\">{response_case_outcome}{system_out}\n    </testcase>",
+                escape_xml(entry.status_code.as_deref().unwrap_or("not received")),
+                entry.duration.as_secs_f64()
+            ));
+
+            for test in &entry.tests {
+                let test_case = match test.passed {
+                    true => format!("    <testcase name=\"{}\" classname=\"{class_name}\" time=\"0\"/>", escape_xml(&test.name)),
+                    false => format!(
+                        "    <testcase name=\"{}\" classname=\"{class_name}\" time=\"0\">\n      <failure message=\"{}\" type=\"test\">{}</failure>\n    </testcase>",
+                        escape_xml(&test.name),
+                        escape_xml(test.messages.first().map(String::as_str).unwrap_or("Failed")),
+                        escape_xml(&test.messages.join("\n"))
+                    )
+                };
+
+                test_cases.push(test_case);
+            }
+
+            tests += test_cases.len();
+            failures += suite_failures;
+            errors += suite_errors;
+
+            test_suites.push(format!(
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{suite_failures}\" errors=\"{suite_errors}\" time=\"{:.3}\" timestamp=\"{}\">\n{}\n  </testsuite>",
+                escape_xml(&entry.get_name()),
+                test_cases.len(),
+                entry.duration.as_secs_f64(),
+                self.started_at,
+                test_cases.join("\n")
+            ));
+        }
+
+        return format!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<testsuites name=\"{}\" tests=\"{tests}\" failures=\"{failures}\" errors=\"{errors}\" time=\"{:.3}\">\n{}\n</testsuites>\n",
+            escape_xml(&self.name),
+            self.get_total_duration().as_secs_f64(),
+            test_suites.join("\n")
+        );
+    }
+
+    /// Standalone page, a summary table linking to the details of each send
+    pub fn to_html(&self) -> String {
+        let passed_entries = self.entries.iter().filter(|entry| entry.is_passed()).count();
+
+        let mut rows: Vec<String> = vec![];
+        let mut details: Vec<String> = vec![];
+
+        for (index, entry) in self.entries.iter().enumerate() {
+            let (outcome_class, outcome) = match entry.is_passed() {
+                true => ("pass", "PASS"),
+                false => ("fail", "FAIL")
+            };
+
+            let passed_tests = entry.tests.iter().filter(|test| test.passed).count();
+
+            rows.push(format!(
+                "<tr><td><a href=\"#send-{index}\">{}</a></td><td class=\"{outcome_class}\">{outcome}</td><td>{}</td><td>{}</td><td>{passed_tests}/{}</td></tr>",
+                escape_xml(&entry.get_name()),
+                escape_xml(entry.status_code.as_deref().unwrap_or("-")),
+                format_duration(entry.duration),
+                entry.tests.len()
+            ));
+
+            let mut detail = format!("<h2 id=\"send-{index}\">{} <span class=\"{outcome_class}\">{outcome}</span></h2>\n", escape_xml(&entry.get_name()));
+
+            detail += &format!(
+                "<p>Status {} in {}</p>\n",
+                escape_xml(entry.status_code.as_deref().unwrap_or("not received")),
+                format_duration(entry.duration)
+            );
+
+            for message in entry.error.iter().chain(entry.failure.iter()) {
+                detail += &format!("<p class=\"fail\">{}</p>\n", escape_xml(message));
+            }
+
+            if !entry.tests.is_empty() {
+                detail += "<ul>\n";
+
+                for test in &entry.tests {
+                    let (test_class, test_outcome) = match test.passed {
+                        true => ("pass", "PASS"),
+                        false => ("fail", "FAIL")
+                    };
+
+                    detail += &format!("<li><span class=\"{test_class}\">{test_outcome}</span> {}", escape_xml(&test.name));
+
+                    for message in &test.messages {
+                        detail += &format!("<br><code>{}</code>", escape_xml(message));
+                    }
+
+                    detail += "</li>\n";
+                }
+
+                detail += "</ul>\n";
+            }
+
+            if let Some(response_excerpt) = &entry.response_excerpt {
+                detail += &format!("<pre>{}</pre>\n", escape_xml(response_excerpt));
+            }
+
+            details.push(detail);
+        }
+
+        let title = escape_xml(&format!("{} run report", self.name));
+
+        return format!(r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+<meta charset="utf-8">
+<meta name="viewport" content="width=device-width, initial-scale=1">
+<meta name="generator" content="ATAC v{}">
+<title>{title}</title>
+<style>
+body {{ max-width: 960px; margin: 2em auto; padding: 0 1em; font-family: sans-serif; line-height: 1.5; color: #222; }}
+h2 {{ margin-top: 2em; border-bottom: 1px solid #ddd; }}
+pre {{ background: #f5f5f5; padding: 1em; overflow-x: auto; }}
+code {{ font-family: monospace; }}
+table {{ border-collapse: collapse; }}
+th, td {{ border: 1px solid #ddd; padding: 0.3em 0.8em; text-align: left; }}
+.pass {{ color: #1a7f37; font-weight: bold; }}
+.fail {{ color: #cf222e; font-weight: bold; }}
+</style>
+</head>
+<body>
+<h1>{title}</h1>
+<p>Started at {} UTC, {passed_entries}/{} sends passed in {}</p>
+<table>
+<tr><th>Request</th><th>Outcome</th><th>Status</th><th>Duration</th><th>Tests passed</th></tr>
+{}
+</table>
+{}</body>
+</html>
+"#,
+            env!("CARGO_PKG_VERSION"),
+            self.started_at,
+            self.entries.len(),
+            format_duration(self.get_total_duration()),
+            rows.join("\n"),
+            details.join("")
+        );
+    }
+
+    /// Sum of the send durations, the concurrent sends overlap
+    fn get_total_duration(&self) -> Duration {
+        return self.entries.iter().map(|entry| entry.duration).sum();
+    }
+}
+
+/// The start of the body, cut on a character boundary
+pub fn get_response_excerpt(body: &str) -> String {
+    return match body.char_indices().nth(RESPONSE_EXCERPT_MAX_CHARS) {
+        None => body.to_string(),
+        Some((end, _)) => format!("{}\n[...] {} bytes in total", &body[..end], body.len())
+    };
+}
+
+fn format_duration(duration: Duration) -> String {
+    return format!("{} ms", duration.as_millis());
+}
+
+/// Also used for HTML, the control characters XML 1.0 forbids are dropped
+fn escape_xml(text: &str) -> String {
+    return text
+        .chars()
+        .filter(|char| !char.is_control() || matches!(char, '\n' | '\r' | '\t'))
+        .collect::<String>()
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;");
+}
@@ -0,0 +1,149 @@
+use std::fs;
+use std::path::Path;
+
+use jsonschema::JSONSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::models::response::{RequestResponse, ResponseContent, TestResult};
+
+#[derive(Error, Debug)]
+pub enum SchemaError {
+    #[error("Could not read schema file \"{0}\"\n\t{1}")]
+    CouldNotReadFile(String, std::io::Error),
+
+    #[error("Could not parse schema\n\t{0}")]
+    InvalidSchemaDocument(String),
+
+    #[error("Schema \"{0}\" not found in the OpenAPI document")]
+    PointerNotFound(String),
+
+    #[error("Invalid JSON Schema\n\t{0}")]
+    InvalidSchema(String),
+}
+
+/// JSON Schema the response body must match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ResponseSchema {
+    /// Schema written directly in the request, as JSON
+    Inline(String),
+
+    /// Path to a JSON or YAML schema file
+    File(String),
+
+    /// Schema found in an OpenAPI document (JSON or YAML), e.g. pointer = "#/components/schemas/User"
+    OpenApi {
+        file: String,
+        pointer: String
+    }
+}
+
+impl ResponseSchema {
+    pub fn to_summary(&self) -> String {
+        match self {
+            ResponseSchema::Inline(schema) => format!("inline {schema}"),
+            ResponseSchema::File(path) => format!("file {path}"),
+            ResponseSchema::OpenApi { file, pointer } => format!("openapi {file}{pointer}")
+        }
+    }
+
+    /// Load the schema document, OpenAPI schemas are referenced from the whole document so that their own references can be resolved
+    pub fn load(&self) -> Result<Value, SchemaError> {
+        match self {
+            ResponseSchema::Inline(schema) => parse_schema_document(schema, false),
+            ResponseSchema::File(path) => read_schema_file(path),
+            ResponseSchema::OpenApi { file, pointer } => {
+                let mut document = read_schema_file(file)?;
+                let json_pointer = pointer.trim_start_matches('#');
+
+                if document.pointer(json_pointer).is_none() {
+                    return Err(SchemaError::PointerNotFound(pointer.clone()));
+                }
+
+                match &mut document {
+                    Value::Object(object) => {
+                        object.insert(String::from("$ref"), Value::String(format!("#{json_pointer}")));
+                    },
+                    _ => return Err(SchemaError::InvalidSchemaDocument(String::from("OpenAPI document is not an object")))
+                }
+
+                Ok(document)
+            }
+        }
+    }
+
+    pub fn compile(&self) -> Result<JSONSchema, SchemaError> {
+        let schema = self.load()?;
+
+        match JSONSchema::compile(&schema) {
+            Ok(schema) => Ok(schema),
+            Err(error) => Err(SchemaError::InvalidSchema(error.to_string()))
+        }
+    }
+
+    /// Validate the response body against the schema, every violation is reported in the test result
+    pub fn validate_response(&self, response: &RequestResponse) -> TestResult {
+        let test_name = String::from("Response body matches schema");
+
+        let schema = match self.compile() {
+            Ok(schema) => schema,
+            Err(error) => return TestResult::failed(test_name, vec![error.to_string()])
+        };
+
+        let body = match &response.content {
+            Some(ResponseContent::Body(body)) => body,
+            _ => return TestResult::failed(test_name, vec![String::from("No response body")])
+        };
+
+        let instance: Value = match serde_json::from_str(body) {
+            Ok(instance) => instance,
+            Err(error) => return TestResult::failed(test_name, vec![format!("Response body is not valid JSON: {error}")])
+        };
+
+        let violations: Vec<String> = match schema.validate(&instance) {
+            Ok(_) => vec![],
+            Err(errors) => errors
+                .map(|error| {
+                    let instance_path = error.instance_path.to_string();
+
+                    match instance_path.is_empty() {
+                        true => error.to_string(),
+                        false => format!("{instance_path}: {error}")
+                    }
+                })
+                .collect()
+        };
+
+        match violations.is_empty() {
+            true => TestResult::passed(test_name),
+            false => TestResult::failed(test_name, violations)
+        }
+    }
+}
+
+fn read_schema_file(path: &str) -> Result<Value, SchemaError> {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(error) => return Err(SchemaError::CouldNotReadFile(path.to_string(), error))
+    };
+
+    let is_yaml = Path::new(path)
+        .extension()
+        .is_some_and(|extension| extension == "yaml" || extension == "yml");
+
+    return parse_schema_document(&content, is_yaml);
+}
+
+fn parse_schema_document(content: &str, is_yaml: bool) -> Result<Value, SchemaError> {
+    let result = match is_yaml {
+        true => serde_yaml::from_str::<Value>(content).map_err(|error| error.to_string()),
+        false => serde_json::from_str::<Value>(content).map_err(|error| error.to_string())
+    };
+
+    match result {
+        Ok(document) => Ok(document),
+        Err(error) => Err(SchemaError::InvalidSchemaDocument(error))
+    }
+}
@@ -0,0 +1,11 @@
+use serde::{Deserialize, Serialize};
+
+/// Snippet pinned to the scratchpad of the workspace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScratchpadEntry {
+    /// Local time
+    pub pinned_at: String,
+    /// Where the text comes from, e.g. "my_collection/my_request headers" or "note"
+    pub source: String,
+    pub text: String,
+}
@@ -6,10 +6,16 @@ use strum::Display;
 pub struct RequestScripts {
     pub pre_request_script: Option<String>,
     pub post_request_script: Option<String>,
+
+    /// Shell command the response body is piped through before being displayed, e.g. to decrypt it
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_processor: Option<String>,
 }
 
 #[derive(ValueEnum, Debug, Clone, Display)]
 pub enum ScriptType {
     Pre,
-    Post
+    Post,
+    /// Response processor command
+    Processor
 }
\ No newline at end of file
@@ -1,5 +1,14 @@
+use std::fmt::{Display, Formatter};
+
 use serde::{Deserialize, Serialize};
 
+use crate::models::charset::{find_charset, RESPONSE_CHARSETS};
+use crate::models::connection_pool::ConnectionPoolOptions;
+use crate::models::http2::Http2Options;
+use crate::models::ip_version::{IpVersion, IP_VERSION_CHOICES};
+use crate::models::result_tab::{ResultTabFocus, RESULT_TAB_FOCUS_CHOICES};
+use crate::models::tls_version::{TlsVersion, TLS_VERSION_CHOICES};
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RequestSettings {
     pub use_config_proxy: bool,
@@ -7,7 +16,115 @@ pub struct RequestSettings {
     pub store_received_cookies: bool,
     pub pretty_print_response_content: bool,
     pub accept_invalid_certs: bool,
-    pub accept_invalid_hostnames: bool
+    pub accept_invalid_hostnames: bool,
+
+    #[serde(default)]
+    pub notify_on_completion: bool,
+
+    /// Redirects followed before returning the last redirect response
+    #[serde(default = "default_max_redirects")]
+    pub max_redirects: usize,
+
+    /// Re-send the body when following a 307 or a 308, otherwise the redirect response is returned
+    #[serde(default = "default_true")]
+    pub resend_body_on_redirect: bool,
+
+    /// Remove the Authorization header when a redirect leads to another host
+    #[serde(default = "default_true")]
+    pub strip_authorization_on_redirect: bool,
+
+    /// Response body kept in memory, in MiB, the rest is dropped. 0 keeps the whole body
+    #[serde(default = "default_max_response_size")]
+    pub max_response_size: usize,
+
+    /// Charset used to decode the response body instead of the detected one
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub response_charset: Option<String>,
+
+    /// Result tab focused once the response is received, the config one is used when none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_tab_after_response: Option<ResultTabFocus>,
+
+    /// Send the validators of the last response with each request, the server answers 304 when nothing changed
+    #[serde(default)]
+    pub auto_revalidate: bool,
+
+    /// Delay added before sending the request and each of its redirects, in ms
+    #[serde(default)]
+    pub simulated_latency: usize,
+
+    /// Upload bandwidth, in KiB/s. 0 does not limit it
+    #[serde(default)]
+    pub upload_limit: usize,
+
+    /// Download bandwidth, in KiB/s. 0 does not limit it
+    #[serde(default)]
+    pub download_limit: usize,
+
+    /// Interval the watched request is re-sent at, in seconds. 0 only re-sends it when its body files change
+    #[serde(default = "default_watch_interval")]
+    pub watch_interval: usize,
+
+    /// Sending the request while it is pending queues a new send, instead of canceling it or doing nothing
+    #[serde(default)]
+    pub queue_sends: bool,
+
+    /// Only adds the "Expect: 100-continue" header to the bodies of at least 1 MiB and the files, the body is sent without waiting for the 100 Continue
+    #[serde(default, alias = "expect_continue")]
+    pub expect_continue_header: bool,
+
+    /// The text body is the whole request, its bytes are written as is to a TCP or TLS connection to the host of the URL
+    #[serde(default)]
+    pub raw_socket: bool,
+
+    /// Connection pool options overriding the ones of the config
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub connection_pool: Option<ConnectionPoolOptions>,
+
+    /// Address family the host names resolve to, e.g. to check each side of a dual-stack service
+    #[serde(default)]
+    pub ip_version: IpVersion,
+
+    /// Local IP address the outgoing connections are bound to, or a network interface name on Linux
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub local_address: Option<String>,
+
+    /// HTTP/2 options overriding the ones of the config
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http2: Option<Http2Options>,
+
+    /// Oldest TLS version negotiated, the config one is used when none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_tls_version: Option<TlsVersion>,
+
+    /// Newest TLS version negotiated, the config one is used when none
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_tls_version: Option<TlsVersion>,
+}
+
+/// Latencies offered by the settings popup, in ms
+pub const LATENCY_STEPS: [usize; 10] = [0, 50, 100, 200, 500, 1000, 2000, 5000, 10000, 30000];
+
+/// Bandwidths offered by the settings popup, in KiB/s
+pub const BANDWIDTH_STEPS: [usize; 11] = [0, 8, 16, 32, 64, 128, 256, 512, 1024, 4096, 10240];
+
+/// Watch intervals offered by the settings popup, in seconds
+pub const WATCH_INTERVAL_STEPS: [usize; 9] = [0, 1, 2, 5, 10, 30, 60, 300, 600];
+
+fn default_max_redirects() -> usize {
+    return 10;
+}
+
+fn default_max_response_size() -> usize {
+    return 10;
+}
+
+fn default_watch_interval() -> usize {
+    return 5;
+}
+
+fn default_true() -> bool {
+    return true;
 }
 
 impl Default for RequestSettings {
@@ -19,31 +136,148 @@ impl Default for RequestSettings {
             pretty_print_response_content: true,
             accept_invalid_certs: false,
             accept_invalid_hostnames: false,
+            notify_on_completion: false,
+            max_redirects: default_max_redirects(),
+            resend_body_on_redirect: true,
+            strip_authorization_on_redirect: true,
+            max_response_size: default_max_response_size(),
+            response_charset: None,
+            result_tab_after_response: None,
+            auto_revalidate: false,
+            simulated_latency: 0,
+            upload_limit: 0,
+            download_limit: 0,
+            watch_interval: default_watch_interval(),
+            queue_sends: false,
+            expect_continue_header: false,
+            raw_socket: false,
+            connection_pool: None,
+            ip_version: IpVersion::Any,
+            local_address: None,
+            http2: None,
+            min_tls_version: None,
+            max_tls_version: None,
         }
     }
 }
 
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum SettingValue {
+    Bool(bool),
+    Number(usize),
+    /// Selected value among the choices
+    Choice(&'static str, &'static [&'static str]),
+    /// Number changed through the steps, any other number can still be set from the CLI
+    Steps(usize, &'static [usize]),
+}
+
+impl Display for SettingValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SettingValue::Bool(value) => write!(f, "{value}"),
+            SettingValue::Number(value) => write!(f, "{value}"),
+            SettingValue::Choice(value, _) => write!(f, "{value}"),
+            SettingValue::Steps(value, _) => write!(f, "{value}")
+        }
+    }
+}
+
+impl SettingValue {
+    /// Cycle through the choices, the step being -1 or 1
+    pub fn cycle_choice(value: &'static str, choices: &'static [&'static str], step: isize) -> SettingValue {
+        let index = choices.iter().position(|choice| *choice == value).unwrap_or(0) as isize;
+        let next_index = (index + step).rem_euclid(choices.len() as isize) as usize;
+
+        return SettingValue::Choice(choices[next_index], choices);
+    }
+
+    /// Move to the closest step below or above the value, the direction being -1 or 1
+    pub fn move_step(value: usize, steps: &'static [usize], direction: isize) -> SettingValue {
+        let next_value = match direction < 0 {
+            true => steps.iter().rev().find(|step| **step < value).copied().unwrap_or(value),
+            false => steps.iter().find(|step| **step > value).copied().unwrap_or(value)
+        };
+
+        return SettingValue::Steps(next_value, steps);
+    }
+}
+
 impl RequestSettings {
-    pub fn to_vec(&self) -> Vec<(String, bool)> {
+    /// Name of the charset override, "Auto" when the charset is detected
+    pub fn get_response_charset_name(&self) -> &'static str {
+        return self.response_charset
+            .as_deref()
+            .and_then(find_charset)
+            .unwrap_or(RESPONSE_CHARSETS[0]);
+    }
+
+    /// None when the whole body is kept
+    pub fn get_max_response_bytes(&self) -> Option<usize> {
+        return match self.max_response_size {
+            0 => None,
+            max_response_size => Some(max_response_size * 1024 * 1024)
+        };
+    }
+
+    pub fn to_vec(&self) -> Vec<(String, SettingValue)> {
         vec![
-            (String::from("Use config proxy"), self.use_config_proxy),
-            (String::from("Allow redirects"), self.allow_redirects),
-            (String::from("Store received cookies"), self.store_received_cookies),
-            (String::from("Pretty print response content"), self.pretty_print_response_content),
-            (String::from("Accept invalid certs"), self.accept_invalid_certs),
-            (String::from("Accept invalid hostnames"), self.accept_invalid_hostnames),
+            (String::from("Use config proxy"), SettingValue::Bool(self.use_config_proxy)),
+            (String::from("Allow redirects"), SettingValue::Bool(self.allow_redirects)),
+            (String::from("Max redirects"), SettingValue::Number(self.max_redirects)),
+            (String::from("Re-send body on 307/308"), SettingValue::Bool(self.resend_body_on_redirect)),
+            (String::from("Strip auth on cross-origin redirect"), SettingValue::Bool(self.strip_authorization_on_redirect)),
+            (String::from("Store received cookies"), SettingValue::Bool(self.store_received_cookies)),
+            (String::from("Pretty print response content"), SettingValue::Bool(self.pretty_print_response_content)),
+            (String::from("Accept invalid certs"), SettingValue::Bool(self.accept_invalid_certs)),
+            (String::from("Accept invalid hostnames"), SettingValue::Bool(self.accept_invalid_hostnames)),
+            (String::from("Notify on completion"), SettingValue::Bool(self.notify_on_completion)),
+            (String::from("Max response size (MiB)"), SettingValue::Number(self.max_response_size)),
+            (String::from("Response charset"), SettingValue::Choice(self.get_response_charset_name(), &RESPONSE_CHARSETS)),
+            (String::from("Result tab after response"), SettingValue::Choice(ResultTabFocus::get_choice_name(self.result_tab_after_response), &RESULT_TAB_FOCUS_CHOICES)),
+            (String::from("Auto revalidate"), SettingValue::Bool(self.auto_revalidate)),
+            (String::from("Simulated latency (ms)"), SettingValue::Steps(self.simulated_latency, &LATENCY_STEPS)),
+            (String::from("Upload limit (KiB/s)"), SettingValue::Steps(self.upload_limit, &BANDWIDTH_STEPS)),
+            (String::from("Download limit (KiB/s)"), SettingValue::Steps(self.download_limit, &BANDWIDTH_STEPS)),
+            (String::from("Watch interval (s)"), SettingValue::Steps(self.watch_interval, &WATCH_INTERVAL_STEPS)),
+            (String::from("Queue sends while pending"), SettingValue::Bool(self.queue_sends)),
+            (String::from("Expect 100-continue header"), SettingValue::Bool(self.expect_continue_header)),
+            (String::from("Raw socket"), SettingValue::Bool(self.raw_socket)),
+            (String::from("IP version"), SettingValue::Choice(self.ip_version.get_choice_name(), &IP_VERSION_CHOICES)),
+            (String::from("Min TLS version"), SettingValue::Choice(TlsVersion::get_choice_name(self.min_tls_version), &TLS_VERSION_CHOICES)),
+            (String::from("Max TLS version"), SettingValue::Choice(TlsVersion::get_choice_name(self.max_tls_version), &TLS_VERSION_CHOICES)),
         ]
     }
 
-    pub fn update_from_vec(&mut self, vec: &Vec<(String, bool)>) {
+    pub fn update_from_vec(&mut self, vec: &Vec<(String, SettingValue)>) {
         for (setting_name, setting_value) in vec {
-            match setting_name.as_str() {
-                "Use config proxy" => self.use_config_proxy = *setting_value,
-                "Allow redirects" => self.allow_redirects = *setting_value,
-                "Store received cookies" => self.store_received_cookies = *setting_value,
-                "Pretty print response content" => self.pretty_print_response_content = *setting_value,
-                "Accept invalid certs" => self.accept_invalid_certs = *setting_value,
-                "Accept invalid hostnames" => self.accept_invalid_hostnames = *setting_value,
+            match (setting_name.as_str(), *setting_value) {
+                ("Use config proxy", SettingValue::Bool(value)) => self.use_config_proxy = value,
+                ("Allow redirects", SettingValue::Bool(value)) => self.allow_redirects = value,
+                ("Max redirects", SettingValue::Number(value)) => self.max_redirects = value,
+                ("Re-send body on 307/308", SettingValue::Bool(value)) => self.resend_body_on_redirect = value,
+                ("Strip auth on cross-origin redirect", SettingValue::Bool(value)) => self.strip_authorization_on_redirect = value,
+                ("Store received cookies", SettingValue::Bool(value)) => self.store_received_cookies = value,
+                ("Pretty print response content", SettingValue::Bool(value)) => self.pretty_print_response_content = value,
+                ("Accept invalid certs", SettingValue::Bool(value)) => self.accept_invalid_certs = value,
+                ("Accept invalid hostnames", SettingValue::Bool(value)) => self.accept_invalid_hostnames = value,
+                ("Notify on completion", SettingValue::Bool(value)) => self.notify_on_completion = value,
+                ("Max response size (MiB)", SettingValue::Number(value)) => self.max_response_size = value,
+                ("Response charset", SettingValue::Choice(value, _)) => self.response_charset = match value == RESPONSE_CHARSETS[0] {
+                    true => None,
+                    false => Some(value.to_string())
+                },
+                ("Result tab after response", SettingValue::Choice(value, _)) => self.result_tab_after_response = ResultTabFocus::from_choice(value),
+                ("Auto revalidate", SettingValue::Bool(value)) => self.auto_revalidate = value,
+                ("Simulated latency (ms)", SettingValue::Steps(value, _)) => self.simulated_latency = value,
+                ("Upload limit (KiB/s)", SettingValue::Steps(value, _)) => self.upload_limit = value,
+                ("Download limit (KiB/s)", SettingValue::Steps(value, _)) => self.download_limit = value,
+                ("Watch interval (s)", SettingValue::Steps(value, _)) => self.watch_interval = value,
+                ("Queue sends while pending", SettingValue::Bool(value)) => self.queue_sends = value,
+                ("Expect 100-continue header", SettingValue::Bool(value)) => self.expect_continue_header = value,
+                ("Raw socket", SettingValue::Bool(value)) => self.raw_socket = value,
+                ("IP version", SettingValue::Choice(value, _)) => self.ip_version = IpVersion::from_choice(value).unwrap_or_default(),
+                ("Min TLS version", SettingValue::Choice(value, _)) => self.min_tls_version = TlsVersion::from_choice(value),
+                ("Max TLS version", SettingValue::Choice(value, _)) => self.max_tls_version = TlsVersion::from_choice(value),
 
                 _ => {}
             }
@@ -0,0 +1,209 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::models::response::{BodyText, RequestResponse, RequestResult, ResponseContent, TestResult};
+
+/// Maximum number of differences listed in a failed snapshot test
+const MAX_SNAPSHOT_DIFFERENCES: usize = 20;
+
+/// Canonical response body the next responses are compared to, also answered in offline mode
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ResponseSnapshot {
+    #[serde(default)]
+    pub body: Option<String>,
+
+    /// Status code of the recorded response, e.g. "200 OK"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_code: Option<String>,
+
+    /// Headers of the recorded response, answered with the body in offline mode
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub headers: Vec<(String, String)>,
+
+    /// Volatile JSON fields left out of the comparison.
+    /// Either a key name matched at any depth (e.g. "updated_at") or a path where "*" matches any key or index (e.g. "/items/*/id")
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub ignore: Vec<String>,
+}
+
+impl ResponseSnapshot {
+    /// Store the response body, JSON bodies are stored pretty-printed so that the collection file diffs nicely
+    pub fn record(&mut self, status_code: &str, headers: &[(String, String)], body: &str) {
+        let body = match serde_json::from_str::<Value>(body) {
+            Ok(json) => serde_json::to_string_pretty(&json).unwrap_or(body.to_string()),
+            Err(_) => body.to_string()
+        };
+
+        self.body = Some(body);
+        self.status_code = Some(status_code.to_string());
+        self.headers = headers.to_vec();
+    }
+
+    /// Response answered instead of sending the request, None when nothing has been recorded yet
+    pub fn to_offline_response(&self) -> Option<RequestResponse> {
+        let body = self.body.as_ref()?;
+
+        Some(RequestResponse {
+            duration: None,
+            result: Some(RequestResult::Success { status: self.get_status_code() }),
+            content: Some(ResponseContent::Body(BodyText::Owned(body.clone()))),
+            raw_body: None,
+            cookies: None,
+            headers: self.headers.clone(),
+            charset: None,
+            tests: vec![],
+            cache_status: None,
+            is_offline: true,
+            processed_body: None,
+            soap_fault: None,
+            dns_resolution: None,
+            wire: None,
+            trailers: vec![],
+        })
+    }
+
+    /// e.g. 200 for "200 OK", the snapshots recorded before the status code was stored are answered with a 200
+    fn get_status_code(&self) -> u16 {
+        return self.status_code
+            .as_ref()
+            .and_then(|status_code| status_code.split_whitespace().next())
+            .and_then(|status_code| status_code.parse::<u16>().ok())
+            .unwrap_or(200);
+    }
+
+    /// Compare the response body to the recorded one, returns None when nothing has been recorded yet
+    pub fn compare(&self, response: &RequestResponse) -> Option<TestResult> {
+        let expected_body = self.body.as_ref()?;
+        let test_name = String::from("Response body matches snapshot");
+
+        let body = match &response.content {
+            Some(ResponseContent::Body(body)) => body,
+            _ => return Some(TestResult::failed(test_name, vec![String::from("No response body")]))
+        };
+
+        let mut differences: Vec<String> = vec![];
+
+        match (serde_json::from_str::<Value>(expected_body), serde_json::from_str::<Value>(body)) {
+            (Ok(mut expected), Ok(mut actual)) => {
+                remove_ignored_fields(&mut expected, &mut vec![], &self.ignore);
+                remove_ignored_fields(&mut actual, &mut vec![], &self.ignore);
+
+                diff_json("", &expected, &actual, &mut differences);
+            },
+            _ => diff_text(expected_body, body, &mut differences)
+        }
+
+        if differences.is_empty() {
+            return Some(TestResult::passed(test_name));
+        }
+
+        let difference_count = differences.len();
+
+        if difference_count > MAX_SNAPSHOT_DIFFERENCES {
+            differences.truncate(MAX_SNAPSHOT_DIFFERENCES);
+            differences.push(format!("... and {} more", difference_count - MAX_SNAPSHOT_DIFFERENCES));
+        }
+
+        Some(TestResult::failed(test_name, differences))
+    }
+}
+
+fn is_ignored(path: &[String], ignore_rules: &[String]) -> bool {
+    ignore_rules.iter().any(|rule| match rule.strip_prefix('/') {
+        // Path rule
+        Some(rule_path) => {
+            let rule_segments: Vec<&str> = rule_path.split('/').collect();
+
+            rule_segments.len() == path.len() && rule_segments
+                .iter()
+                .zip(path)
+                .all(|(rule_segment, segment)| *rule_segment == "*" || rule_segment == segment)
+        },
+        // Key rule
+        None => path.last().is_some_and(|key| key == rule)
+    })
+}
+
+fn remove_ignored_fields(value: &mut Value, path: &mut Vec<String>, ignore_rules: &[String]) {
+    if ignore_rules.is_empty() {
+        return;
+    }
+
+    match value {
+        Value::Object(object) => {
+            object.retain(|key, _| {
+                path.push(key.clone());
+                let is_kept = !is_ignored(path, ignore_rules);
+                path.pop();
+
+                is_kept
+            });
+
+            for (key, child) in object.iter_mut() {
+                path.push(key.clone());
+                remove_ignored_fields(child, path, ignore_rules);
+                path.pop();
+            }
+        },
+        Value::Array(array) => for (index, child) in array.iter_mut().enumerate() {
+            path.push(index.to_string());
+            remove_ignored_fields(child, path, ignore_rules);
+            path.pop();
+        },
+        _ => {}
+    }
+}
+
+fn diff_json(path: &str, expected: &Value, actual: &Value, differences: &mut Vec<String>) {
+    match (expected, actual) {
+        (Value::Object(expected_object), Value::Object(actual_object)) => {
+            for (key, expected_value) in expected_object {
+                let child_path = format!("{path}/{key}");
+
+                match actual_object.get(key) {
+                    None => differences.push(format!("{child_path}: missing")),
+                    Some(actual_value) => diff_json(&child_path, expected_value, actual_value, differences)
+                }
+            }
+
+            for key in actual_object.keys() {
+                if !expected_object.contains_key(key) {
+                    differences.push(format!("{path}/{key}: unexpected field"));
+                }
+            }
+        },
+        (Value::Array(expected_array), Value::Array(actual_array)) => {
+            if expected_array.len() != actual_array.len() {
+                differences.push(format!("{}: expected {} elements, got {}", display_path(path), expected_array.len(), actual_array.len()));
+            }
+
+            for (index, (expected_value, actual_value)) in expected_array.iter().zip(actual_array).enumerate() {
+                diff_json(&format!("{path}/{index}"), expected_value, actual_value, differences);
+            }
+        },
+        _ => if expected != actual {
+            differences.push(format!("{}: expected {expected}, got {actual}", display_path(path)));
+        }
+    }
+}
+
+fn diff_text(expected: &str, actual: &str, differences: &mut Vec<String>) {
+    let expected_lines: Vec<&str> = expected.lines().collect();
+    let actual_lines: Vec<&str> = actual.lines().collect();
+
+    for index in 0..usize::max(expected_lines.len(), actual_lines.len()) {
+        let expected_line = expected_lines.get(index);
+        let actual_line = actual_lines.get(index);
+
+        if expected_line != actual_line {
+            differences.push(format!("line {}: expected {:?}, got {:?}", index + 1, expected_line.unwrap_or(&""), actual_line.unwrap_or(&"")));
+        }
+    }
+}
+
+fn display_path(path: &str) -> &str {
+    match path.is_empty() {
+        true => "/",
+        false => path
+    }
+}
@@ -0,0 +1,215 @@
+use std::ops::Range;
+
+use clap::ValueEnum;
+use quick_xml::events::Event;
+use quick_xml::{Reader, Writer};
+use serde::{Deserialize, Serialize};
+use strum::Display;
+
+pub const SOAP_11_ENVELOPE_NAMESPACE: &str = "http://schemas.xmlsoap.org/soap/envelope/";
+pub const SOAP_12_ENVELOPE_NAMESPACE: &str = "http://www.w3.org/2003/05/soap-envelope";
+
+#[derive(Default, Debug, Clone, Copy, PartialEq, ValueEnum, Display, Serialize, Deserialize)]
+pub enum SoapVersion {
+    #[default]
+    #[value(name = "1.1")]
+    #[serde(rename = "1.1")]
+    #[strum(to_string = "SOAP 1.1")]
+    Soap11,
+
+    #[value(name = "1.2")]
+    #[serde(rename = "1.2")]
+    #[strum(to_string = "SOAP 1.2")]
+    Soap12,
+}
+
+impl SoapVersion {
+    pub fn envelope_namespace(&self) -> &'static str {
+        match self {
+            SoapVersion::Soap11 => SOAP_11_ENVELOPE_NAMESPACE,
+            SoapVersion::Soap12 => SOAP_12_ENVELOPE_NAMESPACE
+        }
+    }
+}
+
+/// Sends the body as the payload of a SOAP envelope
+#[derive(Default, Debug, Clone, Serialize, Deserialize)]
+pub struct RequestSoap {
+    pub version: SoapVersion,
+
+    /// Sent in the SOAPAction header with SOAP 1.1, in the action parameter of the content type with SOAP 1.2
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+
+    /// XML put in the header of the envelope, e.g. a WS-Security element
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub envelope_header: Option<String>,
+}
+
+impl RequestSoap {
+    pub fn to_summary(&self) -> String {
+        let mut summary = self.version.to_string();
+
+        if let Some(action) = &self.action {
+            summary += &format!(", action \"{action}\"");
+        }
+
+        if self.envelope_header.is_some() {
+            summary += ", with an envelope header";
+        }
+
+        return summary;
+    }
+
+    pub fn get_content_type(&self) -> String {
+        match (self.version, &self.action) {
+            (SoapVersion::Soap11, _) => String::from("text/xml; charset=utf-8"),
+            (SoapVersion::Soap12, None) => String::from("application/soap+xml; charset=utf-8"),
+            (SoapVersion::Soap12, Some(action)) => format!("application/soap+xml; charset=utf-8; action=\"{action}\"")
+        }
+    }
+
+    /// SOAP 1.2 gives the action in the content type instead
+    pub fn get_soap_action_header(&self) -> Option<String> {
+        match self.version {
+            SoapVersion::Soap11 => Some(format!("\"{}\"", self.action.as_deref().unwrap_or_default())),
+            SoapVersion::Soap12 => None
+        }
+    }
+
+    /// The body is sent as it is when it already is a whole envelope
+    pub fn wrap_in_envelope(&self, body: &str) -> String {
+        if get_root_element_name(body).is_some_and(|root_name| root_name == "Envelope") {
+            return body.to_string();
+        }
+
+        let header = match &self.envelope_header {
+            None => String::new(),
+            Some(envelope_header) => format!("    <soap:Header>\n{}\n    </soap:Header>\n", indent_xml(envelope_header, 8))
+        };
+
+        return format!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n<soap:Envelope xmlns:soap=\"{}\">\n{header}    <soap:Body>\n{}\n    </soap:Body>\n</soap:Envelope>",
+            self.version.envelope_namespace(),
+            indent_xml(body, 8)
+        );
+    }
+}
+
+/// Error answered in the body of a SOAP response
+#[derive(Debug, Clone)]
+pub struct SoapFault {
+    pub code: String,
+    pub reason: String,
+    /// Lines of the body taken by the fault element, highlighted in the TUI
+    pub lines: Range<usize>,
+}
+
+impl SoapFault {
+    pub fn to_message(&self) -> String {
+        match (self.code.is_empty(), self.reason.is_empty()) {
+            (true, true) => String::from("Fault without code nor reason"),
+            (false, true) => self.code.clone(),
+            (true, false) => self.reason.clone(),
+            (false, false) => format!("{}: {}", self.code, self.reason)
+        }
+    }
+}
+
+/// Looks for the fault element of a SOAP 1.1 (faultcode, faultstring) or SOAP 1.2 (Code/Value, Reason/Text) response
+pub fn find_soap_fault(body: &str) -> Option<SoapFault> {
+    let mut reader = Reader::from_str(body);
+    let mut path: Vec<String> = vec![];
+    let mut fault_depth: Option<usize> = None;
+    let mut fault_start = 0;
+    let mut code = String::new();
+    let mut reason = String::new();
+
+    loop {
+        let position = reader.buffer_position();
+
+        match reader.read_event() {
+            Ok(Event::Start(element)) => {
+                let name = String::from_utf8_lossy(element.local_name().as_ref()).to_string();
+
+                if fault_depth.is_none() && name == "Fault" && path.iter().any(|parent| parent == "Body") {
+                    fault_depth = Some(path.len());
+                    fault_start = position;
+                }
+
+                path.push(name);
+            },
+            Ok(Event::End(_)) => {
+                path.pop();
+
+                if fault_depth == Some(path.len()) {
+                    let fault_end = reader.buffer_position();
+
+                    return Some(SoapFault {
+                        code: code.trim().to_string(),
+                        reason: reason.trim().to_string(),
+                        lines: get_line_index(body, fault_start)..get_line_index(body, fault_end) + 1,
+                    });
+                }
+            },
+            Ok(Event::Text(text)) if fault_depth.is_some() => {
+                let text = text.unescape().unwrap_or_default();
+                let parents: Vec<&str> = path.iter().rev().take(2).map(String::as_str).collect();
+
+                match parents.as_slice() {
+                    ["faultcode", ..] | ["Value", "Code", ..] if code.is_empty() => code = text.to_string(),
+                    ["faultstring", ..] | ["Text", "Reason", ..] if reason.is_empty() => reason = text.to_string(),
+                    _ => {}
+                }
+            },
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+/// Indent the elements of an XML document, None when it is not well-formed
+pub fn pretty_print_xml(xml: &str) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+    reader.config_mut().trim_text(true);
+
+    let mut writer = Writer::new_with_indent(Vec::new(), b' ', 4);
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Eof) => break,
+            Ok(event) => writer.write_event(event).ok()?,
+            Err(_) => return None
+        }
+    }
+
+    return String::from_utf8(writer.into_inner()).ok();
+}
+
+/// Local name of the first element of the document
+pub fn get_root_element_name(xml: &str) -> Option<String> {
+    let mut reader = Reader::from_str(xml);
+
+    loop {
+        match reader.read_event() {
+            Ok(Event::Start(element)) | Ok(Event::Empty(element)) => return Some(String::from_utf8_lossy(element.local_name().as_ref()).to_string()),
+            Ok(Event::Eof) | Err(_) => return None,
+            _ => {}
+        }
+    }
+}
+
+fn indent_xml(xml: &str, indentation: usize) -> String {
+    return xml
+        .trim()
+        .lines()
+        .map(|line| format!("{}{line}", " ".repeat(indentation)))
+        .collect::<Vec<String>>()
+        .join("\n");
+}
+
+fn get_line_index(text: &str, byte_position: usize) -> usize {
+    let byte_position = byte_position.min(text.len());
+
+    return text.as_bytes()[..byte_position].iter().filter(|byte| **byte == b'\n').count();
+}
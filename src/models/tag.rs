@@ -0,0 +1,36 @@
+/// Tags separated by commas or spaces, e.g. "smoke, #auth", lowercased and without duplicates
+pub fn parse_tags(text: &str) -> Vec<String> {
+    let mut tags: Vec<String> = vec![];
+
+    for tag in text.split(|char: char| char == ',' || char.is_whitespace()) {
+        let tag = tag.trim_start_matches('#').to_lowercase();
+
+        if !tag.is_empty() && !tags.contains(&tag) {
+            tags.push(tag);
+        }
+    }
+
+    return tags;
+}
+
+/// The tags of the element, followed by the new ones it does not have yet
+pub fn add_tags(tags: &[String], new_tags: &[String]) -> Vec<String> {
+    return parse_tags(&tags.iter().chain(new_tags).cloned().collect::<Vec<String>>().join(","));
+}
+
+pub fn remove_tags(tags: &[String], removed_tags: &[String]) -> Vec<String> {
+    let removed_tags = parse_tags(&removed_tags.join(","));
+
+    return tags
+        .iter()
+        .filter(|tag| !removed_tags.contains(&tag.to_lowercase()))
+        .cloned()
+        .collect();
+}
+
+/// Whether one of the element tags is one of the filter tags, the tags are compared case-insensitively
+pub fn has_any_tag<'a>(element_tags: impl IntoIterator<Item = &'a String>, filter_tags: &[String]) -> bool {
+    return element_tags
+        .into_iter()
+        .any(|tag| filter_tags.iter().any(|filter_tag| filter_tag.eq_ignore_ascii_case(tag)));
+}
@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::models::auth::Auth;
+use crate::models::body::ContentType;
+use crate::models::method::Method;
+use crate::models::request::{KeyValue, Request, DEFAULT_HEADERS};
+use crate::models::scripts::RequestScripts;
+use crate::models::settings::RequestSettings;
+
+/// Skeleton new requests can be created from, stored in the "templates" folder of the app directory.
+/// Every field is optional, the headers default to the base headers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RequestTemplate {
+    /// File name without its extension
+    #[serde(skip)]
+    pub name: String,
+
+    #[serde(default)]
+    pub url: String,
+
+    #[serde(default)]
+    pub method: Method,
+
+    #[serde(default)]
+    pub params: Vec<KeyValue>,
+
+    #[serde(default = "default_template_headers")]
+    pub headers: Vec<KeyValue>,
+
+    #[serde(default)]
+    pub body: ContentType,
+
+    #[serde(default)]
+    pub auth: Auth,
+
+    #[serde(default)]
+    pub scripts: RequestScripts,
+
+    #[serde(default)]
+    pub settings: RequestSettings,
+}
+
+fn default_template_headers() -> Vec<KeyValue> {
+    return DEFAULT_HEADERS.clone();
+}
+
+impl RequestTemplate {
+    pub fn from_request(template_name: String, request: &Request) -> RequestTemplate {
+        RequestTemplate {
+            name: template_name,
+            url: request.url.clone(),
+            method: request.method,
+            params: request.params.clone(),
+            headers: request.headers.clone(),
+            body: request.body.clone(),
+            auth: request.auth.clone(),
+            scripts: request.scripts.clone(),
+            settings: request.settings.clone(),
+        }
+    }
+
+    pub fn to_request(&self, request_name: String) -> Request {
+        Request {
+            name: request_name,
+            url: self.url.clone(),
+            method: self.method,
+            params: self.params.clone(),
+            headers: self.headers.clone(),
+            body: self.body.clone(),
+            auth: self.auth.clone(),
+            scripts: self.scripts.clone(),
+            settings: self.settings.clone(),
+            ..Default::default()
+        }
+    }
+}
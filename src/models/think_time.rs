@@ -0,0 +1,66 @@
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+use ring::rand::{SecureRandom, SystemRandom};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ThinkTimeError {
+    #[error("The maximum think time {1} ms is shorter than the minimum {0} ms")]
+    MaxBelowMin(u64, u64),
+}
+
+/// Pause of the runs after a request, before sending the next one, e.g. to mimic a user reading the page.
+/// Set per request or for the whole collection, the request one prevails
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ThinkTime {
+    pub min_ms: u64,
+
+    /// A random duration between the minimum and the maximum is drawn for each pause when given, the minimum is used otherwise
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_ms: Option<u64>,
+}
+
+impl ThinkTime {
+    pub fn new(min_ms: u64, max_ms: Option<u64>) -> Result<ThinkTime, ThinkTimeError> {
+        if let Some(max_ms) = max_ms {
+            if max_ms < min_ms {
+                return Err(ThinkTimeError::MaxBelowMin(min_ms, max_ms));
+            }
+        }
+
+        Ok(ThinkTime {
+            min_ms,
+            max_ms,
+        })
+    }
+
+    /// Drawn again for each pause
+    pub fn get_duration(&self) -> Duration {
+        let max_ms = match self.max_ms {
+            Some(max_ms) if max_ms > self.min_ms => max_ms,
+            _ => return Duration::from_millis(self.min_ms)
+        };
+
+        let mut random_bytes = [0u8; 8];
+
+        // The minimum is kept when the system cannot provide random bytes
+        if SystemRandom::new().fill(&mut random_bytes).is_err() {
+            return Duration::from_millis(self.min_ms);
+        }
+
+        let random_offset = u64::from_le_bytes(random_bytes) % (max_ms - self.min_ms + 1);
+
+        return Duration::from_millis(self.min_ms + random_offset);
+    }
+}
+
+impl Display for ThinkTime {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.max_ms {
+            Some(max_ms) if max_ms > self.min_ms => write!(f, "{}-{max_ms} ms", self.min_ms),
+            _ => write!(f, "{} ms", self.min_ms)
+        }
+    }
+}
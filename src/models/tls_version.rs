@@ -0,0 +1,55 @@
+use reqwest::tls;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumIter, IntoEnumIterator};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, Display, EnumIter)]
+pub enum TlsVersion {
+    #[serde(rename = "1.0")]
+    #[strum(to_string = "TLS 1.0")]
+    Tls1_0,
+    #[serde(rename = "1.1")]
+    #[strum(to_string = "TLS 1.1")]
+    Tls1_1,
+    #[serde(rename = "1.2")]
+    #[strum(to_string = "TLS 1.2")]
+    Tls1_2,
+    #[serde(rename = "1.3")]
+    #[strum(to_string = "TLS 1.3")]
+    Tls1_3,
+}
+
+/// Oldest version rustls, the TLS backend of the HTTP client, can negotiate
+pub const MIN_SUPPORTED_TLS_VERSION: TlsVersion = TlsVersion::Tls1_2;
+
+/// Choices of the request settings, "Config" follows the version of the config
+pub const TLS_VERSION_CHOICES: [&str; 5] = ["Config", "TLS 1.0", "TLS 1.1", "TLS 1.2", "TLS 1.3"];
+
+impl TlsVersion {
+    pub fn to_reqwest(self) -> tls::Version {
+        return match self {
+            TlsVersion::Tls1_0 => tls::Version::TLS_1_0,
+            TlsVersion::Tls1_1 => tls::Version::TLS_1_1,
+            TlsVersion::Tls1_2 => tls::Version::TLS_1_2,
+            TlsVersion::Tls1_3 => tls::Version::TLS_1_3
+        };
+    }
+
+    /// Accepts "1.2" as well as "TLS 1.2"
+    pub fn from_choice(choice: &str) -> Option<TlsVersion> {
+        let choice = choice.trim().trim_start_matches("TLS").trim_start_matches("tls").trim();
+
+        return TlsVersion::iter().find(|tls_version| tls_version.to_string().ends_with(&format!(" {choice}")));
+    }
+
+    pub fn get_choice_name(tls_version: Option<TlsVersion>) -> &'static str {
+        let tls_version_name = match tls_version {
+            None => return TLS_VERSION_CHOICES[0],
+            Some(tls_version) => tls_version.to_string()
+        };
+
+        return TLS_VERSION_CHOICES
+            .iter()
+            .find(|choice| **choice == tls_version_name)
+            .unwrap_or(&TLS_VERSION_CHOICES[0]);
+    }
+}
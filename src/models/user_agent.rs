@@ -0,0 +1,18 @@
+use lazy_static::lazy_static;
+
+lazy_static! {
+    /// Name and value of the User-Agent presets proposed in the headers tab
+    pub static ref USER_AGENT_PRESETS: Vec<(&'static str, String)> = vec![
+        ("ATAC", format!("ATAC/v{}", env!("CARGO_PKG_VERSION"))),
+        ("curl", String::from("curl/8.10.1")),
+        ("Wget", String::from("Wget/1.24.5")),
+        ("Python requests", String::from("python-requests/2.32.3")),
+        ("Chrome (Windows)", String::from("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36")),
+        ("Edge (Windows)", String::from("Mozilla/5.0 (Windows NT 10.0; Win64; x64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Safari/537.36 Edg/129.0.0.0")),
+        ("Firefox (Linux)", String::from("Mozilla/5.0 (X11; Linux x86_64; rv:131.0) Gecko/20100101 Firefox/131.0")),
+        ("Safari (macOS)", String::from("Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_7) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.0 Safari/605.1.15")),
+        ("Safari (iPhone)", String::from("Mozilla/5.0 (iPhone; CPU iPhone OS 18_0 like Mac OS X) AppleWebKit/605.1.15 (KHTML, like Gecko) Version/18.0 Mobile/15E148 Safari/604.1")),
+        ("Chrome (Android)", String::from("Mozilla/5.0 (Linux; Android 10; K) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/129.0.0.0 Mobile Safari/537.36")),
+        ("Googlebot", String::from("Mozilla/5.0 (compatible; Googlebot/2.1; +http://www.google.com/bot.html)")),
+    ];
+}
@@ -0,0 +1,47 @@
+use std::time::{Duration, Instant, SystemTime};
+
+use chrono::Local;
+
+/// Re-sends the selected request every interval or when its body files change, kept for the session only
+#[derive(Debug, Clone, Default)]
+pub struct RequestWatch {
+    /// None until the first run, which happens as soon as the watch is started
+    pub last_run_at: Option<Instant>,
+    /// Local time of the last run, e.g. "14:02:51"
+    pub last_run: Option<String>,
+    /// Last modification of the body files at the last run
+    pub files_modified_at: Option<SystemTime>,
+}
+
+impl RequestWatch {
+    /// The interval is in seconds, 0 only re-sends the request when its body files change
+    pub fn is_due(&self, now: Instant, interval: usize, files_modified_at: Option<SystemTime>) -> bool {
+        let last_run_at = match self.last_run_at {
+            None => return true,
+            Some(last_run_at) => last_run_at
+        };
+
+        let is_interval_elapsed = interval > 0 && now >= last_run_at + Duration::from_secs(interval as u64);
+
+        return is_interval_elapsed || files_modified_at != self.files_modified_at;
+    }
+
+    pub fn record_run(&mut self, now: Instant, files_modified_at: Option<SystemTime>) {
+        self.last_run_at = Some(now);
+        self.last_run = Some(Local::now().format("%H:%M:%S").to_string());
+        self.files_modified_at = files_modified_at;
+    }
+
+    /// e.g. "Watching every 5s, last run 14:02:51"
+    pub fn to_summary(&self, interval: usize) -> String {
+        let every = match interval {
+            0 => String::from("body files"),
+            interval => format!("every {interval}s")
+        };
+
+        return match &self.last_run {
+            None => format!("Watching {every}, not run yet"),
+            Some(last_run) => format!("Watching {every}, last run {last_run}")
+        };
+    }
+}
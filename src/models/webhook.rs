@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone)]
+pub struct ReceivedRequest {
+    pub received_at: String,
+    pub method: String,
+    pub path: String,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CannedResponse {
+    #[serde(default = "default_status_code")]
+    pub status_code: u16,
+
+    #[serde(default)]
+    pub headers: Vec<(String, String)>,
+
+    #[serde(default)]
+    pub body: String,
+}
+
+impl Default for CannedResponse {
+    fn default() -> Self {
+        CannedResponse {
+            status_code: default_status_code(),
+            headers: vec![],
+            body: String::new(),
+        }
+    }
+}
+
+fn default_status_code() -> u16 {
+    200
+}
+
+impl ReceivedRequest {
+    pub fn to_summary(&self) -> String {
+        format!("{} {} {}", self.received_at, self.method, self.path)
+    }
+}
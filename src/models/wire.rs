@@ -0,0 +1,239 @@
+use reqwest::header::HeaderMap;
+use reqwest::{Method, StatusCode, Url, Version};
+
+/// Indexes of the HPACK static table (RFC 7541, appendix A), the value is empty when only the name is indexed
+const HPACK_STATIC_TABLE: [(&str, &str); 61] = [
+    (":authority", ""),
+    (":method", "GET"),
+    (":method", "POST"),
+    (":path", "/"),
+    (":path", "/index.html"),
+    (":scheme", "http"),
+    (":scheme", "https"),
+    (":status", "200"),
+    (":status", "204"),
+    (":status", "206"),
+    (":status", "304"),
+    (":status", "400"),
+    (":status", "404"),
+    (":status", "500"),
+    ("accept-charset", ""),
+    ("accept-encoding", "gzip, deflate"),
+    ("accept-language", ""),
+    ("accept-ranges", ""),
+    ("accept", ""),
+    ("access-control-allow-origin", ""),
+    ("age", ""),
+    ("allow", ""),
+    ("authorization", ""),
+    ("cache-control", ""),
+    ("content-disposition", ""),
+    ("content-encoding", ""),
+    ("content-language", ""),
+    ("content-length", ""),
+    ("content-location", ""),
+    ("content-range", ""),
+    ("content-type", ""),
+    ("cookie", ""),
+    ("date", ""),
+    ("etag", ""),
+    ("expect", ""),
+    ("expires", ""),
+    ("from", ""),
+    ("host", ""),
+    ("if-match", ""),
+    ("if-modified-since", ""),
+    ("if-none-match", ""),
+    ("if-range", ""),
+    ("if-unmodified-since", ""),
+    ("last-modified", ""),
+    ("link", ""),
+    ("location", ""),
+    ("max-forwards", ""),
+    ("proxy-authenticate", ""),
+    ("proxy-authorization", ""),
+    ("range", ""),
+    ("referer", ""),
+    ("refresh", ""),
+    ("retry-after", ""),
+    ("server", ""),
+    ("set-cookie", ""),
+    ("strict-transport-security", ""),
+    ("transfer-encoding", ""),
+    ("user-agent", ""),
+    ("vary", ""),
+    ("via", ""),
+    ("www-authenticate", ""),
+];
+
+/// Headers HTTP/2 forbids, the client removes them before sending (RFC 9113, section 8.2.2)
+const CONNECTION_SPECIFIC_HEADERS: [&str; 5] = ["connection", "keep-alive", "proxy-connection", "transfer-encoding", "upgrade"];
+
+/// Overhead of each field in the header list size (RFC 7541, section 4.1)
+const HPACK_FIELD_OVERHEAD: usize = 32;
+
+/// Request and response fields as exchanged with the server, displayed in the wire result tab
+#[derive(Debug, Clone)]
+pub struct WireExchange {
+    /// e.g. "HTTP/2.0"
+    pub version: String,
+    pub is_http2: bool,
+    /// Request line or pseudo-headers first, then the headers
+    pub request_fields: Vec<WireField>,
+    /// Status line or pseudo-header first, then the headers
+    pub response_fields: Vec<WireField>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WireField {
+    pub name: String,
+    pub value: String,
+    /// e.g. Authorization, sent as never indexed literals
+    pub is_sensitive: bool,
+}
+
+impl WireField {
+    fn new(name: &str, value: &str, is_sensitive: bool) -> WireField {
+        WireField {
+            name: name.to_string(),
+            value: value.to_string(),
+            is_sensitive,
+        }
+    }
+
+    /// How HPACK encodes the field on a new connection, the dynamic table then indexes the repeated fields
+    fn get_hpack_representation(&self) -> String {
+        if CONNECTION_SPECIFIC_HEADERS.contains(&self.name.as_str()) {
+            return String::from("removed, connection-specific");
+        }
+
+        let full_index = HPACK_STATIC_TABLE
+            .iter()
+            .position(|(name, value)| *name == self.name && !value.is_empty() && *value == self.value);
+
+        if let Some(full_index) = full_index {
+            return format!("static {}", full_index + 1);
+        }
+
+        let name_index = HPACK_STATIC_TABLE
+            .iter()
+            .position(|(name, _)| *name == self.name);
+
+        let literal = match self.is_sensitive {
+            true => "never indexed",
+            false => "literal"
+        };
+
+        return match name_index {
+            Some(name_index) => format!("{literal}, name {}", name_index + 1),
+            None => String::from(literal)
+        };
+    }
+
+    fn get_hpack_size(&self) -> usize {
+        return self.name.len() + self.value.len() + HPACK_FIELD_OVERHEAD;
+    }
+}
+
+impl WireExchange {
+    pub fn new(method: &Method, url: &Url, request_headers: &HeaderMap, version: Version, status: StatusCode, response_headers: &HeaderMap) -> WireExchange {
+        let is_http2 = version == Version::HTTP_2;
+        let version_name = format!("{version:?}");
+
+        let path = match url.query() {
+            None => url.path().to_string(),
+            Some(query) => format!("{}?{query}", url.path())
+        };
+
+        let mut request_fields: Vec<WireField> = match is_http2 {
+            true => {
+                let authority = match url.port() {
+                    None => url.host_str().unwrap_or("").to_string(),
+                    Some(port) => format!("{}:{port}", url.host_str().unwrap_or(""))
+                };
+
+                vec![
+                    WireField::new(":method", method.as_str(), false),
+                    WireField::new(":scheme", url.scheme(), false),
+                    WireField::new(":authority", &authority, false),
+                    WireField::new(":path", &path, false),
+                ]
+            },
+            // Sent as HTTP/1.1 even when the server answers with HTTP/1.0
+            false => vec![WireField::new(&format!("{method} {path} HTTP/1.1"), "", false)]
+        };
+
+        request_fields.extend(headers_to_fields(request_headers));
+
+        let mut response_fields: Vec<WireField> = match is_http2 {
+            true => vec![WireField::new(":status", status.as_str(), false)],
+            false => vec![WireField::new(&format!("{version_name} {status}"), "", false)]
+        };
+
+        response_fields.extend(headers_to_fields(response_headers));
+
+        WireExchange {
+            version: version_name,
+            is_http2,
+            request_fields,
+            response_fields,
+        }
+    }
+
+    pub fn to_lines(&self) -> Vec<String> {
+        let mut lines: Vec<String> = vec![self.version.clone(), String::new()];
+
+        lines.push(String::from("Request"));
+        lines.extend(self.fields_to_lines(&self.request_fields));
+        lines.push(String::new());
+
+        lines.push(String::from("Response"));
+        lines.extend(self.fields_to_lines(&self.response_fields));
+        lines.push(String::new());
+
+        lines.push(String::from("The headers added by the client while sending, e.g. cookie or content-length, are not listed"));
+
+        return lines;
+    }
+
+    fn fields_to_lines(&self, fields: &[WireField]) -> Vec<String> {
+        let field_lines: Vec<String> = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| match index == 0 && !self.is_http2 {
+                // Request or status line
+                true => field.name.clone(),
+                false => format!("{}: {}", field.name, field.value)
+            })
+            .collect();
+
+        if !self.is_http2 {
+            return field_lines;
+        }
+
+        let width = field_lines.iter().map(String::len).max().unwrap_or(0);
+
+        let mut lines: Vec<String> = field_lines
+            .iter()
+            .zip(fields)
+            .map(|(field_line, field)| format!("{field_line:<width$}  [{}] {} B", field.get_hpack_representation(), field.get_hpack_size()))
+            .collect();
+
+        let header_list_size: usize = fields
+            .iter()
+            .filter(|field| !CONNECTION_SPECIFIC_HEADERS.contains(&field.name.as_str()))
+            .map(WireField::get_hpack_size)
+            .sum();
+
+        lines.push(format!("Header list size {header_list_size} B"));
+
+        return lines;
+    }
+}
+
+fn headers_to_fields(headers: &HeaderMap) -> Vec<WireField> {
+    return headers
+        .iter()
+        .map(|(name, value)| WireField::new(name.as_str(), value.to_str().unwrap_or("<binary>"), value.is_sensitive()))
+        .collect();
+}
@@ -9,7 +9,9 @@ use strum::Display;
 
 use crate::app::app::App;
 use crate::app::files::key_bindings::{KEY_BINDINGS, TextAreaMode};
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
+use crate::cli::args::ARGS;
 use crate::tui::app_states::AppState::*;
 use crate::tui::event_key_bindings::EventKeyBinding;
 use crate::tui::events::AppEvent;
@@ -29,9 +31,62 @@ pub enum AppState {
     DisplayingCookies,
 
     #[strum(to_string = "Editing cookies")]
-    #[allow(dead_code)]
     EditingCookies,
 
+    #[strum(to_string = "Creating cookie")]
+    CreatingCookie,
+
+    /* Webhook listener */
+
+    #[strum(to_string = "Displaying webhook listener")]
+    DisplayingWebhookListener,
+
+    /* Git */
+
+    #[strum(to_string = "Displaying git status")]
+    DisplayingGitStatus,
+
+    /* Scratchpad */
+
+    #[strum(to_string = "Displaying scratchpad")]
+    DisplayingScratchpad,
+
+    #[strum(to_string = "Writing scratchpad note")]
+    WritingScratchpadNote,
+
+    /* Response cache */
+
+    #[strum(to_string = "Displaying response cache")]
+    DisplayingResponseCache,
+
+    /* Logs */
+
+    #[strum(to_string = "Displaying logs")]
+    DisplayingLogs,
+
+    /* Scripting console */
+
+    #[strum(to_string = "Displaying scripting console")]
+    DisplayingScriptingConsole,
+
+    /* Errors */
+
+    #[strum(to_string = "Displaying error")]
+    DisplayingError,
+
+    /* Macros */
+
+    #[strum(to_string = "Replaying macro")]
+    ReplayingMacro,
+
+    /* Environments */
+
+    #[strum(to_string = "Choosing environment")]
+    ChoosingEnvironment,
+
+    #[strum(to_string = "Displaying variable usages")]
+    DisplayingVariableUsages,
+
     /* Collections */
 
     #[strum(to_string = "Choosing an element to create")]
@@ -49,12 +104,24 @@ pub enum AppState {
     #[strum(to_string = "Deleting request")]
     DeletingRequest,
 
+    #[strum(to_string = "Recovering unsaved edit")]
+    RecoveringUnsavedEdit,
+
+    #[strum(to_string = "Collection changed on disk")]
+    ReloadingChangedCollection,
+
     #[strum(to_string = "Renaming collection")]
     RenamingCollection,
 
     #[strum(to_string = "Renaming request")]
     RenamingRequest,
 
+    #[strum(to_string = "Editing tags")]
+    EditingElementTags,
+
+    #[strum(to_string = "Filtering by tag")]
+    FilteringCollectionsByTag,
+
     /* Request */
 
     #[strum(to_string = "Request menu")]
@@ -75,9 +142,24 @@ pub enum AppState {
     #[strum(to_string = "Editing request auth bearer token")]
     EditingRequestAuthBearerToken,
 
+    #[strum(to_string = "Editing request auth HMAC")]
+    EditingRequestAuthHmac,
+
+    #[strum(to_string = "Editing request auth NTLM")]
+    EditingRequestAuthNtlm,
+
+    #[strum(to_string = "Editing request auth OAuth")]
+    EditingRequestAuthOAuth1,
+
+    #[strum(to_string = "Editing request auth plugin")]
+    EditingRequestAuthPlugin,
+
     #[strum(to_string = "Editing request header")]
     EditingRequestHeader,
 
+    #[strum(to_string = "Editing request cookie")]
+    EditingRequestCookie,
+
     #[strum(to_string = "Editing request body (Form)")]
     EditingRequestBodyTable,
 
@@ -93,63 +175,155 @@ pub enum AppState {
     #[strum(to_string = "Editing post-request script")]
     EditingPostRequestScript,
 
+    #[strum(to_string = "Editing response processor")]
+    EditingResponseProcessor,
+
     #[strum(to_string = "Editing request settings")]
     EditingRequestSettings,
+
+    #[strum(to_string = "Choosing User-Agent")]
+    ChoosingRequestUserAgent,
+
+    #[strum(to_string = "Previewing request")]
+    PreviewingRequest,
+
+    #[strum(to_string = "Displaying JWT")]
+    DisplayingJwt,
+
+    #[strum(to_string = "Entering a JWT, a secret or a JWKS URL")]
+    EditingJwtInput,
+
+    #[strum(to_string = "Using encoding tools")]
+    UsingEncodingTools,
+
+    #[strum(to_string = "Editing encoding tools input")]
+    EditingEncodingToolsInput,
+
+    #[strum(to_string = "Load testing request")]
+    LoadTestingRequest,
+
+    #[strum(to_string = "Going to line")]
+    GoingToLine,
+
+    #[strum(to_string = "Choosing response link")]
+    ChoosingResponseLink,
 }
 
 pub fn next_app_state(app_state: &AppState) -> AppState {
     match app_state {
         Normal => DisplayingCookies,
         DisplayingCookies => EditingCookies,
-        EditingCookies => ChoosingElementToCreate,
+        EditingCookies => CreatingCookie,
+        CreatingCookie => DisplayingWebhookListener,
+        DisplayingWebhookListener => DisplayingGitStatus,
+        DisplayingGitStatus => DisplayingScratchpad,
+        DisplayingScratchpad => WritingScratchpadNote,
+        WritingScratchpadNote => DisplayingResponseCache,
+        DisplayingResponseCache => DisplayingLogs,
+        DisplayingLogs => DisplayingScriptingConsole,
+        DisplayingScriptingConsole => DisplayingError,
+        DisplayingError => ReplayingMacro,
+        ReplayingMacro => ChoosingEnvironment,
+        ChoosingEnvironment => DisplayingVariableUsages,
+        DisplayingVariableUsages => ChoosingElementToCreate,
         ChoosingElementToCreate => CreatingNewCollection,
         CreatingNewCollection => CreatingNewRequest,
         CreatingNewRequest => DeletingCollection,
         DeletingCollection => DeletingRequest,
-        DeletingRequest => RenamingCollection,
+        DeletingRequest => RecoveringUnsavedEdit,
+        RecoveringUnsavedEdit => ReloadingChangedCollection,
+        ReloadingChangedCollection => RenamingCollection,
         RenamingCollection => RenamingRequest,
-        RenamingRequest => SelectedRequest,
+        RenamingRequest => EditingElementTags,
+        EditingElementTags => FilteringCollectionsByTag,
+        FilteringCollectionsByTag => SelectedRequest,
         SelectedRequest => EditingRequestUrl,
         EditingRequestUrl => EditingRequestParam,
         EditingRequestParam => EditingRequestAuthUsername,
         EditingRequestAuthUsername => EditingRequestAuthPassword,
         EditingRequestAuthPassword => EditingRequestAuthBearerToken,
-        EditingRequestAuthBearerToken => EditingRequestHeader,
-        EditingRequestHeader => EditingRequestBodyTable,
+        EditingRequestAuthBearerToken => EditingRequestAuthHmac,
+        EditingRequestAuthHmac => EditingRequestAuthNtlm,
+        EditingRequestAuthNtlm => EditingRequestAuthOAuth1,
+        EditingRequestAuthOAuth1 => EditingRequestAuthPlugin,
+        EditingRequestAuthPlugin => EditingRequestHeader,
+        EditingRequestHeader => EditingRequestCookie,
+        EditingRequestCookie => EditingRequestBodyTable,
         EditingRequestBodyTable => EditingRequestBodyFile,
         EditingRequestBodyFile => EditingRequestBodyString,
         EditingRequestBodyString => EditingPreRequestScript,
         EditingPreRequestScript => EditingPostRequestScript,
-        EditingPostRequestScript => EditingRequestSettings,
-        EditingRequestSettings => Normal,
+        EditingPostRequestScript => EditingResponseProcessor,
+        EditingResponseProcessor => EditingRequestSettings,
+        EditingRequestSettings => ChoosingRequestUserAgent,
+        ChoosingRequestUserAgent => PreviewingRequest,
+        PreviewingRequest => DisplayingJwt,
+        DisplayingJwt => EditingJwtInput,
+        EditingJwtInput => UsingEncodingTools,
+        UsingEncodingTools => EditingEncodingToolsInput,
+        EditingEncodingToolsInput => LoadTestingRequest,
+        LoadTestingRequest => GoingToLine,
+        GoingToLine => ChoosingResponseLink,
+        ChoosingResponseLink => Normal,
     }
 }
 
 pub fn previous_app_state(app_state: &AppState) -> AppState {
     match app_state {
-        Normal => EditingRequestSettings,
+        Normal => ChoosingResponseLink,
+        ChoosingResponseLink => GoingToLine,
+        GoingToLine => LoadTestingRequest,
         DisplayingCookies => Normal,
         EditingCookies => DisplayingCookies,
-        ChoosingElementToCreate => EditingCookies,
+        CreatingCookie => EditingCookies,
+        DisplayingWebhookListener => CreatingCookie,
+        DisplayingGitStatus => DisplayingWebhookListener,
+        DisplayingScratchpad => DisplayingGitStatus,
+        WritingScratchpadNote => DisplayingScratchpad,
+        DisplayingResponseCache => WritingScratchpadNote,
+        DisplayingLogs => DisplayingResponseCache,
+        DisplayingScriptingConsole => DisplayingLogs,
+        DisplayingError => DisplayingScriptingConsole,
+        ReplayingMacro => DisplayingError,
+        ChoosingEnvironment => ReplayingMacro,
+        DisplayingVariableUsages => ChoosingEnvironment,
+        ChoosingElementToCreate => DisplayingVariableUsages,
         CreatingNewCollection => ChoosingElementToCreate,
         CreatingNewRequest => CreatingNewCollection,
         DeletingCollection => CreatingNewRequest,
         DeletingRequest => DeletingCollection,
-        RenamingCollection => DeletingRequest,
+        RecoveringUnsavedEdit => DeletingRequest,
+        ReloadingChangedCollection => RecoveringUnsavedEdit,
+        RenamingCollection => ReloadingChangedCollection,
         RenamingRequest => RenamingCollection,
-        SelectedRequest => RenamingRequest,
+        EditingElementTags => RenamingRequest,
+        FilteringCollectionsByTag => EditingElementTags,
+        SelectedRequest => FilteringCollectionsByTag,
         EditingRequestUrl => SelectedRequest,
         EditingRequestParam => EditingRequestUrl,
         EditingRequestAuthUsername => EditingRequestParam,
         EditingRequestAuthPassword => EditingRequestAuthUsername,
         EditingRequestAuthBearerToken => EditingRequestAuthPassword,
-        EditingRequestHeader => EditingRequestAuthBearerToken,
-        EditingRequestBodyTable => EditingRequestHeader,
+        EditingRequestAuthHmac => EditingRequestAuthBearerToken,
+        EditingRequestAuthNtlm => EditingRequestAuthHmac,
+        EditingRequestAuthOAuth1 => EditingRequestAuthNtlm,
+        EditingRequestAuthPlugin => EditingRequestAuthOAuth1,
+        EditingRequestHeader => EditingRequestAuthPlugin,
+        EditingRequestCookie => EditingRequestHeader,
+        EditingRequestBodyTable => EditingRequestCookie,
         EditingRequestBodyFile => EditingRequestBodyTable,
         EditingRequestBodyString => EditingRequestBodyFile,
         EditingPreRequestScript => EditingRequestBodyString,
         EditingPostRequestScript => EditingPreRequestScript,
-        EditingRequestSettings => EditingPostRequestScript,
+        EditingResponseProcessor => EditingPostRequestScript,
+        EditingRequestSettings => EditingResponseProcessor,
+        ChoosingRequestUserAgent => EditingRequestSettings,
+        PreviewingRequest => ChoosingRequestUserAgent,
+        DisplayingJwt => PreviewingRequest,
+        EditingJwtInput => DisplayingJwt,
+        UsingEncodingTools => EditingJwtInput,
+        EditingEncodingToolsInput => UsingEncodingTools,
+        LoadTestingRequest => EditingEncodingToolsInput,
     }
 }
 
@@ -162,6 +336,8 @@ impl AppState {
                 ExitApp(EventKeyBinding::new(vec![key_bindings.main_menu.exit, key!(ctrl-c)], "Exit", Some("Exit"))),
 
                 Documentation(EventKeyBinding::new(vec![key_bindings.generic.display_help], "Display help", Some("Help"))),
+                DisplayCheatsheet(EventKeyBinding::new(vec![key_bindings.generic.display_cheatsheet], "Display cheatsheet", None)),
+                AbortAll(EventKeyBinding::new(vec![key_bindings.generic.abort_all], "Abort all pending requests and tasks", None)),
 
                 MoveCollectionCursorUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
                 MoveCollectionCursorDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
@@ -173,12 +349,27 @@ impl AppState {
                 CreateElement(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.create_element], "Create element", Some("Create"))),
                 DeleteElement(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete element", None)),
                 RenameElement(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.rename_element], "Rename element", None)),
+                EditElementDescription(EventKeyBinding::new(vec![key_bindings.main_menu.edit_description], "Edit description", None)),
+                EditElementTags(EventKeyBinding::new(vec![key_bindings.main_menu.edit_tags], "Edit tags", None)),
+                FilterCollectionsByTag(EventKeyBinding::new(vec![key_bindings.main_menu.filter_by_tag], "Filter by tag", None)),
+                ToggleRequestSkip(EventKeyBinding::new(vec![key_bindings.main_menu.toggle_request_skip], "Skip in runs", None)),
 
                 MoveRequestUp(EventKeyBinding::new(vec![key_bindings.main_menu.move_request_up], "Move request up", None)),
                 MoveRequestDown(EventKeyBinding::new(vec![key_bindings.main_menu.move_request_down], "Move request down", None)),
 
                 NextEnvironment(EventKeyBinding::new(vec![key_bindings.main_menu.next_environment], "Next environment", None)),
+                PreviousEnvironment(EventKeyBinding::new(vec![key_bindings.main_menu.previous_environment], "Previous environment", None)),
+                ChooseEnvironment(EventKeyBinding::new(vec![key_bindings.main_menu.choose_environment], "Choose environment", None)),
+                DisplayVariableUsages(EventKeyBinding::new(vec![key_bindings.main_menu.display_variable_usages], "Display variable usages", None)),
+                NextWorkspace(EventKeyBinding::new(vec![key_bindings.main_menu.next_workspace], "Next workspace", None)),
                 DisplayCookies(EventKeyBinding::new(vec![key_bindings.main_menu.display_cookies], "Display cookies", None)),
+                DisplayWebhookListener(EventKeyBinding::new(vec![key_bindings.main_menu.display_webhook_listener], "Display webhook listener", None)),
+                DisplayGitStatus(EventKeyBinding::new(vec![key_bindings.main_menu.display_git_status], "Display git status", None)),
+                DisplayScratchpad(EventKeyBinding::new(vec![key_bindings.main_menu.display_scratchpad], "Display scratchpad", None)),
+                DisplayResponseCache(EventKeyBinding::new(vec![key_bindings.main_menu.display_response_cache], "Display response cache", None)),
+                ToggleOfflineMode(EventKeyBinding::new(vec![key_bindings.main_menu.toggle_offline_mode], "Toggle offline mode", None)),
+                DisplayLogs(EventKeyBinding::new(vec![key_bindings.main_menu.display_logs], "Display logs", None)),
+                DisplayScriptingConsole(EventKeyBinding::new(vec![key_bindings.main_menu.display_scripting_console], "Display scripting console", None)),
             ],
             DisplayingCookies => vec![
                 GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
@@ -188,10 +379,125 @@ impl AppState {
                 CookiesMoveLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move left", Some("Left"))),
                 CookiesMoveRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move right", Some("Right"))),
 
+                EditCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit cookie", Some("Edit"))),
+                NewCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.create_element], "New cookie", Some("New"))),
                 DeleteCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete cookie", Some("Delete"))),
+                DeleteDomainCookies(EventKeyBinding::new(vec![key_bindings.main_menu.delete_domain_cookies], "Delete domain cookies", None)),
             ],
             EditingCookies => vec![
-                Documentation(EventKeyBinding::new(vec![*EMPTY_KEY], "Not implemented yet", None))
+                CancelEditingCookie(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyCookie(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingCookieDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingCookieDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingCookieMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingCookieMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingCookieCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            CreatingCookie => vec![
+                CancelEditingCookie(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                CreateCookie(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingCookieDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingCookieDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingCookieMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingCookieMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingCookieCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            DisplayingWebhookListener => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                WebhookListenerMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                WebhookListenerMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+
+                ToggleWebhookListener(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Start/stop listener", Some("Start/Stop"))),
+                ClearWebhookRequests(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Clear received requests", Some("Clear"))),
+            ],
+            DisplayingGitStatus => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                GitPull(EventKeyBinding::new(vec![key_bindings.main_menu.git_pull], "Pull", Some("Pull"))),
+                GitCommit(EventKeyBinding::new(vec![key_bindings.main_menu.git_commit], "Commit all changes", Some("Commit"))),
+                GitPush(EventKeyBinding::new(vec![key_bindings.main_menu.git_push], "Push", Some("Push"))),
+            ],
+            DisplayingScratchpad => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                ScratchpadMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                ScratchpadMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+
+                NewScratchpadNote(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.create_element], "New note", Some("Note"))),
+                PinClipboardToScratchpad(EventKeyBinding::new(vec![key_bindings.main_menu.pin_clipboard], "Pin clipboard", Some("Pin"))),
+                CopyScratchpadEntry(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.yank_response_part], "Yank entry", Some("Yank"))),
+                DeleteScratchpadEntry(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete entry", Some("Delete"))),
+            ],
+            WritingScratchpadNote => vec![
+                CancelScratchpadNote(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                PinScratchpadNote(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                WritingScratchpadNoteDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                WritingScratchpadNoteDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                WritingScratchpadNoteMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                WritingScratchpadNoteMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                WritingScratchpadNoteCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            DisplayingResponseCache => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                ResponseCacheMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                ResponseCacheMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+
+                DeleteResponseCacheEntry(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete entry", Some("Delete"))),
+            ],
+            DisplayingLogs => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                LogsScrollUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Scroll up", Some("Up"))),
+                LogsScrollDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Scroll down", Some("Down"))),
+                LogsPreviousLevel(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Fewer levels", Some("Left"))),
+                LogsNextLevel(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "More levels", Some("Right"))),
+
+                ClearLogs(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Clear logs", Some("Clear"))),
+            ],
+            DisplayingScriptingConsole => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Quit", Some("Quit"))),
+                RunConsoleScript(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Run script", Some("Run"))),
+
+                ConsoleHistoryPrevious(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Previous script", Some("Up"))),
+                ConsoleHistoryNext(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Next script", Some("Down"))),
+
+                ScriptingConsoleDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                ScriptingConsoleDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                ScriptingConsoleMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                ScriptingConsoleMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                ScriptingConsoleCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            DisplayingError => vec![
+                CloseErrorPopup(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back, key_bindings.generic.navigation.select], "Close", Some("Close"))),
+            ],
+            ReplayingMacro => vec![
+                CancelMacroReplay(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ConfirmMacroReplay(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Replay", Some("Replay"))),
+
+                ReplayingMacroDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                ReplayingMacroDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                ReplayingMacroMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                ReplayingMacroMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                ReplayingMacroCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            ChoosingEnvironment => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Cancel", Some("Cancel"))),
+
+                EnvironmentsMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                EnvironmentsMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+
+                SelectEnvironment(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Select environment", Some("Select"))),
+            ],
+            DisplayingVariableUsages => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
+
+                VariableUsagesScrollUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Scroll up", Some("Up"))),
+                VariableUsagesScrollDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Scroll down", Some("Down"))),
             ],
             ChoosingElementToCreate => vec![
                 GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit", Some("Quit"))),
@@ -222,6 +528,7 @@ impl AppState {
 
                 CreatingRequestSelectCollectionUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.alt_move_cursor_up], "Collection selection up", Some("Up"))),
                 CreatingRequestSelectCollectionDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.alt_move_cursor_down], "Collection selection down", Some("Down"))),
+                CreatingRequestNextTemplate(EventKeyBinding::new(vec![key_bindings.main_menu.next_request_template], "Next template", Some("Template"))),
 
                 CreatingRequestCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
@@ -242,6 +549,22 @@ impl AppState {
                 DeleteRequest(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Select choice", Some("Select"))),
 
             ],
+            RecoveringUnsavedEdit => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Decide on next start", Some("Later"))),
+
+                RecoveringEditMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move selection left", Some("Left"))),
+                RecoveringEditMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move selection right", Some("Right"))),
+
+                RecoverUnsavedEdit(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Select choice", Some("Select"))),
+            ],
+            ReloadingChangedCollection => vec![
+                KeepEditingRequest(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Keep editing", Some("Keep editing"))),
+
+                ReloadingCollectionMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move selection left", Some("Left"))),
+                ReloadingCollectionMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move selection right", Some("Right"))),
+
+                ReloadChangedCollection(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Select choice", Some("Select"))),
+            ],
             RenamingCollection => vec![
                 GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
                 RenameCollection(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
@@ -262,6 +585,26 @@ impl AppState {
                 RenamingRequestMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
                 RenamingRequestCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
+            EditingElementTags => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyElementTags(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingTagsDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingTagsDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingTagsMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingTagsMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingTagsCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            FilteringCollectionsByTag => vec![
+                GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ApplyTagFilter(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                FilteringByTagDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                FilteringByTagDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                FilteringByTagMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                FilteringByTagMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                FilteringByTagCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             SelectedRequest => {
                 // Depending on the current request view, some keys may need to be deactivated
                 let (params_events_allowed, result_events_allowed) = match request_view {
@@ -275,6 +618,8 @@ impl AppState {
 
                     GoBackToMainMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Quit to main menu", Some("Quit"))),
                     Documentation(EventKeyBinding::new(vec![key_bindings.generic.display_help], "Display help", Some("Help"))),
+                    DisplayCheatsheet(EventKeyBinding::new(vec![key_bindings.generic.display_cheatsheet], "Display cheatsheet", None)),
+                    AbortAll(EventKeyBinding::new(vec![key_bindings.generic.abort_all], "Abort all pending requests and tasks", None)),
 
                     EditUrl(EventKeyBinding::new(vec![key_bindings.request_selected.change_url], "Edit URL", Some("URL"))),
                     EditMethod(EventKeyBinding::new(vec![key_bindings.request_selected.change_method], "Change method", Some("Method"))),
@@ -284,9 +629,20 @@ impl AppState {
                     NextView(EventKeyBinding::new(vec![key_bindings.request_selected.next_view], "Next view", None)),
 
                     SendRequest(EventKeyBinding::new(vec![key_bindings.request_selected.send_request, key_bindings.request_selected.alt_send_request], "Send/cancel request", Some("Send/Cancel"))),
+                    LoadTest(EventKeyBinding::new(vec![key_bindings.request_selected.load_test], "Load test", None)),
+                    SimulateCors(EventKeyBinding::new(vec![key_bindings.request_selected.simulate_cors], "Simulate browser CORS", None)),
+                    RefreshRequest(EventKeyBinding::new(vec![key_bindings.request_selected.refresh_request], "Send bypassing the response cache", None)),
+                    RevalidateRequest(EventKeyBinding::new(vec![key_bindings.request_selected.revalidate_request], "Revalidate request", None)),
+                    ToggleWatch(EventKeyBinding::new(vec![key_bindings.request_selected.toggle_watch], "Watch request", None)),
+                    PreviewRequest(EventKeyBinding::new(vec![key_bindings.request_selected.preview_request], "Preview request", None)),
+                    DecodeJwt(EventKeyBinding::new(vec![key_bindings.request_selected.decode_jwt], "Decode JWT", None)),
+                    UseEncodingTools(EventKeyBinding::new(vec![key_bindings.request_selected.encoding_tools], "Encoding tools", None)),
 
                     NextEnvironment(EventKeyBinding::new(vec![key_bindings.main_menu.next_environment], "Next environment", None)),
+                    PreviousEnvironment(EventKeyBinding::new(vec![key_bindings.main_menu.previous_environment], "Previous environment", None)),
+                    ChooseEnvironment(EventKeyBinding::new(vec![key_bindings.main_menu.choose_environment], "Choose environment", None)),
                     DisplayCookies(EventKeyBinding::new(vec![key_bindings.main_menu.display_cookies], "Display cookies", None)),
+                    DisplayWebhookListener(EventKeyBinding::new(vec![key_bindings.main_menu.display_webhook_listener], "Display webhook listener", None)),
                 ];
 
                 let mut base_param_tabs_events: Vec<AppEvent> = vec![];
@@ -331,6 +687,19 @@ impl AppState {
                             CreateRequestHeader(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.create_element], "Create header", None)),
                             DeleteRequestHeader(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete header", None)),
                             ToggleRequestHeader(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.toggle_element], "Toggle header", None)),
+                            ChooseRequestUserAgent(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.change_user_agent], "Choose User-Agent", None)),
+                        ],
+                        RequestParamsTabs::Cookies => vec![
+                            EditRequestCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit cookie", None)),
+
+                            RequestCookiesMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", None)),
+                            RequestCookiesMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", None)),
+                            RequestCookiesMoveLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Move left", None)),
+                            RequestCookiesMoveRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Move right", None)),
+
+                            CreateRequestCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.create_element], "Create cookie", None)),
+                            DeleteRequestCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.delete_element], "Delete cookie", None)),
+                            ToggleRequestCookie(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.toggle_element], "Toggle cookie", None)),
                         ],
                         RequestParamsTabs::Body => vec![
                             EditRequestBody(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit body", None)),
@@ -346,8 +715,13 @@ impl AppState {
                         ],
                         RequestParamsTabs::Scripts => vec![
                             EditRequestScript(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit request script", Some("Edit"))),
-                            RequestScriptMove(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
-                            RequestScriptMove(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+                            RequestScriptMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                            RequestScriptMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+                        ],
+                        RequestParamsTabs::Docs => vec![
+                            EditRequestDescription(EventKeyBinding::new(vec![key_bindings.generic.list_and_table_actions.edit_element], "Edit description", Some("Edit"))),
+                            RequestDescriptionScrollUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Scroll up", None)),
+                            RequestDescriptionScrollDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Scroll down", None)),
                         ]
                     };
 
@@ -367,6 +741,15 @@ impl AppState {
                         ScrollResultRight(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.scroll_right], "Scroll result right", None)),
                     
                         CopyResponsePart(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.yank_response_part], "Yank response part", Some("Yank response"))),
+                        PinResponsePart(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.pin_response_part], "Pin response part to scratchpad", None)),
+                        CycleBodyHighlighting(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.cycle_body_highlighting], "Cycle body highlighting", None)),
+                        ToggleResultRecordFold(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_record_fold], "Fold/unfold NDJSON record", None)),
+                        ToggleResultTableView(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_table_view], "Toggle CSV table/raw text", None)),
+                        ToggleProcessedBody(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_processed_body], "Toggle processed/raw body", None)),
+                        ToggleLineWrapping(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_line_wrapping], "Toggle line wrapping", None)),
+                        ToggleLineNumbers(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_line_numbers], "Toggle line numbers", None)),
+                        GoToLine(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.go_to_line], "Go to line", None)),
+                        DisplayResponseLinks(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.display_response_links], "Follow response link", None)),
                     ];
 
                     if params_events_allowed {
@@ -381,6 +764,16 @@ impl AppState {
 
                 base_events
             },
+            EditingResponseProcessor => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyResponseProcessor(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingResponseProcessorDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingResponseProcessorDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingResponseProcessorMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingResponseProcessorMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingResponseProcessorCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             EditingRequestUrl => vec![
                 GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
                 ModifyRequestUrl(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
@@ -431,6 +824,46 @@ impl AppState {
                 EditingRequestAuthBearerTokenMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
                 EditingRequestAuthBearerTokenCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
+            EditingRequestAuthHmac => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyRequestAuthHmac(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingRequestAuthHmacDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingRequestAuthHmacDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingRequestAuthHmacMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingRequestAuthHmacMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingRequestAuthHmacCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            EditingRequestAuthNtlm => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyRequestAuthNtlm(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingRequestAuthNtlmDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingRequestAuthNtlmDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingRequestAuthNtlmMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingRequestAuthNtlmMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingRequestAuthNtlmCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            EditingRequestAuthOAuth1 => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyRequestAuthOAuth1(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingRequestAuthOAuth1DeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingRequestAuthOAuth1DeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingRequestAuthOAuth1MoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingRequestAuthOAuth1MoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingRequestAuthOAuth1CharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            EditingRequestAuthPlugin => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyRequestAuthPlugin(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingRequestAuthPluginDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingRequestAuthPluginDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingRequestAuthPluginMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingRequestAuthPluginMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingRequestAuthPluginCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             EditingRequestHeader => vec![
                 GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
                 ModifyRequestHeader(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
@@ -439,8 +872,21 @@ impl AppState {
                 EditingRequestHeaderDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
                 EditingRequestHeaderMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
                 EditingRequestHeaderMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingRequestHeaderComplete(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.complete_header], "Complete header", Some("Complete"))),
+                EditingRequestHeaderCompletionMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Previous header completion", None)),
+                EditingRequestHeaderCompletionMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Next header completion", None)),
                 EditingRequestHeaderCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
+            EditingRequestCookie => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ModifyRequestCookie(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingRequestCookieDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingRequestCookieDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingRequestCookieMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingRequestCookieMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingRequestCookieCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
             EditingRequestBodyTable => vec![
                 GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
                 ModifyRequestBodyTable(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
@@ -459,10 +905,16 @@ impl AppState {
                 EditingRequestBodyFileDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
                 EditingRequestBodyFileMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
                 EditingRequestBodyFileMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingRequestBodyFileCompletePath(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.complete_file_path], "Complete path", Some("Complete"))),
+                EditingRequestBodyFileCompletionMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Previous path completion", None)),
+                EditingRequestBodyFileCompletionMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Next path completion", None)),
                 EditingRequestBodyFileCharInput(EventKeyBinding::new(vec![], "Char input", None)),
             ],
             EditingRequestBodyString => match key_bindings.generic.text_inputs.text_area_mode {
                 TextAreaMode::VimEmulation => vec![
+                    ToggleLineNumbers(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_line_numbers], "Toggle line numbers", None)),
+                    GoToLine(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.go_to_line], "Go to line", None)),
+                    EditingRequestBodyStringFormatJson(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.format_json_body], "Format JSON", None)),
                     EditingRequestBodyStringVimInput(EventKeyBinding::new(vec![], "Vim input", None)),
                     Documentation(EventKeyBinding::new(vec![*EMPTY_KEY], "Vim key-bindings", Some("Vim-like key bindings"))),
                     Documentation(EventKeyBinding::new(vec![key!(q)], "Quit without saving", Some("Quit without saving"))),
@@ -485,6 +937,9 @@ impl AppState {
                     EditingRequestBodyStringMoveCursorDown(EventKeyBinding::new(vec![text_area_key_bindings.move_cursor_down], "Down", Some("Down"))),
                     EditingRequestBodyStringMoveCursorLeft(EventKeyBinding::new(vec![text_area_key_bindings.move_cursor_left], "Left", Some("Left"))),
                     EditingRequestBodyStringMoveCursorRight(EventKeyBinding::new(vec![text_area_key_bindings.move_cursor_right], "Right", Some("Right"))),
+                    ToggleLineNumbers(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_line_numbers], "Toggle line numbers", None)),
+                    GoToLine(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.go_to_line], "Go to line", None)),
+                    EditingRequestBodyStringFormatJson(EventKeyBinding::new(vec![key_bindings.request_selected.param_tabs.format_json_body], "Format JSON", None)),
                     EditingRequestBodyStringCharInput(EventKeyBinding::new(vec![], "Char input", None)),
                 ],
             },
@@ -547,9 +1002,90 @@ impl AppState {
 
                 RequestSettingsMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
                 RequestSettingsMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
-                RequestSettingsToggleSetting(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left, key_bindings.generic.navigation.move_cursor_right], "Toggle setting", Some("Toggle"))),
+                RequestSettingsPreviousValue(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Toggle setting / decrease", Some("Toggle"))),
+                RequestSettingsNextValue(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Toggle setting / increase", None)),
 
                 ModifyRequestSettings(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Confirm", Some("Confirm"))),
+            ],
+            ChoosingRequestUserAgent => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Cancel", Some("Cancel"))),
+
+                RequestUserAgentMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                RequestUserAgentMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+
+                SelectRequestUserAgent(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Select User-Agent", Some("Select"))),
+            ],
+            PreviewingRequest => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back, key_bindings.request_selected.preview_request], "Quit", Some("Quit"))),
+
+                RequestPreviewScrollUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Scroll up", Some("Up"))),
+                RequestPreviewScrollDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Scroll down", Some("Down"))),
+                RequestPreviewScrollLeft(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Scroll left", Some("Left"))),
+                RequestPreviewScrollRight(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Scroll right", Some("Right"))),
+                ToggleLineWrapping(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.toggle_line_wrapping], "Toggle line wrapping", Some("Wrap"))),
+            ],
+            DisplayingJwt => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back, key_bindings.request_selected.decode_jwt], "Quit", Some("Quit"))),
+
+                JwtScrollUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Scroll up", Some("Up"))),
+                JwtScrollDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Scroll down", Some("Down"))),
+                PreviousJwt(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Previous token", Some("Left"))),
+                NextJwt(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Next token", Some("Right"))),
+
+                EditJwtInput(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Paste a token, verify with a secret or a JWKS URL", Some("Paste/Verify"))),
+            ],
+            EditingJwtInput => vec![
+                CancelEditingJwtInput(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ConfirmJwtInput(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                EditingJwtInputDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingJwtInputDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingJwtInputMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingJwtInputMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingJwtInputCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            UsingEncodingTools => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back, key_bindings.request_selected.encoding_tools], "Quit", Some("Quit"))),
+
+                PreviousEncodingTool(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_left], "Previous tool", Some("Left"))),
+                NextEncodingTool(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_right], "Next tool", Some("Right"))),
+                EncodingToolsMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Input or secret", Some("Up"))),
+                EncodingToolsMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Input or secret", Some("Down"))),
+
+                EditEncodingToolsInput(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Edit", Some("Edit"))),
+                CopyEncodingToolResult(EventKeyBinding::new(vec![key_bindings.request_selected.result_tabs.yank_response_part], "Copy result", Some("Copy"))),
+                InsertEncodingToolResult(EventKeyBinding::new(vec![key_bindings.request_selected.insert_tool_result], "Insert result into the field", Some("Insert"))),
+            ],
+            EditingEncodingToolsInput => vec![
+                ConfirmEncodingToolsInput(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm, key_bindings.generic.text_inputs.text_input.cancel], "Confirm", Some("Confirm"))),
+
+                EditingEncodingToolsInputDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                EditingEncodingToolsInputDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                EditingEncodingToolsInputMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                EditingEncodingToolsInputMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                EditingEncodingToolsInputCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            LoadTestingRequest => vec![
+                QuitLoadTest(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back], "Stop and quit", Some("Quit"))),
+                RestartLoadTest(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Restart", Some("Restart"))),
+            ],
+            GoingToLine => vec![
+                CancelGoToLine(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.cancel], "Cancel", Some("Cancel"))),
+                ConfirmGoToLine(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.confirm], "Confirm", Some("Confirm"))),
+
+                GoingToLineDeleteCharBackward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_backward], "Delete char backward", Some("Delete"))),
+                GoingToLineDeleteCharForward(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.delete_forward], "Delete char forward", Some("Backspace"))),
+                GoingToLineMoveCursorLeft(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_left], "Move cursor left", Some("Left"))),
+                GoingToLineMoveCursorRight(EventKeyBinding::new(vec![key_bindings.generic.text_inputs.text_input.move_cursor_right], "Move cursor right", Some("Right"))),
+                GoingToLineCharInput(EventKeyBinding::new(vec![], "Char input", None)),
+            ],
+            ChoosingResponseLink => vec![
+                GoBackToRequestMenu(EventKeyBinding::new(vec![key_bindings.generic.navigation.go_back, key_bindings.request_selected.result_tabs.display_response_links], "Quit", Some("Quit"))),
+
+                ResponseLinksMoveUp(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_up], "Move up", Some("Up"))),
+                ResponseLinksMoveDown(EventKeyBinding::new(vec![key_bindings.generic.navigation.move_cursor_down], "Move down", Some("Down"))),
+
+                FollowResponseLink(EventKeyBinding::new(vec![key_bindings.generic.navigation.select], "Follow link as new request", Some("Follow"))),
             ]
         }
     }
@@ -583,7 +1119,31 @@ lazy_static! {
 
 impl App<'_> {
     pub fn update_current_available_events(&mut self) {
-        *AVAILABLE_EVENTS.write() = self.state.get_available_events(self.request_view, self.request_param_tab);
+        let mut available_events = self.state.get_available_events(self.request_view, self.request_param_tab);
+
+        if self.is_current_collection_read_only() {
+            available_events.retain(|event| !event.is_modifying_collection());
+        }
+
+        // No collection can be created either
+        if ARGS.read_only {
+            available_events.retain(|event| !matches!(event, CreateElement(_)));
+        }
+
+        *AVAILABLE_EVENTS.write() = available_events;
+    }
+
+    /// Collection highlighted in the main menu, or the one of the selected request
+    fn is_current_collection_read_only(&self) -> bool {
+        let collection_index = match self.state {
+            Normal => self.collections_tree.state.selected().first().copied(),
+            _ => self.collections_tree.selected.map(|(collection_index, _)| collection_index)
+        };
+
+        match collection_index {
+            Some(collection_index) => self.is_collection_read_only(collection_index),
+            None => ARGS.read_only
+        }
     }
 
     pub fn get_state_line(&self) -> Line {
@@ -591,16 +1151,22 @@ impl App<'_> {
             Normal |
             ChoosingElementToCreate |
             CreatingNewCollection | CreatingNewRequest |
-            DisplayingCookies | EditingCookies => Line::from(self.state.to_string()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color),
+            DisplayingCookies | EditingCookies | CreatingCookie |
+            DisplayingWebhookListener | DisplayingGitStatus |
+            DisplayingScratchpad | WritingScratchpadNote |
+            DisplayingResponseCache | DisplayingLogs | DisplayingScriptingConsole | DisplayingError | ReplayingMacro |
+            ChoosingEnvironment | DisplayingVariableUsages |
+            RecoveringUnsavedEdit | ReloadingChangedCollection |
+            FilteringCollectionsByTag => Line::from(tr(&self.state.to_string())).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color),
 
             DeletingCollection | RenamingCollection => {
                 let collection_index = self.collections_tree.state.selected()[0];
                 let collection_name = &self.collections[collection_index].name;
 
                 Line::from(vec![
-                    Span::raw("Collection > ").fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(format!("{} > ", tr("Collection"))).fg(THEME.read().ui.secondary_foreground_color),
                     Span::raw(format!("{} > ", collection_name)).fg(THEME.read().ui.secondary_foreground_color),
-                    Span::raw(self.state.to_string()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
+                    Span::raw(tr(&self.state.to_string())).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
                 ])
             },
 
@@ -609,35 +1175,58 @@ impl App<'_> {
                 let selected_request = &self.collections[selected_request_index[0]].requests[selected_request_index[1]].read();
 
                 Line::from(vec![
-                    Span::raw("Request > ").fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(format!("{} > ", tr("Request"))).fg(THEME.read().ui.secondary_foreground_color),
                     Span::raw(format!("{} > ", selected_request.name)).fg(THEME.read().ui.secondary_foreground_color),
-                    Span::raw(self.state.to_string()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
+                    Span::raw(tr(&self.state.to_string())).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
+                ])
+            },
+
+            EditingElementTags => {
+                let selected_element = self.collections_tree.state.selected();
+                let collection = &self.collections[selected_element[0]];
+
+                let (element_type, element_name) = match selected_element.get(1) {
+                    None => (tr("Collection"), collection.name.clone()),
+                    Some(request_index) => (tr("Request"), collection.requests[*request_index].read().name.clone())
+                };
+
+                Line::from(vec![
+                    Span::raw(format!("{element_type} > ")).fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(format!("{element_name} > ")).fg(THEME.read().ui.secondary_foreground_color),
+                    Span::raw(tr(&self.state.to_string())).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
                 ])
             },
 
             SelectedRequest |
             EditingRequestUrl |
             EditingRequestParam |
-            EditingRequestAuthUsername | EditingRequestAuthPassword | EditingRequestAuthBearerToken  |
-            EditingRequestHeader |
+            EditingRequestAuthUsername | EditingRequestAuthPassword | EditingRequestAuthBearerToken | EditingRequestAuthHmac | EditingRequestAuthNtlm | EditingRequestAuthOAuth1 | EditingRequestAuthPlugin |
+            EditingRequestHeader | EditingRequestCookie |
             EditingRequestBodyTable | EditingRequestBodyFile | EditingRequestBodyString |
-            EditingPreRequestScript | EditingPostRequestScript |
-            EditingRequestSettings
+            EditingPreRequestScript | EditingPostRequestScript | EditingResponseProcessor |
+            EditingRequestSettings |
+            ChoosingRequestUserAgent |
+            PreviewingRequest |
+            DisplayingJwt | EditingJwtInput |
+            UsingEncodingTools | EditingEncodingToolsInput |
+            LoadTestingRequest |
+            GoingToLine |
+            ChoosingResponseLink
             => {
                 let local_selected_request = self.get_selected_request_as_local();
                 let selected_request = local_selected_request.read();
 
                 if self.state == SelectedRequest {
                     Line::from(vec![
-                        Span::raw("Request > ").fg(THEME.read().ui.secondary_foreground_color),
+                        Span::raw(format!("{} > ", tr("Request"))).fg(THEME.read().ui.secondary_foreground_color),
                         Span::raw(selected_request.name.clone()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
                     ])
                 }
                 else {
                     Line::from(vec![
-                        Span::raw("Request > ").fg(THEME.read().ui.secondary_foreground_color),
+                        Span::raw(format!("{} > ", tr("Request"))).fg(THEME.read().ui.secondary_foreground_color),
                         Span::raw(format!("{} > ", selected_request.name)).fg(THEME.read().ui.secondary_foreground_color),
-                        Span::raw(self.state.to_string()).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
+                        Span::raw(tr(&self.state.to_string())).fg(THEME.read().ui.font_color).bg(THEME.read().ui.main_background_color)
                     ])
                 }
             }
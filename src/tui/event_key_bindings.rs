@@ -4,6 +4,7 @@ use ratatui::style::{Color, Stylize};
 
 use crate::tui::app_states::EMPTY_KEY;
 use crate::app::files::key_bindings::unique_key_and_help;
+use crate::app::files::locale::tr;
 
 #[derive(Clone)]
 pub struct EventKeyBinding {
@@ -16,10 +17,10 @@ impl EventKeyBinding {
     pub fn new(keys: Vec<KeyCombination>, event_name: &str, short_name: Option<&str>) -> EventKeyBinding {
         EventKeyBinding {
             keys,
-            event_name: String::from(event_name),
+            event_name: tr(event_name),
             short_name: match short_name {
                 None => None,
-                Some(short_name) => Some(String::from(short_name))
+                Some(short_name) => Some(tr(short_name))
             }
         }
     }
@@ -1,3 +1,5 @@
+use std::time::Duration;
+
 use crokey::KeyCombination;
 use crokey::OneToThree::One;
 use ratatui::crossterm::event;
@@ -6,6 +8,7 @@ use tracing::{debug};
 use tui_textarea::CursorMove;
 
 use crate::app::app::App;
+use crate::app::startup::log_layer::APP_LOG;
 use crate::app::files::key_bindings::KEY_BINDINGS;
 use crate::tui::app_states::AVAILABLE_EVENTS;
 use crate::tui::event_key_bindings::EventKeyBinding;
@@ -28,12 +31,27 @@ pub enum AppEvent {
     CreateElement(EventKeyBinding),
     DeleteElement(EventKeyBinding),
     RenameElement(EventKeyBinding),
+    EditElementDescription(EventKeyBinding),
+    EditElementTags(EventKeyBinding),
+    FilterCollectionsByTag(EventKeyBinding),
+    ToggleRequestSkip(EventKeyBinding),
 
     MoveRequestUp(EventKeyBinding),
     MoveRequestDown(EventKeyBinding),
 
     NextEnvironment(EventKeyBinding),
+    PreviousEnvironment(EventKeyBinding),
+    ChooseEnvironment(EventKeyBinding),
+    DisplayVariableUsages(EventKeyBinding),
+    NextWorkspace(EventKeyBinding),
     DisplayCookies(EventKeyBinding),
+    DisplayWebhookListener(EventKeyBinding),
+    DisplayGitStatus(EventKeyBinding),
+    DisplayScratchpad(EventKeyBinding),
+    DisplayResponseCache(EventKeyBinding),
+    ToggleOfflineMode(EventKeyBinding),
+    DisplayLogs(EventKeyBinding),
+    DisplayScriptingConsole(EventKeyBinding),
 
     GoBackToMainMenu(EventKeyBinding),
 
@@ -44,6 +62,95 @@ pub enum AppEvent {
     CookiesMoveLeft(EventKeyBinding),
     CookiesMoveRight(EventKeyBinding),
     DeleteCookie(EventKeyBinding),
+    DeleteDomainCookies(EventKeyBinding),
+    EditCookie(EventKeyBinding),
+    NewCookie(EventKeyBinding),
+
+    CancelEditingCookie(EventKeyBinding),
+    ModifyCookie(EventKeyBinding),
+    CreateCookie(EventKeyBinding),
+    EditingCookieDeleteCharBackward(EventKeyBinding),
+    EditingCookieDeleteCharForward(EventKeyBinding),
+    EditingCookieMoveCursorLeft(EventKeyBinding),
+    EditingCookieMoveCursorRight(EventKeyBinding),
+    EditingCookieCharInput(EventKeyBinding),
+
+    /* Webhook listener */
+
+    WebhookListenerMoveUp(EventKeyBinding),
+    WebhookListenerMoveDown(EventKeyBinding),
+    ToggleWebhookListener(EventKeyBinding),
+    ClearWebhookRequests(EventKeyBinding),
+
+    /* Git */
+
+    GitPull(EventKeyBinding),
+    GitCommit(EventKeyBinding),
+    GitPush(EventKeyBinding),
+
+    /* Scratchpad */
+
+    ScratchpadMoveUp(EventKeyBinding),
+    ScratchpadMoveDown(EventKeyBinding),
+    NewScratchpadNote(EventKeyBinding),
+    PinClipboardToScratchpad(EventKeyBinding),
+    CopyScratchpadEntry(EventKeyBinding),
+    DeleteScratchpadEntry(EventKeyBinding),
+
+    CancelScratchpadNote(EventKeyBinding),
+    PinScratchpadNote(EventKeyBinding),
+    WritingScratchpadNoteDeleteCharBackward(EventKeyBinding),
+    WritingScratchpadNoteDeleteCharForward(EventKeyBinding),
+    WritingScratchpadNoteMoveCursorLeft(EventKeyBinding),
+    WritingScratchpadNoteMoveCursorRight(EventKeyBinding),
+    WritingScratchpadNoteCharInput(EventKeyBinding),
+
+    /* Response cache */
+
+    ResponseCacheMoveUp(EventKeyBinding),
+    ResponseCacheMoveDown(EventKeyBinding),
+    DeleteResponseCacheEntry(EventKeyBinding),
+
+    /* Logs */
+
+    LogsScrollUp(EventKeyBinding),
+    LogsScrollDown(EventKeyBinding),
+    LogsPreviousLevel(EventKeyBinding),
+    LogsNextLevel(EventKeyBinding),
+    ClearLogs(EventKeyBinding),
+
+    /* Scripting console */
+
+    RunConsoleScript(EventKeyBinding),
+    ConsoleHistoryPrevious(EventKeyBinding),
+    ConsoleHistoryNext(EventKeyBinding),
+    ScriptingConsoleDeleteCharBackward(EventKeyBinding),
+    ScriptingConsoleDeleteCharForward(EventKeyBinding),
+    ScriptingConsoleMoveCursorLeft(EventKeyBinding),
+    ScriptingConsoleMoveCursorRight(EventKeyBinding),
+    ScriptingConsoleCharInput(EventKeyBinding),
+
+    /* Errors */
+
+    CloseErrorPopup(EventKeyBinding),
+
+    /* Macros */
+
+    CancelMacroReplay(EventKeyBinding),
+    ConfirmMacroReplay(EventKeyBinding),
+    ReplayingMacroDeleteCharBackward(EventKeyBinding),
+    ReplayingMacroDeleteCharForward(EventKeyBinding),
+    ReplayingMacroMoveCursorLeft(EventKeyBinding),
+    ReplayingMacroMoveCursorRight(EventKeyBinding),
+    ReplayingMacroCharInput(EventKeyBinding),
+
+    /* Environments */
+
+    EnvironmentsMoveUp(EventKeyBinding),
+    EnvironmentsMoveDown(EventKeyBinding),
+    SelectEnvironment(EventKeyBinding),
+    VariableUsagesScrollUp(EventKeyBinding),
+    VariableUsagesScrollDown(EventKeyBinding),
 
     /* Collections */
 
@@ -65,6 +172,7 @@ pub enum AppEvent {
     CreatingRequestMoveCursorRight(EventKeyBinding),
     CreatingRequestSelectCollectionUp(EventKeyBinding),
     CreatingRequestSelectCollectionDown(EventKeyBinding),
+    CreatingRequestNextTemplate(EventKeyBinding),
     CreatingRequestCharInput(EventKeyBinding),
 
     DeletingCollectionMoveCursorLeft(EventKeyBinding),
@@ -75,6 +183,15 @@ pub enum AppEvent {
     DeletingRequestMoveCursorRight(EventKeyBinding),
     DeleteRequest(EventKeyBinding),
 
+    RecoveringEditMoveCursorLeft(EventKeyBinding),
+    RecoveringEditMoveCursorRight(EventKeyBinding),
+    RecoverUnsavedEdit(EventKeyBinding),
+
+    ReloadingCollectionMoveCursorLeft(EventKeyBinding),
+    ReloadingCollectionMoveCursorRight(EventKeyBinding),
+    ReloadChangedCollection(EventKeyBinding),
+    KeepEditingRequest(EventKeyBinding),
+
     RenameCollection(EventKeyBinding),
     RenamingCollectionDeleteCharBackward(EventKeyBinding),
     RenamingCollectionDeleteCharForward(EventKeyBinding),
@@ -88,6 +205,20 @@ pub enum AppEvent {
     RenamingRequestMoveCursorLeft(EventKeyBinding),
     RenamingRequestMoveCursorRight(EventKeyBinding),
     RenamingRequestCharInput(EventKeyBinding),
+
+    ModifyElementTags(EventKeyBinding),
+    EditingTagsDeleteCharBackward(EventKeyBinding),
+    EditingTagsDeleteCharForward(EventKeyBinding),
+    EditingTagsMoveCursorLeft(EventKeyBinding),
+    EditingTagsMoveCursorRight(EventKeyBinding),
+    EditingTagsCharInput(EventKeyBinding),
+
+    ApplyTagFilter(EventKeyBinding),
+    FilteringByTagDeleteCharBackward(EventKeyBinding),
+    FilteringByTagDeleteCharForward(EventKeyBinding),
+    FilteringByTagMoveCursorLeft(EventKeyBinding),
+    FilteringByTagMoveCursorRight(EventKeyBinding),
+    FilteringByTagCharInput(EventKeyBinding),
     
     /* Request */
 
@@ -101,6 +232,10 @@ pub enum AppEvent {
     NextView(EventKeyBinding),
 
     SendRequest(EventKeyBinding),
+    SimulateCors(EventKeyBinding),
+    RefreshRequest(EventKeyBinding),
+    RevalidateRequest(EventKeyBinding),
+    ToggleWatch(EventKeyBinding),
 
     /* Param tabs */
 
@@ -129,6 +264,16 @@ pub enum AppEvent {
     CreateRequestHeader(EventKeyBinding),
     DeleteRequestHeader(EventKeyBinding),
     ToggleRequestHeader(EventKeyBinding),
+    ChooseRequestUserAgent(EventKeyBinding),
+
+    EditRequestCookie(EventKeyBinding),
+    RequestCookiesMoveUp(EventKeyBinding),
+    RequestCookiesMoveDown(EventKeyBinding),
+    RequestCookiesMoveLeft(EventKeyBinding),
+    RequestCookiesMoveRight(EventKeyBinding),
+    CreateRequestCookie(EventKeyBinding),
+    DeleteRequestCookie(EventKeyBinding),
+    ToggleRequestCookie(EventKeyBinding),
 
     EditRequestBody(EventKeyBinding),
     RequestBodyTableMoveUp(EventKeyBinding),
@@ -141,7 +286,12 @@ pub enum AppEvent {
 
     EditRequestScript(EventKeyBinding),
     // Move up or down
-    RequestScriptMove(EventKeyBinding),
+    RequestScriptMoveUp(EventKeyBinding),
+    RequestScriptMoveDown(EventKeyBinding),
+
+    EditRequestDescription(EventKeyBinding),
+    RequestDescriptionScrollUp(EventKeyBinding),
+    RequestDescriptionScrollDown(EventKeyBinding),
 
     /* Result tabs */
 
@@ -155,6 +305,15 @@ pub enum AppEvent {
     /* Others */
 
     CopyResponsePart(EventKeyBinding),
+    PinResponsePart(EventKeyBinding),
+    CycleBodyHighlighting(EventKeyBinding),
+    ToggleResultRecordFold(EventKeyBinding),
+    ToggleResultTableView(EventKeyBinding),
+    ToggleProcessedBody(EventKeyBinding),
+    ToggleLineWrapping(EventKeyBinding),
+    ToggleLineNumbers(EventKeyBinding),
+    GoToLine(EventKeyBinding),
+    DisplayResponseLinks(EventKeyBinding),
 
     /* Request Text inputs */
 
@@ -195,6 +354,34 @@ pub enum AppEvent {
     EditingRequestAuthBearerTokenMoveCursorRight(EventKeyBinding),
     EditingRequestAuthBearerTokenCharInput(EventKeyBinding),
 
+    ModifyRequestAuthHmac(EventKeyBinding),
+    EditingRequestAuthHmacDeleteCharBackward(EventKeyBinding),
+    EditingRequestAuthHmacDeleteCharForward(EventKeyBinding),
+    EditingRequestAuthHmacMoveCursorLeft(EventKeyBinding),
+    EditingRequestAuthHmacMoveCursorRight(EventKeyBinding),
+    EditingRequestAuthHmacCharInput(EventKeyBinding),
+
+    ModifyRequestAuthNtlm(EventKeyBinding),
+    EditingRequestAuthNtlmDeleteCharBackward(EventKeyBinding),
+    EditingRequestAuthNtlmDeleteCharForward(EventKeyBinding),
+    EditingRequestAuthNtlmMoveCursorLeft(EventKeyBinding),
+    EditingRequestAuthNtlmMoveCursorRight(EventKeyBinding),
+    EditingRequestAuthNtlmCharInput(EventKeyBinding),
+
+    ModifyRequestAuthOAuth1(EventKeyBinding),
+    EditingRequestAuthOAuth1DeleteCharBackward(EventKeyBinding),
+    EditingRequestAuthOAuth1DeleteCharForward(EventKeyBinding),
+    EditingRequestAuthOAuth1MoveCursorLeft(EventKeyBinding),
+    EditingRequestAuthOAuth1MoveCursorRight(EventKeyBinding),
+    EditingRequestAuthOAuth1CharInput(EventKeyBinding),
+
+    ModifyRequestAuthPlugin(EventKeyBinding),
+    EditingRequestAuthPluginDeleteCharBackward(EventKeyBinding),
+    EditingRequestAuthPluginDeleteCharForward(EventKeyBinding),
+    EditingRequestAuthPluginMoveCursorLeft(EventKeyBinding),
+    EditingRequestAuthPluginMoveCursorRight(EventKeyBinding),
+    EditingRequestAuthPluginCharInput(EventKeyBinding),
+
     /* Headers */
 
     ModifyRequestHeader(EventKeyBinding),
@@ -203,6 +390,18 @@ pub enum AppEvent {
     EditingRequestHeaderMoveCursorLeft(EventKeyBinding),
     EditingRequestHeaderMoveCursorRight(EventKeyBinding),
     EditingRequestHeaderCharInput(EventKeyBinding),
+    EditingRequestHeaderComplete(EventKeyBinding),
+    EditingRequestHeaderCompletionMoveUp(EventKeyBinding),
+    EditingRequestHeaderCompletionMoveDown(EventKeyBinding),
+
+    /* Request cookies */
+
+    ModifyRequestCookie(EventKeyBinding),
+    EditingRequestCookieDeleteCharBackward(EventKeyBinding),
+    EditingRequestCookieDeleteCharForward(EventKeyBinding),
+    EditingRequestCookieMoveCursorLeft(EventKeyBinding),
+    EditingRequestCookieMoveCursorRight(EventKeyBinding),
+    EditingRequestCookieCharInput(EventKeyBinding),
 
     /* Body */
 
@@ -219,6 +418,9 @@ pub enum AppEvent {
     EditingRequestBodyFileMoveCursorLeft(EventKeyBinding),
     EditingRequestBodyFileMoveCursorRight(EventKeyBinding),
     EditingRequestBodyFileCharInput(EventKeyBinding),
+    EditingRequestBodyFileCompletePath(EventKeyBinding),
+    EditingRequestBodyFileCompletionMoveUp(EventKeyBinding),
+    EditingRequestBodyFileCompletionMoveDown(EventKeyBinding),
 
     EditingRequestBodyStringVimInput(EventKeyBinding),
 
@@ -237,6 +439,7 @@ pub enum AppEvent {
     EditingRequestBodyStringMoveCursorDown(EventKeyBinding),
     EditingRequestBodyStringMoveCursorLeft(EventKeyBinding),
     EditingRequestBodyStringMoveCursorRight(EventKeyBinding),
+    EditingRequestBodyStringFormatJson(EventKeyBinding),
     EditingRequestBodyStringCharInput(EventKeyBinding),
 
     /* Scripts */
@@ -278,24 +481,111 @@ pub enum AppEvent {
     EditingPostRequestScriptMoveCursorLeft(EventKeyBinding),
     EditingPostRequestScriptMoveCursorRight(EventKeyBinding),
     EditingPostRequestScriptCharInput(EventKeyBinding),
+
+    ModifyResponseProcessor(EventKeyBinding),
+    EditingResponseProcessorDeleteCharBackward(EventKeyBinding),
+    EditingResponseProcessorDeleteCharForward(EventKeyBinding),
+    EditingResponseProcessorMoveCursorLeft(EventKeyBinding),
+    EditingResponseProcessorMoveCursorRight(EventKeyBinding),
+    EditingResponseProcessorCharInput(EventKeyBinding),
     
     /* Settings */
 
     RequestSettingsMoveUp(EventKeyBinding),
     RequestSettingsMoveDown(EventKeyBinding),
-    RequestSettingsToggleSetting(EventKeyBinding),
+    RequestSettingsPreviousValue(EventKeyBinding),
+    RequestSettingsNextValue(EventKeyBinding),
     ModifyRequestSettings(EventKeyBinding),
 
+    /* User-Agent */
+
+    RequestUserAgentMoveUp(EventKeyBinding),
+    RequestUserAgentMoveDown(EventKeyBinding),
+    SelectRequestUserAgent(EventKeyBinding),
+
+    /* Preview */
+
+    PreviewRequest(EventKeyBinding),
+    RequestPreviewScrollUp(EventKeyBinding),
+    RequestPreviewScrollDown(EventKeyBinding),
+    RequestPreviewScrollLeft(EventKeyBinding),
+    RequestPreviewScrollRight(EventKeyBinding),
+
+    /* JWT */
+
+    DecodeJwt(EventKeyBinding),
+    JwtScrollUp(EventKeyBinding),
+    JwtScrollDown(EventKeyBinding),
+    PreviousJwt(EventKeyBinding),
+    NextJwt(EventKeyBinding),
+    EditJwtInput(EventKeyBinding),
+
+    CancelEditingJwtInput(EventKeyBinding),
+    ConfirmJwtInput(EventKeyBinding),
+    EditingJwtInputDeleteCharBackward(EventKeyBinding),
+    EditingJwtInputDeleteCharForward(EventKeyBinding),
+    EditingJwtInputMoveCursorLeft(EventKeyBinding),
+    EditingJwtInputMoveCursorRight(EventKeyBinding),
+    EditingJwtInputCharInput(EventKeyBinding),
+
+    /* Encoding tools */
+
+    UseEncodingTools(EventKeyBinding),
+    PreviousEncodingTool(EventKeyBinding),
+    NextEncodingTool(EventKeyBinding),
+    EncodingToolsMoveUp(EventKeyBinding),
+    EncodingToolsMoveDown(EventKeyBinding),
+    EditEncodingToolsInput(EventKeyBinding),
+    CopyEncodingToolResult(EventKeyBinding),
+    InsertEncodingToolResult(EventKeyBinding),
+
+    ConfirmEncodingToolsInput(EventKeyBinding),
+    EditingEncodingToolsInputDeleteCharBackward(EventKeyBinding),
+    EditingEncodingToolsInputDeleteCharForward(EventKeyBinding),
+    EditingEncodingToolsInputMoveCursorLeft(EventKeyBinding),
+    EditingEncodingToolsInputMoveCursorRight(EventKeyBinding),
+    EditingEncodingToolsInputCharInput(EventKeyBinding),
+
+    /* Go to line */
+
+    CancelGoToLine(EventKeyBinding),
+    ConfirmGoToLine(EventKeyBinding),
+    GoingToLineDeleteCharBackward(EventKeyBinding),
+    GoingToLineDeleteCharForward(EventKeyBinding),
+    GoingToLineMoveCursorLeft(EventKeyBinding),
+    GoingToLineMoveCursorRight(EventKeyBinding),
+    GoingToLineCharInput(EventKeyBinding),
+
+    /* Response links */
+
+    ResponseLinksMoveUp(EventKeyBinding),
+    ResponseLinksMoveDown(EventKeyBinding),
+    FollowResponseLink(EventKeyBinding),
+
+    /* Load test */
+
+    LoadTest(EventKeyBinding),
+    QuitLoadTest(EventKeyBinding),
+    RestartLoadTest(EventKeyBinding),
+
     /* Others */
 
-    Documentation(EventKeyBinding)
+    Documentation(EventKeyBinding),
+    DisplayCheatsheet(EventKeyBinding),
+    AbortAll(EventKeyBinding),
 }
 
 impl App<'_> {
     /// Handle events
     pub async fn handle_events(&mut self) {
+        // The replayed keys do not wait for the tick, a pressed key is still read first
+        let timeout = match self.macro_popup.pending_keys.is_empty() {
+            true => self.tick_rate,
+            false => Duration::ZERO
+        };
+
         // Refreshes the app every tick_rate
-        if event::poll(self.tick_rate).unwrap() {
+        if event::poll(timeout).unwrap() {
             // Block while a key is pressed
             if let Event::Key(key_event) = event::read().unwrap() {
                 // We do not need
@@ -311,6 +601,9 @@ impl App<'_> {
                 }
             }
         }
+        else if let Some(key) = self.macro_popup.pending_keys.pop_front() {
+            self.handle_key(key).await;
+        }
     }
 
     async fn handle_key(&mut self, key: KeyCombination) -> bool {
@@ -320,6 +613,18 @@ impl App<'_> {
         {
             let key_bindings = KEY_BINDINGS.read();
 
+            // The macro keys are not recorded, they are available everywhere
+            if key == key_bindings.generic.record_macro {
+                self.tui_toggle_macro_recording();
+                return false;
+            }
+            else if key == key_bindings.generic.replay_macro {
+                self.replay_macro_state();
+                return false;
+            }
+
+            self.record_macro_key(key);
+
             // Help is being displayed
             if self.should_display_help {
                 match key {
@@ -334,12 +639,65 @@ impl App<'_> {
                 // Avoid triggering other keys
                 return false;
             }
+            // Cheatsheet is being displayed, the chars are typed in its search
+            else if self.should_display_cheatsheet {
+                match key {
+                    key if key == key_bindings.generic.text_inputs.text_input.cancel => self.should_display_cheatsheet = false,
+
+                    key if key == key_bindings.generic.navigation.alt_move_cursor_up => self.cheatsheet_popup.scroll_up(),
+                    key if key == key_bindings.generic.navigation.alt_move_cursor_down => self.cheatsheet_popup.scroll_down(),
+
+                    key if key == key_bindings.generic.text_inputs.text_input.delete_backward => self.cheatsheet_popup.search_input.delete_char_forward(),
+                    key if key == key_bindings.generic.text_inputs.text_input.delete_forward => self.cheatsheet_popup.search_input.delete_char_backward(),
+                    key if key == key_bindings.generic.text_inputs.text_input.move_cursor_left => self.cheatsheet_popup.search_input.move_cursor_left(),
+                    key if key == key_bindings.generic.text_inputs.text_input.move_cursor_right => self.cheatsheet_popup.search_input.move_cursor_right(),
+
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => {
+                        self.cheatsheet_popup.search_input.enter_char(char);
+                        self.cheatsheet_popup.vertical_scroll = 0;
+                    },
+
+                    _ => {}
+                }
+
+                // Avoid triggering other keys
+                return false;
+            }
             // Help is not being displayed
             else if key == key_bindings.generic.display_help {
                 self.should_display_help = true;
                 self.help_popup.selection = self.state;
                 return false;
             }
+            // The cheatsheet key could be typed in a text input
+            else if key == key_bindings.generic.display_cheatsheet && !self.is_typing_text() {
+                self.display_cheatsheet();
+                return false;
+            }
+            // Kill-switch
+            else if key == key_bindings.generic.abort_all {
+                self.tui_abort_all();
+                return false;
+            }
+
+            // Environment key completions are being displayed, they take the completion and navigation keys
+            if !self.env_key_completion_popup.choices.is_empty() {
+                match key {
+                    key if key == key_bindings.generic.text_inputs.text_input.complete_env_key => {
+                        self.tui_complete_env_key();
+                        return false;
+                    },
+                    key if key == key_bindings.generic.navigation.move_cursor_up => {
+                        self.env_key_completion_popup.previous();
+                        return false;
+                    },
+                    key if key == key_bindings.generic.navigation.move_cursor_down => {
+                        self.env_key_completion_popup.next();
+                        return false;
+                    },
+                    _ => {}
+                }
+            }
         }
 
         let mut miss_input = false;
@@ -362,7 +720,10 @@ impl App<'_> {
             Some(event) => match event {
                 /* Main menu */
 
-                ExitApp(_) => self.should_quit = true,
+                ExitApp(_) => {
+                    self.save_cookie_jar();
+                    self.should_quit = true
+                },
 
                 MoveCollectionCursorUp(_) => self.collections_tree.up(),
                 MoveCollectionCursorDown(_) => self.collections_tree.down(),
@@ -374,12 +735,27 @@ impl App<'_> {
                 CreateElement(_) => self.choose_element_to_create_state(),
                 DeleteElement(_) => self.delete_element(),
                 RenameElement(_) => self.rename_element(),
+                EditElementDescription(_) => self.tui_edit_element_description(),
+                EditElementTags(_) => self.edit_element_tags_state(),
+                FilterCollectionsByTag(_) => self.filter_collections_by_tag_state(),
+                ToggleRequestSkip(_) => self.tui_toggle_request_skip(),
 
                 MoveRequestUp(_) => self.tui_move_request_up(),
                 MoveRequestDown(_) => self.tui_move_request_down(),
 
                 NextEnvironment(_) => self.tui_next_environment(),
+                PreviousEnvironment(_) => self.tui_previous_environment(),
+                ChooseEnvironment(_) => self.choose_environment_state(),
+                DisplayVariableUsages(_) => self.display_variable_usages_state(),
+                NextWorkspace(_) => self.tui_next_workspace(),
                 DisplayCookies(_) => self.display_cookies_state(),
+                DisplayWebhookListener(_) => self.display_webhook_listener_state(),
+                DisplayGitStatus(_) => self.display_git_status_state(),
+                DisplayScratchpad(_) => self.display_scratchpad_state(),
+                DisplayResponseCache(_) => self.display_response_cache_state(),
+                ToggleOfflineMode(_) => self.toggle_offline_mode(),
+                DisplayLogs(_) => self.display_logs_state(),
+                DisplayScriptingConsole(_) => self.display_scripting_console_state(),
 
                 GoBackToMainMenu(_) => self.normal_state(),
 
@@ -391,6 +767,107 @@ impl App<'_> {
                 CookiesMoveRight(_) => self.cookies_popup.cookies_table.right(),
 
                 DeleteCookie(_) => self.tui_delete_cookie(),
+                DeleteDomainCookies(_) => self.tui_delete_domain_cookies(),
+                EditCookie(_) => self.edit_cookie_state(),
+                NewCookie(_) => self.create_cookie_state(),
+
+                CancelEditingCookie(_) => self.display_cookies_state(),
+                ModifyCookie(_) => self.tui_modify_cookie(),
+                CreateCookie(_) => self.tui_create_cookie(),
+                EditingCookieDeleteCharBackward(_) => self.cookies_popup.cookies_table.selection_text_input.delete_char_forward(),
+                EditingCookieDeleteCharForward(_) => self.cookies_popup.cookies_table.selection_text_input.delete_char_backward(),
+                EditingCookieMoveCursorLeft(_) => self.cookies_popup.cookies_table.selection_text_input.move_cursor_left(),
+                EditingCookieMoveCursorRight(_) => self.cookies_popup.cookies_table.selection_text_input.move_cursor_right(),
+                EditingCookieCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.cookies_popup.cookies_table.selection_text_input.enter_char(char),
+                    _ => {}
+                },
+
+                /* Webhook listener */
+
+                WebhookListenerMoveUp(_) => self.webhook_listener_popup.previous(),
+                WebhookListenerMoveDown(_) => self.webhook_listener_popup.next(),
+                ToggleWebhookListener(_) => self.tui_toggle_webhook_listener(),
+                ClearWebhookRequests(_) => self.tui_clear_webhook_requests(),
+
+                /* Git */
+
+                GitPull(_) => self.tui_git_pull(),
+                GitCommit(_) => self.tui_git_commit(),
+                GitPush(_) => self.tui_git_push(),
+
+                /* Scratchpad */
+
+                ScratchpadMoveUp(_) => self.scratchpad_popup.previous(),
+                ScratchpadMoveDown(_) => self.scratchpad_popup.next(),
+                NewScratchpadNote(_) => self.write_scratchpad_note_state(),
+                PinClipboardToScratchpad(_) => self.tui_pin_clipboard_to_scratchpad(),
+                CopyScratchpadEntry(_) => self.tui_copy_scratchpad_entry(),
+                DeleteScratchpadEntry(_) => self.tui_delete_scratchpad_entry(),
+
+                CancelScratchpadNote(_) => self.display_scratchpad_state(),
+                PinScratchpadNote(_) => self.tui_pin_scratchpad_note(),
+                WritingScratchpadNoteDeleteCharBackward(_) => self.scratchpad_popup.note_input.delete_char_forward(),
+                WritingScratchpadNoteDeleteCharForward(_) => self.scratchpad_popup.note_input.delete_char_backward(),
+                WritingScratchpadNoteMoveCursorLeft(_) => self.scratchpad_popup.note_input.move_cursor_left(),
+                WritingScratchpadNoteMoveCursorRight(_) => self.scratchpad_popup.note_input.move_cursor_right(),
+                WritingScratchpadNoteCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.scratchpad_popup.note_input.enter_char(char),
+                    _ => {}
+                },
+
+                /* Response cache */
+
+                ResponseCacheMoveUp(_) => self.response_cache_popup.previous(),
+                ResponseCacheMoveDown(_) => self.response_cache_popup.next(),
+                DeleteResponseCacheEntry(_) => self.response_cache_popup.delete_selected_entry(),
+
+                /* Logs */
+
+                LogsScrollUp(_) => self.logs_popup.scroll_up(),
+                LogsScrollDown(_) => self.logs_popup.scroll_down(),
+                LogsPreviousLevel(_) => self.logs_popup.previous_level(),
+                LogsNextLevel(_) => self.logs_popup.next_level(),
+                ClearLogs(_) => APP_LOG.write().clear(),
+
+                /* Scripting console */
+
+                RunConsoleScript(_) => self.tui_run_console_script().await,
+                ConsoleHistoryPrevious(_) => self.scripting_console_popup.previous_history_entry(),
+                ConsoleHistoryNext(_) => self.scripting_console_popup.next_history_entry(),
+                ScriptingConsoleDeleteCharBackward(_) => self.scripting_console_popup.input.delete_char_forward(),
+                ScriptingConsoleDeleteCharForward(_) => self.scripting_console_popup.input.delete_char_backward(),
+                ScriptingConsoleMoveCursorLeft(_) => self.scripting_console_popup.input.move_cursor_left(),
+                ScriptingConsoleMoveCursorRight(_) => self.scripting_console_popup.input.move_cursor_right(),
+                ScriptingConsoleCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.scripting_console_popup.input.enter_char(char),
+                    _ => {}
+                },
+
+                /* Errors */
+
+                CloseErrorPopup(_) => self.close_error_popup(),
+
+                /* Macros */
+
+                CancelMacroReplay(_) => self.tui_cancel_macro_replay(),
+                ConfirmMacroReplay(_) => self.tui_replay_macro(),
+                ReplayingMacroDeleteCharBackward(_) => self.macro_popup.count_input.delete_char_forward(),
+                ReplayingMacroDeleteCharForward(_) => self.macro_popup.count_input.delete_char_backward(),
+                ReplayingMacroMoveCursorLeft(_) => self.macro_popup.count_input.move_cursor_left(),
+                ReplayingMacroMoveCursorRight(_) => self.macro_popup.count_input.move_cursor_right(),
+                ReplayingMacroCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } if char.is_ascii_digit() => self.macro_popup.count_input.enter_char(char),
+                    _ => {}
+                },
+
+                /* Environments */
+
+                EnvironmentsMoveUp(_) => self.environment_popup.previous(),
+                EnvironmentsMoveDown(_) => self.environment_popup.next(),
+                SelectEnvironment(_) => self.tui_select_environment(),
+                VariableUsagesScrollUp(_) => self.variable_usages_popup.scroll_up(),
+                VariableUsagesScrollDown(_) => self.variable_usages_popup.scroll_down(),
 
                 /* Collections */
 
@@ -415,6 +892,7 @@ impl App<'_> {
                 CreatingRequestMoveCursorRight(_) => self.new_request_popup.text_input.move_cursor_right(),
                 CreatingRequestSelectCollectionUp(_) => self.new_request_popup.previous_collection(),
                 CreatingRequestSelectCollectionDown(_) => self.new_request_popup.next_collection(),
+                CreatingRequestNextTemplate(_) => self.new_request_popup.next_template(),
                 CreatingRequestCharInput(_) => match key {
                     KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.new_request_popup.text_input.enter_char(char),
                     _ => {}
@@ -434,6 +912,21 @@ impl App<'_> {
                     false => self.normal_state(),
                 },
 
+                RecoveringEditMoveCursorLeft(_) => self.recover_edit_popup.change_state(),
+                RecoveringEditMoveCursorRight(_) => self.recover_edit_popup.change_state(),
+                RecoverUnsavedEdit(_) => match self.recover_edit_popup.state {
+                    true => self.tui_recover_unsaved_edit(),
+                    false => self.tui_discard_unsaved_edit(),
+                },
+
+                ReloadingCollectionMoveCursorLeft(_) => self.reload_collection_popup.change_state(),
+                ReloadingCollectionMoveCursorRight(_) => self.reload_collection_popup.change_state(),
+                ReloadChangedCollection(_) => match self.reload_collection_popup.state {
+                    true => self.tui_reload_changed_collection(),
+                    false => self.tui_keep_editing_request(),
+                },
+                KeepEditingRequest(_) => self.tui_keep_editing_request(),
+
                 RenameCollection(_) => self.tui_rename_collection(),
                 RenamingCollectionDeleteCharBackward(_) => self.rename_collection_input.delete_char_forward(),
                 RenamingCollectionDeleteCharForward(_) => self.rename_collection_input.delete_char_backward(),
@@ -453,6 +946,26 @@ impl App<'_> {
                     KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.rename_request_input.enter_char(char),
                     _ => {}
                 },
+
+                ModifyElementTags(_) => self.tui_modify_element_tags(),
+                EditingTagsDeleteCharBackward(_) => self.edit_tags_input.delete_char_forward(),
+                EditingTagsDeleteCharForward(_) => self.edit_tags_input.delete_char_backward(),
+                EditingTagsMoveCursorLeft(_) => self.edit_tags_input.move_cursor_left(),
+                EditingTagsMoveCursorRight(_) => self.edit_tags_input.move_cursor_right(),
+                EditingTagsCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.edit_tags_input.enter_char(char),
+                    _ => {}
+                },
+
+                ApplyTagFilter(_) => self.tui_apply_tag_filter(),
+                FilteringByTagDeleteCharBackward(_) => self.tag_filter_input.delete_char_forward(),
+                FilteringByTagDeleteCharForward(_) => self.tag_filter_input.delete_char_backward(),
+                FilteringByTagMoveCursorLeft(_) => self.tag_filter_input.move_cursor_left(),
+                FilteringByTagMoveCursorRight(_) => self.tag_filter_input.move_cursor_right(),
+                FilteringByTagCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.tag_filter_input.enter_char(char),
+                    _ => {}
+                },
                 
                 /* Selected Request */
 
@@ -464,6 +977,14 @@ impl App<'_> {
 
                 NextView(_) => self.next_request_view(),
                 SendRequest(_) => self.tui_send_request().await,
+                SimulateCors(_) => self.tui_simulate_cors().await,
+                RefreshRequest(_) => self.tui_refresh_request().await,
+                RevalidateRequest(_) => self.tui_revalidate_request().await,
+                ToggleWatch(_) => self.tui_toggle_request_watch(),
+                LoadTest(_) => {
+                    self.load_test_request_state();
+                    self.tui_start_load_test().await;
+                },
 
                 /* Param tabs */
 
@@ -506,14 +1027,33 @@ impl App<'_> {
                 CreateRequestHeader(_) => self.tui_create_new_header(),
                 DeleteRequestHeader(_) => self.tui_delete_header(),
                 ToggleRequestHeader(_) => self.tui_toggle_header(),
+                ChooseRequestUserAgent(_) => self.choose_request_user_agent_state(),
+
+                EditRequestCookie(_) => match self.request_cookies_table.is_selected() {
+                    true => self.edit_request_cookie_state(),
+                    false => {}
+                },
+                RequestCookiesMoveUp(_) => self.request_cookies_table.up(),
+                RequestCookiesMoveDown(_) => self.request_cookies_table.down(),
+                RequestCookiesMoveLeft(_) | RequestCookiesMoveRight(_) => self.request_cookies_table.change_y(),
+                CreateRequestCookie(_) => self.tui_create_new_request_cookie(),
+                DeleteRequestCookie(_) => self.tui_delete_request_cookie(),
+                ToggleRequestCookie(_) => self.tui_toggle_request_cookie(),
 
                 EditRequestBody(_) => match self.body_form_table.is_selected() {
-                    true => self.edit_request_body_table_state(),
+                    true => self.tui_edit_request_body_table(),
                     false => self.edit_request_body_file_or_string_state(),
                 },
-                RequestBodyTableMoveUp(_) => self.body_form_table.up(),
-                RequestBodyTableMoveDown(_) => self.body_form_table.down(),
-                RequestBodyTableMoveLeft(_) | RequestBodyTableMoveRight(_) => self.body_form_table.change_y(),
+                RequestBodyTableMoveUp(_) => match self.body_form_table.is_selected() {
+                    true => self.body_form_table.up(),
+                    false => self.body_file_text_input_selection.previous(),
+                },
+                RequestBodyTableMoveDown(_) => match self.body_form_table.is_selected() {
+                    true => self.body_form_table.down(),
+                    false => self.body_file_text_input_selection.next(),
+                },
+                RequestBodyTableMoveLeft(_) => self.tui_move_request_body_table_column(false),
+                RequestBodyTableMoveRight(_) => self.tui_move_request_body_table_column(true),
                 CreateRequestBodyTableElement(_) => self.tui_create_new_form_data(),
                 DeleteRequestBodyTableElement(_) => self.tui_delete_form_data(),
                 ToggleRequestBodyTableElement(_) => self.tui_toggle_form_data(),
@@ -521,7 +1061,14 @@ impl App<'_> {
                 /* Scripts */
 
                 EditRequestScript(_) => self.edit_request_script_state(),
-                RequestScriptMove(_) => self.script_console.change_selection(),
+                RequestScriptMoveUp(_) => self.script_console.previous_selection(),
+                RequestScriptMoveDown(_) => self.script_console.next_selection(),
+
+                /* Docs */
+
+                EditRequestDescription(_) => self.tui_edit_request_description(),
+                RequestDescriptionScrollUp(_) => self.request_description_scroll = self.request_description_scroll.saturating_sub(1),
+                RequestDescriptionScrollDown(_) => self.request_description_scroll = self.request_description_scroll.saturating_add(1),
 
                 /* Result tabs */
 
@@ -535,6 +1082,15 @@ impl App<'_> {
                 /* Others */
 
                 CopyResponsePart(_) => self.copy_response_body_content_to_clipboard(),
+                PinResponsePart(_) => self.tui_pin_response_part(),
+                CycleBodyHighlighting(_) => self.tui_cycle_body_highlighting_language(),
+                ToggleResultRecordFold(_) => self.tui_toggle_result_record_fold(),
+                ToggleResultTableView(_) => self.tui_toggle_result_table_view(),
+                ToggleProcessedBody(_) => self.tui_toggle_processed_body(),
+                ToggleLineWrapping(_) => self.tui_toggle_line_wrapping(),
+                ToggleLineNumbers(_) => self.should_display_line_numbers = !self.should_display_line_numbers,
+                GoToLine(_) => self.go_to_line_state(),
+                DisplayResponseLinks(_) => self.display_response_links_state(),
 
                 /* Request text inputs */
 
@@ -592,15 +1148,83 @@ impl App<'_> {
                     _ => {}
                 },
 
+                ModifyRequestAuthHmac(_) => self.tui_modify_request_auth_hmac(),
+                EditingRequestAuthHmacDeleteCharBackward(_) => self.get_selected_auth_hmac_text_input().delete_char_forward(),
+                EditingRequestAuthHmacDeleteCharForward(_) => self.get_selected_auth_hmac_text_input().delete_char_backward(),
+                EditingRequestAuthHmacMoveCursorLeft(_) => self.get_selected_auth_hmac_text_input().move_cursor_left(),
+                EditingRequestAuthHmacMoveCursorRight(_) => self.get_selected_auth_hmac_text_input().move_cursor_right(),
+                EditingRequestAuthHmacCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.get_selected_auth_hmac_text_input().enter_char(char),
+                    _ => {}
+                },
+
+                ModifyRequestAuthNtlm(_) => self.tui_modify_request_auth_ntlm(),
+                EditingRequestAuthNtlmDeleteCharBackward(_) => self.get_selected_auth_ntlm_text_input().delete_char_forward(),
+                EditingRequestAuthNtlmDeleteCharForward(_) => self.get_selected_auth_ntlm_text_input().delete_char_backward(),
+                EditingRequestAuthNtlmMoveCursorLeft(_) => self.get_selected_auth_ntlm_text_input().move_cursor_left(),
+                EditingRequestAuthNtlmMoveCursorRight(_) => self.get_selected_auth_ntlm_text_input().move_cursor_right(),
+                EditingRequestAuthNtlmCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.get_selected_auth_ntlm_text_input().enter_char(char),
+                    _ => {}
+                },
+
+                ModifyRequestAuthOAuth1(_) => self.tui_modify_request_auth_oauth1(),
+                EditingRequestAuthOAuth1DeleteCharBackward(_) => self.get_selected_auth_oauth1_text_input().delete_char_forward(),
+                EditingRequestAuthOAuth1DeleteCharForward(_) => self.get_selected_auth_oauth1_text_input().delete_char_backward(),
+                EditingRequestAuthOAuth1MoveCursorLeft(_) => self.get_selected_auth_oauth1_text_input().move_cursor_left(),
+                EditingRequestAuthOAuth1MoveCursorRight(_) => self.get_selected_auth_oauth1_text_input().move_cursor_right(),
+                EditingRequestAuthOAuth1CharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.get_selected_auth_oauth1_text_input().enter_char(char),
+                    _ => {}
+                },
+
+                ModifyRequestAuthPlugin(_) => self.tui_modify_request_auth_plugin(),
+                EditingRequestAuthPluginDeleteCharBackward(_) => self.get_selected_auth_plugin_text_input().delete_char_forward(),
+                EditingRequestAuthPluginDeleteCharForward(_) => self.get_selected_auth_plugin_text_input().delete_char_backward(),
+                EditingRequestAuthPluginMoveCursorLeft(_) => self.get_selected_auth_plugin_text_input().move_cursor_left(),
+                EditingRequestAuthPluginMoveCursorRight(_) => self.get_selected_auth_plugin_text_input().move_cursor_right(),
+                EditingRequestAuthPluginCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.get_selected_auth_plugin_text_input().enter_char(char),
+                    _ => {}
+                },
+
                 /* Header */
 
                 ModifyRequestHeader(_) => self.tui_modify_request_header(),
-                EditingRequestHeaderDeleteCharBackward(_) => self.headers_table.selection_text_input.delete_char_forward(),
-                EditingRequestHeaderDeleteCharForward(_) => self.headers_table.selection_text_input.delete_char_backward(),
+                EditingRequestHeaderDeleteCharBackward(_) => {
+                    self.headers_table.selection_text_input.delete_char_forward();
+                    self.tui_update_header_completions();
+                },
+                EditingRequestHeaderDeleteCharForward(_) => {
+                    self.headers_table.selection_text_input.delete_char_backward();
+                    self.tui_update_header_completions();
+                },
                 EditingRequestHeaderMoveCursorLeft(_) => self.headers_table.selection_text_input.move_cursor_left(),
                 EditingRequestHeaderMoveCursorRight(_) => self.headers_table.selection_text_input.move_cursor_right(),
                 EditingRequestHeaderCharInput(_) => match key {
-                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.headers_table.selection_text_input.enter_char(char),
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => {
+                        self.headers_table.selection_text_input.enter_char(char);
+                        self.tui_update_header_completions();
+                    },
+                    _ => {}
+                },
+                EditingRequestHeaderComplete(_) => self.tui_complete_header(),
+                EditingRequestHeaderCompletionMoveUp(_) => if !self.header_completion_popup.choices.is_empty() {
+                    self.header_completion_popup.previous();
+                },
+                EditingRequestHeaderCompletionMoveDown(_) => if !self.header_completion_popup.choices.is_empty() {
+                    self.header_completion_popup.next();
+                },
+
+                /* Request cookies */
+
+                ModifyRequestCookie(_) => self.tui_modify_request_cookie(),
+                EditingRequestCookieDeleteCharBackward(_) => self.request_cookies_table.selection_text_input.delete_char_forward(),
+                EditingRequestCookieDeleteCharForward(_) => self.request_cookies_table.selection_text_input.delete_char_backward(),
+                EditingRequestCookieMoveCursorLeft(_) => self.request_cookies_table.selection_text_input.move_cursor_left(),
+                EditingRequestCookieMoveCursorRight(_) => self.request_cookies_table.selection_text_input.move_cursor_right(),
+                EditingRequestCookieCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.request_cookies_table.selection_text_input.enter_char(char),
                     _ => {}
                 },
 
@@ -616,15 +1240,31 @@ impl App<'_> {
                     _ => {}
                 },
                 
-                ModifyRequestBodyFile(_) => self.tui_next_request_body(),
-                EditingRequestBodyFileDeleteCharBackward(_) => self.body_file_text_input.delete_char_forward(),
-                EditingRequestBodyFileDeleteCharForward(_) => self.body_file_text_input.delete_char_backward(),
-                EditingRequestBodyFileMoveCursorLeft(_) => self.body_file_text_input.move_cursor_left(),
-                EditingRequestBodyFileMoveCursorRight(_) => self.body_file_text_input.move_cursor_right(),
+                ModifyRequestBodyFile(_) => self.tui_modify_request_body_file(),
+                EditingRequestBodyFileDeleteCharBackward(_) => {
+                    self.get_selected_body_file_text_input().delete_char_forward();
+                    self.tui_update_body_file_completions();
+                },
+                EditingRequestBodyFileDeleteCharForward(_) => {
+                    self.get_selected_body_file_text_input().delete_char_backward();
+                    self.tui_update_body_file_completions();
+                },
+                EditingRequestBodyFileMoveCursorLeft(_) => self.get_selected_body_file_text_input().move_cursor_left(),
+                EditingRequestBodyFileMoveCursorRight(_) => self.get_selected_body_file_text_input().move_cursor_right(),
                 EditingRequestBodyFileCharInput(_) => match key {
-                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.body_file_text_input.enter_char(char),
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => {
+                        self.get_selected_body_file_text_input().enter_char(char);
+                        self.tui_update_body_file_completions();
+                    },
                     _ => {}
                 },
+                EditingRequestBodyFileCompletePath(_) => self.tui_complete_body_file_path(),
+                EditingRequestBodyFileCompletionMoveUp(_) => if !self.body_file_completion_popup.choices.is_empty() {
+                    self.body_file_completion_popup.previous();
+                },
+                EditingRequestBodyFileCompletionMoveDown(_) => if !self.body_file_completion_popup.choices.is_empty() {
+                    self.body_file_completion_popup.next();
+                },
 
                 EditingRequestBodyStringVimInput(_) => match self.body_text_area_vim_emulation.transition(key, &mut self.body_text_area) {
                     VimTransition::Mode(mode) if self.body_text_area_vim_emulation.mode != mode => {
@@ -660,6 +1300,7 @@ impl App<'_> {
                 EditingRequestBodyStringMoveCursorDown(_) => self.body_text_area.move_cursor(CursorMove::Bottom),
                 EditingRequestBodyStringMoveCursorLeft(_) => self.body_text_area.move_cursor(CursorMove::Back),
                 EditingRequestBodyStringMoveCursorRight(_) => self.body_text_area.move_cursor(CursorMove::Forward),
+                EditingRequestBodyStringFormatJson(_) => self.tui_format_json_body(),
                 EditingRequestBodyStringCharInput(_) => match key {
                     KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.body_text_area.insert_char(char),
                     _ => {}
@@ -744,20 +1385,114 @@ impl App<'_> {
                     KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.script_console.post_request_text_area.insert_char(char),
                     _ => {}
                 },
+
+                ModifyResponseProcessor(_) => self.tui_modify_response_processor(),
+                EditingResponseProcessorDeleteCharBackward(_) => self.script_console.response_processor_text_input.delete_char_forward(),
+                EditingResponseProcessorDeleteCharForward(_) => self.script_console.response_processor_text_input.delete_char_backward(),
+                EditingResponseProcessorMoveCursorLeft(_) => self.script_console.response_processor_text_input.move_cursor_left(),
+                EditingResponseProcessorMoveCursorRight(_) => self.script_console.response_processor_text_input.move_cursor_right(),
+                EditingResponseProcessorCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.script_console.response_processor_text_input.enter_char(char),
+                    _ => {}
+                },
                 
                 /* Settings */
 
                 RequestSettingsMoveUp(_) => self.request_settings_popup.previous(),
                 RequestSettingsMoveDown(_) => self.request_settings_popup.next(),
-                RequestSettingsToggleSetting(_) => self.request_settings_popup.toggle_setting(),
+                RequestSettingsPreviousValue(_) => self.request_settings_popup.previous_value(),
+                RequestSettingsNextValue(_) => self.request_settings_popup.next_value(),
                 ModifyRequestSettings(_) => self.tui_modify_request_settings(),
 
+                /* User-Agent */
+
+                RequestUserAgentMoveUp(_) => self.user_agent_popup.previous(),
+                RequestUserAgentMoveDown(_) => self.user_agent_popup.next(),
+                SelectRequestUserAgent(_) => self.tui_select_request_user_agent(),
+
+                /* Preview */
+
+                PreviewRequest(_) => self.preview_request_state(),
+                RequestPreviewScrollUp(_) => self.request_preview_popup.scroll_up(),
+                RequestPreviewScrollDown(_) => self.request_preview_popup.scroll_down(),
+                RequestPreviewScrollLeft(_) => self.request_preview_popup.scroll_left(),
+                RequestPreviewScrollRight(_) => self.request_preview_popup.scroll_right(),
+
+                /* JWT */
+
+                DecodeJwt(_) => self.display_jwt_state(),
+                JwtScrollUp(_) => self.jwt_popup.scroll_up(),
+                JwtScrollDown(_) => self.jwt_popup.scroll_down(),
+                PreviousJwt(_) => self.tui_previous_jwt(),
+                NextJwt(_) => self.tui_next_jwt(),
+                EditJwtInput(_) => self.edit_jwt_input_state(),
+
+                CancelEditingJwtInput(_) => self.tui_cancel_jwt_input(),
+                ConfirmJwtInput(_) => self.tui_confirm_jwt_input().await,
+                EditingJwtInputDeleteCharBackward(_) => self.jwt_popup.input.delete_char_forward(),
+                EditingJwtInputDeleteCharForward(_) => self.jwt_popup.input.delete_char_backward(),
+                EditingJwtInputMoveCursorLeft(_) => self.jwt_popup.input.move_cursor_left(),
+                EditingJwtInputMoveCursorRight(_) => self.jwt_popup.input.move_cursor_right(),
+                EditingJwtInputCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.jwt_popup.input.enter_char(char),
+                    _ => {}
+                },
+
+                /* Encoding tools */
+
+                UseEncodingTools(_) => self.use_encoding_tools_state(),
+                PreviousEncodingTool(_) => self.encoding_tools_popup.previous_tool(),
+                NextEncodingTool(_) => self.encoding_tools_popup.next_tool(),
+                EncodingToolsMoveUp(_) | EncodingToolsMoveDown(_) => self.encoding_tools_popup.toggle_selected_input(),
+                EditEncodingToolsInput(_) => self.edit_encoding_tools_input_state(),
+                CopyEncodingToolResult(_) => self.tui_copy_encoding_tool_result(),
+                InsertEncodingToolResult(_) => self.tui_insert_encoding_tool_result(),
+
+                ConfirmEncodingToolsInput(_) => self.tui_confirm_encoding_tools_input(),
+                EditingEncodingToolsInputDeleteCharBackward(_) => self.encoding_tools_popup.get_selected_input().delete_char_forward(),
+                EditingEncodingToolsInputDeleteCharForward(_) => self.encoding_tools_popup.get_selected_input().delete_char_backward(),
+                EditingEncodingToolsInputMoveCursorLeft(_) => self.encoding_tools_popup.get_selected_input().move_cursor_left(),
+                EditingEncodingToolsInputMoveCursorRight(_) => self.encoding_tools_popup.get_selected_input().move_cursor_right(),
+                EditingEncodingToolsInputCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } => self.encoding_tools_popup.get_selected_input().enter_char(char),
+                    _ => {}
+                },
+
+                /* Go to line */
+
+                CancelGoToLine(_) => self.tui_quit_go_to_line(),
+                ConfirmGoToLine(_) => self.tui_go_to_line(),
+                GoingToLineDeleteCharBackward(_) => self.go_to_line_popup.input.delete_char_forward(),
+                GoingToLineDeleteCharForward(_) => self.go_to_line_popup.input.delete_char_backward(),
+                GoingToLineMoveCursorLeft(_) => self.go_to_line_popup.input.move_cursor_left(),
+                GoingToLineMoveCursorRight(_) => self.go_to_line_popup.input.move_cursor_right(),
+                GoingToLineCharInput(_) => match key {
+                    KeyCombination { codes: One(KeyCode::Char(char)), .. } if char.is_ascii_digit() => self.go_to_line_popup.input.enter_char(char),
+                    _ => {}
+                },
+
+                /* Response links */
+
+                ResponseLinksMoveUp(_) => self.response_links_popup.previous(),
+                ResponseLinksMoveDown(_) => self.response_links_popup.next(),
+                FollowResponseLink(_) => self.tui_follow_response_link(),
+
+                /* Load test */
+
+                QuitLoadTest(_) => self.tui_quit_load_test(),
+                RestartLoadTest(_) => self.tui_start_load_test().await,
+
                 /* Others */
 
                 Documentation(_) => {}
+                DisplayCheatsheet(_) => {}
+                AbortAll(_) => self.tui_abort_all(),
             }
         };
 
+        // The key may have typed "{{", moved the cursor or left the input
+        self.tui_update_env_key_completions();
+
         return miss_input;
     }
 }
@@ -774,16 +1509,91 @@ impl AppEvent {
             CreateElement(event_key_bindings) |
             DeleteElement(event_key_bindings) |
             RenameElement(event_key_bindings) |
+            EditElementDescription(event_key_bindings) |
+            EditElementTags(event_key_bindings) |
+            FilterCollectionsByTag(event_key_bindings) |
+            ToggleRequestSkip(event_key_bindings) |
             MoveRequestUp(event_key_bindings) |
             MoveRequestDown(event_key_bindings) |
             NextEnvironment(event_key_bindings) |
+            PreviousEnvironment(event_key_bindings) |
+            ChooseEnvironment(event_key_bindings) |
+            DisplayVariableUsages(event_key_bindings) |
+            NextWorkspace(event_key_bindings) |
             DisplayCookies(event_key_bindings) |
+            DisplayWebhookListener(event_key_bindings) |
+            DisplayGitStatus(event_key_bindings) |
+            DisplayScratchpad(event_key_bindings) |
+            DisplayResponseCache(event_key_bindings) |
+            ToggleOfflineMode(event_key_bindings) |
+            DisplayLogs(event_key_bindings) |
+            DisplayScriptingConsole(event_key_bindings) |
             GoBackToMainMenu(event_key_bindings) |
             CookiesMoveUp(event_key_bindings) |
             CookiesMoveDown(event_key_bindings) |
             CookiesMoveLeft(event_key_bindings) |
             CookiesMoveRight(event_key_bindings) |
             DeleteCookie(event_key_bindings) |
+            DeleteDomainCookies(event_key_bindings) |
+            EditCookie(event_key_bindings) |
+            NewCookie(event_key_bindings) |
+            CancelEditingCookie(event_key_bindings) |
+            ModifyCookie(event_key_bindings) |
+            CreateCookie(event_key_bindings) |
+            EditingCookieDeleteCharBackward(event_key_bindings) |
+            EditingCookieDeleteCharForward(event_key_bindings) |
+            EditingCookieMoveCursorLeft(event_key_bindings) |
+            EditingCookieMoveCursorRight(event_key_bindings) |
+            EditingCookieCharInput(event_key_bindings) |
+            WebhookListenerMoveUp(event_key_bindings) |
+            WebhookListenerMoveDown(event_key_bindings) |
+            ToggleWebhookListener(event_key_bindings) |
+            ClearWebhookRequests(event_key_bindings) |
+            GitPull(event_key_bindings) |
+            GitCommit(event_key_bindings) |
+            GitPush(event_key_bindings) |
+            ScratchpadMoveUp(event_key_bindings) |
+            ScratchpadMoveDown(event_key_bindings) |
+            NewScratchpadNote(event_key_bindings) |
+            PinClipboardToScratchpad(event_key_bindings) |
+            CopyScratchpadEntry(event_key_bindings) |
+            DeleteScratchpadEntry(event_key_bindings) |
+            CancelScratchpadNote(event_key_bindings) |
+            PinScratchpadNote(event_key_bindings) |
+            WritingScratchpadNoteDeleteCharBackward(event_key_bindings) |
+            WritingScratchpadNoteDeleteCharForward(event_key_bindings) |
+            WritingScratchpadNoteMoveCursorLeft(event_key_bindings) |
+            WritingScratchpadNoteMoveCursorRight(event_key_bindings) |
+            WritingScratchpadNoteCharInput(event_key_bindings) |
+            ResponseCacheMoveUp(event_key_bindings) |
+            ResponseCacheMoveDown(event_key_bindings) |
+            DeleteResponseCacheEntry(event_key_bindings) |
+            LogsScrollUp(event_key_bindings) |
+            LogsScrollDown(event_key_bindings) |
+            LogsPreviousLevel(event_key_bindings) |
+            LogsNextLevel(event_key_bindings) |
+            ClearLogs(event_key_bindings) |
+            RunConsoleScript(event_key_bindings) |
+            ConsoleHistoryPrevious(event_key_bindings) |
+            ConsoleHistoryNext(event_key_bindings) |
+            ScriptingConsoleDeleteCharBackward(event_key_bindings) |
+            ScriptingConsoleDeleteCharForward(event_key_bindings) |
+            ScriptingConsoleMoveCursorLeft(event_key_bindings) |
+            ScriptingConsoleMoveCursorRight(event_key_bindings) |
+            ScriptingConsoleCharInput(event_key_bindings) |
+            CloseErrorPopup(event_key_bindings) |
+            CancelMacroReplay(event_key_bindings) |
+            ConfirmMacroReplay(event_key_bindings) |
+            ReplayingMacroDeleteCharBackward(event_key_bindings) |
+            ReplayingMacroDeleteCharForward(event_key_bindings) |
+            ReplayingMacroMoveCursorLeft(event_key_bindings) |
+            ReplayingMacroMoveCursorRight(event_key_bindings) |
+            ReplayingMacroCharInput(event_key_bindings) |
+            EnvironmentsMoveUp(event_key_bindings) |
+            EnvironmentsMoveDown(event_key_bindings) |
+            SelectEnvironment(event_key_bindings) |
+            VariableUsagesScrollUp(event_key_bindings) |
+            VariableUsagesScrollDown(event_key_bindings) |
             ChooseElementToCreateMoveCursorLeft(event_key_bindings) |
             ChooseElementToCreateMoveCursorRight(event_key_bindings) |
             SelectElementToCreate(event_key_bindings) |
@@ -800,6 +1610,7 @@ impl AppEvent {
             CreatingRequestMoveCursorRight(event_key_bindings) |
             CreatingRequestSelectCollectionUp(event_key_bindings) |
             CreatingRequestSelectCollectionDown(event_key_bindings) |
+            CreatingRequestNextTemplate(event_key_bindings) |
             CreatingRequestCharInput(event_key_bindings) |
             DeletingCollectionMoveCursorLeft(event_key_bindings) |
             DeletingCollectionMoveCursorRight(event_key_bindings) |
@@ -807,6 +1618,13 @@ impl AppEvent {
             DeletingRequestMoveCursorLeft(event_key_bindings) |
             DeletingRequestMoveCursorRight(event_key_bindings) |
             DeleteRequest(event_key_bindings) |
+            RecoveringEditMoveCursorLeft(event_key_bindings) |
+            RecoveringEditMoveCursorRight(event_key_bindings) |
+            RecoverUnsavedEdit(event_key_bindings) |
+            ReloadingCollectionMoveCursorLeft(event_key_bindings) |
+            ReloadingCollectionMoveCursorRight(event_key_bindings) |
+            ReloadChangedCollection(event_key_bindings) |
+            KeepEditingRequest(event_key_bindings) |
             RenameCollection(event_key_bindings) |
             RenamingCollectionDeleteCharBackward(event_key_bindings) |
             RenamingCollectionDeleteCharForward(event_key_bindings) |
@@ -819,12 +1637,28 @@ impl AppEvent {
             RenamingRequestMoveCursorLeft(event_key_bindings) |
             RenamingRequestMoveCursorRight(event_key_bindings) |
             RenamingRequestCharInput(event_key_bindings) |
+            ModifyElementTags(event_key_bindings) |
+            EditingTagsDeleteCharBackward(event_key_bindings) |
+            EditingTagsDeleteCharForward(event_key_bindings) |
+            EditingTagsMoveCursorLeft(event_key_bindings) |
+            EditingTagsMoveCursorRight(event_key_bindings) |
+            EditingTagsCharInput(event_key_bindings) |
+            ApplyTagFilter(event_key_bindings) |
+            FilteringByTagDeleteCharBackward(event_key_bindings) |
+            FilteringByTagDeleteCharForward(event_key_bindings) |
+            FilteringByTagMoveCursorLeft(event_key_bindings) |
+            FilteringByTagMoveCursorRight(event_key_bindings) |
+            FilteringByTagCharInput(event_key_bindings) |
             GoBackToRequestMenu(event_key_bindings) |
             EditUrl(event_key_bindings) |
             EditMethod(event_key_bindings) |
             EditSettings(event_key_bindings) |
             NextView(event_key_bindings) |
             SendRequest(event_key_bindings) |
+            SimulateCors(event_key_bindings) |
+            RefreshRequest(event_key_bindings) |
+            RevalidateRequest(event_key_bindings) |
+            ToggleWatch(event_key_bindings) |
             NextParamTab(event_key_bindings) |
             ModifyRequestAuthMethod(event_key_bindings) |
             ModifyRequestBodyContentType(event_key_bindings) |
@@ -847,6 +1681,15 @@ impl AppEvent {
             CreateRequestHeader(event_key_bindings) |
             DeleteRequestHeader(event_key_bindings) |
             ToggleRequestHeader(event_key_bindings) |
+            ChooseRequestUserAgent(event_key_bindings) |
+            EditRequestCookie(event_key_bindings) |
+            RequestCookiesMoveUp(event_key_bindings) |
+            RequestCookiesMoveDown(event_key_bindings) |
+            RequestCookiesMoveLeft(event_key_bindings) |
+            RequestCookiesMoveRight(event_key_bindings) |
+            CreateRequestCookie(event_key_bindings) |
+            DeleteRequestCookie(event_key_bindings) |
+            ToggleRequestCookie(event_key_bindings) |
             EditRequestBody(event_key_bindings) |
             RequestBodyTableMoveUp(event_key_bindings) |
             RequestBodyTableMoveDown(event_key_bindings) |
@@ -856,13 +1699,26 @@ impl AppEvent {
             DeleteRequestBodyTableElement(event_key_bindings) |
             ToggleRequestBodyTableElement(event_key_bindings) |
             EditRequestScript(event_key_bindings) |
-            RequestScriptMove(event_key_bindings) |
+            RequestScriptMoveUp(event_key_bindings) |
+            RequestScriptMoveDown(event_key_bindings) |
+            EditRequestDescription(event_key_bindings) |
+            RequestDescriptionScrollUp(event_key_bindings) |
+            RequestDescriptionScrollDown(event_key_bindings) |
             NextResultTab(event_key_bindings) |
             ScrollResultUp(event_key_bindings) |
             ScrollResultDown(event_key_bindings) |
             ScrollResultLeft(event_key_bindings) |
             ScrollResultRight(event_key_bindings) |
             CopyResponsePart(event_key_bindings) |
+            PinResponsePart(event_key_bindings) |
+            CycleBodyHighlighting(event_key_bindings) |
+            ToggleResultRecordFold(event_key_bindings) |
+            ToggleResultTableView(event_key_bindings) |
+            ToggleProcessedBody(event_key_bindings) |
+            ToggleLineWrapping(event_key_bindings) |
+            ToggleLineNumbers(event_key_bindings) |
+            GoToLine(event_key_bindings) |
+            DisplayResponseLinks(event_key_bindings) |
             ModifyRequestUrl(event_key_bindings) |
             EditingRequestUrlDeleteCharBackward(event_key_bindings) |
             EditingRequestUrlDeleteCharForward(event_key_bindings) |
@@ -893,12 +1749,45 @@ impl AppEvent {
             EditingRequestAuthBearerTokenMoveCursorLeft(event_key_bindings) |
             EditingRequestAuthBearerTokenMoveCursorRight(event_key_bindings) |
             EditingRequestAuthBearerTokenCharInput(event_key_bindings) |
+            ModifyRequestAuthHmac(event_key_bindings) |
+            EditingRequestAuthHmacDeleteCharBackward(event_key_bindings) |
+            EditingRequestAuthHmacDeleteCharForward(event_key_bindings) |
+            EditingRequestAuthHmacMoveCursorLeft(event_key_bindings) |
+            EditingRequestAuthHmacMoveCursorRight(event_key_bindings) |
+            EditingRequestAuthHmacCharInput(event_key_bindings) |
+            ModifyRequestAuthNtlm(event_key_bindings) |
+            EditingRequestAuthNtlmDeleteCharBackward(event_key_bindings) |
+            EditingRequestAuthNtlmDeleteCharForward(event_key_bindings) |
+            EditingRequestAuthNtlmMoveCursorLeft(event_key_bindings) |
+            EditingRequestAuthNtlmMoveCursorRight(event_key_bindings) |
+            EditingRequestAuthNtlmCharInput(event_key_bindings) |
+            ModifyRequestAuthOAuth1(event_key_bindings) |
+            EditingRequestAuthOAuth1DeleteCharBackward(event_key_bindings) |
+            EditingRequestAuthOAuth1DeleteCharForward(event_key_bindings) |
+            EditingRequestAuthOAuth1MoveCursorLeft(event_key_bindings) |
+            EditingRequestAuthOAuth1MoveCursorRight(event_key_bindings) |
+            EditingRequestAuthOAuth1CharInput(event_key_bindings) |
+            ModifyRequestAuthPlugin(event_key_bindings) |
+            EditingRequestAuthPluginDeleteCharBackward(event_key_bindings) |
+            EditingRequestAuthPluginDeleteCharForward(event_key_bindings) |
+            EditingRequestAuthPluginMoveCursorLeft(event_key_bindings) |
+            EditingRequestAuthPluginMoveCursorRight(event_key_bindings) |
+            EditingRequestAuthPluginCharInput(event_key_bindings) |
             ModifyRequestHeader(event_key_bindings) |
             EditingRequestHeaderDeleteCharBackward(event_key_bindings) |
             EditingRequestHeaderDeleteCharForward(event_key_bindings) |
             EditingRequestHeaderMoveCursorLeft(event_key_bindings) |
             EditingRequestHeaderMoveCursorRight(event_key_bindings) |
             EditingRequestHeaderCharInput(event_key_bindings) |
+            EditingRequestHeaderComplete(event_key_bindings) |
+            EditingRequestHeaderCompletionMoveUp(event_key_bindings) |
+            EditingRequestHeaderCompletionMoveDown(event_key_bindings) |
+            ModifyRequestCookie(event_key_bindings) |
+            EditingRequestCookieDeleteCharBackward(event_key_bindings) |
+            EditingRequestCookieDeleteCharForward(event_key_bindings) |
+            EditingRequestCookieMoveCursorLeft(event_key_bindings) |
+            EditingRequestCookieMoveCursorRight(event_key_bindings) |
+            EditingRequestCookieCharInput(event_key_bindings) |
             ModifyRequestBodyTable(event_key_bindings) |
             EditingRequestBodyTableDeleteCharBackward(event_key_bindings) |
             EditingRequestBodyTableDeleteCharForward(event_key_bindings) |
@@ -911,6 +1800,9 @@ impl AppEvent {
             EditingRequestBodyFileMoveCursorLeft(event_key_bindings) |
             EditingRequestBodyFileMoveCursorRight(event_key_bindings) |
             EditingRequestBodyFileCharInput(event_key_bindings) |
+            EditingRequestBodyFileCompletePath(event_key_bindings) |
+            EditingRequestBodyFileCompletionMoveUp(event_key_bindings) |
+            EditingRequestBodyFileCompletionMoveDown(event_key_bindings) |
             EditingRequestBodyStringVimInput(event_key_bindings) |
             EditingRequestBodyStringCopy(event_key_bindings) |
             EditingRequestBodyStringPaste(event_key_bindings) |
@@ -927,6 +1819,7 @@ impl AppEvent {
             EditingRequestBodyStringMoveCursorDown(event_key_bindings) |
             EditingRequestBodyStringMoveCursorLeft(event_key_bindings) |
             EditingRequestBodyStringMoveCursorRight(event_key_bindings) |
+            EditingRequestBodyStringFormatJson(event_key_bindings) |
             EditingRequestBodyStringCharInput(event_key_bindings) |
             EditingPreRequestScriptVimInput(event_key_bindings) |
             EditingPreRequestScriptCopy(event_key_bindings) |
@@ -962,12 +1855,108 @@ impl AppEvent {
             EditingPostRequestScriptMoveCursorLeft(event_key_bindings) |
             EditingPostRequestScriptMoveCursorRight(event_key_bindings) |
             EditingPostRequestScriptCharInput(event_key_bindings) |
+            ModifyResponseProcessor(event_key_bindings) |
+            EditingResponseProcessorDeleteCharBackward(event_key_bindings) |
+            EditingResponseProcessorDeleteCharForward(event_key_bindings) |
+            EditingResponseProcessorMoveCursorLeft(event_key_bindings) |
+            EditingResponseProcessorMoveCursorRight(event_key_bindings) |
+            EditingResponseProcessorCharInput(event_key_bindings) |
             RequestSettingsMoveUp(event_key_bindings) |
             RequestSettingsMoveDown(event_key_bindings) |
-            RequestSettingsToggleSetting(event_key_bindings) |
+            RequestSettingsPreviousValue(event_key_bindings) |
+            RequestSettingsNextValue(event_key_bindings) |
             ModifyRequestSettings(event_key_bindings) |
-            Documentation(event_key_bindings)
+            RequestUserAgentMoveUp(event_key_bindings) |
+            RequestUserAgentMoveDown(event_key_bindings) |
+            SelectRequestUserAgent(event_key_bindings) |
+            PreviewRequest(event_key_bindings) |
+            RequestPreviewScrollUp(event_key_bindings) |
+            RequestPreviewScrollDown(event_key_bindings) |
+            RequestPreviewScrollLeft(event_key_bindings) |
+            RequestPreviewScrollRight(event_key_bindings) |
+            DecodeJwt(event_key_bindings) |
+            JwtScrollUp(event_key_bindings) |
+            JwtScrollDown(event_key_bindings) |
+            PreviousJwt(event_key_bindings) |
+            NextJwt(event_key_bindings) |
+            EditJwtInput(event_key_bindings) |
+            CancelEditingJwtInput(event_key_bindings) |
+            ConfirmJwtInput(event_key_bindings) |
+            EditingJwtInputDeleteCharBackward(event_key_bindings) |
+            EditingJwtInputDeleteCharForward(event_key_bindings) |
+            EditingJwtInputMoveCursorLeft(event_key_bindings) |
+            EditingJwtInputMoveCursorRight(event_key_bindings) |
+            EditingJwtInputCharInput(event_key_bindings) |
+            UseEncodingTools(event_key_bindings) |
+            PreviousEncodingTool(event_key_bindings) |
+            NextEncodingTool(event_key_bindings) |
+            EncodingToolsMoveUp(event_key_bindings) |
+            EncodingToolsMoveDown(event_key_bindings) |
+            EditEncodingToolsInput(event_key_bindings) |
+            CopyEncodingToolResult(event_key_bindings) |
+            InsertEncodingToolResult(event_key_bindings) |
+            ConfirmEncodingToolsInput(event_key_bindings) |
+            EditingEncodingToolsInputDeleteCharBackward(event_key_bindings) |
+            EditingEncodingToolsInputDeleteCharForward(event_key_bindings) |
+            EditingEncodingToolsInputMoveCursorLeft(event_key_bindings) |
+            EditingEncodingToolsInputMoveCursorRight(event_key_bindings) |
+            EditingEncodingToolsInputCharInput(event_key_bindings) |
+            CancelGoToLine(event_key_bindings) |
+            ConfirmGoToLine(event_key_bindings) |
+            GoingToLineDeleteCharBackward(event_key_bindings) |
+            GoingToLineDeleteCharForward(event_key_bindings) |
+            GoingToLineMoveCursorLeft(event_key_bindings) |
+            GoingToLineMoveCursorRight(event_key_bindings) |
+            GoingToLineCharInput(event_key_bindings) |
+            ResponseLinksMoveUp(event_key_bindings) |
+            ResponseLinksMoveDown(event_key_bindings) |
+            FollowResponseLink(event_key_bindings) |
+            LoadTest(event_key_bindings) |
+            QuitLoadTest(event_key_bindings) |
+            RestartLoadTest(event_key_bindings) |
+            Documentation(event_key_bindings) |
+            DisplayCheatsheet(event_key_bindings) |
+            AbortAll(event_key_bindings)
             => event_key_bindings,
         }
     }
-}
\ No newline at end of file
+
+    /// Events leading to a modification of the highlighted or selected collection
+    pub fn is_modifying_collection(&self) -> bool {
+        matches!(
+            self,
+            DeleteElement(_) |
+            RenameElement(_) |
+            EditElementDescription(_) |
+            EditElementTags(_) |
+            ToggleRequestSkip(_) |
+            MoveRequestUp(_) |
+            MoveRequestDown(_) |
+            EditUrl(_) |
+            EditMethod(_) |
+            EditSettings(_) |
+            ModifyRequestAuthMethod(_) |
+            ModifyRequestBodyContentType(_) |
+            EditRequestQueryParam(_) |
+            CreateRequestQueryParam(_) |
+            DeleteRequestQueryParam(_) |
+            ToggleRequestQueryParam(_) |
+            EditRequestAuth(_) |
+            EditRequestHeader(_) |
+            CreateRequestHeader(_) |
+            DeleteRequestHeader(_) |
+            ToggleRequestHeader(_) |
+            ChooseRequestUserAgent(_) |
+            EditRequestCookie(_) |
+            CreateRequestCookie(_) |
+            DeleteRequestCookie(_) |
+            ToggleRequestCookie(_) |
+            EditRequestBody(_) |
+            CreateRequestBodyTableElement(_) |
+            DeleteRequestBodyTableElement(_) |
+            ToggleRequestBodyTableElement(_) |
+            EditRequestScript(_) |
+            EditRequestDescription(_)
+        )
+    }
+}
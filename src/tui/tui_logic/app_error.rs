@@ -0,0 +1,26 @@
+use crate::app::app::App;
+use crate::app::business_logic::app_error::PENDING_ERRORS;
+use crate::tui::app_states::AppState;
+
+impl App<'_> {
+    /// Errors are queued by the file writes and the background tasks, and displayed one after the other
+    pub fn tui_display_pending_error(&mut self) {
+        if self.state == AppState::DisplayingError {
+            return;
+        }
+
+        let error = match PENDING_ERRORS.write().pop_front() {
+            None => return,
+            Some(error) => error
+        };
+
+        self.error_popup.error = Some(error);
+        self.error_popup.previous_state = self.state;
+        self.state = AppState::DisplayingError;
+    }
+
+    pub fn close_error_popup(&mut self) {
+        self.error_popup.error = None;
+        self.state = self.error_popup.previous_state;
+    }
+}
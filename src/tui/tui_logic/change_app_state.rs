@@ -1,10 +1,12 @@
-use std::sync::Arc;
+use tracing::warn;
 
 use crate::app::app::App;
+use crate::app::startup::log_layer::APP_LOG;
 use crate::models::body::ContentType;
+use crate::models::user_agent::USER_AGENT_PRESETS;
 use crate::tui::app_states::AppState;
 use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
-use crate::tui::utils::stateful::cookie_table::cookie_to_row;
+use crate::tui::utils::stateful::go_to_line_popup::GoToLineTarget;
 
 impl App<'_> {
     pub fn normal_state(&mut self) {
@@ -12,21 +14,72 @@ impl App<'_> {
     }
 
     pub fn display_cookies_state(&mut self) {
-        let local_cookie_store = Arc::clone(&self.cookies_popup.cookie_store);
+        self.cookies_popup.error = None;
+        self.tui_refresh_cookies_table();
+        self.state = AppState::DisplayingCookies;
+    }
+
+    pub fn display_webhook_listener_state(&mut self) {
+        self.state = AppState::DisplayingWebhookListener;
+    }
+
+    pub fn display_git_status_state(&mut self) {
+        self.git_popup.last_output = None;
+        self.tui_refresh_git_status();
+        self.state = AppState::DisplayingGitStatus;
+    }
+
+    pub fn display_scratchpad_state(&mut self) {
+        self.state = AppState::DisplayingScratchpad;
+    }
+
+    pub fn display_response_cache_state(&mut self) {
+        self.response_cache_popup.selection = 0;
+        self.state = AppState::DisplayingResponseCache;
+    }
+
+    pub fn display_logs_state(&mut self) {
+        APP_LOG.write().unseen_errors = 0;
+        self.logs_popup.vertical_scroll = 0;
+        self.state = AppState::DisplayingLogs;
+    }
+
+    pub fn display_scripting_console_state(&mut self) {
+        self.scripting_console_popup.input.reset_input();
+        self.scripting_console_popup.history_index = None;
+        self.state = AppState::DisplayingScriptingConsole;
+    }
 
-        self.cookies_popup.cookies_table.rows = vec![];
+    pub fn write_scratchpad_note_state(&mut self) {
+        self.scratchpad_popup.note_input.reset_input();
+        self.state = AppState::WritingScratchpadNote;
+    }
 
-        for cookie in local_cookie_store.read().unwrap().iter_any() {
-            self.cookies_popup.cookies_table.rows.push(cookie_to_row(cookie))
+    pub fn choose_environment_state(&mut self) {
+        if self.environments.is_empty() {
+            return;
         }
 
-        self.tui_update_cookies_table_selection();
-        self.state = AppState::DisplayingCookies;
+        self.environment_popup.choices = self.environments
+            .iter()
+            .map(|environment| environment.read().name.clone())
+            .collect();
+        self.environment_popup.selection = self.selected_environment;
+
+        self.state = AppState::ChoosingEnvironment;
+    }
+
+    pub fn display_variable_usages_state(&mut self) {
+        self.variable_usages_popup.usages = self.get_variable_usages();
+        self.variable_usages_popup.vertical_scroll = 0;
+        self.state = AppState::DisplayingVariableUsages;
     }
 
-    #[allow(dead_code)]
     pub fn edit_cookie_state(&mut self) {
-        let selection = self.cookies_popup.cookies_table.selection.unwrap();
+        let selection = match self.cookies_popup.cookies_table.selection {
+            None => return,
+            Some(selection) => selection
+        };
 
         let input_text = self.cookies_popup.cookies_table.rows[selection.0][selection.1].clone();
 
@@ -34,9 +87,16 @@ impl App<'_> {
         self.cookies_popup.cookies_table.selection_text_input.enter_str(&input_text);
         self.cookies_popup.cookies_table.selection_text_input.cursor_position = input_text.len();
 
+        self.cookies_popup.error = None;
         self.state = AppState::EditingCookies;
     }
 
+    pub fn create_cookie_state(&mut self) {
+        self.cookies_popup.cookies_table.selection_text_input.reset_input();
+        self.cookies_popup.error = None;
+        self.state = AppState::CreatingCookie;
+    }
+
     pub fn choose_element_to_create_state(&mut self) {
         self.creation_popup.selection = 0;
         
@@ -72,6 +132,8 @@ impl App<'_> {
         
         self.new_request_popup.selected_collection = popup_selected_collection_index;
         self.new_request_popup.max_selection = collections_length;
+        self.new_request_popup.selected_template = None;
+        self.new_request_popup.max_template_selection = self.request_templates.len();
         self.state = AppState::CreatingNewRequest;
     }
 
@@ -80,6 +142,18 @@ impl App<'_> {
         self.state = AppState::DeletingCollection;
     }
 
+    pub fn recover_unsaved_edit_state(&mut self) {
+        self.recover_edit_popup.state = true;
+        self.state = AppState::RecoveringUnsavedEdit;
+    }
+
+    pub fn reload_changed_collection_state(&mut self, collection_index: usize) {
+        self.changed_collection = Some((collection_index, self.state));
+        // Keeping the edit is the safe choice
+        self.reload_collection_popup.state = false;
+        self.state = AppState::ReloadingChangedCollection;
+    }
+
     pub fn delete_request_state(&mut self) {
         self.delete_request_popup.state = false;
         self.state = AppState::DeletingRequest;
@@ -106,6 +180,31 @@ impl App<'_> {
 
         self.state = AppState::RenamingRequest;
     }
+
+    /// Tags of the collection or request highlighted in the collections list
+    pub fn edit_element_tags_state(&mut self) {
+        let selected_element = self.collections_tree.state.selected().to_vec();
+
+        let tags = match selected_element.len() {
+            // Selection on a collection
+            1 => self.collections[selected_element[0]].tags.clone(),
+            // Selection on a request
+            2 => self.collections[selected_element[0]].requests[selected_element[1]].read().tags.clone(),
+            _ => return
+        };
+
+        self.edit_tags_input.text = tags.join(", ");
+        self.edit_tags_input.cursor_position = self.edit_tags_input.text.len();
+
+        self.state = AppState::EditingElementTags;
+    }
+
+    pub fn filter_collections_by_tag_state(&mut self) {
+        self.tag_filter_input.text = self.tag_filter.join(", ");
+        self.tag_filter_input.cursor_position = self.tag_filter_input.text.len();
+
+        self.state = AppState::FilteringCollectionsByTag;
+    }
     
     pub fn select_request_state(&mut self) {
         self.state = AppState::SelectedRequest;
@@ -138,9 +237,35 @@ impl App<'_> {
         self.update_inputs();
     }
 
+    pub fn edit_request_auth_hmac_state(&mut self) {
+        self.state = AppState::EditingRequestAuthHmac;
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_ntlm_state(&mut self) {
+        self.state = AppState::EditingRequestAuthNtlm;
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_oauth1_state(&mut self) {
+        self.state = AppState::EditingRequestAuthOAuth1;
+        self.update_inputs();
+    }
+
+    pub fn edit_request_auth_plugin_state(&mut self) {
+        self.state = AppState::EditingRequestAuthPlugin;
+        self.update_inputs();
+    }
+
     pub fn edit_request_header_state(&mut self) {
         self.state = AppState::EditingRequestHeader;
         self.update_inputs();
+        self.tui_update_header_completions();
+    }
+
+    pub fn edit_request_cookie_state(&mut self) {
+        self.state = AppState::EditingRequestCookie;
+        self.update_inputs();
     }
 
     pub fn edit_request_body_table_state(&mut self) {
@@ -173,7 +298,7 @@ impl App<'_> {
                 ContentType::File(_) => {
                     self.state = AppState::EditingRequestBodyFile;
                 }
-                ContentType::Raw(_) | ContentType::Json(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) => {
+                ContentType::Raw(_) | ContentType::Json(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) | ContentType::Protobuf(_) | ContentType::MessagePack(_) | ContentType::Cbor(_) => {
                     self.state = AppState::EditingRequestBodyString;
                 }
                 _ => {
@@ -184,6 +309,7 @@ impl App<'_> {
 
         self.request_param_tab = RequestParamsTabs::Body;
         self.update_inputs();
+        self.tui_update_body_file_completions();
     }
 
     pub fn edit_request_script_state(&mut self) {
@@ -192,6 +318,7 @@ impl App<'_> {
         match self.script_console.script_selection {
             0 => self.state = AppState::EditingPreRequestScript,
             1 => self.state = AppState::EditingPostRequestScript,
+            2 => self.state = AppState::EditingResponseProcessor,
             _ => {}
         }
         
@@ -208,4 +335,116 @@ impl App<'_> {
 
         self.state = AppState::EditingRequestSettings;
     }
+
+    pub fn preview_request_state(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        self.request_preview_popup.preview = self.get_request_preview(&selected_request);
+        self.request_preview_popup.vertical_scroll = 0;
+        self.request_preview_popup.horizontal_scroll = 0;
+
+        drop(selected_request);
+
+        self.state = AppState::PreviewingRequest;
+    }
+
+    pub fn display_jwt_state(&mut self) {
+        self.jwt_popup.jwts = self.find_jwts();
+        self.jwt_popup.selection = 0;
+        self.tui_decode_selected_jwt();
+
+        self.state = AppState::DisplayingJwt;
+    }
+
+    pub fn edit_jwt_input_state(&mut self) {
+        self.state = AppState::EditingJwtInput;
+    }
+
+    /// The input is prefilled with the selected field, which the result can replace
+    pub fn use_encoding_tools_state(&mut self) {
+        let (target, text) = self.get_encoding_tools_target();
+
+        self.encoding_tools_popup.target = Some(target);
+        self.encoding_tools_popup.input.reset_input();
+        self.encoding_tools_popup.input.enter_str(&text);
+        self.encoding_tools_popup.is_secret_selected = false;
+
+        self.state = AppState::UsingEncodingTools;
+    }
+
+    pub fn edit_encoding_tools_input_state(&mut self) {
+        self.state = AppState::EditingEncodingToolsInput;
+    }
+
+    /// Preselect the preset matching the current User-Agent header if any
+    pub fn choose_request_user_agent_state(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+
+        {
+            let selected_request = local_selected_request.read();
+
+            let current_user_agent = selected_request.headers
+                .iter()
+                .find(|header| header.data.0.eq_ignore_ascii_case("user-agent"))
+                .map(|header| header.data.1.clone());
+
+            self.user_agent_popup.selection = USER_AGENT_PRESETS
+                .iter()
+                .position(|(_, value)| Some(value) == current_user_agent.as_ref())
+                .unwrap_or(0);
+        }
+
+        self.state = AppState::ChoosingRequestUserAgent;
+    }
+
+    pub fn load_test_request_state(&mut self) {
+        self.state = AppState::LoadTestingRequest;
+    }
+
+    /// Opened from the request body editor, or from the result body
+    pub fn go_to_line_state(&mut self) {
+        let (target, lines_count) = match self.state {
+            AppState::EditingRequestBodyString => (GoToLineTarget::RequestBody, self.body_text_area.lines().len()),
+            _ => match self.get_result_body_lines_count() {
+                Some(lines_count) => (GoToLineTarget::Result, lines_count),
+                None => return
+            }
+        };
+
+        self.go_to_line_popup.target = target;
+        self.go_to_line_popup.lines_count = lines_count;
+        self.go_to_line_popup.input.reset_input();
+
+        self.state = AppState::GoingToLine;
+    }
+
+    pub fn display_response_links_state(&mut self) {
+        self.response_links_popup.links = self.find_selected_response_links();
+        self.response_links_popup.selection = 0;
+        self.response_links_popup.vertical_scroll = 0;
+
+        self.state = AppState::ChoosingResponseLink;
+    }
+
+    /// Not while recording, the macro would replay itself
+    pub fn replay_macro_state(&mut self) {
+        if self.state == AppState::ReplayingMacro || !self.macro_popup.pending_keys.is_empty() {
+            return;
+        }
+
+        if self.macro_popup.recording.is_some() {
+            warn!("A macro cannot be replayed while being recorded");
+            return;
+        }
+
+        if self.macro_popup.keys.is_empty() {
+            warn!("No macro recorded in this workspace");
+            return;
+        }
+
+        self.macro_popup.previous_state = self.state;
+        self.macro_popup.count_input.reset_input();
+        self.state = AppState::ReplayingMacro;
+    }
 }
\ No newline at end of file
@@ -0,0 +1,21 @@
+use crate::app::app::App;
+use crate::tui::app_states::AVAILABLE_EVENTS;
+use crate::tui::utils::stateful::cheatsheet_popup::get_cheatsheet_groups;
+
+impl App<'_> {
+    /// Generated from the key bindings each time, the remapped keys are displayed
+    pub fn display_cheatsheet(&mut self) {
+        self.cheatsheet_popup.groups = get_cheatsheet_groups();
+        self.cheatsheet_popup.search_input.reset_input();
+        self.cheatsheet_popup.vertical_scroll = 0;
+
+        self.should_display_cheatsheet = true;
+    }
+
+    /// A char input or a text area takes every key without a binding
+    pub fn is_typing_text(&self) -> bool {
+        return AVAILABLE_EVENTS.read()
+            .iter()
+            .any(|event| event.get_event_key_bindings().keys.is_empty());
+    }
+}
@@ -1,19 +1,40 @@
 use crate::app::app::App;
+use crate::cli::args::ARGS;
 use crate::models::auth::Auth;
-use crate::models::body::ContentType;
+use crate::models::body::{ContentType, MultipartPart};
 use crate::models::request::{Request, DEFAULT_HEADERS};
 use crate::models::settings::RequestSettings;
 
 impl App<'_> {
+    /// Read-only collections, or every collection with --read-only, cannot be modified from the TUI
+    pub fn is_collection_read_only(&self, collection_index: usize) -> bool {
+        return ARGS.read_only || self.collections[collection_index].read_only;
+    }
+
     pub fn reset_inputs(&mut self) {
         self.url_text_input.reset_input();
         self.query_params_table.selection_text_input.reset_input();
         self.auth_basic_username_text_input.reset_input();
         self.auth_basic_password_text_input.reset_input();
         self.auth_bearer_token_text_input.reset_input();
+        self.auth_hmac_secret_text_input.reset_input();
+        self.auth_hmac_string_to_sign_text_input.reset_input();
+        self.auth_hmac_header_text_input.reset_input();
+        self.auth_ntlm_username_text_input.reset_input();
+        self.auth_ntlm_password_text_input.reset_input();
+        self.auth_ntlm_domain_text_input.reset_input();
+        self.auth_oauth1_consumer_key_text_input.reset_input();
+        self.auth_oauth1_consumer_secret_text_input.reset_input();
+        self.auth_oauth1_token_text_input.reset_input();
+        self.auth_oauth1_token_secret_text_input.reset_input();
+        self.auth_plugin_name_text_input.reset_input();
+        self.auth_plugin_config_text_input.reset_input();
         self.headers_table.selection_text_input.reset_input();
+        self.request_cookies_table.selection_text_input.reset_input();
         self.body_form_table.selection_text_input.reset_input();
         self.body_file_text_input.reset_input();
+        self.body_file_content_type_text_input.reset_input();
+        self.body_file_preview = None;
     }
 
     pub fn update_inputs(&mut self) {
@@ -25,6 +46,7 @@ impl App<'_> {
         self.url_text_input.enter_str(&selected_request.url_with_params_to_string());
         self.query_params_table.rows = selected_request.params.clone();
         self.headers_table.rows = selected_request.headers.clone();
+        self.request_cookies_table.rows = selected_request.cookies.clone();
 
         if !selected_request.params.is_empty() {
             let selection = self.query_params_table.selection.unwrap();
@@ -56,6 +78,40 @@ impl App<'_> {
 
                 self.auth_bearer_token_text_input.enter_str(bearer_token);
             }
+            Auth::HmacSignature { secret, string_to_sign, header, .. } => {
+                // Secret, string to sign, header, algorithm and encoding
+                self.auth_text_input_selection.max_selection = 5;
+                self.auth_text_input_selection.usable = true;
+
+                self.auth_hmac_secret_text_input.enter_str(secret);
+                self.auth_hmac_string_to_sign_text_input.enter_str(string_to_sign);
+                self.auth_hmac_header_text_input.enter_str(header);
+            }
+            Auth::Ntlm { username, password, domain } => {
+                self.auth_text_input_selection.max_selection = 3;
+                self.auth_text_input_selection.usable = true;
+
+                self.auth_ntlm_username_text_input.enter_str(username);
+                self.auth_ntlm_password_text_input.enter_str(password);
+                self.auth_ntlm_domain_text_input.enter_str(domain);
+            }
+            Auth::OAuth1 { consumer_key, consumer_secret, token, token_secret, .. } => {
+                // Consumer key and secret, token and secret, signature method and placement
+                self.auth_text_input_selection.max_selection = 6;
+                self.auth_text_input_selection.usable = true;
+
+                self.auth_oauth1_consumer_key_text_input.enter_str(consumer_key);
+                self.auth_oauth1_consumer_secret_text_input.enter_str(consumer_secret);
+                self.auth_oauth1_token_text_input.enter_str(token);
+                self.auth_oauth1_token_secret_text_input.enter_str(token_secret);
+            }
+            Auth::Plugin { plugin, config } => {
+                self.auth_text_input_selection.max_selection = 2;
+                self.auth_text_input_selection.usable = true;
+
+                self.auth_plugin_name_text_input.enter_str(plugin);
+                self.auth_plugin_config_text_input.enter_str(config);
+            }
         }
 
         if !selected_request.headers.is_empty() {
@@ -70,12 +126,44 @@ impl App<'_> {
             self.headers_table.selection_text_input.enter_str(&header_text);
         }
 
+        if !selected_request.cookies.is_empty() {
+            let selection = self.request_cookies_table.selection.unwrap();
+
+            let cookie_text = match selection {
+                (x, 0) => selected_request.cookies[x].data.0.clone(),
+                (x, 1) => selected_request.cookies[x].data.1.clone(),
+                _ => String::new() // Should not happen
+            };
+
+            self.request_cookies_table.selection_text_input.enter_str(&cookie_text);
+        }
+
         match &selected_request.body {
             ContentType::NoBody => {
                 self.body_form_table.rows = Vec::new();
                 self.refresh_body_textarea(&String::new());
             }
-            ContentType::Multipart(form) | ContentType::Form(form) => {
+            ContentType::Multipart(parts) => {
+                self.body_form_table.rows = parts.iter().map(MultipartPart::to_key_value).collect();
+
+                if !parts.is_empty() {
+                    let selection = self.body_form_table.selection.unwrap();
+
+                    let part_text = match selection {
+                        (x, 0) => parts[x].name.clone(),
+                        (x, 1) => parts[x].value.clone(),
+                        (x, 2) => parts[x].source.to_string(),
+                        (x, 3) => parts[x].content_type.clone().unwrap_or_default(),
+                        (x, 4) => parts[x].file_name.clone().unwrap_or_default(),
+                        _ => String::new() // Should not happen
+                    };
+
+                    self.body_form_table.selection_text_input.enter_str(&part_text);
+                }
+
+                self.refresh_body_textarea(&String::new());
+            }
+            ContentType::Form(form) => {
                 self.body_form_table.rows = form.clone();
 
                 if !form.is_empty() {
@@ -92,10 +180,11 @@ impl App<'_> {
 
                 self.refresh_body_textarea(&String::new());
             }
-            ContentType::File(file_path) =>  {
-                self.body_file_text_input.enter_str(file_path);
+            ContentType::File(body_file) =>  {
+                self.body_file_text_input.enter_str(&body_file.path);
+                self.body_file_content_type_text_input.enter_str(&body_file.content_type.clone().unwrap_or_default());
             },
-            ContentType::Raw(body) | ContentType::Json(body) | ContentType::Xml(body) | ContentType::Html(body) | ContentType::Javascript(body) => {
+            ContentType::Raw(body) | ContentType::Json(body) | ContentType::Xml(body) | ContentType::Html(body) | ContentType::Javascript(body) | ContentType::Protobuf(body) | ContentType::MessagePack(body) | ContentType::Cbor(body) => {
                 self.body_form_table.rows = Vec::new();
                 self.refresh_body_textarea(body);
             }
@@ -113,6 +202,12 @@ impl App<'_> {
         
         self.tui_refresh_pre_request_script_textarea(pre_request_script);
         self.tui_refresh_post_request_script_textarea(post_request_script);
+
+        self.script_console.response_processor_text_input.reset_input();
+
+        if let Some(response_processor) = &selected_request.scripts.response_processor {
+            self.script_console.response_processor_text_input.enter_str(response_processor);
+        }
     }
     
     pub fn reset_cursors(&mut self) {
@@ -121,24 +216,45 @@ impl App<'_> {
         self.auth_basic_username_text_input.reset_cursor();
         self.auth_basic_password_text_input.reset_cursor();
         self.auth_bearer_token_text_input.reset_cursor();
+        self.auth_hmac_secret_text_input.reset_cursor();
+        self.auth_hmac_string_to_sign_text_input.reset_cursor();
+        self.auth_hmac_header_text_input.reset_cursor();
+        self.auth_ntlm_username_text_input.reset_cursor();
+        self.auth_ntlm_password_text_input.reset_cursor();
+        self.auth_ntlm_domain_text_input.reset_cursor();
+        self.auth_oauth1_consumer_key_text_input.reset_cursor();
+        self.auth_oauth1_consumer_secret_text_input.reset_cursor();
+        self.auth_oauth1_token_text_input.reset_cursor();
+        self.auth_oauth1_token_secret_text_input.reset_cursor();
+        self.auth_plugin_name_text_input.reset_cursor();
+        self.auth_plugin_config_text_input.reset_cursor();
         self.headers_table.selection_text_input.reset_cursor();
+        self.request_cookies_table.selection_text_input.reset_cursor();
         self.body_form_table.selection_text_input.reset_cursor();
         self.body_file_text_input.reset_cursor();
+        self.body_file_content_type_text_input.reset_cursor();
+        self.script_console.response_processor_text_input.reset_cursor();
     }
 
     pub fn select_request(&mut self) {
         if self.collections_tree.state.selected().len() == 2 {
+            self.save_response_view();
             self.collections_tree.set_selected();
             self.tui_update_query_params_selection();
             self.tui_update_headers_selection();
+            self.tui_update_request_cookies_selection();
             self.tui_update_body_table_selection();
+
+            // Prepared when the response was received
+            self.load_response_view();
             self.tui_refresh_result_scrollbars();
-            
+
             self.select_request_state();
         }
     }
 
     pub fn unselect_request(&mut self) {
+        self.save_response_view();
         self.collections_tree.state.select(Vec::new());
         self.collections_tree.set_unselected();
         self.normal_state()
@@ -181,11 +297,19 @@ impl App<'_> {
         let new_request_name = self.new_request_popup.text_input.text.trim().to_string();
 
         let selected_collection_index = self.new_request_popup.selected_collection;
-        let new_request = Request {
-            name: new_request_name,
-            headers: DEFAULT_HEADERS.clone(),
-            settings: RequestSettings::default(),
-            ..Default::default()
+
+        if self.is_collection_read_only(selected_collection_index) {
+            return;
+        }
+
+        let new_request = match self.new_request_popup.selected_template {
+            Some(template_index) => self.request_templates[template_index].to_request(new_request_name),
+            None => Request {
+                name: new_request_name,
+                headers: DEFAULT_HEADERS.clone(),
+                settings: RequestSettings::default(),
+                ..Default::default()
+            }
         };
         
         match self.new_request(selected_collection_index, new_request) {
@@ -271,6 +395,19 @@ impl App<'_> {
         self.normal_state();
     }
 
+    /// Only on a highlighted request
+    pub fn tui_toggle_request_skip(&mut self) {
+        let selected_element = self.collections_tree.state.selected().to_vec();
+
+        if selected_element.len() != 2 {
+            return;
+        }
+
+        let skipped = self.collections[selected_element[0]].requests[selected_element[1]].read().skipped;
+
+        self.modify_request_skipped(selected_element[0], selected_element[1], !skipped).ok();
+    }
+
     pub fn tui_move_request_up(&mut self) {
         if self.collections_tree.state.selected().len() != 2 {
             return;
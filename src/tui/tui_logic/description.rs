@@ -0,0 +1,58 @@
+use tracing::warn;
+
+use crate::app::app::App;
+use crate::app::files::utils::edit_in_external_editor;
+
+impl App<'_> {
+    pub fn tui_edit_request_description(&mut self) {
+        let selected_request_index = self.collections_tree.selected.unwrap();
+        self.tui_edit_request_description_from_indexes(selected_request_index);
+    }
+
+    /// Edit the description of the collection or request highlighted in the collections list
+    pub fn tui_edit_element_description(&mut self) {
+        let selected_element = self.collections_tree.state.selected().to_vec();
+
+        match selected_element.len() {
+            // Selection on a collection
+            1 => {
+                let collection_index = selected_element[0];
+                let description = self.collections[collection_index].description.clone().unwrap_or_default();
+
+                if let Some(new_description) = self.edit_description_in_external_editor(&description) {
+                    self.modify_collection_description(collection_index, Some(new_description)).ok();
+                }
+            },
+            // Selection on a request
+            2 => self.tui_edit_request_description_from_indexes((selected_element[0], selected_element[1])),
+            _ => {}
+        }
+    }
+
+    fn tui_edit_request_description_from_indexes(&mut self, request_index: (usize, usize)) {
+        let description = {
+            let local_request = self.get_request_as_local_from_indexes(&request_index);
+            let request = local_request.read();
+            request.description.clone().unwrap_or_default()
+        };
+
+        if let Some(new_description) = self.edit_description_in_external_editor(&description) {
+            self.modify_request_description(request_index.0, request_index.1, Some(new_description)).ok();
+        }
+
+        self.request_description_scroll = 0;
+    }
+
+    /// Hands the terminal over to $EDITOR, returns None if the editor failed
+    fn edit_description_in_external_editor(&mut self, description: &str) -> Option<String> {
+        let result = self.run_with_suspended_terminal(|| edit_in_external_editor(description, "md"));
+
+        match result {
+            Ok(new_description) => Some(new_description),
+            Err(error) => {
+                warn!("Could not edit the description: {error}");
+                None
+            }
+        }
+    }
+}
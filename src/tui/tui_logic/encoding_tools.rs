@@ -0,0 +1,117 @@
+use arboard::Clipboard;
+
+use crate::app::app::App;
+use crate::models::auth::Auth;
+use crate::models::body::ContentType;
+use crate::tui::app_states::AppState;
+use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
+use crate::tui::utils::stateful::encoding_tools_popup::EncodingToolsTarget;
+
+impl App<'_> {
+    /// Selected field of the current param tab, or the URL when the tab has none
+    pub fn get_encoding_tools_target(&self) -> (EncodingToolsTarget, String) {
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        let url = (EncodingToolsTarget::Url, self.url_text_input.text.clone());
+
+        return match self.request_param_tab {
+            RequestParamsTabs::QueryParams if self.query_params_table.selection.is_some() => (EncodingToolsTarget::QueryParam, self.query_params_table.selection_text_input.text.clone()),
+            RequestParamsTabs::Auth => match selected_request.auth {
+                Auth::NoAuth => url,
+                Auth::BasicAuth { .. } => match self.auth_text_input_selection.selected {
+                    0 => (EncodingToolsTarget::BasicAuthUsername, self.auth_basic_username_text_input.text.clone()),
+                    _ => (EncodingToolsTarget::BasicAuthPassword, self.auth_basic_password_text_input.text.clone())
+                },
+                Auth::BearerToken { .. } => (EncodingToolsTarget::BearerToken, self.auth_bearer_token_text_input.text.clone()),
+                Auth::HmacSignature { .. } => match self.auth_text_input_selection.selected {
+                    0 => (EncodingToolsTarget::HmacSecret, self.auth_hmac_secret_text_input.text.clone()),
+                    1 => (EncodingToolsTarget::HmacStringToSign, self.auth_hmac_string_to_sign_text_input.text.clone()),
+                    2 => (EncodingToolsTarget::HmacHeader, self.auth_hmac_header_text_input.text.clone()),
+                    _ => url
+                },
+                Auth::Ntlm { .. } | Auth::OAuth1 { .. } | Auth::Plugin { .. } => url
+            },
+            RequestParamsTabs::Headers if self.headers_table.selection.is_some() => (EncodingToolsTarget::Header, self.headers_table.selection_text_input.text.clone()),
+            RequestParamsTabs::Cookies if self.request_cookies_table.selection.is_some() => (EncodingToolsTarget::Cookie, self.request_cookies_table.selection_text_input.text.clone()),
+            RequestParamsTabs::Body => match selected_request.body {
+                ContentType::NoBody => url,
+                ContentType::Multipart(_) | ContentType::Form(_) => match self.body_form_table.selection {
+                    None => url,
+                    Some(_) => (EncodingToolsTarget::FormData, self.body_form_table.selection_text_input.text.clone())
+                },
+                ContentType::File(_) => match self.body_file_text_input_selection.selected {
+                    0 => (EncodingToolsTarget::BodyFile, self.body_file_text_input.text.clone()),
+                    _ => url
+                },
+                ContentType::Raw(_) | ContentType::Json(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) | ContentType::Protobuf(_) | ContentType::MessagePack(_) | ContentType::Cbor(_) => (EncodingToolsTarget::Body, self.body_text_area.lines().join("\n"))
+            },
+            _ => url
+        };
+    }
+
+    pub fn tui_confirm_encoding_tools_input(&mut self) {
+        self.state = AppState::UsingEncodingTools;
+    }
+
+    pub fn tui_copy_encoding_tool_result(&mut self) {
+        let result = match self.encoding_tools_popup.get_result() {
+            Ok(result) => result,
+            Err(_) => return
+        };
+
+        // No clipboard is available in some terminals, e.g. over SSH
+        if let Ok(mut clipboard) = Clipboard::new() {
+            clipboard.set_text(result).ok();
+        }
+    }
+
+    /// Replace the content of the field the tools were opened from by the result
+    pub fn tui_insert_encoding_tool_result(&mut self) {
+        let target = match self.encoding_tools_popup.target {
+            None => return,
+            Some(target) => target
+        };
+
+        let result = match self.encoding_tools_popup.get_result() {
+            Ok(result) => result,
+            Err(_) => return
+        };
+
+        let text_input = match target {
+            EncodingToolsTarget::Url => &mut self.url_text_input,
+            EncodingToolsTarget::QueryParam => &mut self.query_params_table.selection_text_input,
+            EncodingToolsTarget::BasicAuthUsername => &mut self.auth_basic_username_text_input,
+            EncodingToolsTarget::BasicAuthPassword => &mut self.auth_basic_password_text_input,
+            EncodingToolsTarget::BearerToken => &mut self.auth_bearer_token_text_input,
+            EncodingToolsTarget::HmacSecret => &mut self.auth_hmac_secret_text_input,
+            EncodingToolsTarget::HmacStringToSign => &mut self.auth_hmac_string_to_sign_text_input,
+            EncodingToolsTarget::HmacHeader => &mut self.auth_hmac_header_text_input,
+            EncodingToolsTarget::Header => &mut self.headers_table.selection_text_input,
+            EncodingToolsTarget::Cookie => &mut self.request_cookies_table.selection_text_input,
+            EncodingToolsTarget::FormData => &mut self.body_form_table.selection_text_input,
+            EncodingToolsTarget::BodyFile => &mut self.body_file_text_input,
+            EncodingToolsTarget::Body => {
+                self.refresh_body_textarea(&result);
+                self.tui_next_request_body();
+                return;
+            }
+        };
+
+        text_input.reset_input();
+        text_input.enter_str(&result);
+
+        match target {
+            EncodingToolsTarget::Url => self.tui_modify_request_url(),
+            EncodingToolsTarget::QueryParam => self.tui_modify_request_query_param(),
+            EncodingToolsTarget::BasicAuthUsername => self.tui_modify_request_auth_basic_username(),
+            EncodingToolsTarget::BasicAuthPassword => self.tui_modify_request_auth_basic_password(),
+            EncodingToolsTarget::BearerToken => self.tui_modify_request_auth_bearer_token(),
+            EncodingToolsTarget::HmacSecret | EncodingToolsTarget::HmacStringToSign | EncodingToolsTarget::HmacHeader => self.tui_modify_request_auth_hmac(),
+            EncodingToolsTarget::Header => self.tui_modify_request_header(),
+            EncodingToolsTarget::Cookie => self.tui_modify_request_cookie(),
+            EncodingToolsTarget::FormData => self.tui_modify_request_form_data(),
+            EncodingToolsTarget::BodyFile | EncodingToolsTarget::Body => self.tui_next_request_body()
+        }
+    }
+}
@@ -1,69 +1,215 @@
-use ratatui::style::Stylize;
+use ratatui::style::{Color, Style, Stylize};
 use ratatui::text::{Line, Span};
-use regex::Regex;
+use tui_textarea::CursorMove;
 
 use crate::app::app::App;
+use crate::cli::args::ARGS;
+use crate::app::business_logic::environment::{BUILTIN_ENV_KEYS, ENV_KEY_REGEX};
+use crate::app::files::key_bindings::{TextAreaMode, KEY_BINDINGS};
 use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState;
+use crate::tui::utils::completion::{get_completions, get_env_key_start};
+use crate::tui::utils::stateful::text_input::TextInput;
+use crate::tui::utils::vim_emulation::VimMode;
+
+const UNRESOLVED_ENV_KEY_COLOR: Color = Color::Red;
 
 impl App<'_> {
     pub fn tui_next_environment(&mut self) {
-        if self.selected_environment + 1 < self.environments.len() {
-            self.selected_environment += 1;
+        if self.environments.is_empty() {
+            return;
         }
-        else {
-            self.selected_environment = 0;
+
+        let next_environment = (self.selected_environment + 1) % self.environments.len();
+        self.switch_environment(next_environment);
+    }
+
+    pub fn tui_previous_environment(&mut self) {
+        if self.environments.is_empty() {
+            return;
         }
+
+        let previous_environment = match self.selected_environment {
+            0 => self.environments.len() - 1,
+            selected_environment => selected_environment - 1
+        };
+
+        self.switch_environment(previous_environment);
+    }
+
+    pub fn tui_select_environment(&mut self) {
+        self.switch_environment(self.environment_popup.selection);
+        self.normal_state();
     }
 
+    fn switch_environment(&mut self, environment_index: usize) {
+        // Each environment has its own cookie jar
+        self.save_cookie_jar();
+        self.selected_environment = environment_index;
+        self.load_cookie_jar();
+    }
+
+    /// Resolved keys are highlighted, unresolved ones would be sent literally and stand out
     pub fn tui_add_color_to_env_keys(&self, input: &str) -> Line {
-        if self.environments.is_empty() || !input.contains('{') {
+        if !input.contains("{{") {
             return Line::raw(input.to_string());
         }
 
         let mut spans: Vec<Span> = vec![];
-
-        let regex = Regex::new(r"\{\{(\w+)}}").unwrap();
         let mut tmp_index: usize = 0;
 
-        let local_env = self.get_selected_env_as_local();
-
-        if let Some(local_env) = local_env {
-            let env = local_env.read();
-
-            let mut keys: Vec<&str> = env.values.keys().map(|key| key.as_str()).collect();
-            keys.extend(vec![
-                "NOW",
-                "TIMESTAMP",
-                "UUIDv4",
-                "UUIDv7"
-            ]);
-
-            for match_ in regex.captures_iter(input) {
-                for sub_match in match_.iter() {
-                    if let Some(sub_match) = sub_match {
-                        for key in &keys {
-                            if sub_match.as_str() == &format!("{{{{{}}}}}", key) {
-                                let range = sub_match.range();
-
-                                spans.push(Span::raw(input[tmp_index..range.start].to_string()));
-                                spans.push(
-                                    Span::raw(sub_match.as_str().to_owned())
-                                        .fg(THEME.read().others.environment_variable_highlight_color)
-                                );
-
-                                tmp_index = range.end;
-                            }
-                        }
+        for capture in ENV_KEY_REGEX.captures_iter(input) {
+            let env_key_match = capture.get(0).unwrap();
+
+            let color = match self.is_env_key_resolved(&capture[1]) {
+                true => THEME.read().others.environment_variable_highlight_color,
+                false => UNRESOLVED_ENV_KEY_COLOR
+            };
+
+            spans.push(Span::raw(input[tmp_index..env_key_match.start()].to_string()));
+            spans.push(Span::raw(env_key_match.as_str().to_string()).fg(color));
+
+            tmp_index = env_key_match.end();
+        }
+
+        spans.push(Span::raw(input[tmp_index..].to_string()));
+
+        return Line::from(spans);
+    }
+
+    /// The body text area cannot be colored span by span, its search highlight is used instead
+    pub fn tui_highlight_body_unresolved_env_keys(&mut self) {
+        let body = self.body_text_area.lines().join("\n");
+
+        let pattern = self.get_unresolved_env_keys(&body)
+            .iter()
+            .map(|key| regex::escape(&format!("{{{{{key}}}}}")))
+            .collect::<Vec<String>>()
+            .join("|");
+
+        // An empty pattern clears the highlight
+        if self.body_text_area.set_search_pattern(pattern).is_ok() {
+            self.body_text_area.set_search_style(Style::new().fg(UNRESOLVED_ENV_KEY_COLOR).bold());
+        }
+    }
+
+    /// Lists the keys completing the "{{" typed before the cursor of the URL, a query param, a header or the body.
+    /// The keys of the selected environment come first, then the ones of the data row, of --var and the built-in ones
+    pub fn tui_update_env_key_completions(&mut self) {
+        self.env_key_completion_popup.selection = 0;
+
+        let text_before_cursor = self.get_env_key_completion_text_before_cursor().unwrap_or_default();
+
+        let key_start = match get_env_key_start(&text_before_cursor) {
+            Some(key_start) => key_start,
+            None => {
+                self.env_key_completion_popup.choices = vec![];
+                return;
+            }
+        };
+
+        let mut keys: Vec<String> = vec![];
+
+        if let Some(local_env) = self.get_selected_env_as_local() {
+            keys.extend(local_env.read().values.keys().cloned());
+        }
+
+        if let Some(data_row) = &self.data_row {
+            keys.extend(data_row.keys().cloned());
+        }
+
+        keys.extend(ARGS.variables.iter().map(|(key, _)| key.clone()));
+
+        let candidates = keys.iter().map(String::as_str).chain(BUILTIN_ENV_KEYS);
+
+        self.env_key_completion_popup.choices = get_completions(key_start, candidates);
+    }
+
+    /// Replaces the key start with the highlighted key and closes the braces
+    pub fn tui_complete_env_key(&mut self) {
+        let text_before_cursor = self.get_env_key_completion_text_before_cursor().unwrap_or_default();
+
+        let key_start_length = match get_env_key_start(&text_before_cursor) {
+            Some(key_start) => key_start.chars().count(),
+            None => return
+        };
+
+        let key = match self.env_key_completion_popup.choices.get(self.env_key_completion_popup.selection) {
+            Some(key) => key.clone(),
+            None => return
+        };
+
+        match self.state {
+            AppState::EditingRequestBodyString => {
+                let (row, column) = self.body_text_area.cursor();
+                let text_after_cursor: String = self.body_text_area.lines()[row].chars().skip(column).collect();
+
+                self.body_text_area.move_cursor(CursorMove::Jump(row as u16, (column - key_start_length) as u16));
+                self.body_text_area.delete_str(key_start_length);
+
+                match text_after_cursor.starts_with("}}") {
+                    true => {
+                        self.body_text_area.insert_str(key);
+                        self.body_text_area.move_cursor(CursorMove::Forward);
+                        self.body_text_area.move_cursor(CursorMove::Forward);
+                    },
+                    false => {
+                        self.body_text_area.insert_str(format!("{key}}}}}"));
                     }
                 }
-            }
+            },
+            _ => {
+                let text_input = match self.get_env_key_completion_text_input() {
+                    Some(text_input) => text_input,
+                    None => return
+                };
+
+                for _ in 0..key_start_length {
+                    text_input.delete_char_backward();
+                }
 
-            spans.push(Span::raw(String::from(&input[tmp_index..input.len()])));
+                let is_closed = text_input.text[text_input.cursor_position..].starts_with("}}");
+
+                text_input.enter_str(&key);
+
+                match is_closed {
+                    true => {
+                        text_input.move_cursor_right();
+                        text_input.move_cursor_right();
+                    },
+                    false => text_input.enter_str("}}")
+                }
+            }
         }
-        else {
-            spans.push(Span::raw(input.to_string()));
+
+        self.env_key_completion_popup.choices = vec![];
+    }
+
+    fn get_env_key_completion_text_input(&mut self) -> Option<&mut TextInput> {
+        return match self.state {
+            AppState::EditingRequestUrl => Some(&mut self.url_text_input),
+            AppState::EditingRequestParam => Some(&mut self.query_params_table.selection_text_input),
+            AppState::EditingRequestHeader => Some(&mut self.headers_table.selection_text_input),
+            _ => None
+        };
+    }
+
+    /// None outside of the inputs completing the environment keys, or in the normal mode of the vim emulation
+    fn get_env_key_completion_text_before_cursor(&mut self) -> Option<String> {
+        if self.state == AppState::EditingRequestBodyString {
+            let is_vim_emulation = KEY_BINDINGS.read().generic.text_inputs.text_area_mode == TextAreaMode::VimEmulation;
+
+            if is_vim_emulation && self.body_text_area_vim_emulation.mode != VimMode::Insert {
+                return None;
+            }
+
+            let (row, column) = self.body_text_area.cursor();
+
+            return Some(self.body_text_area.lines()[row].chars().take(column).collect());
         }
 
-        return Line::from(spans);
+        let text_input = self.get_env_key_completion_text_input()?;
+
+        return Some(text_input.text[..text_input.cursor_position].to_string());
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,266 @@
+use std::mem;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use parking_lot::RwLock;
+use tracing::{info, warn};
+
+use crate::app::app::App;
+use crate::app::files::collection::{get_collection_directory_format, get_collection_file_format, read_collection_directory, read_collection_file};
+use crate::app::files::encryption::is_encrypted_file;
+use crate::app::files::environment::read_environment_file;
+use crate::app::files::file_watcher::{has_file_changed, record_modified_time};
+use crate::models::collection::CollectionLayout;
+use crate::models::request::Request;
+use crate::tui::app_states::AppState;
+
+/// How often the workspace files are checked for changes made outside of ATAC, e.g. by a git pull
+const FILES_CHECK_PERIOD: Duration = Duration::from_secs(2);
+
+impl App<'_> {
+    /// Reload the collections and environments changed on disk, and load the new collections.
+    /// Deleted files stay loaded until the workspace is reloaded
+    pub fn tui_reload_changed_files(&mut self) {
+        if self.last_files_check.elapsed() < FILES_CHECK_PERIOD {
+            return;
+        }
+
+        self.last_files_check = Instant::now();
+
+        self.reload_changed_environments();
+
+        let is_editing_request = match self.state {
+            AppState::Normal |
+            AppState::SelectedRequest |
+            AppState::DisplayingCookies | AppState::EditingCookies | AppState::CreatingCookie |
+            AppState::DisplayingWebhookListener | AppState::DisplayingGitStatus |
+            AppState::DisplayingResponseCache | AppState::DisplayingLogs | AppState::DisplayingScriptingConsole => false,
+
+            AppState::EditingRequestUrl |
+            AppState::EditingRequestParam |
+            AppState::EditingRequestAuthUsername | AppState::EditingRequestAuthPassword | AppState::EditingRequestAuthBearerToken | AppState::EditingRequestAuthHmac | AppState::EditingRequestAuthNtlm | AppState::EditingRequestAuthOAuth1 | AppState::EditingRequestAuthPlugin |
+            AppState::EditingRequestHeader | AppState::EditingRequestCookie |
+            AppState::EditingRequestBodyTable | AppState::EditingRequestBodyFile | AppState::EditingRequestBodyString |
+            AppState::EditingPreRequestScript | AppState::EditingPostRequestScript | AppState::EditingResponseProcessor |
+            AppState::EditingRequestSettings | AppState::ChoosingRequestUserAgent => true,
+
+            // The other popups point to collections and requests by their index, the files are reloaded once they are closed
+            _ => return
+        };
+
+        for collection_index in 0..self.collections.len() {
+            if !has_file_changed(&self.collections[collection_index].path) {
+                continue;
+            }
+
+            let is_edited_collection = is_editing_request && self.collections_tree.selected
+                .is_some_and(|(selected_collection_index, _)| selected_collection_index == collection_index);
+
+            // Let the user choose between the changes on disk and the edit in progress
+            if is_edited_collection {
+                info!("Collection \"{}\" changed on disk during an edit", self.collections[collection_index].name);
+                self.reload_changed_collection_state(collection_index);
+                return;
+            }
+
+            self.reload_collection(collection_index);
+        }
+
+        self.load_new_collections();
+    }
+
+    /// Reload the collection changed on disk, discarding the edit in progress
+    pub fn tui_reload_changed_collection(&mut self) {
+        let (collection_index, _) = match self.changed_collection.take() {
+            None => return self.normal_state(),
+            Some(changed_collection) => changed_collection
+        };
+
+        self.reload_collection(collection_index);
+
+        match self.collections_tree.selected {
+            Some(_) => self.select_request_state(),
+            None => self.normal_state()
+        }
+    }
+
+    /// Go back to the edit in progress, saving it will overwrite the changes made on disk
+    pub fn tui_keep_editing_request(&mut self) {
+        let (collection_index, editing_state) = match self.changed_collection.take() {
+            None => return self.normal_state(),
+            Some(changed_collection) => changed_collection
+        };
+
+        let collection_path = self.collections[collection_index].path.clone();
+        record_modified_time(&collection_path);
+
+        self.state = editing_state;
+    }
+
+    fn reload_collection(&mut self, collection_index: usize) {
+        let collection = &self.collections[collection_index];
+        let collection_path = collection.path.clone();
+
+        let result = match collection.layout {
+            CollectionLayout::SingleFile => read_collection_file(&collection_path, collection.file_format),
+            CollectionLayout::FilePerRequest => read_collection_directory(&collection_path, collection.file_format)
+        };
+
+        // An unparsable file is retried on its next change, e.g. once a merge conflict is resolved
+        record_modified_time(&collection_path);
+
+        let mut reloaded_collection = match result {
+            Ok(reloaded_collection) => reloaded_collection,
+            Err(error) => {
+                warn!("Could not reload the collection\n\t{error}");
+                return;
+            }
+        };
+
+        info!("Collection \"{}\" reloaded", reloaded_collection.name);
+
+        let previous_requests = mem::take(&mut self.collections[collection_index].requests);
+        reloaded_collection.requests = merge_reloaded_requests(&previous_requests, reloaded_collection.requests);
+
+        self.collections[collection_index] = reloaded_collection;
+
+        // Follow the selected request, which may have been moved or removed
+        if let Some((selected_collection_index, selected_request_index)) = self.collections_tree.selected {
+            if selected_collection_index != collection_index {
+                return;
+            }
+
+            let selected_request = &previous_requests[selected_request_index];
+
+            let new_request_index = self.collections[collection_index].requests
+                .iter()
+                .position(|request| Arc::ptr_eq(request, selected_request));
+
+            match new_request_index {
+                None => {
+                    self.collections_tree.state.select(vec![collection_index]);
+                    self.collections_tree.set_unselected();
+
+                    if self.state == AppState::SelectedRequest {
+                        self.normal_state();
+                    }
+                },
+                Some(new_request_index) => {
+                    if self.collections_tree.state.selected() == [collection_index, selected_request_index] {
+                        self.collections_tree.state.select(vec![collection_index, new_request_index]);
+                    }
+
+                    self.collections_tree.selected = Some((collection_index, new_request_index));
+
+                    self.tui_update_query_params_selection();
+                    self.tui_update_headers_selection();
+                    self.tui_update_request_cookies_selection();
+                    self.tui_update_body_table_selection();
+                    self.update_inputs();
+                }
+            }
+        }
+    }
+
+    /// Collections added to the workspace directory, e.g. pulled by git
+    fn load_new_collections(&mut self) {
+        let workspace_directory = match self.get_workspace_directory() {
+            None => return,
+            Some(workspace_directory) => workspace_directory
+        };
+
+        let paths = match workspace_directory.read_dir() {
+            Ok(paths) => paths,
+            Err(_) => return
+        };
+
+        for path in paths.flatten().map(|entry| entry.path()) {
+            let is_loaded = self.collections.iter().any(|collection| collection.path == path);
+
+            if is_loaded || path.file_name().is_some_and(|file_name| file_name == "atac.toml") {
+                continue;
+            }
+
+            let file_format = match path.is_dir() {
+                true => get_collection_directory_format(&path),
+                false => get_collection_file_format(&path)
+            };
+
+            let file_format = match file_format {
+                Some(file_format) if has_file_changed(&path) => file_format,
+                _ => continue
+            };
+
+            let result = match path.is_dir() {
+                true => read_collection_directory(&path, file_format),
+                false => read_collection_file(&path, file_format)
+            };
+
+            record_modified_time(&path);
+
+            // The passphrase may have been prompted over the TUI
+            if is_encrypted_file(&path) {
+                self.should_clear_terminal = true;
+            }
+
+            match result {
+                Ok(collection) => {
+                    info!("Collection \"{}\" loaded", collection.name);
+                    self.collections.push(collection);
+                },
+                Err(error) => warn!("Could not load the new collection\n\t{error}")
+            }
+        }
+    }
+
+    fn reload_changed_environments(&mut self) {
+        for environment in self.environments.clone() {
+            let environment_path = environment.read().path.clone();
+
+            if !has_file_changed(&environment_path) {
+                continue;
+            }
+
+            record_modified_time(&environment_path);
+
+            match read_environment_file(&environment_path) {
+                Ok(values) => {
+                    let mut environment = environment.write();
+                    info!("Environment \"{}\" reloaded", environment.name);
+                    environment.values = values;
+                },
+                Err(error) => warn!("Could not reload the environment\n\t{error}")
+            }
+        }
+    }
+}
+
+/// Reuse the request of the same name, which may be being sent, so that its response is kept
+fn merge_reloaded_requests(previous_requests: &[Arc<RwLock<Request>>], reloaded_requests: Vec<Arc<RwLock<Request>>>) -> Vec<Arc<RwLock<Request>>> {
+    let mut unmatched_requests: Vec<&Arc<RwLock<Request>>> = previous_requests.iter().collect();
+
+    return reloaded_requests
+        .into_iter()
+        .map(|reloaded_request| {
+            let request_name = reloaded_request.read().name.clone();
+
+            let position = match unmatched_requests.iter().position(|request| request.read().name == request_name) {
+                None => return reloaded_request,
+                Some(position) => position
+            };
+
+            let previous_request = unmatched_requests.remove(position);
+            let mut request = mem::take(&mut *reloaded_request.write());
+
+            let mut previous_request_guard = previous_request.write();
+            request.response = mem::take(&mut previous_request_guard.response);
+            request.is_pending = previous_request_guard.is_pending;
+            request.queued_sends = previous_request_guard.queued_sends.clone();
+            request.cancellation_token = previous_request_guard.cancellation_token.clone();
+            request.watch = previous_request_guard.watch.take();
+            *previous_request_guard = request;
+
+            previous_request.clone()
+        })
+        .collect();
+}
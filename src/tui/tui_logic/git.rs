@@ -0,0 +1,70 @@
+use tracing::warn;
+
+use crate::app::app::App;
+use crate::app::business_logic::git::{git_commit, git_status, run_git_captured};
+
+impl App<'_> {
+    pub fn tui_refresh_git_status(&mut self) {
+        let workspace_directory = self.get_workspace_directory().unwrap();
+
+        let status = match git_status(&workspace_directory) {
+            Ok(status) => status,
+            Err(error) => error.to_string()
+        };
+
+        // Tabs are not rendered by the paragraphs
+        self.git_popup.status = status.replace('\t', "    ");
+    }
+
+    /// Pull, then reload the workspace so that the pulled collections and environments are displayed
+    pub fn tui_git_pull(&mut self) {
+        let workspace_directory = self.get_workspace_directory().unwrap();
+
+        // Credentials cannot be prompted while the TUI is displayed, git fails instead
+        let result = run_git_captured(&workspace_directory, &["pull"]);
+        let pulled = result.is_ok();
+
+        self.set_git_output(result);
+
+        if pulled {
+            if let Err(error) = self.switch_workspace(self.selected_workspace.clone()) {
+                warn!("Could not reload the workspace: {error}");
+                self.git_popup.last_output = Some(error.to_string());
+            }
+
+            // The passphrase of the encrypted files may have been prompted over the TUI
+            self.should_clear_terminal = true;
+        }
+
+        self.tui_refresh_git_status();
+    }
+
+    /// Commit every change of the workspace, git opens $EDITOR for the message
+    pub fn tui_git_commit(&mut self) {
+        let workspace_directory = self.get_workspace_directory().unwrap();
+
+        let result = self.run_with_suspended_terminal(|| git_commit(&workspace_directory, None));
+
+        self.set_git_output(result.map(|_| String::from("Changes committed")));
+        self.tui_refresh_git_status();
+    }
+
+    pub fn tui_git_push(&mut self) {
+        let workspace_directory = self.get_workspace_directory().unwrap();
+
+        let result = run_git_captured(&workspace_directory, &["push"]);
+
+        self.set_git_output(result);
+        self.tui_refresh_git_status();
+    }
+
+    fn set_git_output(&mut self, result: anyhow::Result<String>) {
+        let output = match result {
+            Ok(output) if output.is_empty() => String::from("Done"),
+            Ok(output) => output,
+            Err(error) => error.to_string()
+        };
+
+        self.git_popup.last_output = Some(output.replace('\t', "    "));
+    }
+}
@@ -0,0 +1,79 @@
+use ratatui::text::Span;
+use tui_textarea::CursorMove;
+
+use crate::app::app::App;
+use crate::models::response::ResponseContent;
+use crate::tui::app_states::AppState;
+use crate::tui::ui::result_tabs::RequestResultTabs;
+use crate::tui::utils::line_wrapping::get_wrapped_rows_count;
+use crate::tui::utils::stateful::go_to_line_popup::GoToLineTarget;
+
+impl App<'_> {
+    pub fn tui_go_to_line(&mut self) {
+        if let Some(line) = self.go_to_line_popup.get_line() {
+            match self.go_to_line_popup.target {
+                GoToLineTarget::RequestBody => {
+                    let row = u16::try_from(line - 1).unwrap_or(u16::MAX);
+                    self.body_text_area.move_cursor(CursorMove::Jump(row, 0));
+                },
+                GoToLineTarget::Result => self.tui_scroll_result_to_line(line - 1)
+            }
+        }
+
+        self.tui_quit_go_to_line();
+    }
+
+    pub fn tui_quit_go_to_line(&mut self) {
+        match self.go_to_line_popup.target {
+            // The body being edited is not reloaded from the request
+            GoToLineTarget::RequestBody => self.state = AppState::EditingRequestBodyString,
+            GoToLineTarget::Result => self.select_request_state()
+        }
+    }
+
+    /// Lines of the displayed result body, the CSV table counting its rows
+    pub fn get_result_body_lines_count(&self) -> Option<usize> {
+        if !matches!(self.request_result_tab, RequestResultTabs::Body) {
+            return None;
+        }
+
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        let body = match &selected_request.response.content {
+            Some(ResponseContent::Body(body)) => body,
+            _ => return None
+        };
+
+        if let Some(ndjson_records) = self.syntax_highlighting.ndjson_records.read().as_ref() {
+            return Some(ndjson_records.lines_count());
+        }
+
+        if let Some(csv_table) = self.syntax_highlighting.csv_table.read().as_ref().filter(|csv_table| !csv_table.is_raw) {
+            return Some(csv_table.rows_count());
+        }
+
+        return Some(body.lines().count());
+    }
+
+    /// The line is put at the top of the result
+    fn tui_scroll_result_to_line(&mut self, line_index: usize) {
+        // The scrollbars are not refreshed after a request is sent
+        self.tui_refresh_result_scrollbars();
+
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        // The vertical scroll counts the rows of the wrapped lines
+        let scroll = match &selected_request.response.content {
+            Some(ResponseContent::Body(body)) if self.should_wrap_lines && !self.is_result_body_structured() => body
+                .lines()
+                .take(line_index)
+                .map(|line| get_wrapped_rows_count(Span::raw(line).width(), self.result_wrap_width))
+                .sum(),
+            _ => line_index
+        };
+
+        self.result_vertical_scrollbar.scroll_to(scroll);
+    }
+}
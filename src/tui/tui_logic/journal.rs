@@ -0,0 +1,188 @@
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use tracing::{info, warn};
+
+use crate::app::app::App;
+use crate::app::files::encryption::is_encrypted_file;
+use crate::app::files::journal::{delete_journal, read_journal, save_journal};
+use crate::models::journal::{UnsavedEdit, UnsavedEditField};
+use crate::tui::app_states::AppState;
+use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
+use crate::tui::utils::stateful::go_to_line_popup::GoToLineTarget;
+
+/// How often the edit in progress is written to the journal
+const JOURNAL_PERIOD: Duration = Duration::from_secs(3);
+
+impl App<'_> {
+    /// Journal the edit in progress from time to time, delete the journal once the edit is confirmed or cancelled
+    pub fn tui_update_journal(&mut self) {
+        let workspace_directory = match self.get_workspace_directory() {
+            None => return,
+            Some(workspace_directory) => workspace_directory
+        };
+
+        let unsaved_edit = match self.get_unsaved_edit() {
+            None => {
+                if self.journaled_edit.take().is_some() {
+                    delete_journal(&workspace_directory);
+                }
+
+                return;
+            },
+            Some(unsaved_edit) => unsaved_edit
+        };
+
+        if self.last_journal_save.elapsed() < JOURNAL_PERIOD || self.journaled_edit.as_ref() == Some(&unsaved_edit) {
+            return;
+        }
+
+        let is_encrypted = is_encrypted_file(&self.collections[unsaved_edit.collection_index].path);
+
+        let journal = UnsavedEdit {
+            saved_at: Utc::now().format("%Y-%m-%d %H:%M:%S UTC").to_string(),
+            ..unsaved_edit.clone()
+        };
+
+        save_journal(&workspace_directory, &journal, is_encrypted);
+
+        self.last_journal_save = Instant::now();
+        self.journaled_edit = Some(unsaved_edit);
+    }
+
+    /// Offer to recover the edit left unsaved by the previous run
+    pub fn tui_check_journal(&mut self) {
+        let workspace_directory = match self.get_workspace_directory() {
+            None => return,
+            Some(workspace_directory) => workspace_directory
+        };
+
+        if let Some(unsaved_edit) = read_journal(&workspace_directory) {
+            info!("Unsaved {} edit found in the journal", unsaved_edit.field);
+
+            self.recovered_edit = Some(unsaved_edit);
+            self.recover_unsaved_edit_state();
+        }
+    }
+
+    /// Reopen the edited field with the journaled text, nothing is saved until the edit is confirmed
+    pub fn tui_recover_unsaved_edit(&mut self) {
+        let unsaved_edit = match self.recovered_edit.take() {
+            None => return self.normal_state(),
+            Some(unsaved_edit) => unsaved_edit
+        };
+
+        let workspace_directory = self.get_workspace_directory().unwrap();
+
+        // The collections may have changed since the journal was written
+        let request_exists = self.collections
+            .get(unsaved_edit.collection_index)
+            .filter(|collection| collection.name == unsaved_edit.collection_name)
+            .and_then(|collection| collection.requests.get(unsaved_edit.request_index))
+            .is_some_and(|request| request.read().name == unsaved_edit.request_name);
+
+        if !request_exists {
+            warn!("Request \"{}\" of the unsaved edit not found", unsaved_edit.request_name);
+            delete_journal(&workspace_directory);
+            return self.normal_state();
+        }
+
+        self.collections_tree.state.open(vec![unsaved_edit.collection_index]);
+        self.collections_tree.state.select(vec![unsaved_edit.collection_index, unsaved_edit.request_index]);
+        self.select_request();
+
+        let text = unsaved_edit.text.clone();
+
+        match unsaved_edit.field {
+            UnsavedEditField::Url => {
+                self.edit_request_url_state();
+                self.url_text_input.reset_input();
+                self.url_text_input.enter_str(&text);
+            },
+            UnsavedEditField::QueryParam { row, column } => {
+                self.request_param_tab = RequestParamsTabs::QueryParams;
+
+                if self.query_params_table.select(row, column) {
+                    self.edit_request_param_state();
+                    self.query_params_table.selection_text_input.reset_input();
+                    self.query_params_table.selection_text_input.enter_str(&text);
+                }
+            },
+            UnsavedEditField::Header { row, column } => {
+                self.request_param_tab = RequestParamsTabs::Headers;
+
+                if self.headers_table.select(row, column) {
+                    self.edit_request_header_state();
+                    self.headers_table.selection_text_input.reset_input();
+                    self.headers_table.selection_text_input.enter_str(&text);
+                }
+            },
+            UnsavedEditField::Body => {
+                self.edit_request_body_file_or_string_state();
+
+                if self.state == AppState::EditingRequestBodyString {
+                    self.refresh_body_textarea(&text);
+                }
+            },
+            UnsavedEditField::PreRequestScript => {
+                self.script_console.script_selection = 0;
+                self.edit_request_script_state();
+                self.tui_refresh_pre_request_script_textarea(&text);
+            },
+            UnsavedEditField::PostRequestScript => {
+                self.script_console.script_selection = 1;
+                self.edit_request_script_state();
+                self.tui_refresh_post_request_script_textarea(&text);
+            },
+        }
+
+        // The journal now follows the reopened edit, or is useless if the field could not be reopened
+        match self.get_unsaved_edit() {
+            Some(_) => self.journaled_edit = Some(unsaved_edit),
+            None => delete_journal(&workspace_directory)
+        }
+    }
+
+    pub fn tui_discard_unsaved_edit(&mut self) {
+        self.recovered_edit = None;
+
+        if let Some(workspace_directory) = self.get_workspace_directory() {
+            delete_journal(&workspace_directory);
+        }
+
+        self.normal_state();
+    }
+
+    /// Field of the selected request being edited, along with its current text
+    fn get_unsaved_edit(&self) -> Option<UnsavedEdit> {
+        let (field, text) = match self.state {
+            AppState::EditingRequestUrl => (UnsavedEditField::Url, self.url_text_input.text.clone()),
+            AppState::EditingRequestParam => {
+                let (row, column) = self.query_params_table.selection?;
+                (UnsavedEditField::QueryParam { row, column }, self.query_params_table.selection_text_input.text.clone())
+            },
+            AppState::EditingRequestHeader => {
+                let (row, column) = self.headers_table.selection?;
+                (UnsavedEditField::Header { row, column }, self.headers_table.selection_text_input.text.clone())
+            },
+            AppState::EditingRequestBodyString => (UnsavedEditField::Body, self.body_text_area.lines().join("\n")),
+            AppState::GoingToLine if self.go_to_line_popup.target == GoToLineTarget::RequestBody => (UnsavedEditField::Body, self.body_text_area.lines().join("\n")),
+            AppState::EditingPreRequestScript => (UnsavedEditField::PreRequestScript, self.script_console.pre_request_text_area.lines().join("\n")),
+            AppState::EditingPostRequestScript => (UnsavedEditField::PostRequestScript, self.script_console.post_request_text_area.lines().join("\n")),
+            _ => return None
+        };
+
+        let (collection_index, request_index) = self.collections_tree.selected?;
+        let collection = &self.collections[collection_index];
+
+        return Some(UnsavedEdit {
+            collection_index,
+            collection_name: collection.name.clone(),
+            request_index,
+            request_name: collection.requests[request_index].read().name.clone(),
+            field,
+            text,
+            saved_at: String::new(),
+        });
+    }
+}
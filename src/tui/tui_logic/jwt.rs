@@ -0,0 +1,77 @@
+use crate::app::app::App;
+use crate::app::business_logic::jwt::{decode_jwt, fetch_jwks, is_jwt};
+use crate::tui::app_states::AppState;
+
+impl App<'_> {
+    pub fn tui_next_jwt(&mut self) {
+        if self.jwt_popup.jwts.is_empty() {
+            return;
+        }
+
+        self.jwt_popup.selection = (self.jwt_popup.selection + 1) % self.jwt_popup.jwts.len();
+        self.tui_decode_selected_jwt();
+    }
+
+    pub fn tui_previous_jwt(&mut self) {
+        if self.jwt_popup.jwts.is_empty() {
+            return;
+        }
+
+        self.jwt_popup.selection = match self.jwt_popup.selection {
+            0 => self.jwt_popup.jwts.len() - 1,
+            selection => selection - 1
+        };
+        self.tui_decode_selected_jwt();
+    }
+
+    pub fn tui_decode_selected_jwt(&mut self) {
+        self.jwt_popup.decoded_jwt = self.jwt_popup.jwts
+            .get(self.jwt_popup.selection)
+            .map(|(_, token)| decode_jwt(token).map_err(|error| error.to_string()));
+        self.jwt_popup.verification = None;
+        self.jwt_popup.vertical_scroll = 0;
+    }
+
+    pub fn tui_cancel_jwt_input(&mut self) {
+        self.jwt_popup.input.reset_input();
+        self.state = AppState::DisplayingJwt;
+    }
+
+    /// A pasted token is decoded, a JWKS URL or a secret verifies the signature of the selected token
+    pub async fn tui_confirm_jwt_input(&mut self) {
+        let input = self.replace_env_keys_by_value(&self.jwt_popup.input.text.trim().to_string());
+
+        self.jwt_popup.input.reset_input();
+        self.state = AppState::DisplayingJwt;
+
+        if input.is_empty() {
+            return;
+        }
+
+        if is_jwt(&input) {
+            self.jwt_popup.jwts.push((String::from("Pasted"), input));
+            self.jwt_popup.selection = self.jwt_popup.jwts.len() - 1;
+            self.tui_decode_selected_jwt();
+            return;
+        }
+
+        let decoded_jwt = match &self.jwt_popup.decoded_jwt {
+            Some(Ok(decoded_jwt)) => decoded_jwt,
+            _ => return
+        };
+
+        let result = match input.starts_with("http://") || input.starts_with("https://") {
+            true => match fetch_jwks(&input).await {
+                Ok(jwks) => decoded_jwt.verify_with_jwks(&jwks),
+                Err(error) => Err(error)
+            },
+            false => decoded_jwt.verify_with_secret(&input)
+        };
+
+        self.jwt_popup.verification = Some(
+            result
+                .map(|_| format!("Valid {} signature", decoded_jwt.get_algorithm()))
+                .map_err(|error| error.to_string())
+        );
+    }
+}
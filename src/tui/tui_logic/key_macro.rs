@@ -0,0 +1,61 @@
+use crokey::KeyCombination;
+use tracing::info;
+
+use crate::app::app::App;
+
+impl App<'_> {
+    /// Starts the recording, or stops it and keeps the recorded keys as the macro of the workspace
+    pub fn tui_toggle_macro_recording(&mut self) {
+        // The replayed keys would be recorded again
+        if !self.macro_popup.pending_keys.is_empty() {
+            return;
+        }
+
+        match self.macro_popup.recording.take() {
+            None => {
+                info!("Recording macro");
+                self.macro_popup.recording = Some(vec![]);
+            },
+            Some(keys) if keys.is_empty() => info!("Empty macro discarded"),
+            Some(keys) => {
+                info!("Macro recorded, {} key(s)", keys.len());
+
+                self.macro_popup.keys = keys;
+                self.save_key_macro();
+            }
+        }
+    }
+
+    pub fn tui_replay_macro(&mut self) {
+        let count = match self.macro_popup.get_count() {
+            None => return,
+            Some(count) => count
+        };
+
+        info!("Replaying macro {count} time(s)");
+
+        for _ in 0..count {
+            self.macro_popup.pending_keys.extend(self.macro_popup.keys.iter().copied());
+        }
+
+        self.state = self.macro_popup.previous_state;
+    }
+
+    pub fn tui_cancel_macro_replay(&mut self) {
+        self.state = self.macro_popup.previous_state;
+    }
+
+    /// The pressed keys are recorded, except the macro ones
+    pub fn record_macro_key(&mut self, key: KeyCombination) {
+        if let Some(recording) = &mut self.macro_popup.recording {
+            recording.push(key);
+        }
+    }
+
+    pub fn stop_macro_replay(&mut self) {
+        if !self.macro_popup.pending_keys.is_empty() {
+            info!("Macro replay stopped");
+            self.macro_popup.pending_keys.clear();
+        }
+    }
+}
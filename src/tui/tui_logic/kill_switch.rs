@@ -0,0 +1,68 @@
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+use tracing::{info, warn};
+
+use crate::app::app::App;
+
+impl App<'_> {
+    /// Kill-switch: cancels every pending request and the queued sends, delays the monitors to their next period, stops the watches, the webhook listener, the load test and the macro replay, then goes back to the main menu
+    pub fn tui_abort_all(&mut self) {
+        let now = Instant::now();
+        let mut canceled_requests = 0;
+        let mut stopped_watches = 0;
+
+        // Canceled under a read lock first, a pending send reading the request until it is canceled
+        for collection in &self.collections {
+            for request in &collection.requests {
+                let request = request.read();
+
+                if request.is_pending {
+                    request.cancellation_token.cancel();
+                    canceled_requests += 1;
+                }
+
+                request.queued_sends.store(0, Ordering::Relaxed);
+            }
+        }
+
+        for collection in &self.collections {
+            for local_request in &collection.requests {
+                let has_timer = {
+                    let request = local_request.read();
+                    request.watch.is_some() || request.monitor.is_some()
+                };
+
+                if !has_timer {
+                    continue;
+                }
+
+                let mut request = local_request.write();
+
+                // Its timer goes with it, the watch would otherwise resume once the request is selected again
+                if request.watch.take().is_some() {
+                    stopped_watches += 1;
+                }
+
+                if let Some(monitor) = &mut request.monitor {
+                    if let Ok(interval) = monitor.get_interval() {
+                        monitor.next_run = Some(now + interval);
+                    }
+                }
+            }
+        }
+
+        if let Some(cancellation_token) = self.webhook_listener_popup.cancellation_token.take() {
+            cancellation_token.cancel();
+        }
+
+        self.load_test_popup.cancellation_token.cancel();
+
+        self.stop_macro_replay();
+
+        warn!("Kill-switch triggered, {canceled_requests} request(s) canceled, {stopped_watches} watch(es) stopped");
+        info!("Back to idle");
+
+        self.normal_state();
+    }
+}
@@ -1,6 +1,27 @@
 pub mod change_app_state;
 mod collection;
-mod request;
+pub mod request;
 mod param_tabs;
 mod result_tabs;
-mod environment;
\ No newline at end of file
+mod environment;mod webhook_listener;
+mod monitor;
+mod notification;
+mod cheatsheet;
+mod kill_switch;
+mod description;
+mod workspace;
+mod git;
+mod terminal;
+mod journal;
+mod scratchpad;
+mod file_watcher;
+mod jwt;
+
+mod encoding_tools;
+mod go_to_line;
+mod app_error;
+mod watch;
+mod key_macro;
+mod scripting_console;
+mod response_links;
+mod tags;
\ No newline at end of file
@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use tokio::task;
+
+use crate::app::app::App;
+use crate::app::business_logic::monitor::{failed_monitor_check, record_monitor_check, send_monitored_request};
+
+impl App<'_> {
+    /// Sends in the background every monitored request whose next check is due
+    pub async fn tui_run_due_monitors(&mut self) {
+        for (collection_index, request_index) in self.take_due_monitors(None) {
+            let local_request = self.get_request_as_local_from_indexes(&(collection_index, request_index));
+
+            let request = local_request.read().clone();
+            let prepared_request = self.prepare_request(&request).await;
+
+            match prepared_request {
+                Ok((prepared_request, _)) => {
+                    let local_env = self.get_selected_env_as_local();
+                    let offline = self.is_offline();
+
+                    let local_pending_notifications = match request.settings.notify_on_completion {
+                        true => Some(Arc::clone(&self.pending_notifications)),
+                        false => None
+                    };
+
+                    task::spawn(async move {
+                        let record = send_monitored_request(prepared_request, local_request, &local_env, offline).await;
+
+                        if let Some(local_pending_notifications) = local_pending_notifications {
+                            if record.is_failure {
+                                let status_code = record.status_code.unwrap_or_default();
+                                local_pending_notifications.write().push((format!("Monitor failed: {}", request.name), status_code));
+                            }
+                        }
+                    });
+                },
+                Err(prepare_request_error) => record_monitor_check(&local_request, failed_monitor_check(prepare_request_error.to_string()))
+            }
+        }
+    }
+}
@@ -0,0 +1,19 @@
+use crate::app::app::App;
+use crate::app::business_logic::notification::send_notification;
+
+impl App<'_> {
+    /// Notifications are queued by the background tasks and written here, so they never interleave with a frame being drawn
+    pub fn tui_send_pending_notifications(&mut self) {
+        let pending_notifications: Vec<(String, String)> = self.pending_notifications.write().drain(..).collect();
+
+        if pending_notifications.is_empty() {
+            return;
+        }
+
+        let method = self.config.get_notification_method();
+
+        for (title, body) in pending_notifications {
+            send_notification(method, &title, &body);
+        }
+    }
+}
@@ -6,9 +6,11 @@ impl App<'_> {
         self.request_param_tab = match self.request_param_tab {
             RequestParamsTabs::QueryParams => RequestParamsTabs::Auth,
             RequestParamsTabs::Auth => RequestParamsTabs::Headers,
-            RequestParamsTabs::Headers => RequestParamsTabs::Body,
+            RequestParamsTabs::Headers => RequestParamsTabs::Cookies,
+            RequestParamsTabs::Cookies => RequestParamsTabs::Body,
             RequestParamsTabs::Body => RequestParamsTabs::Scripts,
-            RequestParamsTabs::Scripts => RequestParamsTabs::QueryParams
+            RequestParamsTabs::Scripts => RequestParamsTabs::Docs,
+            RequestParamsTabs::Docs => RequestParamsTabs::QueryParams
         };
 
         self.tui_load_a_request_param_tab();
@@ -19,8 +21,10 @@ impl App<'_> {
             RequestParamsTabs::QueryParams => self.tui_load_request_query_params_tab(),
             RequestParamsTabs::Auth => self.tui_load_request_auth_param_tab(),
             RequestParamsTabs::Headers => self.tui_load_request_headers_tab(),
+            RequestParamsTabs::Cookies => self.tui_load_request_cookies_tab(),
             RequestParamsTabs::Body => self.tui_load_request_body_param_tab(),
-            RequestParamsTabs::Scripts => {}
+            RequestParamsTabs::Scripts => {},
+            RequestParamsTabs::Docs => self.request_description_scroll = 0
         }
     }
 
@@ -45,7 +49,16 @@ impl App<'_> {
         self.update_inputs();
     }
 
+    pub fn tui_load_request_cookies_tab(&mut self) {
+        self.tui_update_request_cookies_selection();
+
+        self.request_param_tab = RequestParamsTabs::Cookies;
+        self.update_inputs();
+    }
+
     pub fn tui_load_request_body_param_tab(&mut self) {
+        self.body_file_text_input_selection.selected = 0;
+
         self.request_param_tab = RequestParamsTabs::Body;
         self.update_inputs();
     }
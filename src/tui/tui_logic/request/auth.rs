@@ -1,6 +1,8 @@
 use crate::app::app::App;
-use crate::models::auth::Auth::{NoAuth, BasicAuth, BearerToken};
-use crate::models::auth::next_auth;
+use crate::app::business_logic::request::auth::{HmacAuthUpdate, OAuth1AuthUpdate};
+use crate::models::auth::Auth::{NoAuth, BasicAuth, BearerToken, HmacSignature, Ntlm, OAuth1, Plugin};
+use crate::models::auth::{next_auth, next_hmac_algorithm, next_oauth1_placement, next_oauth1_signature_method, next_signature_encoding};
+use crate::tui::utils::stateful::text_input::TextInput;
 
 impl App<'_> {
     pub fn tui_next_request_auth(&mut self) {
@@ -20,9 +22,10 @@ impl App<'_> {
 
     pub fn tui_select_request_auth_input_text(&mut self) {
         let local_selected_request = self.get_selected_request_as_local();
-        let selected_request = local_selected_request.read();
+        // Cloned so the request can be modified while matching
+        let auth = local_selected_request.read().auth.clone();
 
-        match selected_request.auth {
+        match auth {
             NoAuth => {}
             BasicAuth { .. } => match self.auth_text_input_selection.selected {
                 0 => self.edit_request_auth_username_state(),
@@ -32,10 +35,88 @@ impl App<'_> {
             BearerToken { .. } => match self.auth_text_input_selection.selected {
                 0 => self.edit_request_auth_bearer_token_state(),
                 _ => {}
+            },
+            HmacSignature { algorithm, encoding, .. } => {
+                let selected_request_index = &self.collections_tree.selected.unwrap();
+
+                // The algorithm and the encoding are cycled instead of edited
+                match self.auth_text_input_selection.selected {
+                    0..=2 => self.edit_request_auth_hmac_state(),
+                    3 => {
+                        self.modify_request_auth_hmac(selected_request_index.0, selected_request_index.1, HmacAuthUpdate { algorithm: Some(next_hmac_algorithm(&algorithm)), ..Default::default() });
+                        self.update_inputs();
+                    },
+                    4 => {
+                        self.modify_request_auth_hmac(selected_request_index.0, selected_request_index.1, HmacAuthUpdate { encoding: Some(next_signature_encoding(&encoding)), ..Default::default() });
+                        self.update_inputs();
+                    },
+                    _ => {}
+                }
+            },
+            Ntlm { .. } => match self.auth_text_input_selection.selected {
+                0..=2 => self.edit_request_auth_ntlm_state(),
+                _ => {}
+            },
+            OAuth1 { signature_method, placement, .. } => {
+                let selected_request_index = &self.collections_tree.selected.unwrap();
+
+                // The signature method and the placement are cycled instead of edited
+                match self.auth_text_input_selection.selected {
+                    0..=3 => self.edit_request_auth_oauth1_state(),
+                    4 => {
+                        self.modify_request_auth_oauth1(selected_request_index.0, selected_request_index.1, OAuth1AuthUpdate { signature_method: Some(next_oauth1_signature_method(&signature_method)), ..Default::default() });
+                        self.update_inputs();
+                    },
+                    5 => {
+                        self.modify_request_auth_oauth1(selected_request_index.0, selected_request_index.1, OAuth1AuthUpdate { placement: Some(next_oauth1_placement(&placement)), ..Default::default() });
+                        self.update_inputs();
+                    },
+                    _ => {}
+                }
+            },
+            Plugin { .. } => match self.auth_text_input_selection.selected {
+                0..=1 => self.edit_request_auth_plugin_state(),
+                _ => {}
             }
         }
     }
 
+    /// Secret, string to sign or header, following the auth input selection
+    pub fn get_selected_auth_hmac_text_input(&mut self) -> &mut TextInput {
+        match self.auth_text_input_selection.selected {
+            0 => &mut self.auth_hmac_secret_text_input,
+            1 => &mut self.auth_hmac_string_to_sign_text_input,
+            _ => &mut self.auth_hmac_header_text_input
+        }
+    }
+
+    /// Username, password or domain, following the auth input selection
+    pub fn get_selected_auth_ntlm_text_input(&mut self) -> &mut TextInput {
+        match self.auth_text_input_selection.selected {
+            0 => &mut self.auth_ntlm_username_text_input,
+            1 => &mut self.auth_ntlm_password_text_input,
+            _ => &mut self.auth_ntlm_domain_text_input
+        }
+    }
+
+    /// Consumer key or secret, token or token secret, following the auth input selection
+    pub fn get_selected_auth_oauth1_text_input(&mut self) -> &mut TextInput {
+        match self.auth_text_input_selection.selected {
+            0 => &mut self.auth_oauth1_consumer_key_text_input,
+            1 => &mut self.auth_oauth1_consumer_secret_text_input,
+            2 => &mut self.auth_oauth1_token_text_input,
+            _ => &mut self.auth_oauth1_token_secret_text_input
+        }
+    }
+
+    /// Plugin name or config, following the auth input selection
+    pub fn get_selected_auth_plugin_text_input(&mut self) -> &mut TextInput {
+        match self.auth_text_input_selection.selected {
+            0 => &mut self.auth_plugin_name_text_input,
+            _ => &mut self.auth_plugin_config_text_input
+        }
+    }
+
     pub fn tui_modify_request_auth_basic_username(&mut self) {
         let input_text = self.auth_basic_username_text_input.text.clone();
         let selected_request_index = &self.collections_tree.selected.unwrap();
@@ -62,4 +143,88 @@ impl App<'_> {
 
         self.select_request_state();
     }
+
+    pub fn tui_modify_request_auth_hmac(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.auth_text_input_selection.selected {
+            0 => {
+                let input_text = self.auth_hmac_secret_text_input.text.clone();
+                self.modify_request_auth_hmac(selected_request_index.0, selected_request_index.1, HmacAuthUpdate { secret: Some(input_text), ..Default::default() });
+            },
+            1 => {
+                let input_text = self.auth_hmac_string_to_sign_text_input.text.clone();
+                self.modify_request_auth_hmac(selected_request_index.0, selected_request_index.1, HmacAuthUpdate { string_to_sign: Some(input_text), ..Default::default() });
+            },
+            _ => {
+                let input_text = self.auth_hmac_header_text_input.text.clone();
+                self.modify_request_auth_hmac(selected_request_index.0, selected_request_index.1, HmacAuthUpdate { header: Some(input_text), ..Default::default() });
+            }
+        }
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_ntlm(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.auth_text_input_selection.selected {
+            0 => {
+                let input_text = self.auth_ntlm_username_text_input.text.clone();
+                self.modify_request_auth_ntlm(selected_request_index.0, selected_request_index.1, Some(input_text), None, None);
+            },
+            1 => {
+                let input_text = self.auth_ntlm_password_text_input.text.clone();
+                self.modify_request_auth_ntlm(selected_request_index.0, selected_request_index.1, None, Some(input_text), None);
+            },
+            _ => {
+                let input_text = self.auth_ntlm_domain_text_input.text.clone();
+                self.modify_request_auth_ntlm(selected_request_index.0, selected_request_index.1, None, None, Some(input_text));
+            }
+        }
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_oauth1(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.auth_text_input_selection.selected {
+            0 => {
+                let input_text = self.auth_oauth1_consumer_key_text_input.text.clone();
+                self.modify_request_auth_oauth1(selected_request_index.0, selected_request_index.1, OAuth1AuthUpdate { consumer_key: Some(input_text), ..Default::default() });
+            },
+            1 => {
+                let input_text = self.auth_oauth1_consumer_secret_text_input.text.clone();
+                self.modify_request_auth_oauth1(selected_request_index.0, selected_request_index.1, OAuth1AuthUpdate { consumer_secret: Some(input_text), ..Default::default() });
+            },
+            2 => {
+                let input_text = self.auth_oauth1_token_text_input.text.clone();
+                self.modify_request_auth_oauth1(selected_request_index.0, selected_request_index.1, OAuth1AuthUpdate { token: Some(input_text), ..Default::default() });
+            },
+            _ => {
+                let input_text = self.auth_oauth1_token_secret_text_input.text.clone();
+                self.modify_request_auth_oauth1(selected_request_index.0, selected_request_index.1, OAuth1AuthUpdate { token_secret: Some(input_text), ..Default::default() });
+            }
+        }
+
+        self.select_request_state();
+    }
+
+    pub fn tui_modify_request_auth_plugin(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.auth_text_input_selection.selected {
+            0 => {
+                let input_text = self.auth_plugin_name_text_input.text.clone();
+                self.modify_request_auth_plugin(selected_request_index.0, selected_request_index.1, Some(input_text), None);
+            },
+            _ => {
+                let input_text = self.auth_plugin_config_text_input.text.clone();
+                self.modify_request_auth_plugin(selected_request_index.0, selected_request_index.1, None, Some(input_text));
+            }
+        }
+
+        self.select_request_state();
+    }
 }
\ No newline at end of file
@@ -1,10 +1,19 @@
 use reqwest::header::CONTENT_TYPE;
 use tracing::{info};
-use tui_textarea::TextArea;
+use tui_textarea::{CursorMove, TextArea};
 use rayon::prelude::*;
 
 use crate::app::app::App;
-use crate::models::body::{ContentType, next_content_type};
+use crate::app::business_logic::json::format_json;
+use crate::app::files::utils::get_path_completions;
+use crate::models::body::{BodyFile, ContentType, next_content_type};
+use crate::tui::utils::completion::get_common_prefix;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+/// Name, value, source, content type and file name
+pub const MULTIPART_TABLE_COLUMNS: usize = 5;
+/// Cycled with the edit key instead of being edited
+pub const MULTIPART_SOURCE_COLUMN: usize = 2;
 
 impl App<'_> {
     /// Reset selection if body form data is provided, either set it to none
@@ -43,6 +52,33 @@ impl App<'_> {
         self.select_request_state();
     }
 
+    pub fn tui_edit_request_body_table(&mut self) {
+        let is_multipart = matches!(self.get_selected_request_as_local().read().body, ContentType::Multipart(_));
+
+        match self.body_form_table.selection {
+            Some((row, MULTIPART_SOURCE_COLUMN)) if is_multipart => {
+                let selected_request_index = &self.collections_tree.selected.unwrap();
+
+                if self.next_multipart_part_source(selected_request_index.0, selected_request_index.1, row).is_err() {
+                    return;
+                }
+
+                self.update_inputs();
+            },
+            _ => self.edit_request_body_table_state()
+        }
+    }
+
+    pub fn tui_move_request_body_table_column(&mut self, is_next: bool) {
+        let is_multipart = matches!(self.get_selected_request_as_local().read().body, ContentType::Multipart(_));
+
+        match (is_multipart, is_next) {
+            (true, true) => self.body_form_table.next_column(MULTIPART_TABLE_COLUMNS),
+            (true, false) => self.body_form_table.previous_column(MULTIPART_TABLE_COLUMNS),
+            (false, _) => self.body_form_table.change_y()
+        }
+    }
+
     pub fn tui_create_new_form_data(&mut self) {
         let selected_request_index = &self.collections_tree.selected.unwrap();
 
@@ -102,19 +138,25 @@ impl App<'_> {
             let mut selected_request = local_selected_request.write();
 
             let body_form = &self.body_form_table.rows;
-            let body_file = &self.body_file_text_input.text;
+            let body_file_path = &self.body_file_text_input.text;
             let body_string = self.body_text_area.lines().join("\n");
 
-            let new_body = match selected_request.body {
+            let new_body = match &selected_request.body {
                 ContentType::NoBody => ContentType::NoBody,
-                ContentType::Multipart(_) => ContentType::Multipart(body_form.clone()),
+                ContentType::Multipart(parts) => ContentType::Multipart(parts.clone()),
                 ContentType::Form(_) => ContentType::Form(body_form.clone()),
-                ContentType::File(_) => ContentType::File(body_file.clone()),
+                ContentType::File(body_file) => ContentType::File(BodyFile {
+                    path: body_file_path.clone(),
+                    content_type: body_file.content_type.clone()
+                }),
                 ContentType::Raw(_) => ContentType::Raw(body_string.clone()),
                 ContentType::Json(_) => ContentType::Json(body_string.clone()),
                 ContentType::Xml(_) => ContentType::Xml(body_string.clone()),
                 ContentType::Html(_) => ContentType::Html(body_string.clone()),
                 ContentType::Javascript(_) => ContentType::Javascript(body_string.clone()),
+                ContentType::Protobuf(_) => ContentType::Protobuf(body_string.clone()),
+                ContentType::MessagePack(_) => ContentType::MessagePack(body_string.clone()),
+                ContentType::Cbor(_) => ContentType::Cbor(body_string.clone()),
             };
 
             info!("Body set to \"{}\"", new_body);
@@ -126,6 +168,32 @@ impl App<'_> {
         self.select_request_state();
     }
 
+    /// The body is saved with the rest of the edit, the formatting can be undone
+    pub fn tui_format_json_body(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
+
+        if !matches!(local_selected_request.read().body, ContentType::Json(_)) {
+            return;
+        }
+
+        let body_string = self.body_text_area.lines().join("\n");
+
+        // The syntax error is already displayed under the body
+        let formatted_body = match format_json(&body_string) {
+            Ok(formatted_body) => formatted_body,
+            Err(_) => return
+        };
+
+        if formatted_body == body_string {
+            return;
+        }
+
+        self.body_text_area.select_all();
+        self.body_text_area.insert_str(formatted_body);
+        self.body_text_area.move_cursor(CursorMove::Jump(0, 0));
+    }
+
     pub fn tui_modify_request_content_type(&mut self) {
         let selected_request_index = &self.collections_tree.selected.unwrap();
         let local_selected_request = self.get_request_as_local_from_indexes(selected_request_index);
@@ -147,7 +215,7 @@ impl App<'_> {
                 // TODO: Impossible to set the header for multipart yet, because of boundary and content-length that are computed on reqwest's side
                 ContentType::Multipart(_) => {},
                 // Create or replace Content-Type header with new body content type
-                ContentType::File(_) | ContentType::Form(_) | ContentType::Raw(_) | ContentType::Json(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) => {
+                ContentType::File(_) | ContentType::Form(_) | ContentType::Raw(_) | ContentType::Json(_) | ContentType::Xml(_) | ContentType::Html(_) | ContentType::Javascript(_) | ContentType::Protobuf(_) | ContentType::MessagePack(_) | ContentType::Cbor(_) => {
                     let content_type = &selected_request.body.to_content_type();
                     selected_request.modify_or_create_header(CONTENT_TYPE.as_str(), content_type)
                 }
@@ -158,4 +226,70 @@ impl App<'_> {
         self.tui_update_body_table_selection();
         self.tui_load_request_body_param_tab();
     }
-}
\ No newline at end of file
+
+    /// Path or content type, following the body file input selection
+    pub fn get_selected_body_file_text_input(&mut self) -> &mut TextInput {
+        match self.body_file_text_input_selection.selected {
+            0 => &mut self.body_file_text_input,
+            _ => &mut self.body_file_content_type_text_input
+        }
+    }
+
+    pub fn tui_modify_request_body_file(&mut self) {
+        if self.body_file_text_input_selection.selected == 0 {
+            self.tui_next_request_body();
+            return;
+        }
+
+        let content_type = self.body_file_content_type_text_input.text.trim().to_string();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        let body_file = BodyFile {
+            path: self.body_file_text_input.text.clone(),
+            content_type: match content_type.is_empty() {
+                true => None,
+                false => Some(content_type)
+            },
+        };
+
+        // Also replaces the Content-Type header
+        self.modify_request_content_type(selected_request_index.0, selected_request_index.1, ContentType::File(body_file)).ok();
+
+        self.select_request_state();
+    }
+
+    /// Lists the paths completing the body file path being edited
+    pub fn tui_update_body_file_completions(&mut self) {
+        let completions = match self.body_file_text_input_selection.selected {
+            0 => get_path_completions(&self.body_file_text_input.text),
+            _ => vec![]
+        };
+
+        // Nothing left to complete
+        self.body_file_completion_popup.choices = match completions.len() == 1 && completions[0] == self.body_file_text_input.text {
+            true => vec![],
+            false => completions
+        };
+        self.body_file_completion_popup.selection = 0;
+    }
+
+    /// Completes the path up to the part shared by every completion, or with the highlighted completion
+    pub fn tui_complete_body_file_path(&mut self) {
+        let completions = &self.body_file_completion_popup.choices;
+
+        if completions.is_empty() {
+            return;
+        }
+
+        let common_prefix = get_common_prefix(completions);
+
+        let completed_path = match common_prefix.len() > self.body_file_text_input.text.len() {
+            true => common_prefix,
+            false => completions[self.body_file_completion_popup.selection].clone()
+        };
+
+        self.body_file_text_input.reset_input();
+        self.body_file_text_input.enter_str(&completed_path);
+        self.tui_update_body_file_completions();
+    }
+}
@@ -1,6 +1,31 @@
+use std::sync::Arc;
+
 use crate::app::app::App;
+use crate::tui::utils::stateful::cookie_table::{cookie_to_row, CookieColumns};
 
 impl App<'_> {
+    /// Reload the rows from the cookie store, keeping the selection when possible
+    pub fn tui_refresh_cookies_table(&mut self) {
+        let local_cookie_store = Arc::clone(&self.cookies_popup.cookie_store);
+
+        self.cookies_popup.cookies_table.rows = vec![];
+
+        for cookie in local_cookie_store.read().unwrap().iter_any() {
+            self.cookies_popup.cookies_table.rows.push(cookie_to_row(cookie))
+        }
+
+        match self.cookies_popup.cookies_table.selection {
+            Some((x, y)) if x < self.cookies_popup.cookies_table.rows.len() => {
+                self.cookies_popup.cookies_table.selection = Some((x, y));
+
+                for table_state in self.cookies_popup.cookies_table.lists_states.iter_mut() {
+                    table_state.select(Some(x));
+                }
+            },
+            _ => self.tui_update_cookies_table_selection()
+        }
+    }
+
     pub fn tui_update_cookies_table_selection(&mut self) {
         match self.cookies_popup.cookies_table.rows.is_empty() {
             false => {
@@ -26,18 +51,53 @@ impl App<'_> {
         }
 
         let selection = self.cookies_popup.cookies_table.selection.unwrap();
-        let cookie_row = self.cookies_popup.cookies_table.rows.remove(selection.0);
-        
-        {
-            let mut local_cookie_store = self.cookies_popup.cookie_store.write().unwrap();
-
-            local_cookie_store.remove(
-                &cookie_row[0],
-                &cookie_row[3],
-                &cookie_row[1],
-            );
+        let cookie_row = &self.cookies_popup.cookies_table.rows[selection.0];
+
+        self.delete_cookie(
+            &cookie_row[CookieColumns::Domain as usize],
+            &cookie_row[CookieColumns::Path as usize],
+            &cookie_row[CookieColumns::Name as usize],
+        );
+
+        self.tui_refresh_cookies_table();
+    }
+
+    pub fn tui_delete_domain_cookies(&mut self) {
+        if self.cookies_popup.cookies_table.rows.is_empty() || self.cookies_popup.cookies_table.selection.is_none() {
+            return;
+        }
+
+        let selection = self.cookies_popup.cookies_table.selection.unwrap();
+        let domain = self.cookies_popup.cookies_table.rows[selection.0][CookieColumns::Domain as usize].clone();
+
+        self.delete_domain_cookies(&domain);
+
+        self.tui_refresh_cookies_table();
+    }
+
+    pub fn tui_modify_cookie(&mut self) {
+        let selection = match self.cookies_popup.cookies_table.selection {
+            None => return,
+            Some(selection) => selection
+        };
+
+        let previous_row = self.cookies_popup.cookies_table.rows[selection.0].clone();
+        let mut new_row = previous_row.clone();
+        new_row[selection.1] = self.cookies_popup.cookies_table.selection_text_input.text.clone();
+
+        match self.modify_cookie(&previous_row, &new_row) {
+            Ok(_) => self.display_cookies_state(),
+            // Stays in edition so that the input can be fixed
+            Err(error) => self.cookies_popup.error = Some(error.to_string())
         }
+    }
+
+    pub fn tui_create_cookie(&mut self) {
+        let input = self.cookies_popup.cookies_table.selection_text_input.text.clone();
 
-        self.tui_update_cookies_table_selection();
+        match self.create_cookie(&input) {
+            Ok(_) => self.display_cookies_state(),
+            Err(error) => self.cookies_popup.error = Some(error.to_string())
+        }
     }
-}
\ No newline at end of file
+}
@@ -1,4 +1,7 @@
 use crate::app::app::App;
+use crate::models::header::{get_common_header_values, STANDARD_HEADERS};
+use crate::models::user_agent::USER_AGENT_PRESETS;
+use crate::tui::utils::completion::{get_common_prefix, get_completions, get_env_key_start};
 
 impl App<'_> {
     /// Reset selection if headers are provided, either set it to none
@@ -34,6 +37,91 @@ impl App<'_> {
         self.select_request_state();
     }
 
+    /// Lists the names or values completing the header cell being edited.
+    /// The headers already used in the workspace come first, then the standard ones
+    pub fn tui_update_header_completions(&mut self) {
+        self.header_completion_popup.selection = 0;
+
+        let (row, column) = match self.headers_table.selection {
+            Some(selection) => selection,
+            None => {
+                self.header_completion_popup.choices = vec![];
+                return;
+            }
+        };
+
+        let input = self.headers_table.selection_text_input.text.clone();
+
+        // The environment keys are completed instead
+        if get_env_key_start(&input[..self.headers_table.selection_text_input.cursor_position]).is_some() {
+            self.header_completion_popup.choices = vec![];
+            return;
+        }
+
+        let header_name = self.headers_table.rows[row].data.0.clone();
+
+        let mut workspace_names: Vec<String> = vec![];
+        let mut workspace_values: Vec<String> = vec![];
+
+        for collection in &self.collections {
+            for request in &collection.requests {
+                for header in &request.read().headers {
+                    workspace_names.push(header.data.0.clone());
+
+                    if header.data.0.eq_ignore_ascii_case(&header_name) && !header.data.1.is_empty() {
+                        workspace_values.push(header.data.1.clone());
+                    }
+                }
+            }
+        }
+
+        workspace_names.sort();
+        workspace_values.sort();
+
+        self.header_completion_popup.choices = match column {
+            0 => get_completions(
+                &input,
+                workspace_names.iter().map(String::as_str).chain(STANDARD_HEADERS)
+            ),
+            _ => match input.rfind(',') {
+                // E.g. "no-cache, max-age=0", only the last directive is completed
+                Some(index) => {
+                    let previous_values = input[..=index].to_string() + " ";
+                    let last_value = input[index + 1..].trim_start();
+
+                    get_completions(last_value, get_common_header_values(&header_name).iter().copied())
+                        .into_iter()
+                        .map(|completion| format!("{previous_values}{completion}"))
+                        .collect()
+                },
+                None => get_completions(
+                    &input,
+                    workspace_values.iter().map(String::as_str).chain(get_common_header_values(&header_name).iter().copied())
+                )
+            }
+        };
+    }
+
+    /// Completes the header cell up to the part shared by every completion, or with the highlighted completion
+    pub fn tui_complete_header(&mut self) {
+        let completions = &self.header_completion_popup.choices;
+
+        if completions.is_empty() {
+            return;
+        }
+
+        let common_prefix = get_common_prefix(completions);
+
+        let completed_text = match common_prefix.len() > self.headers_table.selection_text_input.text.len() {
+            true => common_prefix,
+            false => completions[self.header_completion_popup.selection].clone()
+        };
+
+        self.headers_table.selection_text_input.reset_input();
+        self.headers_table.selection_text_input.enter_str(&completed_text);
+        self.tui_update_header_completions();
+    }
+
     pub fn tui_create_new_header(&mut self) {
         let selected_request_index = &self.collections_tree.selected.unwrap();
 
@@ -78,4 +166,17 @@ impl App<'_> {
         
         self.update_inputs();
     }
+
+    pub fn tui_select_request_user_agent(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let user_agent = USER_AGENT_PRESETS[self.user_agent_popup.selection].1.clone();
+
+        match self.set_request_user_agent(selected_request_index.0, selected_request_index.1, user_agent) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.tui_update_headers_selection();
+        self.select_request_state();
+    }
 }
\ No newline at end of file
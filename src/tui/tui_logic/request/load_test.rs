@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use tokio::task;
+use tokio_util::sync::CancellationToken;
+
+use crate::app::app::App;
+use crate::app::business_logic::load_test::run_load_test;
+use crate::models::load_test::LoadTestStats;
+
+impl App<'_> {
+    pub async fn tui_start_load_test(&mut self) {
+        // Stops the previous run if any
+        self.load_test_popup.cancellation_token.cancel();
+
+        let cancellation_token = CancellationToken::new();
+        self.load_test_popup.cancellation_token = cancellation_token.clone();
+
+        *self.load_test_popup.stats.write() = LoadTestStats::default();
+        *self.load_test_popup.error.write() = None;
+
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read().clone();
+
+        let prepared_request = match self.prepare_request(&selected_request).await {
+            Ok((prepared_request, _)) => prepared_request,
+            Err(prepare_request_error) => {
+                *self.load_test_popup.error.write() = Some(prepare_request_error.to_string());
+                return;
+            }
+        };
+
+        let options = self.config.get_load_test_options();
+        let local_stats = Arc::clone(&self.load_test_popup.stats);
+        let local_error = Arc::clone(&self.load_test_popup.error);
+
+        task::spawn(async move {
+            if let Err(load_test_error) = run_load_test(prepared_request, options, local_stats, cancellation_token).await {
+                *local_error.write() = Some(load_test_error.to_string());
+            }
+        });
+    }
+
+    pub fn tui_quit_load_test(&mut self) {
+        self.load_test_popup.cancellation_token.cancel();
+        self.select_request_state();
+    }
+}
@@ -1,6 +1,7 @@
 pub mod auth;
 pub mod body;
 pub mod headers;
+pub mod request_cookies;
 pub mod method;
 pub mod query_params;
 pub mod send;
@@ -9,3 +10,5 @@ pub mod url;
 pub(super) mod utils;
 mod cookies;
 pub mod scripts;
+
+mod load_test;
\ No newline at end of file
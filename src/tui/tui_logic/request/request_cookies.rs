@@ -0,0 +1,81 @@
+use crate::app::app::App;
+
+impl App<'_> {
+    /// Reset selection if request cookies are provided, either set it to none
+    pub fn tui_update_request_cookies_selection(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        match selected_request.cookies.is_empty() {
+            false => {
+                self.request_cookies_table.selection = Some((0, 0));
+                self.request_cookies_table.left_state.select(Some(0));
+                self.request_cookies_table.right_state.select(Some(0));
+            },
+            true => {
+                self.request_cookies_table.selection = None;
+                self.request_cookies_table.left_state.select(None);
+                self.request_cookies_table.right_state.select(None);
+            }
+        }
+    }
+
+    pub fn tui_modify_request_cookie(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        let selection = self.request_cookies_table.selection.unwrap();
+        let input_text = self.request_cookies_table.selection_text_input.text.clone();
+
+        match self.modify_request_cookie(selected_request_index.0, selected_request_index.1, input_text, selection.1, selection.0) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.select_request_state();
+    }
+
+    pub fn tui_create_new_request_cookie(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+
+        match self.create_new_request_cookie(selected_request_index.0, selected_request_index.1, String::from("cookie"), String::from("value")) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.tui_update_request_cookies_selection();
+        self.update_inputs();
+    }
+
+    pub fn tui_delete_request_cookie(&mut self) {
+        if self.request_cookies_table.rows.is_empty() || self.request_cookies_table.selection.is_none() {
+            return;
+        }
+
+        let selection = self.request_cookies_table.selection.unwrap();
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        
+        match self.delete_request_cookie(selected_request_index.0, selected_request_index.1, selection.0) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+        
+        self.tui_update_request_cookies_selection();
+        self.update_inputs();
+    }
+
+    pub fn tui_toggle_request_cookie(&mut self) {
+        if self.request_cookies_table.rows.is_empty() || self.request_cookies_table.selection.is_none() {
+            return;
+        }
+
+        let row = self.request_cookies_table.selection.unwrap().0;
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        
+        match self.toggle_request_cookie(selected_request_index.0, selected_request_index.1, None, row) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+        
+        self.update_inputs();
+    }
+}
\ No newline at end of file
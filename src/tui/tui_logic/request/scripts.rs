@@ -2,6 +2,7 @@ use rayon::prelude::*;
 use tui_textarea::TextArea;
 
 use crate::app::app::App;
+use crate::models::scripts::ScriptType;
 
 impl App<'_> {
     pub fn tui_refresh_pre_request_script_textarea(&mut self, text: &str) {
@@ -63,4 +64,22 @@ impl App<'_> {
         self.save_collection_to_file(selected_request_index.0);
         self.select_request_state();
     }
+
+    /// A blank command removes the response processor
+    pub fn tui_modify_response_processor(&mut self) {
+        let selected_request_index = &self.collections_tree.selected.unwrap();
+        let response_processor = self.script_console.response_processor_text_input.text.trim().to_string();
+
+        let response_processor = match response_processor.is_empty() {
+            true => None,
+            false => Some(response_processor)
+        };
+
+        match self.modify_request_script(selected_request_index.0, selected_request_index.1, &ScriptType::Processor, response_processor) {
+            Ok(_) => {}
+            Err(_) => return
+        }
+
+        self.select_request_state();
+    }
 }
\ No newline at end of file
@@ -1,13 +1,45 @@
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::time::Instant;
 
 use ratatui::style::Stylize;
 use ratatui::text::Line;
+use parking_lot::RwLock;
+use reqwest::cookie::CookieStore;
 use tokio::task;
-use tracing::info;
+use tracing::{error, info, warn};
 use crate::app::app::App;
+use crate::app::business_logic::app_error::report_error;
+use crate::app::business_logic::request::cors::send_cors_preflight;
 use crate::app::business_logic::request::send::send_request;
+use crate::app::files::cookies::save_cookie_jar_to_file;
 use crate::app::files::theme::THEME;
-use crate::tui::utils::syntax_highlighting::highlight;
+use crate::models::request::Request;
+use crate::models::response::{RequestResult, ResponseContent};
+use crate::models::response_cache::{CacheRequest, CacheStatus, ResponseCache};
+use crate::tui::utils::response_views::ResponseView;
+use crate::tui::utils::syntax_highlighting::{get_csv_table, get_ndjson_records, highlight, BodyHighlightingLanguage, CsvTable, NdjsonRecords};
+
+/// How a request is sent, besides the normal send
+pub enum SendMode {
+    Normal,
+    /// The preflight a browser from this origin would send is sent first
+    SimulateCors(String),
+    /// The validators of the last response are sent
+    Revalidate,
+    /// The response cache is not looked up, the new response replaces the cached one
+    RefreshCache,
+}
+
+/// Response received by a request sent from the TUI, shown by the main loop if the request is still selected
+pub struct ReceivedResponse {
+    pub request: Arc<RwLock<Request>>,
+    pub file_format: Option<String>,
+    pub pre_request_console_output: String,
+    pub post_request_console_output: String,
+    pub ndjson_records: Option<NdjsonRecords>,
+    pub csv_table: Option<CsvTable>,
+}
 
 impl App<'_> {
     pub async fn tui_send_request(&mut self) {
@@ -17,83 +49,427 @@ impl App<'_> {
             let selected_request = local_selected_request.read();
             
             if selected_request.is_pending {
-                selected_request.cancellation_token.cancel();
-                info!("Request canceled");
+                match selected_request.settings.queue_sends {
+                    true => {
+                        let queued_sends = selected_request.queued_sends.fetch_add(1, Ordering::Relaxed) + 1;
+                        info!("Send queued, {queued_sends} waiting");
+                    },
+                    false => {
+                        selected_request.cancellation_token.cancel();
+                        info!("Request canceled");
+                    }
+                }
                 return;
             }
         }
-        
-        let mut selected_request = local_selected_request.write();
 
-        /* PRE-REQUEST SCRIPT */
-        let mut local_console_output = self.script_console.console_output.write();
-        let mut local_highlighted_console_output = self.syntax_highlighting.highlighted_console_output.write();
+        self.tui_send_local_request(local_selected_request, true, SendMode::Normal).await;
+    }
+
+    /// Sends the request the way a browser would from the CORS origin, the verdicts are added to its tests
+    pub async fn tui_simulate_cors(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+
+        if local_selected_request.read().is_pending {
+            return;
+        }
+
+        let cors_origin = self.config.get_cors_origin().to_string();
+
+        self.tui_send_local_request(local_selected_request, true, SendMode::SimulateCors(cors_origin)).await;
+    }
+
+    /// Re-sends the request with the validators of its last response, the server answers 304 when nothing changed
+    pub async fn tui_revalidate_request(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+
+        {
+            let selected_request = local_selected_request.read();
+
+            if selected_request.is_pending {
+                return;
+            }
+
+            if selected_request.cache_validators.is_empty() {
+                warn!("No ETag or Last-Modified received yet, nothing to revalidate");
+                return;
+            }
+        }
+
+        self.tui_send_local_request(local_selected_request, true, SendMode::Revalidate).await;
+    }
+
+    /// Sends the request without looking the response cache up
+    pub async fn tui_refresh_request(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+
+        if local_selected_request.read().is_pending {
+            return;
+        }
+
+        self.tui_send_local_request(local_selected_request, true, SendMode::RefreshCache).await;
+    }
 
-        // Resets the data
-        *local_console_output = None;
-        *local_highlighted_console_output = vec![];
+    /// Send the request in the background, a 401 sends the collection login request and retries once when allowed.
+    /// With the auto revalidate setting, the validators of the last response are always sent.
+    /// A fresh response of the response cache is used instead of sending the request, when the cache is enabled.
+    /// Offline, the request is answered with its snapshot and neither the cache nor the CORS preflight are used.
+    /// Any request can be sent, the response is only shown if the request is selected once received
+    pub async fn tui_send_local_request(&mut self, local_request: Arc<RwLock<Request>>, can_login_again: bool, send_mode: SendMode) {
+        let local_pending_logins = match can_login_again && self.get_login_request_as_local(&local_request).is_some() {
+            true => Some(Arc::clone(&self.pending_logins)),
+            false => None
+        };
+
+        let is_selected = self.is_selected_request(&local_request);
+
+        // Cloned so that the request is not locked while the pre-request script runs
+        let request = local_request.read().clone();
+
+        /* PRE-REQUEST SCRIPT */
 
-        let (prepared_request, console_output) = match self.prepare_request(&*selected_request).await {
+        let (prepared_request, pre_request_console_output) = match self.prepare_request(&request).await {
             Ok(result) => result,
             Err(prepare_request_error) => {
-                selected_request.response.status_code = Some(prepare_request_error.to_string());
+                let message = format!("Could not prepare \"{}\"", request.name);
+
+                match prepare_request_error.get_remediation_hint() {
+                    Some(hint) => report_error(&message, &prepare_request_error, hint),
+                    None => error!("{message}: {prepare_request_error}")
+                }
+
+                local_request.write().response.result = Some(RequestResult::local_error(prepare_request_error));
                 return;
             }
         };
 
-        let mut highlighted_console_output = highlight(&console_output, "json").unwrap();
+        if is_selected {
+            // A new response gets its language auto-detected again
+            self.syntax_highlighting.body_language = BodyHighlightingLanguage::Auto;
+            set_console_output(&self.get_shown_response_view(), &pre_request_console_output, None);
+        }
 
-        highlighted_console_output.insert(0, Line::default());
-        highlighted_console_output.insert(1, Line::raw("----- Pre-request script start -----").fg(THEME.read().ui.secondary_foreground_color).centered());
-        highlighted_console_output.push(Line::raw("----- Pre-request script end -----").fg(THEME.read().ui.secondary_foreground_color).centered());
+        let should_revalidate = matches!(send_mode, SendMode::Revalidate) || request.settings.auto_revalidate;
 
-        *local_highlighted_console_output = highlighted_console_output;
+        let prepared_request = match should_revalidate {
+            true => request.cache_validators.add_conditional_headers(prepared_request),
+            false => prepared_request
+        };
 
-        *local_console_output = Some(console_output);
+        let local_request = local_request.clone();
+        let local_env = self.get_selected_env_as_local();
+        let offline = self.is_offline();
+        let should_highlight = !self.config.is_syntax_highlighting_disabled();
+        let local_pending_received_responses = Arc::clone(&self.pending_received_responses);
 
-        // Drops the write mutex
-        drop(local_console_output);
-        drop(local_highlighted_console_output);
+        let local_pending_notifications = match request.settings.notify_on_completion {
+            true => Some(Arc::clone(&self.pending_notifications)),
+            false => None
+        };
+        let notification_minimum_duration = self.config.get_notification_minimum_duration();
+        let request_name = request.name.clone();
 
-        let local_selected_request = self.get_selected_request_as_local();
-        let local_env = self.get_selected_env_as_local();
-        let local_console_output = Arc::clone(&self.script_console.console_output);
-        let local_highlighted_body = Arc::clone(&self.syntax_highlighting.highlighted_body);
-        let local_highlighted_console_output = Arc::clone(&self.syntax_highlighting.highlighted_console_output);
+        let local_cookie_jar = match request.settings.store_received_cookies {
+            true => self.get_cookie_jar_path().map(|path| (path, Arc::clone(&self.cookies_popup.cookie_store))),
+            false => None
+        };
+
+        /* RESPONSE CACHE */
+
+        let local_response_cache = match self.config.is_response_cache_enabled() && !offline {
+            true => Some(Arc::clone(&self.response_cache_popup.response_cache)),
+            false => None
+        };
+
+        // The URL once the environment values substituted, and the headers the stored responses may vary on
+        let request_target = match &local_response_cache {
+            None => None,
+            Some(_) => prepared_request
+                .try_clone()
+                .and_then(|request| request.build().ok())
+                .map(|request| {
+                    let mut url = request.url().clone();
+
+                    let mut headers: Vec<(String, String)> = request.headers()
+                        .iter()
+                        .map(|(header_name, header_value)| (header_name.to_string(), header_value.to_str().unwrap_or("").to_string()))
+                        .collect();
+
+                    // Added by the client when sending
+                    if let Some(cookies) = self.cookies_popup.cookie_store.cookies(&url) {
+                        headers.push((String::from("cookie"), cookies.to_str().unwrap_or("").to_string()));
+                    }
+
+                    // Same key with or without the trailing "?" of an empty query
+                    if url.query() == Some("") {
+                        url.set_query(None);
+                    }
+
+                    let cache_request = CacheRequest {
+                        headers,
+                        environment: local_env.as_ref().map(|env| env.read().name.clone()),
+                    };
+
+                    (request.method().to_string(), url.to_string(), cache_request)
+                })
+        };
+
+        // The CORS simulation and the revalidation always reach the server, the other methods may modify the cached resources
+        let cache_key = match (&local_response_cache, request_target, &send_mode) {
+            (Some(_), Some((method, url, cache_request)), SendMode::Normal | SendMode::RefreshCache) if ResponseCache::is_cacheable_method(&method) => Some((ResponseCache::get_key(&method, &url), cache_request)),
+            (Some(response_cache), Some((method, url, _)), _) if !ResponseCache::is_cacheable_method(&method) => {
+                response_cache.write().invalidate_url(&url);
+                None
+            },
+            _ => None
+        };
+
+        let cached_response = match (&local_response_cache, &cache_key, &send_mode) {
+            (Some(response_cache), Some((cache_key, cache_request)), SendMode::Normal) => response_cache
+                .write()
+                .lookup(cache_key, cache_request)
+                .map(|(response, file_format)| (response, String::new(), file_format)),
+            _ => None
+        };
 
         /* SEND REQUEST */
 
         task::spawn(async move {
-            let (response, result_console_output, highlighted_body) = match send_request(prepared_request, local_selected_request.clone(), &local_env).await {
-                Ok(response) => response,
-                Err(response_error) => {
-                    let mut selected_request = local_selected_request.write();
-                    selected_request.response.status_code = Some(response_error.to_string());
-                    return;
+            let request_start = Instant::now();
+
+            let (response, result_console_output, file_format) = match cached_response {
+                Some(cached_response) => cached_response,
+                None => {
+                    let (prepared_request, cors_simulation) = match &send_mode {
+                        SendMode::SimulateCors(cors_origin) if !offline => match send_cors_preflight(prepared_request, cors_origin).await {
+                            Ok((prepared_request, cors_simulation)) => (prepared_request, Some(cors_simulation)),
+                            Err(error) => {
+                                let mut request = local_request.write();
+                                error!("CORS preflight of \"{}\" failed: {error}", request.name);
+                                request.response.result = Some(RequestResult::local_error(error));
+                                return;
+                            }
+                        },
+                        _ => (prepared_request, None)
+                    };
+
+                    let (mut response, result_console_output, file_format) = match send_request(prepared_request, local_request.clone(), &local_env, offline).await {
+                        Ok(response) => response,
+                        Err(response_error) => {
+                            let mut request = local_request.write();
+                            error!("Could not send \"{}\": {response_error}", request.name);
+                            request.response.result = Some(RequestResult::local_error(response_error));
+                            return;
+                        }
+                    };
+
+                    if let Some(cors_simulation) = &cors_simulation {
+                        let cors_test_results = cors_simulation.get_test_results(&response);
+                        response.tests.extend(cors_test_results);
+                    }
+
+                    if let (Some(response_cache), Some((cache_key, cache_request))) = (&local_response_cache, &cache_key) {
+                        let mut response_cache = response_cache.write();
+                        let cache_storage = response_cache.store(cache_key, cache_request, &response, &file_format);
+
+                        let cache_status = match send_mode {
+                            SendMode::RefreshCache => CacheStatus::Refreshed(cache_storage),
+                            _ => CacheStatus::Miss(cache_storage)
+                        };
+
+                        response_cache.push_log(cache_key, cache_status.clone());
+                        response.cache_status = Some(cache_status);
+                    }
+
+                    (response, result_console_output, file_format)
                 }
             };
 
-            let mut selected_request = local_selected_request.write();
+            if let Some(local_pending_notifications) = local_pending_notifications {
+                if request_start.elapsed() >= notification_minimum_duration {
+                    let status_code = response.get_status_text().unwrap_or_default();
+                    local_pending_notifications.write().push((request_name, status_code));
+                }
+            }
+
+            if let Some((cookie_jar_path, cookie_store)) = local_cookie_jar {
+                save_cookie_jar_to_file(&cookie_store, &cookie_jar_path);
+            }
+
+            // Retried once the login request has been sent
+            if let Some(local_pending_logins) = local_pending_logins {
+                if response.is_unauthorized() {
+                    local_pending_logins.write().push(local_request.clone());
+                }
+            }
+
+            // Parsed before locking the request, the file format detection waits for the thread pool the UI may be blocked in
+            let ndjson_records = get_ndjson_records(&response, should_highlight);
+            let csv_table = get_csv_table(&response);
+
+            {
+                let mut request = local_request.write();
+                request.cache_validators.update(&response);
+                request.response = response;
+            }
+
+            local_pending_received_responses.write().push(ReceivedResponse {
+                request: local_request,
+                file_format,
+                pre_request_console_output,
+                post_request_console_output: result_console_output,
+                ndjson_records,
+                csv_table,
+            });
+        });
+    }
+
+    /// Prepares the views of the received responses, so that selecting their request only swaps them in.
+    /// The response of the selected request is shown and its result tab focused
+    pub fn tui_show_received_responses(&mut self) {
+        let received_responses: Vec<ReceivedResponse> = self.pending_received_responses.write().drain(..).collect();
 
-            let mut console_output = local_console_output.write();
-            let mut local_highlighted_console_output = local_highlighted_console_output.write();
+        for received_response in received_responses {
+            let view = ResponseView::default();
 
+            set_console_output(&view, &received_response.pre_request_console_output, Some(&received_response.post_request_console_output));
 
-            let mut highlighted_console_output = highlight(&result_console_output, "json").unwrap();
+            *view.syntax_highlighting.ndjson_records.write() = received_response.ndjson_records;
+            *view.syntax_highlighting.csv_table.write() = received_response.csv_table;
 
-            highlighted_console_output.insert(0, Line::default());
-            highlighted_console_output.insert(1, Line::raw("----- Post-request script start -----").fg(THEME.read().ui.secondary_foreground_color).centered());
-            highlighted_console_output.push(Line::raw("----- Post-request script end -----").fg(THEME.read().ui.secondary_foreground_color).centered());
+            let request = received_response.request.read();
 
-            *local_highlighted_body.write() = highlighted_body;
-            local_highlighted_console_output.extend(highlighted_console_output);
+            // Highlighted in the background, large bodies would freeze the UI
+            if let (Some(ResponseContent::Body(body)), Some(file_format)) = (&request.response.content, &received_response.file_format) {
+                if !self.config.is_syntax_highlighting_disabled() {
+                    view.syntax_highlighting.body_highlighter.highlight(body.to_string(), file_format);
+                }
+            }
+
+            self.response_views.set(&received_response.request, view.clone());
+
+            if !self.is_selected_request(&received_response.request) {
+                continue;
+            }
+
+            self.syntax_highlighting = view.syntax_highlighting;
+            self.script_console.console_output = view.console_output;
+
+            if let Some(result_tab) = self.get_result_tab_after_response(&request) {
+                self.request_result_tab = result_tab;
+            }
+
+            drop(request);
+
+            self.tui_refresh_result_scrollbars();
+        }
+    }
+
+    /// Sends once more each request which queued sends and is no longer pending
+    pub async fn tui_send_queued_requests(&mut self) {
+        let mut queued_requests: Vec<Arc<RwLock<Request>>> = vec![];
+
+        for collection in &self.collections {
+            for local_request in &collection.requests {
+                // Skipped while a response is being written, retried on the next loop
+                let request = match local_request.try_read() {
+                    Some(request) => request,
+                    None => continue
+                };
+
+                if request.is_pending {
+                    continue;
+                }
 
-            *console_output = match console_output.as_ref() {
-                None => Some(result_console_output),
-                Some(console_output) => Some(format!("{console_output}\n{result_console_output}"))
+                let is_queued = request.queued_sends
+                    .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |queued_sends| queued_sends.checked_sub(1))
+                    .is_ok();
+
+                if is_queued {
+                    queued_requests.push(local_request.clone());
+                }
+            }
+        }
+
+        for local_request in queued_requests {
+            self.tui_send_local_request(local_request, true, SendMode::Normal).await;
+        }
+    }
+
+    /// Sends the login request of the requests which got a 401, then retries them once it has been received
+    pub async fn tui_send_pending_logins(&mut self) {
+        let pending_retries: Vec<Arc<RwLock<Request>>> = self.pending_login_retries.write().drain(..).collect();
+
+        for local_request in pending_retries {
+            self.tui_send_local_request(local_request, false, SendMode::Normal).await;
+        }
+
+        let pending_logins: Vec<Arc<RwLock<Request>>> = self.pending_logins.write().drain(..).collect();
+
+        for local_request in pending_logins {
+            let local_login_request = match self.get_login_request_as_local(&local_request) {
+                Some(local_login_request) => local_login_request,
+                None => continue
             };
 
-            selected_request.response = response;
-        });
+            let login_request = local_login_request.read().clone();
+
+            info!("Sending the login request \"{}\"", login_request.name);
+
+            let prepared_login_request = match self.prepare_request(&login_request).await {
+                Ok((prepared_login_request, _)) => prepared_login_request,
+                Err(prepare_request_error) => {
+                    let message = format!("Could not prepare the login request \"{}\"", login_request.name);
+
+                    match prepare_request_error.get_remediation_hint() {
+                        Some(hint) => report_error(&message, &prepare_request_error, hint),
+                        None => error!("{message}: {prepare_request_error}")
+                    }
+
+                    local_login_request.write().response.result = Some(RequestResult::local_error(prepare_request_error));
+                    continue;
+                }
+            };
+
+            let local_env = self.get_selected_env_as_local();
+            let offline = self.is_offline();
+            let local_pending_login_retries = Arc::clone(&self.pending_login_retries);
+
+            task::spawn(async move {
+                // The post-request script of the login request stores the new token in the environment
+                if send_request(prepared_login_request, local_login_request, &local_env, offline).await.is_ok() {
+                    local_pending_login_retries.write().push(local_request);
+                }
+            });
+        }
     }
-}
\ No newline at end of file
+}
+
+/// Console output of the scripts of the last send, without the post-request part while the response is awaited
+fn set_console_output(view: &ResponseView, pre_request_console_output: &str, post_request_console_output: Option<&str>) {
+    let secondary_foreground_color = THEME.read().ui.secondary_foreground_color;
+
+    let mut highlighted_console_output = highlight(pre_request_console_output, "json").unwrap();
+
+    highlighted_console_output.insert(0, Line::default());
+    highlighted_console_output.insert(1, Line::raw("----- Pre-request script start -----").fg(secondary_foreground_color).centered());
+    highlighted_console_output.push(Line::raw("----- Pre-request script end -----").fg(secondary_foreground_color).centered());
+
+    let console_output = match post_request_console_output {
+        None => pre_request_console_output.to_string(),
+        Some(post_request_console_output) => {
+            let mut highlighted_post_request_console_output = highlight(post_request_console_output, "json").unwrap();
+
+            highlighted_post_request_console_output.insert(0, Line::default());
+            highlighted_post_request_console_output.insert(1, Line::raw("----- Post-request script start -----").fg(secondary_foreground_color).centered());
+            highlighted_post_request_console_output.push(Line::raw("----- Post-request script end -----").fg(secondary_foreground_color).centered());
+
+            highlighted_console_output.extend(highlighted_post_request_console_output);
+
+            format!("{pre_request_console_output}\n{post_request_console_output}")
+        }
+    };
+
+    *view.syntax_highlighting.highlighted_console_output.write() = highlighted_console_output;
+    *view.console_output.write() = Some(console_output);
+}
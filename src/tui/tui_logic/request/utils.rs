@@ -3,61 +3,93 @@ use image::EncodableLayout;
 use rayon::prelude::*;
 
 use crate::app::app::App;
+use crate::app::business_logic::app_error::report_error;
 use crate::models::response::ResponseContent;
 use crate::tui::ui::result_tabs::RequestResultTabs;
 
+const CLIPBOARD_HINT: &str = "No clipboard may be available in this terminal, e.g. over SSH, save the response to a file instead";
+
 impl App<'_> {
 
     /// Copy the response's body content to the clipboard if it's present, otherwise does nothing
     pub fn copy_response_body_content_to_clipboard(&self) {
+        // No clipboard is available in some terminals, e.g. over SSH
+        let mut clipboard = match Clipboard::new() {
+            Ok(clipboard) => clipboard,
+            Err(error) => {
+                report_error("Could not access the clipboard", error, CLIPBOARD_HINT);
+                return;
+            }
+        };
+
+        if let Some(text) = self.get_response_part_text() {
+            if let Err(error) = clipboard.set_text(text) {
+                report_error("Could not copy response part to clipboard", error, CLIPBOARD_HINT);
+            }
+
+            return;
+        }
+
         let local_selected_request = self.get_selected_request_as_local();
         let selected_request = local_selected_request.read();
 
-        let mut clipboard = Clipboard::new().unwrap();
+        if let (RequestResultTabs::Body, Some(ResponseContent::Image(image_response))) = (self.request_result_tab, &selected_request.response.content) {
+            if let Some(image) = &image_response.image {
+                let rgba_image = image.to_rgba8();
 
-        match self.request_result_tab {
-            RequestResultTabs::Body => match &selected_request.response.content {
-                None => {}
-                Some(content) => match content {
-                    ResponseContent::Body(body) => {
-                        clipboard.set_text(body).expect("Could not copy response content to clipboard");
-                    }
-                    ResponseContent::Image(image_response) => match &image_response.image {
-                        None => {}
-                        Some(image) => {
-                            let rgba_image = image.to_rgba8();
-
-                            clipboard
-                                .set_image(ImageData {
-                                    width: rgba_image.width() as usize,
-                                    height: rgba_image.height() as usize,
-                                    bytes: rgba_image.as_bytes().into()
-                                })
-                                .expect("Could not copy response image to clipboard");
-                        }
-                    }
+                let copy_result = clipboard.set_image(ImageData {
+                    width: rgba_image.width() as usize,
+                    height: rgba_image.height() as usize,
+                    bytes: rgba_image.as_bytes().into()
+                });
+
+                if let Err(error) = copy_result {
+                    report_error("Could not copy response image to clipboard", error, CLIPBOARD_HINT);
                 }
             }
-            RequestResultTabs::Cookies => match &selected_request.response.cookies {
-                None => {}
-                Some(cookies) => clipboard.set_text(cookies).expect("Could not copy cookies to clipboard")
+        }
+    }
+
+    /// Text of the current result tab, None when it is empty or when the body is an image
+    pub fn get_response_part_text(&self) -> Option<String> {
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        match self.request_result_tab {
+            RequestResultTabs::Body => match &selected_request.response.content {
+                Some(ResponseContent::Body(body)) => Some(body.to_string()),
+                Some(ResponseContent::Image(_)) | None => None
             }
+            RequestResultTabs::Cookies => selected_request.response.cookies.clone(),
             RequestResultTabs::Headers => {
-                let headers_string: String = selected_request.response.headers
+                let mut headers_string: String = selected_request.response.headers
                     .par_iter()
                     .map(|(header, value)| format!("{}: {}\n", header, value))
                     .collect();
 
-                clipboard.set_text(headers_string).expect("Could not copy headers to clipboard")
-            }
-            RequestResultTabs::Console => {
-                let local_console_output = self.script_console.console_output.read();
+                if !selected_request.response.trailers.is_empty() {
+                    headers_string.push_str("\nTrailers\n");
 
-                match local_console_output.as_ref() {
-                    None => {}
-                    Some(console_output) => clipboard.set_text(console_output).expect("Could not copy console output to clipboard")
+                    for (trailer, value) in &selected_request.response.trailers {
+                        headers_string.push_str(&format!("{}: {}\n", trailer, value));
+                    }
                 }
+
+                Some(headers_string)
+            }
+            RequestResultTabs::Tests => {
+                let tests_string: String = selected_request.response.tests
+                    .iter()
+                    .flat_map(|test| test.to_lines())
+                    .map(|line| format!("{line}\n"))
+                    .collect();
+
+                Some(tests_string)
             }
+            RequestResultTabs::Console => self.script_console.console_output.read().clone(),
+            RequestResultTabs::Wire => selected_request.response.wire
+                .as_ref()
+                .map(|wire| wire.to_lines().join("\n"))
         }
     }
-}
\ No newline at end of file
+}
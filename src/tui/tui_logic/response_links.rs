@@ -0,0 +1,32 @@
+use crate::app::app::App;
+use crate::app::business_logic::app_error::report_error;
+
+impl App<'_> {
+    /// The new request is selected, ready to be sent
+    pub fn tui_follow_response_link(&mut self) {
+        let (collection_index, request_index) = match self.collections_tree.selected {
+            None => return,
+            Some(selection) => selection
+        };
+
+        let link = match self.response_links_popup.links.get(self.response_links_popup.selection) {
+            None => return self.select_request_state(),
+            Some(link) => link.clone()
+        };
+
+        if self.is_collection_read_only(collection_index) {
+            return;
+        }
+
+        match self.follow_response_link(collection_index, request_index, &link) {
+            Ok(new_request_index) => {
+                self.collections_tree.state.select(vec![collection_index, new_request_index]);
+                self.select_request();
+            },
+            Err(error) => {
+                report_error("Could not follow the response link", error, "Check the link and the URL of the request");
+                self.select_request_state();
+            }
+        }
+    }
+}
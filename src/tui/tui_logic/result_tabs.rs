@@ -1,25 +1,190 @@
-use std::str::Lines;
+use ratatui::text::Span;
 
 use crate::app::app::App;
+use crate::models::method::Method;
+use crate::models::request::Request;
+use crate::models::result_tab::ResultTabFocus;
 use crate::tui::ui::result_tabs::RequestResultTabs;
+use crate::models::body::find_response_file_format;
 use crate::models::response::ResponseContent;
+use crate::models::wire::WireExchange;
+use crate::tui::utils::response_views::ResponseView;
+use crate::tui::utils::line_wrapping::get_wrapped_rows_count;
+use crate::tui::utils::syntax_highlighting::{get_csv_table, get_ndjson_records, BodyHighlightingLanguage};
 
 impl App<'_> {
+    /// Result tabs shown by the config, the console one only once a script wrote to it
+    pub fn get_result_tabs(&self) -> Vec<RequestResultTabs> {
+        let has_console_output = self.script_console.console_output.read().is_some();
+
+        let result_tabs: Vec<RequestResultTabs> = self.config.get_shown_result_tabs()
+            .into_iter()
+            .map(RequestResultTabs::from)
+            .filter(|tab| has_console_output || *tab != RequestResultTabs::Console)
+            .collect();
+
+        return match result_tabs.is_empty() {
+            true => vec![RequestResultTabs::Body],
+            false => result_tabs
+        };
+    }
+
     pub fn tui_next_request_result_tab(&mut self) {
-        self.request_result_tab = match self.request_result_tab {
-            RequestResultTabs::Body => RequestResultTabs::Cookies,
-            RequestResultTabs::Cookies => RequestResultTabs::Headers,
-            RequestResultTabs::Headers => {
-                let local_console_output = self.script_console.console_output.read();
+        let result_tabs = self.get_result_tabs();
 
-                match local_console_output.as_ref() {
-                    None => RequestResultTabs::Body,
-                    Some(_) => RequestResultTabs::Console
-                }
+        let next_tab_index = match result_tabs.iter().position(|tab| *tab == self.request_result_tab) {
+            Some(tab_index) => (tab_index + 1) % result_tabs.len(),
+            None => 0
+        };
+
+        self.request_result_tab = result_tabs[next_tab_index];
+
+        self.tui_refresh_result_scrollbars();
+    }
+
+    /// None when the focused tab is kept, or when the tab to focus is hidden
+    pub fn get_result_tab_after_response(&self, request: &Request) -> Option<RequestResultTabs> {
+        let focus = request.settings.result_tab_after_response.unwrap_or(self.config.get_result_tab_focus());
+
+        let result_tab = match focus {
+            ResultTabFocus::Keep => return None,
+            ResultTabFocus::Auto => match (request.method, request.response.tests.is_empty()) {
+                (Method::HEAD, _) => RequestResultTabs::Headers,
+                (_, false) => RequestResultTabs::Tests,
+                (_, true) => RequestResultTabs::Body
+            },
+            _ => RequestResultTabs::from(focus.get_tab()?)
+        };
+
+        return match self.get_result_tabs().contains(&result_tab) {
+            true => Some(result_tab),
+            false => None
+        };
+    }
+
+    /// Response view shown, sharing its state with the stored view of the selected request
+    pub fn get_shown_response_view(&self) -> ResponseView {
+        return ResponseView {
+            syntax_highlighting: self.syntax_highlighting.clone(),
+            console_output: self.script_console.console_output.clone(),
+        };
+    }
+
+    /// Keep the response view of the selected request, e.g. its highlighting language, before the selection changes
+    pub fn save_response_view(&mut self) {
+        let local_selected_request = self.collections_tree.selected
+            .and_then(|(collection_index, request_index)| self.collections.get(collection_index)?.requests.get(request_index).cloned());
+
+        if let Some(local_selected_request) = local_selected_request {
+            let view = self.get_shown_response_view();
+            self.response_views.set(&local_selected_request, view);
+        }
+    }
+
+    /// Show the response view of the selected request, nothing is parsed nor highlighted again
+    pub fn load_response_view(&mut self) {
+        let view = self.response_views.get(&self.get_selected_request_as_local());
+
+        self.syntax_highlighting = view.syntax_highlighting;
+        self.script_console.console_output = view.console_output;
+    }
+
+    pub fn tui_cycle_body_highlighting_language(&mut self) {
+        self.syntax_highlighting.body_language = self.syntax_highlighting.body_language.next();
+        self.tui_refresh_body_highlighting();
+    }
+
+    /// Switch between the body rewritten by the response processor and the body as it was received
+    pub fn tui_toggle_processed_body(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+
+        if !local_selected_request.write().response.toggle_processed_body() {
+            return;
+        }
+
+        self.tui_refresh_body_highlighting();
+        self.tui_refresh_result_scrollbars();
+    }
+
+    pub fn tui_refresh_body_highlighting(&mut self) {
+        let body_language = self.syntax_highlighting.body_language;
+
+        let local_selected_request = self.get_selected_request_as_local();
+        let selected_request = local_selected_request.read();
+
+        let body = match &selected_request.response.content {
+            Some(ResponseContent::Body(body)) => body,
+            _ => {
+                *self.syntax_highlighting.ndjson_records.write() = None;
+                *self.syntax_highlighting.csv_table.write() = None;
+                self.syntax_highlighting.body_highlighter.clear();
+                return;
             }
-            RequestResultTabs::Console => RequestResultTabs::Body
         };
 
+        let file_format = match body_language {
+            BodyHighlightingLanguage::Auto => find_response_file_format(&selected_request.response.headers, body),
+            _ => body_language.get_file_format().map(String::from)
+        };
+
+        *self.syntax_highlighting.ndjson_records.write() = match body_language {
+            BodyHighlightingLanguage::Auto => get_ndjson_records(&selected_request.response, !self.config.is_syntax_highlighting_disabled()),
+            _ => None
+        };
+
+        *self.syntax_highlighting.csv_table.write() = match body_language {
+            BodyHighlightingLanguage::Auto => get_csv_table(&selected_request.response),
+            _ => None
+        };
+
+        match file_format {
+            Some(file_format) if !self.config.is_syntax_highlighting_disabled() => self.syntax_highlighting.body_highlighter.highlight(body.to_string(), &file_format),
+            _ => self.syntax_highlighting.body_highlighter.clear()
+        }
+    }
+
+    /// Fold or unfold the newline-delimited JSON record at the top of the result body
+    pub fn tui_toggle_result_record_fold(&mut self) {
+        let mut ndjson_records = self.syntax_highlighting.ndjson_records.write();
+
+        let ndjson_records = match ndjson_records.as_mut() {
+            Some(ndjson_records) => ndjson_records,
+            None => return
+        };
+
+        let header_line = match ndjson_records.toggle_record_at_line(self.result_vertical_scrollbar.scroll) {
+            Some(header_line) => header_line,
+            None => return
+        };
+
+        // Keeps the toggled record at the top
+        self.result_vertical_scrollbar.set_scroll(ndjson_records.lines_count());
+        self.result_vertical_scrollbar.scroll_to(header_line);
+        self.result_horizontal_scrollbar.set_scroll(ndjson_records.width());
+    }
+
+    /// Wrap the long lines of the result and of the request preview, or scroll them horizontally
+    pub fn tui_toggle_line_wrapping(&mut self) {
+        self.should_wrap_lines = !self.should_wrap_lines;
+
+        // The wrapped lines take more rows
+        self.tui_refresh_result_scrollbars();
+    }
+
+    /// The CSV tables and the NDJSON records are never wrapped
+    pub fn is_result_body_structured(&self) -> bool {
+        return self.syntax_highlighting.ndjson_records.read().is_some()
+            || self.syntax_highlighting.csv_table.read().as_ref().is_some_and(|csv_table| !csv_table.is_raw);
+    }
+
+    /// Switch between the CSV table and the body as it was received
+    pub fn tui_toggle_result_table_view(&mut self) {
+        match self.syntax_highlighting.csv_table.write().as_mut() {
+            Some(csv_table) => csv_table.is_raw = !csv_table.is_raw,
+            None => return
+        }
+
+        // The table and the text do not have the same size
         self.tui_refresh_result_scrollbars();
     }
 
@@ -39,9 +204,16 @@ impl App<'_> {
                         horizontal_max = 0;
                     },
                     Some(content) => match content {
-                        ResponseContent::Body(body) => {
-                            lines_count = body.lines().count();
-                            horizontal_max = App::get_max_str_len(body.lines());
+                        ResponseContent::Body(body) => match (self.syntax_highlighting.ndjson_records.read().as_ref(), self.syntax_highlighting.csv_table.read().as_ref()) {
+                            (Some(ndjson_records), _) => {
+                                lines_count = ndjson_records.lines_count();
+                                horizontal_max = ndjson_records.width();
+                            },
+                            (None, Some(csv_table)) if !csv_table.is_raw => {
+                                lines_count = csv_table.rows_count();
+                                horizontal_max = csv_table.width();
+                            },
+                            _ => (lines_count, horizontal_max) = self.get_text_size(body.lines().map(get_str_width))
                         }
                         ResponseContent::Image(_) => {
                             lines_count = 0;
@@ -56,25 +228,33 @@ impl App<'_> {
                         lines_count = 0;
                         horizontal_max = 0;
                     },
-                    Some(cookies) => {
-                        lines_count = cookies.lines().count();
-                        horizontal_max = App::get_max_str_len(cookies.lines());
-                    }
+                    Some(cookies) => (lines_count, horizontal_max) = self.get_text_size(cookies.lines().map(get_str_width))
                 }
             }
             RequestResultTabs::Headers => {
-                lines_count = selected_request.response.headers.len();
+                let trailers = &selected_request.response.trailers;
 
-                let mut max_tmp = 0;
+                // The trailers follow an empty line and their title
+                let trailers_title_widths = match trailers.is_empty() {
+                    true => vec![],
+                    false => vec![0, get_str_width("Trailers")]
+                };
 
-                for (header, value) in &selected_request.response.headers {
-                    let str_len = header.len() + value.len();
-                    if str_len > max_tmp {
-                        max_tmp = str_len;
-                    }
-                }
-                
-                horizontal_max = max_tmp;
+                let header_widths = selected_request.response.headers
+                    .iter()
+                    .map(|(header, value)| get_str_width(header) + 2 + get_str_width(value))
+                    .chain(trailers_title_widths)
+                    .chain(trailers.iter().map(|(trailer, value)| get_str_width(trailer) + 2 + get_str_width(value)));
+
+                (lines_count, horizontal_max) = self.get_text_size(header_widths);
+            }
+            RequestResultTabs::Tests => {
+                let tests_lines: Vec<String> = selected_request.response.tests
+                    .iter()
+                    .flat_map(|test| test.to_lines())
+                    .collect();
+
+                (lines_count, horizontal_max) = self.get_text_size(tests_lines.iter().map(|line| get_str_width(line)));
             }
             RequestResultTabs::Console => {
                 let local_console_output = self.script_console.console_output.read();
@@ -84,27 +264,32 @@ impl App<'_> {
                         lines_count = 0;
                         horizontal_max = 0;
                     },
-                    Some(console_output) => {
-                        lines_count = console_output.lines().count();
-                        horizontal_max = App::get_max_str_len(console_output.lines());
-                    }
+                    Some(console_output) => (lines_count, horizontal_max) = self.get_text_size(console_output.lines().map(get_str_width))
                 }
             }
+            RequestResultTabs::Wire => {
+                let wire_lines = selected_request.response.wire
+                    .as_ref()
+                    .map(WireExchange::to_lines)
+                    .unwrap_or_default();
+
+                (lines_count, horizontal_max) = self.get_text_size(wire_lines.iter().map(|line| get_str_width(line)));
+            }
         }
 
         self.result_vertical_scrollbar.set_scroll(lines_count);
         self.result_horizontal_scrollbar.set_scroll(horizontal_max);
     }
     
-    pub fn get_max_str_len(lines: Lines) -> usize {
-        let mut max_tmp = 0;
-
-        for line in lines {
-            if line.len() > max_tmp {
-                max_tmp = line.len();
-            }
-        }
-
-        return max_tmp;
+    /// Lines count and max width of a text, the lines count being the rows count when the lines are wrapped
+    fn get_text_size(&self, line_widths: impl Iterator<Item = usize>) -> (usize, usize) {
+        return match self.should_wrap_lines {
+            true => (line_widths.map(|line_width| get_wrapped_rows_count(line_width, self.result_wrap_width)).sum(), 0),
+            false => line_widths.fold((0, 0), |(lines_count, max_width), line_width| (lines_count + 1, max_width.max(line_width)))
+        };
     }
-}
\ No newline at end of file
+}
+
+fn get_str_width(line: &str) -> usize {
+    return Span::raw(line).width();
+}
@@ -0,0 +1,82 @@
+use arboard::Clipboard;
+use chrono::Local;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::models::scratchpad::ScratchpadEntry;
+
+impl App<'_> {
+    /// Newest entries come first, the pinned one gets selected
+    fn pin_to_scratchpad(&mut self, source: String, text: String) {
+        if text.trim().is_empty() {
+            return;
+        }
+
+        info!("Pinned {source} to the scratchpad");
+
+        self.scratchpad_popup.entries.insert(0, ScratchpadEntry {
+            pinned_at: Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+            source,
+            text,
+        });
+        self.scratchpad_popup.selection = 0;
+
+        self.save_scratchpad();
+    }
+
+    pub fn tui_pin_response_part(&mut self) {
+        let text = match self.get_response_part_text() {
+            None => return,
+            Some(text) => text
+        };
+
+        let source = {
+            let local_selected_request = self.get_selected_request_as_local();
+            let selected_request = local_selected_request.read();
+            let collection_index = self.collections_tree.selected.unwrap().0;
+
+            format!("{}/{} {}", self.collections[collection_index].name, selected_request.name, self.request_result_tab.to_string().to_lowercase())
+        };
+
+        self.pin_to_scratchpad(source, text);
+    }
+
+    pub fn tui_pin_clipboard_to_scratchpad(&mut self) {
+        // No clipboard is available in some terminals, e.g. over SSH
+        let text = match Clipboard::new().and_then(|mut clipboard| clipboard.get_text()) {
+            Ok(text) => text,
+            Err(_) => return
+        };
+
+        self.pin_to_scratchpad(String::from("clipboard"), text);
+    }
+
+    pub fn tui_pin_scratchpad_note(&mut self) {
+        let note = self.scratchpad_popup.note_input.text.clone();
+
+        self.pin_to_scratchpad(String::from("note"), note);
+        self.display_scratchpad_state();
+    }
+
+    pub fn tui_copy_scratchpad_entry(&mut self) {
+        let text = match self.scratchpad_popup.get_selected_entry() {
+            None => return,
+            Some(entry) => entry.text.clone()
+        };
+
+        if let Ok(mut clipboard) = Clipboard::new() {
+            clipboard.set_text(text).ok();
+        }
+    }
+
+    pub fn tui_delete_scratchpad_entry(&mut self) {
+        if self.scratchpad_popup.selection >= self.scratchpad_popup.entries.len() {
+            return;
+        }
+
+        self.scratchpad_popup.entries.remove(self.scratchpad_popup.selection);
+        self.scratchpad_popup.selection = self.scratchpad_popup.selection.min(self.scratchpad_popup.entries.len().saturating_sub(1));
+
+        self.save_scratchpad();
+    }
+}
@@ -0,0 +1,131 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use tokio::task;
+
+use crate::app::app::App;
+use crate::app::business_logic::request::send::send_request;
+use crate::app::business_logic::scripting_console::evaluate_console_script;
+use crate::models::response::{RequestResult, ResponseContent};
+use crate::tui::utils::stateful::scripting_console_popup::{ConsoleLineKind, ScriptingConsolePopup};
+
+impl App<'_> {
+    pub async fn tui_run_console_script(&mut self) {
+        let code = self.scripting_console_popup.input.text.trim().to_string();
+
+        if code.is_empty() {
+            return;
+        }
+
+        self.scripting_console_popup.input.reset_input();
+        self.scripting_console_popup.history_index = None;
+
+        if self.scripting_console_popup.history.last() != Some(&code) {
+            self.scripting_console_popup.history.push(code.clone());
+        }
+
+        let lines = Arc::clone(&self.scripting_console_popup.lines);
+        ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Input, &format!("> {code}"));
+
+        let env = self.get_selected_env_as_local().map(|local_env| local_env.read().values.clone());
+
+        let evaluation = {
+            let responses = self.scripting_console_popup.responses.read();
+            evaluate_console_script(&code, &self.collections, env, &self.scripting_console_popup.vars, &responses)
+        };
+
+        let evaluation = match evaluation {
+            Ok(evaluation) => evaluation,
+            Err(error) => {
+                ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Error, &error);
+                return;
+            }
+        };
+
+        ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Output, &evaluation.console_output);
+
+        if let Some(value) = &evaluation.value {
+            ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Output, value);
+        }
+
+        self.scripting_console_popup.vars = evaluation.vars;
+
+        if let Some(env_values) = evaluation.env {
+            self.tui_apply_console_env(env_values);
+        }
+
+        for path in evaluation.sends {
+            self.tui_send_console_request(path).await;
+        }
+    }
+
+    /// Only saved when the script changed a value
+    fn tui_apply_console_env(&mut self, env_values: IndexMap<String, String>) {
+        let local_env = match self.get_selected_env_as_local() {
+            None => return,
+            Some(local_env) => local_env
+        };
+
+        if local_env.read().values == env_values {
+            return;
+        }
+
+        local_env.write().values = env_values;
+        self.save_environment_to_file(self.selected_environment);
+    }
+
+    /// Sent in the background, the response is stored for the next scripts and its status printed in the console
+    async fn tui_send_console_request(&mut self, path: String) {
+        let lines = Arc::clone(&self.scripting_console_popup.lines);
+
+        let local_request = path
+            .split_once('/')
+            .and_then(|(collection_name, request_name)| {
+                self.collections
+                    .iter()
+                    .find(|collection| collection.name == collection_name)
+                    .and_then(|collection| collection.requests.iter().find(|request| request.read().name == request_name))
+                    .cloned()
+            });
+
+        let local_request = match local_request {
+            Some(local_request) => local_request,
+            None => {
+                ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Error, &format!("Request \"{path}\" not found"));
+                return;
+            }
+        };
+
+        let request = local_request.read().clone();
+
+        let prepared_request = match self.prepare_request(&request).await {
+            Ok((prepared_request, _)) => prepared_request,
+            Err(prepare_request_error) => {
+                ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Error, &format!("{path}: {prepare_request_error}"));
+                return;
+            }
+        };
+
+        let local_env = self.get_selected_env_as_local();
+        let offline = self.is_offline();
+        let local_responses = Arc::clone(&self.scripting_console_popup.responses);
+
+        task::spawn(async move {
+            match send_request(prepared_request, local_request, &local_env, offline).await {
+                Ok((response, _, _)) => {
+                    // Without response, the response content holds the error
+                    let status = match (&response.result, &response.content) {
+                        (Some(result @ RequestResult::Success { .. }), _) => format!("{path} answered {result} {}", response.duration.as_deref().unwrap_or_default()),
+                        (Some(RequestResult::TransportError(_)), Some(ResponseContent::Body(error))) => format!("{path} failed: {error}"),
+                        (Some(result), _) => format!("{path} failed: {result}"),
+                        (None, _) => format!("{path} failed")
+                    };
+
+                    ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Output, status.trim_end());
+                    local_responses.write().insert(path, response);
+                },
+                Err(error) => ScriptingConsolePopup::push_lines(&lines, ConsoleLineKind::Error, &format!("{path}: {error}"))
+            }
+        });
+    }
+}
\ No newline at end of file
@@ -0,0 +1,42 @@
+use crate::app::app::App;
+use crate::models::tag::parse_tags;
+
+impl App<'_> {
+    pub fn tui_modify_element_tags(&mut self) {
+        let selected_element = self.collections_tree.state.selected().to_vec();
+        let tags = parse_tags(&self.edit_tags_input.text);
+
+        let result = match selected_element.len() {
+            // Selection on a collection
+            1 => self.modify_collection_tags(selected_element[0], tags),
+            // Selection on a request
+            2 => self.modify_request_tags(selected_element[0], selected_element[1], tags),
+            _ => Ok(())
+        };
+
+        if result.is_err() {
+            return;
+        }
+
+        self.normal_state();
+    }
+
+    /// No tags displays every request again
+    pub fn tui_apply_tag_filter(&mut self) {
+        self.tag_filter = parse_tags(&self.tag_filter_input.text);
+
+        // The highlighted element may be hidden, the matching collections are unfolded instead
+        self.collections_tree.state.select(Vec::new());
+        self.collections_tree.state.close_all();
+
+        if !self.tag_filter.is_empty() {
+            for (collection_index, collection) in self.collections.iter().enumerate() {
+                if collection.has_tagged_requests(&self.tag_filter) {
+                    self.collections_tree.state.open(vec![collection_index]);
+                }
+            }
+        }
+
+        self.normal_state();
+    }
+}
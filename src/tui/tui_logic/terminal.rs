@@ -0,0 +1,23 @@
+use std::io::stdout;
+
+use ratatui::crossterm::ExecutableCommand;
+use ratatui::crossterm::terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen};
+
+use crate::app::app::App;
+
+impl App<'_> {
+    /// Hands the terminal over to an external program (e.g. $EDITOR), then takes it back
+    pub(super) fn run_with_suspended_terminal<T>(&mut self, external_program: impl FnOnce() -> T) -> T {
+        disable_raw_mode().unwrap();
+        stdout().execute(LeaveAlternateScreen).unwrap();
+
+        let result = external_program();
+
+        stdout().execute(EnterAlternateScreen).unwrap();
+        enable_raw_mode().unwrap();
+
+        self.should_clear_terminal = true;
+
+        return result;
+    }
+}
@@ -0,0 +1,21 @@
+use crate::app::app::App;
+use crate::tui::app_states::AppState;
+use crate::tui::tui_logic::request::send::SendMode;
+
+impl App<'_> {
+    pub fn tui_toggle_request_watch(&mut self) {
+        let local_selected_request = self.get_selected_request_as_local();
+        self.toggle_request_watch(&local_selected_request);
+    }
+
+    /// Re-sends the watched request while it is displayed, the edits in progress and the popups pause it
+    pub async fn tui_run_due_watch(&mut self) {
+        if self.state != AppState::SelectedRequest {
+            return;
+        }
+
+        if let Some(local_request) = self.take_due_watched_request() {
+            self.tui_send_local_request(local_request, true, SendMode::Normal).await;
+        }
+    }
+}
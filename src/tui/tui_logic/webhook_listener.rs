@@ -0,0 +1,42 @@
+use std::sync::Arc;
+
+use tokio::task;
+use tokio_util::sync::CancellationToken;
+use tracing::info;
+
+use crate::app::app::App;
+use crate::app::business_logic::webhook_listener::run_webhook_listener;
+
+impl App<'_> {
+    pub fn tui_toggle_webhook_listener(&mut self) {
+        if let Some(cancellation_token) = self.webhook_listener_popup.cancellation_token.take() {
+            cancellation_token.cancel();
+            info!("Webhook listener stop requested");
+            return;
+        }
+
+        let port = self.config.get_webhook_listener_port();
+        let canned_response = self.config.get_webhook_listener_response();
+        let cancellation_token = CancellationToken::new();
+
+        let local_received_requests = Arc::clone(&self.webhook_listener_popup.received_requests);
+        let local_listener_error = Arc::clone(&self.webhook_listener_popup.listener_error);
+        let local_cancellation_token = cancellation_token.clone();
+
+        *local_listener_error.write() = None;
+
+        self.webhook_listener_popup.port = port;
+        self.webhook_listener_popup.cancellation_token = Some(cancellation_token);
+
+        task::spawn(async move {
+            if let Err(error) = run_webhook_listener(port, canned_response, local_received_requests, local_cancellation_token, None).await {
+                *local_listener_error.write() = Some(error.to_string());
+            }
+        });
+    }
+
+    pub fn tui_clear_webhook_requests(&mut self) {
+        self.webhook_listener_popup.received_requests.write().clear();
+        self.webhook_listener_popup.list_state.select(None);
+    }
+}
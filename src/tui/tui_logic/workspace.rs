@@ -0,0 +1,31 @@
+use tracing::warn;
+
+use crate::app::app::App;
+
+impl App<'_> {
+    /// Cycle from the main directory to each config workspace, then back to the main directory.
+    /// Workspaces whose directory cannot be found are skipped
+    pub fn tui_next_workspace(&mut self) {
+        let mut workspaces: Vec<Option<String>> = vec![None];
+        workspaces.extend(self.workspaces.keys().cloned().map(Some));
+
+        let current_index = workspaces
+            .iter()
+            .position(|workspace| *workspace == self.selected_workspace)
+            .unwrap_or(0);
+
+        for offset in 1..workspaces.len() {
+            let next_workspace = workspaces[(current_index + offset) % workspaces.len()].clone();
+
+            match self.switch_workspace(next_workspace) {
+                Ok(_) => break,
+                Err(error) => warn!("{error}")
+            }
+        }
+
+        // The passphrase of the encrypted files may have been prompted over the TUI
+        self.should_clear_terminal = true;
+
+        self.normal_state();
+    }
+}
@@ -0,0 +1,43 @@
+use ratatui::buffer::Buffer;
+use ratatui::style::Modifier;
+
+use crate::app::files::theme::THEME;
+
+/// Text marker in front of a disabled param, header, cookie or form element
+pub const DISABLED_MARKER: &str = "[off] ";
+
+/// Applies the accessibility options of the theme to the whole rendered frame
+pub fn apply_accessibility(buffer: &mut Buffer) {
+    let theme = THEME.read();
+    let high_contrast = theme.accessibility.high_contrast;
+    let reduced_borders = theme.accessibility.reduced_borders;
+
+    if !high_contrast && !reduced_borders {
+        return;
+    }
+
+    for cell in buffer.content.iter_mut() {
+        if high_contrast {
+            cell.modifier.remove(Modifier::DIM);
+            cell.modifier.insert(Modifier::BOLD);
+
+            if cell.fg == theme.ui.secondary_foreground_color {
+                cell.fg = theme.ui.font_color;
+            }
+        }
+
+        if reduced_borders && is_box_drawing(cell.symbol()) {
+            cell.set_symbol(" ");
+        }
+    }
+}
+
+/// Lines and corners of the borders and separators, e.g. "─", "│" or "┌"
+fn is_box_drawing(symbol: &str) -> bool {
+    let mut chars = symbol.chars();
+
+    return match (chars.next(), chars.next()) {
+        (Some(char), None) => ('\u{2500}'..='\u{257F}').contains(&char),
+        _ => false
+    };
+}
@@ -7,6 +7,7 @@ use tui_tree_widget::{Tree, TreeItem};
 use rayon::prelude::*;
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 
 impl<'a> App<'a> {
@@ -14,11 +15,16 @@ impl<'a> App<'a> {
         let items: Vec<TreeItem<'a, usize>> = self.collections
             .par_iter()
             .enumerate()
-            .map(|(collection_index, request)| {
-                request.to_tree_item(collection_index)
+            .filter_map(|(collection_index, collection)| {
+                collection.to_tree_item(collection_index, &self.tag_filter)
             })
             .collect();
 
+        let title = match self.tag_filter.is_empty() {
+            true => tr("Collections"),
+            false => format!("{} #{}", tr("Collections"), self.tag_filter.join(" #"))
+        };
+
         
         let tree_items = self.collections_tree.items.clone();
         
@@ -30,7 +36,7 @@ impl<'a> App<'a> {
             .node_no_children_symbol("")
             .block(
                 Block::default()
-                    .title("Collections")
+                    .title(title)
                     .borders(Borders::ALL)
                     .fg(THEME.read().ui.main_foreground_color)
             );
@@ -0,0 +1,68 @@
+use ratatui::Frame;
+use ratatui::layout::{Position, Rect};
+use ratatui::style::Stylize;
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::files::theme::THEME;
+use crate::tui::utils::stateful::choice_popup::ChoicePopup;
+
+const MAX_COMPLETIONS_DISPLAYED: usize = 8;
+
+/// Completions of a text input displayed from the given position, cut at the bottom of the area
+pub(super) fn render_completions(frame: &mut Frame, completion_popup: &ChoicePopup, x: u16, y: u16, width: u16, area: Rect) {
+    if completion_popup.choices.is_empty() {
+        return;
+    }
+
+    let completions = &completion_popup.choices;
+    let selection = completion_popup.selection;
+
+    // Scrolls so the highlighted completion stays visible
+    let first_displayed = selection.saturating_sub(MAX_COMPLETIONS_DISPLAYED - 1);
+
+    let completion_lines: Vec<Line> = completions
+        .iter()
+        .enumerate()
+        .skip(first_displayed)
+        .take(MAX_COMPLETIONS_DISPLAYED)
+        .map(|(index, completion)| match index == selection {
+            true => Line::from(completion.clone()).fg(THEME.read().others.selection_highlight_color).bold(),
+            false => Line::from(completion.clone()).fg(THEME.read().ui.font_color)
+        })
+        .collect();
+
+    let completions_area = Rect {
+        x,
+        y,
+        width,
+        height: (completion_lines.len() as u16 + 2).min(area.bottom().saturating_sub(y)),
+    };
+
+    let completions_block = Block::new()
+        .title(format!("Completions ({})", completions.len()))
+        .borders(Borders::ALL)
+        .fg(THEME.read().ui.main_foreground_color)
+        .bg(THEME.read().ui.main_background_color);
+
+    let completions_paragraph = Paragraph::new(completion_lines).block(completions_block);
+
+    frame.render_widget(Clear, completions_area);
+    frame.render_widget(completions_paragraph, completions_area);
+}
+
+/// Environment key completions displayed under the cursor, as wide as the longest key
+pub(super) fn render_env_key_completions(frame: &mut Frame, completion_popup: &ChoicePopup, cursor: Position, area: Rect) {
+    let longest_key_width = completion_popup.choices
+        .iter()
+        .map(|key| key.len() as u16)
+        .max()
+        .unwrap_or(0);
+
+    let width = (longest_key_width + 2).max(20).min(area.width);
+
+    // Moved to the left when the cursor is close to the right of the area
+    let x = cursor.x.min(area.right().saturating_sub(width));
+
+    render_completions(frame, completion_popup, x, cursor.y + 1, width, area);
+}
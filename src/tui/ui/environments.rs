@@ -1,11 +1,15 @@
 use ratatui::Frame;
 use ratatui::layout::Rect;
-use ratatui::style::Style;
+use ratatui::style::{Color, Modifier, Style, Stylize};
+use ratatui::text::Span;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
 
+/// Environments flagged as production in the config stand out wherever they are displayed
+pub(super) const PRODUCTION_ENVIRONMENT_COLOR: Color = Color::Red;
+
 impl<'a> App<'a> {
     pub(super) fn render_environments(&mut self, frame: &mut Frame, rect: Rect) {
         let local_env = self.get_selected_env_as_local().unwrap();
@@ -15,14 +19,32 @@ impl<'a> App<'a> {
 
         drop(env);
 
+        let (title, style) = match self.config.is_production_environment(&current_environment) {
+            true => ("Environment (production)", Style::new().fg(PRODUCTION_ENVIRONMENT_COLOR).add_modifier(Modifier::BOLD)),
+            false => ("Environment", Style::new().fg(THEME.read().ui.secondary_foreground_color))
+        };
+
         let current_environment_paragraph = Paragraph::new(current_environment)
             .block(
                 Block::default()
-                    .title("Environment")
+                    .title(title)
                     .borders(Borders::ALL)
-                    .style(Style::new().fg(THEME.read().ui.secondary_foreground_color))
+                    .style(style)
             );
 
         frame.render_widget(current_environment_paragraph, rect)
     }
-}
\ No newline at end of file
+
+    /// Selected environment, displayed in the footer whatever the state
+    pub(super) fn get_environment_indicator(&self) -> Option<Span<'static>> {
+        let local_env = self.get_selected_env_as_local()?;
+        let environment_name = local_env.read().name.clone();
+
+        let indicator = match self.config.is_production_environment(&environment_name) {
+            true => Span::raw(format!(" {environment_name} (production) ")).fg(Color::White).bg(PRODUCTION_ENVIRONMENT_COLOR).bold(),
+            false => Span::raw(format!(" {environment_name} ")).fg(THEME.read().ui.secondary_foreground_color).bg(THEME.read().ui.secondary_background_color)
+        };
+
+        return Some(indicator);
+    }
+}
@@ -3,13 +3,35 @@ use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::layout::Direction::{Horizontal, Vertical};
 use ratatui::prelude::Stylize;
 use ratatui::style::Style;
-use ratatui::widgets::{Block, Paragraph};
+use ratatui::widgets::{Block, Borders, Paragraph, Wrap};
 use tui_big_text::{BigTextBuilder, PixelSize};
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
+use crate::tui::utils::markdown::markdown_to_lines;
 
 impl App<'_> {
     pub(super) fn render_homepage(&mut self, frame: &mut Frame, rect: Rect) {
+        let highlighted_element = self.collections_tree.state.selected();
+
+        // A highlighted collection with a description displays it instead of the title
+        if highlighted_element.len() == 1 {
+            if let Some(collection) = self.collections.get(highlighted_element[0]) {
+                if let Some(description) = &collection.description {
+                    let description_paragraph = Paragraph::new(markdown_to_lines(description))
+                        .wrap(Wrap { trim: false })
+                        .block(
+                            Block::new()
+                                .title(collection.name.clone())
+                                .borders(Borders::TOP)
+                                .fg(THEME.read().ui.main_foreground_color)
+                        );
+
+                    frame.render_widget(description_paragraph, rect);
+                    return;
+                }
+            }
+        }
+
         let block = Block::new();
 
         let inner_block_area = block.inner(rect);
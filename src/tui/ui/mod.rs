@@ -6,4 +6,6 @@ pub(super) mod collection;
 pub(super) mod environments;
 pub(super) mod request;
 pub(super) mod homepage;
-pub(super) mod popups;
\ No newline at end of file
+pub(super) mod popups;
+pub(super) mod completions;
+pub(super) mod accessibility;
\ No newline at end of file
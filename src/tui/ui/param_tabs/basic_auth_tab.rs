@@ -5,6 +5,7 @@ use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::app_states::AppState::{SelectedRequest, EditingRequestAuthUsername, EditingRequestAuthPassword};
 
@@ -22,13 +23,13 @@ impl App<'_> {
             .split(area);
 
         let mut username_block = Block::new()
-            .title("Username")
+            .title(tr("Username"))
             .borders(Borders::ALL)
             .fg(THEME.read().ui.main_foreground_color);
 
 
         let mut password_block = Block::new()
-            .title("Password")
+            .title(tr("Password"))
             .borders(Borders::ALL)
             .fg(THEME.read().ui.main_foreground_color);
 
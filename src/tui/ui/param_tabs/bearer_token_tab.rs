@@ -5,6 +5,7 @@ use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::app_states::AppState::{EditingRequestAuthBearerToken, SelectedRequest};
 
@@ -21,7 +22,7 @@ impl App<'_> {
             .split(area);
 
         let mut bearer_token_block = Block::new()
-            .title("Bearer token")
+            .title(tr("Bearer token"))
             .borders(Borders::ALL)
             .fg(THEME.read().ui.main_foreground_color);
 
@@ -1,12 +1,20 @@
+use std::path::PathBuf;
+
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::layout::Direction::Vertical;
 use ratatui::style::Stylize;
+use ratatui::text::{Line, Text};
 use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
-use crate::tui::app_states::AppState;
+use crate::app::files::utils::{format_size, read_file_start};
+use crate::tui::app_states::AppState::{EditingRequestBodyFile, SelectedRequest};
+use crate::tui::ui::completions::render_completions;
+
+/// Bytes of the file displayed in the preview
+const PREVIEW_LENGTH: usize = 512;
 
 impl App<'_> {
     pub(super) fn render_file_body_tab(&mut self, frame: &mut Frame, area: Rect) {
@@ -14,39 +22,155 @@ impl App<'_> {
             Vertical,
             [
                 Constraint::Length(3),
+                Constraint::Length(3),
+                Constraint::Fill(1),
             ]
         )
             .vertical_margin(1)
             .horizontal_margin(4)
             .split(area);
 
-        let file_body_block = Block::new()
-            .title("File path")
-            .borders(Borders::ALL)
-            .fg(THEME.read().others.selection_highlight_color);
-
+        let mut should_color_blocks = false;
         let mut should_display_cursor = false;
 
-        if self.state == AppState::EditingRequestBodyFile {
-            should_display_cursor = true;
-        }
+        // Prevent from rendering the cursor while no input text has been selected
+        match self.state {
+            SelectedRequest => {
+                should_color_blocks = true;
+            },
+            EditingRequestBodyFile => {
+                should_color_blocks = true;
+                should_display_cursor = true;
+            },
+            _ => {}
+        };
+
+        let input_selected = self.body_file_text_input_selection.selected;
+
+        let text_inputs = [
+            ("File path", &self.body_file_text_input),
+            ("Content-Type", &self.body_file_content_type_text_input),
+        ];
+
+        let mut input_cursor_position = 0;
+        let mut paragraphs = vec![];
 
-        let adjusted_input_length = file_body_layout[0].width as usize - 2;
-        let (padded_text, input_cursor_position) = self.body_file_text_input.get_padded_text_and_cursor(adjusted_input_length);
+        for (index, (title, text_input)) in text_inputs.into_iter().enumerate() {
+            let mut block = Block::new()
+                .title(title)
+                .borders(Borders::ALL)
+                .fg(THEME.read().ui.main_foreground_color);
+
+            let adjusted_input_length = file_body_layout[index].width as usize - 2;
+            let (padded_text, cursor_position) = text_input.get_padded_text_and_cursor(adjusted_input_length);
+
+            let mut paragraph = match index == 1 && text_input.text.is_empty() && !(should_display_cursor && input_selected == 1) {
+                true => Paragraph::new("application/octet-stream").fg(THEME.read().ui.secondary_foreground_color),
+                false => Paragraph::new(self.tui_add_color_to_env_keys(&padded_text)).fg(THEME.read().ui.font_color)
+            };
+
+            if should_color_blocks && input_selected == index {
+                block = block.fg(THEME.read().others.selection_highlight_color);
+                paragraph = paragraph.fg(THEME.read().others.selection_highlight_color);
+
+                input_cursor_position = cursor_position as u16;
+            }
+
+            paragraphs.push(paragraph.block(block));
+        }
 
         if should_display_cursor {
             frame.set_cursor_position(Position::new(
-                file_body_layout[0].x + input_cursor_position as u16 + 1,
-                file_body_layout[0].y + 1
+                file_body_layout[input_selected].x + input_cursor_position + 1,
+                file_body_layout[input_selected].y + 1
             ));
         }
 
-        let file_body_line = self.tui_add_color_to_env_keys(&padded_text);
+        for (index, paragraph) in paragraphs.into_iter().enumerate() {
+            frame.render_widget(paragraph, file_body_layout[index]);
+        }
+
+        /* PREVIEW */
+
+        let file_path = self.replace_env_keys_by_value(&self.body_file_text_input.text);
+
+        // Only read the file again when its path changes
+        let is_preview_outdated = match &self.body_file_preview {
+            Some((preview_path, _)) => *preview_path != file_path,
+            None => true
+        };
+
+        if is_preview_outdated {
+            let file_start = read_file_start(&PathBuf::from(&file_path), PREVIEW_LENGTH).map_err(|error| error.to_string());
+            self.body_file_preview = Some((file_path.clone(), file_start));
+        }
+
+        let (preview_title, preview_text) = match &self.body_file_preview {
+            Some((_, Ok((file_length, file_start)))) => (
+                format!("Preview ({})", format_size(*file_length)),
+                get_preview_text(file_start)
+            ),
+            Some((_, Err(error))) => (
+                String::from("Preview"),
+                Text::from(format!("COULD NOT OPEN FILE \"{file_path}\" {error}")).fg(THEME.read().ui.secondary_foreground_color)
+            ),
+            None => (String::from("Preview"), Text::default())
+        };
+
+        let preview_block = Block::new()
+            .title(preview_title)
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let preview_paragraph = Paragraph::new(preview_text)
+            .block(preview_block)
+            .fg(THEME.read().ui.font_color);
+
+        frame.render_widget(preview_paragraph, file_body_layout[2]);
+
+        /* PATH COMPLETIONS */
+
+        if self.state != EditingRequestBodyFile || input_selected != 0 {
+            return;
+        }
+
+        render_completions(frame, &self.body_file_completion_popup, file_body_layout[0].x, file_body_layout[0].y + 3, file_body_layout[0].width, area);
+    }
+}
+
+/// Text files are displayed as is, other files as a hex dump
+fn get_preview_text(bytes: &[u8]) -> Text<'static> {
+    // The preview may cut a multibyte character at its end
+    let text = match std::str::from_utf8(bytes) {
+        Ok(text) => Some(text),
+        Err(error) if error.error_len().is_none() => std::str::from_utf8(&bytes[..error.valid_up_to()]).ok(),
+        Err(_) => None
+    };
 
-        let file_body_paragraph = Paragraph::new(file_body_line)
-            .block(file_body_block)
-            .fg(THEME.read().others.selection_highlight_color);
+    if let Some(text) = text {
+        let is_printable = text.chars().all(|char| !char.is_control() || matches!(char, '\n' | '\r' | '\t'));
 
-        frame.render_widget(file_body_paragraph, file_body_layout[0]);
+        if is_printable {
+            return Text::from(text.replace('\t', "    ").replace('\r', ""));
+        }
     }
-}
\ No newline at end of file
+
+    let lines: Vec<Line> = bytes
+        .chunks(16)
+        .enumerate()
+        .map(|(index, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|byte| format!("{byte:02x}")).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|byte| match byte.is_ascii_graphic() || *byte == b' ' {
+                    true => *byte as char,
+                    false => '.'
+                })
+                .collect();
+
+            Line::from(format!("{:08x}  {:<47}  |{ascii}|", index * 16, hex.join(" ")))
+        })
+        .collect();
+
+    return Text::from(lines);
+}
@@ -1,138 +1,197 @@
+use std::path::Path;
+
 use ratatui::Frame;
 use ratatui::layout::Direction::{Horizontal, Vertical};
 use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::prelude::{Modifier, Style};
 use ratatui::style::Stylize;
-use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph, Wrap};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
+use crate::models::body::{MultipartPart, PartSource};
 use crate::tui::app_states::AppState::{EditingRequestBodyTable};
+use crate::tui::ui::accessibility::DISABLED_MARKER;
 use crate::models::request::{KeyValue};
 
 impl App<'_> {
     pub(super) fn render_form_body_tab(&mut self, frame: &mut Frame, area: Rect, form: &Vec<KeyValue>, form_selection: (usize, usize)) {
-        let form_layout = Layout::new(
+        let form_body_layout = Layout::new(
             Vertical,
             [
-                Constraint::Length(2),
-                Constraint::Fill(1)
+                Constraint::Fill(1),
+                Constraint::Length(3)
             ]
         )
             .split(area);
 
-        let inner_form_layout = Layout::new(
-            Horizontal,
-            [
-                Constraint::Percentage(50),
-                Constraint::Percentage(50)
-            ]
-        )
-            .split(form_layout[0]);
-
-        let form_title = Paragraph::new("Key")
-            .centered()
-            .block(
-                Block::new()
-                    .borders(Borders::BOTTOM | Borders::RIGHT)
-                    .fg(THEME.read().ui.secondary_foreground_color)
-            )
+        // Body as it will be sent, only the enabled fields are kept
+        let encoded_body_block = Block::new()
+            .title(tr("URL-encoded"))
+            .borders(Borders::TOP)
             .fg(THEME.read().ui.secondary_foreground_color);
 
-        let form_value = Paragraph::new("Value")
-            .centered()
-            .block(
-                Block::new()
-                    .borders(Borders::BOTTOM)
-                    .fg(THEME.read().ui.secondary_foreground_color)
-            )
-            .fg(THEME.read().ui.secondary_foreground_color);
+        let encoded_body_paragraph = Paragraph::new(self.get_masked_form_body(form))
+            .block(encoded_body_block)
+            .wrap(Wrap { trim: false })
+            .fg(THEME.read().ui.font_color);
 
-        frame.render_widget(form_title, inner_form_layout[0]);
-        frame.render_widget(form_value, inner_form_layout[1]);
+        frame.render_widget(encoded_body_paragraph, form_body_layout[1]);
 
-        let horizontal_margin = 2;
+        let rows = form
+            .iter()
+            .map(|form_data| (
+                form_data.enabled,
+                vec![
+                    self.tui_add_color_to_env_keys(&form_data.data.0),
+                    self.tui_add_color_to_env_keys(&form_data.data.1),
+                ]
+            ))
+            .collect();
 
-        let table_layout = Layout::new(
-            Horizontal,
+        self.render_body_table(frame, form_body_layout[0], &[("Key", 50), ("Value", 50)], rows, form_selection);
+    }
+
+    pub(super) fn render_multipart_body_tab(&mut self, frame: &mut Frame, area: Rect, parts: &[MultipartPart], part_selection: (usize, usize)) {
+        let rows = parts
+            .iter()
+            .map(|part| {
+                let content_type = match &part.content_type {
+                    None => Line::from("-").fg(THEME.read().ui.secondary_foreground_color),
+                    Some(content_type) => self.tui_add_color_to_env_keys(content_type)
+                };
+
+                // The name of the file is sent when none is given
+                let file_name = match (&part.file_name, part.source) {
+                    (Some(file_name), _) => self.tui_add_color_to_env_keys(file_name),
+                    (None, PartSource::File) => {
+                        let file_name = Path::new(&part.value)
+                            .file_name()
+                            .map(|file_name| file_name.to_string_lossy().to_string())
+                            .unwrap_or_default();
+
+                        Line::from(file_name).fg(THEME.read().ui.secondary_foreground_color)
+                    },
+                    (None, PartSource::Text) => Line::from("-").fg(THEME.read().ui.secondary_foreground_color)
+                };
+
+                (
+                    part.enabled,
+                    vec![
+                        self.tui_add_color_to_env_keys(&part.name),
+                        self.tui_add_color_to_env_keys(&part.value),
+                        Line::from(part.source.to_string()),
+                        content_type,
+                        file_name,
+                    ]
+                )
+            })
+            .collect();
+
+        let columns = [
+            ("Name", 20),
+            ("Value", 30),
+            ("Source", 10),
+            ("Content-Type", 20),
+            ("File name", 20),
+        ];
+
+        self.render_body_table(frame, area, &columns, rows, part_selection);
+    }
+
+    /// Columns are given with their title and their width percentage
+    fn render_body_table(&self, frame: &mut Frame, area: Rect, columns: &[(&str, u16)], rows: Vec<(bool, Vec<Line>)>, selection: (usize, usize)) {
+        let form_layout = Layout::new(
+            Vertical,
             [
-                Constraint::Percentage(50),
-                Constraint::Percentage(50)
+                Constraint::Length(2),
+                Constraint::Fill(1)
             ]
         )
+            .split(area);
+
+        let constraints: Vec<Constraint> = columns
+            .iter()
+            .map(|(_, percentage)| Constraint::Percentage(*percentage))
+            .collect();
+
+        let inner_form_layout = Layout::new(Horizontal, constraints.clone()).split(form_layout[0]);
+
+        for (index, (title, _)) in columns.iter().enumerate() {
+            // The last column has no right border
+            let borders = match index == columns.len() - 1 {
+                true => Borders::BOTTOM,
+                false => Borders::BOTTOM | Borders::RIGHT
+            };
+
+            let column_title = Paragraph::new(*title)
+                .centered()
+                .block(
+                    Block::new()
+                        .borders(borders)
+                        .fg(THEME.read().ui.secondary_foreground_color)
+                )
+                .fg(THEME.read().ui.secondary_foreground_color);
+
+            frame.render_widget(column_title, inner_form_layout[index]);
+        }
+
+        let horizontal_margin = 2;
+
+        let table_layout = Layout::new(Horizontal, constraints)
             .horizontal_margin(horizontal_margin)
             .split(form_layout[1]);
 
-        let mut keys: Vec<ListItem> = vec![];
-        let mut values: Vec<ListItem> = vec![];
+        let mut cells: Vec<Vec<ListItem>> = vec![vec![]; columns.len()];
 
-        for form_data in form.iter() {
-            let key = self.tui_add_color_to_env_keys(&form_data.data.0);
-            let value = self.tui_add_color_to_env_keys(&form_data.data.1);
+        for (enabled, row) in rows {
+            for (index, mut cell) in row.into_iter().enumerate() {
+                if index == 0 && !enabled && THEME.read().accessibility.text_markers {
+                    cell.spans.insert(0, Span::raw(DISABLED_MARKER));
+                }
 
-            let mut key = ListItem::from(key);
-            let mut value = ListItem::from(value);
+                let mut cell = ListItem::from(cell);
 
-            if !form_data.enabled {
-                key = key.fg(THEME.read().ui.secondary_foreground_color).dim();
-                value = value.fg(THEME.read().ui.secondary_foreground_color).dim();
-            }
+                if !enabled {
+                    cell = cell.fg(THEME.read().ui.secondary_foreground_color).dim();
+                }
 
-            keys.push(key);
-            values.push(value);
+                cells[index].push(cell);
+            }
         }
 
-        let mut left_list_style = Style::default();
-        let mut right_list_style = Style::default();
-
-        match form_selection.1 {
-            0 => left_list_style = left_list_style
-                .add_modifier(Modifier::BOLD)
-                .fg(THEME.read().others.selection_highlight_color),
-            1 => right_list_style = right_list_style
-                .add_modifier(Modifier::BOLD)
-                .fg(THEME.read().others.selection_highlight_color),
-            _ => {}
-        }
+        for (index, column_cells) in cells.into_iter().enumerate() {
+            let mut list_style = Style::default();
 
-        let left_list = List::new(keys)
-            .highlight_style(left_list_style)
-            .fg(THEME.read().ui.font_color);
+            if selection.1 == index {
+                list_style = list_style
+                    .add_modifier(Modifier::BOLD)
+                    .fg(THEME.read().others.selection_highlight_color);
+            }
 
-        let right_list = List::new(values)
-            .highlight_style(right_list_style)
-            .fg(THEME.read().ui.font_color);
+            let list = List::new(column_cells)
+                .highlight_style(list_style)
+                .fg(THEME.read().ui.font_color);
 
-        frame.render_stateful_widget(left_list, table_layout[0], &mut self.body_form_table.left_state.clone());
-        frame.render_stateful_widget(right_list, table_layout[1], &mut self.body_form_table.right_state.clone());
+            frame.render_stateful_widget(list, table_layout[index], &mut self.body_form_table.left_state.clone());
+        }
 
         // Form input & cursor
 
         if self.state == EditingRequestBodyTable {
-            let cell_with = form_layout[1].width / 2;
-
-            let width_adjustment = match form_selection.1 {
-                0 => 0,
-                1 => {
-                    let even_odd_adjustment = match form_layout[1].width % 2 {
-                        1 => 1,
-                        0 => 2,
-                        _ => 0
-                    };
-                    cell_with - even_odd_adjustment
-                },
-                _ => 0
-            };
+            let cell_rect = table_layout[selection.1];
 
-            let height_adjustment = (form_selection.0 - self.body_form_table.left_state.offset()) as u16 % form_layout[1].height;
+            let height_adjustment = (selection.0 - self.body_form_table.left_state.offset()) as u16 % form_layout[1].height;
 
-            let selection_position_x = form_layout[1].x + width_adjustment + horizontal_margin;
+            let selection_position_x = cell_rect.x;
             let selection_position_y = form_layout[1].y + height_adjustment;
 
             let form_data_text = self.body_form_table.selection_text_input.text.clone();
 
-            let text_input = Paragraph::new(format!("{:fill$}", form_data_text, fill = (cell_with - horizontal_margin) as usize));
-            let text_rect = Rect::new(selection_position_x, selection_position_y, cell_with, 1);
+            let text_input = Paragraph::new(format!("{:fill$}", form_data_text, fill = cell_rect.width as usize));
+            let text_rect = Rect::new(selection_position_x, selection_position_y, cell_rect.width, 1);
 
             frame.render_widget(text_input, text_rect);
 
@@ -142,4 +201,4 @@ impl App<'_> {
             ));
         }
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,166 @@
+use ratatui::Frame;
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use ratatui::prelude::{Modifier, Style};
+use ratatui::style::{Color, Stylize};
+use ratatui::text::Span;
+use ratatui::widgets::Paragraph;
+
+use crate::app::app::App;
+use crate::app::business_logic::json::{find_matching_bracket, validate_json};
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::EditingRequestBodyString;
+use crate::tui::ui::completions::render_env_key_completions;
+
+impl App<'_> {
+    /// JSON bodies are validated, and their brackets matched while being edited
+    pub(super) fn render_text_body_tab(&mut self, frame: &mut Frame, area: Rect, is_json: bool) {
+        let [text_area_area, status_area] = Layout::new(
+            Vertical,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(1)
+            ]
+        )
+            .areas(area);
+
+        self.body_text_area.set_style(Style::new().fg(THEME.read().ui.font_color));
+
+        match self.should_display_line_numbers {
+            true => self.body_text_area.set_line_number_style(Style::new().fg(THEME.read().ui.secondary_foreground_color)),
+            false => self.body_text_area.remove_line_number()
+        }
+
+        self.tui_highlight_body_unresolved_env_keys();
+
+        frame.render_widget(&self.body_text_area, text_area_area);
+
+        // E.g. to find the line of a parsing error
+        let (cursor_row, cursor_column) = self.body_text_area.cursor();
+        let cursor_position = format!("Ln {}/{}, Col {}", cursor_row + 1, self.body_text_area.lines().len(), cursor_column + 1);
+
+        let [validation_area, cursor_position_area] = Layout::new(
+            Horizontal,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(cursor_position.len() as u16 + 1)
+            ]
+        )
+            .areas(status_area);
+
+        let cursor_position_paragraph = Paragraph::new(cursor_position)
+            .right_aligned()
+            .fg(THEME.read().ui.secondary_foreground_color);
+
+        frame.render_widget(cursor_position_paragraph, cursor_position_area);
+
+        if self.state == EditingRequestBodyString {
+            if let Some(cursor_screen_position) = self.get_body_text_area_screen_position(frame, text_area_area, (cursor_row, cursor_column)) {
+                render_env_key_completions(frame, &self.env_key_completion_popup, cursor_screen_position, area);
+            }
+        }
+
+        if !is_json {
+            return;
+        }
+
+        /* JSON VALIDATION */
+
+        let body = self.body_text_area.lines().join("\n");
+
+        let validation_paragraph = match validate_json(&body) {
+            Ok(_) => Paragraph::new("✓ Valid JSON").fg(THEME.read().ui.secondary_foreground_color),
+            Err(error) => {
+                // The error is underlined when its line is displayed
+                let error_row = error.line.saturating_sub(1);
+                let error_column = error.column.saturating_sub(1);
+
+                if let Some(error_position) = self.get_body_text_area_screen_position(frame, text_area_area, (error_row, error_column)) {
+                    if let Some(cell) = frame.buffer_mut().cell_mut(error_position) {
+                        cell.set_style(Style::new().fg(Color::Red).add_modifier(Modifier::UNDERLINED));
+                    }
+                }
+
+                Paragraph::new(format!("✗ {error}")).fg(Color::Red)
+            }
+        };
+
+        frame.render_widget(validation_paragraph, validation_area);
+
+        /* BRACKET MATCHING */
+
+        if self.state != EditingRequestBodyString {
+            return;
+        }
+
+        let (opening_bracket, closing_bracket) = match find_matching_bracket(self.body_text_area.lines(), (cursor_row, cursor_column)) {
+            Some(brackets) => brackets,
+            None => return
+        };
+
+        for bracket in [opening_bracket, closing_bracket] {
+            // The bracket under the cursor keeps the cursor style
+            if bracket == (cursor_row, cursor_column) {
+                continue;
+            }
+
+            if let Some(bracket_position) = self.get_body_text_area_screen_position(frame, text_area_area, bracket) {
+                if let Some(cell) = frame.buffer_mut().cell_mut(bracket_position) {
+                    cell.set_style(Style::new().fg(THEME.read().others.selection_highlight_color).add_modifier(Modifier::BOLD | Modifier::UNDERLINED));
+                }
+            }
+        }
+    }
+
+    /// The text area does not give its scroll, it is deduced from where its cursor was rendered.
+    /// None when the (row, column) position is not displayed
+    fn get_body_text_area_screen_position(&self, frame: &mut Frame, area: Rect, (row, column): (usize, usize)) -> Option<Position> {
+        let cursor_modifier = self.body_text_area.cursor_style().add_modifier;
+
+        if cursor_modifier.is_empty() {
+            return None;
+        }
+
+        let buffer = frame.buffer_mut();
+
+        let cursor_screen_position = area
+            .positions()
+            .find(|position| buffer.cell(*position).is_some_and(|cell| cell.modifier.contains(cursor_modifier)))?;
+
+        let lines = self.body_text_area.lines();
+        let (cursor_row, cursor_column) = self.body_text_area.cursor();
+        let tab_length = self.body_text_area.tab_length() as usize;
+
+        let line = lines.get(row)?;
+        let column_offset = get_display_width(line, column, tab_length) as i64 - get_display_width(&lines[cursor_row], cursor_column, tab_length) as i64;
+
+        let x = cursor_screen_position.x as i64 + column_offset;
+        let y = cursor_screen_position.y as i64 + row as i64 - cursor_row as i64;
+
+        // The line numbers are on the left of the text
+        let text_left = match self.body_text_area.line_number_style() {
+            Some(_) => area.x as i64 + lines.len().to_string().len() as i64 + 2,
+            None => area.x as i64
+        };
+
+        if x < text_left || x >= area.right() as i64 || y < area.y as i64 || y >= area.bottom() as i64 {
+            return None;
+        }
+
+        return Some(Position::new(x as u16, y as u16));
+    }
+}
+
+/// Width of the first chars of a line as rendered by the text area, the tabs going to the next tab stop
+fn get_display_width(line: &str, chars_count: usize, tab_length: usize) -> usize {
+    let mut width = 0;
+
+    for char in line.chars().take(chars_count) {
+        width += match char {
+            '\t' => tab_length - width % tab_length.max(1),
+            _ => Span::raw(char.to_string()).width()
+        };
+    }
+
+    return width;
+}
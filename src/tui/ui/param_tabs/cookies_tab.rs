@@ -0,0 +1,153 @@
+use ratatui::Frame;
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use ratatui::prelude::{Modifier, Style};
+use ratatui::style::Stylize;
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::{EditingRequestCookie};
+use crate::tui::ui::accessibility::DISABLED_MARKER;
+use crate::models::request::Request;
+
+impl App<'_> {
+    pub(super) fn render_request_cookies_tab(&mut self, frame: &mut Frame, area: Rect, request: &Request, cookie_selection: (usize, usize)) {
+        let cookies_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(2),
+                Constraint::Fill(1)
+            ]
+        )
+            .split(area);
+
+        let inner_cookie_layout = Layout::new(
+            Horizontal,
+            [
+                Constraint::Percentage(50),
+                Constraint::Percentage(50)
+            ]
+        )
+            .split(cookies_layout[0]);
+
+        let cookie_title = Paragraph::new("Cookie")
+            .centered()
+            .block(
+                Block::new()
+                    .borders(Borders::BOTTOM | Borders::RIGHT)
+                    .fg(THEME.read().ui.secondary_foreground_color)
+            )
+            .fg(THEME.read().ui.secondary_foreground_color);
+
+        let cookie_value = Paragraph::new("Value")
+            .centered()
+            .block(
+                Block::new()
+                    .borders(Borders::BOTTOM)
+                    .fg(THEME.read().ui.secondary_foreground_color)
+            )
+            .fg(THEME.read().ui.secondary_foreground_color);
+
+        frame.render_widget(cookie_title, inner_cookie_layout[0]);
+        frame.render_widget(cookie_value, inner_cookie_layout[1]);
+
+        let horizontal_margin = 2;
+
+        let table_layout = Layout::new(
+            Horizontal,
+            [
+                Constraint::Percentage(50),
+                Constraint::Percentage(50)
+            ]
+        )
+            .horizontal_margin(horizontal_margin)
+            .split(cookies_layout[1]);
+
+        let mut cookies: Vec<ListItem> = vec![];
+        let mut values: Vec<ListItem> = vec![];
+
+        for cookie in request.cookies.iter() {
+            let mut key = self.tui_add_color_to_env_keys(&cookie.data.0);
+            let value = self.tui_add_color_to_env_keys(&cookie.data.1);
+
+            if !cookie.enabled && THEME.read().accessibility.text_markers {
+                key.spans.insert(0, Span::raw(DISABLED_MARKER));
+            }
+
+            let mut key = ListItem::from(key);
+            let mut value = ListItem::from(value);
+
+            if !cookie.enabled {
+                key = key.fg(THEME.read().ui.secondary_foreground_color).dim();
+                value = value.fg(THEME.read().ui.secondary_foreground_color).dim();
+            }
+
+            cookies.push(key);
+            values.push(value);
+        }
+
+        let mut left_list_style = Style::default();
+        let mut right_list_style = Style::default();
+
+        match cookie_selection.1 {
+            0 => left_list_style = left_list_style
+                .add_modifier(Modifier::BOLD)
+                .fg(THEME.read().others.selection_highlight_color),
+            1 => right_list_style = right_list_style
+                .add_modifier(Modifier::BOLD)
+                .fg(THEME.read().others.selection_highlight_color),
+            _ => {}
+        }
+
+        let left_list = List::new(cookies)
+            .highlight_style(left_list_style)
+            .fg(THEME.read().ui.font_color);
+
+        let right_list = List::new(values)
+            .highlight_style(right_list_style)
+            .fg(THEME.read().ui.font_color);
+
+        frame.render_stateful_widget(left_list, table_layout[0], &mut self.request_cookies_table.left_state.clone());
+        frame.render_stateful_widget(right_list, table_layout[1], &mut self.request_cookies_table.right_state.clone());
+
+        // Cookie input & cursor
+
+        if self.state == EditingRequestCookie {
+            let cell_width = cookies_layout[1].width / 2;
+
+            let width_adjustment = match cookie_selection.1 {
+                0 => 0,
+                1 => {
+                    let even_odd_adjustment = match cookies_layout[1].width % 2 {
+                        1 => 1,
+                        0 => 2,
+                        _ => 0
+                    };
+                    cell_width - even_odd_adjustment
+                },
+                _ => 0
+            };
+
+            let height_adjustment = (cookie_selection.0 - self.request_cookies_table.left_state.offset()) as u16 % cookies_layout[1].height;
+
+            let selection_position_x = cookies_layout[1].x + width_adjustment + horizontal_margin;
+            let selection_position_y = cookies_layout[1].y + height_adjustment;
+            
+            let text_rect = Rect::new(selection_position_x, selection_position_y, cell_width, 1);
+            
+            let adjusted_input_length = text_rect.width as usize - 2;
+            let (padded_text, input_cursor_position) = self.request_cookies_table.selection_text_input.get_padded_text_and_cursor(adjusted_input_length);
+            
+            let text_input = Paragraph::new(format!("{:fill$}", padded_text, fill = (cell_width - horizontal_margin) as usize));
+
+            frame.render_widget(text_input, text_rect);
+
+            frame.set_cursor_position(Position::new(
+                selection_position_x + input_cursor_position as u16,
+                selection_position_y
+            ));
+        }
+    }
+}
\ No newline at end of file
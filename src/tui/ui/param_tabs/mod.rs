@@ -2,7 +2,13 @@ pub mod param_tabs;
 mod query_params_tab;
 mod basic_auth_tab;
 mod bearer_token_tab;
+mod hmac_auth_tab;
+mod ntlm_auth_tab;
+mod oauth1_auth_tab;
 mod headers_tab;
+mod cookies_tab;
 mod body_form_tab;
 mod body_file_tab;
-mod script;
\ No newline at end of file
+mod script;
+mod body_text_tab;
+mod plugin_auth_tab;
\ No newline at end of file
@@ -1,17 +1,18 @@
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::layout::Direction::Vertical;
-use ratatui::prelude::Style;
 use ratatui::style::Stylize;
 use ratatui::text::Line;
-use ratatui::widgets::{Block, Borders, Paragraph, Tabs};
+use ratatui::widgets::{Block, Borders, Paragraph, Tabs, Wrap};
 use strum::{Display, EnumIter, FromRepr, IntoEnumIterator};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::models::auth::Auth::*;
 use crate::models::body::ContentType::*;
 use crate::models::request::Request;
+use crate::tui::utils::markdown::markdown_to_lines;
 
 #[derive(Default, Clone, Copy, Display, FromRepr, EnumIter)]
 pub enum RequestParamsTabs {
@@ -22,10 +23,14 @@ pub enum RequestParamsTabs {
     Auth,
     #[strum(to_string = "Headers")]
     Headers,
+    #[strum(to_string = "Cookies")]
+    Cookies,
     #[strum(to_string = "Body")]
     Body,
     #[strum(to_string = "Scripts")]
-    Scripts
+    Scripts,
+    #[strum(to_string = "Docs")]
+    Docs
 }
 
 impl App<'_> {
@@ -43,24 +48,31 @@ impl App<'_> {
 
         let param_tabs = RequestParamsTabs::iter()
             .map(|tab| {
+                let tab_name = tr(&tab.to_string());
+
                 let text = match tab {
                     RequestParamsTabs::QueryParams => match request.params.is_empty() {
-                        true => tab.to_string(),
-                        false => format!("{} ({})", tab.to_string(), request.params.len())
+                        true => tab_name,
+                        false => format!("{} ({})", tab_name, request.params.len())
                     },
                     RequestParamsTabs::Auth => match request.auth {
-                        NoAuth => tab.to_string(),
-                        BasicAuth { .. } | BearerToken { .. } => format!("{} ({})", tab.to_string(), request.auth.to_string())
+                        NoAuth => tab_name,
+                        BasicAuth { .. } | BearerToken { .. } | HmacSignature { .. } | Ntlm { .. } | OAuth1 { .. } | Plugin { .. } => format!("{} ({})", tab_name, request.auth)
                     },
                     RequestParamsTabs::Headers => match request.headers.is_empty() {
-                        true => tab.to_string(),
-                        false => format!("{} ({})", tab.to_string(), request.headers.len())
+                        true => tab_name,
+                        false => format!("{} ({})", tab_name, request.headers.len())
+                    },
+                    RequestParamsTabs::Cookies => match request.cookies.is_empty() {
+                        true => tab_name,
+                        false => format!("{} ({})", tab_name, request.cookies.len())
                     },
                     RequestParamsTabs::Body => match request.body {
-                        NoBody => tab.to_string(),
-                        Multipart(_) | Form(_) | File(_) | Raw(_) | Json(_) | Xml(_) | Html(_) | Javascript(_) => format!("{} ({})", tab.to_string(), request.body.to_string())
+                        NoBody => tab_name,
+                        Multipart(_) | Form(_) | File(_) | Raw(_) | Json(_) | Xml(_) | Html(_) | Javascript(_) | Protobuf(_) | MessagePack(_) | Cbor(_) => format!("{} ({})", tab_name, request.body)
                     },
-                    RequestParamsTabs::Scripts => tab.to_string(),
+                    RequestParamsTabs::Scripts => tab_name,
+                    RequestParamsTabs::Docs => tab_name,
                 };
 
                 text.fg(THEME.read().ui.font_color)
@@ -86,8 +98,8 @@ impl App<'_> {
                     None => {
                         let params_lines = vec![
                             Line::default(),
-                            Line::from("No params").fg(THEME.read().ui.font_color),
-                            Line::from("(Add one with n or via the URL)").fg(THEME.read().ui.secondary_foreground_color)
+                            Line::from(tr("No params")).fg(THEME.read().ui.font_color),
+                            Line::from(tr("(Add one with n or via the URL)")).fg(THEME.read().ui.secondary_foreground_color)
                         ];
 
                         let params_paragraph = Paragraph::new(params_lines).centered();
@@ -104,8 +116,8 @@ impl App<'_> {
                     NoAuth => {
                         let auth_lines = vec![
                             Line::default(),
-                            Line::from("No auth").fg(THEME.read().ui.font_color),
-                            Line::from("(Change auth method with ^a)").fg(THEME.read().ui.secondary_foreground_color)
+                            Line::from(tr("No auth")).fg(THEME.read().ui.font_color),
+                            Line::from(tr("(Change auth method with ^a)")).fg(THEME.read().ui.secondary_foreground_color)
                         ];
 
                         let auth_paragraph = Paragraph::new(auth_lines).centered();
@@ -118,6 +130,18 @@ impl App<'_> {
                     BearerToken { .. } => {
                         self.render_bearer_token_tab(frame, request_params_layout[1]);
                     }
+                    HmacSignature { algorithm, encoding, .. } => {
+                        self.render_hmac_auth_tab(frame, request_params_layout[1], *algorithm, *encoding);
+                    }
+                    Ntlm { .. } => {
+                        self.render_ntlm_auth_tab(frame, request_params_layout[1]);
+                    }
+                    OAuth1 { signature_method, placement, .. } => {
+                        self.render_oauth1_auth_tab(frame, request_params_layout[1], *signature_method, *placement);
+                    }
+                    Plugin { .. } => {
+                        self.render_plugin_auth_tab(frame, request_params_layout[1]);
+                    }
                 }
             }
             RequestParamsTabs::Headers => {
@@ -125,8 +149,8 @@ impl App<'_> {
                     None => {
                         let headers_lines = vec![
                             Line::default(),
-                            Line::from("Default headers").fg(THEME.read().ui.font_color),
-                            Line::from("(Add one with n)").fg(THEME.read().ui.secondary_foreground_color)
+                            Line::from(tr("Default headers")).fg(THEME.read().ui.font_color),
+                            Line::from(tr("(Add one with n)")).fg(THEME.read().ui.secondary_foreground_color)
                         ];
 
                         let headers_paragraph = Paragraph::new(headers_lines).centered();
@@ -138,51 +162,90 @@ impl App<'_> {
                     }
                 }
             }
+            RequestParamsTabs::Cookies => {
+                match self.request_cookies_table.selection {
+                    None => {
+                        let cookies_lines = vec![
+                            Line::default(),
+                            Line::from(tr("No request cookies")).fg(THEME.read().ui.font_color),
+                            Line::from(tr("(Add one with n, the jar cookies are still sent)")).fg(THEME.read().ui.secondary_foreground_color)
+                        ];
+
+                        let cookies_paragraph = Paragraph::new(cookies_lines).centered();
+
+                        frame.render_widget(cookies_paragraph, request_params_layout[1]);
+                    },
+                    Some(cookie_selection) => {
+                        self.render_request_cookies_tab(frame, request_params_layout[1], request, cookie_selection);
+                    }
+                }
+            }
             RequestParamsTabs::Body => {
                 match &request.body {
                     NoBody => {
                         let body_lines = vec![
                             Line::default(),
-                            Line::from("No body").fg(THEME.read().ui.font_color),
-                            Line::from("(Change body type with ^b)").fg(THEME.read().ui.secondary_foreground_color)
+                            Line::from(tr("No body")).fg(THEME.read().ui.font_color),
+                            Line::from(tr("(Change body type with ^b)")).fg(THEME.read().ui.secondary_foreground_color)
                         ];
 
                         let body_paragraph = Paragraph::new(body_lines).centered();
 
                         frame.render_widget(body_paragraph, request_params_layout[1]);
                     }
-                    Multipart(form) | Form(form) => {
+                    Multipart(_) | Form(_) => {
                         match self.body_form_table.selection {
                             None => {
                                 let multipart_form_lines = vec![
                                     Line::default(),
-                                    Line::from("No form data").fg(THEME.read().ui.font_color),
-                                    Line::from("(Add one with n)").fg(THEME.read().ui.secondary_foreground_color)
+                                    Line::from(tr("No form data")).fg(THEME.read().ui.font_color),
+                                    Line::from(tr("(Add one with n)")).fg(THEME.read().ui.secondary_foreground_color)
                                 ];
 
                                 let multipart_form_paragraph = Paragraph::new(multipart_form_lines).centered();
 
                                 frame.render_widget(multipart_form_paragraph, request_params_layout[1]);
                             },
-                            Some(multipart_form_selection) => {
-                                self.render_form_body_tab(frame, request_params_layout[1], form, multipart_form_selection);
+                            Some(multipart_form_selection) => match &request.body {
+                                Multipart(parts) => self.render_multipart_body_tab(frame, request_params_layout[1], parts, multipart_form_selection),
+                                Form(form) => self.render_form_body_tab(frame, request_params_layout[1], form, multipart_form_selection),
+                                _ => {}
                             }
                         }
                     },
                     File(_) => {
                       self.render_file_body_tab(frame, request_params_layout[1]);
                     },
-                    Raw(_) | Json(_) | Xml(_) | Html(_) | Javascript(_) => {
-                        self.body_text_area.set_style(Style::new().fg(THEME.read().ui.font_color));
-                        self.body_text_area.set_line_number_style(Style::new().fg(THEME.read().ui.secondary_foreground_color));
-
-                        frame.render_widget(&self.body_text_area, request_params_layout[1]);
+                    Raw(_) | Json(_) | Xml(_) | Html(_) | Javascript(_) | Protobuf(_) | MessagePack(_) | Cbor(_) => {
+                        self.render_text_body_tab(frame, request_params_layout[1], matches!(request.body, Json(_)));
                     }
                 }
             }
             RequestParamsTabs::Scripts => {
                 self.render_request_script(frame, request_params_layout[1]);
             }
+            RequestParamsTabs::Docs => {
+                match &request.description {
+                    None => {
+                        let docs_lines = vec![
+                            Line::default(),
+                            Line::from(tr("No description")).fg(THEME.read().ui.font_color),
+                            Line::from(tr("(Write one in Markdown with Enter)")).fg(THEME.read().ui.secondary_foreground_color)
+                        ];
+
+                        let docs_paragraph = Paragraph::new(docs_lines).centered();
+
+                        frame.render_widget(docs_paragraph, request_params_layout[1]);
+                    },
+                    Some(description) => {
+                        let docs_paragraph = Paragraph::new(markdown_to_lines(description))
+                            .wrap(Wrap { trim: false })
+                            .scroll((self.request_description_scroll, 0));
+
+                        frame.render_widget(docs_paragraph, request_params_layout[1]);
+                    }
+                }
+            }
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,83 @@
+use ratatui::Frame;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::{Constraint, Layout, Position, Rect};
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::{EditingRequestAuthPlugin, SelectedRequest};
+
+impl App<'_> {
+    pub(super) fn render_plugin_auth_tab(&mut self, frame: &mut Frame, area: Rect) {
+        let plugin_auth_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(3),
+                Constraint::Length(3),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(4)
+            .split(area);
+
+        let mut should_color_blocks = false;
+        let mut should_display_cursor = false;
+
+        // Prevent from rendering the cursor while no input text has been selected
+        match self.state {
+            SelectedRequest => {
+                should_color_blocks = true;
+            },
+            EditingRequestAuthPlugin => {
+                should_color_blocks = true;
+                should_display_cursor = true;
+            },
+            _ => {}
+        };
+
+        let input_selected = self.auth_text_input_selection.selected;
+
+        let text_inputs = [
+            ("Plugin", &self.auth_plugin_name_text_input),
+            ("Config", &self.auth_plugin_config_text_input),
+        ];
+
+        let mut input_cursor_position = 0;
+        let mut paragraphs = vec![];
+
+        for (index, (title, text_input)) in text_inputs.into_iter().enumerate() {
+            let mut block = Block::new()
+                .title(title)
+                .borders(Borders::ALL)
+                .fg(THEME.read().ui.main_foreground_color);
+
+            let adjusted_input_length = plugin_auth_layout[index].width as usize - 2;
+            let (padded_text, cursor_position) = text_input.get_padded_text_and_cursor(adjusted_input_length);
+
+            let line = self.tui_add_color_to_env_keys(&padded_text);
+
+            let mut paragraph = Paragraph::new(line).fg(THEME.read().ui.font_color);
+
+            if should_color_blocks && input_selected == index {
+                block = block.fg(THEME.read().others.selection_highlight_color);
+                paragraph = paragraph.fg(THEME.read().others.selection_highlight_color);
+
+                input_cursor_position = cursor_position as u16;
+            }
+
+            paragraphs.push(paragraph.block(block));
+        }
+
+        if should_display_cursor {
+            frame.set_cursor_position(Position::new(
+                plugin_auth_layout[input_selected].x + input_cursor_position + 1,
+                plugin_auth_layout[input_selected].y + 1
+            ));
+        }
+
+        for (index, paragraph) in paragraphs.into_iter().enumerate() {
+            frame.render_widget(paragraph, plugin_auth_layout[index]);
+        }
+    }
+}
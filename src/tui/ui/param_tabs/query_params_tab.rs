@@ -3,11 +3,14 @@ use ratatui::layout::Direction::{Horizontal, Vertical};
 use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::prelude::{Modifier, Style};
 use ratatui::style::Stylize;
+use ratatui::text::Span;
 use ratatui::widgets::{Block, Borders, List, ListItem, Paragraph};
 
 use crate::app::app::App;
 use crate::app::files::theme::THEME;
 use crate::tui::app_states::AppState::EditingRequestParam;
+use crate::tui::ui::accessibility::DISABLED_MARKER;
+use crate::tui::ui::completions::render_env_key_completions;
 use crate::models::request::Request;
 
 impl App<'_> {
@@ -67,9 +70,13 @@ impl App<'_> {
         let mut values: Vec<ListItem> = vec![];
 
         for param in request.params.iter() {
-            let key = self.tui_add_color_to_env_keys(&param.data.0);
+            let mut key = self.tui_add_color_to_env_keys(&param.data.0);
             let value = self.tui_add_color_to_env_keys(&param.data.1);
 
+            if !param.enabled && THEME.read().accessibility.text_markers {
+                key.spans.insert(0, Span::raw(DISABLED_MARKER));
+            }
+
             let mut key = ListItem::from(key);
             let mut value = ListItem::from(value);
 
@@ -138,10 +145,11 @@ impl App<'_> {
 
             frame.render_widget(text_input, text_rect);
 
-            frame.set_cursor_position(Position::new(
-                selection_position_x + input_cursor_position as u16,
-                selection_position_y
-            ));
+            let cursor_position = Position::new(selection_position_x + input_cursor_position as u16, selection_position_y);
+
+            frame.set_cursor_position(cursor_position);
+
+            render_env_key_completions(frame, &self.env_key_completion_popup, cursor_position, area);
         }
     }
 }
\ No newline at end of file
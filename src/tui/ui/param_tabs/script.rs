@@ -1,11 +1,12 @@
 use std::ops::Deref;
 use ratatui::Frame;
-use ratatui::layout::{Alignment, Constraint, Layout, Rect};
+use ratatui::layout::{Alignment, Constraint, Layout, Position, Rect};
 use ratatui::layout::Direction::Vertical;
 use ratatui::prelude::{Style, Stylize};
-use ratatui::widgets::{Block, Borders};
+use ratatui::widgets::{Block, Borders, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::app_states::AppState;
 
@@ -14,8 +15,9 @@ impl App<'_> {
         let scripts_layout = Layout::new(
             Vertical,
             vec![
-                Constraint::Percentage(50),
-                Constraint::Percentage(50)
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+                Constraint::Length(3)
             ]
         )
             .split(area);
@@ -29,6 +31,7 @@ impl App<'_> {
         let title = match self.script_console.script_selection {
             0 => " Pre-request ",
             1 => " Post-request ",
+            2 => " Response processor ",
             _ => ""
         };
         
@@ -41,8 +44,11 @@ impl App<'_> {
                 1 => {
                     pre_request_script_text_area.set_style(Style::new().fg(THEME.read().ui.font_color));
                     post_request_script_text_area.set_style(Style::new().fg(THEME.read().others.selection_highlight_color));
+                },
+                _ => {
+                    pre_request_script_text_area.set_style(Style::new().fg(THEME.read().ui.font_color));
+                    post_request_script_text_area.set_style(Style::new().fg(THEME.read().ui.font_color));
                 }
-                _ => {}
             };
         }
         else {
@@ -60,5 +66,37 @@ impl App<'_> {
 
         frame.render_widget(pre_request_script_text_area.deref(), scripts_layout[0]);
         frame.render_widget(post_request_script_text_area.deref(), scripts_layout[1]);
+
+        /* RESPONSE PROCESSOR */
+
+        let is_processor_highlighted = match self.state {
+            AppState::SelectedRequest => self.script_console.script_selection == 2,
+            AppState::EditingResponseProcessor => true,
+            _ => false
+        };
+
+        let processor_block = Block::default()
+            .title(tr("Response processor (shell command, body on stdin)"))
+            .borders(Borders::TOP | Borders::BOTTOM)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let processor_inner_area = processor_block.inner(scripts_layout[2]);
+        let (padded_text, cursor_position) = self.script_console.response_processor_text_input.get_padded_text_and_cursor(processor_inner_area.width as usize);
+
+        let processor_paragraph = Paragraph::new(padded_text)
+            .block(processor_block)
+            .fg(match is_processor_highlighted {
+                true => THEME.read().others.selection_highlight_color,
+                false => THEME.read().ui.font_color
+            });
+
+        frame.render_widget(processor_paragraph, scripts_layout[2]);
+
+        if self.state == AppState::EditingResponseProcessor {
+            frame.set_cursor_position(Position::new(
+                processor_inner_area.x + cursor_position as u16,
+                processor_inner_area.y
+            ));
+        }
     }
 }
\ No newline at end of file
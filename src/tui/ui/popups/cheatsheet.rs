@@ -0,0 +1,93 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::layout::Direction::Vertical;
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::key_bindings::KEY_BINDINGS;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+/// Column of the keys, the action names are padded up to it
+const ACTION_NAME_WIDTH: usize = 40;
+
+impl App<'_> {
+    pub fn render_cheatsheet_popup(&mut self, frame: &mut Frame) {
+        let key_bindings = KEY_BINDINGS.read();
+
+        let popup_block = Block::default()
+            .title(tr("Cheatsheet"))
+            .title_bottom(format!(
+                "Search by typing, scroll with {}/{}, quit with {}",
+                key_bindings.generic.navigation.alt_move_cursor_up,
+                key_bindings.generic.navigation.alt_move_cursor_down,
+                key_bindings.generic.text_inputs.text_input.cancel
+            ))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.secondary_background_color);
+
+        let area = centered_rect(110, 40, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let [search_area, actions_area] = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(2),
+                Constraint::Fill(1),
+            ]
+        )
+            .horizontal_margin(1)
+            .areas(popup_block.inner(area));
+
+        frame.render_widget(popup_block, area);
+
+        let search_prefix = "Search: ";
+        let search_width = (search_area.width as usize).saturating_sub(search_prefix.len());
+        let (padded_search, search_cursor_position) = self.cheatsheet_popup.search_input.get_padded_text_and_cursor(search_width);
+
+        let search_line = Line::from(vec![
+            Span::raw(search_prefix).fg(THEME.read().ui.secondary_foreground_color),
+            Span::raw(padded_search).fg(THEME.read().ui.font_color),
+        ]);
+
+        frame.render_widget(Paragraph::new(search_line), search_area);
+
+        frame.set_cursor_position(Position::new(
+            search_area.x + (search_prefix.len() + search_cursor_position) as u16,
+            search_area.y
+        ));
+
+        let mut lines: Vec<Line> = vec![];
+        let filtered_groups = self.cheatsheet_popup.get_filtered_groups();
+
+        if filtered_groups.is_empty() {
+            lines.push(Line::from(tr("No matching action")).fg(THEME.read().ui.secondary_foreground_color).centered());
+        }
+
+        for (context, actions) in filtered_groups {
+            lines.push(Line::from(context.to_string()).fg(THEME.read().others.selection_highlight_color).bold());
+
+            for action in actions {
+                lines.push(Line::from(vec![
+                    Span::raw(format!("    {:<ACTION_NAME_WIDTH$}", action.name)).fg(THEME.read().ui.font_color),
+                    Span::raw(action.keys.clone()).fg(THEME.read().ui.secondary_foreground_color),
+                ]));
+            }
+
+            lines.push(Line::default());
+        }
+
+        // Do not scroll past the last action
+        let max_scroll = lines.len().saturating_sub(actions_area.height as usize) as u16;
+        self.cheatsheet_popup.vertical_scroll = self.cheatsheet_popup.vertical_scroll.min(max_scroll);
+
+        let actions_paragraph = Paragraph::new(lines).scroll((self.cheatsheet_popup.vertical_scroll, 0));
+
+        frame.render_widget(actions_paragraph, actions_area);
+    }
+}
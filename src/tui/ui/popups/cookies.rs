@@ -1,19 +1,21 @@
 use ratatui::Frame;
-use ratatui::layout::{Constraint, Layout, Rect};
+use ratatui::layout::{Constraint, Layout, Position, Rect};
 use ratatui::layout::Direction::{Horizontal, Vertical};
 use ratatui::prelude::{Line, Modifier, Style};
 use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState::{CreatingCookie, EditingCookies};
 use crate::tui::utils::centered_rect::centered_rect;
 use crate::tui::utils::stateful::cookie_table::{CookieColumns, COOKIES_COLUMNS_NUMBER};
 
 impl App<'_> {
     pub fn render_cookies_popup(&mut self, frame: &mut Frame) {
         let popup_block = Block::default()
-            .title("Cookies")
+            .title(tr("Cookies"))
             .borders(Borders::ALL)
             .fg(THEME.read().ui.font_color)
             .bg(THEME.read().ui.main_background_color);
@@ -25,11 +27,25 @@ impl App<'_> {
 
         let horizontal_margin = 1;
 
+        let is_typing = matches!(self.state, EditingCookies | CreatingCookie);
+
+        let input_height = match is_typing {
+            true => 3,
+            false => 0
+        };
+
+        let error_height = match self.cookies_popup.error {
+            None => 0,
+            Some(_) => 1
+        };
+
         let cookies_layout = Layout::new(
             Vertical,
             [
                 Constraint::Length(2),
-                Constraint::Fill(1)
+                Constraint::Fill(1),
+                Constraint::Length(error_height),
+                Constraint::Length(input_height),
             ]
         )
             .vertical_margin(1)
@@ -43,7 +59,7 @@ impl App<'_> {
             .split(cookies_layout[0]);
 
         let header_names = vec![
-            CookieColumns::URL.to_string(),
+            CookieColumns::Domain.to_string(),
             CookieColumns::Name.to_string(),
             CookieColumns::Value.to_string(),
             CookieColumns::Path.to_string(),
@@ -66,8 +82,8 @@ impl App<'_> {
             None => {
                 let cookies_lines = vec![
                     Line::default(),
-                    Line::from("No cookies"),
-                    Line::from("(Add one by sending a request)".fg(THEME.read().ui.font_color))
+                    Line::from(tr("No cookies")),
+                    Line::from(tr("(Add one by sending a request or by creating it)").fg(THEME.read().ui.font_color))
                 ];
 
                 let cookies_paragraph = Paragraph::new(cookies_lines).centered();
@@ -76,9 +92,48 @@ impl App<'_> {
             },
             Some(selection) => {
                 self.render_cookie_list(selection, frame, cookies_layout[1]);
-                //self.render_cookie_cursor(selection, horizontal_margin + 1, frame, cookies_layout[1]);
             }
         }
+
+        if let Some(error) = &self.cookies_popup.error {
+            let error_paragraph = Paragraph::new(error.as_str()).red();
+
+            frame.render_widget(error_paragraph, cookies_layout[2]);
+        }
+
+        if is_typing {
+            self.render_cookie_input(frame, cookies_layout[3]);
+        }
+    }
+
+    fn render_cookie_input(&mut self, frame: &mut Frame, area: Rect) {
+        let title = match (&self.state, self.cookies_popup.cookies_table.selection) {
+            (EditingCookies, Some((_, column))) => match CookieColumns::from_repr(column) {
+                Some(column) => format!("Edit {}", column.to_string().replace('\n', " ")),
+                None => String::from("Edit")
+            },
+            _ => String::from("New cookie: <URL> <name>=<value>; Path=/; Max-Age=3600; HttpOnly")
+        };
+
+        let input_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let input_area = input_block.inner(area);
+
+        let adjusted_input_length = input_area.width as usize;
+        let (padded_text, input_cursor_position) = self.cookies_popup.cookies_table.selection_text_input.get_padded_text_and_cursor(adjusted_input_length);
+
+        let input_paragraph = Paragraph::new(padded_text).fg(THEME.read().ui.font_color);
+
+        frame.render_widget(input_block, area);
+        frame.render_widget(input_paragraph, input_area);
+
+        frame.set_cursor_position(Position::new(
+            input_area.x + input_cursor_position as u16,
+            input_area.y
+        ));
     }
 
     fn render_cookie_list(&mut self, selection: (usize, usize), frame: &mut Frame, area: Rect) {
@@ -131,36 +186,4 @@ impl App<'_> {
             );
         }
     }
-
-    /*
-    fn render_cookie_cursor(&mut self, selection: (usize, usize), horizontal_margin: u16, frame: &mut Frame, area: Rect) {
-        if self.state == EditingCookies {
-            let cell_with = area.width / COOKIES_COLUMNS_NUMBER as u16;
-
-            let even_odd_adjustment = match area.width % 2 {
-                1 => 1,
-                0 => 2,
-                _ => 0
-            };
-
-            let width_adjustment = cell_with - even_odd_adjustment;
-
-            let height_adjustment = (selection.0 - self.cookies_popup.cookies_table.lists_states[0].offset()) as u16 % area.height;
-
-            let selection_position_x = area.x + width_adjustment + horizontal_margin;
-            let selection_position_y = area.y + height_adjustment;
-
-            let param_text = self.cookies_popup.cookies_table.selection_text_input.text.clone();
-
-            let text_input = Paragraph::new(format!("{:fill$}", param_text, fill = (cell_with - horizontal_margin) as usize));
-            let text_rect = Rect::new(selection_position_x, selection_position_y, cell_with, 1);
-
-            frame.render_widget(text_input, text_rect);
-
-            frame.set_cursor(
-                selection_position_x + self.cookies_popup.cookies_table.selection_text_input.cursor_position as u16,
-                selection_position_y
-            );
-        }
-    }*/
-}
\ No newline at end of file
+}
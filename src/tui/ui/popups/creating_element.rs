@@ -5,13 +5,14 @@ use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::utils::centered_rect::centered_rect;
 
 impl App<'_> {
     pub fn render_creating_element_popup(&mut self, frame: &mut Frame) {
         let popup_block = Block::default()
-            .title("Choose element to create")
+            .title(tr("Choose element to create"))
             .borders(Borders::ALL)
             .fg(THEME.read().ui.main_foreground_color)
             .bg(THEME.read().ui.main_background_color);
@@ -4,12 +4,13 @@ use ratatui::prelude::Stylize;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::utils::centered_rect::centered_rect;
 impl App<'_> {
     pub fn render_creating_new_collection_popup(&mut self, frame: &mut Frame) {
         let popup_block = Block::default()
-            .title("Enter the new collection name")
+            .title(tr("Enter the new collection name"))
             .borders(Borders::ALL)
             .fg(THEME.read().ui.main_foreground_color)
             .bg(THEME.read().ui.main_background_color);
@@ -5,6 +5,8 @@ use ratatui::prelude::Stylize;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::key_bindings::KEY_BINDINGS;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::utils::centered_rect::centered_rect;
 impl App<'_> {
@@ -15,36 +17,49 @@ impl App<'_> {
             .bg(THEME.read().ui.main_background_color);
 
 
-        let area = centered_rect(50, 6, frame.area());
+        // The template selection is only displayed when there are templates
+        let has_templates = !self.request_templates.is_empty();
+
+        let area = match has_templates {
+            true => centered_rect(50, 9, frame.area()),
+            false => centered_rect(50, 6, frame.area())
+        };
+
+        let mut constraints = vec![Constraint::Length(3)];
+
+        if has_templates {
+            constraints.push(Constraint::Length(3));
+        }
+
+        constraints.push(Constraint::Length(3));
 
         let new_request_layout = Layout::new(
             Vertical,
-            vec![
-                Constraint::Length(3),
-                Constraint::Length(3),
-            ]
+            constraints
         )
             .split(area);
 
+        let name_input_area = new_request_layout[new_request_layout.len() - 1];
+
 
         let selected_collection_name = self.collections[self.new_request_popup.selected_collection].name.clone();
         let selected_collection_paragraph = Paragraph::new(selected_collection_name)
             .fg(THEME.read().ui.font_color)
             .block(
                 Block::new()
-                    .title("Collection ↑ ↓")
+                    .title(tr("Collection ↑ ↓"))
                     .borders(Borders::ALL)
                     .fg(THEME.read().ui.main_foreground_color)
             );
 
-        let adjusted_input_length = new_request_layout[1].width as usize - 2;
+        let adjusted_input_length = name_input_area.width as usize - 2;
         let (padded_text, input_cursor_position) = self.new_request_popup.text_input.get_padded_text_and_cursor(adjusted_input_length);
         
         let new_request_name_paragraph = Paragraph::new(padded_text)
             .fg(THEME.read().ui.font_color)
             .block(
                 Block::new()
-                    .title("Request name")
+                    .title(tr("Request name"))
                     .borders(Borders::ALL)
                     .fg(THEME.read().ui.main_foreground_color)
             );
@@ -52,11 +67,29 @@ impl App<'_> {
         frame.render_widget(Clear, area);
         frame.render_widget(popup_block, area);
         frame.render_widget(selected_collection_paragraph, new_request_layout[0]);
-        frame.render_widget(new_request_name_paragraph, new_request_layout[1]);
+        frame.render_widget(new_request_name_paragraph, name_input_area);
+
+        if has_templates {
+            let selected_template_name = match self.new_request_popup.selected_template {
+                None => String::from("None"),
+                Some(template_index) => self.request_templates[template_index].name.clone()
+            };
+
+            let selected_template_paragraph = Paragraph::new(selected_template_name)
+                .fg(THEME.read().ui.font_color)
+                .block(
+                    Block::new()
+                        .title(format!("Template {}", KEY_BINDINGS.read().main_menu.next_request_template))
+                        .borders(Borders::ALL)
+                        .fg(THEME.read().ui.main_foreground_color)
+                );
+
+            frame.render_widget(selected_template_paragraph, new_request_layout[1]);
+        }
 
         frame.set_cursor_position(Position::new(
-            new_request_layout[1].x + input_cursor_position as u16 + 1,
-            new_request_layout[1].y + 1
+            name_input_area.x + input_cursor_position as u16 + 1,
+            name_input_area.y + 1
         ));
     }
 }
\ No newline at end of file
@@ -6,12 +6,13 @@ use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::utils::centered_rect::centered_rect;
 impl App<'_> {
     pub fn render_deleting_collection_popup(&mut self, frame: &mut Frame) {
         let popup_block = Block::default()
-            .title("Confirm delete collection")
+            .title(tr("Confirm delete collection"))
             .borders(Borders::ALL)
             .style(Style::default().bg(THEME.read().ui.main_background_color));
 
@@ -28,8 +29,8 @@ impl App<'_> {
             .horizontal_margin(1)
             .split(area);
 
-        let mut no_paragraph = Paragraph::new("no").centered();
-        let mut yes_paragraph = Paragraph::new("yes").centered();
+        let mut no_paragraph = Paragraph::new(tr("no")).centered();
+        let mut yes_paragraph = Paragraph::new(tr("yes")).centered();
 
         match self.delete_collection_popup.state {
             false => no_paragraph = no_paragraph.fg(THEME.read().others.selection_highlight_color).bold(),
@@ -6,13 +6,14 @@ use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::utils::centered_rect::centered_rect;
 
 impl App<'_> {
     pub fn render_deleting_request_popup(&mut self, frame: &mut Frame) {
         let popup_block = Block::default()
-            .title("Confirm delete request")
+            .title(tr("Confirm delete request"))
             .borders(Borders::ALL)
             .style(Style::default().bg(THEME.read().ui.main_background_color));
 
@@ -29,8 +30,8 @@ impl App<'_> {
             .horizontal_margin(1)
             .split(area);
 
-        let mut no_paragraph = Paragraph::new("no").centered();
-        let mut yes_paragraph = Paragraph::new("yes").centered();
+        let mut no_paragraph = Paragraph::new(tr("no")).centered();
+        let mut yes_paragraph = Paragraph::new(tr("yes")).centered();
 
         match self.delete_request_popup.state {
             false => no_paragraph = no_paragraph.fg(THEME.read().others.selection_highlight_color).bold(),
@@ -0,0 +1,114 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::layout::Direction::Vertical;
+use ratatui::style::{Color, Stylize};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Tabs, Wrap};
+use strum::IntoEnumIterator;
+
+use crate::app::app::App;
+use crate::app::business_logic::encoding::EncodingTool;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_encoding_tools_popup(&mut self, frame: &mut Frame) {
+        let title = match self.encoding_tools_popup.target {
+            None => String::from("Encoding tools"),
+            Some(target) => format!("Encoding tools - {target}")
+        };
+
+        let popup_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 16, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let needs_secret = self.encoding_tools_popup.tool.needs_secret();
+
+        let [tools_area, input_area, secret_area, result_area] = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(2),
+                Constraint::Length(3),
+                Constraint::Length(match needs_secret {
+                    true => 3,
+                    false => 0
+                }),
+                Constraint::Fill(1),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(1)
+            .areas(area);
+
+        let tools = Tabs::new(EncodingTool::iter().map(|tool| tool.to_string()))
+            .highlight_style(THEME.read().others.selection_highlight_color)
+            .select(self.encoding_tools_popup.tool as usize)
+            .block(
+                Block::new().borders(Borders::BOTTOM)
+                    .fg(THEME.read().ui.main_foreground_color)
+            );
+
+        frame.render_widget(tools, tools_area);
+
+        let is_editing = self.state == AppState::EditingEncodingToolsInput;
+
+        let mut inputs = vec![("Input", &self.encoding_tools_popup.input, input_area, !self.encoding_tools_popup.is_secret_selected)];
+
+        if needs_secret {
+            inputs.push(("Secret", &self.encoding_tools_popup.secret, secret_area, self.encoding_tools_popup.is_secret_selected));
+        }
+
+        for (input_name, text_input, input_area, is_selected) in inputs {
+            let mut input_block = Block::default()
+                .title(input_name)
+                .borders(Borders::ALL)
+                .fg(THEME.read().ui.main_foreground_color);
+
+            if is_selected {
+                input_block = input_block.fg(THEME.read().others.selection_highlight_color);
+            }
+
+            let input_inner_area = input_block.inner(input_area);
+
+            let adjusted_input_length = input_inner_area.width as usize;
+            let (padded_text, input_cursor_position) = text_input.get_padded_text_and_cursor(adjusted_input_length);
+
+            let input_paragraph = Paragraph::new(padded_text)
+                .block(input_block)
+                .fg(THEME.read().ui.font_color);
+
+            frame.render_widget(input_paragraph, input_area);
+
+            if is_selected && is_editing {
+                frame.set_cursor_position(Position::new(
+                    input_inner_area.x + input_cursor_position as u16,
+                    input_inner_area.y
+                ));
+            }
+        }
+
+        let result_block = Block::default()
+            .title(tr("Result"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color);
+
+        let result_paragraph = match self.encoding_tools_popup.get_result() {
+            Ok(result) => Paragraph::new(result).fg(THEME.read().ui.font_color),
+            Err(error) => Paragraph::new(error.to_string()).fg(Color::Red)
+        };
+
+        let result_paragraph = result_paragraph
+            .block(result_block)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(result_paragraph, result_area);
+    }
+}
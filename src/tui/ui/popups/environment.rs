@@ -0,0 +1,50 @@
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::Layout;
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::ui::environments::PRODUCTION_ENVIRONMENT_COLOR;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_environment_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Choose environment"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let environments_number = self.environment_popup.choices.len() as u16;
+
+        let area = centered_rect(40, 2 + environments_number, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let environments_layout = Layout::new(
+            Vertical,
+            vec![Constraint::Length(1); environments_number as usize]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .split(area);
+
+        for (index, environment_name) in self.environment_popup.choices.iter().enumerate() {
+            let mut paragraph = match self.config.is_production_environment(environment_name) {
+                true => Paragraph::new(format!("{environment_name} (production)")).centered().fg(PRODUCTION_ENVIRONMENT_COLOR),
+                false => Paragraph::new(environment_name.clone()).centered().fg(THEME.read().ui.font_color)
+            };
+
+            if index == self.environment_popup.selection {
+                paragraph = paragraph.bold().reversed();
+            }
+
+            frame.render_widget(paragraph, environments_layout[index]);
+        }
+    }
+}
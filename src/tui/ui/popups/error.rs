@@ -0,0 +1,45 @@
+use ratatui::Frame;
+use ratatui::style::{Color, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_error_popup(&mut self, frame: &mut Frame) {
+        let error = match &self.error_popup.error {
+            None => return,
+            Some(error) => error
+        };
+
+        let popup_block = Block::default()
+            .title(tr("Error"))
+            .borders(Borders::ALL)
+            .fg(Color::Red)
+            .bg(THEME.read().ui.main_background_color);
+
+        let lines = vec![
+            Line::from(error.message.clone()).bold().fg(THEME.read().ui.font_color),
+            Line::from(error.value.clone()).fg(THEME.read().ui.font_color),
+            Line::default(),
+            Line::from(error.hint.clone()).fg(THEME.read().ui.secondary_foreground_color),
+        ];
+
+        // The popup grows with the wrapped lines
+        let width = u16::min(80, frame.area().width);
+        let inner_width = width.saturating_sub(2).max(1) as usize;
+        let height: usize = lines.iter().map(|line| usize::max(line.width().div_ceil(inner_width), 1)).sum();
+
+        let area = centered_rect(width, height as u16 + 2, frame.area());
+
+        let error_paragraph = Paragraph::new(lines)
+            .block(popup_block)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(error_paragraph, area);
+    }
+}
@@ -0,0 +1,36 @@
+use ratatui::Frame;
+use ratatui::layout::Position;
+use ratatui::prelude::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_filtering_by_tag_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Enter the tags to display, empty for all"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(50, 3, frame.area());
+        let tag_filter_area = popup_block.inner(area);
+
+        let adjusted_input_length = tag_filter_area.width as usize;
+        let (padded_text, input_cursor_position) = self.tag_filter_input.get_padded_text_and_cursor(adjusted_input_length);
+
+        let tag_filter_paragraph = Paragraph::new(padded_text).fg(THEME.read().ui.font_color);
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+        frame.render_widget(tag_filter_paragraph, tag_filter_area);
+
+        frame.set_cursor_position(Position::new(
+            tag_filter_area.x + input_cursor_position as u16,
+            tag_filter_area.y
+        ));
+    }
+}
@@ -0,0 +1,57 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::layout::Direction::Vertical;
+use ratatui::style::Stylize;
+use ratatui::text::Text;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_git_popup(&mut self, frame: &mut Frame) {
+        let title = match &self.selected_workspace {
+            None => String::from("Git"),
+            Some(workspace) => format!("Git - {workspace}")
+        };
+
+        let popup_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.font_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 24, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let git_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Fill(1),
+                Constraint::Fill(1)
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(1)
+            .split(area);
+
+        let status_paragraph = Paragraph::new(self.git_popup.status.clone())
+            .block(Block::new().borders(Borders::BOTTOM))
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(status_paragraph, git_layout[0]);
+
+        let last_output = match &self.git_popup.last_output {
+            None => Text::from("Pull, commit or push the workspace").fg(THEME.read().ui.secondary_foreground_color),
+            Some(last_output) => Text::from(last_output.clone())
+        };
+
+        let output_paragraph = Paragraph::new(last_output)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(output_paragraph, git_layout[1]);
+    }
+}
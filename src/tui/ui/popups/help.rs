@@ -6,6 +6,7 @@ use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Clear, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::tui::app_states::event_available_keys_to_spans;
 use crate::tui::utils::centered_rect::centered_rect;
@@ -43,7 +44,7 @@ impl App<'_> {
             .horizontal_margin(1)
             .split(area);
 
-        let title_paragraph = Paragraph::new(self.help_popup.selection.to_string().bold().underlined())
+        let title_paragraph = Paragraph::new(tr(&self.help_popup.selection.to_string()).bold().underlined())
             .centered()
             .fg(THEME.read().ui.font_color);
         frame.render_widget(title_paragraph, help_layout[1]);
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Utc};
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::style::{Color, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState;
+use crate::tui::utils::centered_rect::centered_rect;
+
+/// Registered claims holding a date, as seconds since the epoch
+const TIME_CLAIMS: [&str; 3] = ["exp", "nbf", "iat"];
+
+impl App<'_> {
+    pub fn render_jwt_popup(&mut self, frame: &mut Frame) {
+        let title = match self.jwt_popup.jwts.get(self.jwt_popup.selection) {
+            None => String::from("JWT"),
+            Some((source, _)) => format!("JWT {}/{} - {source}", self.jwt_popup.selection + 1, self.jwt_popup.jwts.len())
+        };
+
+        let popup_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let inner_area = popup_block.inner(area);
+        frame.render_widget(popup_block, area);
+
+        let [jwt_area, input_area] = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(match self.state == AppState::EditingJwtInput {
+                true => 3,
+                false => 0
+            }),
+        ])
+            .areas(inner_area);
+
+        let mut lines: Vec<Line> = vec![];
+
+        match &self.jwt_popup.decoded_jwt {
+            None => lines.push(Line::from(tr("No JWT found, paste one")).fg(THEME.read().ui.secondary_foreground_color).centered()),
+            Some(Err(error)) => lines.push(Line::from(error.clone()).fg(Color::Red)),
+            Some(Ok(decoded_jwt)) => {
+                lines.push(Line::from("Header").fg(THEME.read().ui.secondary_foreground_color).bold());
+
+                for header_line in serde_json::to_string_pretty(&decoded_jwt.header).unwrap().lines() {
+                    lines.push(Line::from(header_line.to_string()).fg(THEME.read().ui.font_color));
+                }
+
+                lines.push(Line::default());
+                lines.push(Line::from("Payload").fg(THEME.read().ui.secondary_foreground_color).bold());
+
+                for payload_line in serde_json::to_string_pretty(&decoded_jwt.payload).unwrap().lines() {
+                    lines.push(Line::from(payload_line.to_string()).fg(THEME.read().ui.font_color));
+                }
+
+                lines.push(Line::default());
+
+                let now = Utc::now().timestamp();
+
+                for claim in TIME_CLAIMS {
+                    let timestamp = match decoded_jwt.payload[claim].as_i64() {
+                        None => continue,
+                        Some(timestamp) => timestamp
+                    };
+
+                    let date = match DateTime::from_timestamp(timestamp, 0) {
+                        None => continue,
+                        Some(date) => date.to_string()
+                    };
+
+                    let status = match claim {
+                        "exp" if timestamp <= now => Some(Span::raw(" expired").fg(Color::Red)),
+                        "exp" => Some(Span::raw(" valid").fg(Color::Green)),
+                        "nbf" if timestamp > now => Some(Span::raw(" not valid yet").fg(Color::Red)),
+                        _ => None
+                    };
+
+                    let mut claim_line = vec![
+                        Span::raw(format!("{claim}: ")).fg(THEME.read().ui.secondary_foreground_color),
+                        Span::raw(date).fg(THEME.read().ui.font_color),
+                    ];
+
+                    if let Some(status) = status {
+                        claim_line.push(status);
+                    }
+
+                    lines.push(Line::from(claim_line));
+                }
+
+                let verification_line = match &self.jwt_popup.verification {
+                    None => Line::from(format!("{} signature not verified, enter a secret or a JWKS URL", decoded_jwt.get_algorithm())).fg(THEME.read().ui.secondary_foreground_color),
+                    Some(Ok(verification)) => Line::from(verification.clone()).fg(Color::Green),
+                    Some(Err(error)) => Line::from(error.clone()).fg(Color::Red)
+                };
+
+                lines.push(verification_line);
+            }
+        }
+
+        let jwt_paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .scroll((self.jwt_popup.vertical_scroll, 0));
+
+        frame.render_widget(jwt_paragraph, jwt_area);
+
+        if self.state == AppState::EditingJwtInput {
+            let input_block = Block::default()
+                .title(tr("Token, secret or JWKS URL"))
+                .borders(Borders::ALL)
+                .fg(THEME.read().ui.main_foreground_color);
+
+            let input_inner_area = input_block.inner(input_area);
+
+            let adjusted_input_length = input_inner_area.width as usize;
+            let (padded_text, input_cursor_position) = self.jwt_popup.input.get_padded_text_and_cursor(adjusted_input_length);
+
+            let input_paragraph = Paragraph::new(padded_text)
+                .block(input_block)
+                .fg(THEME.read().ui.font_color);
+
+            frame.render_widget(input_paragraph, input_area);
+
+            frame.set_cursor_position(Position::new(
+                input_inner_area.x + input_cursor_position as u16,
+                input_inner_area.y
+            ));
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::layout::Direction::Vertical;
+use ratatui::prelude::Line;
+use ratatui::style::{Color, Stylize};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Sparkline};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_load_test_popup(&mut self, frame: &mut Frame) {
+        let stats = self.load_test_popup.stats.read().clone();
+
+        let status = match (&*self.load_test_popup.error.read(), stats.is_finished()) {
+            (Some(error), _) => error.clone(),
+            (None, true) => String::from("Finished"),
+            (None, false) => String::from("Running...")
+        };
+
+        let popup_block = Block::default()
+            .title(format!("Load test - {status}"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.font_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(80, 16, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let load_test_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(6),
+                Constraint::Fill(1)
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .split(area);
+
+        let report_lines: Vec<Line> = stats
+            .to_report_lines()
+            .into_iter()
+            .map(Line::from)
+            .collect();
+
+        frame.render_widget(Paragraph::new(report_lines), load_test_layout[0]);
+
+        let latency_per_second = stats.latency_per_second();
+
+        // Only keeps the last seconds that fit in the popup
+        let width = load_test_layout[1].width as usize;
+        let latency_per_second = &latency_per_second[latency_per_second.len().saturating_sub(width)..];
+
+        let sparkline = Sparkline::default()
+            .block(Block::new().title(tr("Average latency (ms/s)")).borders(Borders::TOP))
+            .data(latency_per_second)
+            .fg(Color::Cyan);
+
+        frame.render_widget(sparkline, load_test_layout[1]);
+    }
+}
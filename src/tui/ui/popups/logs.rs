@@ -0,0 +1,69 @@
+use ratatui::Frame;
+use ratatui::style::{Color, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use tracing::Level;
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::app::startup::log_layer::APP_LOG;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_logs_popup(&mut self, frame: &mut Frame) {
+        let min_level = self.logs_popup.get_level();
+
+        let popup_block = Block::default()
+            .title(format!("{} ({} {min_level})", tr("Logs"), tr("up to")))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let app_log = APP_LOG.read();
+        let mut lines: Vec<Line> = vec![];
+
+        // A more verbose level is a greater one
+        for record in app_log.records.iter().filter(|record| record.level <= min_level) {
+            let level_color = match record.level {
+                Level::ERROR => Color::Red,
+                Level::WARN => Color::Yellow,
+                Level::INFO => Color::Green,
+                _ => THEME.read().ui.secondary_foreground_color
+            };
+
+            let mut message_lines = record.message.lines();
+
+            lines.push(Line::from(vec![
+                Span::raw(format!("{} ", record.time)).fg(THEME.read().ui.secondary_foreground_color),
+                Span::raw(format!("{:<5} ", record.level)).fg(level_color).bold(),
+                Span::raw(message_lines.next().unwrap_or_default().to_string()).fg(THEME.read().ui.font_color),
+            ]));
+
+            for message_line in message_lines {
+                lines.push(Line::from(format!("               {message_line}")).fg(THEME.read().ui.font_color));
+            }
+        }
+
+        if lines.is_empty() {
+            lines.push(Line::from(tr("No log record")).fg(THEME.read().ui.secondary_foreground_color).centered());
+        }
+
+        drop(app_log);
+
+        // Stop at the last page
+        let height = popup_block.inner(area).height as usize;
+        let max_scroll = lines.len().saturating_sub(height) as u16;
+        self.logs_popup.vertical_scroll = u16::min(self.logs_popup.vertical_scroll, max_scroll);
+
+        let logs_paragraph = Paragraph::new(lines)
+            .block(popup_block)
+            .scroll((self.logs_popup.vertical_scroll, 0));
+
+        frame.render_widget(logs_paragraph, area);
+    }
+}
@@ -5,6 +5,29 @@ pub mod creating_new_request;
 pub mod deleting_collection;
 pub mod deleting_request;
 pub mod request_settings;
+pub mod user_agent;
 pub mod renaming_collection;
 pub mod renaming_request;
 pub mod creating_element;
+pub mod webhook_listener;
+pub mod load_test;
+pub mod git;
+pub mod recovering_unsaved_edit;
+pub mod reloading_changed_collection;
+pub mod environment;
+pub mod request_preview;
+pub mod variable_usages;
+pub mod jwt;
+pub mod encoding_tools;
+pub mod go_to_line;
+
+pub mod scratchpad;
+pub mod cheatsheet;
+pub mod response_cache;
+pub mod logs;
+pub mod error;
+pub mod key_macro;
+pub mod scripting_console;
+pub mod response_links;
+pub mod editing_tags;
+pub mod filtering_by_tag;
\ No newline at end of file
@@ -0,0 +1,71 @@
+use ratatui::Frame;
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::layout::{Constraint, Layout};
+use ratatui::prelude::Style;
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_recovering_unsaved_edit_popup(&mut self, frame: &mut Frame) {
+        let message = match &self.recovered_edit {
+            None => String::new(),
+            Some(unsaved_edit) => format!(
+                "Recover the unsaved {} of {} > {} from {}?",
+                unsaved_edit.field,
+                unsaved_edit.collection_name,
+                unsaved_edit.request_name,
+                unsaved_edit.saved_at
+            )
+        };
+
+        let popup_block = Block::default()
+            .title(tr("Unsaved edit found"))
+            .borders(Borders::ALL)
+            .style(Style::default().bg(THEME.read().ui.main_background_color));
+
+        let area = centered_rect(60, 6, frame.area());
+
+        let recovering_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(2),
+                Constraint::Length(1)
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(1)
+            .split(area);
+
+        let choices_layout = Layout::new(
+            Horizontal,
+            [
+                Constraint::Percentage(50),
+                Constraint::Percentage(50),
+            ]
+        )
+            .split(recovering_layout[1]);
+
+        let message_paragraph = Paragraph::new(message)
+            .centered()
+            .wrap(Wrap { trim: true });
+
+        let mut no_paragraph = Paragraph::new(tr("no")).centered();
+        let mut yes_paragraph = Paragraph::new(tr("yes")).centered();
+
+        match self.recover_edit_popup.state {
+            false => no_paragraph = no_paragraph.fg(THEME.read().others.selection_highlight_color).bold(),
+            true => yes_paragraph = yes_paragraph.fg(THEME.read().others.selection_highlight_color).bold(),
+        }
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+        frame.render_widget(message_paragraph, recovering_layout[0]);
+        frame.render_widget(no_paragraph, choices_layout[0]);
+        frame.render_widget(yes_paragraph, choices_layout[1]);
+    }
+}
@@ -0,0 +1,91 @@
+use ratatui::Frame;
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::line_wrapping::wrap_line;
+
+impl App<'_> {
+    pub fn render_request_preview_popup(&mut self, frame: &mut Frame) {
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let preview = &self.request_preview_popup.preview;
+        let mut lines: Vec<Line> = vec![];
+
+        if preview.has_pre_request_script {
+            lines.push(Line::from("The pre-request script is not run, it may still modify the request").fg(THEME.read().ui.secondary_foreground_color));
+            lines.push(Line::default());
+        }
+
+        lines.push(Line::from(vec![
+            Span::raw(format!(" {} ", preview.method)).bg(preview.method.get_color()).fg(THEME.read().ui.font_color),
+            Span::raw(format!(" {}", preview.url)).fg(THEME.read().ui.font_color)
+        ]));
+
+        lines.push(Line::default());
+
+        for (header_name, header_value) in &preview.headers {
+            lines.push(Line::from(vec![
+                Span::raw(format!("{header_name}: ")).fg(THEME.read().ui.secondary_foreground_color),
+                Span::raw(header_value.clone()).fg(THEME.read().ui.font_color)
+            ]));
+        }
+
+        if let Some(body) = &preview.body {
+            lines.push(Line::default());
+
+            for body_line in body.lines() {
+                lines.push(Line::from(body_line.to_string()).fg(THEME.read().ui.font_color));
+            }
+        }
+
+        let inner_width = area.width.saturating_sub(2) as usize;
+        let max_width = lines.iter().map(Line::width).max().unwrap_or(0);
+
+        let (title, preview_paragraph) = match self.should_wrap_lines {
+            true => {
+                let rows: Vec<Line> = lines
+                    .iter()
+                    .flat_map(|line| wrap_line(line, inner_width, usize::MAX))
+                    .collect();
+
+                (
+                    String::from("Preview (wrapped)"),
+                    Paragraph::new(rows).scroll((self.request_preview_popup.vertical_scroll, 0))
+                )
+            },
+            false => {
+                // Scrolls until the end of the widest line is displayed
+                let max_scroll = u16::try_from(max_width.saturating_sub(inner_width)).unwrap_or(u16::MAX);
+                self.request_preview_popup.horizontal_scroll = self.request_preview_popup.horizontal_scroll.min(max_scroll);
+
+                let horizontal_scroll = self.request_preview_popup.horizontal_scroll;
+
+                let title = match max_width > inner_width {
+                    true => format!("Preview (col {}/{})", horizontal_scroll + 1, max_width),
+                    false => String::from("Preview")
+                };
+
+                (
+                    title,
+                    Paragraph::new(lines).scroll((self.request_preview_popup.vertical_scroll, horizontal_scroll))
+                )
+            }
+        };
+
+        let popup_block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let preview_paragraph = preview_paragraph.block(popup_block);
+
+        frame.render_widget(preview_paragraph, area);
+    }
+}
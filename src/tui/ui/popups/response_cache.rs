@@ -0,0 +1,106 @@
+use ratatui::Frame;
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_response_cache_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Response cache"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let response_cache = self.response_cache_popup.response_cache.read();
+
+        let mut lines: Vec<Line> = vec![];
+        // First and last line of the selected entry
+        let mut selected_entry_lines = (0, 0);
+
+        if response_cache.entries.is_empty() {
+            let empty_text = match self.config.is_response_cache_enabled() {
+                true => tr("No cached response"),
+                false => tr("No cached response, enable_response_cache must be set in the config")
+            };
+
+            lines.push(Line::from(empty_text).fg(THEME.read().ui.secondary_foreground_color).centered());
+        }
+
+        for (index, entry) in response_cache.entries.iter().enumerate() {
+            let is_selected = index == self.response_cache_popup.selection;
+
+            if is_selected {
+                selected_entry_lines.0 = lines.len();
+            }
+
+            let mut key_line = Line::from(Span::raw(entry.key.clone()).bold());
+
+            key_line = match is_selected {
+                true => key_line.fg(THEME.read().others.selection_highlight_color),
+                false => key_line.fg(THEME.read().ui.font_color)
+            };
+
+            lines.push(key_line);
+
+            let freshness = match entry.is_fresh() {
+                true => format!("fresh for {}s", entry.get_fresh_for()),
+                false => String::from("stale")
+            };
+
+            lines.push(Line::from(format!(
+                "    {} | age {}s, {freshness} | {} hit(s)",
+                entry.response.get_status_text().unwrap_or_default(),
+                entry.get_age().as_secs(),
+                entry.hits
+            )).fg(THEME.read().ui.secondary_foreground_color));
+
+            if let Some(cache_control) = &entry.cache_control {
+                lines.push(Line::from(format!("    Cache-Control: {cache_control}")).fg(THEME.read().ui.secondary_foreground_color));
+            }
+
+            if is_selected {
+                selected_entry_lines.1 = lines.len() - 1;
+            }
+        }
+
+        if !response_cache.log.is_empty() {
+            lines.push(Line::default());
+            lines.push(Line::from(tr("Recent sends")).bold().fg(THEME.read().ui.font_color));
+
+            for record in &response_cache.log {
+                lines.push(Line::from(format!("    [{}] {} | {}", record.sent_at, record.key, record.status)).fg(THEME.read().ui.secondary_foreground_color));
+            }
+        }
+
+        // Keep the whole selected entry visible
+        let height = popup_block.inner(area).height as usize;
+        let mut vertical_scroll = self.response_cache_popup.vertical_scroll as usize;
+
+        if selected_entry_lines.1 >= vertical_scroll + height {
+            vertical_scroll = (selected_entry_lines.1 + 1).saturating_sub(height);
+        }
+
+        if selected_entry_lines.0 < vertical_scroll {
+            vertical_scroll = selected_entry_lines.0;
+        }
+
+        drop(response_cache);
+
+        self.response_cache_popup.vertical_scroll = vertical_scroll as u16;
+
+        let entries_paragraph = Paragraph::new(lines)
+            .block(popup_block)
+            .scroll((self.response_cache_popup.vertical_scroll, 0));
+
+        frame.render_widget(entries_paragraph, area);
+    }
+}
@@ -0,0 +1,86 @@
+use ratatui::Frame;
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_response_links_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Response links"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let mut lines: Vec<Line> = vec![];
+        // First and last line of the selected link
+        let mut selected_link_lines = (0, 0);
+
+        if self.response_links_popup.links.is_empty() {
+            lines.push(Line::from(tr("No HAL, JSON:API or OData link in the response")).fg(THEME.read().ui.secondary_foreground_color).centered());
+        }
+
+        for (index, link) in self.response_links_popup.links.iter().enumerate() {
+            let is_selected = index == self.response_links_popup.selection;
+
+            if is_selected {
+                selected_link_lines.0 = lines.len();
+            }
+
+            let mut rel_spans = vec![Span::raw(link.rel.clone()).bold()];
+
+            if let Some(title) = &link.title {
+                rel_spans.push(Span::raw(format!(" ({title})")));
+            }
+
+            let mut rel_line = Line::from(rel_spans);
+
+            rel_line = match is_selected {
+                true => rel_line.fg(THEME.read().others.selection_highlight_color),
+                false => rel_line.fg(THEME.read().ui.font_color)
+            };
+
+            lines.push(rel_line);
+
+            let templated = match link.templated {
+                true => format!(" ({})", tr("templated")),
+                false => String::new()
+            };
+
+            lines.push(Line::from(format!("    GET {}{templated}", link.href)).fg(THEME.read().ui.font_color));
+            lines.push(Line::from(format!("    {}", link.path)).fg(THEME.read().ui.secondary_foreground_color));
+
+            if is_selected {
+                selected_link_lines.1 = lines.len() - 1;
+            }
+        }
+
+        // Keep the whole selected link visible
+        let height = popup_block.inner(area).height as usize;
+        let mut vertical_scroll = self.response_links_popup.vertical_scroll as usize;
+
+        if selected_link_lines.1 >= vertical_scroll + height {
+            vertical_scroll = (selected_link_lines.1 + 1).saturating_sub(height);
+        }
+
+        if selected_link_lines.0 < vertical_scroll {
+            vertical_scroll = selected_link_lines.0;
+        }
+
+        self.response_links_popup.vertical_scroll = vertical_scroll as u16;
+
+        let links_paragraph = Paragraph::new(lines)
+            .block(popup_block)
+            .scroll((self.response_links_popup.vertical_scroll, 0));
+
+        frame.render_widget(links_paragraph, area);
+    }
+}
@@ -0,0 +1,129 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::layout::Direction::Vertical;
+use ratatui::style::Stylize;
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::app_states::AppState;
+use crate::tui::utils::centered_rect::centered_rect;
+
+/// Text lines displayed for each entry, the whole text is yanked
+const MAX_ENTRY_LINES: usize = 4;
+
+impl App<'_> {
+    pub fn render_scratchpad_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Scratchpad"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let is_writing_note = self.state == AppState::WritingScratchpadNote;
+
+        let [entries_area, note_area] = Layout::new(
+            Vertical,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(match is_writing_note {
+                    true => 3,
+                    false => 0
+                }),
+            ]
+        )
+            .areas(popup_block.inner(area));
+
+        frame.render_widget(popup_block, area);
+
+        let mut lines: Vec<Line> = vec![];
+        // First and last line of the selected entry
+        let mut selected_entry_lines = (0, 0);
+
+        if self.scratchpad_popup.entries.is_empty() {
+            lines.push(Line::from(tr("Nothing pinned yet, pin a response part, the clipboard or a note")).fg(THEME.read().ui.secondary_foreground_color).centered());
+        }
+
+        for (index, entry) in self.scratchpad_popup.entries.iter().enumerate() {
+            let is_selected = index == self.scratchpad_popup.selection;
+
+            if is_selected {
+                selected_entry_lines.0 = lines.len();
+            }
+
+            let mut header_line = Line::from(vec![
+                Span::raw(format!("[{}] ", entry.pinned_at)),
+                Span::raw(entry.source.clone()).bold(),
+            ]);
+
+            header_line = match is_selected {
+                true => header_line.fg(THEME.read().others.selection_highlight_color),
+                false => header_line.fg(THEME.read().ui.secondary_foreground_color)
+            };
+
+            lines.push(header_line);
+
+            let text_lines_count = entry.text.lines().count();
+
+            for text_line in entry.text.lines().take(MAX_ENTRY_LINES) {
+                lines.push(Line::from(format!("    {text_line}")).fg(THEME.read().ui.font_color));
+            }
+
+            if text_lines_count > MAX_ENTRY_LINES {
+                lines.push(Line::from(format!("    … {} more lines", text_lines_count - MAX_ENTRY_LINES)).fg(THEME.read().ui.secondary_foreground_color));
+            }
+
+            if is_selected {
+                selected_entry_lines.1 = lines.len() - 1;
+            }
+
+            lines.push(Line::default());
+        }
+
+        // Keep the whole selected entry visible
+        let height = entries_area.height as usize;
+        let mut vertical_scroll = self.scratchpad_popup.vertical_scroll as usize;
+
+        if selected_entry_lines.1 >= vertical_scroll + height {
+            vertical_scroll = (selected_entry_lines.1 + 1).saturating_sub(height);
+        }
+
+        if selected_entry_lines.0 < vertical_scroll {
+            vertical_scroll = selected_entry_lines.0;
+        }
+
+        self.scratchpad_popup.vertical_scroll = vertical_scroll as u16;
+
+        let entries_paragraph = Paragraph::new(lines).scroll((self.scratchpad_popup.vertical_scroll, 0));
+
+        frame.render_widget(entries_paragraph, entries_area);
+
+        if is_writing_note {
+            let note_block = Block::default()
+                .title(tr("Note"))
+                .borders(Borders::ALL)
+                .fg(THEME.read().others.selection_highlight_color);
+
+            let note_inner_area = note_block.inner(note_area);
+
+            let (padded_text, cursor_position) = self.scratchpad_popup.note_input.get_padded_text_and_cursor(note_inner_area.width as usize);
+
+            let note_paragraph = Paragraph::new(padded_text)
+                .block(note_block)
+                .fg(THEME.read().ui.font_color);
+
+            frame.render_widget(note_paragraph, note_area);
+
+            frame.set_cursor_position(Position::new(
+                note_inner_area.x + cursor_position as u16,
+                note_inner_area.y
+            ));
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout, Position};
+use ratatui::layout::Direction::Vertical;
+use ratatui::style::{Color, Stylize};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::stateful::scripting_console_popup::ConsoleLineKind;
+
+impl App<'_> {
+    pub fn render_scripting_console_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Scripting console"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let [output_area, input_area] = Layout::new(
+            Vertical,
+            [
+                Constraint::Fill(1),
+                Constraint::Length(3),
+            ]
+        )
+            .areas(popup_block.inner(area));
+
+        frame.render_widget(popup_block, area);
+
+        let mut lines: Vec<Line> = vec![];
+
+        {
+            let console_lines = self.scripting_console_popup.lines.read();
+
+            if console_lines.is_empty() {
+                lines.push(Line::from(tr("JavaScript with collections, env, vars, responses and send(\"collection/request\")")).fg(THEME.read().ui.secondary_foreground_color).centered());
+            }
+
+            for console_line in console_lines.iter() {
+                let line = Line::from(console_line.text.clone());
+
+                lines.push(match console_line.kind {
+                    ConsoleLineKind::Input => line.fg(THEME.read().ui.secondary_foreground_color),
+                    ConsoleLineKind::Output => line.fg(THEME.read().ui.font_color),
+                    ConsoleLineKind::Error => line.fg(Color::Red),
+                });
+            }
+        }
+
+        // Follow the last lines
+        let vertical_scroll = lines.len().saturating_sub(output_area.height as usize) as u16;
+
+        let output_paragraph = Paragraph::new(lines).scroll((vertical_scroll, 0));
+
+        frame.render_widget(output_paragraph, output_area);
+
+        let input_block = Block::default()
+            .title(tr("Script"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().others.selection_highlight_color);
+
+        let input_inner_area = input_block.inner(input_area);
+
+        let (padded_text, cursor_position) = self.scripting_console_popup.input.get_padded_text_and_cursor(input_inner_area.width as usize);
+
+        let input_paragraph = Paragraph::new(padded_text)
+            .block(input_block)
+            .fg(THEME.read().ui.font_color);
+
+        frame.render_widget(input_paragraph, input_area);
+
+        frame.set_cursor_position(Position::new(
+            input_inner_area.x + cursor_position as u16,
+            input_inner_area.y
+        ));
+    }
+}
\ No newline at end of file
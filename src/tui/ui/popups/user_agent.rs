@@ -0,0 +1,65 @@
+use ratatui::Frame;
+use ratatui::layout::Constraint;
+use ratatui::layout::Direction::Vertical;
+use ratatui::layout::Layout;
+use ratatui::style::Stylize;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::models::user_agent::USER_AGENT_PRESETS;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_user_agent_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Choose User-Agent"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let presets_number = self.user_agent_popup.choices.len() as u16;
+
+        // Presets, a blank line and 3 lines for the selected User-Agent value
+        let area = centered_rect(60, 2 + presets_number + 1 + 3, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let user_agent_layout = Layout::new(
+            Vertical,
+            vec![
+                Constraint::Length(presets_number),
+                Constraint::Length(1),
+                Constraint::Length(3),
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(2)
+            .split(area);
+
+        let presets_layout = Layout::new(
+            Vertical,
+            vec![Constraint::Length(1); presets_number as usize]
+        )
+            .split(user_agent_layout[0]);
+
+        for (index, preset_name) in self.user_agent_popup.choices.iter().enumerate() {
+            let mut paragraph = Paragraph::new(preset_name.clone()).centered().fg(THEME.read().ui.font_color);
+
+            if index == self.user_agent_popup.selection {
+                paragraph = paragraph.fg(THEME.read().others.selection_highlight_color).bold();
+            }
+
+            frame.render_widget(paragraph, presets_layout[index]);
+        }
+
+        let user_agent_paragraph = Paragraph::new(USER_AGENT_PRESETS[self.user_agent_popup.selection].1.clone())
+            .centered()
+            .wrap(Wrap { trim: true })
+            .fg(THEME.read().ui.secondary_foreground_color);
+
+        frame.render_widget(user_agent_paragraph, user_agent_layout[2]);
+    }
+}
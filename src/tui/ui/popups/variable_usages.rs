@@ -0,0 +1,65 @@
+use ratatui::Frame;
+use ratatui::style::{Color, Stylize};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_variable_usages_popup(&mut self, frame: &mut Frame) {
+        let popup_block = Block::default()
+            .title(tr("Variable usages"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.main_foreground_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(100, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+
+        let mut lines: Vec<Line> = vec![];
+
+        if self.variable_usages_popup.usages.is_empty() {
+            lines.push(Line::from(tr("No environment variables or placeholders")).fg(THEME.read().ui.secondary_foreground_color).centered());
+        }
+
+        for usage in &self.variable_usages_popup.usages {
+            let mut key_line = vec![
+                Span::raw(format!("{{{{{}}}}}", usage.key)).fg(THEME.read().others.environment_variable_highlight_color).bold()
+            ];
+
+            if usage.is_builtin {
+                key_line.push(Span::raw(" builtin").fg(THEME.read().ui.secondary_foreground_color));
+            }
+            else if usage.is_undefined() {
+                key_line.push(Span::raw(" undefined").fg(Color::Red));
+            }
+            else {
+                key_line.push(Span::raw(format!(" defined in {}", usage.defined_in.join(", "))).fg(THEME.read().ui.secondary_foreground_color));
+
+                if !usage.missing_in.is_empty() {
+                    key_line.push(Span::raw(format!(", missing in {}", usage.missing_in.join(", "))).fg(Color::Red));
+                }
+            }
+
+            lines.push(Line::from(key_line));
+
+            if usage.references.is_empty() {
+                lines.push(Line::from("    unused").fg(THEME.read().ui.secondary_foreground_color));
+            }
+
+            for reference in &usage.references {
+                lines.push(Line::from(format!("    {reference}")).fg(THEME.read().ui.font_color));
+            }
+        }
+
+        let usages_paragraph = Paragraph::new(lines)
+            .block(popup_block)
+            .scroll((self.variable_usages_popup.vertical_scroll, 0));
+
+        frame.render_widget(usages_paragraph, area);
+    }
+}
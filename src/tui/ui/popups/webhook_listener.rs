@@ -0,0 +1,109 @@
+use ratatui::Frame;
+use ratatui::layout::{Constraint, Layout};
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::prelude::{Line, Modifier, Style};
+use ratatui::style::Stylize;
+use ratatui::text::Span;
+use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
+
+use crate::app::app::App;
+use crate::app::files::locale::tr;
+use crate::app::files::theme::THEME;
+use crate::tui::utils::centered_rect::centered_rect;
+
+impl App<'_> {
+    pub fn render_webhook_listener_popup(&mut self, frame: &mut Frame) {
+        let status = match (&*self.webhook_listener_popup.listener_error.read(), self.webhook_listener_popup.is_running()) {
+            (Some(error), _) => error.lines().next().unwrap_or_default().to_string(),
+            (None, true) => format!("Listening on port {}", self.webhook_listener_popup.port),
+            (None, false) => String::from("Stopped")
+        };
+
+        let popup_block = Block::default()
+            .title(format!("Webhook listener - {status}"))
+            .borders(Borders::ALL)
+            .fg(THEME.read().ui.font_color)
+            .bg(THEME.read().ui.main_background_color);
+
+        let area = centered_rect(120, 30, frame.area());
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(popup_block, area);
+
+        let webhook_layout = Layout::new(
+            Horizontal,
+            [
+                Constraint::Percentage(35),
+                Constraint::Percentage(65)
+            ]
+        )
+            .vertical_margin(1)
+            .horizontal_margin(1)
+            .split(area);
+
+        let received_requests = self.webhook_listener_popup.received_requests.read();
+
+        if received_requests.is_empty() {
+            let lines = vec![
+                Line::default(),
+                Line::from(tr("No request received")),
+                Line::from("(Start the listener and send a request to it)".fg(THEME.read().ui.secondary_foreground_color))
+            ];
+
+            let paragraph = Paragraph::new(lines).centered();
+
+            frame.render_widget(paragraph, webhook_layout[0]);
+            return;
+        }
+
+        let items: Vec<ListItem> = received_requests
+            .iter()
+            .map(|received_request| ListItem::from(received_request.to_summary()))
+            .collect();
+
+        let list = List::new(items)
+            .block(Block::new().borders(Borders::RIGHT))
+            .highlight_style(Style::default().fg(THEME.read().others.selection_highlight_color).add_modifier(Modifier::BOLD));
+
+        frame.render_stateful_widget(list, webhook_layout[0], &mut self.webhook_listener_popup.list_state);
+
+        let selected_request = match self.webhook_listener_popup.list_state.selected() {
+            None => return,
+            Some(selection) => match received_requests.get(selection) {
+                None => return,
+                Some(selected_request) => selected_request
+            }
+        };
+
+        let details_layout = Layout::new(
+            Vertical,
+            [
+                Constraint::Length(selected_request.headers.len() as u16 + 2),
+                Constraint::Fill(1)
+            ]
+        )
+            .horizontal_margin(1)
+            .split(webhook_layout[1]);
+
+        let mut header_lines = vec![
+            Line::from(format!("{} {}", selected_request.method, selected_request.path)).bold()
+        ];
+
+        for (header, value) in &selected_request.headers {
+            header_lines.push(Line::from(vec![
+                Span::raw(format!("{header}: ")).fg(THEME.read().ui.secondary_foreground_color),
+                Span::raw(value.clone())
+            ]));
+        }
+
+        let headers_paragraph = Paragraph::new(header_lines)
+            .block(Block::new().borders(Borders::BOTTOM));
+
+        frame.render_widget(headers_paragraph, details_layout[0]);
+
+        let body_paragraph = Paragraph::new(selected_request.body.clone())
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(body_paragraph, details_layout[1]);
+    }
+}
@@ -5,13 +5,15 @@ use ratatui::style::Stylize;
 use ratatui::widgets::{Block, Borders, Padding, Paragraph};
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
 use crate::models::request::Request;
 use crate::tui::ui::views::RequestView;
 use crate::tui::app_states::AppState::EditingRequestUrl;
+use crate::tui::ui::completions::render_env_key_completions;
 
 impl App<'_> {
-    pub(super) fn render_request(&mut self, frame: &mut Frame, rect: Rect, request: Request) {
+    pub(super) fn render_request(&mut self, frame: &mut Frame, rect: Rect, request: &Request) {
         let request_layout = Layout::new(
             Vertical,
             [
@@ -48,7 +50,7 @@ impl App<'_> {
         let method = request.method.clone();
 
         let method_block = Block::new()
-            .title("Method").title_alignment(Alignment::Center)
+            .title(tr("Method")).title_alignment(Alignment::Center)
             .borders(Borders::ALL)
             .padding(Padding::horizontal(1))
             .fg(THEME.read().ui.main_foreground_color);
@@ -66,7 +68,7 @@ impl App<'_> {
         // REQUEST URL
 
         let url_block = Block::new()
-            .title("URL")
+            .title(tr("URL"))
             .borders(Borders::ALL)
             .padding(Padding::horizontal(1))
             .fg(THEME.read().ui.main_foreground_color);
@@ -132,7 +134,7 @@ impl App<'_> {
             let request_params_area = params_block.inner(request_main_layout[0]);
 
             frame.render_widget(params_block, request_main_layout[0]);
-            self.render_request_params(frame, request_params_area, &request);
+            self.render_request_params(frame, request_params_area, request);
         }
 
         // REQUEST RESULT LAYOUT
@@ -142,7 +144,17 @@ impl App<'_> {
             let result_block_area = result_block.inner(request_main_layout[1]);
 
             frame.render_widget(result_block, request_main_layout[1]);
-            self.render_request_result(frame, result_block_area, &request);
+            self.render_request_result(frame, result_block_area, request);
+        }
+
+        // Over the params and the result
+        if self.state == EditingRequestUrl {
+            let url_cursor_position = Position::new(
+                request_header_layout[1].x + input_cursor_position as u16 + 2,
+                request_header_layout[1].y + 1
+            );
+
+            render_env_key_completions(frame, &self.env_key_completion_popup, url_cursor_position, rect);
         }
     }
 }
\ No newline at end of file
@@ -1,23 +1,30 @@
+use std::sync::atomic::Ordering;
+
 use ratatui::Frame;
 use ratatui::layout::{Constraint, Layout, Margin, Rect};
-use ratatui::layout::Direction::Vertical;
-use ratatui::prelude::Style;
+use ratatui::layout::Direction::{Horizontal, Vertical};
+use ratatui::prelude::{Color, Style};
 use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
-use ratatui::widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, Tabs};
+use ratatui::widgets::{Block, Borders, Paragraph, Scrollbar, ScrollbarOrientation, Tabs, Wrap};
 use ratatui_image::{Image, Resize};
 use ratatui_image::picker::Picker;
-use strum::{Display, EnumIter, FromRepr, IntoEnumIterator};
+use strum::Display;
 use throbber_widgets_tui::{BRAILLE_DOUBLE, Throbber, WhichUse};
 use rayon::prelude::*;
 
 use crate::app::app::App;
+use crate::app::files::locale::tr;
 use crate::app::files::theme::THEME;
+use crate::app::files::utils::format_size;
 use crate::models::request::Request;
-use crate::models::response::ResponseContent;
+use crate::models::response::{RequestResult, ResponseContent};
+use crate::models::result_tab::ResultTab;
 use crate::tui::utils::centered_rect::centered_rect;
+use crate::tui::utils::line_wrapping::{get_wrapped_rows_count, wrap_line};
+use crate::tui::utils::syntax_highlighting::BodyHighlightingLanguage;
 
-#[derive(Default, Clone, Copy, Display, FromRepr, EnumIter)]
+#[derive(Default, Clone, Copy, PartialEq, Display)]
 pub enum RequestResultTabs {
     #[default]
     #[strum(to_string = "Result body")]
@@ -26,8 +33,25 @@ pub enum RequestResultTabs {
     Cookies,
     #[strum(to_string = "Headers")]
     Headers,
+    #[strum(to_string = "Tests")]
+    Tests,
     #[strum(to_string = "Console")]
     Console,
+    #[strum(to_string = "Wire")]
+    Wire,
+}
+
+impl From<ResultTab> for RequestResultTabs {
+    fn from(result_tab: ResultTab) -> Self {
+        match result_tab {
+            ResultTab::Body => RequestResultTabs::Body,
+            ResultTab::Cookies => RequestResultTabs::Cookies,
+            ResultTab::Headers => RequestResultTabs::Headers,
+            ResultTab::Tests => RequestResultTabs::Tests,
+            ResultTab::Console => RequestResultTabs::Console,
+            ResultTab::Wire => RequestResultTabs::Wire
+        }
+    }
 }
 
 impl App<'_> {
@@ -45,36 +69,62 @@ impl App<'_> {
 
         // REQUEST RESULT TABS
 
-        let result_tabs = RequestResultTabs::iter()
-            .filter_map(|tab| {
+        let shown_result_tabs = self.get_result_tabs();
+
+        // The focused tab may have been hidden, e.g. the console of the previous response
+        if !shown_result_tabs.contains(&self.request_result_tab) {
+            self.request_result_tab = shown_result_tabs[0];
+        }
+
+        let selected_result_tab_index = shown_result_tabs
+            .iter()
+            .position(|tab| *tab == self.request_result_tab)
+            .unwrap_or(0);
+
+        let result_tabs = shown_result_tabs
+            .into_iter()
+            .map(|tab| {
+                let tab_name = tr(&tab.to_string());
+
                 let text = match tab {
                     RequestResultTabs::Body => {
+                        let body_language = match self.syntax_highlighting.body_language {
+                            BodyHighlightingLanguage::Auto => match self.syntax_highlighting.csv_table.read().as_ref() {
+                                Some(csv_table) if !csv_table.is_raw => String::from(" [Table]"),
+                                _ => String::new()
+                            },
+                            body_language => format!(" [{}]", body_language)
+                        };
+
                         if let Some(duration) = &request.response.duration {
-                            Some(format!("{} ({})", tab.to_string(), duration))
+                            format!("{} ({}){}", tab_name, duration, body_language)
                         }
                         else {
-                            Some(format!("{}", tab.to_string()))
+                            format!("{}{}", tab_name, body_language)
                         }
                     },
-                    RequestResultTabs::Cookies | RequestResultTabs::Headers => Some(tab.to_string()),
-                    RequestResultTabs::Console => {
-                        let local_console_output = self.script_console.console_output.read();
-
-                        match local_console_output.as_ref() {
-                            None => None,
-                            Some(_) => Some(tab.to_string())
+                    RequestResultTabs::Headers if !request.response.trailers.is_empty() => format!("{} (+{} trailers)", tab_name, request.response.trailers.len()),
+                    RequestResultTabs::Cookies | RequestResultTabs::Headers | RequestResultTabs::Console => tab_name,
+                    RequestResultTabs::Wire => match &request.response.wire {
+                        Some(wire) => format!("{} ({})", tab_name, wire.version),
+                        None => tab_name
+                    },
+                    RequestResultTabs::Tests => {
+                        let tests = &request.response.tests;
+
+                        match tests.is_empty() {
+                            true => tab_name,
+                            false => {
+                                let passed_tests = tests.iter().filter(|test| test.passed).count();
+                                format!("{} ({}/{})", tab_name, passed_tests, tests.len())
+                            }
                         }
                     }
                 };
 
-                match text {
-                    Some(text) => Some(text.fg(THEME.read().ui.font_color)),
-                    None => None
-                }
+                text.fg(THEME.read().ui.font_color)
             });
 
-        let selected_result_tab_index = self.request_result_tab as usize;
-
         let result_tabs = Tabs::new(result_tabs)
             .highlight_style(THEME.read().others.selection_highlight_color)
             .select(selected_result_tab_index)
@@ -85,8 +135,31 @@ impl App<'_> {
 
         frame.render_widget(result_tabs, request_result_layout[0]);
 
+        let streamed_lines = request.response_stream.lines.read();
+
+        // Newline-delimited JSON response still being received
+        if request.is_pending && !streamed_lines.is_empty() {
+            let receiving_paragraph = Paragraph::new(format!("Receiving... {} records", streamed_lines.len()))
+                .centered()
+                .fg(THEME.read().ui.secondary_foreground_color);
+
+            frame.render_widget(receiving_paragraph, request_result_layout[1]);
+
+            // Follows the last received lines
+            let displayed_lines_count = request_result_layout[2].height as usize;
+
+            let lines: Vec<Line> = streamed_lines
+                .iter()
+                .skip(streamed_lines.len().saturating_sub(displayed_lines_count))
+                .map(|line| Line::raw(line.as_str()))
+                .collect();
+
+            let stream_paragraph = Paragraph::new(lines).fg(THEME.read().ui.font_color);
+
+            frame.render_widget(stream_paragraph, request_result_layout[2]);
+        }
         // If the selected request is currently pending
-        if request.is_pending {
+        else if request.is_pending {
             let area = centered_rect(9, 1, request_result_layout[2]);
 
             self.result_throbber_state.calc_next();
@@ -98,17 +171,81 @@ impl App<'_> {
                 .use_type(WhichUse::Spin);
 
             frame.render_stateful_widget(throbber, area, &mut self.result_throbber_state);
+
+            // File body still being uploaded
+            if let Some((sent, total)) = request.upload_progress.get() {
+                let upload_progress_paragraph = Paragraph::new(format!("Uploading {}% ({} / {})", sent * 100 / total.max(1), format_size(sent), format_size(total)))
+                    .centered()
+                    .fg(THEME.read().ui.secondary_foreground_color);
+
+                frame.render_widget(upload_progress_paragraph, request_result_layout[1]);
+            }
         }
         // If the selected request is not pending
         else {
             // REQUEST RESULT STATUS CODE
 
-            let status_code = match &request.response.status_code {
-                None => "",
-                Some(status_code) => status_code
+            let mut status_line = match &request.response.result {
+                None => vec![],
+                Some(result @ RequestResult::Success { .. }) => vec![Span::raw(result.to_string())],
+                Some(result) => vec![Span::raw(result.to_string()).fg(Color::Red)]
             };
 
-            let status_code_paragraph = Paragraph::new(status_code)
+            // Charset the text body was decoded with
+            if let Some(charset) = &request.response.charset {
+                status_line.push(Span::raw(format!(" | {charset}")));
+            }
+
+            if let Some(cache_status) = &request.response.cache_status {
+                status_line.push(Span::raw(format!(" | {cache_status}")));
+            }
+
+            if let Some(dns_resolution) = &request.response.dns_resolution {
+                status_line.push(Span::raw(format!(" | {dns_resolution}")));
+            }
+
+            if let Some(processed_body) = &request.response.processed_body {
+                match processed_body.is_raw {
+                    true => status_line.push(Span::raw(" | Raw body").fg(Color::Yellow)),
+                    false => status_line.push(Span::raw(" | Processed body"))
+                }
+            }
+
+            if let Some(soap_fault) = &request.response.soap_fault {
+                status_line.push(Span::raw(" | "));
+                status_line.push(Span::raw(format!("SOAP fault {}", soap_fault.code)).fg(Color::Red));
+            }
+
+            if request.response.is_offline {
+                if !status_line.is_empty() {
+                    status_line.push(Span::raw(" | "));
+                }
+
+                status_line.push(Span::raw("Offline (snapshot)").fg(Color::Yellow));
+            }
+
+            if let Some(monitor) = &request.monitor {
+                if !status_line.is_empty() {
+                    status_line.push(Span::raw(" | "));
+                }
+
+                let monitor_summary = Span::raw(format!("Monitor {}", monitor.to_summary()));
+
+                match monitor.has_last_check_failed() {
+                    true => status_line.push(monitor_summary.fg(Color::Red)),
+                    false => status_line.push(monitor_summary)
+                }
+            }
+
+            if let Some(watch) = &request.watch {
+                if !status_line.is_empty() {
+                    status_line.push(Span::raw(" | "));
+                }
+
+                status_line.push(Span::raw(watch.to_summary(request.settings.watch_interval)).fg(Color::Cyan));
+            }
+
+            let status_code_paragraph = Paragraph::new(Line::from(status_line))
                 .centered()
                 .fg(THEME.read().ui.secondary_foreground_color);
             frame.render_widget(status_code_paragraph, request_result_layout[1]);
@@ -116,29 +253,250 @@ impl App<'_> {
 
             // REQUEST RESULT CONTENT
 
+            // Paragraphs scroll up to u16::MAX, the bodies only render their displayed lines instead
+            let first_line = self.result_vertical_scrollbar.scroll;
+            let displayed_lines_count = request_result_layout[2].height as usize;
+            let vertical_scroll = u16::try_from(first_line).unwrap_or(u16::MAX);
+            let horizontal_scroll = u16::try_from(self.result_horizontal_scrollbar.scroll).unwrap_or(u16::MAX);
+
+            // Lines of the text body, they can be numbered in a gutter
+            let body_lines_count = match (self.request_result_tab, &request.response.content) {
+                (RequestResultTabs::Body, Some(ResponseContent::Body(body))) if !self.is_result_body_structured() => Some(body.lines().count()),
+                _ => None
+            };
+
+            let line_numbers_width = match body_lines_count {
+                Some(lines_count) if self.should_display_line_numbers => lines_count.max(1).to_string().len() + 1,
+                _ => 0
+            };
+
+            // The last column is taken by the vertical scrollbar
+            let wrap_width = request_result_layout[2].width.saturating_sub(1 + line_numbers_width as u16) as usize;
+            self.result_wrap_width = wrap_width;
+
+            // First displayed line and lines count of the body
+            let mut body_position: Option<(usize, usize)> = None;
+
             match self.request_result_tab {
                 RequestResultTabs::Body => match &request.response.content {
                     None => {},
                     Some(content) => match content {
-                        ResponseContent::Body(body) => {
-                            let lines: Vec<Line>;
-                            let last_highlighted = self.syntax_highlighting.highlighted_body.read();
+                        // No response was received, the error is detailed instead of the body
+                        ResponseContent::Body(_) if request.response.get_transport_error().is_some() => {
+                            let error = request.response.get_transport_error().unwrap();
 
-                            if !self.config.is_syntax_highlighting_disabled() && last_highlighted.is_some() {
-                                lines = last_highlighted.clone().unwrap();
+                            let mut lines = vec![
+                                Line::from(error.kind.to_string()).bold().fg(Color::Red),
+                            ];
+
+                            if let Some(url) = &error.url {
+                                lines.push(Line::from(url.clone()).fg(THEME.read().ui.font_color));
                             }
-                            else {
-                                lines = body.lines().par_bridge().map(|line| Line::raw(line)).collect();
+
+                            lines.push(Line::default());
+                            lines.push(Line::from(tr("Caused by")).fg(THEME.read().ui.secondary_foreground_color));
+
+                            for (index, cause) in error.chain.iter().enumerate() {
+                                lines.push(Line::from(format!("  {index}: {cause}")).fg(THEME.read().ui.font_color));
                             }
 
-                            let body_paragraph = Paragraph::new(lines)
-                                .scroll((
-                                    self.result_vertical_scrollbar.scroll,
-                                    self.result_horizontal_scrollbar.scroll
-                                ));
+                            lines.push(Line::default());
+                            lines.push(Line::from(error.get_hint()).fg(THEME.read().ui.secondary_foreground_color));
+
+                            let error_paragraph = Paragraph::new(lines)
+                                .wrap(Wrap { trim: false })
+                                .scroll((vertical_scroll, 0));
+
+                            frame.render_widget(error_paragraph, request_result_layout[2]);
+                        }
+                        // A 304 has no body, the reason is given instead
+                        ResponseContent::Body(body) if body.is_empty() && request.response.is_not_modified() => {
+                            let explanation: Vec<Line> = request.cache_validators
+                                .explain_not_modified()
+                                .into_iter()
+                                .map(Line::raw)
+                                .collect();
+
+                            let explanation_paragraph = Paragraph::new(explanation)
+                                .fg(THEME.read().ui.secondary_foreground_color)
+                                .scroll((vertical_scroll, horizontal_scroll));
+
+                            frame.render_widget(explanation_paragraph, request_result_layout[2]);
+                        }
+                        ResponseContent::Body(_) if self.syntax_highlighting.csv_table.read().as_ref().is_some_and(|csv_table| !csv_table.is_raw) => {
+                            let csv_table = self.syntax_highlighting.csv_table.read();
+                            let csv_table = csv_table.as_ref().unwrap();
+
+                            let csv_table_layout = Layout::new(
+                                Vertical,
+                                [
+                                    Constraint::Length(2),
+                                    Constraint::Fill(1)
+                                ]
+                            )
+                                .split(request_result_layout[2]);
+
+                            // The column headers only scroll horizontally
+                            let header_paragraph = Paragraph::new(csv_table.header())
+                                .fg(THEME.read().ui.secondary_foreground_color)
+                                .scroll((0, horizontal_scroll));
+
+                            body_position = Some((first_line, csv_table.rows_count()));
+
+                            let rows_paragraph = Paragraph::new(csv_table.rows(first_line, csv_table_layout[1].height as usize))
+                                .fg(THEME.read().ui.font_color)
+                                .scroll((0, horizontal_scroll));
+
+                            frame.render_widget(header_paragraph, csv_table_layout[0]);
+                            frame.render_widget(rows_paragraph, csv_table_layout[1]);
+                        }
+                        ResponseContent::Body(_) if self.syntax_highlighting.ndjson_records.read().is_some() => {
+                            let ndjson_records = self.syntax_highlighting.ndjson_records.read();
+                            let ndjson_records = ndjson_records.as_ref().unwrap();
+
+                            body_position = Some((first_line, ndjson_records.lines_count()));
+
+                            let body_paragraph = Paragraph::new(ndjson_records.get_lines(first_line, displayed_lines_count))
+                                .scroll((0, horizontal_scroll));
 
                             frame.render_widget(body_paragraph, request_result_layout[2]);
                         }
+                        ResponseContent::Body(body) => {
+                            let body_highlighter = &self.syntax_highlighting.body_highlighter;
+                            let highlighted_body = body_highlighter.highlighted_body.read();
+
+                            // The lines of a SOAP fault stand out, they were found in the processed body
+                            let soap_fault_lines = match &request.response.processed_body {
+                                Some(processed_body) if processed_body.is_raw => None,
+                                _ => request.response.soap_fault.as_ref().map(|soap_fault| soap_fault.lines.clone())
+                            };
+
+                            let get_displayed_line = |index: usize, line: &str| -> Line<'static> {
+                                if soap_fault_lines.as_ref().is_some_and(|soap_fault_lines| soap_fault_lines.contains(&index)) {
+                                    return Line::raw(line.to_string()).fg(Color::White).bg(Color::Red);
+                                }
+
+                                match highlighted_body.as_ref().and_then(|highlighted_body| highlighted_body.get_line(index)) {
+                                    Some(highlighted_line) => highlighted_line.clone(),
+                                    None => Line::raw(line.to_string())
+                                }
+                            };
+
+                            let [line_numbers_area, body_area] = Layout::new(
+                                Horizontal,
+                                [
+                                    Constraint::Length(line_numbers_width as u16),
+                                    Constraint::Fill(1)
+                                ]
+                            )
+                                .areas(request_result_layout[2]);
+
+                            let get_line_number = |index: usize| -> Line<'static> {
+                                Line::raw(format!("{:>width$} ", index + 1, width = line_numbers_width.saturating_sub(1)))
+                            };
+
+                            let mut line_numbers: Vec<Line> = vec![];
+                            let mut first_displayed_line = first_line;
+
+                            // Only the displayed lines are rendered, the others are not highlighted yet
+                            let lines: Vec<Line> = match self.should_wrap_lines {
+                                true => {
+                                    let mut rows: Vec<Line> = vec![];
+                                    // The vertical scroll counts the rows of the wrapped lines
+                                    let mut rows_to_skip = first_line;
+
+                                    for (index, line) in body.lines().enumerate() {
+                                        if rows.len() >= displayed_lines_count {
+                                            break;
+                                        }
+
+                                        let line_rows_count = get_wrapped_rows_count(Span::raw(line).width(), wrap_width);
+
+                                        if rows_to_skip >= line_rows_count {
+                                            rows_to_skip -= line_rows_count;
+                                            continue;
+                                        }
+
+                                        // The displayed lines are highlighted first
+                                        if rows.is_empty() {
+                                            body_highlighter.viewport_start.store(index, Ordering::Relaxed);
+                                            first_displayed_line = index;
+                                        }
+
+                                        let max_rows = rows_to_skip + displayed_lines_count - rows.len();
+                                        let line_rows = wrap_line(&get_displayed_line(index, line), wrap_width, max_rows);
+
+                                        // Only the first row of a line is numbered
+                                        for (row_index, row) in line_rows.into_iter().enumerate().skip(rows_to_skip) {
+                                            line_numbers.push(match row_index {
+                                                0 => get_line_number(index),
+                                                _ => Line::default()
+                                            });
+
+                                            rows.push(row);
+                                        }
+
+                                        rows_to_skip = 0;
+                                    }
+
+                                    rows
+                                },
+                                false => {
+                                    // The displayed lines are highlighted first
+                                    body_highlighter.viewport_start.store(first_line, Ordering::Relaxed);
+
+                                    let lines: Vec<Line> = body
+                                        .lines()
+                                        .enumerate()
+                                        .skip(first_line)
+                                        .take(displayed_lines_count)
+                                        .map(|(index, line)| get_displayed_line(index, line))
+                                        .collect();
+
+                                    line_numbers = (first_line..first_line + lines.len()).map(get_line_number).collect();
+
+                                    lines
+                                }
+                            };
+
+                            body_position = body_lines_count.map(|lines_count| (first_displayed_line, lines_count));
+
+                            let body_paragraph = match self.should_wrap_lines {
+                                true => Paragraph::new(lines),
+                                false => Paragraph::new(lines).scroll((0, horizontal_scroll))
+                            };
+
+                            if line_numbers_width > 0 {
+                                let line_numbers_paragraph = Paragraph::new(line_numbers).fg(THEME.read().ui.secondary_foreground_color);
+
+                                frame.render_widget(line_numbers_paragraph, line_numbers_area);
+                            }
+
+                            frame.render_widget(body_paragraph, body_area);
+
+                            if let Some(highlighted_body) = highlighted_body.as_ref().filter(|highlighted_body| !highlighted_body.is_complete()) {
+                                let label = format!("Highlighting {}%", highlighted_body.get_progress());
+                                let throbber_width = label.len() as u16 + 2;
+
+                                // Leaves the column of the vertical scrollbar free
+                                let throbber_area = Rect {
+                                    x: request_result_layout[1].right().saturating_sub(throbber_width + 1),
+                                    y: request_result_layout[1].y,
+                                    width: throbber_width.min(request_result_layout[1].width),
+                                    height: 1,
+                                };
+
+                                self.result_throbber_state.calc_next();
+
+                                let throbber = Throbber::default()
+                                    .label(label)
+                                    .style(Style::new().fg(THEME.read().ui.secondary_foreground_color))
+                                    .throbber_set(BRAILLE_DOUBLE)
+                                    .use_type(WhichUse::Spin);
+
+                                frame.render_stateful_widget(throbber, throbber_area, &mut self.result_throbber_state);
+                            }
+                        }
                         ResponseContent::Image(image_response) => match &image_response.image {
                             _ if self.config.is_image_preview_disabled() => {
                                 let image_disabled_paragraph = Paragraph::new("\nImage preview disabled").centered();
@@ -170,17 +528,15 @@ impl App<'_> {
                         Some(cookies) => cookies
                     };
 
-                    let cookies_paragraph = Paragraph::new(result_cookies)
-                        .fg(THEME.read().ui.font_color)
-                        .scroll((
-                            self.result_vertical_scrollbar.scroll,
-                            self.result_horizontal_scrollbar.scroll
-                        ));
+                    let cookies_lines = result_cookies.lines().map(Line::raw).collect();
+
+                    let cookies_paragraph = self.get_result_paragraph(cookies_lines, vertical_scroll, horizontal_scroll)
+                        .fg(THEME.read().ui.font_color);
 
                     frame.render_widget(cookies_paragraph, request_result_layout[2]);
                 }
                 RequestResultTabs::Headers => {
-                    let result_headers: Vec<Line> = request.response.headers
+                    let mut result_headers: Vec<Line> = request.response.headers
                         .par_iter()
                         .map(
                             |(header, value)| 
@@ -192,26 +548,105 @@ impl App<'_> {
                         )
                         .collect();
 
-                    let headers_paragraph = Paragraph::new(result_headers)
-                        .scroll((
-                            self.result_vertical_scrollbar.scroll,
-                            self.result_horizontal_scrollbar.scroll
-                        ));
+                    if !request.response.trailers.is_empty() {
+                        result_headers.push(Line::default());
+                        result_headers.push(Line::raw("Trailers").bold().fg(THEME.read().ui.font_color));
+
+                        for (trailer, value) in &request.response.trailers {
+                            result_headers.push(Line::from(vec![
+                                Span::raw(trailer).bold().fg(THEME.read().ui.secondary_foreground_color),
+                                Span::raw(": ").fg(THEME.read().ui.secondary_foreground_color),
+                                Span::raw(value).fg(THEME.read().ui.font_color)
+                            ]));
+                        }
+                    }
+
+                    let headers_paragraph = self.get_result_paragraph(result_headers, vertical_scroll, horizontal_scroll);
 
                     frame.render_widget(headers_paragraph, request_result_layout[2]);
                 },
+                RequestResultTabs::Tests => {
+                    let mut tests_lines: Vec<Line> = vec![];
+
+                    let text_markers = THEME.read().accessibility.text_markers;
+
+                    for test in &request.response.tests {
+                        let test_marker = match (test.passed, text_markers) {
+                            (true, false) => Span::raw("✓ ").fg(Color::Green),
+                            (true, true) => Span::raw("[pass] ").fg(Color::Green),
+                            (false, false) => Span::raw("✗ ").fg(Color::Red),
+                            (false, true) => Span::raw("[fail] ").fg(Color::Red)
+                        };
+
+                        let test_line = Line::from(vec![
+                            test_marker,
+                            Span::raw(test.name.clone()).fg(THEME.read().ui.font_color)
+                        ]);
+
+                        tests_lines.push(test_line);
+
+                        for message in &test.messages {
+                            tests_lines.push(Line::raw(format!("    {message}")).fg(THEME.read().ui.secondary_foreground_color));
+                        }
+                    }
+
+                    let tests_paragraph = self.get_result_paragraph(tests_lines, vertical_scroll, horizontal_scroll);
+
+                    frame.render_widget(tests_paragraph, request_result_layout[2]);
+                },
                 RequestResultTabs::Console => {
                     let highlighted_console_output = self.syntax_highlighting.highlighted_console_output.read().clone();
                     
-                    let console_paragraph = Paragraph::new(highlighted_console_output)
-                        .scroll((
-                            self.result_vertical_scrollbar.scroll,
-                            self.result_horizontal_scrollbar.scroll
-                        ));
+                    let console_paragraph = self.get_result_paragraph(highlighted_console_output, vertical_scroll, horizontal_scroll);
 
                     frame.render_widget(console_paragraph, request_result_layout[2]);
                 }
+                RequestResultTabs::Wire => {
+                    let wire_lines: Vec<Line> = match &request.response.wire {
+                        None => vec![Line::raw("No exchange received yet")],
+                        Some(wire) => wire.to_lines().into_iter().map(Line::raw).collect()
+                    };
+
+                    let wire_paragraph = self.get_result_paragraph(wire_lines, vertical_scroll, horizontal_scroll)
+                        .fg(THEME.read().ui.font_color);
+
+                    frame.render_widget(wire_paragraph, request_result_layout[2]);
+                }
+            };
+
+            let is_structured_body = matches!(self.request_result_tab, RequestResultTabs::Body) && self.is_result_body_structured();
+
+            // Horizontal position, when the lines are wider than the result
+            let horizontal_position = match self.should_wrap_lines && !is_structured_body {
+                true => Some(String::from("Wrapped")),
+                false => {
+                    let max_width = self.result_horizontal_scrollbar.max_scroll + 1;
+
+                    match max_width > wrap_width {
+                        true => Some(format!("Col {}/{}", self.result_horizontal_scrollbar.scroll + 1, max_width)),
+                        false => None
+                    }
+                }
             };
+
+            // Line at the top of the body, e.g. to find the line of an error
+            let vertical_position = body_position.map(|(first_displayed_line, lines_count)| {
+                let lines_count = lines_count.max(1);
+
+                format!("Ln {}/{}", (first_displayed_line + 1).min(lines_count), lines_count)
+            });
+
+            let position = [vertical_position, horizontal_position]
+                .into_iter()
+                .flatten()
+                .collect::<Vec<String>>()
+                .join(", ");
+
+            if !position.is_empty() {
+                let position_paragraph = Paragraph::new(position).fg(THEME.read().ui.secondary_foreground_color);
+
+                frame.render_widget(position_paragraph, request_result_layout[1]);
+            }
         }
 
         let result_vertical_scrollbar = Scrollbar::new(ScrollbarOrientation::VerticalRight)
@@ -240,4 +675,19 @@ impl App<'_> {
             &mut self.result_horizontal_scrollbar.state
         );
     }
+
+    /// Long lines are wrapped at the width of the result, or scrolled horizontally
+    fn get_result_paragraph<'b>(&self, lines: Vec<Line<'b>>, vertical_scroll: u16, horizontal_scroll: u16) -> Paragraph<'b> {
+        return match self.should_wrap_lines {
+            true => {
+                let rows: Vec<Line> = lines
+                    .iter()
+                    .flat_map(|line| wrap_line(line, self.result_wrap_width, usize::MAX))
+                    .collect();
+
+                Paragraph::new(rows).scroll((vertical_scroll, 0))
+            },
+            false => Paragraph::new(lines).scroll((vertical_scroll, horizontal_scroll))
+        };
+    }
 }
@@ -1,17 +1,22 @@
+use std::sync::atomic::Ordering;
+
 use ratatui::backend::Backend;
 use ratatui::{Frame, Terminal};
 use ratatui::layout::{Alignment, Constraint, Layout};
 use ratatui::layout::Direction::{Horizontal, Vertical};
 use ratatui::prelude::{Modifier};
-use ratatui::style::{Stylize};
-use ratatui::text::Line;
+use ratatui::style::{Color, Stylize};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders};
 use ratatui::widgets::block::Title;
 
 use crate::app::app::{App};
+use crate::app::files::config::StatusBarHints;
 use crate::app::files::theme::THEME;
+use crate::app::startup::log_layer::APP_LOG;
 use crate::tui::app_states::AppState::*;
 use crate::tui::app_states::{AVAILABLE_EVENTS, event_available_keys_to_spans};
+use crate::tui::ui::accessibility::apply_accessibility;
 
 
 impl App<'_> {
@@ -39,8 +44,13 @@ impl App<'_> {
 
         // HEADER
 
+        let header_title = match &self.selected_workspace {
+            None => String::from("* ATAC *"),
+            Some(workspace_name) => format!("* ATAC [{workspace_name}] *")
+        };
+
         let header = Block::new()
-            .title("* ATAC *")
+            .title(header_title)
             .add_modifier(Modifier::BOLD)
             .add_modifier(Modifier::ITALIC)
             .title_alignment(Alignment::Center)
@@ -102,9 +112,12 @@ impl App<'_> {
         match self.collections_tree.selected {
             None => self.render_homepage(frame, inner_layout[1]),
             Some(selection) => {
-                let selected_request = self.get_request_as_local_from_indexes(&selection).read().clone();
+                let local_selected_request = self.get_request_as_local_from_indexes(&selection);
+
+                // Rendered from the lock, the response body is not copied at each frame
+                let selected_request = local_selected_request.read();
 
-                self.render_request(frame, inner_layout[1], selected_request);
+                self.render_request(frame, inner_layout[1], &selected_request);
             }
         }
 
@@ -112,37 +125,152 @@ impl App<'_> {
 
         let state_line = self.get_state_line();
         let events = &*AVAILABLE_EVENTS.read();
-        let available_keys = Line::from(event_available_keys_to_spans(
-            events,
-            THEME.read().ui.secondary_foreground_color,
-            THEME.read().ui.secondary_background_color,
-            true
-        ).concat());
 
-        let footer = Block::new()
-            .title(Title::from(state_line).alignment(Alignment::Left))
-            .title(Title::from(available_keys).alignment(Alignment::Right));
+        let mut footer = Block::new();
+        // Titles on the left, each one followed by a space
+        let mut left_titles_width = state_line.width() + 1;
+
+        if let Some(environment_indicator) = self.get_environment_indicator() {
+            left_titles_width += environment_indicator.width() + 1;
+            footer = footer.title(Title::from(environment_indicator).alignment(Alignment::Left));
+        }
+
+        if self.is_offline() {
+            let offline_indicator = Span::raw(" OFFLINE ").fg(Color::Black).bg(Color::Yellow).bold();
+
+            left_titles_width += offline_indicator.width() + 1;
+            footer = footer.title(Title::from(offline_indicator).alignment(Alignment::Left));
+        }
+
+        let unseen_errors = APP_LOG.read().unseen_errors;
+
+        if unseen_errors > 0 {
+            let errors_indicator = Span::raw(format!(" {unseen_errors} error(s) ")).fg(Color::White).bg(Color::Red).bold();
+
+            left_titles_width += errors_indicator.width() + 1;
+            footer = footer.title(Title::from(errors_indicator).alignment(Alignment::Left));
+        }
+
+        if let Some(recording) = &self.macro_popup.recording {
+            let recording_indicator = Span::raw(format!(" REC {} ", recording.len())).fg(Color::White).bg(Color::Red).bold();
+
+            left_titles_width += recording_indicator.width() + 1;
+            footer = footer.title(Title::from(recording_indicator).alignment(Alignment::Left));
+        }
+
+        if let Some(pending_requests_indicator) = self.get_pending_requests_indicator() {
+            left_titles_width += pending_requests_indicator.width() + 1;
+            footer = footer.title(Title::from(pending_requests_indicator).alignment(Alignment::Left));
+        }
+
+        footer = footer.title(Title::from(state_line).alignment(Alignment::Left));
+
+        let status_bar_hints = self.config.get_status_bar_hints();
+
+        if status_bar_hints != StatusBarHints::Hidden {
+            let key_hints = event_available_keys_to_spans(
+                events,
+                THEME.read().ui.secondary_foreground_color,
+                THEME.read().ui.secondary_background_color,
+                status_bar_hints == StatusBarHints::Short
+            );
+
+            // The hints that do not fit are dropped rather than hiding the state
+            let max_hints_width = (main_layout[2].width as usize).saturating_sub(left_titles_width);
+            let mut available_keys: Vec<Span> = vec![];
+            let mut hints_width = 0;
+
+            for key_hint in key_hints {
+                let key_hint_width: usize = key_hint.iter().map(|span| span.width()).sum();
+
+                if hints_width + key_hint_width > max_hints_width {
+                    break;
+                }
+
+                hints_width += key_hint_width;
+                available_keys.extend(key_hint);
+            }
+
+            footer = footer.title(Title::from(Line::from(available_keys)).alignment(Alignment::Right));
+        }
 
         frame.render_widget(footer, main_layout[2]);
 
         // POPUPS
 
         match self.state {
-            DisplayingCookies | EditingCookies => self.render_cookies_popup(frame),
+            DisplayingCookies | EditingCookies | CreatingCookie => self.render_cookies_popup(frame),
+            DisplayingWebhookListener => self.render_webhook_listener_popup(frame),
+            DisplayingGitStatus => self.render_git_popup(frame),
+            DisplayingScratchpad | WritingScratchpadNote => self.render_scratchpad_popup(frame),
+            DisplayingResponseCache => self.render_response_cache_popup(frame),
+            DisplayingLogs => self.render_logs_popup(frame),
+            DisplayingScriptingConsole => self.render_scripting_console_popup(frame),
+            DisplayingError => self.render_error_popup(frame),
+            ReplayingMacro => self.render_macro_popup(frame),
+            ChoosingEnvironment => self.render_environment_popup(frame),
+            DisplayingVariableUsages => self.render_variable_usages_popup(frame),
+            LoadTestingRequest => self.render_load_test_popup(frame),
             ChoosingElementToCreate => self.render_creating_element_popup(frame),
             CreatingNewCollection => self.render_creating_new_collection_popup(frame),
             CreatingNewRequest => self.render_creating_new_request_popup(frame),
             DeletingCollection => self.render_deleting_collection_popup(frame),
             DeletingRequest => self.render_deleting_request_popup(frame),
+            RecoveringUnsavedEdit => self.render_recovering_unsaved_edit_popup(frame),
+            ReloadingChangedCollection => self.render_reloading_changed_collection_popup(frame),
             EditingRequestSettings => self.render_request_settings_popup(frame),
+            ChoosingRequestUserAgent => self.render_user_agent_popup(frame),
+            PreviewingRequest => self.render_request_preview_popup(frame),
+            DisplayingJwt | EditingJwtInput => self.render_jwt_popup(frame),
+            UsingEncodingTools | EditingEncodingToolsInput => self.render_encoding_tools_popup(frame),
             RenamingCollection => self.render_renaming_collection_popup(frame),
             RenamingRequest => self.render_renaming_request_popup(frame),
+            EditingElementTags => self.render_editing_tags_popup(frame),
+            FilteringCollectionsByTag => self.render_filtering_by_tag_popup(frame),
+            GoingToLine => self.render_go_to_line_popup(frame),
+            ChoosingResponseLink => self.render_response_links_popup(frame),
             _ => {}
         }
 
         if self.should_display_help {
             self.render_help_popup(frame);   
         }
+
+        if self.should_display_cheatsheet {
+            self.render_cheatsheet_popup(frame);
+        }
+
+        // ACCESSIBILITY
+
+        apply_accessibility(frame.buffer_mut());
+    }
+
+    /// Requests of every collection waiting for their response and queued sends, None when there is none
+    fn get_pending_requests_indicator(&self) -> Option<Span<'static>> {
+        if !self.config.should_show_pending_requests() {
+            return None;
+        }
+
+        let mut pending_requests_count = 0;
+        let mut queued_sends_count = 0;
+
+        for request in self.collections.iter().flat_map(|collection| &collection.requests) {
+            let request = request.read();
+
+            if request.is_pending {
+                pending_requests_count += 1;
+            }
+
+            queued_sends_count += request.queued_sends.load(Ordering::Relaxed);
+        }
+
+        let indicator = match (pending_requests_count, queued_sends_count) {
+            (0, 0) => return None,
+            (pending_count, 0) => format!(" {pending_count} pending "),
+            (pending_count, queued_count) => format!(" {pending_count} pending, {queued_count} queued ")
+        };
+
+        return Some(Span::raw(indicator).fg(THEME.read().ui.secondary_foreground_color).bg(THEME.read().ui.secondary_background_color));
     }
 
     pub fn draw(&mut self, terminal: &mut Terminal<impl Backend>) -> std::io::Result<()> {
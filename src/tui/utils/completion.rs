@@ -0,0 +1,50 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+
+lazy_static! {
+    /// "{{" not closed yet, followed by the start of a key
+    static ref ENV_KEY_START_REGEX: Regex = Regex::new(r"\{\{(\w*)$").unwrap();
+}
+
+/// Candidates starting with the input, ignoring the case, in the order given and without duplicates.
+/// Nothing is left to complete once the input is the only completion
+pub fn get_completions<'a>(input: &str, candidates: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let lowercase_input = input.to_lowercase();
+    let mut completions: Vec<String> = vec![];
+
+    for candidate in candidates {
+        let is_duplicate = completions.iter().any(|completion| completion.eq_ignore_ascii_case(candidate));
+
+        if !is_duplicate && candidate.to_lowercase().starts_with(&lowercase_input) {
+            completions.push(candidate.to_string());
+        }
+    }
+
+    return match completions.len() == 1 && completions[0] == input {
+        true => vec![],
+        false => completions
+    };
+}
+
+/// Start shared by every completion, the input can be completed up to it without choosing one
+pub fn get_common_prefix(completions: &[String]) -> String {
+    let mut common_prefix = match completions.first() {
+        Some(completion) => completion.clone(),
+        None => return String::new()
+    };
+
+    for completion in completions.iter().skip(1) {
+        while !completion.starts_with(&common_prefix) {
+            common_prefix.pop();
+        }
+    }
+
+    return common_prefix;
+}
+
+/// Start of the environment key typed right before the cursor, e.g. "BASE" in "http://{{BASE"
+pub fn get_env_key_start(text_before_cursor: &str) -> Option<&str> {
+    return ENV_KEY_START_REGEX
+        .captures(text_before_cursor)
+        .map(|captures| captures.get(1).unwrap().as_str());
+}
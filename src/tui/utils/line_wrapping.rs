@@ -0,0 +1,44 @@
+use ratatui::style::Style;
+use ratatui::text::{Line, Span};
+
+/// Rows taken by a line once wrapped, an empty line still takes one
+pub fn get_wrapped_rows_count(line_width: usize, wrap_width: usize) -> usize {
+    return line_width.div_ceil(wrap_width.max(1)).max(1);
+}
+
+/// Split a line in rows of at most the given width, keeping the style of its spans.
+/// Stops after max_rows, only the displayed part of a very long line is wrapped
+pub fn wrap_line(line: &Line, wrap_width: usize, max_rows: usize) -> Vec<Line<'static>> {
+    let wrap_width = wrap_width.max(1);
+
+    let mut rows: Vec<Line> = vec![];
+    let mut row_spans: Vec<Span> = vec![];
+    let mut row_width = 0;
+
+    for grapheme in line.styled_graphemes(Style::default()) {
+        let grapheme_width = Span::raw(grapheme.symbol).width();
+
+        if row_width + grapheme_width > wrap_width && row_width > 0 {
+            rows.push(Line::from(std::mem::take(&mut row_spans)));
+            row_width = 0;
+
+            if rows.len() >= max_rows {
+                return rows;
+            }
+        }
+
+        // Consecutive graphemes of the same style share a span
+        match row_spans.last_mut() {
+            Some(span) if span.style == grapheme.style => span.content.to_mut().push_str(grapheme.symbol),
+            _ => row_spans.push(Span::styled(grapheme.symbol.to_string(), grapheme.style))
+        }
+
+        row_width += grapheme_width;
+    }
+
+    if (!row_spans.is_empty() || rows.is_empty()) && rows.len() < max_rows {
+        rows.push(Line::from(row_spans));
+    }
+
+    return rows;
+}
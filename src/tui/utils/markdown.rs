@@ -0,0 +1,205 @@
+use pulldown_cmark::{Event, Options, Parser, Tag, TagEnd};
+use ratatui::style::{Modifier, Style, Stylize};
+use ratatui::text::{Line, Span};
+
+use crate::app::files::theme::THEME;
+
+/// Render Markdown as styled lines. Tables and images are displayed as plain text
+pub fn markdown_to_lines(markdown: &str) -> Vec<Line<'static>> {
+    let mut renderer = MarkdownRenderer::default();
+
+    let options = Options::ENABLE_STRIKETHROUGH | Options::ENABLE_TASKLISTS;
+
+    for event in Parser::new_ext(markdown, options) {
+        renderer.handle_event(event);
+    }
+
+    renderer.flush_line();
+
+    while let Some(line) = renderer.lines.last() {
+        match line.spans.is_empty() {
+            true => renderer.lines.pop(),
+            false => break
+        };
+    }
+
+    return renderer.lines;
+}
+
+#[derive(Default)]
+struct MarkdownRenderer {
+    lines: Vec<Line<'static>>,
+    current_line: Vec<Span<'static>>,
+    /// Nested inline styles (emphasis, strong, headings, links)
+    styles: Vec<Style>,
+    /// Next item number of each nested list, None for bullet lists
+    list_indices: Vec<Option<u64>>,
+    block_quote_depth: usize,
+    in_code_block: bool,
+    link_url: Option<String>,
+}
+
+impl MarkdownRenderer {
+    fn handle_event(&mut self, event: Event) {
+        match event {
+            Event::Start(tag) => self.start_tag(tag),
+            Event::End(tag) => self.end_tag(tag),
+            Event::Text(text) => match self.in_code_block {
+                true => {
+                    for line in text.lines() {
+                        self.current_line.push(Span::raw(format!("  {line}")).fg(THEME.read().ui.secondary_foreground_color));
+                        self.flush_line();
+                    }
+                },
+                false => self.push_text(text.to_string())
+            },
+            Event::Code(code) => self.current_line.push(Span::raw(code.to_string()).fg(THEME.read().ui.secondary_foreground_color)),
+            Event::Html(html) | Event::InlineHtml(html) => self.push_text(html.trim_end().to_string()),
+            Event::SoftBreak => self.push_text(String::from(" ")),
+            Event::HardBreak => self.flush_line(),
+            Event::Rule => {
+                self.flush_line();
+                self.lines.push(Line::from("─".repeat(20)).fg(THEME.read().ui.secondary_foreground_color));
+                self.lines.push(Line::default());
+            },
+            Event::TaskListMarker(checked) => match checked {
+                true => self.push_text(String::from("[x] ")),
+                false => self.push_text(String::from("[ ] "))
+            },
+            _ => {}
+        }
+    }
+
+    fn start_tag(&mut self, tag: Tag) {
+        match tag {
+            Tag::Heading { level, .. } => {
+                self.flush_line();
+
+                let mut style = Style::new()
+                    .fg(THEME.read().others.selection_highlight_color)
+                    .add_modifier(Modifier::BOLD);
+
+                if level as usize == 1 {
+                    style = style.add_modifier(Modifier::UNDERLINED);
+                }
+
+                self.styles.push(style);
+            },
+            Tag::BlockQuote(_) => {
+                self.flush_line();
+                self.block_quote_depth += 1;
+            },
+            Tag::CodeBlock(_) => {
+                self.flush_line();
+                self.in_code_block = true;
+            },
+            Tag::List(first_index) => {
+                self.flush_line();
+                self.list_indices.push(first_index);
+            },
+            Tag::Item => {
+                self.flush_line();
+
+                let indentation = "  ".repeat(self.list_indices.len().saturating_sub(1));
+
+                let bullet = match self.list_indices.last_mut() {
+                    Some(Some(index)) => {
+                        let bullet = format!("{index}. ");
+                        *index += 1;
+                        bullet
+                    },
+                    _ => String::from("• ")
+                };
+
+                self.current_line.push(Span::raw(format!("{indentation}{bullet}")).fg(THEME.read().ui.secondary_foreground_color));
+            },
+            Tag::Emphasis => self.push_style(Style::new().add_modifier(Modifier::ITALIC)),
+            Tag::Strong => self.push_style(Style::new().add_modifier(Modifier::BOLD)),
+            Tag::Strikethrough => self.push_style(Style::new().add_modifier(Modifier::CROSSED_OUT)),
+            Tag::Link { dest_url, .. } => {
+                self.link_url = Some(dest_url.to_string());
+                self.push_style(Style::new().add_modifier(Modifier::UNDERLINED));
+            },
+            _ => {}
+        }
+    }
+
+    fn end_tag(&mut self, tag: TagEnd) {
+        match tag {
+            TagEnd::Paragraph => {
+                self.flush_line();
+
+                // Tight list items do not need to be spaced
+                if self.list_indices.is_empty() {
+                    self.lines.push(Line::default());
+                }
+            },
+            TagEnd::Heading(_) => {
+                self.styles.pop();
+                self.flush_line();
+                self.lines.push(Line::default());
+            },
+            TagEnd::BlockQuote(_) => {
+                self.flush_line();
+                self.block_quote_depth -= 1;
+            },
+            TagEnd::CodeBlock => {
+                self.in_code_block = false;
+                self.lines.push(Line::default());
+            },
+            TagEnd::List(_) => {
+                self.flush_line();
+                self.list_indices.pop();
+
+                if self.list_indices.is_empty() {
+                    self.lines.push(Line::default());
+                }
+            },
+            TagEnd::Item => self.flush_line(),
+            TagEnd::Emphasis | TagEnd::Strong | TagEnd::Strikethrough => {
+                self.styles.pop();
+            },
+            TagEnd::Link => {
+                self.styles.pop();
+
+                if let Some(link_url) = self.link_url.take() {
+                    self.current_line.push(Span::raw(format!(" <{link_url}>")).fg(THEME.read().ui.secondary_foreground_color));
+                }
+            },
+            _ => {}
+        }
+    }
+
+    fn push_style(&mut self, style: Style) {
+        let current_style = self.current_style();
+        self.styles.push(current_style.patch(style));
+    }
+
+    fn current_style(&self) -> Style {
+        match self.styles.last() {
+            None => Style::new().fg(THEME.read().ui.font_color),
+            Some(style) => *style
+        }
+    }
+
+    fn push_text(&mut self, text: String) {
+        let style = self.current_style();
+        self.current_line.push(Span::styled(text, style));
+    }
+
+    fn flush_line(&mut self) {
+        if self.current_line.is_empty() {
+            return;
+        }
+
+        let mut spans = vec![];
+
+        if self.block_quote_depth > 0 {
+            spans.push(Span::raw("│ ".repeat(self.block_quote_depth)).fg(THEME.read().ui.secondary_foreground_color));
+        }
+
+        spans.append(&mut self.current_line);
+
+        self.lines.push(Line::from(spans));
+    }
+}
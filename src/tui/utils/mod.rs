@@ -1,4 +1,7 @@
 pub mod stateful;
 pub mod vim_emulation;
 pub mod syntax_highlighting;
-pub(super) mod centered_rect;
\ No newline at end of file
+pub mod response_views;
+pub mod line_wrapping;
+pub mod completion;
+pub(super) mod centered_rect;pub mod markdown;
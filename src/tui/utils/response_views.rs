@@ -0,0 +1,36 @@
+use std::sync::{Arc, Weak};
+
+use parking_lot::RwLock;
+
+use crate::models::request::Request;
+use crate::tui::utils::syntax_highlighting::SyntaxHighlighting;
+
+/// Response of a request once prepared for display, highlighted when received
+#[derive(Default, Clone)]
+pub struct ResponseView {
+    pub syntax_highlighting: SyntaxHighlighting,
+    pub console_output: Arc<RwLock<Option<String>>>,
+}
+
+/// Prepared responses of the requests, so that selecting a request only swaps its view in
+#[derive(Default)]
+pub struct ResponseViews {
+    views: Vec<(Weak<RwLock<Request>>, ResponseView)>,
+}
+
+impl ResponseViews {
+    /// Shares the state of the stored view, an empty one when the request received no response yet
+    pub fn get(&self, local_request: &Arc<RwLock<Request>>) -> ResponseView {
+        return self.views
+            .iter()
+            .find(|(request, _)| request.as_ptr() == Arc::as_ptr(local_request))
+            .map(|(_, view)| view.clone())
+            .unwrap_or_default();
+    }
+
+    /// Replace the view of the request, the views of the deleted requests are dropped
+    pub fn set(&mut self, local_request: &Arc<RwLock<Request>>, view: ResponseView) {
+        self.views.retain(|(request, _)| request.strong_count() > 0 && request.as_ptr() != Arc::as_ptr(local_request));
+        self.views.push((Arc::downgrade(local_request), view));
+    }
+}
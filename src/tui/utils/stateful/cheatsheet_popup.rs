@@ -0,0 +1,143 @@
+use strum::IntoEnumIterator;
+
+use crate::app::files::locale::tr;
+use crate::tui::app_states::{AppState, EMPTY_KEY, next_app_state};
+use crate::tui::events::AppEvent;
+use crate::tui::ui::param_tabs::param_tabs::RequestParamsTabs;
+use crate::tui::ui::views::RequestView;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+pub struct CheatsheetGroup {
+    /// e.g. "Main menu", "Request menu > Headers"
+    pub context: String,
+    pub actions: Vec<CheatsheetAction>,
+}
+
+#[derive(Clone, PartialEq)]
+pub struct CheatsheetAction {
+    pub name: String,
+    pub keys: String,
+}
+
+#[derive(Default)]
+pub struct CheatsheetPopup {
+    pub groups: Vec<CheatsheetGroup>,
+    pub search_input: TextInput,
+    pub vertical_scroll: u16,
+}
+
+impl CheatsheetPopup {
+    pub fn scroll_up(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_add(1);
+    }
+
+    /// A context matching the search keeps all its actions, otherwise only the actions whose name or keys match are kept
+    pub fn get_filtered_groups(&self) -> Vec<(&str, Vec<&CheatsheetAction>)> {
+        let search = self.search_input.text.trim().to_lowercase();
+        let mut filtered_groups = vec![];
+
+        for group in &self.groups {
+            let actions: Vec<&CheatsheetAction> = match group.context.to_lowercase().contains(&search) {
+                true => group.actions.iter().collect(),
+                false => group.actions
+                    .iter()
+                    .filter(|action| action.name.to_lowercase().contains(&search) || action.keys.to_lowercase().contains(&search))
+                    .collect()
+            };
+
+            if !actions.is_empty() {
+                filtered_groups.push((group.context.as_str(), actions));
+            }
+        }
+
+        return filtered_groups;
+    }
+}
+
+/// Actions of every app state with their current key bindings, the request menu is split by param tab
+pub fn get_cheatsheet_groups() -> Vec<CheatsheetGroup> {
+    let mut groups: Vec<CheatsheetGroup> = vec![];
+    let mut state = AppState::Normal;
+
+    loop {
+        match state {
+            AppState::SelectedRequest => {
+                let tabs_actions: Vec<(RequestParamsTabs, Vec<CheatsheetAction>)> = RequestParamsTabs::iter()
+                    .map(|tab| (tab, events_to_actions(&state.get_available_events(RequestView::Normal, tab))))
+                    .collect();
+
+                // Actions available whatever the param tab
+                let common_actions: Vec<CheatsheetAction> = tabs_actions[0].1
+                    .iter()
+                    .filter(|action| tabs_actions.iter().all(|(_, actions)| actions.contains(action)))
+                    .cloned()
+                    .collect();
+
+                groups.push(CheatsheetGroup {
+                    context: tr(&state.to_string()),
+                    actions: common_actions.clone(),
+                });
+
+                for (tab, actions) in tabs_actions {
+                    let tab_actions: Vec<CheatsheetAction> = actions
+                        .into_iter()
+                        .filter(|action| !common_actions.contains(action))
+                        .collect();
+
+                    groups.push(CheatsheetGroup {
+                        context: format!("{} > {}", tr(&state.to_string()), tr(&tab.to_string())),
+                        actions: tab_actions,
+                    });
+                }
+            },
+            _ => groups.push(CheatsheetGroup {
+                context: tr(&state.to_string()),
+                actions: events_to_actions(&state.get_available_events(RequestView::Normal, RequestParamsTabs::default())),
+            })
+        }
+
+        state = next_app_state(&state);
+
+        if state == AppState::Normal {
+            break;
+        }
+    }
+
+    groups.retain(|group| !group.actions.is_empty());
+
+    return groups;
+}
+
+/// Events without any key, e.g. the char inputs, are left out
+fn events_to_actions(events: &[AppEvent]) -> Vec<CheatsheetAction> {
+    let mut actions: Vec<CheatsheetAction> = vec![];
+
+    for event in events {
+        let event_key_bindings = event.get_event_key_bindings();
+
+        let keys: Vec<String> = event_key_bindings.keys
+            .iter()
+            .filter(|key| **key != *EMPTY_KEY)
+            .map(|key| key.to_string())
+            .collect();
+
+        if keys.is_empty() {
+            continue;
+        }
+
+        let action = CheatsheetAction {
+            name: event_key_bindings.event_name.clone(),
+            keys: keys.join(", "),
+        };
+
+        if !actions.contains(&action) {
+            actions.push(action);
+        }
+    }
+
+    return actions;
+}
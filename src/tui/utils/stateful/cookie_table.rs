@@ -7,8 +7,8 @@ use crate::tui::utils::stateful::text_input::TextInput;
 
 #[derive(Display, FromRepr)]
 pub enum CookieColumns {
-    #[strum(to_string = "URL")]
-    URL,
+    #[strum(to_string = "Domain")]
+    Domain,
     #[strum(to_string = "Name")]
     Name,
     #[strum(to_string = "Value")]
@@ -176,16 +176,11 @@ impl Default for StatefulCookieTable {
 
 pub fn cookie_to_row(cookie: &Cookie) -> [String; COOKIES_COLUMNS_NUMBER]{
     [
-        match cookie.domain() {
-            None => String::new(),
-            Some(domain) => domain.to_string()
-        },
+        // Domain and path under which the cookie is stored, so that it can be found again
+        String::from(&cookie.domain),
         cookie.name().to_string(),
         cookie.value().to_string(),
-        match cookie.path() {
-            None => String::new(),
-            Some(path) => path.to_string()
-        },
+        String::from(&cookie.path),
         match cookie.expires() {
             None => String::new(),
             Some(expiration) => match expiration.is_datetime() {
@@ -193,14 +188,8 @@ pub fn cookie_to_row(cookie: &Cookie) -> [String; COOKIES_COLUMNS_NUMBER]{
                 false => String::from("session")
             }
         },
-        match cookie.http_only() {
-            None => String::new(),
-            Some(http_only) => http_only.to_string()
-        },
-        match cookie.secure() {
-            None => String::new(),
-            Some(secure) => secure.to_string()
-        },
+        cookie.http_only().unwrap_or(false).to_string(),
+        cookie.secure().unwrap_or(false).to_string(),
         match cookie.same_site() {
             None => String::new(),
             Some(same_site) => same_site.to_string()
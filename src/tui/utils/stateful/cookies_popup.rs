@@ -6,5 +6,7 @@ use crate::tui::utils::stateful::cookie_table::StatefulCookieTable;
 #[derive(Default)]
 pub struct CookiesPopup {
     pub cookies_table: StatefulCookieTable,
-    pub cookie_store: Arc<CookieStoreRwLock>
-}
\ No newline at end of file
+    pub cookie_store: Arc<CookieStoreRwLock>,
+    /// Last edition or creation error
+    pub error: Option<String>
+}
@@ -0,0 +1,79 @@
+use strum::{Display, IntoEnumIterator};
+
+use crate::app::business_logic::encoding::EncodingTool;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+/// Field of the selected request the tools were opened from, the result can be inserted into it
+#[derive(Clone, Copy, Display)]
+pub enum EncodingToolsTarget {
+    #[strum(to_string = "URL")]
+    Url,
+    #[strum(to_string = "Query param")]
+    QueryParam,
+    #[strum(to_string = "Basic auth username")]
+    BasicAuthUsername,
+    #[strum(to_string = "Basic auth password")]
+    BasicAuthPassword,
+    #[strum(to_string = "Bearer token")]
+    BearerToken,
+    #[strum(to_string = "HMAC secret")]
+    HmacSecret,
+    #[strum(to_string = "HMAC string to sign")]
+    HmacStringToSign,
+    #[strum(to_string = "HMAC header")]
+    HmacHeader,
+    #[strum(to_string = "Header")]
+    Header,
+    #[strum(to_string = "Cookie")]
+    Cookie,
+    #[strum(to_string = "Form data")]
+    FormData,
+    #[strum(to_string = "Body file")]
+    BodyFile,
+    #[strum(to_string = "Body")]
+    Body,
+}
+
+#[derive(Default)]
+pub struct EncodingToolsPopup {
+    pub tool: EncodingTool,
+    pub input: TextInput,
+    /// Only used by the HMAC
+    pub secret: TextInput,
+    pub is_secret_selected: bool,
+    pub target: Option<EncodingToolsTarget>,
+}
+
+impl EncodingToolsPopup {
+    pub fn next_tool(&mut self) {
+        let tool_index = (self.tool as usize + 1) % EncodingTool::iter().len();
+
+        self.tool = EncodingTool::from_repr(tool_index).unwrap();
+        self.is_secret_selected = false;
+    }
+
+    pub fn previous_tool(&mut self) {
+        let tool_index = match self.tool as usize {
+            0 => EncodingTool::iter().len() - 1,
+            tool_index => tool_index - 1
+        };
+
+        self.tool = EncodingTool::from_repr(tool_index).unwrap();
+        self.is_secret_selected = false;
+    }
+
+    pub fn toggle_selected_input(&mut self) {
+        self.is_secret_selected = !self.is_secret_selected && self.tool.needs_secret();
+    }
+
+    pub fn get_selected_input(&mut self) -> &mut TextInput {
+        match self.is_secret_selected {
+            true => &mut self.secret,
+            false => &mut self.input
+        }
+    }
+
+    pub fn get_result(&self) -> anyhow::Result<String> {
+        return self.tool.apply(&self.input.text, &self.secret.text);
+    }
+}
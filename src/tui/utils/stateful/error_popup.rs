@@ -0,0 +1,9 @@
+use crate::app::business_logic::app_error::AppError;
+use crate::tui::app_states::AppState;
+
+#[derive(Default)]
+pub struct ErrorPopup {
+    pub error: Option<AppError>,
+    /// State the app goes back to once the error has been read
+    pub previous_state: AppState,
+}
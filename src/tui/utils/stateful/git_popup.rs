@@ -0,0 +1,7 @@
+#[derive(Default)]
+pub struct GitPopup {
+    /// Output of git status, or the error if it could not be retrieved
+    pub status: String,
+    /// Output of the last pull, commit or push
+    pub last_output: Option<String>,
+}
@@ -0,0 +1,30 @@
+use strum::Display;
+
+use crate::tui::utils::stateful::text_input::TextInput;
+
+/// Text the popup was opened from, it jumps back to it
+#[derive(Default, Clone, Copy, PartialEq, Display)]
+pub enum GoToLineTarget {
+    #[default]
+    #[strum(to_string = "Body")]
+    RequestBody,
+    #[strum(to_string = "Result")]
+    Result,
+}
+
+#[derive(Default)]
+pub struct GoToLinePopup {
+    pub input: TextInput,
+    pub target: GoToLineTarget,
+    /// Lines of the target text, the entered line is limited to it
+    pub lines_count: usize,
+}
+
+impl GoToLinePopup {
+    /// Lines start at 1, "0" goes to the first line
+    pub fn get_line(&self) -> Option<usize> {
+        let line = self.input.text.trim().parse::<usize>().ok()?;
+
+        return Some(line.clamp(1, self.lines_count.max(1)));
+    }
+}
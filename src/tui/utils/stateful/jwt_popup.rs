@@ -0,0 +1,26 @@
+use crate::app::business_logic::jwt::DecodedJwt;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+#[derive(Default)]
+pub struct JwtPopup {
+    /// Where each token was found, and the token
+    pub jwts: Vec<(String, String)>,
+    pub selection: usize,
+    /// Decoded selected token, or the reason it could not be decoded
+    pub decoded_jwt: Option<Result<DecodedJwt, String>>,
+    /// Result of the last signature verification of the selected token
+    pub verification: Option<Result<String, String>>,
+    /// A pasted token, a secret or a JWKS URL
+    pub input: TextInput,
+    pub vertical_scroll: u16,
+}
+
+impl JwtPopup {
+    pub fn scroll_up(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_add(1);
+    }
+}
@@ -0,0 +1,13 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use tokio_util::sync::CancellationToken;
+
+use crate::models::load_test::LoadTestStats;
+
+#[derive(Default)]
+pub struct LoadTestPopup {
+    pub stats: Arc<RwLock<LoadTestStats>>,
+    pub error: Arc<RwLock<Option<String>>>,
+    pub cancellation_token: CancellationToken,
+}
@@ -0,0 +1,45 @@
+use tracing::Level;
+
+use crate::models::app_log::LOG_PANEL_LEVELS;
+
+pub struct LogsPopup {
+    /// Index of the least severe level displayed in LOG_PANEL_LEVELS
+    pub level_index: usize,
+    pub vertical_scroll: u16,
+}
+
+impl Default for LogsPopup {
+    fn default() -> Self {
+        LogsPopup {
+            // Info
+            level_index: 2,
+            vertical_scroll: 0,
+        }
+    }
+}
+
+impl LogsPopup {
+    pub fn get_level(&self) -> Level {
+        return LOG_PANEL_LEVELS[self.level_index];
+    }
+
+    /// Display fewer records
+    pub fn previous_level(&mut self) {
+        self.level_index = self.level_index.saturating_sub(1);
+        self.vertical_scroll = 0;
+    }
+
+    /// Display more records
+    pub fn next_level(&mut self) {
+        self.level_index = usize::min(self.level_index + 1, LOG_PANEL_LEVELS.len() - 1);
+        self.vertical_scroll = 0;
+    }
+
+    pub fn scroll_up(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_add(1);
+    }
+}
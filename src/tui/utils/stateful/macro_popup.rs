@@ -0,0 +1,35 @@
+use std::collections::VecDeque;
+
+use crokey::KeyCombination;
+
+use crate::tui::app_states::AppState;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+/// Most replays of a macro at once
+pub const MAX_MACRO_REPLAYS: usize = 1000;
+
+#[derive(Default)]
+pub struct MacroPopup {
+    /// Last macro recorded in the workspace
+    pub keys: Vec<KeyCombination>,
+    /// Keys pressed since the recording started, None when not recording
+    pub recording: Option<Vec<KeyCombination>>,
+    /// Keys left to replay, handled one per tick
+    pub pending_keys: VecDeque<KeyCombination>,
+    /// Number of times the macro is replayed
+    pub count_input: TextInput,
+    /// State the macro is replayed from
+    pub previous_state: AppState,
+}
+
+impl MacroPopup {
+    /// Empty counts replay the macro once
+    pub fn get_count(&self) -> Option<usize> {
+        let count = match self.count_input.text.trim() {
+            "" => 1,
+            count => count.parse::<usize>().ok()?
+        };
+
+        return Some(count.clamp(1, MAX_MACRO_REPLAYS));
+    }
+}
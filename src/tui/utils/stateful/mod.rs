@@ -12,3 +12,20 @@ pub mod text_input;
 pub mod text_input_selection;
 pub mod validation_popup;
 pub mod new_request_popup;
+pub mod webhook_listener_popup;
+pub mod load_test_popup;
+pub mod git_popup;
+pub mod request_preview_popup;
+pub mod variable_usages_popup;
+pub mod jwt_popup;
+pub mod encoding_tools_popup;
+pub mod go_to_line_popup;
+
+pub mod scratchpad_popup;
+pub mod cheatsheet_popup;
+pub mod response_cache_popup;
+pub mod logs_popup;
+pub mod error_popup;
+pub mod macro_popup;
+pub mod scripting_console_popup;
+pub mod response_links_popup;
\ No newline at end of file
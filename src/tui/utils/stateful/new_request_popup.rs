@@ -4,6 +4,9 @@ use crate::tui::utils::stateful::text_input::TextInput;
 pub struct NewRequestPopup {
     pub selected_collection: usize,
     pub max_selection: usize,
+    /// None when the request is not created from a template
+    pub selected_template: Option<usize>,
+    pub max_template_selection: usize,
     pub text_input: TextInput
 }
 
@@ -25,4 +28,13 @@ impl NewRequestPopup {
             self.selected_collection = self.max_selection - 1;
         }
     }
-}
\ No newline at end of file
+
+    /// Cycle through the templates, then back to no template
+    pub fn next_template(&mut self) {
+        self.selected_template = match self.selected_template {
+            None if self.max_template_selection > 0 => Some(0),
+            Some(index) if index + 1 < self.max_template_selection => Some(index + 1),
+            _ => None
+        };
+    }
+}
@@ -0,0 +1,27 @@
+use crate::app::business_logic::request::preview::RequestPreview;
+
+#[derive(Default)]
+pub struct RequestPreviewPopup {
+    pub preview: RequestPreview,
+    pub vertical_scroll: u16,
+    pub horizontal_scroll: u16,
+}
+
+impl RequestPreviewPopup {
+    pub fn scroll_up(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_add(1);
+    }
+
+    pub fn scroll_left(&mut self) {
+        self.horizontal_scroll = self.horizontal_scroll.saturating_sub(1);
+    }
+
+    /// Limited to the widest line when rendered
+    pub fn scroll_right(&mut self) {
+        self.horizontal_scroll = self.horizontal_scroll.saturating_add(1);
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+
+use crate::models::response_cache::ResponseCache;
+
+#[derive(Default)]
+pub struct ResponseCachePopup {
+    pub response_cache: Arc<RwLock<ResponseCache>>,
+    /// Index of the selected entry
+    pub selection: usize,
+    /// Kept between frames so that the selected entry stays visible
+    pub vertical_scroll: u16,
+}
+
+impl ResponseCachePopup {
+    pub fn previous(&mut self) {
+        let entries_count = self.response_cache.read().entries.len();
+
+        self.selection = match self.selection {
+            0 => entries_count.saturating_sub(1),
+            selection => selection - 1
+        };
+    }
+
+    pub fn next(&mut self) {
+        let entries_count = self.response_cache.read().entries.len();
+
+        self.selection = match self.selection + 1 >= entries_count {
+            true => 0,
+            false => self.selection + 1
+        };
+    }
+
+    pub fn delete_selected_entry(&mut self) {
+        let mut response_cache = self.response_cache.write();
+
+        if self.selection >= response_cache.entries.len() {
+            return;
+        }
+
+        response_cache.entries.remove(self.selection);
+        self.selection = self.selection.min(response_cache.entries.len().saturating_sub(1));
+    }
+}
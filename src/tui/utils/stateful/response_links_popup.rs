@@ -0,0 +1,26 @@
+use crate::app::business_logic::response_links::ResponseLink;
+
+#[derive(Default)]
+pub struct ResponseLinksPopup {
+    pub links: Vec<ResponseLink>,
+    /// Index of the selected link
+    pub selection: usize,
+    /// Kept between frames so that the selected link stays visible
+    pub vertical_scroll: u16,
+}
+
+impl ResponseLinksPopup {
+    pub fn previous(&mut self) {
+        self.selection = match self.selection {
+            0 => self.links.len().saturating_sub(1),
+            selection => selection - 1
+        };
+    }
+
+    pub fn next(&mut self) {
+        self.selection = match self.selection + 1 >= self.links.len() {
+            true => 0,
+            false => self.selection + 1
+        };
+    }
+}
@@ -0,0 +1,32 @@
+use crate::models::scratchpad::ScratchpadEntry;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+#[derive(Default)]
+pub struct ScratchpadPopup {
+    /// Newest first
+    pub entries: Vec<ScratchpadEntry>,
+    pub selection: usize,
+    pub note_input: TextInput,
+    /// Kept between frames so that the selected entry stays visible
+    pub vertical_scroll: u16,
+}
+
+impl ScratchpadPopup {
+    pub fn previous(&mut self) {
+        self.selection = match self.selection {
+            0 => self.entries.len().saturating_sub(1),
+            selection => selection - 1
+        };
+    }
+
+    pub fn next(&mut self) {
+        self.selection = match self.selection + 1 >= self.entries.len() {
+            true => 0,
+            false => self.selection + 1
+        };
+    }
+
+    pub fn get_selected_entry(&self) -> Option<&ScratchpadEntry> {
+        return self.entries.get(self.selection);
+    }
+}
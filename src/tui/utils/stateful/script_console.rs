@@ -1,6 +1,7 @@
 use std::sync::Arc;
 use parking_lot::RwLock;
 use tui_textarea::TextArea;
+use crate::tui::utils::stateful::text_input::TextInput;
 use crate::tui::utils::vim_emulation::Vim;
 
 #[derive(Default)]
@@ -8,16 +9,20 @@ pub struct ScriptConsole<'a> {
     pub console_output: Arc<RwLock<Option<String>>>,
     pub pre_request_text_area: TextArea<'a>,
     pub post_request_text_area: TextArea<'a>,
+    pub response_processor_text_input: TextInput,
     pub script_selection: u16,
     pub vim_emulation: Vim
 }
 
+/// Pre-request script, post-request script and response processor
+const SCRIPT_SELECTION_COUNT: u16 = 3;
+
 impl ScriptConsole<'_> {
-    pub fn change_selection(&mut self) {
-        self.script_selection = match self.script_selection {
-            0 => 1,
-            1 => 0,
-            _ => 0
-        }
+    pub fn previous_selection(&mut self) {
+        self.script_selection = (self.script_selection + SCRIPT_SELECTION_COUNT - 1) % SCRIPT_SELECTION_COUNT;
+    }
+
+    pub fn next_selection(&mut self) {
+        self.script_selection = (self.script_selection + 1) % SCRIPT_SELECTION_COUNT;
     }
 }
\ No newline at end of file
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use indexmap::IndexMap;
+use parking_lot::RwLock;
+
+use crate::models::response::RequestResponse;
+use crate::tui::utils::stateful::text_input::TextInput;
+
+/// Lines kept in the console, the oldest are dropped
+pub const MAX_CONSOLE_LINES: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ConsoleLineKind {
+    Input,
+    Output,
+    Error,
+}
+
+#[derive(Debug, Clone)]
+pub struct ConsoleLine {
+    pub kind: ConsoleLineKind,
+    pub text: String,
+}
+
+pub struct ScriptingConsolePopup {
+    pub input: TextInput,
+    /// Shared with the sends made from the console, which print their status when answered
+    pub lines: Arc<RwLock<Vec<ConsoleLine>>>,
+    /// Last response of each "collection/request" sent from the console
+    pub responses: Arc<RwLock<IndexMap<String, RequestResponse>>>,
+    /// JSON object of the "vars" variable, kept between two scripts
+    pub vars: String,
+    /// Scripts already run, oldest first
+    pub history: Vec<String>,
+    /// Browsed script of the history, None while typing a new one
+    pub history_index: Option<usize>,
+}
+
+impl Default for ScriptingConsolePopup {
+    fn default() -> Self {
+        ScriptingConsolePopup {
+            input: TextInput::default(),
+            lines: Arc::new(RwLock::new(vec![])),
+            responses: Arc::new(RwLock::new(IndexMap::new())),
+            vars: String::from("{}"),
+            history: vec![],
+            history_index: None,
+        }
+    }
+}
+
+impl ScriptingConsolePopup {
+    pub fn push_lines(lines: &RwLock<Vec<ConsoleLine>>, kind: ConsoleLineKind, text: &str) {
+        let mut lines = lines.write();
+
+        for line in text.lines() {
+            lines.push(ConsoleLine {
+                kind,
+                text: line.to_string(),
+            });
+        }
+
+        let overflow = lines.len().saturating_sub(MAX_CONSOLE_LINES);
+        lines.drain(..overflow);
+    }
+
+    pub fn previous_history_entry(&mut self) {
+        if self.history.is_empty() {
+            return;
+        }
+
+        let index = match self.history_index {
+            None => self.history.len() - 1,
+            Some(index) => index.saturating_sub(1)
+        };
+
+        self.history_index = Some(index);
+        self.input.reset_input();
+        self.input.enter_str(&self.history[index]);
+    }
+
+    pub fn next_history_entry(&mut self) {
+        let index = match self.history_index {
+            None => return,
+            Some(index) => index + 1
+        };
+
+        self.input.reset_input();
+
+        match index < self.history.len() {
+            true => {
+                self.history_index = Some(index);
+                self.input.enter_str(&self.history[index]);
+            },
+            false => self.history_index = None
+        }
+    }
+}
\ No newline at end of file
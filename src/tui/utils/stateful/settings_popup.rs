@@ -1,6 +1,8 @@
+use crate::models::settings::SettingValue;
+
 #[derive(Default)]
 pub struct SettingsPopup {
-    pub settings: Vec<(String, bool)>,
+    pub settings: Vec<(String, SettingValue)>,
     pub selection: usize,
 }
 
@@ -23,7 +25,23 @@ impl SettingsPopup {
         }
     }
 
-    pub fn toggle_setting(&mut self) {
-        self.settings[self.selection].1 = !self.settings[self.selection].1
+    /// Toggle a boolean setting, decrease a number setting or select the previous choice or step
+    pub fn previous_value(&mut self) {
+        self.settings[self.selection].1 = match self.settings[self.selection].1 {
+            SettingValue::Bool(value) => SettingValue::Bool(!value),
+            SettingValue::Number(value) => SettingValue::Number(value.saturating_sub(1)),
+            SettingValue::Choice(value, choices) => SettingValue::cycle_choice(value, choices, -1),
+            SettingValue::Steps(value, steps) => SettingValue::move_step(value, steps, -1)
+        };
+    }
+
+    /// Toggle a boolean setting, increase a number setting or select the next choice or step
+    pub fn next_value(&mut self) {
+        self.settings[self.selection].1 = match self.settings[self.selection].1 {
+            SettingValue::Bool(value) => SettingValue::Bool(!value),
+            SettingValue::Number(value) => SettingValue::Number(value + 1),
+            SettingValue::Choice(value, choices) => SettingValue::cycle_choice(value, choices, 1),
+            SettingValue::Steps(value, steps) => SettingValue::move_step(value, steps, 1)
+        };
     }
 }
@@ -47,6 +47,19 @@ impl StatefulCustomTable {
         self.left_state.select(Some(x));
     }
 
+    /// For tables having more than a key and a value column
+    pub fn next_column(&mut self, columns: usize) {
+        if let Some((x, y)) = self.selection {
+            self.selection = Some((x, (y + 1) % columns));
+        }
+    }
+
+    pub fn previous_column(&mut self, columns: usize) {
+        if let Some((x, y)) = self.selection {
+            self.selection = Some((x, (y + columns - 1) % columns));
+        }
+    }
+
     pub fn up(&mut self) {
         if self.rows.is_empty() || self.selection.is_none() {
             return;
@@ -57,11 +70,10 @@ impl StatefulCustomTable {
                 None => 0,
                 Some(i) => self.decrement_x(i)
             },
-            (_, 1) => match self.right_state.selected() {
+            (_, _) => match self.right_state.selected() {
                 None => 0,
                 Some(i) => self.decrement_x(i)
-            },
-            (_, _) => 0
+            }
         };
 
         self.left_state.select(Some(x));
@@ -82,11 +94,10 @@ impl StatefulCustomTable {
                 None => 0,
                 Some(i) => self.increment_x(i)
             },
-            (_, 1) => match self.right_state.selected() {
+            (_, _) => match self.right_state.selected() {
                 None => 0,
                 Some(i) => self.increment_x(i)
-            },
-            (_, _) => 0
+            }
         };
 
         self.left_state.select(Some(x));
@@ -100,4 +111,17 @@ impl StatefulCustomTable {
     pub fn is_selected(&self) -> bool {
         return self.selection.is_some();
     }
+
+    /// Returns false if the cell does not exist
+    pub fn select(&mut self, x: usize, y: usize) -> bool {
+        if x >= self.rows.len() || y > 1 {
+            return false;
+        }
+
+        self.selection = Some((x, y));
+        self.left_state.select(Some(x));
+        self.right_state.select(Some(x));
+
+        return true;
+    }
 }
\ No newline at end of file
@@ -2,8 +2,8 @@ use ratatui::widgets::{ScrollbarState};
 
 #[derive(Default)]
 pub struct StatefulScrollbar {
-    pub scroll: u16,
-    pub max_scroll: u16,
+    pub scroll: usize,
+    pub max_scroll: usize,
     pub state: ScrollbarState,
 }
 
@@ -24,7 +24,7 @@ impl StatefulScrollbar {
 
     pub fn set_scroll(&mut self, lines: usize) {
         if lines > 0 {
-            self.max_scroll = lines as u16 - 1;
+            self.max_scroll = lines - 1;
         }
         else {
             self.max_scroll = 0;
@@ -35,4 +35,10 @@ impl StatefulScrollbar {
 
         self.state = self.state.content_length(lines);
     }
+
+    /// Limited to the max scroll
+    pub fn scroll_to(&mut self, scroll: usize) {
+        self.scroll = scroll.min(self.max_scroll);
+        self.state = self.state.position(self.scroll);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use crate::app::business_logic::variable_usages::VariableUsages;
+
+#[derive(Default)]
+pub struct VariableUsagesPopup {
+    pub usages: Vec<VariableUsages>,
+    pub vertical_scroll: u16,
+}
+
+impl VariableUsagesPopup {
+    pub fn scroll_up(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_sub(1);
+    }
+
+    pub fn scroll_down(&mut self) {
+        self.vertical_scroll = self.vertical_scroll.saturating_add(1);
+    }
+}
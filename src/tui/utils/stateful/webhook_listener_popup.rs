@@ -0,0 +1,53 @@
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use ratatui::widgets::ListState;
+use tokio_util::sync::CancellationToken;
+
+use crate::models::webhook::ReceivedRequest;
+
+#[derive(Default)]
+pub struct WebhookListenerPopup {
+    pub received_requests: Arc<RwLock<Vec<ReceivedRequest>>>,
+    pub listener_error: Arc<RwLock<Option<String>>>,
+    /// Is some when the listener is running
+    pub cancellation_token: Option<CancellationToken>,
+    pub port: u16,
+    pub list_state: ListState,
+}
+
+impl WebhookListenerPopup {
+    pub fn is_running(&self) -> bool {
+        return self.cancellation_token.is_some();
+    }
+
+    pub fn previous(&mut self) {
+        let requests_length = self.received_requests.read().len();
+
+        if requests_length == 0 {
+            return;
+        }
+
+        let selection = match self.list_state.selected() {
+            None | Some(0) => requests_length - 1,
+            Some(selection) => selection - 1
+        };
+
+        self.list_state.select(Some(selection));
+    }
+
+    pub fn next(&mut self) {
+        let requests_length = self.received_requests.read().len();
+
+        if requests_length == 0 {
+            return;
+        }
+
+        let selection = match self.list_state.selected() {
+            Some(selection) if selection + 1 < requests_length => selection + 1,
+            _ => 0
+        };
+
+        self.list_state.select(Some(selection));
+    }
+}
@@ -1,18 +1,69 @@
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 use lazy_static::lazy_static;
 use parking_lot::RwLock;
 use ratatui::prelude::Color;
 use ratatui::style::Stylize;
 use ratatui::text::{Line, Span};
+use strum::Display;
 use syntect::easy::HighlightLines;
 use syntect::highlighting::ThemeSet;
 use syntect::parsing::SyntaxSet;
+use tokio::task;
 
-#[derive(Default)]
+use crate::models::body::{find_response_delimiter, find_response_file_format};
+use crate::models::response::{RequestResponse, ResponseContent};
+
+/// Shares its state when cloned, e.g. with the response view of the selected request
+#[derive(Default, Clone)]
 pub struct SyntaxHighlighting {
-    pub highlighted_body: Arc<RwLock<Option<Vec<Line<'static>>>>>,
+    pub body_highlighter: BodyHighlighter,
+    /// Displayed instead of the highlighted body for newline-delimited JSON responses
+    pub ndjson_records: Arc<RwLock<Option<NdjsonRecords>>>,
+    /// Displayed instead of the highlighted body for CSV and TSV responses
+    pub csv_table: Arc<RwLock<Option<CsvTable>>>,
     pub highlighted_console_output: Arc<RwLock<Vec<Line<'static>>>>,
+    pub body_language: BodyHighlightingLanguage,
+}
+
+/// Language used to highlight the response body, auto-detected by default
+#[derive(Default, Copy, Clone, PartialEq, Display)]
+pub enum BodyHighlightingLanguage {
+    #[default]
+    #[strum(to_string = "Auto")]
+    Auto,
+    #[strum(to_string = "JSON")]
+    Json,
+    #[strum(to_string = "XML")]
+    Xml,
+    #[strum(to_string = "HTML")]
+    Html,
+    #[strum(to_string = "Plain text")]
+    PlainText,
+}
+
+impl BodyHighlightingLanguage {
+    pub fn next(&self) -> BodyHighlightingLanguage {
+        match self {
+            BodyHighlightingLanguage::Auto => BodyHighlightingLanguage::Json,
+            BodyHighlightingLanguage::Json => BodyHighlightingLanguage::Xml,
+            BodyHighlightingLanguage::Xml => BodyHighlightingLanguage::Html,
+            BodyHighlightingLanguage::Html => BodyHighlightingLanguage::PlainText,
+            BodyHighlightingLanguage::PlainText => BodyHighlightingLanguage::Auto,
+        }
+    }
+
+    /// Returns None for Auto and PlainText
+    pub fn get_file_format(&self) -> Option<&'static str> {
+        match self {
+            BodyHighlightingLanguage::Auto | BodyHighlightingLanguage::PlainText => None,
+            BodyHighlightingLanguage::Json => Some("json"),
+            BodyHighlightingLanguage::Xml => Some("xml"),
+            BodyHighlightingLanguage::Html => Some("html"),
+        }
+    }
 }
 
 lazy_static! {
@@ -28,22 +79,438 @@ pub fn highlight(string: &str, extension: &str) -> Option<Vec<Line<'static>>> {
         Some(syntax) => syntax
     };
 
-    let mut highlight = HighlightLines::new(syntax, &THEME_SET.themes["base16-ocean.dark"]);
+    let mut highlighter = HighlightLines::new(syntax, &THEME_SET.themes["base16-ocean.dark"]);
+
+    let lines = string
+        .lines()
+        .map(|line| highlight_line(&mut highlighter, line))
+        .collect();
+
+    return Some(lines);
+}
+
+fn highlight_line(highlighter: &mut HighlightLines, line: &str) -> Line<'static> {
+    let result = highlighter.highlight_line(line, &SYNTAX_SET).unwrap();
+
+    let mut highlighted_line: Vec<Span> = vec![];
+
+    for &(ref style, text) in result.iter() {
+        highlighted_line.push(Span::raw(text.to_string()).fg(Color::Rgb(style.foreground.r, style.foreground.g, style.foreground.b)));
+    }
+
+    return Line::from(highlighted_line);
+}
+
+/// Lines highlighted before being shared with the UI
+const HIGHLIGHTING_CHUNK_SIZE: usize = 500;
+/// Lines highlighted ahead when the displayed lines are not highlighted yet, more than a terminal can display
+const VIEWPORT_HEIGHT: usize = 200;
+
+/// Response body being highlighted, its lines are displayed as text until they are highlighted
+pub struct HighlightedBody {
+    lines: Vec<Option<Line<'static>>>,
+    /// Lines highlighted from the top, the lines below may have been highlighted ahead for the viewport
+    highlighted_lines_count: usize,
+}
+
+impl HighlightedBody {
+    pub fn get_line(&self, index: usize) -> Option<&Line<'static>> {
+        return self.lines.get(index).and_then(Option::as_ref);
+    }
+
+    pub fn is_complete(&self) -> bool {
+        return self.highlighted_lines_count >= self.lines.len();
+    }
+
+    /// Percentage of the lines highlighted from the top
+    pub fn get_progress(&self) -> usize {
+        return self.highlighted_lines_count * 100 / self.lines.len().max(1);
+    }
+}
+
+/// Highlights the response body in a background task, so that large bodies do not freeze the UI
+#[derive(Default, Clone)]
+pub struct BodyHighlighter {
+    pub highlighted_body: Arc<RwLock<Option<HighlightedBody>>>,
+    /// First displayed line, highlighted before the lines between it and the highlighted ones
+    pub viewport_start: Arc<AtomicUsize>,
+    /// Changed to stop the highlighting in progress
+    generation: Arc<AtomicUsize>,
+}
+
+impl BodyHighlighter {
+    /// Stop the highlighting in progress and drop the highlighted lines
+    pub fn clear(&self) {
+        self.generation.fetch_add(1, Ordering::SeqCst);
+        *self.highlighted_body.write() = None;
+    }
+
+    /// Replace the highlighted body, nothing is highlighted when the extension is unknown
+    pub fn highlight(&self, body: String, extension: &str) {
+        self.clear();
+
+        let syntax = match SYNTAX_SET.find_syntax_by_extension(extension) {
+            Some(syntax) => syntax,
+            None => return
+        };
+
+        let generation = self.generation.load(Ordering::SeqCst);
+        let local_generation = Arc::clone(&self.generation);
+        let local_highlighted_body = Arc::clone(&self.highlighted_body);
+        let local_viewport_start = Arc::clone(&self.viewport_start);
+
+        task::spawn_blocking(move || {
+            let theme = &THEME_SET.themes["base16-ocean.dark"];
+            let lines: Vec<&str> = body.lines().collect();
+
+            // The write lock is held while checking, a newer highlighting cannot start in between
+            let publish = |update: &mut dyn FnMut(&mut HighlightedBody)| -> bool {
+                let mut highlighted_body = local_highlighted_body.write();
+
+                if local_generation.load(Ordering::SeqCst) != generation {
+                    return false;
+                }
+
+                if let Some(highlighted_body) = highlighted_body.as_mut() {
+                    update(highlighted_body);
+                }
+
+                return true;
+            };
+
+            {
+                let mut highlighted_body = local_highlighted_body.write();
+
+                if local_generation.load(Ordering::SeqCst) != generation {
+                    return;
+                }
+
+                *highlighted_body = Some(HighlightedBody {
+                    lines: vec![None; lines.len()],
+                    highlighted_lines_count: 0,
+                });
+            }
+
+            let mut highlighter = HighlightLines::new(syntax, theme);
+            let mut next_line = 0;
+
+            while next_line < lines.len() {
+                let viewport_start = local_viewport_start.load(Ordering::Relaxed);
+
+                // Highlighted without the parsing state of the lines above, the lines are replaced once the highlighting reaches them
+                if viewport_start > next_line + HIGHLIGHTING_CHUNK_SIZE && viewport_start < lines.len() {
+                    let viewport_end = (viewport_start + VIEWPORT_HEIGHT).min(lines.len());
+
+                    let is_viewport_highlighted = local_highlighted_body
+                        .read()
+                        .as_ref()
+                        .map_or(true, |highlighted_body| highlighted_body.lines[viewport_start..viewport_end].iter().all(Option::is_some));
+
+                    if !is_viewport_highlighted {
+                        let mut viewport_highlighter = HighlightLines::new(syntax, theme);
+
+                        let viewport_lines: Vec<Line> = lines[viewport_start..viewport_end]
+                            .iter()
+                            .map(|line| highlight_line(&mut viewport_highlighter, line))
+                            .collect();
+
+                        let is_current = publish(&mut |highlighted_body| {
+                            for (index, line) in viewport_lines.iter().enumerate() {
+                                highlighted_body.lines[viewport_start + index].get_or_insert_with(|| line.clone());
+                            }
+                        });
+
+                        if !is_current {
+                            return;
+                        }
+                    }
+                }
+
+                let chunk_end = (next_line + HIGHLIGHTING_CHUNK_SIZE).min(lines.len());
+
+                let mut chunk_lines: Vec<Option<Line>> = lines[next_line..chunk_end]
+                    .iter()
+                    .map(|line| Some(highlight_line(&mut highlighter, line)))
+                    .collect();
+
+                let is_current = publish(&mut |highlighted_body| {
+                    for (index, line) in chunk_lines.iter_mut().enumerate() {
+                        highlighted_body.lines[next_line + index] = line.take();
+                    }
+
+                    highlighted_body.highlighted_lines_count = chunk_end;
+                });
+
+                if !is_current {
+                    return;
+                }
+
+                next_line = chunk_end;
+            }
+        });
+    }
+}
+
+/// Records of the response body when it is newline-delimited JSON
+pub fn get_ndjson_records(response: &RequestResponse, should_highlight: bool) -> Option<NdjsonRecords> {
+    let body = match &response.content {
+        Some(ResponseContent::Body(body)) => body,
+        _ => return None
+    };
+
+    return match find_response_file_format(&response.headers, body).as_deref() {
+        Some("ndjson") => Some(NdjsonRecords::new(body, should_highlight)),
+        _ => None
+    };
+}
+
+/// Records of a newline-delimited JSON body, each one being pretty-printed and foldable
+pub struct NdjsonRecords {
+    records: Vec<NdjsonRecord>,
+    folded: HashSet<usize>,
+}
+
+struct NdjsonRecord {
+    line: String,
+    lines: Vec<Line<'static>>,
+}
+
+impl NdjsonRecords {
+    pub fn new(body: &str, should_highlight: bool) -> NdjsonRecords {
+        let records = body
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                // Lines that are not valid JSON are kept as is
+                let pretty_record = jsonxf::pretty_print(line).unwrap_or(line.to_string());
+
+                let highlighted_record = match should_highlight {
+                    true => highlight(&pretty_record, "json"),
+                    false => None
+                };
+
+                NdjsonRecord {
+                    line: line.to_string(),
+                    lines: highlighted_record.unwrap_or_else(|| pretty_record.lines().map(|line| Line::raw(line.to_string())).collect()),
+                }
+            })
+            .collect();
+
+        NdjsonRecords {
+            records,
+            folded: HashSet::new(),
+        }
+    }
+
+    /// A header line per record, followed by the record unless it is folded. Only the records displayed are materialized
+    pub fn get_lines(&self, first_line: usize, count: usize) -> Vec<Line<'static>> {
+        let mut lines: Vec<Line> = vec![];
+        let mut record_first_line = 0;
+
+        for index in 0..self.records.len() {
+            if lines.len() >= count {
+                break;
+            }
+
+            let record_height = self.get_record_height(index);
+
+            if record_first_line + record_height > first_line {
+                let header_line = self.get_header_line(index);
+
+                let record_lines: &[Line] = match self.folded.contains(&index) {
+                    true => &[],
+                    false => &self.records[index].lines
+                };
+
+                let displayed_lines = std::iter::once(&header_line)
+                    .chain(record_lines)
+                    .skip(first_line.saturating_sub(record_first_line))
+                    .take(count - lines.len())
+                    .cloned();
+
+                lines.extend(displayed_lines);
+            }
+
+            record_first_line += record_height;
+        }
+
+        return lines;
+    }
+
+    pub fn lines_count(&self) -> usize {
+        return (0..self.records.len()).map(|index| self.get_record_height(index)).sum();
+    }
+
+    pub fn width(&self) -> usize {
+        return (0..self.records.len())
+            .map(|index| {
+                let record_width = match self.folded.contains(&index) {
+                    true => 0,
+                    false => self.records[index].lines.iter().map(Line::width).max().unwrap_or(0)
+                };
+
+                record_width.max(self.get_header_line(index).width())
+            })
+            .max()
+            .unwrap_or(0);
+    }
+
+    fn get_record_height(&self, index: usize) -> usize {
+        return match self.folded.contains(&index) {
+            true => 1,
+            false => self.records[index].lines.len() + 1
+        };
+    }
+
+    fn get_header_line(&self, index: usize) -> Line<'static> {
+        return match self.folded.contains(&index) {
+            true => Line::from(vec![
+                Span::raw(format!("▶ #{} ", index + 1)).bold(),
+                Span::raw(self.records[index].line.clone()).dim(),
+            ]),
+            false => Line::raw(format!("▼ #{}", index + 1)).bold()
+        };
+    }
+
+    /// Fold or unfold the record displayed at the given line, returns the line of its header
+    pub fn toggle_record_at_line(&mut self, line: usize) -> Option<usize> {
+        let mut header_line = 0;
+
+        for index in 0..self.records.len() {
+            let record_height = self.get_record_height(index);
+
+            if line < header_line + record_height {
+                if !self.folded.remove(&index) {
+                    self.folded.insert(index);
+                }
+
+                return Some(header_line);
+            }
+
+            header_line += record_height;
+        }
+
+        return None;
+    }
+}
+
+/// Table of the response body when it is CSV or TSV
+pub fn get_csv_table(response: &RequestResponse) -> Option<CsvTable> {
+    let body = match &response.content {
+        Some(ResponseContent::Body(body)) => body,
+        _ => return None
+    };
+
+    let delimiter = find_response_delimiter(&response.headers)?;
+
+    return CsvTable::new(body, delimiter);
+}
 
-    let mut lines: Vec<Line> = vec![];
+/// CSV or TSV body displayed as aligned columns, the first record being the header
+pub struct CsvTable {
+    header: Vec<Line<'static>>,
+    rows: Vec<Line<'static>>,
+    width: usize,
+    /// Display the body as it was received
+    pub is_raw: bool,
+}
 
+impl CsvTable {
+    /// Returns None when the body is not valid CSV, it is then displayed as text
+    pub fn new(body: &str, delimiter: u8) -> Option<CsvTable> {
+        let mut reader = csv::ReaderBuilder::new()
+            .delimiter(delimiter)
+            .has_headers(false)
+            .flexible(true)
+            .from_reader(body.as_bytes());
 
-    for line in string.lines() {
-        let result = highlight.highlight_line(line, &SYNTAX_SET).unwrap();
+        let mut records: Vec<Vec<String>> = vec![];
 
-        let mut highlighted_line: Vec<Span> = vec![];
+        for record in reader.records() {
+            let record = record.ok()?;
 
-        for &(ref style, text) in result.iter() {
-            highlighted_line.push(Span::raw(text.to_string()).fg(Color::Rgb(style.foreground.r, style.foreground.g, style.foreground.b)));
+            // Keeps each record on a single line
+            let cells = record
+                .iter()
+                .map(|cell| cell.replace(['\r', '\n'], " ").replace('\t', "    "))
+                .collect();
+
+            records.push(cells);
+        }
+
+        if records.is_empty() {
+            return None;
         }
 
-        lines.push(Line::from(highlighted_line));
+        let columns_count = records.iter().map(Vec::len).max().unwrap_or(0);
+        let mut column_widths = vec![0; columns_count];
+
+        for record in &records {
+            for (index, cell) in record.iter().enumerate() {
+                column_widths[index] = column_widths[index].max(Span::raw(cell.as_str()).width());
+            }
+        }
+
+        let to_line = |record: &Vec<String>| -> Line<'static> {
+            let mut spans: Vec<Span> = vec![];
+
+            for (index, column_width) in column_widths.iter().enumerate() {
+                if index > 0 {
+                    spans.push(Span::raw(" │ ").dim());
+                }
+
+                let cell = record.get(index).map(String::as_str).unwrap_or("");
+                let padding = column_width - Span::raw(cell).width();
+
+                spans.push(Span::raw(format!("{cell}{}", " ".repeat(padding))));
+            }
+
+            return Line::from(spans);
+        };
+
+        let separator = column_widths
+            .iter()
+            .map(|column_width| "─".repeat(*column_width))
+            .collect::<Vec<String>>()
+            .join("─┼─");
+
+        let header = vec![
+            to_line(&records[0]).bold(),
+            Line::raw(separator).dim(),
+        ];
+
+        let rows: Vec<Line> = records[1..].iter().map(to_line).collect();
+
+        let width = column_widths.iter().sum::<usize>() + 3 * columns_count.saturating_sub(1);
+
+        return Some(CsvTable {
+            header,
+            rows,
+            width,
+            is_raw: false,
+        });
     }
 
-    return Some(lines);
-}
\ No newline at end of file
+    /// Column headers, followed by a separator line
+    pub fn header(&self) -> Vec<Line<'static>> {
+        return self.header.clone();
+    }
+
+    /// Only the displayed rows are materialized
+    pub fn rows(&self, first_row: usize, count: usize) -> Vec<Line<'static>> {
+        return self.rows
+            .iter()
+            .skip(first_row)
+            .take(count)
+            .cloned()
+            .collect();
+    }
+
+    pub fn rows_count(&self) -> usize {
+        return self.rows.len();
+    }
+
+    pub fn width(&self) -> usize {
+        return self.width;
+    }
+}